@@ -0,0 +1,124 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use moka::notification::RemovalCause;
+use serde::Serialize;
+
+/// Čítače cache API klienta od startu serveru
+///
+/// Zásahy a výpadky se počítají podle typu entity (project, issue, ...), vyřazení
+/// záznamů podle příčiny z eviction listeneru cache.
+#[derive(Debug, Default)]
+pub struct CacheCounters {
+    by_entity: Mutex<HashMap<String, EntityCounters>>,
+    expired: AtomicU64,
+    evicted: AtomicU64,
+    invalidated: AtomicU64,
+    not_modified: AtomicU64,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct EntityCounters {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+/// Souhrn statistik cache pro diagnostiku
+#[derive(Debug, Clone, Serialize)]
+pub struct CacheStats {
+    pub enabled: bool,
+    pub entries: u64,
+    pub max_entries: u64,
+    pub hits: u64,
+    pub misses: u64,
+    /// Podíl dotazů obsloužených z cache (0.0 - 1.0)
+    pub hit_ratio: f64,
+    /// Záznamy vyřazené po uplynutí TTL
+    pub expired: u64,
+    /// Záznamy vyřazené kvůli kapacitě max_entries
+    pub evicted: u64,
+    /// Záznamy zahozené invalidací po zápisu nebo clear_cache
+    pub invalidated: u64,
+    /// GET požadavky, na které server odpověděl 304 a data se vzala z uložené odpovědi
+    pub not_modified: u64,
+    pub by_entity: BTreeMap<String, EntityCounters>,
+}
+
+impl CacheCounters {
+    pub fn record_hit(&self, entity_type: &str) {
+        self.entity(entity_type, |counters| counters.hits += 1);
+    }
+
+    pub fn record_miss(&self, entity_type: &str) {
+        self.entity(entity_type, |counters| counters.misses += 1);
+    }
+
+    pub fn record_not_modified(&self) {
+        self.not_modified.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Započítá vyřazení záznamu z cache (přepsání novou hodnotou se nepočítá)
+    pub fn record_removal(&self, cause: RemovalCause) {
+        let counter = match cause {
+            RemovalCause::Expired => &self.expired,
+            RemovalCause::Size => &self.evicted,
+            RemovalCause::Explicit => &self.invalidated,
+            RemovalCause::Replaced => return,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn snapshot(&self, enabled: bool, entries: u64, max_entries: u64) -> CacheStats {
+        let by_entity: BTreeMap<String, EntityCounters> = self.by_entity.lock().unwrap()
+            .iter()
+            .map(|(entity, counters)| (entity.clone(), *counters))
+            .collect();
+        let hits: u64 = by_entity.values().map(|counters| counters.hits).sum();
+        let misses: u64 = by_entity.values().map(|counters| counters.misses).sum();
+        CacheStats {
+            enabled,
+            entries,
+            max_entries,
+            hits,
+            misses,
+            hit_ratio: if hits + misses > 0 { hits as f64 / (hits + misses) as f64 } else { 0.0 },
+            expired: self.expired.load(Ordering::Relaxed),
+            evicted: self.evicted.load(Ordering::Relaxed),
+            invalidated: self.invalidated.load(Ordering::Relaxed),
+            not_modified: self.not_modified.load(Ordering::Relaxed),
+            by_entity,
+        }
+    }
+
+    fn entity(&self, entity_type: &str, update: impl FnOnce(&mut EntityCounters)) {
+        let mut by_entity = self.by_entity.lock().unwrap();
+        update(by_entity.entry(entity_type.to_string()).or_default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_counters_snapshot() {
+        let counters = CacheCounters::default();
+        counters.record_miss("issue");
+        counters.record_hit("issue");
+        counters.record_hit("issue");
+        counters.record_hit("project");
+        counters.record_removal(RemovalCause::Expired);
+        counters.record_removal(RemovalCause::Replaced);
+        counters.record_removal(RemovalCause::Explicit);
+
+        let stats = counters.snapshot(true, 3, 1000);
+        assert_eq!(stats.hits, 3);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.hit_ratio, 0.75);
+        assert_eq!(stats.expired, 1);
+        assert_eq!(stats.invalidated, 1);
+        assert_eq!(stats.evicted, 0);
+        assert_eq!(stats.by_entity["issue"].hits, 2);
+        assert_eq!(stats.by_entity["issue"].misses, 1);
+    }
+}
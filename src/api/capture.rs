@@ -0,0 +1,129 @@
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use chrono::Utc;
+use regex::Regex;
+use serde_json::json;
+use tracing::warn;
+
+use crate::config::HttpConfig;
+
+/// Zápis HTTP požadavků a odpovědí do samostatného debug souboru (JSON Lines)
+///
+/// Zapíná se volbou `http.capture_path`. Citlivé hodnoty (API klíče, hesla)
+/// jsou před zápisem maskovány a těla jsou zkrácena na `capture_body_limit` znaků.
+#[derive(Debug)]
+pub struct HttpCapture {
+    path: PathBuf,
+    max_bytes: u64,
+    body_limit: usize,
+    lock: Mutex<()>,
+}
+
+impl HttpCapture {
+    /// Vytvoří capture podle konfigurace, nebo None pokud není zapnut
+    pub fn from_config(config: &HttpConfig) -> Option<Self> {
+        let path = config.capture_path.as_ref().filter(|p| !p.trim().is_empty())?;
+
+        Some(Self {
+            path: PathBuf::from(path),
+            max_bytes: config.capture_max_bytes,
+            body_limit: config.capture_body_limit,
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Zapíše jeden pár požadavek/odpověď
+    pub fn record(&self, method: &str, url: &str, status: Option<u16>, request_body: Option<&str>, response_body: Option<&str>, duration_ms: u128) {
+        let entry = json!({
+            "timestamp": Utc::now(),
+            "method": method,
+            "url": sanitize_url(url),
+            "status": status,
+            "duration_ms": duration_ms,
+            "request_body": request_body.map(|b| self.prepare_body(b)),
+            "response_body": response_body.map(|b| self.prepare_body(b)),
+        });
+
+        let _guard = self.lock.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Err(e) = self.rotate_if_needed().and_then(|_| self.append_line(&entry.to_string())) {
+            warn!("Nepodařilo se zapsat HTTP capture do {}: {}", self.path.display(), e);
+        }
+    }
+
+    fn prepare_body(&self, body: &str) -> String {
+        let sanitized = sanitize_body(body);
+        if sanitized.chars().count() > self.body_limit {
+            format!("{}... (zkráceno)", sanitized.chars().take(self.body_limit).collect::<String>())
+        } else {
+            sanitized
+        }
+    }
+
+    /// Při překročení maximální velikosti přesune soubor na `<path>.1`
+    fn rotate_if_needed(&self) -> std::io::Result<()> {
+        match fs::metadata(&self.path) {
+            Ok(metadata) if metadata.len() >= self.max_bytes => {
+                let mut rotated = self.path.clone().into_os_string();
+                rotated.push(".1");
+                fs::rename(&self.path, PathBuf::from(rotated))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn append_line(&self, line: &str) -> std::io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", line)
+    }
+}
+
+/// Zamaskuje API klíče v query parametrech URL
+pub fn sanitize_url(url: &str) -> String {
+    static KEY_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = KEY_REGEX.get_or_init(|| {
+        Regex::new(r"(?i)([?&](?:key|api_key|password)=)[^&]*").unwrap()
+    });
+    regex.replace_all(url, "${1}***").into_owned()
+}
+
+/// Zamaskuje citlivé hodnoty v JSON těle
+pub fn sanitize_body(body: &str) -> String {
+    static SECRET_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = SECRET_REGEX.get_or_init(|| {
+        Regex::new(r#"(?i)("(?:api_key|password|token)"\s*:\s*)"[^"]*""#).unwrap()
+    });
+    regex.replace_all(body, r#"${1}"***""#).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_url() {
+        assert_eq!(
+            sanitize_url("https://ep.example.com/issues.json?key=secret&limit=25"),
+            "https://ep.example.com/issues.json?key=***&limit=25"
+        );
+        assert_eq!(
+            sanitize_url("https://ep.example.com/issues.json?limit=25"),
+            "https://ep.example.com/issues.json?limit=25"
+        );
+    }
+
+    #[test]
+    fn test_sanitize_body() {
+        assert_eq!(
+            sanitize_body(r#"{"user":{"login":"jan","password":"tajne"}}"#),
+            r#"{"user":{"login":"jan","password":"***"}}"#
+        );
+        assert_eq!(sanitize_body(r#"{"subject":"Test"}"#), r#"{"subject":"Test"}"#);
+    }
+}
@@ -1,117 +1,434 @@
 use std::time::Duration;
 use reqwest::{Client, RequestBuilder};
 use serde_json::Value;
-use tracing::{debug, info};
-use governor::{Quota, RateLimiter, state::{InMemoryState, NotKeyed}, clock::DefaultClock};
-use moka::future::Cache;
+use tracing::{debug, info, warn};
+use moka::{future::Cache, Expiry};
 use std::sync::Arc;
-use std::num::NonZeroU32;
-
-use crate::config::AppConfig;
+use std::sync::OnceLock;
+use regex::Regex;
+
+use crate::config::{AppConfig, AuthType, CacheConfig, WarmUpTarget};
+use crate::utils::correlation::current_correlation_id;
+use super::cache_stats::{CacheCounters, CacheStats};
+use super::capture::HttpCapture;
+use super::connection::{ConnectionStats, ConnectionTracker};
 use super::error::{ApiError, ApiResult};
+use super::queue::{RequestPriority, RequestQueue};
+use super::permissions::{PermissionArea, PermissionProbe, PermissionReport, ProbeStatus};
+use super::session::SessionAuth;
+use super::throttle::{parse_retry_after, AdaptiveRateLimiter, MAX_THROTTLE_WAIT};
 use super::models::*;
 
+/// Velikost stránky při interním průchodu všemi záznamy
+const SCAN_PAGE_SIZE: u32 = 100;
+
+/// Nejvyšší limit, který API vrátí v jedné stránce (vyšší hodnoty tiše ořízne)
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Limit seznamu, který znamená načtení všech záznamů (nejvýše `fetch_all_max_records`)
+pub const FETCH_ALL: u32 = u32::MAX;
+
+/// Výchozí počet záznamů seznamu, pokud volající limit nezadá
+const DEFAULT_PAGE_SIZE: u32 = 25;
+
+/// Záznam cache API klienta s vlastní dobou platnosti podle typu entity
+#[derive(Debug, Clone)]
+struct CachedValue {
+    value: Value,
+    ttl: Duration,
+}
+
+/// Uložená odpověď GET požadavku pro podmíněné dotazy (ETag / Last-Modified)
+#[derive(Debug, Clone)]
+struct ConditionalEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+    value: Value,
+}
+
+/// Nepoužívané uložené odpovědi pro podmíněné dotazy se po této době zahodí
+const CONDITIONAL_CACHE_IDLE: Duration = Duration::from_secs(3600);
+
+/// Hlavička s korelačním ID volání tool
+const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Expirace záznamů podle TTL uloženého v záznamu (project_ttl, issue_ttl, ...)
+struct EntityTtlExpiry;
+
+impl Expiry<String, CachedValue> for EntityTtlExpiry {
+    fn expire_after_create(&self, _key: &String, value: &CachedValue, _created_at: std::time::Instant) -> Option<Duration> {
+        Some(value.ttl)
+    }
+
+    fn expire_after_update(&self, _key: &String, value: &CachedValue, _updated_at: std::time::Instant, _duration_until_expiry: Option<Duration>) -> Option<Duration> {
+        Some(value.ttl)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct EasyProjectClient {
     http_client: reqwest::Client,
     base_url: String,
     api_key: String,
-    cache: Option<Arc<Cache<String, Value>>>,
-    rate_limiter: Option<Arc<RateLimiter<NotKeyed, InMemoryState, DefaultClock>>>,
+    cache: Option<Arc<Cache<String, CachedValue>>>,
+    cache_config: CacheConfig,
+    cache_counters: Arc<CacheCounters>,
+    conditional_cache: Option<Arc<Cache<String, ConditionalEntry>>>,
+    rate_limiter: Arc<AdaptiveRateLimiter>,
+    request_queue: RequestQueue,
+    connections: Arc<ConnectionTracker>,
+    max_retries: u32,
+    retry_delay: Duration,
+    capture: Option<Arc<HttpCapture>>,
+    max_scan_records: u32,
+    fetch_all_max_records: u32,
+    cache_namespace: String,
+    session: Option<Arc<SessionAuth>>,
 }
 
 impl EasyProjectClient {
     pub async fn new(config: &AppConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(config.http.timeout_seconds))
-            .user_agent(&config.http.user_agent)
-            .build()?;
+        let is_session = matches!(config.easyproject.auth_type, AuthType::Session);
+        let http = &config.http;
+        let mut builder = Client::builder()
+            .timeout(Duration::from_secs(http.timeout_seconds))
+            .user_agent(&http.user_agent)
+            // Session autentifikace drží přihlášení v cookies
+            .cookie_store(is_session)
+            // Sestavy posílají desítky požadavků, spojení se proto drží v poolu
+            .pool_max_idle_per_host(http.pool_max_idle_per_host)
+            .pool_idle_timeout((http.pool_idle_timeout_seconds > 0).then(|| Duration::from_secs(http.pool_idle_timeout_seconds)))
+            .tcp_keepalive((http.tcp_keepalive_seconds > 0).then(|| Duration::from_secs(http.tcp_keepalive_seconds)));
+        if http.connect_timeout_seconds > 0 {
+            builder = builder.connect_timeout(Duration::from_secs(http.connect_timeout_seconds));
+        }
+        if http.http2_prior_knowledge {
+            builder = builder.http2_prior_knowledge();
+        }
+        if http.http2_keep_alive_seconds > 0 {
+            builder = builder
+                .http2_keep_alive_interval(Duration::from_secs(http.http2_keep_alive_seconds))
+                .http2_keep_alive_while_idle(true);
+        }
+        let client = builder.build()?;
+
+        let conditional_cache = (config.cache.enabled && config.cache.conditional_requests).then(|| {
+            Arc::new(Cache::builder()
+                .max_capacity(config.cache.max_entries)
+                .time_to_idle(CONDITIONAL_CACHE_IDLE)
+                .build())
+        });
 
+        let cache_counters = Arc::new(CacheCounters::default());
         let cache = if config.cache.enabled {
+            let counters = cache_counters.clone();
             Some(Arc::new(Cache::builder()
                 .max_capacity(config.cache.max_entries)
-                .time_to_live(Duration::from_secs(config.cache.ttl_seconds))
+                .eviction_listener(move |_key, _value, cause| counters.record_removal(cause))
+                // TTL se řídí typem entity (issue_ttl, user_ttl, ...), ne jednou globální hodnotou
+                .expire_after(EntityTtlExpiry)
+                // Potřebné pro invalidaci podle vzoru klíče (invalidate_cache)
+                .support_invalidation_closures()
                 .build()))
         } else {
             None
         };
 
-        let rate_limiter = if config.rate_limiting.enabled {
-            Some(Arc::new(RateLimiter::direct(
-                Quota::per_minute(NonZeroU32::new(config.rate_limiting.requests_per_minute).unwrap())
-                    .allow_burst(NonZeroU32::new(config.rate_limiting.burst_size).unwrap())
-            )))
+        let (api_key, session) = if is_session {
+            let session = SessionAuth::from_config(config)
+                .ok_or("Chybí uživatelské jméno nebo heslo pro session autentifikaci")?;
+            (String::new(), Some(Arc::new(session)))
         } else {
-            None
+            let api_key = config.easyproject.api_key.clone()
+                .ok_or("Chybí API klíč pro EasyProject")?;
+            (api_key, None)
         };
 
-        let api_key = config.easyproject.api_key.clone()
-            .ok_or("Chybí API klíč pro EasyProject")?;
+        let identity = match session {
+            Some(ref session) => format!("session:{}", session.username()),
+            None => api_key.clone(),
+        };
+        let cache_namespace = cache_namespace_for(&config.easyproject.base_url, &identity);
 
         Ok(Self {
             http_client: client,
             base_url: config.easyproject.base_url.clone(),
             api_key,
+            cache_namespace,
             cache,
-            rate_limiter,
+            cache_config: config.cache.clone(),
+            cache_counters,
+            conditional_cache,
+            rate_limiter: Arc::new(AdaptiveRateLimiter::from_config(&config.rate_limiting)),
+            request_queue: RequestQueue::new(config.rate_limiting.max_queue_depth),
+            connections: Arc::new(ConnectionTracker::default()),
+            max_retries: config.http.max_retries,
+            retry_delay: config.retry_delay(),
+            capture: HttpCapture::from_config(&config.http).map(Arc::new),
+            max_scan_records: config.tools.reports.max_scan_records,
+            fetch_all_max_records: config.tools.fetch_all_max_records,
+            session,
         })
     }
 
-    /// Přidá autentifikační hlavičky k požadavku
+    /// Jmenný prostor cache pro tuto instanci EasyProject a přihlašovací identitu
+    pub fn cache_namespace(&self) -> &str {
+        &self.cache_namespace
+    }
+
+    /// Zjistí, zda rate limiter má volnou kapacitu pro nepovinný požadavek
+    ///
+    /// Kontrola spotřebuje jedno povolení, takže nepovinné požadavky (např. přednačítání)
+    /// nechávají rezervu pro požadavky uživatele.
+    pub fn has_spare_capacity(&self) -> bool {
+        self.rate_limiter.check()
+    }
+
+    /// Statistika znovupoužití HTTP spojení z poolu
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.connections.stats()
+    }
+
+    /// Aktuální kvóta rate limiteru (po HTTP 429 může být nižší než v konfiguraci)
+    pub fn current_requests_per_minute(&self) -> Option<u32> {
+        self.rate_limiter.requests_per_minute()
+    }
+
+    /// Počet požadavků čekajících ve frontě na rate limiter
+    pub fn queued_requests(&self) -> usize {
+        self.request_queue.waiting()
+    }
+
+    /// Započítá odpověď do statistiky spojení
+    fn record_connection(&self, response: &reqwest::Response) {
+        let info = response.extensions().get::<hyper::client::connect::HttpInfo>();
+        self.connections.record(info.map(|info| info.local_addr()), info.map(|info| info.remote_addr()));
+    }
+
+    /// Přidá autentifikační hlavičky k požadavku (session autentifikace používá cookies)
     fn add_auth(&self, request_builder: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        request_builder.header("X-Redmine-API-Key", &self.api_key)
+        if self.session.is_some() {
+            request_builder
+        } else {
+            request_builder.header("X-Redmine-API-Key", &self.api_key)
+        }
     }
 
     /// Provede HTTP požadavek s retry logikou
+    ///
+    /// Opakují se pouze GET požadavky, které selžou na dočasné chybě upstreamu
+    /// (např. HTML stránka údržby místo JSON odpovědi). Při session autentifikaci
+    /// se po vypršení session (HTTP 401) jednou znovu přihlásí a požadavek zopakuje.
+    /// Odpověď HTTP 429 se opakuje u všech metod, server takový požadavek nezpracoval;
+    /// čekání podle Retry-After zajistí rate limiter před dalším pokusem.
     async fn execute_request(&self, request: RequestBuilder) -> ApiResult<Value> {
+        self.execute_request_as(request, RequestPriority::Interactive).await
+    }
+
+    /// Provede HTTP požadavek s danou prioritou ve frontě na rate limiter
+    async fn execute_request_as(&self, request: RequestBuilder, priority: RequestPriority) -> ApiResult<Value> {
+        let is_get = request.try_clone()
+            .and_then(|r| r.build().ok())
+            .is_some_and(|r| *r.method() == reqwest::Method::GET);
+
+        // Session a generace přihlášení, se kterou se požadavek odesílá (jen jedno obnovení)
+        let mut relogin = match self.session {
+            Some(ref session) => Some((session, session.ensure(&self.http_client).await?)),
+            None => None,
+        };
+
+        let mut attempt = 0;
+        loop {
+            let current = match request.try_clone() {
+                Some(current) => current,
+                None => return self.execute_request_once(request, priority).await,
+            };
+
+            match self.execute_request_once(current, priority).await {
+                Err(ApiError::Authentication { status: Some(401), .. }) if relogin.is_some() => {
+                    if let Some((session, generation)) = relogin.take() {
+                        session.relogin(&self.http_client, generation).await?;
+                    }
+                }
+                Err(ApiError::RateLimit { retry_after }) if attempt < self.max_retries
+                    && retry_after.is_none_or(|delay| delay <= MAX_THROTTLE_WAIT) => {
+                    attempt += 1;
+                    warn!("Server omezuje počet požadavků (HTTP 429), opakuji pokus {}/{}", attempt, self.max_retries);
+                }
+                Err(e) if is_get && e.is_transient() && attempt < self.max_retries => {
+                    attempt += 1;
+                    let delay = self.retry_delay * attempt;
+                    warn!("Dočasná chyba API ({}), opakuji pokus {}/{} za {:?}", e, attempt, self.max_retries, delay);
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Počká ve frontě na rate limiter, při dlouhém omezení od serveru vrátí chybu
+    ///
+    /// Pořadí ve frontě se uvolní hned po získání povolení, samotné HTTP požadavky běží souběžně.
+    async fn wait_for_rate_limiter(&self, priority: RequestPriority) -> ApiResult<()> {
+        let _ticket = self.request_queue.acquire(priority).await?;
+        self.rate_limiter.until_ready().await
+            .map_err(|remaining| ApiError::RateLimit { retry_after: Some(remaining) })
+    }
+
+    /// Předá rate limiteru odpověď HTTP 429 a vrátí dobu čekání podle Retry-After
+    fn throttled(&self, response: &reqwest::Response) -> Duration {
+        let retry_after = response.headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| parse_retry_after(value, chrono::Utc::now()));
+        self.rate_limiter.on_rate_limited(retry_after)
+    }
+
+    /// Provede jeden HTTP požadavek bez opakování
+    async fn execute_request_once(&self, request: RequestBuilder, priority: RequestPriority) -> ApiResult<Value> {
         // Rate limiting
-        if let Some(ref limiter) = self.rate_limiter {
-            limiter.until_ready().await;
+        self.wait_for_rate_limiter(priority).await?;
+
+        let mut request = request.build().map_err(ApiError::Http)?;
+
+        // Korelační ID volání tool umožní dohledat požadavek i v logu EasyProject
+        if let Some(id) = current_correlation_id().and_then(|id| reqwest::header::HeaderValue::from_str(&id).ok()) {
+            request.headers_mut().insert(REQUEST_ID_HEADER, id);
+        }
+
+        // Podmíněný dotaz: server vrátí 304, pokud se data od uložené odpovědi nezměnila
+        let conditional_key = (self.conditional_cache.is_some() && *request.method() == reqwest::Method::GET)
+            .then(|| request.url().to_string());
+        let stored = match (&self.conditional_cache, &conditional_key) {
+            (Some(cache), Some(key)) => cache.get(key).await,
+            _ => None,
+        };
+        if let Some(ref stored) = stored {
+            let headers = request.headers_mut();
+            if let Some(etag) = stored.etag.as_deref().and_then(|v| reqwest::header::HeaderValue::from_str(v).ok()) {
+                headers.insert(reqwest::header::IF_NONE_MATCH, etag);
+            }
+            if let Some(modified) = stored.last_modified.as_deref().and_then(|v| reqwest::header::HeaderValue::from_str(v).ok()) {
+                headers.insert(reqwest::header::IF_MODIFIED_SINCE, modified);
+            }
         }
 
-        let response = request
-            .send()
-            .await
-            .map_err(ApiError::Http)?;
+        let method = request.method().to_string();
+        let url = request.url().to_string();
+        let path = request.url().path().to_string();
+        let request_body = if self.capture.is_some() {
+            request.body()
+                .and_then(|body| body.as_bytes())
+                .map(|bytes| String::from_utf8_lossy(bytes).into_owned())
+        } else {
+            None
+        };
+        let started = std::time::Instant::now();
+
+        let response = match self.http_client.execute(request).await {
+            Ok(response) => response,
+            Err(e) => {
+                if let Some(ref capture) = self.capture {
+                    capture.record(&method, &url, None, request_body.as_deref(), Some(&e.to_string()), started.elapsed().as_millis());
+                }
+                return Err(ApiError::Http(e));
+            }
+        };
 
+        self.record_connection(&response);
         let status = response.status();
         
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = self.throttled(&response);
+            if let Some(ref capture) = self.capture {
+                capture.record(&method, &url, Some(status.as_u16()), request_body.as_deref(), None, started.elapsed().as_millis());
+            }
+            return Err(ApiError::RateLimit { retry_after: Some(retry_after) });
+        }
+        
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            if let Some(stored) = stored {
+                if let Some(ref capture) = self.capture {
+                    capture.record(&method, &url, Some(status.as_u16()), request_body.as_deref(), None, started.elapsed().as_millis());
+                }
+                debug!("Odpověď se nezměnila (HTTP 304), použita uložená data pro {}", url);
+                self.cache_counters.record_not_modified();
+                return Ok(stored.value);
+            }
+        }
+        
         if !status.is_success() {
             let error_text = response.text().await.unwrap_or_else(|_| "Neznámá chyba".to_string());
-            return Err(ApiError::Api {
-                status: status.as_u16(),
-                message: format!("HTTP error {}: {}", status, error_text),
-            });
+            if let Some(ref capture) = self.capture {
+                capture.record(&method, &url, Some(status.as_u16()), request_body.as_deref(), Some(&error_text), started.elapsed().as_millis());
+            }
+            return Err(ApiError::from_response(status.as_u16(), &path, &error_text));
         }
 
+        let content_type = response.headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_lowercase());
+        let header_text = |name: reqwest::header::HeaderName| response.headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let etag = header_text(reqwest::header::ETAG);
+        let last_modified = header_text(reqwest::header::LAST_MODIFIED);
+
         // Zkontrolujeme, zda odpověď obsahuje data
         let response_text = response.text().await.map_err(ApiError::Http)?;
         
+        if let Some(ref capture) = self.capture {
+            capture.record(&method, &url, Some(status.as_u16()), request_body.as_deref(), Some(&response_text), started.elapsed().as_millis());
+        }
+        
         if response_text.trim().is_empty() {
             // Prázdná odpověď - vrátíme prázdný objekt
             debug!("API vrátilo prázdnou odpověď");
             return Ok(serde_json::json!({}));
         }
 
+        // HTML místo JSON typicky znamená stránku údržby nebo chybovou stránku proxy
+        if is_html_response(content_type.as_deref(), &response_text) {
+            debug!("API vrátilo HTML místo JSON (content-type: {:?}): {}",
+                content_type, truncate_for_log(&response_text));
+            return Err(ApiError::Unavailable(
+                "server vrátil HTML stránku místo JSON odpovědi, zkuste to prosím později".to_string()
+            ));
+        }
+
         // Pokusíme se parsovat JSON
-        serde_json::from_str(&response_text).map_err(|e| {
-            debug!("Chyba parsování JSON: {}. Response text: {}", e, response_text);
+        let value: Value = serde_json::from_str(&response_text).map_err(|e| {
+            debug!("Chyba parsování JSON: {}. Response text: {}", e, truncate_for_log(&response_text));
             ApiError::Api {
                 status: 500,
-                message: format!("Chyba parsování JSON: {}. Response: {}", e, response_text),
+                message: format!("Chyba parsování JSON: {}", e),
             }
-        })
+        })?;
+
+        if let (Some(cache), Some(key)) = (&self.conditional_cache, conditional_key) {
+            if etag.is_some() || last_modified.is_some() {
+                cache.insert(key, ConditionalEntry { etag, last_modified, value: value.clone() }).await;
+            }
+        }
+        Ok(value)
     }
 
     /// Získá data z cache nebo provede API volání
-    async fn get_cached_or_fetch<T>(&self, cache_key: &str, _entity_type: &str, fetch_fn: impl std::future::Future<Output = ApiResult<T>>) -> ApiResult<T>
+    ///
+    /// Doba platnosti záznamu odpovídá TTL typu entity z konfigurace cache.
+    async fn get_cached_or_fetch<T>(&self, cache_key: &str, entity_type: &str, fetch_fn: impl std::future::Future<Output = ApiResult<T>>) -> ApiResult<T>
     where
         T: serde::Serialize + serde::de::DeserializeOwned,
     {
+        let cache_key = &format!("{}::{}", self.cache_namespace, cache_key);
+
         if let Some(cache) = &self.cache {
-            if let Some(cached_value) = cache.get(cache_key).await {
+            if let Some(cached) = cache.get(cache_key).await {
                 debug!("Cache hit pro klíč: {}", cache_key);
-                return serde_json::from_value(cached_value)
+                self.cache_counters.record_hit(entity_type);
+                return serde_json::from_value(cached.value)
                     .map_err(|e| ApiError::Api {
                         status: 500,
                         message: format!("Chyba deserializace z cache: {}", e),
@@ -120,6 +437,9 @@ impl EasyProjectClient {
         }
 
         debug!("Cache miss pro klíč: {}, volám API", cache_key);
+        if self.cache.is_some() {
+            self.cache_counters.record_miss(entity_type);
+        }
         let result = fetch_fn.await?;
 
         // Uložení do cache
@@ -130,20 +450,182 @@ impl EasyProjectClient {
                     message: format!("Chyba serializace do cache: {}", e),
                 })?;
             
-            cache.insert(cache_key.to_string(), value).await;
-            debug!("Uloženo do cache: {}", cache_key);
+            let ttl = self.cache_config.ttl_for_entity(entity_type);
+            cache.insert(cache_key.to_string(), CachedValue { value, ttl }).await;
+            debug!("Uloženo do cache: {} (TTL {} s)", cache_key, ttl.as_secs());
         }
 
         Ok(result)
     }
 
-    /// Invaliduje cache pro daný pattern
-    pub async fn invalidate_cache(&self, pattern: &str) {
+    /// Upraví požadovaný limit seznamu na povolený rozsah
+    ///
+    /// Limity nad velikostí jedné stránky se načítají po více stránkách, celkem však
+    /// nejvýše `max_scan_records` záznamů.
+    pub fn clamp_limit(&self, requested: Option<u32>) -> u32 {
+        requested
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, self.max_scan_records.max(MAX_PAGE_SIZE))
+    }
+
+    /// Načte seznam s daným limitem - podle potřeby po více stránkách
+    ///
+    /// `build_request` dostane limit a offset jedné stránky. Ve výsledku jsou stránky
+    /// spojené a `limit` odpovídá skutečně použitému (případně oříznutému) limitu.
+    /// Limit [`FETCH_ALL`] načte všechny záznamy přes [`Self::paginate_all`].
+    async fn fetch_pages<R, F>(&self, limit: Option<u32>, offset: Option<u32>, build_request: F) -> ApiResult<R>
+    where
+        R: PagedResponse + serde::de::DeserializeOwned,
+        F: Fn(u32, u32) -> RequestBuilder,
+    {
+        if limit == Some(FETCH_ALL) {
+            return self.paginate_all(offset, build_request).await;
+        }
+
+        let requested = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+        let effective = self.clamp_limit(limit);
+        if effective != requested {
+            warn!("Požadovaný limit {} upraven na {}", requested, effective);
+        }
+
+        self.fetch_window(effective, offset.unwrap_or(0), build_request).await
+    }
+
+    /// Načte všechny záznamy seznamu od `offset` až do total_count
+    ///
+    /// Průchod končí nejpozději po `fetch_all_max_records` záznamech; zbytek je
+    /// poznat z total_count ve výsledku.
+    pub async fn paginate_all<R, F>(&self, offset: Option<u32>, build_request: F) -> ApiResult<R>
+    where
+        R: PagedResponse + serde::de::DeserializeOwned,
+        F: Fn(u32, u32) -> RequestBuilder,
+    {
+        let offset = offset.unwrap_or(0);
+        let result: R = self.fetch_window(self.fetch_all_max_records.max(1), offset, build_request).await?;
+
+        let fetched = result.item_count() as i64;
+        if let Some(total) = result.total().filter(|total| (offset as i64 + fetched) < *total as i64) {
+            warn!("Načítání všech záznamů ukončeno po {} z {} (fetch_all_max_records)", fetched, total);
+        }
+        Ok(result)
+    }
+
+    /// Načte nejvýše `effective` záznamů od `offset` po stránkách
+    async fn fetch_window<R, F>(&self, effective: u32, offset: u32, build_request: F) -> ApiResult<R>
+    where
+        R: PagedResponse + serde::de::DeserializeOwned,
+        F: Fn(u32, u32) -> RequestBuilder,
+    {
+        let mut fetched = 0u32;
+        let mut result: Option<R> = None;
+
+        while fetched < effective {
+            let page_limit = (effective - fetched).min(MAX_PAGE_SIZE);
+            // První stránka odpovídá na dotaz uživatele, další stránky ustoupí interaktivním požadavkům
+            let priority = if fetched == 0 { RequestPriority::Interactive } else { RequestPriority::Background };
+            let response = self.execute_request_as(build_request(page_limit, offset + fetched), priority).await?;
+            let page: R = self.parse_response(response)?;
+            let returned = page.item_count() as u32;
+            let total = page.total();
+
+            fetched += returned;
+            match result.as_mut() {
+                Some(result) => result.extend_page(page),
+                None => result = Some(page),
+            }
+
+            let exhausted = total.is_some_and(|total| (offset + fetched) as i64 >= total as i64);
+            if returned < page_limit || exhausted {
+                break;
+            }
+            debug!("Načteno {} z {} požadovaných záznamů, pokračuji další stránkou", fetched, effective);
+        }
+
+        let mut result = result.ok_or_else(|| ApiError::Api {
+            status: 500,
+            message: "API nevrátilo žádnou stránku".to_string(),
+        })?;
+        result.set_window(offset, effective);
+        Ok(result)
+    }
+
+    /// Přednačte do cache často používaná data, aby první volání tools byla rychlá
+    ///
+    /// Načítá stejné dotazy jako tools bez parametrů. Chyby se jen zalogují,
+    /// warm-up nesmí ovlivnit běh serveru.
+    pub async fn warm_up_cache(&self, targets: &[WarmUpTarget]) {
+        if self.cache.is_none() {
+            return;
+        }
+
+        let started = std::time::Instant::now();
+        let mut loaded = 0;
+        for target in targets {
+            let result = match target {
+                WarmUpTarget::Projects => self.list_projects(None, None, None, None, None, None).await.map(|_| ()),
+                WarmUpTarget::Users => match self.get_current_user().await {
+                    Ok(_) => self.list_users(UserListFilter::default()).await.map(|_| ()),
+                    Err(e) => Err(e),
+                },
+                WarmUpTarget::Enumerations => self.get_issue_enumerations(None).await.map(|_| ()),
+                WarmUpTarget::Activities => self.list_time_entry_activities().await.map(|_| ()),
+            };
+            match result {
+                Ok(()) => loaded += 1,
+                Err(e) => warn!("Přednačtení cache ({:?}) selhalo: {}", target, e),
+            }
+        }
+
+        info!("Cache přednačtena ({} z {} částí) za {} ms", loaded, targets.len(), started.elapsed().as_millis());
+    }
+
+    /// Statistika cache API klienta (zásahy, výpadky a vyřazené záznamy)
+    pub async fn cache_stats(&self) -> CacheStats {
+        let entries = match &self.cache {
+            Some(cache) => {
+                // Počet záznamů je přesný až po zpracování čekajících údržbových úloh
+                cache.run_pending_tasks().await;
+                cache.entry_count()
+            }
+            None => 0,
+        };
+        self.cache_counters.snapshot(self.cache.is_some(), entries, self.cache_config.max_entries)
+    }
+
+    /// Zahodí celou cache API klienta včetně uložených odpovědí pro podmíněné dotazy
+    pub async fn clear_cache(&self) {
         if let Some(cache) = &self.cache {
-            // Pro jednoduchost invalidujeme celou cache
-            // V produkční verzi by bylo lepší implementovat pattern matching
             cache.invalidate_all();
-            info!("Cache invalidována pro pattern: {}", pattern);
+            cache.run_pending_tasks().await;
+        }
+        if let Some(cache) = &self.conditional_cache {
+            cache.invalidate_all();
+        }
+        info!("Cache API klienta vymazána");
+    }
+
+    /// Invaliduje záznamy cache odpovídající vzoru klíče
+    ///
+    /// Vzor bez `*` označuje entitu a odpovídá klíči `vzor` i klíčům `vzor_...`
+    /// (např. `project_42` zasáhne `project_42_` s různými include). Vzor s `*`
+    /// je glob, kde `*` odpovídá libovolnému úseku (např. `projects_*` pro seznamy).
+    pub async fn invalidate_cache(&self, pattern: &str) {
+        if let Some(cache) = &self.cache {
+            let namespace = format!("{}::", self.cache_namespace);
+            let owned_pattern = pattern.to_string();
+            let result = cache.invalidate_entries_if(move |key, _| {
+                key.strip_prefix(&namespace)
+                    .is_some_and(|key| cache_key_matches(key, &owned_pattern))
+            });
+
+            match result {
+                Ok(_) => debug!("Cache invalidována pro vzor: {}", pattern),
+                Err(e) => {
+                    // Bez podpory predikátů raději zahodíme vše než vracet zastaralá data
+                    warn!("Invalidace cache podle vzoru {} selhala ({}), invaliduji celou cache", pattern, e);
+                    cache.invalidate_all();
+                }
+            }
         }
     }
 
@@ -163,12 +645,6 @@ impl EasyProjectClient {
             let url = format!("{}/projects.json", self.base_url);
             let mut query_params = Vec::new();
 
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
             if let Some(query) = easy_query_q {
                 query_params.push(("easy_query_q", query));
                 // Pokud je easy_query_q zadáno, automaticky aktivujeme set_filter
@@ -180,20 +656,83 @@ impl EasyProjectClient {
                 query_params.push(("sort", sort));
             }
 
-            let request = self.add_auth(self.http_client.get(&url));
-            let request = if !query_params.is_empty() {
-                request.query(&query_params)
-            } else {
-                request
-            };
-
-            let response = self.execute_request(request).await?;
-            self.parse_response(response)
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
         }).await
     }
 
+    /// Dohledá projekty, jejichž název nebo identifikátor obsahuje text bez ohledu na diakritiku
+    ///
+    /// Fulltext EasyProject diakritiku rozlišuje ("Skoleni" nenajde "Školení"), proto se použije,
+    /// když `easy_query_q` nic nevrátí. Prohledává první stránku (100) projektů.
+    pub async fn match_projects_by_text(&self, text: &str, include_archived: Option<bool>) -> ApiResult<ProjectsResponse> {
+        let mut response = self.list_projects(Some(100), None, include_archived, None, None, None).await?;
+        response.projects.retain(|project| {
+            crate::utils::text_utils::text_contains(&project.name, text)
+                || project.identifier.as_deref().is_some_and(|identifier| crate::utils::text_utils::text_contains(identifier, text))
+        });
+        response.total_count = Some(response.projects.len() as i32);
+        response.offset = None;
+        response.limit = None;
+        Ok(response)
+    }
+
+    /// Určí ID projektu z čísla, identifikátoru nebo názvu projektu
+    ///
+    /// Pořadí: číselné ID, identifikátor (/projects/{identifier}.json), pak fulltext
+    /// v názvech projektů - přesná shoda názvu má přednost, jinak musí být výsledek
+    /// jediný. Více kandidátů vrací InvalidParams se seznamem ID.
+    pub async fn resolve_project(&self, reference: &str) -> ApiResult<i32> {
+        let reference = reference.trim();
+        if reference.is_empty() {
+            return Err(ApiError::InvalidParams("Prázdný identifikátor projektu".to_string()));
+        }
+        if let Ok(id) = reference.parse::<i32>() {
+            return Ok(id);
+        }
+
+        let is_identifier = reference.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+        if is_identifier {
+            let cache_key = format!("project_identifier_{}", reference);
+            let by_identifier: ApiResult<ProjectResponse> = self.get_cached_or_fetch(&cache_key, "project", async {
+                let url = format!("{}/projects/{}.json", self.base_url, reference);
+                let request = self.add_auth(self.http_client.get(&url));
+                let response = self.execute_request(request).await?;
+                self.parse_response(response)
+            }).await;
+            match by_identifier {
+                Ok(response) => return Ok(response.project.id),
+                Err(e) if e.is_not_found() => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        let projects = self.list_projects(Some(25), None, None, Some(reference.to_string()), None, None).await?.projects;
+        let exact: Vec<&Project> = projects.iter()
+            .filter(|project| crate::utils::text_utils::text_equals(&project.name, reference))
+            .collect();
+
+        let candidates: Vec<&Project> = if exact.is_empty() { projects.iter().collect() } else { exact };
+        match candidates.as_slice() {
+            [project] => Ok(project.id),
+            [] => Err(ApiError::NotFound(format!("Projekt '{}' nebyl nalezen", reference))),
+            many => Err(ApiError::InvalidParams(format!(
+                "'{}' odpovídá více projektům: {}. Upřesněte název nebo použijte ID či identifikátor",
+                reference,
+                many.iter()
+                    .map(|project| format!("{} (ID {}{})", project.name, project.id, project.identifier.as_deref().map(|identifier| format!(", {}", identifier)).unwrap_or_default()))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ))),
+        }
+    }
+
     pub async fn get_project(&self, id: i32, include: Option<Vec<String>>) -> ApiResult<ProjectResponse> {
-        let cache_key = format!("project_{}", id);
+        let cache_key = format!("project_{}_{}", id, include_cache_key(&include));
 
         self.get_cached_or_fetch(&cache_key, "project", async {
             let url = format!("{}/projects/{}.json", self.base_url, id);
@@ -208,12 +747,75 @@ impl EasyProjectClient {
         }).await
     }
 
+    pub async fn list_project_memberships(&self, project_id: i32, limit: Option<u32>, offset: Option<u32>) -> ApiResult<MembershipsResponse> {
+        let cache_key = format!("memberships_{}_{}_{}", project_id, limit.unwrap_or(25), offset.unwrap_or(0));
+
+        self.get_cached_or_fetch(&cache_key, "project", async {
+            let url = format!("{}/projects/{}/memberships.json", self.base_url, project_id);
+            let mut query_params = Vec::new();
+
+            if let Some(limit) = limit {
+                query_params.push(("limit", limit.to_string()));
+            }
+            if let Some(offset) = offset {
+                query_params.push(("offset", offset.to_string()));
+            }
+
+            let request = self.add_auth(self.http_client.get(&url))
+                .query(&query_params);
+
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Získá všechna členství projektu (prochází všechny stránky)
+    pub async fn list_all_project_memberships(&self, project_id: i32) -> ApiResult<Vec<Membership>> {
+        let mut memberships = Vec::new();
+        let mut offset: u32 = 0;
+
+        loop {
+            let response = self.list_project_memberships(project_id, Some(SCAN_PAGE_SIZE), Some(offset)).await?;
+            if response.memberships.is_empty() {
+                break;
+            }
+
+            let total = response.total_count.unwrap_or(0).max(0) as usize;
+            memberships.extend(response.memberships);
+            offset += SCAN_PAGE_SIZE;
+
+            if offset as usize >= total || memberships.len() >= self.max_scan_records as usize {
+                break;
+            }
+        }
+
+        Ok(memberships)
+    }
+
     pub async fn create_project(&self, project_data: CreateProjectRequest) -> ApiResult<ProjectResponse> {
         let url = format!("{}/projects.json", self.base_url);
         let request = self.add_auth(self.http_client.post(&url))
             .json(&project_data);
 
         let response = self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("projects_*").await;
+
+        self.parse_response(response)
+    }
+
+    /// Založí kategorii úkolů v projektu (/projects/{id}/issue_categories.json)
+    pub async fn create_issue_category(&self, project_id: i32, name: &str) -> ApiResult<IssueCategoryResponse> {
+        let url = format!("{}/projects/{}/issue_categories.json", self.base_url, project_id);
+        let request = self.add_auth(self.http_client.post(&url))
+            .json(&serde_json::json!({ "issue_category": { "name": name } }));
+
+        let response = self.execute_request(request).await?;
+
+        // Kategorie se vrací v detailu projektu (include=issue_categories)
+        self.invalidate_cache(&format!("project_{}", project_id)).await;
+
         self.parse_response(response)
     }
 
@@ -223,6 +825,11 @@ impl EasyProjectClient {
             .json(&project_data);
 
         let response = self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("projects_*").await;
+        self.invalidate_cache(&format!("project_{}", id)).await;
+
         self.parse_response(response)
     }
 
@@ -233,16 +840,22 @@ impl EasyProjectClient {
         self.execute_request(request).await?;
 
         // Invalidace cache
-        self.invalidate_cache("projects").await;
+        self.invalidate_cache("projects_*").await;
         self.invalidate_cache(&format!("project_{}", id)).await;
+        self.invalidate_cache(&format!("memberships_{}", id)).await;
+        self.invalidate_cache(&format!("project_versions_{}", id)).await;
 
         Ok(())
     }
 
     // === ISSUE API METHODS ===
 
-    pub async fn list_issues(&self, project_id: Option<i32>, limit: Option<u32>, offset: Option<u32>, include: Option<Vec<String>>, easy_query_q: Option<String>, set_filter: Option<bool>, sort: Option<String>, assigned_to_id: Option<i32>, status_id: Option<i32>, tracker_id: Option<i32>, priority_id: Option<i32>) -> ApiResult<IssuesResponse> {
-        let cache_key = format!("issues_{}_{}_{}_{}_{}_{}_{}_{}_{}_{}_{}",
+    pub async fn list_issues(&self, filter: IssueListFilter) -> ApiResult<IssuesResponse> {
+        let IssueListFilter {
+            project_id, limit, offset, include, easy_query_q, set_filter, sort,
+            assigned_to_id, status_id, tracker_id, priority_id, include_subprojects, created_on, updated_on,
+        } = filter;
+        let cache_key = format!("issues_{}_{}_{}_{}_{}_{}_{}_{}_{}_{}_{}_{:?}_{:?}_{:?}",
             project_id.map(|id| id.to_string()).unwrap_or_else(|| "all".to_string()),
             limit.unwrap_or(25),
             offset.unwrap_or(0),
@@ -253,7 +866,10 @@ impl EasyProjectClient {
             assigned_to_id.unwrap_or(0),
             status_id.unwrap_or(0),
             tracker_id.unwrap_or(0),
-            priority_id.unwrap_or(0)
+            priority_id.unwrap_or(0),
+            include_subprojects,
+            created_on,
+            updated_on
         );
 
         self.get_cached_or_fetch(&cache_key, "issue", async {
@@ -263,12 +879,6 @@ impl EasyProjectClient {
             if let Some(project_id) = project_id {
                 query_params.push(("project_id", project_id.to_string()));
             }
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
             if let Some(include) = include {
                 query_params.push(("include", include.join(",")));
             }
@@ -294,17 +904,27 @@ impl EasyProjectClient {
             if let Some(priority_id) = priority_id {
                 query_params.push(("priority_id", priority_id.to_string()));
             }
-
-            let request = self.add_auth(self.http_client.get(&url))
-                .query(&query_params);
-
-            let response = self.execute_request(request).await?;
-            self.parse_response(response)
+            // Filtry vyhodnocené API (rozsahy dat, podprojekty) vyžadují set_filter
+            let mut filters = Vec::new();
+            filters.extend(created_on.map(|filter| ("created_on", filter)));
+            filters.extend(updated_on.map(|filter| ("updated_on", filter)));
+            filters.extend(subproject_filter(include_subprojects));
+            if !filters.is_empty() && !query_params.iter().any(|(key, _)| *key == "set_filter") {
+                query_params.push(("set_filter", "1".to_string()));
+            }
+            query_params.extend(filters);
+
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
         }).await
     }
 
     pub async fn get_issue(&self, id: i32, include: Option<Vec<String>>) -> ApiResult<IssueResponse> {
-        let cache_key = format!("issue_{}", id);
+        let cache_key = format!("issue_{}_{}", id, include_cache_key(&include));
 
         self.get_cached_or_fetch(&cache_key, "issue", async {
             let url = format!("{}/issues/{}.json", self.base_url, id);
@@ -325,6 +945,10 @@ impl EasyProjectClient {
             .json(&issue_data);
 
         let response = self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("issues_*").await;
+
         self.parse_response(response)
     }
 
@@ -335,6 +959,10 @@ impl EasyProjectClient {
 
         let response = self.execute_request(request).await?;
         
+        // Invalidace cache
+        self.invalidate_cache("issues_*").await;
+        self.invalidate_cache(&format!("issue_{}", id)).await;
+        
         // Pokud je odpověď prázdná, nejdříve získáme aktualizovaný úkol
         if response.as_object().map_or(false, |obj| obj.is_empty()) {
             debug!("Prázdná odpověď z update_issue, získávám aktualizovaný úkol");
@@ -346,25 +974,65 @@ impl EasyProjectClient {
 
     // === USER API METHODS ===
 
-    pub async fn list_users(&self, limit: Option<u32>, offset: Option<u32>, easy_query_q: Option<String>, set_filter: Option<bool>, sort: Option<String>, status: Option<String>) -> ApiResult<UsersResponse> {
-        let cache_key = format!("users_{}_{}_{}_{}_{}",
+    pub async fn delete_issue(&self, id: i32) -> ApiResult<()> {
+        let url = format!("{}/issues/{}.json", self.base_url, id);
+        let request = self.add_auth(self.http_client.delete(&url));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache (smazáním úkolu zmizí i jeho podúkoly a časové záznamy)
+        self.invalidate_cache("issues_*").await;
+        self.invalidate_cache(&format!("issue_{}", id)).await;
+        self.invalidate_cache("time_entries_*").await;
+
+        Ok(())
+    }
+
+    /// Přidá uživatele mezi sledující úkolu
+    pub async fn add_watcher(&self, issue_id: i32, user_id: i32) -> ApiResult<()> {
+        let url = format!("{}/issues/{}/watchers.json", self.base_url, issue_id);
+        let request = self.add_auth(self.http_client.post(&url))
+            .json(&serde_json::json!({ "user_id": user_id }));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache(&format!("issue_{}", issue_id)).await;
+
+        Ok(())
+    }
+
+    /// Odebere uživatele ze sledujících úkolu
+    pub async fn remove_watcher(&self, issue_id: i32, user_id: i32) -> ApiResult<()> {
+        let url = format!("{}/issues/{}/watchers/{}.json", self.base_url, issue_id, user_id);
+        let request = self.add_auth(self.http_client.delete(&url));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache(&format!("issue_{}", issue_id)).await;
+
+        Ok(())
+    }
+
+    pub async fn list_users(&self, filter: UserListFilter) -> ApiResult<UsersResponse> {
+        let UserListFilter { limit, offset, easy_query_q, set_filter, sort, status, easy_user_type_ids } = filter;
+        let type_ids = easy_user_type_ids.as_ref()
+            .map(|ids| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join("|"));
+        let cache_key = format!("users_{}_{}_{}_{}_{}_{}_{}",
             limit.unwrap_or(25),
             offset.unwrap_or(0),
             easy_query_q.as_ref().unwrap_or(&"".to_string()),
             set_filter.unwrap_or(false),
-            sort.as_ref().unwrap_or(&"".to_string())
+            sort.as_ref().unwrap_or(&"".to_string()),
+            status.as_ref().unwrap_or(&"".to_string()),
+            type_ids.as_ref().unwrap_or(&"".to_string())
         );
 
         self.get_cached_or_fetch(&cache_key, "user", async {
             let url = format!("{}/users.json", self.base_url);
             let mut query_params = Vec::new();
 
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
             if let Some(query) = easy_query_q {
                 query_params.push(("easy_query_q", query));
                 // Pokud je easy_query_q zadáno, automaticky aktivujeme set_filter
@@ -378,9 +1046,73 @@ impl EasyProjectClient {
             if let Some(status) = status {
                 query_params.push(("status", status));
             }
+            if let Some(type_ids) = type_ids {
+                // Filtr EasyQuery - více hodnot se odděluje svislítkem
+                query_params.push(("easy_user_type_id", type_ids));
+                if !query_params.iter().any(|(key, _)| *key == "set_filter") {
+                    query_params.push(("set_filter", "1".to_string()));
+                }
+            }
 
-            let request = self.add_auth(self.http_client.get(&url))
-                .query(&query_params);
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
+        }).await
+    }
+
+    /// Dohledá uživatele, jejichž jméno, login nebo email obsahuje text bez ohledu na diakritiku
+    ///
+    /// Doplněk fulltextu `easy_query_q` pro jména s diakritikou ("Novak" najde "Novák").
+    /// Prohledává první stránku (100) uživatelů vyhovujících ostatním filtrům.
+    pub async fn match_users_by_text(&self, text: &str, filter: UserListFilter) -> ApiResult<UsersResponse> {
+        let mut response = self.list_users(UserListFilter {
+            limit: Some(100),
+            offset: None,
+            easy_query_q: None,
+            ..filter
+        }).await?;
+        response.users.retain(|user| {
+            let full_name = format!("{} {}", user.firstname.as_deref().unwrap_or(""), user.lastname.as_deref().unwrap_or(""));
+            let matches = [Some(full_name.as_str()), user.login.as_deref(), user.mail.as_deref()].into_iter()
+                .flatten()
+                .any(|value| crate::utils::text_utils::text_contains(value, text));
+            matches
+        });
+        response.total_count = Some(response.users.len() as i32);
+        response.offset = None;
+        response.limit = None;
+        Ok(response)
+    }
+
+    /// Získá seznam typů uživatelů (interní, externí, klient...)
+    pub async fn list_user_types(&self) -> ApiResult<EasyUserTypesResponse> {
+        self.get_cached_or_fetch("user_types", "user", async {
+            let url = format!("{}/easy_user_types.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    pub async fn list_roles(&self) -> ApiResult<RolesResponse> {
+        self.get_cached_or_fetch("roles", "role", async {
+            let url = format!("{}/roles.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Detail role včetně seznamu oprávnění
+    pub async fn get_role(&self, id: i32) -> ApiResult<RoleResponse> {
+        let cache_key = format!("role_{}", id);
+
+        self.get_cached_or_fetch(&cache_key, "role", async {
+            let url = format!("{}/roles/{}.json", self.base_url, id);
+            let request = self.add_auth(self.http_client.get(&url));
 
             let response = self.execute_request(request).await?;
             self.parse_response(response)
@@ -399,17 +1131,84 @@ impl EasyProjectClient {
         }).await
     }
 
+    /// Najde uživatele podle ID, jména, loginu nebo emailu
+    ///
+    /// Číselný řetězec je ID, `me` aktuální uživatel. Jinak se hledá fulltextem přes
+    /// list_users (bez filtru stavu jen aktivní uživatelé): přesná shoda jména, loginu
+    /// nebo emailu má přednost, jinak musí být výsledek jediný. Více kandidátů vrací
+    /// InvalidParams se seznamem ID, aby volající mohl upřesnit výběr.
+    pub async fn resolve_user(&self, query: &str) -> ApiResult<User> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(ApiError::InvalidParams("Prázdné jméno uživatele".to_string()));
+        }
+        if let Ok(id) = query.parse::<i32>() {
+            return Ok(self.get_user(id).await?.user);
+        }
+        if query.eq_ignore_ascii_case("me") {
+            return Ok(self.get_current_user().await?.user);
+        }
+
+        let users = self.list_users(UserListFilter {
+            limit: Some(25),
+            easy_query_q: Some(query.to_string()),
+            ..Default::default()
+        }).await?.users;
+
+        let full_name = |user: &User| format!(
+            "{} {}",
+            user.firstname.as_deref().unwrap_or(""),
+            user.lastname.as_deref().unwrap_or("")
+        ).trim().to_string();
+        let equals = |value: Option<&str>| value.is_some_and(|value| crate::utils::text_utils::text_equals(value, query));
+
+        let exact: Vec<&User> = users.iter()
+            .filter(|user| {
+                let reversed = format!("{} {}", user.lastname.as_deref().unwrap_or(""), user.firstname.as_deref().unwrap_or(""));
+                equals(Some(&full_name(user))) || equals(Some(reversed.trim()))
+                    || equals(user.login.as_deref()) || equals(user.mail.as_deref())
+            })
+            .collect();
+
+        let candidates: Vec<&User> = if exact.is_empty() { users.iter().collect() } else { exact };
+        match candidates.as_slice() {
+            [user] => Ok((*user).clone()),
+            [] => Err(ApiError::NotFound(format!("Uživatel '{}' nebyl nalezen", query))),
+            many => Err(ApiError::InvalidParams(format!(
+                "'{}' odpovídá více uživatelům: {}. Upřesněte jméno nebo použijte ID",
+                query,
+                many.iter()
+                    .map(|user| format!("{} (ID {}{})", full_name(user), user.id, user.mail.as_deref().map(|mail| format!(", {}", mail)).unwrap_or_default()))
+                    .collect::<Vec<_>>()
+                    .join("; ")
+            ))),
+        }
+    }
+
+    /// Uživatel, pod kterým server přistupuje k API (vlastník API klíče nebo přihlášený uživatel)
+    pub async fn get_current_user(&self) -> ApiResult<UserResponse> {
+        self.get_cached_or_fetch("user_current", "user", async {
+            let url = format!("{}/users/current.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
     // === TIME ENTRY API METHODS ===
 
-    pub async fn list_time_entries(&self, project_id: Option<i32>, issue_id: Option<i32>, user_id: Option<i32>, limit: Option<u32>, offset: Option<u32>, from_date: Option<String>, to_date: Option<String>) -> ApiResult<TimeEntriesResponse> {
-        let cache_key = format!("time_entries_{}_{}_{}_{}_{}_{}_{}",
+    pub async fn list_time_entries(&self, filter: TimeEntryListFilter) -> ApiResult<TimeEntriesResponse> {
+        let TimeEntryListFilter { project_id, issue_id, user_id, limit, offset, from_date, to_date, include_subprojects } = filter;
+        let cache_key = format!("time_entries_{}_{}_{}_{}_{}_{}_{}_{:?}",
             project_id.map(|id| id.to_string()).unwrap_or_else(|| "all".to_string()),
             issue_id.map(|id| id.to_string()).unwrap_or_else(|| "all".to_string()),
             user_id.map(|id| id.to_string()).unwrap_or_else(|| "all".to_string()),
             limit.unwrap_or(25),
             offset.unwrap_or(0),
             from_date.as_ref().unwrap_or(&"none".to_string()),
-            to_date.as_ref().unwrap_or(&"none".to_string())
+            to_date.as_ref().unwrap_or(&"none".to_string()),
+            include_subprojects
         );
 
         self.get_cached_or_fetch(&cache_key, "time_entry", async {
@@ -418,7 +1217,7 @@ impl EasyProjectClient {
 
             // Zjistíme, jestli je použit nějaký filtr
             let has_filter = project_id.is_some() || issue_id.is_some() || user_id.is_some()
-                          || from_date.is_some() || to_date.is_some();
+                          || from_date.is_some() || to_date.is_some() || include_subprojects.is_some();
 
             // Pokud je použit filtr, musíme nastavit set_filter=1
             if has_filter {
@@ -434,24 +1233,22 @@ impl EasyProjectClient {
             if let Some(user_id) = user_id {
                 query_params.push(("user_id", user_id.to_string()));
             }
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
             if let Some(from_date) = from_date {
                 query_params.push(("from", from_date));
             }
             if let Some(to_date) = to_date {
                 query_params.push(("to", to_date));
             }
+            if let Some(filter) = subproject_filter(include_subprojects) {
+                query_params.push(filter);
+            }
 
-            let request = self.add_auth(self.http_client.get(&url))
-                .query(&query_params);
-
-            let response = self.execute_request(request).await?;
-            self.parse_response(response)
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
         }).await
     }
 
@@ -464,20 +1261,15 @@ impl EasyProjectClient {
 
         self.get_cached_or_fetch(&cache_key, "time_entry", async {
             let url = format!("{}/issues/{}/time_entries.json", self.base_url, issue_id);
-            let mut query_params = Vec::new();
+            let query_params: Vec<(&str, String)> = Vec::new();
 
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
 
-            let request = self.add_auth(self.http_client.get(&url))
-                .query(&query_params);
-
-            let response = self.execute_request(request).await?;
-            self.parse_response(response)
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
         }).await
     }
 
@@ -487,9 +1279,55 @@ impl EasyProjectClient {
             .json(&time_entry_data);
 
         let response = self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("time_entries_*").await;
+        self.invalidate_cache("issue_*_time_entries_*").await;
+
         self.parse_response(response)
     }
 
+    pub async fn get_time_entry(&self, id: i32) -> ApiResult<TimeEntryResponse> {
+        let cache_key = format!("time_entry_{}", id);
+
+        self.get_cached_or_fetch(&cache_key, "time_entry", async {
+            let url = format!("{}/time_entries/{}.json", self.base_url, id);
+            let request = self.add_auth(self.http_client.get(&url));
+
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    pub async fn update_time_entry(&self, id: i32, time_entry_data: UpdateTimeEntryRequest) -> ApiResult<()> {
+        let url = format!("{}/time_entries/{}.json", self.base_url, id);
+        let request = self.add_auth(self.http_client.put(&url))
+            .json(&time_entry_data);
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("time_entries_*").await;
+        self.invalidate_cache("issue_*_time_entries_*").await;
+        self.invalidate_cache(&format!("time_entry_{}", id)).await;
+
+        Ok(())
+    }
+
+    pub async fn delete_time_entry(&self, id: i32) -> ApiResult<()> {
+        let url = format!("{}/time_entries/{}.json", self.base_url, id);
+        let request = self.add_auth(self.http_client.delete(&url));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("time_entries_*").await;
+        self.invalidate_cache("issue_*_time_entries_*").await;
+        self.invalidate_cache(&format!("time_entry_{}", id)).await;
+
+        Ok(())
+    }
+
     // === MILESTONE (VERSION) API METHODS ===
 
     pub async fn list_milestones(&self, limit: Option<u32>, offset: Option<u32>, project_id: Option<i32>, status: Option<String>, easy_query_q: Option<String>) -> ApiResult<VersionsResponse> {
@@ -505,12 +1343,6 @@ impl EasyProjectClient {
             let url = format!("{}/versions.json", self.base_url);
             let mut query_params = Vec::new();
 
-            if let Some(limit) = limit {
-                query_params.push(("limit", limit.to_string()));
-            }
-            if let Some(offset) = offset {
-                query_params.push(("offset", offset.to_string()));
-            }
             if let Some(status) = status {
                 query_params.push(("status", status));
             }
@@ -518,18 +1350,136 @@ impl EasyProjectClient {
                 query_params.push(("easy_query_q", query));
             }
 
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
+        }).await
+    }
+
+    /// Soubory projektu (/projects/{id}/files.json)
+    pub async fn list_project_files(&self, project_id: i32) -> ApiResult<ProjectFilesResponse> {
+        let cache_key = format!("project_files_{}", project_id);
+
+        self.get_cached_or_fetch(&cache_key, "project", async {
+            let url = format!("{}/projects/{}/files.json", self.base_url, project_id);
             let request = self.add_auth(self.http_client.get(&url));
-            let request = if !query_params.is_empty() {
-                request.query(&query_params)
-            } else {
-                request
+
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Novinky všech projektů (/news.json) nebo jednoho projektu (/projects/{id}/news.json)
+    pub async fn list_news(&self, project_id: Option<i32>, limit: Option<u32>, offset: Option<u32>) -> ApiResult<NewsResponse> {
+        let cache_key = format!("news_{}_{}_{}",
+            project_id.unwrap_or(0),
+            limit.unwrap_or(25),
+            offset.unwrap_or(0)
+        );
+
+        self.get_cached_or_fetch(&cache_key, "news", async {
+            let url = match project_id {
+                Some(project_id) => format!("{}/projects/{}/news.json", self.base_url, project_id),
+                None => format!("{}/news.json", self.base_url),
             };
 
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                self.add_auth(self.http_client.get(&url))
+                    .query(&[("limit", page_limit.to_string()), ("offset", page_offset.to_string())])
+            }).await
+        }).await
+    }
+
+    /// Fulltextové vyhledávání napříč entitami (/search.json, v projektu /projects/{id}/search.json)
+    ///
+    /// Omezení hledání a stránkování jsou v `SearchOptions`.
+    pub async fn search(&self, query: &str, options: SearchOptions) -> ApiResult<SearchResponse> {
+        let SearchOptions { project_id, resource_types, titles_only, open_issues, limit, offset } = options;
+        let cache_key = format!("search_{}_{}_{}_{}_{}_{}_{}",
+            query,
+            project_id.unwrap_or(0),
+            resource_types.join(","),
+            titles_only,
+            open_issues,
+            limit.unwrap_or(25),
+            offset.unwrap_or(0)
+        );
+
+        self.get_cached_or_fetch(&cache_key, "search", async {
+            let url = match project_id {
+                Some(project_id) => format!("{}/projects/{}/search.json", self.base_url, project_id),
+                None => format!("{}/search.json", self.base_url),
+            };
+
+            let mut query_params = vec![("q", query.to_string())];
+            for resource_type in &resource_types {
+                query_params.push((resource_type.as_str(), "1".to_string()));
+            }
+            if titles_only {
+                query_params.push(("titles_only", "1".to_string()));
+            }
+            if open_issues {
+                query_params.push(("open_issues", "1".to_string()));
+            }
+
+            self.fetch_pages(limit, offset, |page_limit, page_offset| {
+                let mut params = query_params.clone();
+                params.push(("limit", page_limit.to_string()));
+                params.push(("offset", page_offset.to_string()));
+                self.add_auth(self.http_client.get(&url)).query(&params)
+            }).await
+        }).await
+    }
+
+    /// Verze dostupné v projektu - vlastní i sdílené z jiných projektů (podle `sharing`)
+    pub async fn list_project_versions(&self, project_id: i32) -> ApiResult<VersionsResponse> {
+        let cache_key = format!("project_versions_{}", project_id);
+
+        self.get_cached_or_fetch(&cache_key, "milestone", async {
+            let url = format!("{}/projects/{}/versions.json", self.base_url, project_id);
+            let request = self.add_auth(self.http_client.get(&url));
+
             let response = self.execute_request(request).await?;
             self.parse_response(response)
         }).await
     }
 
+    /// Ověří, že verzi lze nastavit jako `fixed_version_id` úkolu v daném projektu
+    ///
+    /// Verze musí být projektu viditelná (vlastní nebo sdílená) a otevřená.
+    pub async fn validate_version_assignable(&self, project_id: i32, version_id: i32) -> ApiResult<()> {
+        let versions = self.list_project_versions(project_id).await?;
+
+        if let Some(version) = versions.versions.iter().find(|v| v.id == version_id) {
+            return match version.status.as_deref() {
+                Some("open") | None => Ok(()),
+                Some(status) => Err(ApiError::InvalidParams(format!(
+                    "Verze '{}' (ID: {}) má stav '{}' - úkoly lze přiřadit pouze k otevřeným verzím",
+                    version.name, version_id, status
+                ))),
+            };
+        }
+
+        // Verze není projektu dostupná - pro srozumitelnou chybu zjistíme, komu patří
+        let detail = match self.get_milestone(version_id).await {
+            Ok(response) => format!(
+                "verze '{}' patří projektu {} a má sdílení '{}'",
+                response.version.name,
+                response.version.project.as_ref().map(|p| format!("'{}' (ID: {})", p.name, p.id)).unwrap_or_else(|| "?".to_string()),
+                response.version.sharing.as_deref().unwrap_or("none")
+            ),
+            Err(_) => "verze neexistuje nebo k ní nemáte přístup".to_string(),
+        };
+
+        Err(ApiError::InvalidParams(format!(
+            "Verzi {} nelze přiřadit úkolu v projektu {}: {}. Dostupné verze vrátí tool list_assignable_versions.",
+            version_id, project_id, detail
+        )))
+    }
+
     pub async fn get_milestone(&self, id: i32) -> ApiResult<VersionResponse> {
         let cache_key = format!("milestone_{}", id);
 
@@ -574,7 +1524,8 @@ impl EasyProjectClient {
         let response = self.execute_request(request).await?;
         
         // Invalidace cache
-        self.invalidate_cache("milestone").await;
+        self.invalidate_cache("milestones_*").await;
+        self.invalidate_cache(&format!("project_versions_{}", project_id)).await;
         
         self.parse_response(response)
     }
@@ -611,7 +1562,9 @@ impl EasyProjectClient {
         let response = self.execute_request(request).await?;
         
         // Invalidace cache
-        self.invalidate_cache("milestone").await;
+        self.invalidate_cache("milestones_*").await;
+        self.invalidate_cache("project_versions_*").await;
+        self.invalidate_cache(&format!("milestone_{}", id)).await;
         
         self.parse_response(response)
     }
@@ -623,16 +1576,210 @@ impl EasyProjectClient {
         let _response = self.execute_request(request).await?;
         
         // Invalidace cache
-        self.invalidate_cache("milestone").await;
+        self.invalidate_cache("milestones_*").await;
+        self.invalidate_cache("project_versions_*").await;
+        self.invalidate_cache(&format!("milestone_{}", id)).await;
         
         Ok(())
     }
 
+    // === ATTACHMENT API METHODS ===
+
+    pub async fn get_attachment(&self, id: i32) -> ApiResult<AttachmentResponse> {
+        let cache_key = format!("attachment_{}", id);
+
+        self.get_cached_or_fetch(&cache_key, "attachment", async {
+            let url = format!("{}/attachments/{}.json", self.base_url, id);
+            let request = self.add_auth(self.http_client.get(&url));
+
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Stáhne obsah přílohy, nejvýše `max_bytes` bajtů
+    ///
+    /// Obsah se stahuje jen ze stejného serveru jako API, aby se autentifikace
+    /// neposílala na cizí adresu uvedenou v content_url.
+    pub async fn download_attachment(&self, attachment: &Attachment, max_bytes: u64) -> ApiResult<Vec<u8>> {
+        if let Some(size) = attachment.filesize.filter(|size| *size as u64 > max_bytes) {
+            return Err(ApiError::InvalidParams(format!(
+                "Příloha {} má {} B, povolené maximum je {} B (max_attachment_bytes)",
+                attachment.id, size, max_bytes
+            )));
+        }
+
+        let url = attachment.content_url.clone().unwrap_or_else(|| format!(
+            "{}/attachments/download/{}/{}", self.base_url, attachment.id, attachment.filename
+        ));
+        let same_origin = match (url::Url::parse(&url), url::Url::parse(&self.base_url)) {
+            (Ok(url), Ok(base)) => url.origin() == base.origin(),
+            _ => false,
+        };
+        if !same_origin {
+            return Err(ApiError::InvalidParams(format!(
+                "Adresa obsahu přílohy {} nepatří k instanci {}", url, self.base_url
+            )));
+        }
+
+        debug!("Stahuji přílohu {} z {}", attachment.id, url);
+        let mut response = self.send_raw(self.add_auth(self.http_client.get(&url))).await?;
+        let mut content = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            content.extend_from_slice(&chunk);
+            if content.len() as u64 > max_bytes {
+                return Err(ApiError::InvalidParams(format!(
+                    "Příloha {} je větší než povolené maximum {} B (max_attachment_bytes)",
+                    attachment.id, max_bytes
+                )));
+            }
+        }
+        Ok(content)
+    }
+
+    /// Nahraje soubor (POST /uploads.json) a vrátí token pro připojení k úkolu
+    pub async fn upload_file(&self, filename: &str, content: Vec<u8>) -> ApiResult<String> {
+        let url = format!("{}/uploads.json", self.base_url);
+        let request = self.add_auth(self.http_client.post(&url))
+            .query(&[("filename", filename)])
+            .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+            .body(content);
+
+        let response = self.execute_request(request).await?;
+        let upload: UploadResponse = self.parse_response(response)?;
+        Ok(upload.upload.token)
+    }
+
+    /// Převede úkol do jiného stavu, volitelně s komentářem
+    ///
+    /// Odesílá jen `status_id` (a `notes`), ostatní pole úkolu zůstávají beze změny.
+    pub async fn transition_issue(&self, issue_id: i32, status_id: i32, notes: Option<String>) -> ApiResult<IssueResponse> {
+        let url = format!("{}/issues/{}.json", self.base_url, issue_id);
+        let mut issue = serde_json::Map::new();
+        issue.insert("status_id".to_string(), Value::from(status_id));
+        if let Some(notes) = notes {
+            issue.insert("notes".to_string(), Value::String(notes));
+        }
+        let request = self.add_auth(self.http_client.put(&url))
+            .json(&serde_json::json!({ "issue": issue }));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("issues_*").await;
+        self.invalidate_cache(&format!("issue_{}", issue_id)).await;
+
+        self.get_issue(issue_id, None).await
+    }
+
+    /// Připojí nahrané soubory k úkolu, volitelně s poznámkou
+    pub async fn attach_to_issue(&self, issue_id: i32, uploads: Vec<IssueUpload>, notes: Option<String>) -> ApiResult<()> {
+        let url = format!("{}/issues/{}.json", self.base_url, issue_id);
+        let mut issue = serde_json::Map::new();
+        issue.insert("uploads".to_string(), serde_json::to_value(uploads)?);
+        if let Some(notes) = notes {
+            issue.insert("notes".to_string(), Value::String(notes));
+        }
+        let request = self.add_auth(self.http_client.put(&url))
+            .json(&serde_json::json!({ "issue": issue }));
+
+        self.execute_request(request).await?;
+
+        // Invalidace cache
+        self.invalidate_cache("issues_*").await;
+        self.invalidate_cache(&format!("issue_{}", issue_id)).await;
+
+        Ok(())
+    }
+
     // === ENUMERATION HELPER METHODS ===
 
+    pub async fn list_issue_statuses(&self) -> ApiResult<IssueStatusesResponse> {
+        self.get_cached_or_fetch("issue_statuses", "enumeration", async {
+            let url = format!("{}/issue_statuses.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    pub async fn list_trackers(&self) -> ApiResult<TrackersResponse> {
+        self.get_cached_or_fetch("trackers", "enumeration", async {
+            let url = format!("{}/trackers.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    pub async fn list_issue_priorities(&self) -> ApiResult<IssuePrioritiesResponse> {
+        self.get_cached_or_fetch("issue_priorities", "enumeration", async {
+            let url = format!("{}/enumerations/issue_priorities.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    pub async fn list_time_entry_activities(&self) -> ApiResult<TimeEntryActivitiesResponse> {
+        self.get_cached_or_fetch("time_entry_activities", "enumeration", async {
+            let url = format!("{}/enumerations/time_entry_activities.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Definice vlastních polí (endpoint je dostupný jen administrátorům)
+    pub async fn list_custom_fields(&self) -> ApiResult<CustomFieldsResponse> {
+        self.get_cached_or_fetch("custom_fields", "enumeration", async {
+            let url = format!("{}/custom_fields.json", self.base_url);
+            let request = self.add_auth(self.http_client.get(&url));
+            let response = self.execute_request(request).await?;
+            self.parse_response(response)
+        }).await
+    }
+
+    /// Získá číselníky pro issues (status, priority, tracker)
+    ///
+    /// Přednostně použije nativní endpointy číselníků. Skenování úkolů je jen záloha
+    /// pro instance, kde tyto endpointy neexistují (HTTP 404).
+    pub async fn get_issue_enumerations(&self, project_id: Option<i32>) -> ApiResult<IssueEnumerationsResponse> {
+        match self.fetch_issue_enumerations(project_id).await {
+            Err(e) if e.is_not_found() => {
+                warn!("Endpointy číselníků nejsou dostupné ({}), zjišťuji číselníky z úkolů", e);
+                self.scan_issue_enumerations(project_id).await
+            }
+            result => result,
+        }
+    }
+
+    /// Číselníky z nativních endpointů, trackery u projektu podle jeho nastavení
+    async fn fetch_issue_enumerations(&self, project_id: Option<i32>) -> ApiResult<IssueEnumerationsResponse> {
+        let statuses = self.list_issue_statuses().await?.issue_statuses;
+        let priorities = self.list_issue_priorities().await?.issue_priorities;
+        let project_trackers = match project_id {
+            Some(id) => self.get_project(id, Some(vec!["trackers".to_string()])).await?.project.trackers,
+            None => None,
+        };
+        let trackers = match project_trackers {
+            Some(trackers) => trackers,
+            None => self.list_trackers().await?.trackers,
+        };
+
+        info!("Získány číselníky z API: {} statusů, {} priorit, {} trackerů",
+            statuses.len(), priorities.len(), trackers.len());
+
+        Ok(IssueEnumerationsResponse {
+            statuses: statuses.into_iter().map(|v| EnumerationValue { id: v.id, name: v.name }).collect(),
+            priorities: priorities.into_iter().map(|v| EnumerationValue { id: v.id, name: v.name }).collect(),
+            trackers: trackers.into_iter().map(|v| EnumerationValue { id: v.id, name: v.name }).collect(),
+        })
+    }
+
     /// Interně získá číselníky pro issues pomocí paginace
     /// Skenuje issues a extrahuje všechny unikátní hodnoty pro status, priority, tracker
-    pub async fn get_issue_enumerations(&self, project_id: Option<i32>) -> ApiResult<IssueEnumerationsResponse> {
+    async fn scan_issue_enumerations(&self, project_id: Option<i32>) -> ApiResult<IssueEnumerationsResponse> {
         use std::collections::HashMap;
 
         debug!("Interně získávám číselníky pro issues, project_id: {:?}", project_id);
@@ -641,54 +1788,14 @@ impl EasyProjectClient {
         let mut priorities: HashMap<i32, String> = HashMap::new();
         let mut trackers: HashMap<i32, String> = HashMap::new();
 
-        let mut offset = 0;
-        let limit = 100;
-        let max_iterations = 20; // Max 2000 issues pro skenování
-        let mut iteration = 0;
-
-        loop {
-            iteration += 1;
-            if iteration > max_iterations {
-                debug!("Dosažen maximální počet iterací ({}) při skenování issues", max_iterations);
-                break;
-            }
-
-            // Interně získáme stránku issues
-            let response = self.list_issues(
-                project_id,
-                Some(limit),
-                Some(offset),
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None,
-                None
-            ).await?;
-
-            if response.issues.is_empty() {
-                debug!("Žádné další issues k zpracování");
-                break;
-            }
-
-            // Extrahujeme číselníky z aktuální stránky
-            for issue in &response.issues {
+        // Číselníky extrahujeme průběžně ze stránek, samotné issues nedržíme v paměti
+        self.scan_issues(project_id, None, None, None, |issues| {
+            for issue in issues {
                 statuses.insert(issue.status.id, issue.status.name.clone());
                 priorities.insert(issue.priority.id, issue.priority.name.clone());
                 trackers.insert(issue.tracker.id, issue.tracker.name.clone());
             }
-
-            // Zkontrolujeme, jestli jsou další záznamy
-            let total = response.total_count.unwrap_or(response.issues.len() as i32);
-            offset += limit;
-
-            if offset >= total as u32 {
-                debug!("Zpracovány všechny issues ({})", total);
-                break;
-            }
-        }
+        }).await?;
 
         // Převedeme HashMapy na seřazené Vec
         let mut status_list: Vec<_> = statuses.into_iter()
@@ -716,6 +1823,361 @@ impl EasyProjectClient {
         })
     }
 
+    /// Projde úkoly stránku po stránce a každou stránku předá do `on_page`
+    ///
+    /// Stránky se neukládají do cache a po zpracování se zahazují, takže paměť
+    /// zůstává omezená i na velkých instancích. Průchod končí po `max_scan_records` záznamech.
+    pub async fn scan_issues<F>(&self, project_id: Option<i32>, assigned_to_id: Option<i32>, include_subprojects: Option<bool>, created_on: Option<String>, mut on_page: F) -> ApiResult<ScanSummary>
+    where
+        F: FnMut(&[Issue]),
+    {
+        let url = format!("{}/issues.json", self.base_url);
+        let max_records = self.max_scan_records as usize;
+        let mut summary = ScanSummary { scanned: 0, total_count: None, truncated: false };
+        let mut offset: u32 = 0;
+
+        loop {
+            let mut query_params = vec![
+                ("limit", SCAN_PAGE_SIZE.to_string()),
+                ("offset", offset.to_string()),
+            ];
+            if let Some(project_id) = project_id {
+                query_params.push(("project_id", project_id.to_string()));
+            }
+            if assigned_to_id.is_some() || include_subprojects.is_some() || created_on.is_some() {
+                query_params.push(("set_filter", "1".to_string()));
+            }
+            if let Some(assigned_to_id) = assigned_to_id {
+                query_params.push(("assigned_to_id", assigned_to_id.to_string()));
+            }
+            if let Some(ref created_on) = created_on {
+                query_params.push(("created_on", created_on.clone()));
+            }
+            if let Some(filter) = subproject_filter(include_subprojects) {
+                query_params.push(filter);
+            }
+
+            let request = self.add_auth(self.http_client.get(&url))
+                .query(&query_params);
+            let response: IssuesResponse = self.parse_response(self.execute_request_as(request, RequestPriority::Background).await?)?;
+
+            if response.issues.is_empty() {
+                break;
+            }
+
+            let remaining = max_records - summary.scanned;
+            let page = &response.issues[..response.issues.len().min(remaining)];
+            on_page(page);
+            summary.scanned += page.len();
+            summary.total_count = response.total_count;
+
+            offset += SCAN_PAGE_SIZE;
+
+            // Bez total_count končí průchod až neúplnou stránkou
+            let done = match response.total_count {
+                Some(total) => offset as usize >= total.max(0) as usize,
+                None => response.issues.len() < SCAN_PAGE_SIZE as usize,
+            };
+            if page.len() < response.issues.len() || (!done && summary.scanned >= max_records) {
+                summary.truncated = true;
+                warn!("Průchod úkolů ukončen limitem max_scan_records ({})", max_records);
+                break;
+            }
+            if done {
+                break;
+            }
+        }
+
+        debug!("Průchod úkolů dokončen: {} záznamů", summary.scanned);
+        Ok(summary)
+    }
+
+    /// Projde časové záznamy stránku po stránce, obdobně jako `scan_issues`
+    pub async fn scan_time_entries<F>(&self, project_id: Option<i32>, user_id: Option<i32>, from_date: Option<String>, to_date: Option<String>, include_subprojects: Option<bool>, mut on_page: F) -> ApiResult<ScanSummary>
+    where
+        F: FnMut(&[TimeEntry]),
+    {
+        let url = format!("{}/time_entries.json", self.base_url);
+        let max_records = self.max_scan_records as usize;
+        let mut summary = ScanSummary { scanned: 0, total_count: None, truncated: false };
+        let mut offset: u32 = 0;
+
+        loop {
+            let mut query_params = vec![
+                ("set_filter", "1".to_string()),
+                ("limit", SCAN_PAGE_SIZE.to_string()),
+                ("offset", offset.to_string()),
+            ];
+            if let Some(project_id) = project_id {
+                query_params.push(("project_id", project_id.to_string()));
+            }
+            if let Some(user_id) = user_id {
+                query_params.push(("user_id", user_id.to_string()));
+            }
+            if let Some(ref from_date) = from_date {
+                query_params.push(("from", from_date.clone()));
+            }
+            if let Some(ref to_date) = to_date {
+                query_params.push(("to", to_date.clone()));
+            }
+            if let Some(filter) = subproject_filter(include_subprojects) {
+                query_params.push(filter);
+            }
+
+            let request = self.add_auth(self.http_client.get(&url))
+                .query(&query_params);
+            let response: TimeEntriesResponse = self.parse_response(self.execute_request_as(request, RequestPriority::Background).await?)?;
+
+            if response.time_entries.is_empty() {
+                break;
+            }
+
+            let remaining = max_records - summary.scanned;
+            let page = &response.time_entries[..response.time_entries.len().min(remaining)];
+            on_page(page);
+            summary.scanned += page.len();
+            summary.total_count = response.total_count;
+
+            offset += SCAN_PAGE_SIZE;
+
+            // Bez total_count končí průchod až neúplnou stránkou
+            let done = match response.total_count {
+                Some(total) => offset as usize >= total.max(0) as usize,
+                None => response.time_entries.len() < SCAN_PAGE_SIZE as usize,
+            };
+            if page.len() < response.time_entries.len() || (!done && summary.scanned >= max_records) {
+                summary.truncated = true;
+                warn!("Průchod časových záznamů ukončen limitem max_scan_records ({})", max_records);
+                break;
+            }
+            if done {
+                break;
+            }
+        }
+
+        debug!("Průchod časových záznamů dokončen: {} záznamů", summary.scanned);
+        Ok(summary)
+    }
+
+    /// Postupné načítání úkolů po stránkách pro export (bez cache a bez limitu max_scan_records)
+    pub fn issue_pages(&self, query: &IssueQuery) -> IssuePager<'_> {
+        let mut query_params = vec![("sort", "id".to_string())];
+        if let Some(project_id) = query.project_id {
+            query_params.push(("project_id", project_id.to_string()));
+        }
+        if let Some(ref status_id) = query.status_id {
+            query_params.push(("status_id", status_id.clone()));
+        }
+        if let Some(tracker_id) = query.tracker_id {
+            query_params.push(("tracker_id", tracker_id.to_string()));
+        }
+        if let Some(assigned_to_id) = query.assigned_to_id {
+            query_params.push(("assigned_to_id", assigned_to_id.to_string()));
+        }
+        if let Some(ref updated_since) = query.updated_since {
+            query_params.push(("updated_on", format!(">={}", updated_since)));
+        }
+        if let Some(ref easy_query_q) = query.easy_query_q {
+            query_params.push(("easy_query_q", easy_query_q.clone()));
+        }
+        if let Some(filter) = subproject_filter(query.include_subprojects) {
+            query_params.push(filter);
+        }
+        if query_params.len() > 1 {
+            query_params.push(("set_filter", "1".to_string()));
+        }
+        if let Some(ref include) = query.include {
+            query_params.push(("include", include.join(",")));
+        }
+
+        IssuePager {
+            client: self,
+            url: format!("{}/issues.json", self.base_url),
+            query_params,
+            offset: 0,
+            total_count: None,
+            finished: false,
+        }
+    }
+
+    // === PERMISSION PROBING ===
+
+    /// Ověří, ke kterým oblastem API má nakonfigurovaný klíč přístup
+    ///
+    /// Používá pouze čtecí požadavky s limit=1 a výsledky neukládá do cache.
+    pub async fn probe_permissions(&self) -> PermissionReport {
+        let mut report = PermissionReport {
+            probed_at: chrono::Utc::now(),
+            user_id: None,
+            user_name: None,
+            is_admin: None,
+            probes: Vec::new(),
+        };
+
+        match self.probe_endpoint("users/current.json").await {
+            Ok(value) => {
+                let user = &value["user"];
+                report.user_id = user["id"].as_i64().map(|id| id as i32);
+                report.user_name = match (user["firstname"].as_str(), user["lastname"].as_str()) {
+                    (Some(first), Some(last)) => Some(format!("{} {}", first, last)),
+                    _ => user["login"].as_str().map(|login| login.to_string()),
+                };
+                report.is_admin = user["admin"].as_bool();
+            }
+            Err(e) => warn!("Nepodařilo se zjistit aktuálního uživatele: {}", e),
+        }
+
+        for (area, path) in [
+            (PermissionArea::Projects, "projects.json"),
+            (PermissionArea::Issues, "issues.json"),
+            (PermissionArea::TimeEntries, "time_entries.json"),
+            (PermissionArea::Users, "users.json"),
+        ] {
+            let (status, detail) = match self.probe_endpoint(path).await {
+                Ok(_) => (ProbeStatus::Allowed, None),
+                Err(ApiError::Authentication { message, .. }) => (ProbeStatus::Denied, Some(message)),
+                Err(e) => (ProbeStatus::Unknown, Some(e.to_string())),
+            };
+            report.probes.push(PermissionProbe { area, status, detail });
+        }
+
+        // Zakládání projektů ani administraci nelze ověřit bez zápisu - odvodíme je z příznaku admin
+        let admin_status = match report.is_admin {
+            Some(true) => ProbeStatus::Allowed,
+            Some(false) => ProbeStatus::Denied,
+            None => ProbeStatus::Unknown,
+        };
+        report.probes.push(PermissionProbe {
+            area: PermissionArea::Admin,
+            status: admin_status,
+            detail: None,
+        });
+        report.probes.push(PermissionProbe {
+            area: PermissionArea::ProjectCreation,
+            status: if admin_status == ProbeStatus::Allowed { ProbeStatus::Allowed } else { ProbeStatus::Unknown },
+            detail: if admin_status == ProbeStatus::Allowed {
+                None
+            } else {
+                Some("Nelze ověřit bez založení projektu, závisí na globálních oprávněních".to_string())
+            },
+        });
+
+        info!("Ověření oprávnění dokončeno (admin: {:?})", report.is_admin);
+        report
+    }
+
+    /// Obecný GET požadavek na cestu relativní k base_url (bez cache)
+    ///
+    /// Cestu musí volající předem ověřit, klient pouze doplní příponu `.json`.
+    pub async fn get_raw(&self, path: &str, query: &[(String, String)]) -> ApiResult<Value> {
+        let url = format!("{}/{}.json", self.base_url, path);
+        debug!("Obecný GET požadavek: {}", url);
+        let request = self.add_auth(self.http_client.get(&url))
+            .query(query);
+        self.execute_request(request).await
+    }
+
+    /// Zjistí verzi Redmine/EasyProject ze stránky informací administrace
+    ///
+    /// REST API verzi nevystavuje; stránka /admin/info je HTML a dostupná jen administrátorům.
+    /// Vrací `None`, pokud stránku nelze načíst nebo verzi v ní nelze rozpoznat.
+    pub async fn detect_instance_version(&self) -> Option<String> {
+        static VERSION_REGEX: OnceLock<Regex> = OnceLock::new();
+        let regex = VERSION_REGEX.get_or_init(|| {
+            Regex::new(r"(?i)\b(Redmine|Easy\s?(?:Project|Redmine)?)\s+(?:version\s+)?(\d+(?:\.\d+)+(?:[.-][\w.]+)?)").unwrap()
+        });
+
+        let url = format!("{}/admin/info", self.base_url);
+        let html = match self.send_raw(self.add_auth(self.http_client.get(&url))).await {
+            Ok(response) => response.text().await.ok()?,
+            Err(e) => {
+                debug!("Stránka {} není dostupná: {}", url, e);
+                return None;
+            }
+        };
+
+        let mut versions: Vec<String> = Vec::new();
+        for captures in regex.captures_iter(&html) {
+            let version = format!("{} {}", &captures[1], &captures[2]);
+            if !versions.contains(&version) {
+                versions.push(version);
+            }
+        }
+        if versions.is_empty() {
+            None
+        } else {
+            Some(versions.join(", "))
+        }
+    }
+
+    /// Ověří dostupnost instance a platnost přihlášení požadavkem na aktuálního uživatele
+    ///
+    /// Požadavek jde mimo cache, aby ověřil skutečný stav. Verze se zjišťuje jen
+    /// při platném přihlášení (stránka /admin/info vyžaduje administrátora).
+    pub async fn check_connection(&self) -> ConnectionCheck {
+        let url = format!("{}/users/current.json", self.base_url);
+        let started = std::time::Instant::now();
+        let result = self.execute_request(self.add_auth(self.http_client.get(&url))).await;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        let mut check = ConnectionCheck {
+            base_url: self.base_url.clone(),
+            reachable: true,
+            authenticated: false,
+            latency_ms,
+            user: None,
+            admin: None,
+            version: None,
+            error: None,
+        };
+
+        match result.and_then(|value| self.parse_response::<UserResponse>(value)) {
+            Ok(response) => {
+                check.authenticated = true;
+                check.user = Some(response.user.login.clone().unwrap_or_else(|| response.user.id.to_string()));
+                check.admin = response.user.admin;
+                if response.user.admin == Some(true) {
+                    check.version = self.detect_instance_version().await;
+                }
+            }
+            Err(e) => {
+                check.reachable = !matches!(e, ApiError::Http(_));
+                check.error = Some(e.to_string());
+            }
+        }
+        check
+    }
+
+    async fn probe_endpoint(&self, path: &str) -> ApiResult<Value> {
+        let url = format!("{}/{}", self.base_url, path);
+        let request = self.add_auth(self.http_client.get(&url))
+            .query(&[("limit", "1")]);
+        self.execute_request(request).await
+    }
+
+    /// Odešle požadavek, jehož odpověď není JSON (stránky, obsah souborů)
+    ///
+    /// Dodrží rate limiting a session autentifikaci, neúspěšný HTTP status vrátí jako chybu.
+    async fn send_raw(&self, request: RequestBuilder) -> ApiResult<reqwest::Response> {
+        self.wait_for_rate_limiter(RequestPriority::Interactive).await?;
+        if let Some(ref session) = self.session {
+            session.ensure(&self.http_client).await?;
+        }
+
+        let response = request.send().await?;
+        self.record_connection(&response);
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            return Err(ApiError::RateLimit { retry_after: Some(self.throttled(&response)) });
+        }
+        if !status.is_success() {
+            let path = response.url().path().to_string();
+            let body = response.text().await.unwrap_or_default();
+            return Err(ApiError::from_response(status.as_u16(), &path, &body));
+        }
+        Ok(response)
+    }
+
     fn parse_response<T: serde::de::DeserializeOwned>(&self, value: Value) -> ApiResult<T> {
         debug!("Parsování API response: {}", serde_json::to_string_pretty(&value).unwrap_or_else(|_| "Nepodařilo se serializovat".to_string()));
         serde_json::from_value(value).map_err(|e|
@@ -725,4 +2187,409 @@ impl EasyProjectClient {
             }
         )
     }
-} 
\ No newline at end of file
+}
+
+/// Porovná klíč cache (bez jmenného prostoru) se vzorem z `invalidate_cache`
+fn cache_key_matches(key: &str, pattern: &str) -> bool {
+    if !pattern.contains('*') {
+        return key == pattern
+            || key.strip_prefix(pattern).is_some_and(|rest| rest.starts_with('_'));
+    }
+
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = key.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = parts.collect();
+    let last = parts.pop().unwrap_or_default();
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+/// Rozpozná HTML odpověď podle content-type nebo obsahu těla
+fn is_html_response(content_type: Option<&str>, body: &str) -> bool {
+    let html_content_type = content_type.is_some_and(|ct| ct.contains("text/html"));
+    let html_body = body.trim_start().starts_with('<');
+    html_content_type || html_body
+}
+
+/// Zkrátí tělo odpovědi pro debug log
+fn truncate_for_log(text: &str) -> String {
+    const MAX_LOG_CHARS: usize = 500;
+    if text.chars().count() > MAX_LOG_CHARS {
+        format!("{}...", text.chars().take(MAX_LOG_CHARS).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+/// Filtry a stránkování pro `EasyProjectClient::list_issues`
+#[derive(Debug, Clone, Default)]
+pub struct IssueListFilter {
+    pub project_id: Option<i32>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Vnořená data úkolů (např. `relations`)
+    pub include: Option<Vec<String>>,
+    pub easy_query_q: Option<String>,
+    pub set_filter: Option<bool>,
+    pub sort: Option<String>,
+    pub assigned_to_id: Option<i32>,
+    pub status_id: Option<i32>,
+    pub tracker_id: Option<i32>,
+    pub priority_id: Option<i32>,
+    pub include_subprojects: Option<bool>,
+    /// Filtr data vytvoření ve formátu Redmine (např. `><2024-01-01|2024-01-31`)
+    pub created_on: Option<String>,
+    /// Filtr data poslední změny ve formátu Redmine
+    pub updated_on: Option<String>,
+}
+
+/// Filtry a stránkování pro `EasyProjectClient::list_users`
+#[derive(Debug, Clone, Default)]
+pub struct UserListFilter {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub easy_query_q: Option<String>,
+    pub set_filter: Option<bool>,
+    pub sort: Option<String>,
+    /// Stav uživatele (např. `active` nebo číselně 1 aktivní, 3 zablokovaný)
+    pub status: Option<String>,
+    /// ID typů uživatelů (easy_user_type_id)
+    pub easy_user_type_ids: Option<Vec<i32>>,
+}
+
+/// Filtry a stránkování pro `EasyProjectClient::list_time_entries`
+#[derive(Debug, Clone, Default)]
+pub struct TimeEntryListFilter {
+    pub project_id: Option<i32>,
+    pub issue_id: Option<i32>,
+    pub user_id: Option<i32>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    /// Od data (YYYY-MM-DD, včetně)
+    pub from_date: Option<String>,
+    /// Do data (YYYY-MM-DD, včetně)
+    pub to_date: Option<String>,
+    pub include_subprojects: Option<bool>,
+}
+
+/// Omezení a stránkování pro `EasyProjectClient::search`
+#[derive(Debug, Clone, Default)]
+pub struct SearchOptions {
+    /// Hledat jen v projektu (/projects/{id}/search.json)
+    pub project_id: Option<i32>,
+    /// Názvy parametrů /search.json (`issues`, `projects`, `wiki_pages`, `news`, ...);
+    /// prázdný seznam hledá ve všech typech
+    pub resource_types: Vec<String>,
+    pub titles_only: bool,
+    pub open_issues: bool,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Filtr úkolů pro stránkovaný průchod (`EasyProjectClient::issue_pages`)
+#[derive(Debug, Clone, Default)]
+pub struct IssueQuery {
+    pub project_id: Option<i32>,
+    /// ID stavu nebo `open`, `closed`, `*` (všechny)
+    pub status_id: Option<String>,
+    pub tracker_id: Option<i32>,
+    pub assigned_to_id: Option<i32>,
+    /// Jen úkoly změněné od data (YYYY-MM-DD)
+    pub updated_since: Option<String>,
+    pub easy_query_q: Option<String>,
+    pub include_subprojects: Option<bool>,
+    /// Vnořená data úkolů (např. `relations`)
+    pub include: Option<Vec<String>>,
+}
+
+/// Stránkovaný průchod úkoly - každé volání `next_page` načte jednu stránku z API
+pub struct IssuePager<'a> {
+    client: &'a EasyProjectClient,
+    url: String,
+    query_params: Vec<(&'static str, String)>,
+    offset: u32,
+    total_count: Option<usize>,
+    finished: bool,
+}
+
+impl IssuePager<'_> {
+    /// Celkový počet úkolů podle API (známý po načtení první stránky)
+    pub fn total_count(&self) -> Option<usize> {
+        self.total_count
+    }
+
+    /// Načte další stránku úkolů, `None` po poslední stránce
+    pub async fn next_page(&mut self) -> ApiResult<Option<Vec<Issue>>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let mut params = self.query_params.clone();
+        params.push(("limit", SCAN_PAGE_SIZE.to_string()));
+        params.push(("offset", self.offset.to_string()));
+        let request = self.client.add_auth(self.client.http_client.get(&self.url)).query(&params);
+        let response: IssuesResponse = self.client.parse_response(self.client.execute_request(request).await?)?;
+
+        let total = response.total_count.unwrap_or(0).max(0) as usize;
+        self.total_count = Some(total);
+        self.offset += SCAN_PAGE_SIZE;
+        if response.issues.is_empty() || self.offset as usize >= total {
+            self.finished = true;
+        }
+
+        if response.issues.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(response.issues))
+        }
+    }
+}
+
+/// Část klíče cache podle parametru include (nezávislá na pořadí a duplicitách)
+///
+/// Odpověď bez vnořených dat (journals, attachments...) se tak nevrátí volajícímu, který je požaduje.
+fn include_cache_key(include: &Option<Vec<String>>) -> String {
+    let mut parts: Vec<&str> = include.iter().flatten().map(|part| part.trim()).filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() {
+        return "none".to_string();
+    }
+    parts.sort_unstable();
+    parts.dedup();
+    parts.join(",")
+}
+
+/// Filtr rozsahu dat ve tvaru Redmine API (`><od|do`, `>=od` nebo `<=do`)
+///
+/// Data se předávají ve formátu YYYY-MM-DD, bez obou mezí vrací `None`.
+pub fn date_range_filter(from: Option<&str>, to: Option<&str>) -> Option<String> {
+    match (from, to) {
+        (Some(from), Some(to)) => Some(format!("><{}|{}", from, to)),
+        (Some(from), None) => Some(format!(">={}", from)),
+        (None, Some(to)) => Some(format!("<={}", to)),
+        (None, None) => None,
+    }
+}
+
+/// Filtr podprojektů pro Redmine dotazy
+///
+/// Bez explicitní hodnoty rozhoduje nastavení instance (display_subprojects_issues),
+/// `subproject_id=*` zahrne všechny podprojekty, `subproject_id=!*` pouze samotný projekt.
+fn subproject_filter(include_subprojects: Option<bool>) -> Option<(&'static str, String)> {
+    include_subprojects.map(|include| {
+        let value = if include { "*" } else { "!*" };
+        ("subproject_id", value.to_string())
+    })
+}
+
+/// Sestaví jmenný prostor cache z URL instance a identity přihlášení
+///
+/// API klíč se do klíče cache neukládá, používá se pouze jeho otisk.
+fn cache_namespace_for(base_url: &str, api_key: &str) -> String {
+    let instance = base_url.trim().trim_end_matches('/').to_lowercase();
+    format!("{}#{:016x}", instance, fnv1a_64(api_key.as_bytes()))
+}
+
+/// Stabilní 64bitový FNV-1a hash (nezávislý na verzi Rustu, na rozdíl od DefaultHasher)
+pub(crate) fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::Matcher;
+
+    async fn client(base_url: &str) -> EasyProjectClient {
+        let mut config = AppConfig::default();
+        config.easyproject.base_url = base_url.to_string();
+        config.easyproject.api_key = Some("test-key".to_string());
+        config.cache.enabled = false;
+        EasyProjectClient::new(&config).await.unwrap()
+    }
+
+    fn attachment(filesize: Option<i64>, content_url: Option<String>) -> Attachment {
+        Attachment {
+            id: 9,
+            filename: "report.txt".to_string(),
+            filesize,
+            content_type: Some("text/plain".to_string()),
+            description: None,
+            content_url,
+            author: None,
+            created_on: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("GET", "/attachments/download/9/report.txt")
+            .match_header("X-Redmine-API-Key", "test-key")
+            .with_body("obsah přílohy")
+            .create_async().await;
+        let client = client(&server.url()).await;
+
+        let content = client.download_attachment(&attachment(None, None), 1024).await.unwrap();
+        assert_eq!(content, "obsah přílohy".as_bytes());
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_rejects_declared_size() {
+        let server = mockito::Server::new_async().await;
+        let client = client(&server.url()).await;
+
+        let error = client.download_attachment(&attachment(Some(2048), None), 1024).await.unwrap_err();
+        assert!(matches!(error, ApiError::InvalidParams(ref message) if message.contains("2048 B")));
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_stops_over_limit() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/attachments/download/9/report.txt")
+            .with_body("x".repeat(2048))
+            .create_async().await;
+        let client = client(&server.url()).await;
+
+        // Server uvádí menší velikost, než jakou ve skutečnosti posílá
+        let error = client.download_attachment(&attachment(Some(10), None), 1024).await.unwrap_err();
+        assert!(matches!(error, ApiError::InvalidParams(ref message) if message.contains("max_attachment_bytes")));
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_rejects_foreign_origin() {
+        let server = mockito::Server::new_async().await;
+        let client = client(&server.url()).await;
+
+        let foreign = attachment(None, Some("https://example.com/attachments/download/9/report.txt".to_string()));
+        let error = client.download_attachment(&foreign, 1024).await.unwrap_err();
+        assert!(matches!(error, ApiError::InvalidParams(ref message) if message.contains("nepatří k instanci")));
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_http_error() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/attachments/download/9/report.txt")
+            .with_status(404)
+            .create_async().await;
+        let client = client(&server.url()).await;
+
+        let error = client.download_attachment(&attachment(None, None), 1024).await.unwrap_err();
+        assert!(matches!(error, ApiError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_and_attach() {
+        let mut server = mockito::Server::new_async().await;
+        let upload = server.mock("POST", "/uploads.json")
+            .match_query(Matcher::UrlEncoded("filename".to_string(), "report.txt".to_string()))
+            .match_header("content-type", "application/octet-stream")
+            .match_body("obsah")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"upload":{"token":"7.abc"}}"#)
+            .create_async().await;
+        let attach = server.mock("PUT", "/issues/42.json")
+            .match_body(Matcher::Json(serde_json::json!({
+                "issue": {
+                    "uploads": [{ "token": "7.abc", "filename": "report.txt", "content_type": "text/plain" }],
+                    "notes": "Přidán report"
+                }
+            })))
+            .with_status(204)
+            .create_async().await;
+        let client = client(&server.url()).await;
+
+        let token = client.upload_file("report.txt", b"obsah".to_vec()).await.unwrap();
+        assert_eq!(token, "7.abc");
+
+        let uploads = vec![IssueUpload {
+            token,
+            filename: "report.txt".to_string(),
+            content_type: Some("text/plain".to_string()),
+            description: None,
+        }];
+        client.attach_to_issue(42, uploads, Some("Přidán report".to_string())).await.unwrap();
+
+        upload.assert_async().await;
+        attach.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_upload_file_rejected() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("POST", "/uploads.json")
+            .match_query(Matcher::Any)
+            .with_status(422)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"errors":["Soubor je příliš velký"]}"#)
+            .create_async().await;
+        let client = client(&server.url()).await;
+
+        let error = client.upload_file("report.txt", b"obsah".to_vec()).await.unwrap_err();
+        assert!(matches!(error, ApiError::Validation { status: 422, .. }));
+    }
+
+    fn issues_page(ids: std::ops::Range<i32>, total_count: Option<i32>) -> String {
+        let issues: Vec<Value> = ids.map(|id| serde_json::json!({
+            "id": id,
+            "subject": format!("Úkol {}", id),
+            "project": { "id": 1, "name": "Projekt" },
+            "tracker": { "id": 1, "name": "Úkol" },
+            "status": { "id": 1, "name": "Nový" },
+            "priority": { "id": 2, "name": "Normální" }
+        })).collect();
+        let mut body = serde_json::json!({ "issues": issues });
+        if let Some(total_count) = total_count {
+            body["total_count"] = total_count.into();
+        }
+        body.to_string()
+    }
+
+    async fn mock_issues_page(server: &mut mockito::ServerGuard, offset: u32, body: String) -> mockito::Mock {
+        server.mock("GET", "/issues.json")
+            .match_query(Matcher::UrlEncoded("offset".into(), offset.to_string()))
+            .with_header("content-type", "application/json")
+            .with_body(body)
+            .create_async().await
+    }
+
+    #[tokio::test]
+    async fn test_scan_issues_truncated_within_last_page() {
+        let mut server = mockito::Server::new_async().await;
+        mock_issues_page(&mut server, 0, issues_page(1..101, Some(180))).await;
+        mock_issues_page(&mut server, 100, issues_page(101..181, Some(180))).await;
+        let mut config = AppConfig::default();
+        config.easyproject.base_url = server.url();
+        config.easyproject.api_key = Some("test-key".to_string());
+        config.cache.enabled = false;
+        config.tools.reports.max_scan_records = 150;
+        let client = EasyProjectClient::new(&config).await.unwrap();
+
+        let summary = client.scan_issues(None, None, None, None, |_| {}).await.unwrap();
+        assert_eq!(summary.scanned, 150);
+        assert!(summary.truncated);
+    }
+
+    #[tokio::test]
+    async fn test_scan_issues_without_total_count() {
+        let mut server = mockito::Server::new_async().await;
+        mock_issues_page(&mut server, 0, issues_page(1..101, None)).await;
+        mock_issues_page(&mut server, 100, issues_page(101..121, None)).await;
+        let client = client(&server.url()).await;
+
+        let summary = client.scan_issues(None, None, None, None, |_| {}).await.unwrap();
+        assert_eq!(summary.scanned, 120);
+        assert!(!summary.truncated);
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use serde::Serialize;
+use tracing::debug;
+
+/// Po tolika sledovaných spojeních se množina vyprázdní (lokální porty se časem opakují)
+const MAX_TRACKED_CONNECTIONS: usize = 1024;
+
+/// Sleduje znovupoužití HTTP spojení z poolu podle lokální adresy socketu odpovědi
+///
+/// Každé nové spojení má jiný lokální port, odpověď se známou adresou je tedy
+/// buď první na novém spojení, nebo přišla po spojení znovu použitém z poolu.
+#[derive(Debug, Default)]
+pub struct ConnectionTracker {
+    requests: AtomicU64,
+    connections: AtomicU64,
+    seen: Mutex<HashSet<SocketAddr>>,
+}
+
+/// Souhrn využití HTTP spojení od startu serveru
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionStats {
+    pub requests: u64,
+    pub connections_opened: u64,
+    pub reused: u64,
+    /// Podíl požadavků odeslaných po znovu použitém spojení (0.0 - 1.0)
+    pub reuse_ratio: f64,
+}
+
+impl ConnectionTracker {
+    /// Zaznamená odpověď; bez známé lokální adresy (např. v testech) se nezapočítá
+    pub fn record(&self, local_addr: Option<SocketAddr>, remote_addr: Option<SocketAddr>) {
+        let Some(local_addr) = local_addr else {
+            return;
+        };
+        self.requests.fetch_add(1, Ordering::Relaxed);
+
+        let mut seen = self.seen.lock().unwrap();
+        if seen.len() >= MAX_TRACKED_CONNECTIONS && !seen.contains(&local_addr) {
+            seen.clear();
+        }
+        if seen.insert(local_addr) {
+            let opened = self.connections.fetch_add(1, Ordering::Relaxed) + 1;
+            debug!("Otevřeno nové HTTP spojení {} -> {:?} (celkem {})", local_addr, remote_addr, opened);
+        }
+    }
+
+    pub fn stats(&self) -> ConnectionStats {
+        let requests = self.requests.load(Ordering::Relaxed);
+        let connections_opened = self.connections.load(Ordering::Relaxed);
+        let reused = requests.saturating_sub(connections_opened);
+        ConnectionStats {
+            requests,
+            connections_opened,
+            reused,
+            reuse_ratio: if requests > 0 { reused as f64 / requests as f64 } else { 0.0 },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_connection_reuse_stats() {
+        let tracker = ConnectionTracker::default();
+        let first: SocketAddr = "10.0.0.1:50001".parse().unwrap();
+        let second: SocketAddr = "10.0.0.1:50002".parse().unwrap();
+
+        tracker.record(Some(first), None);
+        tracker.record(Some(first), None);
+        tracker.record(Some(first), None);
+        tracker.record(Some(second), None);
+        tracker.record(None, None);
+
+        let stats = tracker.stats();
+        assert_eq!(stats.requests, 4);
+        assert_eq!(stats.connections_opened, 2);
+        assert_eq!(stats.reused, 2);
+        assert_eq!(stats.reuse_ratio, 0.5);
+    }
+}
@@ -9,18 +9,29 @@ pub enum ApiError {
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
     
-    #[error("Authentication error: {0}")]
-    Authentication(String),
+    /// Neplatné přihlášení (HTTP 401) nebo chybějící oprávnění (HTTP 403)
+    #[error("Authentication error: {message}")]
+    Authentication { status: Option<u16>, message: String },
     
     #[error("API error: {status} - {message}")]
     Api { status: u16, message: String },
     
-    #[error("Rate limit exceeded")]
-    RateLimit,
+    #[error("Rate limit exceeded{}", .retry_after.map(|delay| format!(", retry after {} s", delay.as_secs())).unwrap_or_default())]
+    RateLimit { retry_after: Option<std::time::Duration> },
+    
+    #[error("Fronta požadavků na API je plná ({0} čekajících), zkuste to prosím později")]
+    QueueFull(usize),
+    
+    /// EasyProject odmítl zadané hodnoty (HTTP 422), každá hláška se týká jednoho pole
+    #[error("EasyProject odmítl zadané hodnoty:{}", .errors.iter().map(|error| format!("\n- {}", error)).collect::<String>())]
+    Validation { status: u16, errors: Vec<String> },
     
     #[error("Resource not found: {0}")]
     NotFound(String),
     
+    #[error("EasyProject instance je v údržbě nebo dočasně nedostupná: {0}")]
+    Unavailable(String),
+    
     #[error("Invalid parameters: {0}")]
     InvalidParams(String),
     
@@ -42,15 +53,81 @@ pub struct ApiErrorResponse {
     pub error: Option<String>,
 }
 
+impl ApiErrorResponse {
+    /// Text chyby z těla odpovědi (message, error nebo seznam errors)
+    pub fn into_message(self) -> Option<String> {
+        self.message
+            .or(self.error)
+            .or_else(|| self.errors.filter(|errors| !errors.is_empty()).map(|errors| errors.join(", ")))
+    }
+}
+
 impl From<ApiErrorResponse> for ApiError {
     fn from(error_response: ApiErrorResponse) -> Self {
-        let message = error_response.message
-            .or(error_response.error)
-            .or_else(|| error_response.errors.as_ref().map(|e| e.join(", ")))
+        let message = error_response.into_message()
             .unwrap_or_else(|| "Neznámá chyba API".to_string());
         
         ApiError::Api { status: 400, message }
     }
 }
 
+impl ApiError {
+    /// Převede neúspěšnou odpověď API na typovanou chybu
+    ///
+    /// Tělo odpovědi ve formátu EasyProject (`errors`, `message`, `error`) se použije jako
+    /// text chyby, takže se k uživateli dostanou i validační hlášky typu "Subject can't be blank".
+    pub fn from_response(status: u16, path: &str, body: &str) -> Self {
+        let parsed = serde_json::from_str::<ApiErrorResponse>(body).ok();
+
+        // Validační chyby se předávají po jednotlivých polích, aby šlo opravit konkrétní hodnotu
+        if matches!(status, 400 | 422) {
+            if let Some(errors) = parsed.as_ref().and_then(|parsed| parsed.errors.clone()).filter(|errors| !errors.is_empty()) {
+                return ApiError::Validation { status, errors };
+            }
+        }
+
+        let detail = parsed.and_then(ApiErrorResponse::into_message);
+
+        match status {
+            401 => ApiError::Authentication {
+                status: Some(status),
+                message: detail.unwrap_or_else(|| format!("{}: neplatný API klíč nebo vypršelé přihlášení (HTTP 401)", path)),
+            },
+            403 => ApiError::Authentication {
+                status: Some(status),
+                message: detail.unwrap_or_else(|| format!("{}: nedostatečná oprávnění (HTTP 403)", path)),
+            },
+            404 => ApiError::NotFound(path.to_string()),
+            429 => ApiError::RateLimit { retry_after: None },
+            _ => ApiError::Api {
+                status,
+                message: detail.unwrap_or_else(|| format!("HTTP error {}: {}", status, body.trim())),
+            },
+        }
+    }
+
+    /// Jednotlivé validační hlášky EasyProject (prázdné pro ostatní chyby)
+    pub fn validation_errors(&self) -> &[String] {
+        match self {
+            ApiError::Validation { errors, .. } => errors,
+            _ => &[],
+        }
+    }
+
+    /// Chybí oprávnění k endpointu nebo akci (HTTP 403)
+    pub fn is_forbidden(&self) -> bool {
+        matches!(self, ApiError::Authentication { status: Some(403), .. } | ApiError::Api { status: 403, .. })
+    }
+
+    /// Dočasná chyba upstreamu, kterou má smysl zopakovat
+    pub fn is_transient(&self) -> bool {
+        matches!(self, ApiError::Unavailable(_))
+    }
+
+    /// Endpoint nebo entita neexistuje (HTTP 404)
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ApiError::NotFound(_) | ApiError::Api { status: 404, .. })
+    }
+}
+
 pub type ApiResult<T> = Result<T, ApiError>; 
\ No newline at end of file
@@ -1,6 +1,13 @@
 pub mod client;
 pub mod models;
 pub mod error;
+pub mod cache_stats;
+pub mod capture;
+pub mod connection;
+pub mod permissions;
+pub mod queue;
+pub mod session;
+pub mod throttle;
 
 pub use client::EasyProjectClient;
 pub use models::*;
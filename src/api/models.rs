@@ -28,8 +28,30 @@ pub struct Project {
     pub trackers: Option<Vec<Tracker>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub issue_categories: Option<Vec<IssueCategory>>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_module_names")]
     pub enabled_modules: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomValue>>,
+}
+
+/// API vrací moduly jako objekty `{id, name}`, starší instance jen jako názvy
+fn deserialize_module_names<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ModuleName {
+        Name(String),
+        Module { name: String },
+    }
+
+    let modules: Option<Vec<ModuleName>> = Option::deserialize(deserializer)?;
+    Ok(modules.map(|modules| modules.into_iter()
+        .map(|module| match module {
+            ModuleName::Name(name) | ModuleName::Module { name } => name,
+        })
+        .collect()))
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -112,6 +134,118 @@ pub struct Issue {
     pub updated_on: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub closed_on: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<Attachment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<IssueChild>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relations: Option<Vec<IssueRelation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub journals: Option<Vec<Journal>>,
+    /// Sledující úkolu (vrací se při include=watchers)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watchers: Option<Vec<UserReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_fields: Option<Vec<CustomValue>>,
+    /// Stavy, do kterých smí aktuální uživatel úkol převést (vrací se při include=allowed_statuses)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_statuses: Option<Vec<IssueStatus>>,
+}
+
+/// Podúkol (vrací se při include=children)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueChild {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracker: Option<Tracker>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<IssueChild>>,
+}
+
+/// Vazba mezi úkoly (vrací se při include=relations)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRelation {
+    pub id: i32,
+    pub issue_id: i32,
+    pub issue_to_id: i32,
+    pub relation_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<i32>,
+}
+
+/// Záznam historie úkolu (vrací se při include=journals)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Journal {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub details: Vec<JournalDetail>,
+}
+
+/// Jedna změna atributu v rámci záznamu historie
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalDetail {
+    pub property: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_value: Option<String>,
+}
+
+/// Příloha úkolu (vrací se při include=attachments)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: i32,
+    pub filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filesize: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttachmentResponse {
+    pub attachment: Attachment,
+}
+
+/// Odpověď na nahrání souboru (POST /uploads.json) - token pro připojení k úkolu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadResponse {
+    pub upload: UploadToken,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadToken {
+    pub token: String,
+}
+
+/// Nahraný soubor připojovaný k úkolu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueUpload {
+    pub token: String,
+    pub filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -119,6 +253,54 @@ pub struct IssueReference {
     pub id: i32,
 }
 
+/// Hodnota vlastního pole entity (pole `custom_fields` v odpovědích)
+///
+/// Hodnota je řetězec, u polí s více hodnotami pole řetězců, u nevyplněných null.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomValue {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiple: Option<bool>,
+    #[serde(default)]
+    pub value: Value,
+}
+
+/// Definice vlastního pole (/custom_fields.json, vyžaduje administrátora)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomField {
+    pub id: i32,
+    pub name: String,
+    /// Typ entity, ke které pole patří (issue, project, time_entry, user...)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customized_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_required: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multiple: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub possible_values: Option<Vec<CustomFieldPossibleValue>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trackers: Option<Vec<Tracker>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldPossibleValue {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldsResponse {
+    pub custom_fields: Vec<CustomField>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Tracker {
     pub id: i32,
@@ -145,6 +327,11 @@ pub struct IssueCategory {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueCategoryResponse {
+    pub issue_category: IssueCategory,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Version {
     pub id: i32,
@@ -202,7 +389,7 @@ pub struct User {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub easy_external_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub easy_user_type: Option<Value>,
+    pub easy_user_type: Option<EasyUserType>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub easy_user_type_id: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -260,6 +447,52 @@ pub struct TimeEntryActivity {
 }
 
 /// API Response wrappers
+/// Stránkovaná odpověď seznamu z API, kterou lze skládat z více stránek
+pub trait PagedResponse {
+    /// Počet položek v odpovědi
+    fn item_count(&self) -> usize;
+    /// Celkový počet záznamů podle API
+    fn total(&self) -> Option<i32>;
+    /// Připojí položky další stránky
+    fn extend_page(&mut self, page: Self);
+    /// Nastaví offset a limit odpovídající spojenému výsledku
+    fn set_window(&mut self, offset: u32, limit: u32);
+}
+
+macro_rules! impl_paged_response {
+    ($response:ty, $items:ident) => {
+        impl PagedResponse for $response {
+            fn item_count(&self) -> usize {
+                self.$items.len()
+            }
+
+            fn total(&self) -> Option<i32> {
+                self.total_count
+            }
+
+            fn extend_page(&mut self, page: Self) {
+                self.$items.extend(page.$items);
+                if page.total_count.is_some() {
+                    self.total_count = page.total_count;
+                }
+            }
+
+            fn set_window(&mut self, offset: u32, limit: u32) {
+                self.offset = Some(offset as i32);
+                self.limit = Some(limit as i32);
+            }
+        }
+    };
+}
+
+impl_paged_response!(ProjectsResponse, projects);
+impl_paged_response!(IssuesResponse, issues);
+impl_paged_response!(UsersResponse, users);
+impl_paged_response!(TimeEntriesResponse, time_entries);
+impl_paged_response!(VersionsResponse, versions);
+impl_paged_response!(NewsResponse, news);
+impl_paged_response!(SearchResponse, results);
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectsResponse {
     pub projects: Vec<Project>,
@@ -271,6 +504,91 @@ pub struct ProjectsResponse {
     pub limit: Option<i32>,
 }
 
+/// Soubor projektu (modul Soubory/Dokumenty)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFile {
+    pub id: i32,
+    pub filename: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filesize: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<Version>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub downloads: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectFilesResponse {
+    pub files: Vec<ProjectFile>,
+}
+
+/// Novinka (oznámení) projektu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct News {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<ProjectReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<UserReference>,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsResponse {
+    pub news: Vec<News>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+/// Výsledek fulltextového vyhledávání (/search.json)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub id: i32,
+    pub title: String,
+    /// Typ výsledku, např. `issue`, `issue-closed`, `project`, `wiki-page`, `news`
+    #[serde(rename = "type")]
+    pub result_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub datetime: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResponse {
+    pub results: Vec<SearchResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProjectResponse {
     pub project: Project,
@@ -303,6 +621,123 @@ pub struct UsersResponse {
     pub limit: Option<i32>,
 }
 
+/// Typ uživatele v EasyProject (např. interní, externí, klient)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EasyUserType {
+    pub id: i32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<i32>,
+}
+
+/// Kategorie typu uživatele používaná pro filtrování
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UserTypeCategory {
+    Internal,
+    External,
+    Client,
+}
+
+impl UserTypeCategory {
+    /// Popis kategorie v jazyce výstupu
+    pub fn label(&self) -> &'static str {
+        crate::i18n::messages().user_type_categories[*self as usize]
+    }
+}
+
+impl EasyUserType {
+    /// Odvodí kategorii typu uživatele
+    ///
+    /// Typy uživatelů jsou v EasyProject konfigurovatelné, proto se klientské typy poznají
+    /// podle názvu a zbytek podle příznaku `internal` (případně opět podle názvu).
+    pub fn category(&self) -> Option<UserTypeCategory> {
+        let name = crate::utils::text_utils::normalize_for_match(&self.name);
+        if ["client", "klient", "zakaznik", "customer", "partner"].iter().any(|k| name.contains(k)) {
+            return Some(UserTypeCategory::Client);
+        }
+        match self.internal {
+            Some(true) => Some(UserTypeCategory::Internal),
+            Some(false) => Some(UserTypeCategory::External),
+            None if name.contains("extern") => Some(UserTypeCategory::External),
+            None if name.contains("intern") => Some(UserTypeCategory::Internal),
+            None => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EasyUserTypesResponse {
+    pub easy_user_types: Vec<EasyUserType>,
+}
+
+/// Role, kterou lze přidělit členům projektu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Role {
+    pub id: i32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub builtin: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issues_visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_entries_visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub users_visibility: Option<String>,
+    /// Oprávnění role (vrací jen detail role)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RolesResponse {
+    pub roles: Vec<Role>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleResponse {
+    pub role: Role,
+}
+
+/// Členství uživatele nebo skupiny v projektu
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Membership {
+    pub id: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project: Option<ProjectReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<UserReference>,
+    #[serde(default)]
+    pub roles: Vec<MembershipRole>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipRole {
+    pub id: i32,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inherited: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MembershipsResponse {
+    pub memberships: Vec<Membership>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserResponse {
     pub user: User,
@@ -381,6 +816,9 @@ pub struct CreateIssue {
     pub due_date: Option<NaiveDate>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub done_ratio: Option<i32>,
+    /// Hodnoty vlastních polí podle ID pole; pole neuvedená zde se nemění
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_field_values: Option<std::collections::BTreeMap<String, Value>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -399,6 +837,32 @@ pub struct CreateTimeEntry {
     pub activity_id: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<String>,
+    /// Záznam za jiného uživatele (vyžaduje oprávnění logovat čas za ostatní)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i32>,
+}
+
+/// Částečná aktualizace časového záznamu - odesílají se pouze vyplněná pole
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateTimeEntryRequest {
+    pub time_entry: UpdateTimeEntry,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct UpdateTimeEntry {
+    /// Some(None) odpojí záznam od úkolu (zůstane pouze na projektu)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue_id: Option<Option<i32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spent_on: Option<NaiveDate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hours: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub activity_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
 }
 
 // === MILESTONE (VERSION) MODELS ===
@@ -477,6 +941,26 @@ pub struct EnumerationValue {
     pub name: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueStatusesResponse {
+    pub issue_statuses: Vec<IssueStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackersResponse {
+    pub trackers: Vec<Tracker>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssuePrioritiesResponse {
+    pub issue_priorities: Vec<Priority>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeEntryActivitiesResponse {
+    pub time_entry_activities: Vec<TimeEntryActivity>,
+}
+
 /// Issue enumerations response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueEnumerationsResponse {
@@ -484,3 +968,35 @@ pub struct IssueEnumerationsResponse {
     pub priorities: Vec<EnumerationValue>,
     pub trackers: Vec<EnumerationValue>,
 }
+
+/// Výsledek ověření spojení s instancí EasyProject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionCheck {
+    pub base_url: String,
+    /// Server odpověděl (i chybou autentifikace)
+    pub reachable: bool,
+    /// Přihlášení API klíčem nebo session je platné
+    pub authenticated: bool,
+    /// Doba odezvy ověřovacího požadavku
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Souhrn průchodu stránkovanými daty (scan)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanSummary {
+    /// Počet zpracovaných záznamů
+    pub scanned: usize,
+    /// Celkový počet záznamů hlášený API
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<i32>,
+    /// Průchod byl ukončen limitem max_scan_records
+    pub truncated: bool,
+}
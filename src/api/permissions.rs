@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Oblast API, ke které nástroj potřebuje přístup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionArea {
+    /// Čtení projektů
+    Projects,
+    /// Zakládání projektů (administrátor nebo globální oprávnění)
+    ProjectCreation,
+    /// Čtení úkolů
+    Issues,
+    /// Čtení časových záznamů
+    TimeEntries,
+    /// Seznam uživatelů (v EasyProject pouze pro administrátory)
+    Users,
+    /// Administrátorské operace (např. mazání projektů)
+    Admin,
+}
+
+impl PermissionArea {
+    /// Popis oblasti v jazyce výstupu
+    pub fn label(&self) -> &'static str {
+        crate::i18n::messages().permission_area_labels[*self as usize]
+    }
+}
+
+/// Výsledek ověření jedné oblasti
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProbeStatus {
+    Allowed,
+    Denied,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionProbe {
+    pub area: PermissionArea,
+    pub status: ProbeStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Souhrn oprávnění nakonfigurovaného API klíče
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PermissionReport {
+    pub probed_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_admin: Option<bool>,
+    pub probes: Vec<PermissionProbe>,
+}
+
+impl PermissionReport {
+    /// Vrátí stav oblasti; neověřené oblasti jsou Unknown
+    pub fn status_of(&self, area: PermissionArea) -> ProbeStatus {
+        self.probes.iter()
+            .find(|probe| probe.area == area)
+            .map(|probe| probe.status)
+            .unwrap_or(ProbeStatus::Unknown)
+    }
+
+    /// Oblast je s jistotou nedostupná
+    pub fn is_denied(&self, area: PermissionArea) -> bool {
+        self.status_of(area) == ProbeStatus::Denied
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+use super::error::{ApiError, ApiResult};
+
+/// Priorita požadavku ve frontě před rate limiterem
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestPriority {
+    /// Požadavky vyvolané přímo voláním tool (get, list, první stránka seznamu)
+    Interactive,
+    /// Průchody na pozadí (další stránky seznamů, průchody pro sestavy a číselníky)
+    Background,
+}
+
+/// Prioritní fronta požadavků čekajících na rate limiter
+///
+/// Na rate limiter čeká vždy jen jeden požadavek, ostatní stojí ve frontě. Po získání
+/// povolení se pořadí předá nejstaršímu interaktivnímu požadavku a teprve bez nich
+/// požadavkům na pozadí, takže dlouhé průchody nezdržují odpovědi na dotazy uživatele.
+#[derive(Debug, Clone)]
+pub struct RequestQueue {
+    inner: Arc<QueueInner>,
+}
+
+#[derive(Debug)]
+struct QueueInner {
+    /// Nejvyšší počet čekajících požadavků (0 = bez limitu)
+    max_depth: usize,
+    state: Mutex<QueueState>,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    busy: bool,
+    interactive: VecDeque<oneshot::Sender<QueueTicket>>,
+    background: VecDeque<oneshot::Sender<QueueTicket>>,
+}
+
+/// Pořadí na rate limiteru; uvolněním se předá dalšímu čekajícímu požadavku
+#[derive(Debug)]
+pub struct QueueTicket {
+    queue: Option<Arc<QueueInner>>,
+}
+
+impl RequestQueue {
+    pub fn new(max_depth: usize) -> Self {
+        Self {
+            inner: Arc::new(QueueInner {
+                max_depth,
+                state: Mutex::new(QueueState::default()),
+            }),
+        }
+    }
+
+    /// Počká na pořadí; při plné frontě vrátí chybu `QueueFull`
+    pub async fn acquire(&self, priority: RequestPriority) -> ApiResult<QueueTicket> {
+        let receiver = {
+            let mut state = self.inner.state.lock().unwrap();
+            if !state.busy {
+                state.busy = true;
+                return Ok(QueueTicket { queue: Some(self.inner.clone()) });
+            }
+
+            // Čekatelé zrušení před předáním pořadí se do hloubky fronty nepočítají
+            state.interactive.retain(|sender| !sender.is_closed());
+            state.background.retain(|sender| !sender.is_closed());
+            let waiting = state.interactive.len() + state.background.len();
+            if self.inner.max_depth > 0 && waiting >= self.inner.max_depth {
+                return Err(ApiError::QueueFull(waiting));
+            }
+
+            let (sender, receiver) = oneshot::channel();
+            match priority {
+                RequestPriority::Interactive => state.interactive.push_back(sender),
+                RequestPriority::Background => state.background.push_back(sender),
+            }
+            receiver
+        };
+
+        // Odesílatel se zahodí jen spolu s frontou, pořadí proto vždy dorazí
+        receiver.await.map_err(|_| ApiError::QueueFull(0))
+    }
+
+    /// Počet požadavků čekajících ve frontě
+    pub fn waiting(&self) -> usize {
+        let state = self.inner.state.lock().unwrap();
+        state.interactive.len() + state.background.len()
+    }
+}
+
+impl QueueInner {
+    fn release(self: &Arc<Self>) {
+        loop {
+            let next = {
+                let mut state = self.state.lock().unwrap();
+                match state.interactive.pop_front().or_else(|| state.background.pop_front()) {
+                    Some(sender) => sender,
+                    None => {
+                        state.busy = false;
+                        return;
+                    }
+                }
+            };
+
+            // Zrušený čekatel vrátí pořadí zpět a předá se dalšímu v řadě
+            match next.send(QueueTicket { queue: Some(self.clone()) }) {
+                Ok(()) => return,
+                Err(mut ticket) => {
+                    ticket.queue = None;
+                }
+            }
+        }
+    }
+}
+
+impl Drop for QueueTicket {
+    fn drop(&mut self) {
+        if let Some(queue) = self.queue.take() {
+            queue.release();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_interactive_preempts_background() {
+        let queue = RequestQueue::new(0);
+        let first = queue.acquire(RequestPriority::Background).await.unwrap();
+        let (sender, mut order) = tokio::sync::mpsc::unbounded_channel();
+
+        for (priority, label) in [(RequestPriority::Background, "background"), (RequestPriority::Interactive, "interactive")] {
+            let q = queue.clone();
+            let sender = sender.clone();
+            let expected = queue.waiting() + 1;
+            tokio::spawn(async move {
+                let _ticket = q.acquire(priority).await.unwrap();
+                let _ = sender.send(label);
+            });
+            while queue.waiting() < expected {
+                tokio::task::yield_now().await;
+            }
+        }
+        drop(first);
+
+        assert_eq!(order.recv().await, Some("interactive"));
+        assert_eq!(order.recv().await, Some("background"));
+    }
+
+    #[tokio::test]
+    async fn test_queue_overflow() {
+        let queue = RequestQueue::new(1);
+        let first = queue.acquire(RequestPriority::Interactive).await.unwrap();
+
+        let waiting = tokio::spawn({
+            let queue = queue.clone();
+            async move { queue.acquire(RequestPriority::Background).await.is_ok() }
+        });
+        while queue.waiting() < 1 {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(matches!(
+            queue.acquire(RequestPriority::Interactive).await,
+            Err(ApiError::QueueFull(1))
+        ));
+        drop(first);
+        assert!(waiting.await.unwrap());
+    }
+}
@@ -0,0 +1,113 @@
+use regex::Regex;
+use reqwest::Client;
+use std::sync::OnceLock;
+use tokio::sync::Mutex;
+use tracing::{debug, info};
+
+use crate::config::AppConfig;
+use super::error::{ApiError, ApiResult};
+
+/// Přihlášení jménem a heslem přes přihlašovací formulář EasyProject
+///
+/// Session cookie drží cookie jar HTTP klienta. Stav přihlášení má číslo generace,
+/// aby při vypršení session souběžné požadavky nespustily opakované přihlášení.
+#[derive(Debug)]
+pub struct SessionAuth {
+    username: String,
+    password: String,
+    login_url: String,
+    state: Mutex<SessionState>,
+}
+
+#[derive(Debug, Default)]
+struct SessionState {
+    logged_in: bool,
+    generation: u64,
+}
+
+impl SessionAuth {
+    pub fn from_config(config: &AppConfig) -> Option<Self> {
+        let easyproject = &config.easyproject;
+        Some(Self {
+            username: easyproject.username.clone()?,
+            password: easyproject.password.clone()?,
+            login_url: format!(
+                "{}/{}",
+                easyproject.base_url.trim_end_matches('/'),
+                easyproject.login_path.trim_start_matches('/')
+            ),
+            state: Mutex::new(SessionState::default()),
+        })
+    }
+
+    pub fn username(&self) -> &str {
+        &self.username
+    }
+
+    /// Zajistí platnou session a vrátí její generaci
+    pub async fn ensure(&self, http_client: &Client) -> ApiResult<u64> {
+        let mut state = self.state.lock().await;
+        if !state.logged_in {
+            self.login(http_client).await?;
+            state.logged_in = true;
+            state.generation += 1;
+        }
+        Ok(state.generation)
+    }
+
+    /// Znovu se přihlásí po vypršení session
+    ///
+    /// Pokud mezitím přihlášení obnovil jiný požadavek (generace se změnila), nic nedělá.
+    pub async fn relogin(&self, http_client: &Client, expired_generation: u64) -> ApiResult<()> {
+        let mut state = self.state.lock().await;
+        if state.logged_in && state.generation != expired_generation {
+            return Ok(());
+        }
+        state.logged_in = false;
+        info!("Session EasyProject vypršela, přihlašuji se znovu");
+        self.login(http_client).await?;
+        state.logged_in = true;
+        state.generation += 1;
+        Ok(())
+    }
+
+    async fn login(&self, http_client: &Client) -> ApiResult<()> {
+        debug!("Přihlašuji uživatele {} přes {}", self.username, self.login_url);
+
+        // Přihlašovací formulář obsahuje CSRF token, který je nutné odeslat zpět
+        let form = http_client.get(&self.login_url).send().await?.text().await?;
+        let token = authenticity_token(&form).ok_or_else(|| ApiError::Authentication {
+            status: None,
+            message: "přihlašovací stránka neobsahuje authenticity_token".to_string(),
+        })?;
+
+        let response = http_client.post(&self.login_url)
+            .form(&[
+                ("username", self.username.as_str()),
+                ("password", self.password.as_str()),
+                ("authenticity_token", token.as_str()),
+            ])
+            .send()
+            .await?;
+
+        // Při neúspěchu server znovu zobrazí přihlašovací formulář místo přesměrování
+        let status = response.status();
+        if !status.is_success() || response.url().as_str().trim_end_matches('/') == self.login_url.trim_end_matches('/') {
+            return Err(ApiError::Authentication {
+                status: None,
+                message: format!("přihlášení uživatele {} selhalo (HTTP {})", self.username, status),
+            });
+        }
+
+        info!("Přihlášen uživatel {} (session autentifikace)", self.username);
+        Ok(())
+    }
+}
+
+fn authenticity_token(html: &str) -> Option<String> {
+    static TOKEN_REGEX: OnceLock<Regex> = OnceLock::new();
+    let regex = TOKEN_REGEX.get_or_init(|| {
+        Regex::new(r#"name="(?:authenticity_token|csrf-token)"\s+(?:value|content)="([^"]+)""#).unwrap()
+    });
+    regex.captures(html).map(|captures| captures[1].to_string())
+}
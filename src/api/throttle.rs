@@ -0,0 +1,202 @@
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use chrono::{DateTime, Utc};
+use governor::{Quota, RateLimiter, state::{InMemoryState, NotKeyed}, clock::DefaultClock};
+use tracing::{debug, info, warn};
+
+use crate::config::RateLimitingConfig;
+
+type DirectRateLimiter = RateLimiter<NotKeyed, InMemoryState, DefaultClock>;
+
+/// Nejdelší čekání na konec omezení od serveru v rámci jednoho požadavku, pak se vrátí chyba
+pub const MAX_THROTTLE_WAIT: Duration = Duration::from_secs(60);
+
+/// Čekání po HTTP 429 bez hlavičky Retry-After
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(5);
+
+/// Doba bez HTTP 429, po které se snížená kvóta zdvojnásobí (nejvýše na hodnotu z konfigurace)
+const RECOVERY_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Nejnižší kvóta, na kterou se limiter po HTTP 429 sníží
+const MIN_REQUESTS_PER_MINUTE: u32 = 1;
+
+/// Rate limiter, který se přizpůsobuje omezení ze strany serveru
+///
+/// Po HTTP 429 pozdrží všechny požadavky do uplynutí Retry-After a sníží lokální kvótu
+/// na polovinu. Po `RECOVERY_INTERVAL` bez dalšího omezení se kvóta postupně vrací
+/// na hodnotu z konfigurace. Při vypnutém rate limitingu se dodržuje jen Retry-After.
+#[derive(Debug)]
+pub struct AdaptiveRateLimiter {
+    /// Kvóta z konfigurace jako (requests_per_minute, burst_size), None = rate limiting vypnutý
+    configured: Option<(u32, u32)>,
+    state: Mutex<ThrottleState>,
+}
+
+#[derive(Debug)]
+struct ThrottleState {
+    limiter: Option<Arc<DirectRateLimiter>>,
+    requests_per_minute: u32,
+    paused_until: Option<Instant>,
+    last_change: Instant,
+}
+
+impl AdaptiveRateLimiter {
+    pub fn from_config(config: &RateLimitingConfig) -> Self {
+        let configured = config.enabled.then_some((config.requests_per_minute, config.burst_size));
+        Self {
+            configured,
+            state: Mutex::new(ThrottleState {
+                limiter: configured.map(|(rpm, burst)| Arc::new(build_limiter(rpm, burst))),
+                requests_per_minute: config.requests_per_minute,
+                paused_until: None,
+                last_change: Instant::now(),
+            }),
+        }
+    }
+
+    /// Počká na povolení k odeslání požadavku
+    ///
+    /// Pokud server omezil požadavky na déle než `MAX_THROTTLE_WAIT`, nečeká
+    /// a vrátí zbývající dobu omezení.
+    pub async fn until_ready(&self) -> Result<(), Duration> {
+        let (pause, limiter) = {
+            let mut state = self.state.lock().unwrap();
+            self.recover(&mut state);
+            let pause = state.paused_until
+                .and_then(|until| until.checked_duration_since(Instant::now()));
+            (pause, state.limiter.clone())
+        };
+
+        if let Some(pause) = pause {
+            if pause > MAX_THROTTLE_WAIT {
+                return Err(pause);
+            }
+            debug!("Server omezuje požadavky, čekám {:?}", pause);
+            tokio::time::sleep(pause).await;
+        }
+
+        if let Some(limiter) = limiter {
+            limiter.until_ready().await;
+        }
+        Ok(())
+    }
+
+    /// Zjistí, zda je volná kapacita pro nepovinný požadavek (spotřebuje jedno povolení)
+    pub fn check(&self) -> bool {
+        let state = self.state.lock().unwrap();
+        if state.paused_until.is_some_and(|until| until > Instant::now()) {
+            return false;
+        }
+        state.limiter.as_ref().is_none_or(|limiter| limiter.check().is_ok())
+    }
+
+    /// Zaznamená odpověď HTTP 429 a vrátí dobu, po kterou se požadavky pozdrží
+    ///
+    /// Kvóta se sníží jen jednou za období omezení, aby souběžné odpovědi 429
+    /// nesrazily limiter rovnou na minimum.
+    pub fn on_rate_limited(&self, retry_after: Option<Duration>) -> Duration {
+        let delay = retry_after.unwrap_or(DEFAULT_RETRY_AFTER);
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+
+        let already_paused = state.paused_until.is_some_and(|until| until > now);
+        if state.paused_until.is_none_or(|until| until < now + delay) {
+            state.paused_until = Some(now + delay);
+        }
+
+        if let Some((_, burst)) = self.configured {
+            let tightened = (state.requests_per_minute / 2).max(MIN_REQUESTS_PER_MINUTE);
+            if !already_paused && tightened < state.requests_per_minute {
+                state.requests_per_minute = tightened;
+                state.limiter = Some(Arc::new(build_limiter(tightened, burst)));
+                warn!("Server omezuje požadavky (HTTP 429), kvóta snížena na {} požadavků/min", tightened);
+            }
+        }
+        state.last_change = now;
+        delay
+    }
+
+    /// Aktuální kvóta požadavků za minutu (None = rate limiting vypnutý)
+    pub fn requests_per_minute(&self) -> Option<u32> {
+        self.configured.map(|_| self.state.lock().unwrap().requests_per_minute)
+    }
+
+    /// Po delší době bez omezení vrací sníženou kvótu zpět k hodnotě z konfigurace
+    fn recover(&self, state: &mut ThrottleState) {
+        let Some((configured, burst)) = self.configured else {
+            return;
+        };
+        if state.requests_per_minute < configured && state.last_change.elapsed() >= RECOVERY_INTERVAL {
+            state.requests_per_minute = (state.requests_per_minute * 2).min(configured);
+            state.limiter = Some(Arc::new(build_limiter(state.requests_per_minute, burst)));
+            state.last_change = Instant::now();
+            info!("Kvóta rate limiteru obnovena na {} požadavků/min", state.requests_per_minute);
+        }
+    }
+}
+
+fn build_limiter(requests_per_minute: u32, burst_size: u32) -> DirectRateLimiter {
+    let requests_per_minute = requests_per_minute.max(1);
+    RateLimiter::direct(
+        Quota::per_minute(NonZeroU32::new(requests_per_minute).unwrap())
+            .allow_burst(NonZeroU32::new(burst_size.clamp(1, requests_per_minute)).unwrap())
+    )
+}
+
+/// Přečte hlavičku Retry-After (počet sekund nebo HTTP datum)
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&Utc) - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn config(enabled: bool) -> RateLimitingConfig {
+        RateLimitingConfig {
+            enabled,
+            requests_per_minute: 60,
+            burst_size: 10,
+            max_queue_depth: 0,
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+        assert_eq!(parse_retry_after("30", now), Some(Duration::from_secs(30)));
+        assert_eq!(parse_retry_after(" 0 ", now), Some(Duration::ZERO));
+        assert_eq!(
+            parse_retry_after("Fri, 01 Mar 2024 12:02:00 GMT", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_retry_after("Fri, 01 Mar 2024 11:00:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("brzy", now), None);
+    }
+
+    #[test]
+    fn test_rate_limited_tightens_quota_once_per_pause() {
+        let limiter = AdaptiveRateLimiter::from_config(&config(true));
+        assert_eq!(limiter.on_rate_limited(Some(Duration::from_secs(2))), Duration::from_secs(2));
+        assert_eq!(limiter.requests_per_minute(), Some(30));
+        // Souběžná odpověď 429 ze stejného období omezení kvótu znovu nesnižuje
+        limiter.on_rate_limited(None);
+        assert_eq!(limiter.requests_per_minute(), Some(30));
+        assert!(!limiter.check());
+    }
+
+    #[test]
+    fn test_rate_limited_without_local_limiter() {
+        let limiter = AdaptiveRateLimiter::from_config(&config(false));
+        assert_eq!(limiter.on_rate_limited(None), DEFAULT_RETRY_AFTER);
+        assert_eq!(limiter.requests_per_minute(), None);
+        assert!(!limiter.check());
+    }
+}
@@ -0,0 +1,217 @@
+use std::collections::VecDeque;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::sync::{Mutex, OnceLock};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{error, info};
+
+use crate::config::AuditConfig;
+use crate::mcp::protocol::{CallToolResult, ToolResult};
+
+/// Klíče argumentů, jejichž hodnota se do auditu nezapisuje
+const REDACTED_KEYS: &[&str] = &["password", "api_key", "token", "secret"];
+
+/// Jeden záznam auditu - volání tool, které mění data v EasyProject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub tool: String,
+    /// Instance EasyProject, nad kterou tool běžel (viz [instances] v konfiguraci)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    pub arguments: Option<Value>,
+    /// ID vytvořené nebo změněné entity, pokud je z argumentů nebo výsledku zjistitelné
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_id: Option<i64>,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// MCP klient z initialize (název a verze)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+impl AuditEntry {
+    /// Sestaví záznam z dokončeného volání tool
+    pub fn from_call(tool: &str, arguments: Option<&Value>, result: Result<&CallToolResult, String>) -> Self {
+        let (success, error, created_id) = match result {
+            Ok(result) if result.is_error.unwrap_or(false) => (false, first_text(result), None),
+            Ok(result) => (true, None, result_id(result)),
+            Err(e) => (false, Some(e), None),
+        };
+        // Explicitní "id" má přednost, ostatní *_id v argumentech mohou být jen nadřazené entity
+        let explicit_id = arguments.and_then(|arguments| arguments.get("id")).and_then(Value::as_i64);
+        Self {
+            timestamp: Utc::now(),
+            tool: tool.to_string(),
+            instance: None,
+            arguments: arguments.map(redact),
+            entity_id: explicit_id.or(created_id).or_else(|| arguments.and_then(argument_id)),
+            success,
+            error,
+            client: None,
+            correlation_id: None,
+        }
+    }
+}
+
+/// Audit zápisových operací - posledních `max_entries` záznamů v paměti, volitelně i v souboru JSONL
+#[derive(Debug)]
+pub struct AuditLog {
+    enabled: bool,
+    max_entries: usize,
+    entries: Mutex<VecDeque<AuditEntry>>,
+    file: Option<Mutex<File>>,
+}
+
+impl AuditLog {
+    pub fn from_config(config: &AuditConfig) -> Self {
+        let file = config.path.as_ref().filter(|_| config.enabled).and_then(|path| {
+            match OpenOptions::new().create(true).append(true).open(path) {
+                Ok(file) => {
+                    info!("Audit zápisových operací se ukládá do {}", path.display());
+                    Some(Mutex::new(file))
+                }
+                Err(e) => {
+                    error!("Soubor auditu {} nelze otevřít, audit zůstane jen v paměti: {}", path.display(), e);
+                    None
+                }
+            }
+        });
+        Self {
+            enabled: config.enabled,
+            max_entries: config.max_entries.max(1),
+            entries: Mutex::new(VecDeque::new()),
+            file,
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn record(&self, entry: AuditEntry) {
+        if !self.enabled {
+            return;
+        }
+
+        if let Some(ref file) = self.file {
+            let written = serde_json::to_string(&entry)
+                .map_err(|e| e.to_string())
+                .and_then(|line| {
+                    let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+                    writeln!(file, "{}", line).map_err(|e| e.to_string())
+                });
+            if let Err(e) = written {
+                error!("Záznam auditu pro {} se nepodařilo zapsat: {}", entry.tool, e);
+            }
+        }
+
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        if entries.len() >= self.max_entries {
+            entries.pop_front();
+        }
+        entries.push_back(entry);
+    }
+
+    /// Nejnovější záznamy (od nejnovějšího), volitelně jen pro jeden tool
+    pub fn recent(&self, limit: usize, tool: Option<&str>) -> Vec<AuditEntry> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        entries.iter()
+            .rev()
+            .filter(|entry| tool.is_none_or(|tool| entry.tool == tool))
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Nahradí hodnoty citlivých klíčů (hesla, tokeny) v argumentech
+fn redact(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(map.iter()
+            .map(|(key, value)| {
+                let sensitive = REDACTED_KEYS.iter().any(|redacted| key.to_lowercase().contains(redacted));
+                (key.clone(), if sensitive { Value::from("***") } else { redact(value) })
+            })
+            .collect()),
+        Value::Array(items) => Value::Array(items.iter().map(redact).collect()),
+        other => other.clone(),
+    }
+}
+
+/// ID entity z argumentů zápisových tools bez explicitního "id" (např. issue_id)
+fn argument_id(arguments: &Value) -> Option<i64> {
+    ["issue_id", "time_entry_id", "version_id", "milestone_id", "project_id"].iter()
+        .find_map(|key| arguments.get(*key).and_then(Value::as_i64))
+}
+
+/// ID entity z výsledku: nejdřív ze strukturovaných dat, pak z textu "... vytvořen s ID 123"
+fn result_id(result: &CallToolResult) -> Option<i64> {
+    static CREATED_ID: OnceLock<Regex> = OnceLock::new();
+
+    if let Some(id) = result.structured_content.as_ref().and_then(|content| content.get("id")).and_then(Value::as_i64) {
+        return Some(id);
+    }
+    let regex = CREATED_ID.get_or_init(|| Regex::new(r"\bID:? (\d+)").unwrap());
+    let text = first_text(result)?;
+    regex.captures(&text)?.get(1)?.as_str().parse().ok()
+}
+
+fn first_text(result: &CallToolResult) -> Option<String> {
+    result.content.iter().find_map(|item| match item {
+        ToolResult::Text { text } => Some(text.clone()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn config(max_entries: usize) -> AuditConfig {
+        AuditConfig { enabled: true, path: None, max_entries }
+    }
+
+    #[test]
+    fn test_entry_from_created_result() {
+        let arguments = json!({ "project_id": 7, "subject": "Nový úkol", "password": "tajne" });
+        let result = CallToolResult::success(vec![
+            ToolResult::text("Úkol 'Nový úkol' byl úspěšně vytvořen s ID 1234:\n\n{}".to_string())
+        ]);
+
+        let entry = AuditEntry::from_call("create_issue", Some(&arguments), Ok(&result));
+        assert!(entry.success);
+        assert_eq!(entry.entity_id, Some(1234));
+        assert_eq!(entry.arguments.unwrap()["password"], "***");
+    }
+
+    #[test]
+    fn test_entry_from_failed_update() {
+        let arguments = json!({ "id": 42, "status_id": 3 });
+        let result = CallToolResult::error(vec![ToolResult::text("Chyba při aktualizaci úkolu 42".to_string())]);
+
+        let entry = AuditEntry::from_call("update_issue", Some(&arguments), Ok(&result));
+        assert!(!entry.success);
+        assert_eq!(entry.entity_id, Some(42));
+        assert_eq!(entry.error.as_deref(), Some("Chyba při aktualizaci úkolu 42"));
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_newest() {
+        let log = AuditLog::from_config(&config(2));
+        for tool in ["create_issue", "update_issue", "delete_issue"] {
+            log.record(AuditEntry::from_call(tool, None, Err("x".to_string())));
+        }
+
+        let recent: Vec<String> = log.recent(10, None).into_iter().map(|entry| entry.tool).collect();
+        assert_eq!(recent, vec!["delete_issue", "update_issue"]);
+        assert_eq!(log.recent(10, Some("update_issue")).len(), 1);
+    }
+}
@@ -0,0 +1,218 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use tracing::{debug, info, warn};
+
+use super::AppConfig;
+
+/// Prefix environment proměnných načítaných knihovnou config
+pub(super) const ENV_PREFIX: &str = "EASYPROJECT_MCP";
+
+/// Environment proměnné přepisující konkrétní klíče konfigurace
+pub(super) const ENV_KEY_OVERRIDES: &[(&str, &str)] = &[
+    ("EASYPROJECT_API_KEY", "easyproject.api_key"),
+    ("EASYPROJECT_BASE_URL", "easyproject.base_url"),
+    ("EASYPROJECT_USERNAME", "easyproject.username"),
+    ("EASYPROJECT_PASSWORD", "easyproject.password"),
+    ("EASYPROJECT_LOCALE", "localization.locale"),
+];
+
+/// Klíč konfigurace, který nešel načíst a byl nahrazen výchozí hodnotou
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyError {
+    pub key: String,
+    pub error: String,
+}
+
+/// Environment proměnná, která přepsala hodnotu konfigurace (hodnota se neuvádí)
+#[derive(Debug, Clone, Serialize)]
+pub struct EnvOverride {
+    pub variable: String,
+    pub key: String,
+}
+
+/// Výsledek načtení konfigurace - co se nepodařilo načíst, co je neznámé a co má výchozí hodnotu
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConfigDiagnostics {
+    /// Chyba, kvůli které nešlo konfiguraci načíst vcelku
+    pub load_error: Option<String>,
+    /// Zdroj konfigurace nešel přečíst, použity jen výchozí hodnoty a přepsání
+    pub defaults_only: bool,
+    /// Klíče s neplatnou hodnotou (použita výchozí hodnota)
+    pub failed_keys: Vec<KeyError>,
+    /// Klíče, které konfigurace nezná (typicky překlep)
+    pub unknown_keys: Vec<String>,
+    /// Klíče, které ve zdrojích chybí a mají výchozí hodnotu
+    pub defaulted_keys: Vec<String>,
+    pub env_overrides: Vec<EnvOverride>,
+}
+
+impl ConfigDiagnostics {
+    /// Konfigurace obsahuje chyby nebo neznámé klíče
+    pub fn has_problems(&self) -> bool {
+        self.load_error.is_some() || !self.failed_keys.is_empty() || !self.unknown_keys.is_empty()
+    }
+
+    /// Vypíše diagnostiku do logu (volá se po inicializaci logování)
+    pub fn log(&self) {
+        if let Some(ref error) = self.load_error {
+            warn!("Konfiguraci nešlo načíst vcelku: {}", error);
+        }
+        for failed in &self.failed_keys {
+            warn!("Neplatná hodnota konfigurace '{}', použita výchozí: {}", failed.key, failed.error);
+        }
+        for key in &self.unknown_keys {
+            warn!("Neznámý klíč konfigurace '{}' (překlep?), ignorován", key);
+        }
+        for env in &self.env_overrides {
+            info!("Konfigurace '{}' přepsána z environment proměnné {}", env.key, env.variable);
+        }
+        if !self.defaulted_keys.is_empty() {
+            debug!("Výchozí hodnoty použity pro: {}", self.defaulted_keys.join(", "));
+        }
+    }
+
+    /// Zjistí, které environment proměnné přepisují konfiguraci
+    pub(super) fn collect_env_overrides(&mut self) {
+        for (variable, key) in ENV_KEY_OVERRIDES {
+            if std::env::var(variable).is_ok() {
+                self.env_overrides.push(EnvOverride { variable: variable.to_string(), key: key.to_string() });
+            }
+        }
+
+        let prefix = format!("{}_", ENV_PREFIX);
+        let mut prefixed: Vec<EnvOverride> = std::env::vars()
+            .filter_map(|(variable, _)| {
+                let key = variable.strip_prefix(&prefix)?.to_lowercase();
+                Some(EnvOverride { variable, key })
+            })
+            .collect();
+        prefixed.sort_by(|a, b| a.variable.cmp(&b.variable));
+        self.env_overrides.extend(prefixed);
+    }
+
+    /// Porovná načtené hodnoty se strukturou výchozí konfigurace
+    pub(super) fn compare_with_defaults(&mut self, raw: &Value) {
+        let template = default_template();
+        let mut leaves = Vec::new();
+        walk(raw, &template, "", &mut self.unknown_keys, &mut leaves);
+        self.defaulted_keys = missing_leaves(&template, raw, "");
+    }
+
+    /// Sestaví konfiguraci po jednotlivých klíčích, neplatné hodnoty nahradí výchozími
+    ///
+    /// Používá se, když načtené hodnoty nejdou deserializovat vcelku.
+    pub(super) fn recover(&mut self, raw: &Value) -> AppConfig {
+        let mut merged = default_template();
+        let mut leaves = Vec::new();
+        walk(raw, &merged, "", &mut self.unknown_keys, &mut leaves);
+        self.defaulted_keys = missing_leaves(&merged, raw, "");
+
+        for (path, value) in leaves {
+            let previous = set_path(&mut merged, &path, value.clone());
+            let error = match serde_json::from_value::<AppConfig>(merged.clone()) {
+                Ok(_) => continue,
+                Err(e) => e.to_string(),
+            };
+
+            // Hodnoty z environment proměnných jsou vždy řetězce
+            if let Some(coerced) = coerce_scalar(&value) {
+                set_path(&mut merged, &path, coerced);
+                if serde_json::from_value::<AppConfig>(merged.clone()).is_ok() {
+                    continue;
+                }
+            }
+
+            set_path(&mut merged, &path, previous);
+            self.defaulted_keys.push(path.clone());
+            self.failed_keys.push(KeyError { key: path, error });
+        }
+
+        serde_json::from_value(merged).unwrap_or_default()
+    }
+
+    /// Výchozí konfigurace s přepsanými klíči (klíč, hodnota), když zdroj konfigurace nejde přečíst
+    pub(super) fn recover_overrides(&mut self, overrides: &[(String, String)]) -> AppConfig {
+        let mut raw = Value::Object(Map::new());
+        for (key, value) in overrides {
+            set_path(&mut raw, key, Value::String(value.clone()));
+        }
+        self.recover(&raw)
+    }
+}
+
+fn default_template() -> Value {
+    serde_json::to_value(AppConfig::default()).unwrap_or_else(|_| Value::Object(Map::new()))
+}
+
+fn join(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", prefix, key)
+    }
+}
+
+/// Projde načtené hodnoty podle struktury výchozí konfigurace - sbírá neznámé klíče a listy
+fn walk(raw: &Value, template: &Value, prefix: &str, unknown: &mut Vec<String>, leaves: &mut Vec<(String, Value)>) {
+    let (Value::Object(raw), Value::Object(template)) = (raw, template) else {
+        leaves.push((prefix.to_string(), raw.clone()));
+        return;
+    };
+    // Prázdný objekt ve výchozí konfiguraci je mapa s libovolnými klíči (např. instances)
+    if template.is_empty() {
+        leaves.push((prefix.to_string(), Value::Object(raw.clone())));
+        return;
+    }
+
+    for (key, value) in raw {
+        let path = join(prefix, key);
+        match template.get(key) {
+            Some(nested) => walk(value, nested, &path, unknown, leaves),
+            None => unknown.push(path),
+        }
+    }
+}
+
+/// Listy výchozí konfigurace, které v načtených hodnotách chybí
+fn missing_leaves(template: &Value, raw: &Value, prefix: &str) -> Vec<String> {
+    let Value::Object(template) = template else { return Vec::new() };
+
+    let mut missing = Vec::new();
+    for (key, nested) in template {
+        let path = join(prefix, key);
+        match raw.get(key) {
+            Some(value) if nested.is_object() => missing.extend(missing_leaves(nested, value, &path)),
+            Some(_) => {}
+            None => match nested {
+                Value::Object(_) => missing.extend(missing_leaves(nested, &Value::Null, &path)),
+                _ => missing.push(path),
+            },
+        }
+    }
+    missing
+}
+
+/// Nastaví hodnotu na cestě `a.b.c` a vrátí původní hodnotu
+fn set_path(target: &mut Value, path: &str, value: Value) -> Value {
+    let mut current = target;
+    let mut parts = path.split('.').peekable();
+    while let Some(part) = parts.next() {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        let object = current.as_object_mut().expect("hodnota je objekt");
+        if parts.peek().is_none() {
+            return object.insert(part.to_string(), value).unwrap_or(Value::Null);
+        }
+        current = object.entry(part.to_string()).or_insert(Value::Null);
+    }
+    Value::Null
+}
+
+fn coerce_scalar(value: &Value) -> Option<Value> {
+    let text = value.as_str()?.trim();
+    match serde_json::from_str::<Value>(text).ok()? {
+        coerced @ (Value::Bool(_) | Value::Number(_)) => Some(coerced),
+        _ => None,
+    }
+}
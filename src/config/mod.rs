@@ -1,8 +1,19 @@
 use anyhow::{Result, Context};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::OnceLock;
 use std::time::Duration;
 use url::Url;
 
+use crate::i18n::Locale;
+
+mod diagnostics;
+mod watch;
+
+pub use diagnostics::ConfigDiagnostics;
+pub use watch::{spawn_reload_triggers, ReloadTrigger};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
     pub server: ServerConfig,
@@ -12,6 +23,138 @@ pub struct AppConfig {
     pub cache: CacheConfig,
     pub logging: LoggingConfig,
     pub tools: ToolsConfig,
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
+    #[serde(default)]
+    pub snapshots: SnapshotConfig,
+    #[serde(default)]
+    pub localization: LocalizationConfig,
+    #[serde(default)]
+    pub audit: AuditConfig,
+    /// Další pojmenované instance EasyProject obsluhované stejným serverem
+    #[serde(default)]
+    pub instances: BTreeMap<String, InstanceConfig>,
+}
+
+/// Jazyk popisů tools a textového výstupu
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LocalizationConfig {
+    /// cs (výchozí) nebo en
+    #[serde(default)]
+    pub locale: Locale,
+}
+
+/// Auditní záznam volání tools, které mění data v EasyProject
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditConfig {
+    #[serde(default = "default_audit_enabled")]
+    pub enabled: bool,
+    /// Soubor JSONL, do kterého se záznamy připisují (bez souboru jen v paměti)
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Počet posledních záznamů držených v paměti pro get_audit_log
+    #[serde(default = "default_audit_max_entries")]
+    pub max_entries: usize,
+}
+
+fn default_audit_enabled() -> bool {
+    true
+}
+
+fn default_audit_max_entries() -> usize {
+    1000
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_audit_enabled(),
+            path: None,
+            max_entries: default_audit_max_entries(),
+        }
+    }
+}
+
+/// Úložiště snímků projektu (např. baseline plánu) na disku
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotConfig {
+    /// Adresář, do kterého se ukládají snímky
+    #[serde(default = "default_snapshot_dir")]
+    pub dir: String,
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        Self {
+            dir: default_snapshot_dir(),
+        }
+    }
+}
+
+fn default_snapshot_dir() -> String {
+    "snapshots".to_string()
+}
+
+/// Plánované generování sestav projektů do exportního adresáře
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchedulerConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Adresář, do kterého se ukládají vygenerované sestavy
+    #[serde(default = "default_export_dir")]
+    pub export_dir: String,
+    #[serde(default)]
+    pub reports: Vec<ScheduledReportConfig>,
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            export_dir: default_export_dir(),
+            reports: Vec::new(),
+        }
+    }
+}
+
+fn default_export_dir() -> String {
+    "reports".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledReportConfig {
+    pub project_id: i32,
+    /// Cron výraz (minuta hodina den měsíc den_v_týdnu) v časovém pásmu sestav
+    pub cron: String,
+    #[serde(default = "default_export_formats")]
+    pub formats: Vec<ReportExportFormat>,
+    /// Sestava za posledních N dní (bez zadání za celé období projektu)
+    #[serde(default)]
+    pub period_days: Option<u32>,
+    #[serde(default)]
+    pub include_details: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportExportFormat {
+    Md,
+    Csv,
+    Pdf,
+}
+
+impl ReportExportFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReportExportFormat::Md => "md",
+            ReportExportFormat::Csv => "csv",
+            ReportExportFormat::Pdf => "pdf",
+        }
+    }
+}
+
+fn default_export_formats() -> Vec<ReportExportFormat> {
+    vec![ReportExportFormat::Md]
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +163,60 @@ pub struct ServerConfig {
     pub version: String,
     pub transport: TransportType,
     pub websocket_port: Option<u16>,
+    /// Adresa, na které naslouchá HTTP transport (za reverzní proxy typicky 0.0.0.0)
+    #[serde(default = "default_http_host")]
+    pub http_host: String,
+    #[serde(default = "default_http_port")]
+    pub http_port: u16,
+    /// Cesta MCP endpointu HTTP transportu
+    #[serde(default = "default_http_path")]
+    pub http_path: String,
+    /// Povolené hodnoty hlavičky Origin kromě localhost (např. "https://app.example.com")
+    #[serde(default)]
+    pub http_allowed_origins: Vec<String>,
+    /// Po kolika sekundách nečinnosti se HTTP relace ukončí (0 = nikdy)
+    #[serde(default = "default_http_session_idle_timeout")]
+    pub http_session_idle_timeout_seconds: u64,
+    /// Maximální počet současných HTTP relací, další initialize dostane 503 (0 = bez omezení)
+    #[serde(default = "default_http_max_sessions")]
+    pub http_max_sessions: usize,
+    /// Maximální velikost jedné zprávy transportní vrstvy v bajtech (příchozí i odchozí)
+    #[serde(default = "default_max_message_size")]
+    pub max_message_size: usize,
+    /// Velikost textu výsledku tool v bajtech, nad kterou se výsledek dělí na stránkované resources
+    #[serde(default = "default_result_chunk_size")]
+    pub result_chunk_size: usize,
+    /// Sleduje soubor config.* a po jeho změně konfiguraci znovu načte (SIGHUP funguje vždy)
+    #[serde(default)]
+    pub watch_config: bool,
+}
+
+fn default_http_host() -> String {
+    "127.0.0.1".to_string()
+}
+
+fn default_http_port() -> u16 {
+    8080
+}
+
+fn default_http_path() -> String {
+    "/mcp".to_string()
+}
+
+fn default_http_session_idle_timeout() -> u64 {
+    1800
+}
+
+fn default_http_max_sessions() -> usize {
+    100
+}
+
+fn default_max_message_size() -> usize {
+    4 * 1024 * 1024
+}
+
+fn default_result_chunk_size() -> usize {
+    100_000
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -27,6 +224,7 @@ pub struct ServerConfig {
 pub enum TransportType {
     Stdio,
     Websocket,
+    Http,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,6 +238,50 @@ pub struct EasyProjectConfig {
     pub client_secret: Option<String>,
     pub redirect_uri: Option<String>,
     pub scopes: Vec<String>,
+    /// Přihlašovací jméno pro auth_type = 'session'
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Heslo pro auth_type = 'session' (doporučujeme nastavit přes ENV)
+    #[serde(default)]
+    pub password: Option<String>,
+    /// Cesta k přihlašovacímu formuláři pro session autentifikaci
+    #[serde(default = "default_login_path")]
+    pub login_path: String,
+    /// Při startu ověří dostupnost instance a přihlášení (výsledek jen zaloguje)
+    #[serde(default = "default_check_connection_on_startup")]
+    pub check_connection_on_startup: bool,
+    /// Název instance pro argument `instance` v tools, pokud je nastaveno více instancí
+    #[serde(default = "default_instance_name")]
+    pub instance_name: String,
+}
+
+fn default_login_path() -> String {
+    "/login".to_string()
+}
+
+fn default_instance_name() -> String {
+    "default".to_string()
+}
+
+/// Další instance EasyProject; neuvedené hodnoty se převezmou ze sekce [easyproject]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InstanceConfig {
+    pub base_url: String,
+    #[serde(default)]
+    pub auth_type: Option<AuthType>,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Název environment proměnné s API klíčem (má přednost před api_key)
+    #[serde(default)]
+    pub api_key_env: Option<String>,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+}
+
+fn default_check_connection_on_startup() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -56,6 +298,57 @@ pub struct HttpConfig {
     pub max_retries: u32,
     pub retry_delay_seconds: u64,
     pub user_agent: String,
+    /// Cesta k souboru pro zachytávání HTTP komunikace (vypnuto, pokud není nastaveno)
+    #[serde(default)]
+    pub capture_path: Option<String>,
+    /// Velikost capture souboru v bajtech, po jejímž překročení se soubor rotuje
+    #[serde(default = "default_capture_max_bytes")]
+    pub capture_max_bytes: u64,
+    /// Maximální počet znaků zapsaných z těla požadavku/odpovědi
+    #[serde(default = "default_capture_body_limit")]
+    pub capture_body_limit: usize,
+    /// Nejvyšší počet nečinných spojení v poolu na jeden host
+    #[serde(default = "default_pool_max_idle_per_host")]
+    pub pool_max_idle_per_host: usize,
+    /// Doba, po které se nečinné spojení z poolu zavře (0 = nezavírat)
+    #[serde(default = "default_pool_idle_timeout_seconds")]
+    pub pool_idle_timeout_seconds: u64,
+    /// Interval TCP keep-alive v sekundách (0 = vypnuto)
+    #[serde(default = "default_tcp_keepalive_seconds")]
+    pub tcp_keepalive_seconds: u64,
+    /// Timeout navázání spojení v sekundách (0 = jen celkový timeout_seconds)
+    #[serde(default = "default_connect_timeout_seconds")]
+    pub connect_timeout_seconds: u64,
+    /// Komunikovat rovnou přes HTTP/2 bez vyjednání (jen pro servery, které HTTP/2 určitě podporují)
+    #[serde(default)]
+    pub http2_prior_knowledge: bool,
+    /// Interval HTTP/2 PING pro udržení spojení v sekundách (0 = vypnuto)
+    #[serde(default)]
+    pub http2_keep_alive_seconds: u64,
+}
+
+fn default_capture_max_bytes() -> u64 {
+    10 * 1024 * 1024
+}
+
+fn default_capture_body_limit() -> usize {
+    4000
+}
+
+fn default_pool_max_idle_per_host() -> usize {
+    8
+}
+
+fn default_pool_idle_timeout_seconds() -> u64 {
+    90
+}
+
+fn default_tcp_keepalive_seconds() -> u64 {
+    60
+}
+
+fn default_connect_timeout_seconds() -> u64 {
+    10
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +356,13 @@ pub struct RateLimitingConfig {
     pub enabled: bool,
     pub requests_per_minute: u32,
     pub burst_size: u32,
+    /// Nejvyšší počet požadavků čekajících ve frontě na rate limiter (0 = bez limitu)
+    #[serde(default = "default_max_queue_depth")]
+    pub max_queue_depth: usize,
+}
+
+fn default_max_queue_depth() -> usize {
+    200
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,20 +374,125 @@ pub struct CacheConfig {
     pub user_ttl: u64,
     pub issue_ttl: u64,
     pub time_entry_ttl: u64,
+    /// TTL cache výsledků tools v sekundách (0 = vypnuto)
+    #[serde(default = "default_tool_result_ttl")]
+    pub tool_result_ttl: u64,
+    /// Na pozadí přednačte další stránku seznamu, pokud výsledek hlásí has_more
+    #[serde(default)]
+    pub prefetch_next_page: bool,
+    /// GET požadavky posílají If-None-Match / If-Modified-Since a odpověď 304 berou z uložených dat
+    #[serde(default = "default_conditional_requests")]
+    pub conditional_requests: bool,
+    /// Zpřístupní tool clear_cache pro ruční vymazání cache
+    #[serde(default)]
+    pub enable_clear_tool: bool,
+    /// Po startu na pozadí přednačte do cache často používaná data
+    #[serde(default)]
+    pub warm_up: bool,
+    /// Co se má při warm-upu přednačíst
+    #[serde(default = "default_warm_up_targets")]
+    pub warm_up_targets: Vec<WarmUpTarget>,
+}
+
+/// Data přednačítaná do cache po startu serveru
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarmUpTarget {
+    /// První stránka projektů (jako list_projects bez parametrů)
+    Projects,
+    /// Aktuální uživatel a první stránka uživatelů
+    Users,
+    /// Fronty, stavy a priority úkolů
+    Enumerations,
+    /// Aktivity časových záznamů
+    Activities,
+}
+
+fn default_warm_up_targets() -> Vec<WarmUpTarget> {
+    vec![WarmUpTarget::Projects, WarmUpTarget::Users, WarmUpTarget::Enumerations, WarmUpTarget::Activities]
+}
+
+fn default_conditional_requests() -> bool {
+    true
+}
+
+fn default_tool_result_ttl() -> u64 {
+    30
+}
+
+impl CacheConfig {
+    /// TTL záznamu cache API klienta podle typu entity (ostatní typy mají ttl_seconds)
+    pub fn ttl_for_entity(&self, entity_type: &str) -> Duration {
+        let seconds = match entity_type {
+            "project" => self.project_ttl,
+            "user" => self.user_ttl,
+            "issue" => self.issue_ttl,
+            "time_entry" => self.time_entry_ttl,
+            _ => self.ttl_seconds,
+        };
+        Duration::from_secs(seconds)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
+    /// Úroveň nebo filtr ve formátu RUST_LOG (např. "info" nebo "easyproject_mcp_server=debug")
     pub level: String,
     pub format: LogFormat,
-    pub target: String,
+    pub target: LogTarget,
+    /// Soubor logu pro target = "file"
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+    /// Rotace souboru logu
+    #[serde(default)]
+    pub rotation: LogRotation,
+    /// Velikost souboru logu v MB, po které se při rotation = "size" založí nový
+    #[serde(default = "default_log_max_size_mb")]
+    pub max_size_mb: u64,
+    /// Počet ponechaných starších souborů logu (0 = bez omezení u časové rotace)
+    #[serde(default = "default_log_max_files")]
+    pub max_files: usize,
+}
+
+fn default_log_max_size_mb() -> u64 {
+    10
+}
+
+fn default_log_max_files() -> usize {
+    7
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRotation {
+    /// Jeden soubor bez rotace
+    Never,
+    Hourly,
+    /// Nový soubor každý den, k názvu se přidá datum
+    #[default]
+    Daily,
+    /// Nový soubor po dosažení max_size_mb
+    Size,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum LogFormat {
+    /// Jeden JSON objekt na řádek včetně polí aktuálního span (correlation_id, tool)
     Json,
+    /// Víceřádkový čitelný výpis
     Pretty,
+    /// Jednořádkový čitelný výpis
+    Compact,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogTarget {
+    Stderr,
+    /// Při stdio transportu se místo stdout použije stderr, stdout patří JSON-RPC zprávám
+    Stdout,
+    File,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +503,66 @@ pub struct ToolsConfig {
     pub time_entries: TimeEntryToolConfig,
     pub reports: ReportToolConfig,
     pub milestones: MilestoneToolConfig,
+    #[serde(default)]
+    pub raw_api: RawApiToolConfig,
+    /// Při startu ověří oprávnění API klíče a označí nedostupné tools
+    #[serde(default = "default_probe_permissions")]
+    pub probe_permissions: bool,
+    /// Nedostupné tools místo označení úplně skryje ze seznamu
+    #[serde(default)]
+    pub hide_unavailable_tools: bool,
+    /// Nejvyšší počet záznamů načtených seznamem s fetch_all = true
+    #[serde(default = "default_fetch_all_max_records")]
+    pub fetch_all_max_records: u32,
+    /// Rozpočet velikosti textu výsledku tool v bajtech, větší výsledky se dělí na stránkované resources (0 = vypnuto)
+    #[serde(default = "default_max_response_bytes")]
+    pub max_response_bytes: usize,
+    /// Nejdelší doba běhu jednoho volání tool v sekundách, pak se vrátí chyba timeout (0 = bez limitu)
+    #[serde(default = "default_tool_timeout_seconds")]
+    pub tool_timeout_seconds: u64,
+    /// Vracet vedle textu i strukturovaná data výsledku (structuredContent)
+    #[serde(default = "default_structured_content")]
+    pub structured_content: bool,
+    /// Tools, které structuredContent nevrací ani při zapnutém structured_content
+    #[serde(default)]
+    pub structured_content_exclude: Vec<String>,
+    /// Režim jen pro čtení - tools měnící data v EasyProject se vůbec neregistrují
+    #[serde(default)]
+    pub read_only: bool,
+    /// Názvy tools, které se mají registrovat (prázdný seznam = všechny z povolených kategorií)
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+    /// Názvy tools, které se nemají registrovat ani při povolené kategorii
+    #[serde(default)]
+    pub denylist: Vec<String>,
+}
+
+impl ToolsConfig {
+    /// Tool je povolený seznamy allowlist a denylist (denylist má přednost)
+    pub fn is_tool_allowed(&self, name: &str) -> bool {
+        (self.allowlist.is_empty() || self.allowlist.iter().any(|allowed| allowed == name))
+            && !self.denylist.iter().any(|denied| denied == name)
+    }
+}
+
+fn default_probe_permissions() -> bool {
+    true
+}
+
+fn default_fetch_all_max_records() -> u32 {
+    2000
+}
+
+fn default_max_response_bytes() -> usize {
+    100_000
+}
+
+fn default_tool_timeout_seconds() -> u64 {
+    120
+}
+
+fn default_structured_content() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,6 +570,19 @@ pub struct ProjectToolConfig {
     pub enabled: bool,
     pub include_archived: bool,
     pub default_limit: u32,
+    /// Názvy modulů projektu známé na instanci (pro validaci v set_project_modules)
+    #[serde(default = "default_known_modules")]
+    pub known_modules: Vec<String>,
+}
+
+fn default_known_modules() -> Vec<String> {
+    [
+        "issue_tracking", "time_tracking", "news", "documents", "files", "wiki",
+        "repository", "boards", "calendar", "gantt", "easy_gantt", "easy_wbs", "easy_money",
+    ]
+    .iter()
+    .map(|name| name.to_string())
+    .collect()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +591,16 @@ pub struct IssueToolConfig {
     pub default_limit: u32,
     pub include_attachments: bool,
     pub include_relations: bool,
+    /// Maximální velikost přílohy pro download_attachment a upload_attachment v bajtech
+    #[serde(default = "default_max_attachment_bytes")]
+    pub max_attachment_bytes: u64,
+    /// Adresář, ze kterého smí upload_attachment číst soubory (parametr file_path); bez nastavení vypnuto
+    #[serde(default)]
+    pub upload_dir: Option<String>,
+}
+
+fn default_max_attachment_bytes() -> u64 {
+    2 * 1024 * 1024
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -125,12 +613,61 @@ pub struct UserToolConfig {
 pub struct TimeEntryToolConfig {
     pub enabled: bool,
     pub default_limit: u32,
+    /// Před vytvořením záznamu zkontroluje duplicitu (stejný uživatel, den, úkol a podobné hodiny)
+    #[serde(default = "default_duplicate_check")]
+    pub duplicate_check: bool,
+}
+
+fn default_duplicate_check() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ReportToolConfig {
     pub enabled: bool,
     pub cache_ttl: u64,
+    /// Maximální počet záznamů zpracovaných při průchodu všemi stránkami (sestavy, číselníky)
+    #[serde(default = "default_max_scan_records")]
+    pub max_scan_records: u32,
+    /// Maximální počet detailních záznamů vrácených v sestavě při include_details = true
+    #[serde(default = "default_max_detail_records")]
+    pub max_detail_records: u32,
+    /// Podíl vyčerpaného rozpočtu, od kterého budget_burn_report hlásí varování
+    #[serde(default = "default_budget_warning_threshold")]
+    pub budget_warning_threshold: f64,
+    /// Podíl vyčerpaného rozpočtu, od kterého budget_burn_report hlásí kritický stav
+    #[serde(default = "default_budget_critical_threshold")]
+    pub budget_critical_threshold: f64,
+    /// Časové pásmo pro určení "dnes" při výpočtu úkolů po termínu (local, UTC, +02:00...)
+    #[serde(default = "default_report_timezone")]
+    pub timezone: String,
+    /// Pracovní hodiny za den pro odhad, zda zbývající práce stihne termín
+    #[serde(default = "default_working_hours_per_day")]
+    pub working_hours_per_day: f64,
+}
+
+fn default_report_timezone() -> String {
+    "local".to_string()
+}
+
+fn default_working_hours_per_day() -> f64 {
+    8.0
+}
+
+fn default_budget_warning_threshold() -> f64 {
+    0.8
+}
+
+fn default_budget_critical_threshold() -> f64 {
+    1.0
+}
+
+fn default_max_scan_records() -> u32 {
+    5000
+}
+
+fn default_max_detail_records() -> u32 {
+    100
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -139,55 +676,180 @@ pub struct MilestoneToolConfig {
     pub default_limit: u32,
 }
 
+/// Obecný GET na REST API (raw_api_request) pro endpointy, které server nemodeluje
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawApiToolConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Povolené cesty relativně k base_url, `*` odpovídá jednomu segmentu (např. `issues/*`)
+    #[serde(default = "default_raw_api_allowed_paths")]
+    pub allowed_paths: Vec<String>,
+}
+
+impl Default for RawApiToolConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            allowed_paths: default_raw_api_allowed_paths(),
+        }
+    }
+}
+
+fn default_raw_api_allowed_paths() -> Vec<String> {
+    [
+        "projects", "projects/*", "issues", "issues/*", "trackers", "issue_statuses",
+        "enumerations/*", "custom_fields", "roles", "versions/*", "news", "queries",
+    ]
+    .iter()
+    .map(|path| path.to_string())
+    .collect()
+}
+
+/// Přepsání konfigurace z příkazové řádky, platí i pro znovunačtení konfigurace za běhu
+#[derive(Debug, Clone, Default)]
+pub struct CliOverrides {
+    /// Konfigurační soubor místo config.* v pracovním adresáři
+    pub config_path: Option<PathBuf>,
+    /// Přepsané klíče jako (klíč, hodnota), např. ("server.transport", "http")
+    pub values: Vec<(String, String)>,
+}
+
+static CLI_OVERRIDES: OnceLock<CliOverrides> = OnceLock::new();
+
+impl CliOverrides {
+    /// Nastaví přepsání pro celý běh procesu (před prvním načtením konfigurace)
+    pub fn install(self) {
+        let _ = CLI_OVERRIDES.set(self);
+    }
+
+    pub fn current() -> Option<&'static CliOverrides> {
+        CLI_OVERRIDES.get()
+    }
+}
+
 impl AppConfig {
     /// Načte konfiguraci ze souboru a environment proměnných
     pub fn load() -> Result<Self> {
-        // Zkusí načíst konfiguraci normálně
-        let result = Self::try_load_config();
+        Self::load_with_diagnostics().map(|(config, _)| config)
+    }
+    
+    /// Načte konfiguraci a vrátí i diagnostiku načtení
+    ///
+    /// Neplatné hodnoty se nahradí výchozími po jednotlivých klíčích, diagnostika uvádí
+    /// které klíče selhaly, které jsou neznámé, které mají výchozí hodnotu a které přepsalo prostředí.
+    pub fn load_with_diagnostics() -> Result<(Self, ConfigDiagnostics)> {
+        let mut diagnostics = ConfigDiagnostics::default();
+        diagnostics.collect_env_overrides();
         
-        match result {
-            Ok(config) => Ok(config),
-            Err(_) => {
-                // Při chybě použije výchozí konfiguraci
-                tracing::warn!("Použita výchozí konfigurace kvůli chybě deserializace");
-                let mut config = Self::default();
-                
-                // Nastaví API klíč z environment proměnné
-                if let Ok(api_key) = std::env::var("EASYPROJECT_API_KEY") {
-                    config.easyproject.api_key = Some(api_key);
+        let settings = match Self::build_settings() {
+            Ok(settings) => settings,
+            Err(e) => {
+                // Zdroj nejde ani přečíst (např. syntaktická chyba) - výchozí konfigurace
+                // s přepsáním z environment proměnných a příkazové řádky
+                diagnostics.load_error = Some(format!("{:#}", e));
+                diagnostics.defaults_only = true;
+                let mut overrides: Vec<(String, String)> = diagnostics::ENV_KEY_OVERRIDES.iter()
+                    .filter_map(|(variable, key)| Some((key.to_string(), std::env::var(variable).ok()?)))
+                    .collect();
+                if let Some(cli) = CliOverrides::current() {
+                    overrides.extend(cli.values.iter().cloned());
                 }
+                let config = diagnostics.recover_overrides(&overrides);
                 
-                // Nastaví base URL z environment proměnné
-                if let Ok(base_url) = std::env::var("EASYPROJECT_BASE_URL") {
-                    config.easyproject.base_url = base_url;
-                }
-                
-                Ok(config)
+                return Ok((config, diagnostics));
             }
-        }
+        };
+        
+        let raw: serde_json::Value = settings.clone()
+            .try_deserialize()
+            .context("Nepodařilo se přečíst hodnoty konfigurace")?;
+        
+        let config = match settings.try_deserialize::<AppConfig>() {
+            Ok(config) => {
+                diagnostics.compare_with_defaults(&raw);
+                config
+            }
+            Err(e) => {
+                diagnostics.load_error = Some(format!("Nepodařilo se deserializovat konfiguraci: {}", e));
+                diagnostics.recover(&raw)
+            }
+        };
+        
+        Ok((config, diagnostics))
     }
     
-    fn try_load_config() -> Result<Self> {
-        let settings = config::Config::builder()
-            .add_source(config::File::with_name("config").required(false))
-            .add_source(config::Environment::with_prefix("EASYPROJECT_MCP"));
-
-        // Přepsat API klíč z environment proměnné pokud existuje
-        let settings = if let Ok(api_key) = std::env::var("EASYPROJECT_API_KEY") {
-            settings.set_override("easyproject.api_key", api_key)
-                .context("Nepodařilo se nastavit API klíč z environment proměnné")?
-        } else {
-            settings
+    fn build_settings() -> Result<config::Config> {
+        let cli = CliOverrides::current();
+        let file = match cli.and_then(|cli| cli.config_path.as_deref()) {
+            Some(path) => config::File::from(path).required(true),
+            None => config::File::with_name("config").required(false),
         };
+        let settings = config::Config::builder()
+            .add_source(file)
+            .add_source(config::Environment::with_prefix(diagnostics::ENV_PREFIX));
+
+        // Přepsat klíče z environment proměnných (API klíč, URL, přihlašovací údaje)
+        let mut settings = settings;
+        for (variable, key) in diagnostics::ENV_KEY_OVERRIDES {
+            if let Ok(value) = std::env::var(variable) {
+                settings = settings.set_override(*key, value)
+                    .with_context(|| format!("Nepodařilo se nastavit {} z environment proměnné", variable))?;
+            }
+        }
 
-        let settings = settings.build()
-            .context("Nepodařilo se načíst konfiguraci")?;
+        // Parametry příkazové řádky mají přednost před souborem i prostředím
+        for (key, value) in cli.map(|cli| cli.values.as_slice()).unwrap_or_default() {
+            settings = settings.set_override(key.as_str(), value.as_str())
+                .with_context(|| format!("Nepodařilo se nastavit {} z příkazové řádky", key))?;
+        }
 
-        let config: AppConfig = settings
-            .try_deserialize()
-            .context("Nepodařilo se deserializovat konfiguraci")?;
+        settings.build()
+            .context("Nepodařilo se načíst konfiguraci")
+    }
 
-        Ok(config)
+    /// Kopie konfigurace se zamaskovanými hesly a klíči (pro výpis)
+    pub fn redacted(&self) -> Self {
+        let mask = |secret: &Option<String>| secret.as_ref().map(|_| "***".to_string());
+        let mut config = self.clone();
+        config.easyproject.api_key = mask(&self.easyproject.api_key);
+        config.easyproject.client_secret = mask(&self.easyproject.client_secret);
+        config.easyproject.password = mask(&self.easyproject.password);
+        for instance in config.instances.values_mut() {
+            instance.api_key = mask(&instance.api_key);
+            instance.password = mask(&instance.password);
+        }
+        config
+    }
+
+    /// Konfigurace dalších instancí z [instances] - kopie této konfigurace s jinou sekcí [easyproject]
+    pub fn instance_configs(&self) -> Result<Vec<(String, AppConfig)>> {
+        self.instances.iter()
+            .map(|(name, instance)| {
+                let mut easyproject = self.easyproject.clone();
+                easyproject.instance_name = name.clone();
+                easyproject.base_url = instance.base_url.clone();
+                if let Some(ref auth_type) = instance.auth_type {
+                    easyproject.auth_type = auth_type.clone();
+                }
+                if let Some(ref variable) = instance.api_key_env {
+                    let api_key = std::env::var(variable)
+                        .with_context(|| format!("Environment proměnná {} pro instanci '{}' není nastavená", variable, name))?;
+                    easyproject.api_key = Some(api_key);
+                } else if instance.api_key.is_some() {
+                    easyproject.api_key = instance.api_key.clone();
+                }
+                if instance.username.is_some() {
+                    easyproject.username = instance.username.clone();
+                    easyproject.password = instance.password.clone();
+                }
+                let config = AppConfig {
+                    easyproject,
+                    instances: BTreeMap::new(),
+                    ..self.clone()
+                };
+                Ok((name.clone(), config))
+            })
+            .collect()
     }
 
     /// Validuje konfiguraci
@@ -209,9 +871,26 @@ impl AppConfig {
                 }
             }
             AuthType::Session => {
-                // Session auth zatím není implementován
-                anyhow::bail!("Session autentifikace zatím není podporována");
+                if self.easyproject.username.as_deref().unwrap_or("").is_empty()
+                    || self.easyproject.password.as_deref().unwrap_or("").is_empty() {
+                    anyhow::bail!("username a password jsou povinné pro auth_type = 'session'");
+                }
+            }
+        }
+
+        if matches!(self.logging.target, LogTarget::File) && self.logging.path.is_none() {
+            anyhow::bail!("logging.path je povinný pro logging.target = 'file'");
+        }
+        if self.logging.rotation == LogRotation::Size && self.logging.max_size_mb == 0 {
+            anyhow::bail!("logging.max_size_mb musí být větší než 0 pro logging.rotation = 'size'");
+        }
+
+        // Validace dalších instancí
+        for (name, instance) in self.instance_configs()? {
+            if name == self.easyproject.instance_name {
+                anyhow::bail!("Instance '{}' má stejný název jako easyproject.instance_name", name);
             }
+            instance.validate().with_context(|| format!("Neplatná konfigurace instance '{}'", name))?;
         }
 
         // Validace WebSocket portu
@@ -221,6 +900,40 @@ impl AppConfig {
             }
         }
 
+        // Validace HTTP endpointu
+        if matches!(self.server.transport, TransportType::Http) && !self.server.http_path.starts_with('/') {
+            anyhow::bail!("http_path musí začínat znakem '/'");
+        }
+
+        // Validace časového pásma sestav
+        crate::utils::date_utils::parse_timezone(&self.tools.reports.timezone)
+            .map_err(|e| anyhow::anyhow!(e))?;
+        if !(self.tools.reports.working_hours_per_day > 0.0 && self.tools.reports.working_hours_per_day <= 24.0) {
+            anyhow::bail!("working_hours_per_day musí být v rozsahu (0, 24]");
+        }
+
+        // Validace seznamů povolených tools
+        if let Some(name) = self.tools.allowlist.iter().find(|name| self.tools.denylist.contains(name)) {
+            anyhow::bail!("Tool '{}' je současně v tools.allowlist i tools.denylist", name);
+        }
+
+        // Validace plánovaných sestav
+        if self.scheduler.enabled {
+            for report in &self.scheduler.reports {
+                crate::scheduler::cron::CronSchedule::parse(&report.cron)
+                    .map_err(|e| anyhow::anyhow!("Neplatný cron výraz pro projekt {}: {}", report.project_id, e))?;
+            }
+        }
+
+        // Validace limitů zpráv
+        if self.server.result_chunk_size == 0 {
+            anyhow::bail!("result_chunk_size musí být větší než 0");
+        }
+
+        if self.server.result_chunk_size >= self.server.max_message_size {
+            anyhow::bail!("result_chunk_size musí být menší než max_message_size");
+        }
+
         // Validace HTTP nastavení
         if self.http.timeout_seconds == 0 {
             anyhow::bail!("timeout_seconds musí být větší než 0");
@@ -245,14 +958,7 @@ impl AppConfig {
 
     /// Vrátí TTL pro cache podle typu entity
     pub fn cache_ttl_for_entity(&self, entity_type: &str) -> Duration {
-        let seconds = match entity_type {
-            "project" => self.cache.project_ttl,
-            "user" => self.cache.user_ttl,
-            "issue" => self.cache.issue_ttl,
-            "time_entry" => self.cache.time_entry_ttl,
-            _ => self.cache.ttl_seconds,
-        };
-        Duration::from_secs(seconds)
+        self.cache.ttl_for_entity(entity_type)
     }
 }
 
@@ -264,6 +970,15 @@ impl Default for AppConfig {
                 version: "1.0.0".to_string(),
                 transport: TransportType::Stdio,
                 websocket_port: Some(8080),
+                http_host: default_http_host(),
+                http_port: default_http_port(),
+                http_path: default_http_path(),
+                http_allowed_origins: Vec::new(),
+                http_session_idle_timeout_seconds: default_http_session_idle_timeout(),
+                http_max_sessions: default_http_max_sessions(),
+                max_message_size: default_max_message_size(),
+                result_chunk_size: default_result_chunk_size(),
+                watch_config: false,
             },
             easyproject: EasyProjectConfig {
                 base_url: "https://your-easyproject-instance.com".to_string(),
@@ -275,17 +990,32 @@ impl Default for AppConfig {
                 client_secret: None,
                 redirect_uri: None,
                 scopes: vec![],
+                username: None,
+                password: None,
+                login_path: default_login_path(),
+                check_connection_on_startup: default_check_connection_on_startup(),
+                instance_name: default_instance_name(),
             },
             http: HttpConfig {
                 timeout_seconds: 30,
                 max_retries: 3,
                 retry_delay_seconds: 1,
                 user_agent: "EasyProject-MCP-Server/1.0.0".to_string(),
+                capture_path: None,
+                capture_max_bytes: default_capture_max_bytes(),
+                capture_body_limit: default_capture_body_limit(),
+                pool_max_idle_per_host: default_pool_max_idle_per_host(),
+                pool_idle_timeout_seconds: default_pool_idle_timeout_seconds(),
+                tcp_keepalive_seconds: default_tcp_keepalive_seconds(),
+                connect_timeout_seconds: default_connect_timeout_seconds(),
+                http2_prior_knowledge: false,
+                http2_keep_alive_seconds: 0,
             },
             rate_limiting: RateLimitingConfig {
                 enabled: true,
                 requests_per_minute: 60,
                 burst_size: 10,
+                max_queue_depth: default_max_queue_depth(),
             },
             cache: CacheConfig {
                 enabled: true,
@@ -295,23 +1025,36 @@ impl Default for AppConfig {
                 user_ttl: 1800,
                 issue_ttl: 60,
                 time_entry_ttl: 30,
+                tool_result_ttl: default_tool_result_ttl(),
+                prefetch_next_page: false,
+                conditional_requests: default_conditional_requests(),
+                enable_clear_tool: false,
+                warm_up: false,
+                warm_up_targets: default_warm_up_targets(),
             },
             logging: LoggingConfig {
                 level: "info".to_string(),
-                format: LogFormat::Json,
-                target: "stdout".to_string(),
+                format: LogFormat::Compact,
+                target: LogTarget::Stderr,
+                path: None,
+                rotation: LogRotation::default(),
+                max_size_mb: default_log_max_size_mb(),
+                max_files: default_log_max_files(),
             },
             tools: ToolsConfig {
                 projects: ProjectToolConfig {
                     enabled: true,
                     include_archived: false,
                     default_limit: 25,
+                    known_modules: default_known_modules(),
                 },
                 issues: IssueToolConfig {
                     enabled: true,
                     default_limit: 25,
                     include_attachments: false,
                     include_relations: false,
+                    max_attachment_bytes: default_max_attachment_bytes(),
+                    upload_dir: None,
                 },
                 users: UserToolConfig {
                     enabled: true,
@@ -320,16 +1063,39 @@ impl Default for AppConfig {
                 time_entries: TimeEntryToolConfig {
                     enabled: true,
                     default_limit: 25,
+                    duplicate_check: default_duplicate_check(),
                 },
                 reports: ReportToolConfig {
                     enabled: true,
                     cache_ttl: 3600,
+                    max_scan_records: default_max_scan_records(),
+                    max_detail_records: default_max_detail_records(),
+                    budget_warning_threshold: default_budget_warning_threshold(),
+                    budget_critical_threshold: default_budget_critical_threshold(),
+                    timezone: default_report_timezone(),
+                    working_hours_per_day: default_working_hours_per_day(),
                 },
                 milestones: MilestoneToolConfig {
                     enabled: true,
                     default_limit: 25,
                 },
+                raw_api: RawApiToolConfig::default(),
+                probe_permissions: default_probe_permissions(),
+                hide_unavailable_tools: false,
+                fetch_all_max_records: default_fetch_all_max_records(),
+                max_response_bytes: default_max_response_bytes(),
+                tool_timeout_seconds: default_tool_timeout_seconds(),
+                structured_content: default_structured_content(),
+                structured_content_exclude: Vec::new(),
+                read_only: false,
+                allowlist: Vec::new(),
+                denylist: Vec::new(),
             },
+            scheduler: SchedulerConfig::default(),
+            snapshots: SnapshotConfig::default(),
+            localization: LocalizationConfig::default(),
+            audit: AuditConfig::default(),
+            instances: BTreeMap::new(),
         }
     }
 } 
\ No newline at end of file
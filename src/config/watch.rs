@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use super::CliOverrides;
+
+/// Přípony, se kterými se načítá soubor "config" (viz `AppConfig::build_settings`)
+const CONFIG_FILE_EXTENSIONS: &[&str] = &["toml", "json", "yaml", "yml", "ini", "ron", "json5"];
+
+/// Interval kontroly změny souboru konfigurace
+const WATCH_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Důvod znovunačtení konfigurace
+#[derive(Debug, Clone)]
+pub enum ReloadTrigger {
+    /// Proces dostal SIGHUP
+    Signal,
+    /// Změnil se soubor konfigurace
+    FileChanged(PathBuf),
+}
+
+/// Spustí úlohy, které hlásí požadavky na znovunačtení konfigurace
+///
+/// SIGHUP se sleduje vždy (jen na unixu), soubor konfigurace jen při `watch_file`.
+pub fn spawn_reload_triggers(watch_file: bool) -> mpsc::UnboundedReceiver<ReloadTrigger> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+
+    #[cfg(unix)]
+    {
+        let sender = sender.clone();
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(mut hangup) => {
+                tokio::spawn(async move {
+                    while hangup.recv().await.is_some() {
+                        if sender.send(ReloadTrigger::Signal).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+            Err(e) => warn!("Nelze zaregistrovat obsluhu SIGHUP: {}", e),
+        }
+    }
+
+    if watch_file {
+        tokio::spawn(async move {
+            let mut known = config_file_state();
+            let mut interval = tokio::time::interval(WATCH_INTERVAL);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                let current = config_file_state();
+                if let Some((path, _)) = current.iter().find(|entry| !known.contains(entry)) {
+                    debug!("Změna souboru konfigurace {}", path.display());
+                    if sender.send(ReloadTrigger::FileChanged(path.clone())).is_err() {
+                        break;
+                    }
+                }
+                known = current;
+            }
+        });
+    }
+
+    receiver
+}
+
+/// Existující soubory konfigurace (--config nebo config.*) s časem poslední změny
+fn config_file_state() -> Vec<(PathBuf, Option<SystemTime>)> {
+    let candidates: Vec<PathBuf> = match CliOverrides::current().and_then(|cli| cli.config_path.clone()) {
+        Some(path) => vec![path],
+        None => CONFIG_FILE_EXTENSIONS.iter()
+            .map(|extension| PathBuf::from(format!("config.{}", extension)))
+            .collect(),
+    };
+    candidates.into_iter()
+        .filter_map(|path| {
+            let metadata = std::fs::metadata(&path).ok()?;
+            Some((path, metadata.modified().ok()))
+        })
+        .collect()
+}
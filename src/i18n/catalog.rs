@@ -0,0 +1,385 @@
+/// Anglické popisy tools podle názvu tool
+pub(super) const TOOL_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("get_issue_enumerations", "Gets enumerations (status, priority, tracker) for filtering issues. \n\nThe tool loads enumerations from the API (on older instances it scans issues internally) and returns only a compact list of IDs and names. No large data sets are returned into the LLM context. \n\nUsage: Call before list_issues with the status_id, priority_id or tracker_id filters."),
+    ("list_custom_fields", "Gets custom field definitions (ID, format, required flag, possible values) for setting custom_field_values in create_issue and update_issue. Requires administrator rights; without them use get_project with include issue_custom_fields."),
+    ("list_issues", "Gets a list of issues with full-text search and advanced filtering. \n\nUsage: \n- To find issues by subject or description use 'search' \n- To filter issues of a specific user use 'assigned_to_id' \n- To filter issues in a project use 'project_id' \n- To find the correct IDs for status_id, priority_id and tracker_id call 'get_issue_enumerations' first \n\nExample: \n1. Call get_issue_enumerations to get the enumerations \n2. Use list_issues with specific IDs: {\"search\": \"login\", \"status_id\": 2, \"priority_id\": 4}"),
+    ("get_issue", "Gets the details of a specific issue by ID"),
+    ("create_issue", "Creates a new issue in EasyProject"),
+    ("create_issues_batch", "Creates several issues at once (e.g. breaking a feature down into subtasks). Link to a parent issue from the same batch via parent_index (index in the issues array) - parent issues are created first. Shared values (project, tracker, status, priority) can be given once in defaults."),
+    ("update_issue", "Updates an existing issue in EasyProject"),
+    ("delete_issue", "Deletes an issue including its subtasks and time entries (WARNING: This operation is irreversible! Ask the user for confirmation before deleting; to finish work on an issue prefer complete_task)"),
+    ("list_issue_attachments", "Lists issue attachments (name, size, type, author); download the attachment content via download_attachment"),
+    ("download_attachment", "Downloads attachment content - images are returned as images, text files as text and everything else as base64 (size is limited by tools.issues.max_attachment_bytes)"),
+    ("upload_attachment", "Uploads a file and attaches it to an issue. Provide the content with exactly one of content (text), content_base64 (binary data) or file_path (file in tools.issues.upload_dir)"),
+    ("add_watcher", "Adds a user to the issue watchers - they will receive notifications about its changes"),
+    ("remove_watcher", "Removes a user from the issue watchers"),
+    ("assign_issue", "Assigns an issue to a specific user - by ID (assigned_to_id) or by name, login or email (assigned_to)"),
+    ("complete_task", "Marks an issue as done (sets done_ratio to 100%)"),
+    ("transition_issue", "Moves an issue to another status by status name (e.g. 'Resolved') or ID. Respects the workflow - allows only transitions available for the tracker and user role when the instance reports them. Optionally adds a comment."),
+    ("get_issue_context", "Gets the complete issue context in one call - the issue, parent issue, subtasks, relations, latest history entries and latest time entries. The output is shortened and compact, suitable as a basis for further work on the issue."),
+    ("bulk_edit_descriptions", "Replaces text in the descriptions of a project's issues in bulk (e.g. broken links or renamed terms). Always run a preview first (dry_run: true), which shows the diff for each issue and returns a confirm_token. Changes are saved only by a call with dry_run: false and this token."),
+    ("search_issues", "Full-text search of issues in subject and description. Returns a compact list ranked by relevance (ID, subject, status, assignee). An issue number (e.g. '#123') finds the issue directly. Use list_issues for filtering by status, tracker or priority."),
+    ("clone_issue", "Creates a copy of an existing issue in the same or another project. Optionally carries over the description, custom fields, attachments, watchers and subtasks; the subject, assignee and milestone of the copy can be changed."),
+    ("export_issues_jsonl", "Exports all issues matching the filter into a JSON Lines file (one issue per line) in the export directory. Issues are fetched page by page respecting the rate limit, suitable for BI tools and data pipelines."),
+    ("list_milestones", "Gets a list of all milestones (versions) in EasyProject with filtering"),
+    ("list_assignable_versions", "Returns the versions (milestones) that can be set as fixed_version_id on issues in the given project - open project versions and versions shared from other projects"),
+    ("get_milestone", "Gets the details of a specific milestone by ID"),
+    ("create_milestone", "Creates a new milestone in the given project"),
+    ("update_milestone", "Updates an existing milestone"),
+    ("delete_milestone", "Deletes an existing milestone"),
+    ("list_projects", "Gets a list of all projects in EasyProject with full-text search, filtering and sorting. \n\nUsage: To find projects by name or identifier use the 'search' parameter. \nExample: search='Web project' finds all projects containing this text in the name or identifier."),
+    ("get_project", "Gets the details of a specific project by ID"),
+    ("create_project", "Creates a new project in EasyProject"),
+    ("update_project", "Updates an existing project in EasyProject"),
+    ("delete_project", "Deletes a project from EasyProject (WARNING: This operation is irreversible!)"),
+    ("get_project_modules", "Lists project modules (issue tracking, time tracking, documents, wiki...) - enabled and available to enable"),
+    ("set_project_modules", "Enables or disables project modules (e.g. time_tracking, documents). Either sets the complete list (modules) or adds (enable) or removes (disable) individual modules."),
+    ("list_news", "Gets project news (announcements) from the newest - for an overview of recent events in reports and dashboards"),
+    ("list_project_files", "Gets project files (name, size, author, upload date) including download links; load the file content via download_attachment with the file ID"),
+    ("create_project_from_template", "Creates a new project from an existing project (template) - takes over trackers, modules, milestones, categories and optionally open issues as new issues with shifted dates. With dry_run it only returns the plan without changes."),
+    ("global_search", "Full-text search across issues, projects, wiki pages, news and documents in one call. Results can be limited to entity types and a project; use get_issue, get_project etc. for details."),
+    ("generate_project_report", "Generates a detailed project report including issue, time and user statistics"),
+    ("get_dashboard_data", "Gets aggregated dashboard data - overview of projects, issues and time entries"),
+    ("assignment_history", "Reconstructs from issue history (journals) when issues were assigned to or removed from a user in the given period. Suitable for retrospectives and resolving capacity disputes."),
+    ("budget_burn_report", "Compares the hours worked (or costs) of projects with the budget or the sum of issue estimates, computes the burn rate over the recent period and estimates the budget exhaustion date. Projects above the configured thresholds are flagged with a warning."),
+    ("list_generated_reports", "Lists project reports generated by the scheduler into the export directory (newest first). With file_name it returns the report content in Markdown or CSV format, for PDF only the file path."),
+    ("check_project_consistency", "Checks a project for common mistakes: subtasks due after their parent issue, closed parent issues with open subtasks, issues assigned to locked users and time logged on closed issues after they were closed. Returns a list of fixes with the recommended tool."),
+    ("create_baseline", "Saves a named project baseline - planned dates and estimates of all issues. Deviations from it are later evaluated by baseline_variance_report."),
+    ("baseline_variance_report", "Compares the current project state with a saved baseline (create_baseline): shifts of start and due dates, estimate changes and hours worked, added and missing issues. Without a baseline it lists the available baselines."),
+    ("generate_gantt_data", "Builds a project schedule for a Gantt chart: issues with start and due dates, parent issues and dependencies (precedes/blocks). Computes the critical path and the slack of each issue in working days, reports violated dependencies and optionally returns mermaid gantt text."),
+    ("find_overdue_issues", "Finds open issues past their due date and issues whose due date is at risk (the remaining estimate exceeds the working hours until the due date). Each issue has a severity score 0-100, the result is grouped by assignee and project."),
+    ("plan_capacity", "Capacity planning - compares the remaining estimate of each user's open assigned issues with their working capacity (business days in the period × hours per day) across the selected projects and flags over-allocated users."),
+    ("generate_timesheet", "Builds a timesheet for a period - a user × day (or week) matrix of hours worked with totals per user and column and a list of working days without logged time. Output as a markdown table, CSV or JSON."),
+    ("portfolio_overview", "Portfolio overview - compares all active projects in one table: status, open issues, overdue issues, unassigned issues, remaining estimate and hours worked in the period. Projects are ranked by risk (or by the chosen column)."),
+    ("team_velocity", "Computes team velocity - closed issues and hours worked per week or sprint over the last N periods, for the whole team, per user and per project. Returns averages and the trend (rising/falling/stable) from completed periods."),
+    ("get_permissions", "Shows which EasyProject API areas the configured API key can access and which tools are unavailable due to missing permissions"),
+    ("validate_config", "Reloads the configuration (config.toml and environment variables) and prints diagnostics: keys with invalid values, unknown keys (typos), keys with default values, environment overrides and the validation result"),
+    ("raw_api_request", "Generic GET request to the EasyProject REST API for endpoints without a dedicated tool. Only paths from tools.raw_api.allowed_paths are allowed; returns the JSON response"),
+    ("check_connection", "Checks the connection to EasyProject: instance availability, validity of the API key or login, response time and instance version (administrators only). Use it as the first step when other tools keep failing"),
+    ("get_server_info", "Shows the version and settings of this MCP server: the connected EasyProject instance (API key masked), enabled tool categories, cache and rate limiting settings and detected API capabilities. Useful when debugging multiple environments"),
+    ("get_audit_log", "Shows the audit of recent tool calls that change data in EasyProject: time, tool, arguments (passwords and tokens masked), ID of the created or changed entity, outcome and MCP client. Entries are newest first"),
+    ("cache_stats", "Shows API client cache statistics: number of entries, hits and misses by entity type, entries evicted after TTL or due to capacity, invalidations after writes and 304 responses to conditional requests. Also includes the TTL of each entity type to estimate data age"),
+    ("clear_cache", "Clears the API client cache so that subsequent queries are loaded directly from EasyProject. Without a parameter everything is cleared, with pattern only matching entries (e.g. 'issue_42', 'project_7' or 'issues_*')"),
+    ("get_instance_info", "Shows information about the connected EasyProject instance: version (if detectable), available extensions and project modules, counts of trackers, statuses and other enumerations and API limits. Helps find out why some tools or fields are not available on the instance"),
+    ("list_time_entries", "Gets a list of time entries with filtering by project, issue, user and date"),
+    ("get_time_entry", "Gets the details of a specific time entry by ID"),
+    ("create_time_entry", "Creates a new time entry for a project or issue"),
+    ("update_time_entry", "Updates an existing time entry - only the given fields are changed"),
+    ("delete_time_entry", "Deletes a time entry"),
+    ("move_time_entries", "Moves selected time entries to another issue or project (e.g. time logged on the wrong ticket). Entries can be selected by a list of IDs or by a filter (source issue/project, user, period). With dry_run=true it only shows what would be moved."),
+    ("log_time", "Quickly logs time on a project or issue (the default date is today)"),
+    ("log_time_bulk", "Logs time in bulk - a list of entries or the same hours on every business day of a period. Entries are created concurrently and the result reports failures per entry; successful entries stay created."),
+    ("list_users", "Gets a list of all users in EasyProject with full-text search and filtering. \n\nUsage: To find users by name or email use the 'search' parameter. To filter by status use 'status' (e.g. 'active' for active users). To filter by user type use 'user_type' (internal/external/client) or 'user_type_id'. \nExample: search='John Smith' finds all users containing this text in their name."),
+    ("get_user", "Gets the details of a specific user by ID"),
+    ("get_user_workload", "Gets a user's workload - overview of assigned issues and hours worked"),
+    ("get_my_work", "Work overview of the current user (API key owner) - who am I, open assigned issues, hours logged today and upcoming due dates. No need to know your own user ID."),
+    ("list_user_types", "Gets a list of EasyProject user types (internal, external, client...) including their IDs for filtering with list_users"),
+    ("list_roles", "Gets a list of roles including their IDs for assigning project members; with id it returns the role details including permissions"),
+];
+
+/// Anglické popisy parametrů podle českého originálu
+pub(super) const SCHEMA_DESCRIPTIONS: &[(&str, &str)] = &[
+    ("Volitelné ID projektu - vrátí jen trackery povolené v tomto projektu", "Optional project ID - returns only trackers enabled in this project"),
+    ("Jen pole daného typu entity (např. issue, project, time_entry, user)", "Only fields of the given entity type (e.g. issue, project, time_entry, user)"),
+    ("Hodnoty vlastních polí podle ID pole, např. {\"12\": \"hodnota\", \"15\": [\"a\", \"b\"]} (ID polí viz list_custom_fields nebo get_project s include issue_custom_fields)", "Custom field values by field ID, e.g. {\"12\": \"value\", \"15\": [\"a\", \"b\"]} (field IDs via list_custom_fields or get_project with include issue_custom_fields)"),
+    ("ID projektu pro filtrování úkolů", "Project ID for filtering issues"),
+    ("Maximální počet úkolů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)", "Maximum number of issues to return (default: 25; more than 100 are fetched page by page automatically)"),
+    ("Počet úkolů k přeskočení pro stránkování", "Number of issues to skip for pagination"),
+    ("Načte všechny úkoly od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)", "Fetches all issues from the offset page by page, limit is ignored (at most tools.fetch_all_max_records)"),
+    ("Dodatečné informace k zahrnutí", "Additional information to include"),
+    ("Fulltextové vyhledávání v názvech a popisech úkolů (např. 'implementace login')", "Full-text search in issue subjects and descriptions (e.g. 'login implementation')"),
+    ("Řazení výsledků (např. 'priority:desc' nebo 'due_date'). Formát: 'pole' nebo 'pole:desc'", "Result ordering (e.g. 'priority:desc' or 'due_date'). Format: 'field' or 'field:desc'"),
+    ("ID uživatele pro filtrování úkolů přiřazených tomuto uživateli", "User ID for filtering issues assigned to this user"),
+    ("ID statusu pro filtrování úkolů (např. 1=Nový, 2=Probíhá, 3=Vyřešen)", "Status ID for filtering issues (e.g. 1=New, 2=In progress, 3=Resolved)"),
+    ("ID trackeru/typu úkolu (např. 1=Bug, 2=Feature, 3=Support)", "Tracker/issue type ID (e.g. 1=Bug, 2=Feature, 3=Support)"),
+    ("ID priority úkolu (např. 1=Nízká, 2=Normální, 3=Vysoká, 4=Urgentní)", "Issue priority ID (e.g. 1=Low, 2=Normal, 3=High, 4=Urgent)"),
+    ("Při zadaném project_id zahrnout i úkoly podprojektů (výchozí: podle nastavení EasyProject instance)", "With project_id, also include issues of subprojects (default: per EasyProject instance settings)"),
+    ("ID úkolu", "Issue ID"),
+    ("ID projektu (povinné)", "Project ID (required)"),
+    ("Zahrnout i uzavřené úkoly (výchozí: false)", "Include closed issues as well (default: false)"),
+    ("Přidat text diagramu ve formátu mermaid gantt (výchozí: false)", "Add the chart as mermaid gantt text (default: false)"),
+    ("Zahrnout úkoly, jejichž zbývající odhad nestihne termín (výchozí: true)", "Include issues whose remaining estimate will not meet the due date (default: true)"),
+    ("Pracovní hodiny za den pro výpočet ohrožených úkolů (výchozí: z konfigurace)", "Working hours per day for computing at-risk issues (default: from configuration)"),
+    ("Minimální skóre závažnosti 0-100 (výchozí: 0)", "Minimum severity score 0-100 (default: 0)"),
+    ("ID projektů (volitelné, jinak všechny projekty)", "Project IDs (optional, otherwise all projects)"),
+    ("ID uživatelů (volitelné, jinak všichni řešitelé)", "User IDs (optional, otherwise all assignees)"),
+    ("Začátek plánovaného období (formát: YYYY-MM-DD, výchozí: dnes)", "Start of the planned period (format: YYYY-MM-DD, default: today)"),
+    ("Konec plánovaného období (formát: YYYY-MM-DD, výchozí: 4 týdny od začátku)", "End of the planned period (format: YYYY-MM-DD, default: 4 weeks from the start)"),
+    ("Pracovní hodiny za den (výchozí: z konfigurace)", "Working hours per day (default: from configuration)"),
+    ("Podíl vytížení, od kterého je uživatel přetížený, např. 1.0 = 100 % (výchozí: 1.0)", "Utilization ratio from which a user is over-allocated, e.g. 1.0 = 100 % (default: 1.0)"),
+    ("ID projektu - tým tvoří členové projektu včetně těch bez záznamů (nepovinné)", "Project ID - the team consists of project members including those without entries (optional)"),
+    ("Sloupce výkazu: day (dny) nebo week (týdny) (výchozí: day)", "Timesheet columns: day or week (default: day)"),
+    ("Formát výstupu: markdown, csv nebo json (výchozí: markdown)", "Output format: markdown, csv or json (default: markdown)"),
+    ("Zahrnout časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)", "Include time entries of subprojects (default: per EasyProject instance settings)"),
+    ("ID projektů (volitelné, jinak všechny aktivní projekty)", "Project IDs (optional, otherwise all active projects)"),
+    ("Začátek období pro odpracované hodiny (formát: YYYY-MM-DD, výchozí: před 30 dny)", "Start of the period for hours worked (format: YYYY-MM-DD, default: 30 days ago)"),
+    ("Konec období pro odpracované hodiny (formát: YYYY-MM-DD, výchozí: dnes)", "End of the period for hours worked (format: YYYY-MM-DD, default: today)"),
+    ("Řazení: risk (riziko), overdue, open, hours nebo name (výchozí: risk)", "Sorting: risk, overdue, open, hours or name (default: risk)"),
+    ("Maximální počet projektů ve výsledku (výchozí: všechny)", "Maximum number of projects in the result (default: all)"),
+    ("Hledaný text (slova se hledají bez ohledu na diakritiku a velikost písmen)", "Search text (words are matched regardless of diacritics and letter case)"),
+    ("Hledat jen v projektu (nepovinné)", "Search only in the project (optional)"),
+    ("Jen otevřené úkoly (výchozí: true)", "Only open issues (default: true)"),
+    ("Při zadaném project_id hledat i v podprojektech (výchozí: podle nastavení EasyProject instance)", "With project_id, search subprojects as well (default: per EasyProject instance settings)"),
+    ("Maximální počet výsledků (výchozí: 20)", "Maximum number of results (default: 20)"),
+    ("Hledaný text", "Search text"),
+    ("Typy entit k prohledání (výchozí: všechny)", "Entity types to search (default: all)"),
+    ("Hledat jen v projektu a jeho podprojektech (nepovinné)", "Search only in the project and its subprojects (optional)"),
+    ("Hledat pouze v názvech (výchozí: false)", "Search in titles only (default: false)"),
+    ("Z úkolů vracet jen otevřené (výchozí: false)", "Return only open issues (default: false)"),
+    ("Maximální počet výsledků k vrácení (výchozí: 25)", "Maximum number of results to return (default: 25)"),
+    ("Počet výsledků k přeskočení pro stránkování", "Number of results to skip for pagination"),
+    ("ID projektu (volitelné, jinak všechny projekty)", "Project ID (optional, otherwise all projects)"),
+    ("ID uživatele (volitelné, jinak celý tým)", "User ID (optional, otherwise the whole team)"),
+    ("Délka období: week (týden) nebo sprint (výchozí: week)", "Period length: week or sprint (default: week)"),
+    ("Délka sprintu v týdnech pro period=sprint (výchozí: 2)", "Sprint length in weeks for period=sprint (default: 2)"),
+    ("Počet posledních období včetně probíhajícího (výchozí: 6)", "Number of recent periods including the current one (default: 6)"),
+    ("ID trackeru (povinné)", "Tracker ID (required)"),
+    ("ID statusu (povinné)", "Status ID (required)"),
+    ("ID priority (povinné)", "Priority ID (required)"),
+    ("Název úkolu (povinné)", "Issue subject (required)"),
+    ("Popis úkolu (může obsahovat HTML tagy pro formátování)", "Issue description (may contain HTML tags for formatting)"),
+    ("ID kategorie", "Category ID"),
+    ("ID verze/milníku", "Version/milestone ID"),
+    ("ID uživatele, kterému je úkol přiřazen", "ID of the user the issue is assigned to"),
+    ("ID nadřazeného úkolu", "Parent issue ID"),
+    ("Odhadované hodiny", "Estimated hours"),
+    ("Datum zahájení (YYYY-MM-DD)", "Start date (YYYY-MM-DD)"),
+    ("Termín dokončení (YYYY-MM-DD)", "Due date (YYYY-MM-DD)"),
+    ("Procento dokončení (0-100)", "Done ratio (0-100)"),
+    ("Popis úkolu", "Issue description"),
+    ("ID projektu (jinak z defaults)", "Project ID (otherwise from defaults)"),
+    ("ID trackeru (jinak z defaults)", "Tracker ID (otherwise from defaults)"),
+    ("ID statusu (jinak z defaults)", "Status ID (otherwise from defaults)"),
+    ("ID priority (jinak z defaults)", "Priority ID (otherwise from defaults)"),
+    ("ID řešitele", "Assignee ID"),
+    ("Index nadřazeného úkolu v poli issues", "Index of the parent issue in the issues array"),
+    ("ID existujícího nadřazeného úkolu", "ID of an existing parent issue"),
+    ("Společné hodnoty pro všechny úkoly: project_id, tracker_id, status_id, priority_id, assigned_to_id, fixed_version_id", "Shared values for all issues: project_id, tracker_id, status_id, priority_id, assigned_to_id, fixed_version_id"),
+    ("Po první chybě nevytvářet další úkoly (výchozí: false - pokračuje se, přeskočí se jen podúkoly nevytvořených úkolů)", "Stop creating issues after the first error (default: false - continues, skipping only subtasks of issues that were not created)"),
+    ("ID úkolu k aktualizaci (povinné)", "ID of the issue to update (required)"),
+    ("Nový název úkolu", "New issue subject"),
+    ("Nový popis úkolu (může obsahovat HTML tagy pro formátování)", "New issue description (may contain HTML tags for formatting)"),
+    ("Nové ID statusu", "New status ID"),
+    ("Nové ID priority", "New priority ID"),
+    ("ID uživatele, kterému přiřadit úkol", "ID of the user to assign the issue to"),
+    ("ID cílové verze/milníku (musí být dostupná v projektu úkolu, viz list_assignable_versions)", "Target version/milestone ID (must be available in the issue's project, see list_assignable_versions)"),
+    ("Nové procento dokončení (0-100)", "New done ratio (0-100)"),
+    ("Nové odhadované hodiny", "New estimated hours"),
+    ("Nové datum zahájení (YYYY-MM-DD)", "New start date (YYYY-MM-DD)"),
+    ("Nový termín dokončení (YYYY-MM-DD)", "New due date (YYYY-MM-DD)"),
+    ("ID úkolu ke smazání (povinné)", "ID of the issue to delete (required)"),
+    ("ID úkolu (povinné)", "Issue ID (required)"),
+    ("ID přílohy (povinné, viz list_issue_attachments)", "Attachment ID (required, see list_issue_attachments)"),
+    ("Název souboru přílohy (povinné, kromě file_path - výchozí je název souboru)", "Attachment file name (required, except with file_path - defaults to the file name)"),
+    ("Textový obsah souboru", "Text content of the file"),
+    ("Binární obsah souboru v base64", "Binary file content in base64"),
+    ("Cesta k souboru relativně k tools.issues.upload_dir", "File path relative to tools.issues.upload_dir"),
+    ("MIME typ přílohy (např. image/png)", "MIME type of the attachment (e.g. image/png)"),
+    ("Popis přílohy", "Attachment description"),
+    ("Komentář přidaný k úkolu spolu s přílohou", "Comment added to the issue together with the attachment"),
+    ("ID uživatele, který má úkol sledovat (povinné)", "ID of the user who should watch the issue (required)"),
+    ("ID sledujícího uživatele k odebrání (povinné)", "ID of the watcher to remove (required)"),
+    ("ID úkolu k přiřazení (povinné)", "ID of the issue to assign (required)"),
+    ("ID uživatele, kterému přiřadit úkol (povinné, pokud není zadáno assigned_to)", "ID of the user to assign the issue to (required unless assigned_to is given)"),
+    ("Řešitel zadaný jménem, loginem nebo emailem (alternativa k assigned_to_id, 'me' = aktuální uživatel)", "Assignee given by name, login or email (alternative to assigned_to_id, 'me' = current user)"),
+    ("Úkol nepřiřadí, jen zobrazí aktuální vytížení uživatele (otevřené úkoly a zbývající odhad hodin) včetně tohoto úkolu (výchozí: false)", "Does not assign the issue, only shows the user's current workload (open issues and remaining estimated hours) including this issue (default: false)"),
+    ("ID úkolu k označení jako dokončený (povinné)", "ID of the issue to mark as done (required)"),
+    ("Procento dokončení (výchozí: 100)", "Done ratio (default: 100)"),
+    ("Počet posledních záznamů historie (výchozí: 5)", "Number of latest history entries (default: 5)"),
+    ("Počet posledních časových záznamů (výchozí: 10)", "Number of latest time entries (default: 10)"),
+    ("Maximální délka popisu úkolu ve znacích (výchozí: 1500)", "Maximum issue description length in characters (default: 1500)"),
+    ("ID projektu, v jehož úkolech se hledá (povinné)", "ID of the project whose issues are searched (required)"),
+    ("Hledaný text nebo regulární výraz (povinné)", "Text or regular expression to find (required)"),
+    ("Náhrada (u regulárního výrazu lze použít skupiny $1, ${name}) (povinné)", "Replacement (with a regular expression, groups $1, ${name} can be used) (required)"),
+    ("Interpretovat 'find' jako regulární výraz (výchozí: false)", "Interpret 'find' as a regular expression (default: false)"),
+    ("Rozlišovat velká a malá písmena (výchozí: true)", "Case-sensitive matching (default: true)"),
+    ("Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)", "Include issues of subprojects (default: per EasyProject instance settings)"),
+    ("Maximální počet upravených úkolů; při více shodách se nic neuloží (výchozí: 50)", "Maximum number of modified issues; with more matches nothing is saved (default: 50)"),
+    ("Pouze náhled změn bez uložení (výchozí: true)", "Only preview changes without saving (default: true)"),
+    ("Token z náhledu, povinný pro dry_run: false", "Token from the preview, required for dry_run: false"),
+    ("ID projektu (bez zadání všechny projekty)", "Project ID (all projects if omitted)"),
+    ("Stav úkolů: 'open', 'closed', '*' (všechny) nebo ID stavu (výchozí: '*')", "Issue status: 'open', 'closed', '*' (all) or a status ID (default: '*')"),
+    ("ID trackeru", "Tracker ID"),
+    ("ID přiřazeného uživatele", "Assigned user ID"),
+    ("Jen úkoly změněné od data (YYYY-MM-DD) - pro přírůstkový export", "Only issues updated since the date (YYYY-MM-DD) - for incremental export"),
+    ("Fulltextové vyhledávání (easy_query_q)", "Full-text search (easy_query_q)"),
+    ("Maximální počet exportovaných úkolů (bez zadání všechny)", "Maximum number of exported issues (all if omitted)"),
+    ("Maximální počet milníků k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)", "Maximum number of milestones to return (default: 25; more than 100 are fetched page by page automatically)"),
+    ("Počet milníků k přeskočení pro stránkování", "Number of milestones to skip for pagination"),
+    ("ID projektu pro filtrování milníků", "Project ID for filtering milestones"),
+    ("Status milníku pro filtrování", "Milestone status for filtering"),
+    ("Volný text pro vyhledávání v milnících", "Free text to search in milestones"),
+    ("ID projektu úkolu (povinné)", "Project ID of the issue (required)"),
+    ("ID milníku", "Milestone ID"),
+    ("ID projektu, kde se má milník vytvořit", "ID of the project in which to create the milestone"),
+    ("Název milníku", "Milestone name"),
+    ("Popis milníku", "Milestone description"),
+    ("Datum začátku milníku (YYYY-MM-DD)", "Milestone start date (YYYY-MM-DD)"),
+    ("Datum ukončení milníku (YYYY-MM-DD)", "Milestone end date (YYYY-MM-DD)"),
+    ("Status milníku", "Milestone status"),
+    ("Nastavení sdílení milníku", "Milestone sharing setting"),
+    ("Zda je toto výchozí verze projektu", "Whether this is the project's default version"),
+    ("Externí ID pro integraci s jinými systémy", "External ID for integration with other systems"),
+    ("ID milníku k aktualizaci", "ID of the milestone to update"),
+    ("Nový název milníku", "New milestone name"),
+    ("Nový popis milníku", "New milestone description"),
+    ("Nové datum začátku milníku (YYYY-MM-DD)", "New milestone start date (YYYY-MM-DD)"),
+    ("Nové datum ukončení milníku (YYYY-MM-DD)", "New milestone end date (YYYY-MM-DD)"),
+    ("Nový status milníku", "New milestone status"),
+    ("Nové nastavení sdílení milníku", "New milestone sharing setting"),
+    ("Nové externí ID", "New external ID"),
+    ("ID milníku k smazání", "ID of the milestone to delete"),
+    ("Maximální počet projektů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)", "Maximum number of projects to return (default: 25; more than 100 are fetched page by page automatically)"),
+    ("Počet projektů k přeskočení pro stránkování", "Number of projects to skip for pagination"),
+    ("Načte všechny projekty od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)", "Fetches all projects from the offset page by page, limit is ignored (at most tools.fetch_all_max_records)"),
+    ("Zda zahrnout archivované projekty (výchozí: false)", "Whether to include archived projects (default: false)"),
+    ("Fulltextové vyhledávání v názvech a identifikátorech projektů (např. 'webový projekt')", "Full-text search in project names and identifiers (e.g. 'web project')"),
+    ("Řazení výsledků (např. 'name' nebo 'created_on:desc'). Formát: 'pole' nebo 'pole:desc'", "Result ordering (e.g. 'name' or 'created_on:desc'). Format: 'field' or 'field:desc'"),
+    ("ID projektu", "Project ID"),
+    ("Dodatečné informace k zahrnutí (trackers, issue_categories, enabled_modules, atd.)", "Additional information to include (trackers, issue_categories, enabled_modules, etc.)"),
+    ("Název projektu (povinné)", "Project name (required)"),
+    ("Popis projektu", "Project description"),
+    ("Unikátní identifikátor projektu", "Unique project identifier"),
+    ("URL domovské stránky projektu", "Project homepage URL"),
+    ("Zda je projekt veřejný", "Whether the project is public"),
+    ("ID nadřazeného projektu", "Parent project ID"),
+    ("Zda dědit členy z nadřazeného projektu", "Whether to inherit members from the parent project"),
+    ("Seznam ID trackerů povolených v projektu", "List of tracker IDs enabled in the project"),
+    ("Seznam názvů povolených modulů", "List of enabled module names"),
+    ("ID projektu k aktualizaci (povinné)", "ID of the project to update (required)"),
+    ("Nový název projektu", "New project name"),
+    ("Nový popis projektu", "New project description"),
+    ("Nový identifikátor projektu", "New project identifier"),
+    ("Nová URL domovské stránky", "New homepage URL"),
+    ("ID nového nadřazeného projektu", "ID of the new parent project"),
+    ("ID projektu k smazání (povinné)", "ID of the project to delete (required)"),
+    ("Úplný seznam modulů, které mají být povolené (nahradí současný stav)", "Complete list of modules that should be enabled (replaces the current state)"),
+    ("Moduly k povolení", "Modules to enable"),
+    ("Moduly k zakázání", "Modules to disable"),
+    ("ID projektu (výchozí: novinky všech projektů)", "Project ID (default: news of all projects)"),
+    ("Maximální počet novinek k vrácení (výchozí: 25)", "Maximum number of news items to return (default: 25)"),
+    ("Počet novinek k přeskočení pro stránkování", "Number of news items to skip for pagination"),
+    ("Obejít krátkodobou cache výsledků a načíst aktuální data (výchozí: false)", "Bypass the short-lived result cache and fetch current data (default: false)"),
+    ("ID projektu pro generování sestavy (povinné)", "ID of the project to report on (required)"),
+    ("Datum od pro filtrování dat (formát: YYYY-MM-DD)", "Start date for filtering data (format: YYYY-MM-DD)"),
+    ("Datum do pro filtrování dat (formát: YYYY-MM-DD)", "End date for filtering data (format: YYYY-MM-DD)"),
+    ("Zahrnout časové záznamy do sestavy (výchozí: true)", "Include time entries in the report (default: true)"),
+    ("Zahrnout úkoly do sestavy (výchozí: true)", "Include issues in the report (default: true)"),
+    ("Zahrnout přehled členů projektu s rolemi a statistikami (výchozí: true)", "Include an overview of project members with roles and statistics (default: true)"),
+    ("Zahrnout detailní seznam úkolů a časových záznamů (omezeno konfigurací max_detail_records, výchozí: false)", "Include a detailed list of issues and time entries (limited by max_detail_records, default: false)"),
+    ("Zahrnout úkoly a časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)", "Include issues and time entries of subprojects (default: per EasyProject instance settings)"),
+    ("Určit úkoly po termínu podle časového pásma řešitele (utc_offset), jinak podle konfigurace (výchozí: false)", "Determine overdue issues by the assignee's time zone (utc_offset), otherwise by configuration (default: false)"),
+    ("Seznam ID projektů pro filtrování (nepovinné)", "List of project IDs for filtering (optional)"),
+    ("ID uživatele pro filtrování (nepovinné)", "User ID for filtering (optional)"),
+    ("ID řešitele pro filtrování (nepovinné)", "Assignee ID for filtering (optional)"),
+    ("ID uživatele, jehož přiřazení sledujeme (povinné)", "ID of the user whose assignments are tracked (required)"),
+    ("Omezení na konkrétní projekt (nepovinné)", "Restrict to a specific project (optional)"),
+    ("Začátek období (formát: YYYY-MM-DD)", "Start of the period (format: YYYY-MM-DD)"),
+    ("Konec období (formát: YYYY-MM-DD)", "End of the period (format: YYYY-MM-DD)"),
+    ("Maximální počet úkolů, jejichž historie se prochází (výchozí: 100)", "Maximum number of issues whose history is scanned (default: 100)"),
+    ("ID projektů k vyhodnocení (povinné)", "IDs of the projects to evaluate (required)"),
+    ("Rozpočet v hodinách pro každý projekt (výchozí: součet odhadů úkolů projektu)", "Budget in hours for each project (default: sum of the project's issue estimates)"),
+    ("Finanční rozpočet pro každý projekt (vyžaduje hourly_rate)", "Financial budget for each project (requires hourly_rate)"),
+    ("Hodinová sazba pro přepočet hodin na náklady", "Hourly rate for converting hours to costs"),
+    ("Počet posledních dní pro výpočet tempa čerpání (výchozí: 30)", "Number of recent days used to compute the burn rate (default: 30)"),
+    ("Podíl vyčerpání pro varování, např. 0.8 = 80 % (výchozí: z konfigurace)", "Burned share for a warning, e.g. 0.8 = 80 % (default: from configuration)"),
+    ("Podíl vyčerpání pro kritický stav (výchozí: z konfigurace)", "Burned share for the critical state (default: from configuration)"),
+    ("Pouze sestavy daného projektu", "Only reports of the given project"),
+    ("Pouze sestavy v daném formátu", "Only reports in the given format"),
+    ("Maximální počet vypsaných sestav (výchozí: 20)", "Maximum number of listed reports (default: 20)"),
+    ("Název souboru sestavy, jejíž obsah se má vrátit", "File name of the report whose content should be returned"),
+    ("ID projektu ke kontrole (povinné)", "ID of the project to check (required)"),
+    ("Maximální počet nálezů v každé kontrole (výchozí: 100)", "Maximum number of findings per check (default: 100)"),
+    ("Název baseline, např. 'schvaleny-plan-2024' (povinné)", "Baseline name, e.g. 'approved-plan-2024' (required)"),
+    ("Přepsat existující baseline se stejným názvem (výchozí: false)", "Overwrite an existing baseline with the same name (default: false)"),
+    ("Název baseline k porovnání", "Name of the baseline to compare against"),
+    ("Počet úkolů s největším skluzem ve výpisu (výchozí: 20)", "Number of most delayed issues in the listing (default: 20)"),
+    ("Cursor ze zkráceného výsledku (povinné)", "Cursor from the truncated result (required)"),
+    ("Index části, první část má index 0 (povinné)", "Chunk index, the first chunk has index 0 (required)"),
+    ("Znovu ověřit oprávnění proti API místo použití posledního výsledku (výchozí: false)", "Re-check permissions against the API instead of using the last result (default: false)"),
+    ("Vypsat i všechny klíče, pro které byla použita výchozí hodnota (výchozí: false)", "Also list all keys that fell back to their default value (default: false)"),
+    ("Query parametry požadavku (např. {\"limit\": 10, \"include\": \"journals\"})", "Request query parameters (e.g. {\"limit\": 10, \"include\": \"journals\"})"),
+    ("Maximální počet záznamů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)", "Maximum number of entries to return (default: 25; more than 100 are fetched page by page automatically)"),
+    ("Počet záznamů k přeskočení pro stránkování", "Number of entries to skip for pagination"),
+    ("Načte všechny časové záznamy od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)", "Fetches all time entries from the offset page by page, limit is ignored (at most tools.fetch_all_max_records)"),
+    ("ID projektu pro filtrování", "Project ID for filtering"),
+    ("ID úkolu pro filtrování", "Issue ID for filtering"),
+    ("ID uživatele pro filtrování", "User ID for filtering"),
+    ("Datum od (formát: YYYY-MM-DD)", "Date from (format: YYYY-MM-DD)"),
+    ("Datum do (formát: YYYY-MM-DD)", "Date to (format: YYYY-MM-DD)"),
+    ("Při zadaném project_id zahrnout i časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)", "With project_id, also include time entries of subprojects (default: per EasyProject instance settings)"),
+    ("ID časového záznamu", "Time entry ID"),
+    ("Počet odpracovaných hodin", "Number of hours worked"),
+    ("ID aktivity", "Activity ID"),
+    ("Datum práce (formát: YYYY-MM-DD)", "Date of work (format: YYYY-MM-DD)"),
+    ("ID úkolu (alternativně k project_id)", "Issue ID (alternative to project_id)"),
+    ("ID projektu (alternativně k issue_id, při zadaném úkolu se doplní z úkolu a musí se shodovat)", "Project ID (alternative to issue_id; with an issue it is taken from the issue and must match)"),
+    ("Komentář k časovému záznamu", "Time entry comment"),
+    ("Vytvořit záznam i tehdy, když ve stejný den na stejném úkolu existuje záznam s podobnými hodinami (výchozí: false)", "Create the entry even if an entry with similar hours exists on the same issue on the same day (default: false)"),
+    ("ID úkolu (projekt se doplní z úkolu)", "Issue ID (the project is taken from the issue)"),
+    ("ID projektu (u záznamu vázaného na úkol musí odpovídat projektu úkolu)", "Project ID (for an entry bound to an issue it must match the issue's project)"),
+    ("ID časového záznamu ke smazání", "ID of the time entry to delete"),
+    ("ID konkrétních časových záznamů k přesunu", "IDs of the specific time entries to move"),
+    ("Přesunout záznamy z tohoto úkolu", "Move entries from this issue"),
+    ("Přesunout záznamy z tohoto projektu", "Move entries from this project"),
+    ("Pouze záznamy tohoto uživatele", "Only entries of this user"),
+    ("Pouze záznamy od data (formát: YYYY-MM-DD)", "Only entries from the date (format: YYYY-MM-DD)"),
+    ("Pouze záznamy do data (formát: YYYY-MM-DD)", "Only entries up to the date (format: YYYY-MM-DD)"),
+    ("Cílový úkol (projekt se převezme z úkolu)", "Target issue (the project is taken from the issue)"),
+    ("Cílový projekt (záznamy budou odpojeny od úkolu)", "Target project (entries will be detached from their issue)"),
+    ("Pouze zobrazit záznamy, které by byly přesunuty (výchozí: false)", "Only show the entries that would be moved (default: false)"),
+    ("Maximální počet přesouvaných záznamů (výchozí: 100)", "Maximum number of moved entries (default: 100)"),
+    ("Datum práce (formát: YYYY-MM-DD, výchozí: dnes)", "Date of work (format: YYYY-MM-DD, default: today)"),
+    ("Maximální počet uživatelů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)", "Maximum number of users to return (default: 25; more than 100 are fetched page by page automatically)"),
+    ("Počet uživatelů k přeskočení pro stránkování", "Number of users to skip for pagination"),
+    ("Načte všechny uživatele od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)", "Fetches all users from the offset page by page, limit is ignored (at most tools.fetch_all_max_records)"),
+    ("Fulltextové vyhledávání ve jménech a emailech uživatelů (např. 'Jan Novák' nebo 'jan@firma.cz')", "Full-text search in user names and emails (e.g. 'John Smith' or 'john@company.com')"),
+    ("Řazení výsledků (např. 'lastname' nebo 'created_on:desc'). Formát: 'pole' nebo 'pole:desc'", "Result ordering (e.g. 'lastname' or 'created_on:desc'). Format: 'field' or 'field:desc'"),
+    ("Filtrování podle stavu uživatele", "Filter by user status"),
+    ("Filtrování podle kategorie typu uživatele", "Filter by user type category"),
+    ("Filtrování podle konkrétního typu uživatele (ID z list_user_types)", "Filter by a specific user type (ID from list_user_types)"),
+    ("ID uživatele", "User ID"),
+    ("Datum od pro filtrování časových záznamů (formát: YYYY-MM-DD)", "Start date for filtering time entries (format: YYYY-MM-DD)"),
+    ("Datum do pro filtrování časových záznamů (formát: YYYY-MM-DD)", "End date for filtering time entries (format: YYYY-MM-DD)"),
+    ("ID role pro detail s oprávněními (volitelné)", "Role ID for details with permissions (optional)"),
+    ("Počet dní dopředu pro blížící se termíny (výchozí: 7)", "Number of days ahead for upcoming due dates (default: 7)"),
+    ("Cílový stav - název (bez ohledu na diakritiku a velikost písmen) nebo ID (povinné)", "Target status - name (regardless of diacritics and letter case) or ID (required)"),
+    ("Komentář připojený ke změně stavu (nepovinné)", "Comment attached to the status change (optional)"),
+    ("ID uživatele, za kterého se čas loguje (výchozí: aktuální uživatel; vyžaduje oprávnění logovat čas za ostatní)", "ID of the user the time is logged for (default: current user; requires permission to log time for others)"),
+    ("Uživatel zadaný jménem, loginem nebo emailem (alternativa k user_id)", "User given by name, login or email (alternative to user_id)"),
+    ("ID kopírovaného úkolu (povinné)", "ID of the issue to copy (required)"),
+    ("Cílový projekt (výchozí: projekt původního úkolu)", "Target project (default: project of the original issue)"),
+    ("Název kopie (výchozí: název původního úkolu)", "Subject of the copy (default: subject of the original issue)"),
+    ("Řešitel kopie (výchozí: řešitel původního úkolu)", "Assignee of the copy (default: assignee of the original issue)"),
+    ("Milník kopie (výchozí: milník původního úkolu, pokud je v cílovém projektu dostupný)", "Milestone of the copy (default: milestone of the original issue if available in the target project)"),
+    ("Zkopírovat popis (výchozí: true)", "Copy the description (default: true)"),
+    ("Zkopírovat hodnoty vlastních polí (výchozí: true)", "Copy custom field values (default: true)"),
+    ("Zkopírovat přílohy do velikosti tools.issues.max_attachment_bytes (výchozí: false)", "Copy attachments up to tools.issues.max_attachment_bytes in size (default: false)"),
+    ("Přidat stejné sledující (výchozí: false)", "Add the same watchers (default: false)"),
+    ("Zkopírovat i podúkoly včetně vnořených (výchozí: false)", "Copy subtasks as well, including nested ones (default: false)"),
+    ("ID projektu, který slouží jako šablona (povinné)", "ID of the project used as the template (required)"),
+    ("Název nového projektu (povinné)", "Name of the new project (required)"),
+    ("Popis nového projektu (výchozí: popis šablony)", "Description of the new project (default: template description)"),
+    ("ID nadřazeného projektu (výchozí: nadřazený projekt šablony)", "Parent project ID (default: parent project of the template)"),
+    ("Zda je projekt veřejný (výchozí: podle šablony)", "Whether the project is public (default: as the template)"),
+    ("Začátek nového projektu (YYYY-MM-DD) - termíny milníků a úkolů se posunou o rozdíl od nejdřívějšího data šablony (výchozí: bez posunu)", "Start of the new project (YYYY-MM-DD) - milestone and issue dates are shifted by the difference from the earliest template date (default: no shift)"),
+    ("Zkopírovat milníky (verze) šablony (výchozí: true)", "Copy the template milestones (versions) (default: true)"),
+    ("Zkopírovat kategorie úkolů (výchozí: true)", "Copy issue categories (default: true)"),
+    ("Zkopírovat otevřené úkoly šablony jako nové úkoly (výchozí: false)", "Copy open template issues as new issues (default: false)"),
+    ("Jen vrátit plán kopírování, nic nevytvářet (výchozí: false)", "Only return the copy plan, create nothing (default: false)"),
+    ("Záznamy k vytvoření (nejvýše 100)", "Entries to create (at most 100)"),
+    ("Datum práce (YYYY-MM-DD)", "Date of work (YYYY-MM-DD)"),
+    ("ID projektu (alternativně k issue_id)", "Project ID (alternative to issue_id)"),
+    ("ID aktivity (výchozí: společné activity_id)", "Activity ID (default: the shared activity_id)"),
+    ("Začátek období - záznam na každý pracovní den (YYYY-MM-DD)", "Start of the period - one entry per business day (YYYY-MM-DD)"),
+    ("Konec období včetně (YYYY-MM-DD)", "End of the period, inclusive (YYYY-MM-DD)"),
+    ("Hodiny na každý pracovní den období", "Hours for each business day of the period"),
+    ("Společné ID úkolu pro záznamy bez issue_id/project_id", "Shared issue ID for entries without issue_id/project_id"),
+    ("Společné ID projektu pro záznamy bez issue_id/project_id", "Shared project ID for entries without issue_id/project_id"),
+    ("Společné ID aktivity pro záznamy bez activity_id", "Shared activity ID for entries without activity_id"),
+    ("Společný komentář pro záznamy bez komentáře", "Shared comment for entries without a comment"),
+    ("Vytvořit záznamy i tehdy, když ve stejný den na stejném úkolu existuje záznam s podobnými hodinami (výchozí: false)", "Create the entries even if an entry with similar hours already exists on the same day and issue (default: false)"),
+    ("Vzor klíče cache: entita s ID (např. 'issue_42') nebo glob s * (např. 'issues_*'). Bez vzoru se vymaže celá cache", "Cache key pattern: an entity with ID (e.g. 'issue_42') or a glob with * (e.g. 'issues_*'). Without a pattern the whole cache is cleared"),
+    ("Počet vrácených záznamů (výchozí 20, maximum 200)", "Number of returned entries (default 20, maximum 200)"),
+    ("Jen záznamy daného tool (např. 'update_issue')", "Only entries of the given tool (e.g. 'update_issue')"),
+];
@@ -0,0 +1,1628 @@
+use super::Locale;
+
+/// Texty výstupu tools a formátovacích funkcí v jednom jazyce
+///
+/// Jednoduché popisky jsou řetězce, věty s hodnotami funkce (kvůli odlišnému slovosledu).
+pub struct Messages {
+    pub locale: Locale,
+
+    // Entity a popisky polí
+    pub project: &'static str,
+    pub issue: &'static str,
+    pub user: &'static str,
+    pub time_entry: &'static str,
+    pub status: &'static str,
+    pub description: &'static str,
+    pub identifier: &'static str,
+    pub homepage: &'static str,
+    pub parent_project: &'static str,
+    pub created: &'static str,
+    pub updated: &'static str,
+    pub tracker: &'static str,
+    pub priority: &'static str,
+    pub author: &'static str,
+    pub assigned_to: &'static str,
+    pub estimated_hours: &'static str,
+    pub spent_hours: &'static str,
+    pub done_ratio: &'static str,
+    pub start_date: &'static str,
+    pub due_date: &'static str,
+    pub attachments: &'static str,
+    pub uploaded: &'static str,
+    pub login: &'static str,
+    pub email: &'static str,
+    pub role: &'static str,
+    pub administrator: &'static str,
+    pub last_login: &'static str,
+    pub hours: &'static str,
+    pub activity: &'static str,
+    pub date: &'static str,
+    pub comment: &'static str,
+    pub subject: &'static str,
+    pub name: &'static str,
+    pub user_type: &'static str,
+    pub issue_subject: &'static str,
+    pub total_hours: &'static str,
+    pub yes: &'static str,
+    pub no: &'static str,
+    pub error: &'static str,
+
+    // Stavy
+    pub project_active: &'static str,
+    pub project_closed: &'static str,
+    pub project_archived: &'static str,
+    pub project_planned: &'static str,
+    pub project_deleted: &'static str,
+    pub user_active: &'static str,
+    pub user_registered: &'static str,
+    pub user_locked: &'static str,
+    pub unknown: &'static str,
+
+    // Seznamy
+    pub no_projects: &'static str,
+    pub no_issues: &'static str,
+    pub no_users: &'static str,
+    pub no_time_entries: &'static str,
+    pub no_records: &'static str,
+    pub found_projects: fn(usize) -> String,
+    pub found_issues: fn(usize) -> String,
+    pub found_users: fn(usize) -> String,
+    pub found_time_entries: fn(usize) -> String,
+    pub found_projects_total: fn(usize, i32) -> String,
+    pub found_issues_total: fn(usize, i32) -> String,
+    pub found_users_total: fn(usize, i32) -> String,
+    pub found_time_entries_total: fn(usize, i32, f64) -> String,
+
+    // Detaily
+    pub project_detail: fn(&str) -> String,
+    pub issue_detail: fn(&str) -> String,
+    pub user_detail: fn(&str, &str) -> String,
+    pub time_entry_detail: fn(i32, f64, &str, &str) -> String,
+
+    // Chyby list a get tools
+    pub missing_id: &'static str,
+    pub error_fetching_projects: &'static str,
+    pub error_fetching_project: fn(i32) -> String,
+    pub error_fetching_issues: &'static str,
+    pub error_fetching_issue: fn(i32) -> String,
+    pub error_fetching_users: &'static str,
+    pub error_fetching_user: fn(i32) -> String,
+    pub error_fetching_user_types: &'static str,
+    pub error_fetching_time_entries: &'static str,
+    pub error_fetching_time_entry: fn(i32) -> String,
+    pub invalid_date: fn(&str, &str) -> String,
+    pub issue_unavailable: &'static str,
+
+    // Parametry format a columns
+    pub format_description: &'static str,
+    pub columns_description: fn(&str) -> String,
+    pub export_format_description: &'static str,
+    pub unknown_column: fn(&str, &str) -> String,
+
+    // Registry a rozpočet odpovědi
+    pub unavailable_prefix: &'static str,
+    pub fresh_description: &'static str,
+    pub instance_description: fn(&str) -> String,
+    pub unknown_instance: fn(&str, &str) -> String,
+    pub project_reference_hint: &'static str,
+    pub project_not_resolved: fn(&str, &str) -> String,
+    pub tool_unavailable: fn(&str) -> String,
+    pub tool_not_found: fn(&str) -> String,
+    pub invalid_arguments: fn(&str, &str) -> String,
+    pub arguments_not_object: &'static str,
+    pub missing_arguments: fn(&str) -> String,
+    pub argument_type: fn(&str, &str) -> String,
+    pub argument_enum: fn(&str, &str) -> String,
+    pub argument_minimum: fn(&str, f64) -> String,
+    pub argument_maximum: fn(&str, f64) -> String,
+    pub argument_pattern: fn(&str, &str) -> String,
+    pub tool_timeout: fn(&str, u64) -> String,
+    pub chunk_truncated: fn(usize, usize, &str) -> String,
+    pub chunk_limit_hint: fn(u64) -> String,
+
+    // Výstup tools - číselníky a milníky
+    pub error_fetching_enumerations: &'static str,
+    pub error_fetching_custom_fields: &'static str,
+    pub custom_fields_admin_hint: &'static str,
+    pub found_milestones_total: fn(usize, i32) -> String,
+    pub error_fetching_milestones: &'static str,
+    pub error_fetching_project_versions: fn(i32) -> String,
+    pub milestone_detail: fn(&str) -> String,
+    pub error_fetching_milestone: fn(i32) -> String,
+    pub milestone_created: fn(&str, i32) -> String,
+    pub error_creating_milestone: &'static str,
+    pub milestone_updated: fn(&str) -> String,
+    pub error_updating_milestone: fn(i32) -> String,
+    pub milestone_deleted: fn(i32) -> String,
+    pub error_deleting_milestone: fn(i32) -> String,
+
+    // Výstup tools - společné a systémové
+    pub missing_parameter: fn(&str) -> String,
+    pub empty_parameter: fn(&str) -> String,
+    pub error_loading_config: &'static str,
+    pub api_path_not_allowed: fn(&str, &str) -> String,
+    pub query_key_not_allowed: &'static str,
+    pub query_value_type: fn(&str, &str) -> String,
+    pub error_calling_api: fn(&str) -> String,
+    pub instance_info: &'static str,
+    pub server_info: fn(&str, &str) -> String,
+    pub audit_empty: &'static str,
+    pub audit_summary: fn(usize, usize) -> String,
+
+    // Výstup tools - uživatelé
+    pub no_user_type_in_category: fn(&str) -> String,
+    pub user_workload: fn(&str, &str, &str) -> String,
+    pub error_fetching_current_user: &'static str,
+    pub error_fetching_role: fn(i32) -> String,
+    pub error_fetching_roles: &'static str,
+
+    // Výstup tools - časové záznamy
+    pub duplicate_time_entries: fn(&str) -> String,
+    pub invalid_hours: &'static str,
+    pub issue_or_project_required: &'static str,
+    pub time_entry_created: fn(i32, f64, &str) -> String,
+    pub error_creating_time_entry: &'static str,
+    pub no_time_entry_changes: &'static str,
+    pub error_updating_time_entry: fn(i32) -> String,
+    pub time_entry_updated_unreadable: fn(i32) -> String,
+    pub time_entry_updated: fn(i32, &str) -> String,
+    pub time_entry_deleted: fn(i32, f64, &str, &str, &str, Option<i32>) -> String,
+    pub error_deleting_time_entry: fn(i32) -> String,
+    pub move_target_required: &'static str,
+    pub move_target_issue_unavailable: fn(i32) -> String,
+    pub move_source_required: &'static str,
+    pub error_selecting_time_entries: &'static str,
+    pub error_parsing_arguments: &'static str,
+    pub missing_log_time_arguments: &'static str,
+    pub user_not_resolved: fn(&str) -> String,
+    pub time_logged: fn(f64, &str, i32) -> String,
+    pub error_logging_time: &'static str,
+
+    // Výstup tools - projekty
+    pub project_created: fn(&str, i32) -> String,
+    pub error_creating_project: fn(&str) -> String,
+    pub project_updated: fn(&str, i32) -> String,
+    pub error_updating_project: fn(i32) -> String,
+    pub error_fetching_project_before_delete: fn(i32) -> String,
+    pub project_removed: fn(&str, i32) -> String,
+    pub error_deleting_project: fn(i32, &str) -> String,
+    pub project_modules: fn(&str, i32) -> String,
+    pub modules_change_required: &'static str,
+    pub unknown_modules: fn(&str, &str) -> String,
+    pub project_modules_unchanged: fn(&str, i32, &str) -> String,
+    pub project_modules_updated: fn(&str, i32) -> String,
+    pub error_updating_project_modules: fn(i32) -> String,
+    pub found_news_total: fn(usize, i32) -> String,
+    pub error_fetching_news: &'static str,
+    pub project_files: fn(i32, usize) -> String,
+    pub error_fetching_project_files: fn(i32) -> String,
+    pub unknown_search_type: fn(&str, &str) -> String,
+    pub error_searching: &'static str,
+    pub error_fetching_template_versions: fn(i32) -> String,
+    pub template_plan: fn(&str, &str, usize, usize, usize, i64) -> String,
+    pub template_category_failed: fn(&str) -> String,
+    pub template_version_failed: fn(&str) -> String,
+    pub template_statuses_unavailable: &'static str,
+    pub template_issue_failed: fn(i32, &str) -> String,
+    pub template_created_counts: fn(usize, usize, usize, usize, usize, usize) -> String,
+    pub project_created_from_template: fn(&str, i32, &str, &str, i64) -> String,
+    pub template_not_created: &'static str,
+
+    // Výstup tools - úkoly
+    pub error_creating_issue: fn(&str) -> String,
+    pub issue_created: fn(&str, i32) -> String,
+    pub batch_size: fn(usize, usize) -> String,
+    pub error_parsing_update_arguments: &'static str,
+    pub missing_update_arguments: &'static str,
+    pub error_updating_issue: fn(i32) -> String,
+    pub issue_updated: fn(&str, i32) -> String,
+    pub error_fetching_issue_before_delete: fn(i32) -> String,
+    pub issue_removed: fn(&str, i32) -> String,
+    pub error_deleting_issue: fn(i32, &str) -> String,
+    pub error_fetching_attachments: fn(i32) -> String,
+    pub error_fetching_attachment: fn(i32) -> String,
+    pub error_downloading_attachment: fn(i32) -> String,
+    pub attachment_summary: fn(&str, i32, &str, usize) -> String,
+    pub attachment_source_required: &'static str,
+    pub attachment_too_large: fn(usize, u64) -> String,
+    pub error_uploading_file: fn(&str) -> String,
+    pub attachment_added: fn(&str, usize, i32) -> String,
+    pub error_attaching_file: fn(&str, i32) -> String,
+    pub watcher_added: fn(i32, i32) -> String,
+    pub error_adding_watcher: fn(i32, i32) -> String,
+    pub watcher_removed: fn(i32, i32) -> String,
+    pub error_removing_watcher: fn(i32, i32) -> String,
+    pub error_fetching_user_load: fn(i32) -> String,
+    pub assignment_preview: fn(i32, &str) -> String,
+    pub assignee_required: &'static str,
+    pub issue_assigned: fn(i32, i32) -> String,
+    pub issue_completed: fn(i32, i32) -> String,
+    pub error_fetching_statuses: &'static str,
+    pub workflow_transitions_hint: fn(&str) -> String,
+    pub issue_already_in_status: fn(i32, &str) -> String,
+    pub status_not_changed: fn(i32, &str, &str, &str) -> String,
+    pub status_changed: fn(&str, i32, &str, &str, bool) -> String,
+    pub error_changing_status: fn(i32) -> String,
+    pub issue_context: fn(&str, i32) -> String,
+    pub invalid_regex: &'static str,
+    pub error_scanning_project_issues: fn(i32) -> String,
+    pub no_description_matches: fn(usize, i32) -> String,
+    pub confirm_token_required: &'static str,
+    pub preview_outdated: &'static str,
+    pub too_many_edits: fn(usize, usize) -> String,
+    pub error_creating_export_dir: fn(&str) -> String,
+    pub error_creating_file: fn(&str) -> String,
+    pub export_interrupted: fn(&str, &str, usize) -> String,
+    pub error_copying_issue: fn(i32) -> String,
+    pub clone_version_unavailable: fn(i32, i32) -> String,
+    pub clone_watcher_failed: fn(i32, &str) -> String,
+    pub clone_attachment_failed: fn(i32, &str) -> String,
+    pub clone_attach_failed: fn(i32) -> String,
+    pub clone_subtask_limit: fn(usize, usize) -> String,
+    pub clone_subtask_unreadable: fn(i32) -> String,
+    pub clone_subtask_failed: fn(i32) -> String,
+    pub issue_cloned: fn(i32, i32, &str, &str) -> String,
+    pub including_subtasks: fn(usize) -> String,
+    pub warnings: &'static str,
+
+    // Výstup tools - sestavy
+    pub project_report: fn(&str, i32) -> String,
+    pub dashboard_data: &'static str,
+    pub project_ids_required: &'static str,
+    pub budget_requires_rate: &'static str,
+    pub invalid_report_file_name: fn(&str) -> String,
+    pub report_not_found: fn(&str) -> String,
+    pub pdf_report_saved: fn(&str) -> String,
+    pub error_reading_report: fn(&str) -> String,
+    pub report_dir_empty: fn(&str) -> String,
+    pub error_reading_report_dir: &'static str,
+    pub no_generated_reports: &'static str,
+    pub consistency_report: fn(i32, usize) -> String,
+    pub baseline_exists: fn(&str, i32) -> String,
+    pub error_saving_baseline: &'static str,
+    pub baseline_not_found: fn(&str, i32) -> String,
+    pub error_loading_baseline: fn(&str) -> String,
+    pub baseline_variance: fn(i32, &str) -> String,
+    pub error_loading_project_issues: fn(i32) -> String,
+    pub project_schedule: fn(i32, usize, usize, usize) -> String,
+    pub invalid_value: fn(&str, &str, &str) -> String,
+    pub error_loading_closed_issues: &'static str,
+    pub error_loading_time_entries: &'static str,
+    pub team_velocity: fn(&str, &str, &str, &str) -> String,
+    pub error_loading_issues: &'static str,
+    pub deadline_risks: fn(usize, usize) -> String,
+    pub date_range_reversed: &'static str,
+    pub capacity_plan: fn(&str, &str, i64, &str) -> String,
+    pub nobody: &'static str,
+    pub timesheet_period: fn(i64) -> String,
+
+    // Instrukce serveru
+    pub tool_group_labels: [&'static str; 6],
+    pub instructions_header: fn(&str, &str, usize) -> String,
+    pub instructions_no_groups: &'static str,
+    pub instructions_read_only: &'static str,
+    pub instructions_unavailable: fn(&str) -> String,
+    pub instructions_workflow: &'static str,
+    pub instructions_enumerations: &'static str,
+    pub instructions_projects: &'static str,
+    pub instructions_versions: &'static str,
+    pub instructions_user_types: &'static str,
+    pub instructions_chunks: &'static str,
+    pub instructions_resources: &'static str,
+
+    // Server MCP
+    pub error_creating_api_client: &'static str,
+    pub server_not_initialized: &'static str,
+    pub invalid_request_params: fn(&str) -> String,
+    pub missing_request_params: fn(&str) -> String,
+    pub resource_not_found: fn(&str) -> String,
+    pub result_page_name: fn(usize) -> String,
+
+    // Výstup tools - číselníky úkolů
+    pub issue_enumerations: &'static str,
+    pub enumeration_statuses: &'static str,
+    pub enumeration_priorities: &'static str,
+    pub enumeration_trackers: &'static str,
+    pub enumerations_usage: &'static str,
+
+    // Výstup tools - chybějící argumenty
+    pub missing_parameters: fn(&str) -> String,
+    pub missing_tool_arguments: fn(&str) -> String,
+
+    // Výstup tools - přiřaditelné verze
+    pub assignable_versions: fn(i32, usize, usize) -> String,
+    pub no_assignable_versions: &'static str,
+    pub version_shared_from: fn(&str, i32, &str) -> String,
+    pub due_on: &'static str,
+
+    // Výstup tools - vyhledávání
+    pub found_search_results: fn(usize, &str, i32) -> String,
+
+    // Výstup tools - moje práce, typy uživatelů a role
+    pub my_work_summary: fn(&str, i32, &str, &str, f64, usize, i32) -> String,
+    pub overdue: &'static str,
+    pub due_within_days: fn(i64) -> String,
+    pub user_types_heading: fn(usize) -> String,
+    pub category: &'static str,
+    pub unspecified: &'static str,
+    pub default_flag: &'static str,
+    pub roles_heading: fn(usize) -> String,
+    pub not_assignable: &'static str,
+
+    // Výstup tools - oprávnění, konfigurace, cache a spojení
+    pub api_key_permissions: &'static str,
+    pub probed_at: &'static str,
+    pub probe_allowed: &'static str,
+    pub probe_denied: &'static str,
+    pub probe_unknown: &'static str,
+    pub all_tools_available: &'static str,
+    pub unavailable_tools: &'static str,
+    pub permission_area_labels: [&'static str; 6],
+    pub user_type_categories: [&'static str; 3],
+    pub config_diagnostics: &'static str,
+    pub config_load_failed: fn(&str) -> String,
+    pub config_failed_keys: &'static str,
+    pub config_unknown_keys: &'static str,
+    pub config_env_overrides: &'static str,
+    pub config_defaulted_keys: fn(usize) -> String,
+    pub config_defaulted_count: fn(usize) -> String,
+    pub config_validation_failed: fn(&str) -> String,
+    pub config_valid_with_problems: &'static str,
+    pub config_ok: &'static str,
+    pub instance_enumeration_labels: [&'static str; 6],
+    pub instance_plugin_labels: [&'static str; 4],
+    pub current_user_unavailable: &'static str,
+    pub instance_version_unknown: &'static str,
+    pub project_modules_unavailable: &'static str,
+    pub cache_summary: fn(u64, u64, f64, u64, u64) -> String,
+    pub cache_disabled: &'static str,
+    pub cache_pattern_cleared: fn(&str, u64, u64) -> String,
+    pub cache_cleared: fn(u64) -> String,
+    pub connection_ok: fn(&str, &str, u64, &str) -> String,
+    pub version_suffix: fn(&str) -> String,
+    pub connection_auth_failed: fn(&str, u64, &str) -> String,
+    pub connection_unreachable: fn(&str, &str) -> String,
+    pub capabilities_not_probed: &'static str,
+
+    // Výstup tools - časové záznamy: změny, přesun a hromadné logování
+    pub issue_unreadable: fn(i32) -> String,
+    pub issue_project_mismatch: fn(i32, &str, i32, i32) -> String,
+    pub time_entry_change_labels: [&'static str; 6],
+    pub no_value_changes: &'static str,
+    pub issue_target: fn(i32) -> String,
+    pub project_target: fn(&str) -> String,
+    pub time_entries_move_heading: fn(bool, usize, f64, &str) -> String,
+    pub moved_from: &'static str,
+    pub entries_already_on_target: fn(usize) -> String,
+    pub dry_run_no_changes: &'static str,
+    pub moved_summary: fn(usize, usize) -> String,
+    pub bulk_hours_per_day_required: &'static str,
+    pub to_date_before_from_date: &'static str,
+    pub bulk_period_incomplete: &'static str,
+    pub bulk_entries_missing: &'static str,
+    pub bulk_too_many_entries: fn(usize, usize) -> String,
+    pub bulk_entry_invalid: fn(usize, &str, &str) -> String,
+    pub bulk_entry_missing_target: &'static str,
+    pub bulk_entry_missing_activity: &'static str,
+    pub bulk_entry_invalid_hours: &'static str,
+    pub probable_duplicate: fn(i32) -> String,
+    pub task_aborted: &'static str,
+    pub bulk_created_summary: fn(usize, usize, f64) -> String,
+    pub bulk_not_created: &'static str,
+
+    // Výstup tools - úkoly: vstupy, dávky, přílohy a hromadné úpravy
+    pub custom_field_key_not_numeric: fn(&str) -> String,
+    pub custom_field_value_object: fn(i32) -> String,
+    pub assignee_unresolved: fn(&str) -> String,
+    pub batch_parent_conflict: fn(usize) -> String,
+    pub batch_parent_missing: fn(usize, usize) -> String,
+    pub batch_parent_cycle: fn(usize) -> String,
+    pub batch_skipped_after_error: &'static str,
+    pub batch_parent_not_created: fn(usize) -> String,
+    pub batch_value_missing: fn(&str) -> String,
+    pub batch_created: fn(usize, usize) -> String,
+    pub upload_disabled: &'static str,
+    pub directory_unavailable: fn(&str) -> String,
+    pub file_unavailable: fn(&str) -> String,
+    pub file_outside_upload_dir: fn(&str, &str) -> String,
+    pub file_too_large: fn(&str, u64, u64) -> String,
+    pub file_unreadable: fn(&str) -> String,
+    pub invalid_base64: &'static str,
+    pub user_fallback_name: fn(i32) -> String,
+    pub remaining_hours_rule: &'static str,
+    pub status_param_invalid: &'static str,
+    pub status_id_unavailable: fn(i32, &str) -> String,
+    pub status_not_found: fn(&str, &str) -> String,
+    pub status_ambiguous: fn(&str, &str) -> String,
+    pub error_fetching_parent_issue: &'static str,
+    pub bulk_edit_preview: fn(usize, usize) -> String,
+    pub bulk_edit_scan_truncated: &'static str,
+    pub match_count: fn(usize) -> String,
+    pub bulk_edit_too_many: fn(usize, usize) -> String,
+    pub bulk_edit_confirm_hint: fn(&str) -> String,
+    pub descriptions_updated: fn(usize) -> String,
+    pub failed_edits: fn(usize) -> String,
+    pub issues_exported: fn(usize, &str) -> String,
+    pub export_limited: fn(usize, usize) -> String,
+    pub found_issues_for: fn(usize, &str) -> String,
+    pub of_matching: fn(usize) -> String,
+    pub unassigned: &'static str,
+
+    // Schémata tools s dynamickým popisem
+    pub batch_issues_description: fn(usize) -> String,
+    pub raw_api_path_description: fn(&str) -> String,
+
+    // Výstup tools - sestavy: přiřazení, rozpočet, kontroly a baseline
+    pub error_fetching_project_members: &'static str,
+    pub assignment_history_heading: fn(i32, &str, &str) -> String,
+    pub period_start: &'static str,
+    pub today: &'static str,
+    pub no_assignment_changes: &'static str,
+    pub assigned_action: &'static str,
+    pub unassigned_action: &'static str,
+    pub previously_user: fn(&str) -> String,
+    pub newly_user: fn(&str) -> String,
+    pub changed_by: fn(&str) -> String,
+    pub assignment_summary: fn(usize, usize, usize) -> String,
+    pub limited_by_max_issues: fn(usize) -> String,
+    pub issue_history_failed: fn(usize) -> String,
+    pub budget_burn_heading: fn(u32, f64, f64) -> String,
+    pub budget_project_failed: fn(i32) -> String,
+    pub budget_estimates_failed: fn(&str, i32) -> String,
+    pub budget_time_entries_failed: fn(&str, i32) -> String,
+    pub budget_sources: [&'static str; 3],
+    pub budget_unknown: fn(f64, f64) -> String,
+    pub budget_consumption: fn(f64, &str, f64, f64, f64) -> String,
+    pub budget_costs: fn(f64, f64) -> String,
+    pub burn_rate: fn(f64) -> String,
+    pub budget_exhausted: &'static str,
+    pub budget_exhaustion_estimate: fn(&str, i64) -> String,
+    pub budget_no_recent_burn: &'static str,
+    pub budget_status_critical: &'static str,
+    pub budget_status_warning: &'static str,
+    pub budget_status: &'static str,
+    pub no_project_over_threshold: &'static str,
+    pub projects_over_threshold: &'static str,
+    pub generated_reports_heading: fn(&str, usize) -> String,
+    pub generated_report_line: fn(&str, i32, &str, u64) -> String,
+    pub more_reports: fn(usize) -> String,
+    pub check_subtask_due_after_parent: &'static str,
+    pub check_closed_parent_open_children: &'static str,
+    pub check_assigned_to_locked_user: &'static str,
+    pub locked_users_unavailable: &'static str,
+    pub check_time_logged_after_close: &'static str,
+    pub baseline_saved: fn(&str, &str, i32, usize, f64, &str) -> String,
+    pub baseline_scan_truncated: &'static str,
+    pub no_baselines: fn(i32) -> String,
+    pub project_baselines: fn(i32, &str) -> String,
+
+    // Výstup tools - sestavy: harmonogram, velocity, rizika, výkaz a portfolio
+    pub schedule_title: fn(i32) -> String,
+    pub schedule_default_section: &'static str,
+    pub velocity_trends: [&'static str; 4],
+    pub team: &'static str,
+    pub risk_levels: [&'static str; 3],
+    pub unassigned_group: &'static str,
+    pub total: &'static str,
+    pub timesheet_title: fn(&str, &str) -> String,
+    pub workdays_without_time: &'static str,
+    pub portfolio_health_levels: [&'static str; 3],
+    pub portfolio_table_header: &'static str,
+    pub portfolio_heading: fn(usize, &str, &str) -> String,
+    pub shown_first_projects: fn(usize, usize) -> String,
+    pub portfolio_scan_truncated: &'static str,
+}
+
+pub static CS: Messages = Messages {
+    locale: Locale::Cs,
+
+    project: "Projekt",
+    issue: "Úkol",
+    user: "Uživatel",
+    time_entry: "Časový záznam",
+    status: "Status",
+    description: "Popis",
+    identifier: "Identifikátor",
+    homepage: "Domovská stránka",
+    parent_project: "Nadřazený projekt",
+    created: "Vytvořeno",
+    updated: "Aktualizováno",
+    tracker: "Tracker",
+    priority: "Priorita",
+    author: "Autor",
+    assigned_to: "Přiřazeno",
+    estimated_hours: "Odhadované hodiny",
+    spent_hours: "Strávené hodiny",
+    done_ratio: "Dokončeno",
+    start_date: "Datum zahájení",
+    due_date: "Termín dokončení",
+    attachments: "Přílohy",
+    uploaded: "Nahráno",
+    login: "Přihlašovací jméno",
+    email: "Email",
+    role: "Role",
+    administrator: "Administrátor",
+    last_login: "Poslední přihlášení",
+    hours: "hodin",
+    activity: "Aktivita",
+    date: "Datum",
+    comment: "Komentář",
+    subject: "Název",
+    name: "Jméno",
+    user_type: "Typ",
+    issue_subject: "Název úkolu",
+    total_hours: "Celkem hodin",
+    yes: "ano",
+    no: "ne",
+    error: "Chyba",
+
+    project_active: "Aktivní",
+    project_closed: "Uzavřený",
+    project_archived: "Archivovaný",
+    project_planned: "Plánovaný",
+    project_deleted: "Smazaný",
+    user_active: "Aktivní",
+    user_registered: "Registrovaný",
+    user_locked: "Zablokovaný",
+    unknown: "Neznámý",
+
+    no_projects: "Žádné projekty nebyly nalezeny.",
+    no_issues: "Žádné úkoly nebyly nalezeny.",
+    no_users: "Žádní uživatelé nebyli nalezeni.",
+    no_time_entries: "Žádné časové záznamy nebyly nalezeny.",
+    no_records: "Žádné záznamy nebyly nalezeny.",
+    found_projects: |count| format!("Nalezeno {} projektů", count),
+    found_issues: |count| format!("Nalezeno {} úkolů", count),
+    found_users: |count| format!("Nalezeno {} uživatelů", count),
+    found_time_entries: |count| format!("Nalezeno {} časových záznamů", count),
+    found_projects_total: |count, total| format!("Nalezeno {} projektů (celkem: {})", count, total),
+    found_issues_total: |count, total| format!("Nalezeno {} úkolů (celkem: {})", count, total),
+    found_users_total: |count, total| format!("Nalezeno {} uživatelů (celkem: {})", count, total),
+    found_time_entries_total: |count, total, hours| format!("Nalezeno {} časových záznamů (celkem: {}, {} hodin)", count, total, hours),
+
+    project_detail: |name| format!("Detail projektu '{}'", name),
+    issue_detail: |subject| format!("Detail úkolu '{}'", subject),
+    user_detail: |firstname, lastname| format!("Detail uživatele '{} {}'", firstname, lastname),
+    time_entry_detail: |id, hours, date, project| format!("Detail časového záznamu {} ({} h, {}, projekt {})", id, hours, date, project),
+
+    missing_id: "Chybí povinný parametr 'id'",
+    error_fetching_projects: "Chyba při získávání projektů",
+    error_fetching_project: |id| format!("Chyba při získávání projektu {}", id),
+    error_fetching_issues: "Chyba při získávání úkolů",
+    error_fetching_issue: |id| format!("Chyba při získávání úkolu {}", id),
+    error_fetching_users: "Chyba při získávání uživatelů",
+    error_fetching_user: |id| format!("Chyba při získávání uživatele {}", id),
+    error_fetching_user_types: "Chyba při získávání typů uživatelů",
+    error_fetching_time_entries: "Chyba při získávání časových záznamů",
+    error_fetching_time_entry: |id| format!("Chyba při získávání časového záznamu {}", id),
+    invalid_date: |parameter, value| format!("Neplatný formát data '{}': {}. Očekávaný formát: YYYY-MM-DD", parameter, value),
+    issue_unavailable: "Úkol nelze načíst",
+
+    format_description: "Formát výstupu: json (výchozí), text (čitelný přehled), markdown, compact (JSON na jednom řádku)",
+    columns_description: |defaults| format!("Sloupce tabulky pro format=markdown a export_format=csv (výchozí: {})", defaults),
+    export_format_description: "Export pro tabulkové procesory: csv (oddělovač ';', první řádek záhlaví) - výstupem je jen CSV text, parametr format se ignoruje",
+    unknown_column: |column, available| format!("Neznámý sloupec '{}'. Dostupné sloupce: {}", column, available),
+
+    unavailable_prefix: "[NEDOSTUPNÉ - chybí oprávnění]",
+    fresh_description: "Obejít krátkodobou cache výsledků a načíst aktuální data (výchozí: false)",
+    instance_description: |default| format!("Instance EasyProject, na které se tool spustí (výchozí: {})", default),
+    unknown_instance: |instance, available| format!("Neznámá instance '{}'. Dostupné instance: {}", instance, available),
+    project_reference_hint: "Místo ID lze zadat identifikátor nebo název projektu",
+    project_not_resolved: |reference, error| format!("Projekt '{}' nelze určit: {}", reference, error),
+    tool_unavailable: |tool| format!(
+        "Tool '{}' není dostupný - API klíč nemá potřebná oprávnění. Podrobnosti vrátí tool get_permissions.",
+        tool
+    ),
+    tool_not_found: |tool| format!("Tool '{}' nenalezen", tool),
+    invalid_arguments: |tool, errors| format!("Neplatné argumenty pro tool '{}': {}", tool, errors),
+    arguments_not_object: "argumenty musí být JSON objekt",
+    missing_arguments: |fields| format!("chybí povinné argumenty: {}", fields),
+    argument_type: |argument, expected| format!("argument '{}' musí být typu {}", argument, expected),
+    argument_enum: |argument, allowed| format!("argument '{}' musí být jedna z hodnot: {}", argument, allowed),
+    argument_minimum: |argument, minimum| format!("argument '{}' musí být alespoň {}", argument, minimum),
+    argument_maximum: |argument, maximum| format!("argument '{}' může být nejvýše {}", argument, maximum),
+    argument_pattern: |argument, pattern| format!("argument '{}' neodpovídá vzoru {}", argument, pattern),
+    tool_timeout: |tool, seconds| format!(
+        "Tool '{}' nedoběhl do {} s - EasyProject odpovídá pomalu. Zkuste menší rozsah dat (limit, filtry) nebo volání zopakujte později.",
+        tool, seconds
+    ),
+    chunk_truncated: |bytes, pages, remaining| format!(
+        "Výsledek má {} B a byl rozdělen na {} stránek. \
+        Zobrazena je stránka 1, další stránky načtěte přes resources/read:\n{}",
+        bytes, pages, remaining
+    ),
+    chunk_limit_hint: |limit| format!("\nMenší výsledek získáte také nižším limitem (nyní {}).", limit),
+
+    error_fetching_enumerations: "Chyba při získávání číselníků",
+    error_fetching_custom_fields: "Chyba při získávání vlastních polí",
+    custom_fields_admin_hint: " (endpoint vyžaduje administrátora, pole projektu zjistíte přes get_project s include issue_custom_fields)",
+    found_milestones_total: |count, total| format!("Nalezeno {} milníků (celkem: {})", count, total),
+    error_fetching_milestones: "Chyba při získávání milníků",
+    error_fetching_project_versions: |project_id| format!("Chyba při získávání verzí projektu {}", project_id),
+    milestone_detail: |name| format!("Detail milníku '{}'", name),
+    error_fetching_milestone: |id| format!("Chyba při získávání milníku {}", id),
+    milestone_created: |name, id| format!("Milník '{}' byl úspěšně vytvořen s ID {}", name, id),
+    error_creating_milestone: "Chyba při vytváření milníku",
+    milestone_updated: |name| format!("Milník '{}' byl úspěšně aktualizován", name),
+    error_updating_milestone: |id| format!("Chyba při aktualizaci milníku {}", id),
+    milestone_deleted: |id| format!("Milník s ID {} byl úspěšně smazán", id),
+    error_deleting_milestone: |id| format!("Chyba při mazání milníku {}", id),
+
+    missing_parameter: |parameter| format!("Chybí povinný parametr '{}'", parameter),
+    empty_parameter: |parameter| format!("Parametr '{}' nesmí být prázdný", parameter),
+    error_loading_config: "Chyba při načítání konfigurace",
+    api_path_not_allowed: |path, patterns| format!("Cesta '{}' není povolena. Povolené vzory: {}", path, patterns),
+    query_key_not_allowed: "Query parametr 'key' není povolen",
+    query_value_type: |key, value| format!("Query parametr '{}' musí být řetězec, číslo nebo boolean, ne {}", key, value),
+    error_calling_api: |path| format!("Chyba při volání API '{}'", path),
+    instance_info: "Informace o instanci EasyProject",
+    server_info: |name, version| format!("Informace o MCP serveru {} {}", name, version),
+    audit_empty: "Audit neobsahuje žádné záznamy zápisových operací",
+    audit_summary: |count, failed| format!("Posledních {} zápisových operací ({} neúspěšných)", count, failed),
+
+    no_user_type_in_category: |category| format!(
+        "V EasyProject neexistuje žádný typ uživatele v kategorii '{}'. Dostupné typy zjistíte pomocí list_user_types.",
+        category
+    ),
+    user_workload: |mail, firstname, lastname| format!("Pracovní vytížení uživatele '{}' ({} {})", mail, firstname, lastname),
+    error_fetching_current_user: "Chyba při získávání aktuálního uživatele",
+    error_fetching_role: |id| format!("Chyba při získávání role {}", id),
+    error_fetching_roles: "Chyba při získávání rolí",
+
+    duplicate_time_entries: |listed| format!(
+        "Pravděpodobný duplicitní záznam - ve stejný den na stejném úkolu už existuje záznam s podobnými hodinami:\n{}\n\n\
+        Záznam nebyl vytvořen. Pokud jde opravdu o další práci, zopakujte volání s allow_duplicate: true.",
+        listed
+    ),
+    invalid_hours: "Počet hodin musí být mezi 0.01 a 24.0",
+    issue_or_project_required: "Musí být zadán alespoň jeden z parametrů 'issue_id' nebo 'project_id'",
+    time_entry_created: |id, hours, date| format!("Časový záznam úspěšně vytvořen s ID: {} ({} hodin na {})", id, hours, date),
+    error_creating_time_entry: "Chyba při vytváření časového záznamu",
+    no_time_entry_changes: "Není zadána žádná změna - uveďte alespoň jedno pole k aktualizaci",
+    error_updating_time_entry: |id| format!("Chyba při aktualizaci časového záznamu {}", id),
+    time_entry_updated_unreadable: |id| format!("Časový záznam {} byl aktualizován, ale nový stav se nepodařilo načíst", id),
+    time_entry_updated: |id, summary| format!("Časový záznam {} byl aktualizován: {}", id, summary),
+    time_entry_deleted: |id, hours, date, user, project, issue| format!(
+        "Časový záznam {} byl smazán ({} hodin, {}, {}, projekt {}{})",
+        id, hours, date, user, project,
+        issue.map(|issue| format!(", úkol #{}", issue)).unwrap_or_default()
+    ),
+    error_deleting_time_entry: |id| format!("Chyba při mazání časového záznamu {}", id),
+    move_target_required: "Zadejte právě jeden cíl: to_issue_id nebo to_project_id",
+    move_target_issue_unavailable: |id| format!("Cílový úkol {} nelze načíst", id),
+    move_source_required: "Zadejte time_entry_ids nebo zdroj (from_issue_id / from_project_id)",
+    error_selecting_time_entries: "Chyba při výběru časových záznamů",
+    error_parsing_arguments: "Chyba při parsování argumentů",
+    missing_log_time_arguments: "Chybí povinné parametry pro log_time",
+    user_not_resolved: |user| format!("Uživatele '{}' nelze určit", user),
+    time_logged: |hours, date, id| format!("✅ Čas úspěšně zalogován: {} hodin na {} (ID: {})", hours, date, id),
+    error_logging_time: "Chyba při logování času",
+
+    project_created: |name, id| format!("Projekt '{}' byl úspěšně vytvořen s ID {}", name, id),
+    error_creating_project: |name| format!("Chyba při vytváření projektu '{}'", name),
+    project_updated: |name, id| format!("Projekt '{}' (ID: {}) byl úspěšně aktualizován", name, id),
+    error_updating_project: |id| format!("Chyba při aktualizaci projektu {}", id),
+    error_fetching_project_before_delete: |id| format!("Chyba při získávání projektu {} před smazáním", id),
+    project_removed: |name, id| format!("Projekt '{}' (ID: {}) byl úspěšně smazán.", name, id),
+    error_deleting_project: |id, name| format!("Chyba při mazání projektu {} ({})", id, name),
+    project_modules: |name, id| format!("Moduly projektu '{}' (ID: {})", name, id),
+    modules_change_required: "Zadejte 'modules', 'enable' nebo 'disable'",
+    unknown_modules: |unknown, known| format!("Neznámé moduly: {}. Známé moduly: {}", unknown, known),
+    project_modules_unchanged: |name, id, modules| format!("Moduly projektu '{}' (ID: {}) se nemění: {}", name, id, modules),
+    project_modules_updated: |name, id| format!("Moduly projektu '{}' (ID: {}) byly aktualizovány", name, id),
+    error_updating_project_modules: |id| format!("Chyba při aktualizaci modulů projektu {}", id),
+    found_news_total: |count, total| format!("Nalezeno {} novinek (celkem: {})", count, total),
+    error_fetching_news: "Chyba při získávání novinek",
+    project_files: |id, count| format!("Projekt {} má {} souborů", id, count),
+    error_fetching_project_files: |id| format!("Chyba při získávání souborů projektu {}", id),
+    unknown_search_type: |unknown, allowed| format!("Neznámý typ '{}'. Povolené typy: {}", unknown, allowed),
+    error_searching: "Chyba při vyhledávání",
+    error_fetching_template_versions: |id| format!("Chyba při získávání milníků šablony {}", id),
+    template_plan: |name, template, categories, versions, issues, shift_days| format!(
+        "Plán vytvoření projektu '{}' ze šablony '{}' (dry_run - nic nebylo vytvořeno): {} kategorií, {} milníků, {} úkolů, posun termínů {} dní",
+        name, template, categories, versions, issues, shift_days
+    ),
+    template_category_failed: |name| format!("Kategorie '{}'", name),
+    template_version_failed: |name| format!("Milník '{}'", name),
+    template_statuses_unavailable: "Stavy úkolů nelze načíst",
+    template_issue_failed: |id, subject| format!("Úkol #{} '{}'", id, subject),
+    template_created_counts: |categories, categories_total, versions, versions_total, issues, issues_total| format!(
+        "{} z {} kategorií, {} z {} milníků, {} z {} úkolů",
+        categories, categories_total, versions, versions_total, issues, issues_total
+    ),
+    project_created_from_template: |name, id, template, counts, shift_days| format!(
+        "Projekt '{}' (ID {}) vytvořen ze šablony '{}': {} (posun termínů {} dní).",
+        name, id, template, counts, shift_days
+    ),
+    template_not_created: "Nepodařilo se vytvořit",
+
+    error_creating_issue: |subject| format!("Chyba při vytváření úkolu '{}'", subject),
+    issue_created: |subject, id| format!("Úkol '{}' byl úspěšně vytvořen s ID {}", subject, id),
+    batch_size: |max, given| format!("Dávka musí obsahovat 1 až {} úkolů (zadáno {})", max, given),
+    error_parsing_update_arguments: "Chyba při parsování argumentů pro aktualizaci úkolu",
+    missing_update_arguments: "Chybí argumenty pro aktualizaci úkolu",
+    error_updating_issue: |id| format!("Chyba při aktualizaci úkolu {}", id),
+    issue_updated: |subject, id| format!("Úkol '{}' (ID: {}) byl úspěšně aktualizován", subject, id),
+    error_fetching_issue_before_delete: |id| format!("Chyba při získávání úkolu {} před smazáním", id),
+    issue_removed: |subject, id| format!("Úkol '{}' (ID: {}) byl úspěšně smazán.", subject, id),
+    error_deleting_issue: |id, subject| format!("Chyba při mazání úkolu {} ({})", id, subject),
+    error_fetching_attachments: |id| format!("Chyba při získávání příloh úkolu {}", id),
+    error_fetching_attachment: |id| format!("Chyba při získávání přílohy {}", id),
+    error_downloading_attachment: |id| format!("Chyba při stahování přílohy {}", id),
+    attachment_summary: |filename, id, content_type, bytes| format!("Příloha '{}' (ID: {}, {}, {} B)", filename, id, content_type, bytes),
+    attachment_source_required: "Zadejte právě jeden z parametrů content, content_base64 nebo file_path",
+    attachment_too_large: |bytes, max_bytes| format!("Obsah má {} B, povolené maximum je {} B (tools.issues.max_attachment_bytes)", bytes, max_bytes),
+    error_uploading_file: |filename| format!("Chyba při nahrávání souboru {}", filename),
+    attachment_added: |filename, bytes, issue_id| format!("Soubor '{}' ({} B) byl připojen k úkolu {}.", filename, bytes, issue_id),
+    error_attaching_file: |filename, issue_id| format!("Chyba při připojování přílohy {} k úkolu {}", filename, issue_id),
+    watcher_added: |user_id, issue_id| format!("Uživatel {} nyní sleduje úkol {}.", user_id, issue_id),
+    error_adding_watcher: |user_id, issue_id| format!("Chyba při přidávání sledujícího {} k úkolu {}", user_id, issue_id),
+    watcher_removed: |user_id, issue_id| format!("Uživatel {} už nesleduje úkol {}.", user_id, issue_id),
+    error_removing_watcher: |user_id, issue_id| format!("Chyba při odebírání sledujícího {} z úkolu {}", user_id, issue_id),
+    error_fetching_user_load: |user_id| format!("Chyba při zjišťování vytížení uživatele {}", user_id),
+    assignment_preview: |issue_id, user| format!(
+        "Náhled vytížení před přiřazením úkolu {} uživateli {} (úkol nebyl změněn, \
+        pro přiřazení zavolejte assign_issue bez preview_load)",
+        issue_id, user
+    ),
+    assignee_required: "Musí být zadán parametr 'assigned_to_id' nebo 'assigned_to'",
+    issue_assigned: |issue_id, user_id| format!("Úkol {} byl úspěšně přiřazen uživateli {}.", issue_id, user_id),
+    issue_completed: |issue_id, done_ratio| format!("Úkol {} byl úspěšně označen jako dokončený ({}%).", issue_id, done_ratio),
+    error_fetching_statuses: "Chyba při získávání stavů úkolů",
+    workflow_transitions_hint: |status| format!(" (přechody povolené workflow ze stavu '{}')", status),
+    issue_already_in_status: |id, status| format!("Úkol {} už je ve stavu '{}'", id, status),
+    status_not_changed: |id, current, from, to| format!(
+        "Stav úkolu {} se nezměnil (zůstává '{}') - přechod '{}' -> '{}' workflow pravděpodobně nepovoluje",
+        id, current, from, to
+    ),
+    status_changed: |subject, id, from, to, with_notes| format!(
+        "Úkol '{}' (ID: {}) převeden ze stavu '{}' do '{}'{}.",
+        subject, id, from, to, if with_notes { " s komentářem" } else { "" }
+    ),
+    error_changing_status: |id| format!("Chyba při změně stavu úkolu {}", id),
+    issue_context: |subject, id| format!("Kontext úkolu '{}' (ID: {})", subject, id),
+    invalid_regex: "Neplatný regulární výraz",
+    error_scanning_project_issues: |project_id| format!("Chyba při procházení úkolů projektu {}", project_id),
+    no_description_matches: |scanned, project_id| format!("V popisech {} prohledaných úkolů projektu {} nebyla nalezena žádná shoda", scanned, project_id),
+    confirm_token_required: "Ostrá změna vyžaduje confirm_token z náhledu (nejdříve zavolejte s dry_run: true)",
+    preview_outdated: "Popisy úkolů nebo parametry se od náhledu změnily, proveďte nový náhled (dry_run: true)",
+    too_many_edits: |count, max| format!("Počet úkolů ({}) překračuje max_issues ({}), nic nebylo uloženo", count, max),
+    error_creating_export_dir: |dir| format!("Nelze vytvořit exportní adresář {}", dir),
+    error_creating_file: |path| format!("Nelze vytvořit soubor {}", path),
+    export_interrupted: |error, path, exported| format!(
+        "Chyba při načítání úkolů: {}. Soubor {} obsahuje jen prvních {} úkolů.",
+        error, path, exported
+    ),
+    error_copying_issue: |id| format!("Chyba při kopírování úkolu {}", id),
+    clone_version_unavailable: |id, version_id| format!("#{}: milník {} není v cílovém projektu dostupný, kopie je bez milníku", id, version_id),
+    clone_watcher_failed: |id, watcher| format!("#{}: sledujícího {} nelze přidat", id, watcher),
+    clone_attachment_failed: |id, filename| format!("#{}: přílohu '{}' nelze zkopírovat", id, filename),
+    clone_attach_failed: |id| format!("#{}: přílohy nelze připojit", id),
+    clone_subtask_limit: |max, remaining| format!("Zkopírováno nejvýše {} podúkolů, zbývající ({}) nebyly zkopírovány", max, remaining),
+    clone_subtask_unreadable: |id| format!("Podúkol #{} nelze načíst", id),
+    clone_subtask_failed: |id| format!("Podúkol #{} nelze zkopírovat", id),
+    issue_cloned: |source_id, id, subject, project| format!("Úkol #{} zkopírován jako #{} '{}' v projektu {}", source_id, id, subject, project),
+    including_subtasks: |count| format!(" včetně {} podúkolů", count),
+    warnings: "Upozornění",
+
+    project_report: |name, id| format!("Sestava pro projekt '{}' (ID: {})", name, id),
+    dashboard_data: "Dashboard data",
+    project_ids_required: "Parametr 'project_ids' musí obsahovat alespoň jeden projekt",
+    budget_requires_rate: "Parametr 'budget_amount' vyžaduje zadání 'hourly_rate'",
+    invalid_report_file_name: |file_name| format!("Neplatný název souboru sestavy: {}", file_name),
+    report_not_found: |file_name| format!("Sestava {} neexistuje", file_name),
+    pdf_report_saved: |path| format!("PDF sestava je uložena v souboru {}", path),
+    error_reading_report: |file_name| format!("Chyba při čtení sestavy {}", file_name),
+    report_dir_empty: |dir| format!("Adresář {} zatím neobsahuje žádné sestavy", dir),
+    error_reading_report_dir: "Chyba při čtení adresáře sestav",
+    no_generated_reports: "Nebyly nalezeny žádné vygenerované sestavy",
+    consistency_report: |project_id, findings| format!("Kontrola konzistence projektu {} ({} nálezů)", project_id, findings),
+    baseline_exists: |name, project_id| format!("Baseline '{}' projektu {} již existuje (pro přepsání použijte overwrite: true)", name, project_id),
+    error_saving_baseline: "Chyba při ukládání baseline",
+    baseline_not_found: |name, project_id| format!("Baseline '{}' projektu {} neexistuje", name, project_id),
+    error_loading_baseline: |name| format!("Chyba při načítání baseline '{}'", name),
+    baseline_variance: |project_id, name| format!(
+        "Odchylky projektu {} od baseline '{}' (chybějící úkoly mohou být uzavřené nebo smazané)",
+        project_id, name
+    ),
+    error_loading_project_issues: |project_id| format!("Chyba při načítání úkolů projektu {}", project_id),
+    project_schedule: |project_id, scheduled, unscheduled, critical| format!(
+        "Harmonogram projektu {} ({} naplánovaných úkolů, {} bez termínů, kritická cesta {} úkolů)",
+        project_id, scheduled, unscheduled, critical
+    ),
+    invalid_value: |parameter, value, allowed| format!("Neplatná hodnota '{}': {} (povoleno: {})", parameter, value, allowed),
+    error_loading_closed_issues: "Chyba při načítání uzavřených úkolů",
+    error_loading_time_entries: "Chyba při načítání časových záznamů",
+    team_velocity: |from, to, closed_trend, hours_trend| format!(
+        "Rychlost týmu od {} do {} (uzavřené úkoly: {}, hodiny: {})",
+        from, to, closed_trend, hours_trend
+    ),
+    error_loading_issues: "Chyba při načítání úkolů",
+    deadline_risks: |overdue, at_risk| format!("Úkoly po termínu: {}, ohrožené termíny: {}", overdue, at_risk),
+    date_range_reversed: "Parametr 'to_date' nesmí být dříve než 'from_date'",
+    capacity_plan: |from, to, business_days, over_allocated| format!(
+        "Plán kapacit {} - {} ({} pracovních dní, přetížení: {})",
+        from, to, business_days, over_allocated
+    ),
+    nobody: "žádní",
+    timesheet_period: |max_days| format!("Období výkazu musí mít 1 až {} dní a 'to_date' nesmí být před 'from_date'", max_days),
+
+    tool_group_labels: ["projekty", "úkoly", "uživatelé", "časové záznamy", "reporty", "milníky"],
+    instructions_header: |base_url, groups, tools| format!(
+        "EasyProject MCP Server pro správu projektů, úkolů a uživatelů prostřednictvím EasyProject API.\n\
+        Připojená instance: {}\n\
+        Povolené skupiny nástrojů: {} ({} nástrojů)\n",
+        base_url, groups, tools
+    ),
+    instructions_no_groups: "žádné",
+    instructions_read_only: "Režim: pouze pro čtení - data v EasyProject nelze měnit.\n",
+    instructions_unavailable: |tools| format!("Kvůli chybějícím oprávněním API klíče nejsou dostupné: {}\n", tools),
+    instructions_workflow: "\nDoporučený postup:\n",
+    instructions_enumerations: "- Před filtrováním úkolů podle stavu, priority nebo typu zavolejte get_issue_enumerations pro zjištění ID.\n",
+    instructions_projects: "- ID projektů zjistíte pomocí list_projects, výsledky seznamů jsou stránkované (limit/offset).\n",
+    instructions_versions: "- Před nastavením fixed_version_id ověřte dostupné verze pomocí list_assignable_versions.\n",
+    instructions_user_types: "- Pro filtrování uživatelů podle typu použijte list_users s parametrem user_type.\n",
+    instructions_chunks: "- Dlouhé výsledky jsou rozděleny na stránky, další stránky načtěte přes resources/read.\n",
+    instructions_resources: "- Detail entity lze načíst i jako resource, např. easyproject://issue/{id}.\n",
+
+    error_creating_api_client: "Nepodařilo se vytvořit API klient",
+    server_not_initialized: "Server není inicializován",
+    invalid_request_params: |method| format!("Neplatné parametry pro {}", method),
+    missing_request_params: |method| format!("Chybí parametry pro {}", method),
+    resource_not_found: |uri| format!("Resource '{}' neexistuje nebo už vypršel", uri),
+    result_page_name: |total| format!("Stránka výsledku ({} stránek celkem)", total),
+
+    issue_enumerations: "Číselníky pro filtrování úkolů",
+    enumeration_statuses: "STAVY (status_id)",
+    enumeration_priorities: "PRIORITY (priority_id)",
+    enumeration_trackers: "TYPY ÚKOLŮ (tracker_id)",
+    enumerations_usage: "Použití:\n\
+        - Pro filtrování podle statusu: list_issues s parametrem status_id=<ID>\n\
+        - Pro filtrování podle priority: list_issues s parametrem priority_id=<ID>\n\
+        - Pro filtrování podle typu: list_issues s parametrem tracker_id=<ID>\n",
+
+    missing_parameters: |parameters| format!("Chybí povinné parametry: {}", parameters),
+    missing_tool_arguments: |tool| format!("Chybí argumenty pro {}", tool),
+
+    assignable_versions: |project_id, count, total| format!("Přiřaditelné verze pro projekt {} ({} z {})", project_id, count, total),
+    no_assignable_versions: "Projekt nemá žádné otevřené vlastní ani sdílené verze.",
+    version_shared_from: |project, id, sharing| format!("sdíleno z projektu '{}' (ID: {}, sdílení: {})", project, id, sharing),
+    due_on: "termín",
+
+    found_search_results: |count, query, total| format!("Nalezeno {} výsledků pro '{}' (celkem: {})", count, query, total),
+
+    my_work_summary: |name, id, login, day, hours, entries, issues| format!(
+        "Přihlášen jako {} (ID {}{})\n\nDnes ({}): odpracováno {:.2} h v {} záznamech\nOtevřené přiřazené úkoly: {}\n",
+        name, id, login, day, hours, entries, issues
+    ),
+    overdue: "Po termínu",
+    due_within_days: |days| format!("Termín do {} dní", days),
+    user_types_heading: |count| format!("Typy uživatelů ({})", count),
+    category: "kategorie",
+    unspecified: "neurčeno",
+    default_flag: "výchozí",
+    roles_heading: |count| format!("Role ({})", count),
+    not_assignable: "nelze přidělit",
+
+    api_key_permissions: "Oprávnění API klíče",
+    probed_at: "Ověřeno",
+    probe_allowed: "✅ povoleno",
+    probe_denied: "⛔ zamítnuto",
+    probe_unknown: "❔ neověřeno",
+    all_tools_available: "Všechny tools jsou dostupné.",
+    unavailable_tools: "Nedostupné tools",
+    permission_area_labels: ["Čtení projektů", "Zakládání projektů", "Čtení úkolů", "Čtení časových záznamů", "Seznam uživatelů", "Administrace"],
+    user_type_categories: ["interní", "externí", "klient"],
+    config_diagnostics: "Diagnostika konfigurace",
+    config_load_failed: |error| format!("⚠️ Konfiguraci nešlo načíst vcelku: {}", error),
+    config_failed_keys: "Neplatné hodnoty (použita výchozí hodnota)",
+    config_unknown_keys: "Neznámé klíče (ignorovány, zkontrolujte překlepy)",
+    config_env_overrides: "Přepsáno z environment proměnných",
+    config_defaulted_keys: |count| format!("Výchozí hodnoty ({})", count),
+    config_defaulted_count: |count| format!("Výchozí hodnota použita pro {} klíčů (vypíšete je s include_defaulted=true).", count),
+    config_validation_failed: |error| format!("❌ Validace selhala: {}", error),
+    config_valid_with_problems: "⚠️ Konfigurace je platná, ale obsahuje výše uvedené problémy.",
+    config_ok: "✅ Konfigurace je v pořádku.",
+    instance_enumeration_labels: ["Fronty (trackery)", "Stavy úkolů", "Priority", "Aktivity časových záznamů", "Role", "Vlastní pole"],
+    instance_plugin_labels: ["Kontakty", "CRM případy", "Docházka", "Typy uživatelů"],
+    current_user_unavailable: "Aktuálního uživatele nelze zjistit",
+    instance_version_unknown: "nezjištěno - REST API verzi nevystavuje, stránka /admin/info vyžaduje administrátora",
+    project_modules_unavailable: "Moduly projektů nelze zjistit",
+    cache_summary: |entries, max_entries, ratio, hits, misses| format!("Cache API klienta: {} z {} záznamů, úspěšnost {:.0} % ({} zásahů, {} výpadků)", entries, max_entries, ratio, hits, misses),
+    cache_disabled: "Cache API klienta je vypnutá (cache.enabled = false)",
+    cache_pattern_cleared: |pattern, removed, before| format!("Z cache odstraněny záznamy odpovídající vzoru '{}' ({} z {} záznamů)", pattern, removed, before),
+    cache_cleared: |count| format!("Cache vymazána ({} záznamů)", count),
+    connection_ok: |base_url, user, latency, version| format!("✅ Spojení s {} je v pořádku: přihlášen uživatel {}, odezva {} ms{}", base_url, user, latency, version),
+    version_suffix: |version| format!(", verze {}", version),
+    connection_auth_failed: |base_url, latency, error| format!(
+        "❌ Instance {} odpovídá, ale ověření selhalo (odezva {} ms): {}\n\
+        Zkontrolujte API klíč (případně uživatelské jméno a heslo) a jeho oprávnění.",
+        base_url, latency, error
+    ),
+    connection_unreachable: |base_url, error| format!("❌ Instance {} není dostupná: {}\nZkontrolujte base_url a síťové spojení.", base_url, error),
+    capabilities_not_probed: "neověřeno - zapněte tools.probe_permissions nebo zavolejte get_permissions",
+
+    issue_unreadable: |id| format!("Úkol {} nelze načíst (neexistuje nebo k němu nemáte přístup)", id),
+    issue_project_mismatch: |issue_id, project_name, issue_project_id, project_id| format!(
+        "Úkol {} patří do projektu '{}' (ID {}), ne do projektu {}. \
+        Vynechte project_id (doplní se z úkolu) nebo použijte project_id {}.",
+        issue_id, project_name, issue_project_id, project_id, issue_project_id
+    ),
+    time_entry_change_labels: ["hodiny", "datum", "aktivita", "projekt", "úkol", "komentář"],
+    no_value_changes: "beze změny hodnot",
+    issue_target: |id| format!("úkol #{}", id),
+    project_target: |project| format!("projekt {}", project),
+    time_entries_move_heading: |dry_run, count, hours, target| format!("{} {} časových záznamů ({:.2} h) na {}", if dry_run { "Náhled přesunu" } else { "Přesun" }, count, hours, target),
+    moved_from: "z",
+    entries_already_on_target: |count| format!("{} záznamů už je na cíli, přeskočeno.", count),
+    dry_run_no_changes: "Žádné změny nebyly provedeny (dry_run).",
+    moved_summary: |moved, failed| format!("Přesunuto: {}, chyby: {}", moved, failed),
+    bulk_hours_per_day_required: "Pro období (from_date, to_date) je povinný parametr 'hours_per_day'",
+    to_date_before_from_date: "Parametr 'to_date' nesmí být před 'from_date'",
+    bulk_period_incomplete: "Období musí mít zadané 'from_date' i 'to_date'",
+    bulk_entries_missing: "Zadejte 'entries' nebo období 'from_date' a 'to_date' s 'hours_per_day'",
+    bulk_too_many_entries: |max, count| format!("Najednou lze vytvořit nejvýše {} záznamů (zadáno {})", max, count),
+    bulk_entry_invalid: |index, date, problem| format!("Záznam {} ({}): {}", index, date, problem),
+    bulk_entry_missing_target: "chybí 'issue_id' nebo 'project_id'",
+    bulk_entry_missing_activity: "chybí 'activity_id'",
+    bulk_entry_invalid_hours: "počet hodin musí být mezi 0.01 a 24.0",
+    probable_duplicate: |id| format!("pravděpodobný duplikát existujícího záznamu #{} (použijte allow_duplicate: true)", id),
+    task_aborted: "úloha byla přerušena",
+    bulk_created_summary: |created, total, hours| format!("Vytvořeno {} z {} časových záznamů, celkem {:.2} h.", created, total, hours),
+    bulk_not_created: "Nevytvořené záznamy",
+
+    custom_field_key_not_numeric: |key| format!("Klíč vlastního pole '{}' není číselné ID pole", key),
+    custom_field_value_object: |id| format!("Hodnota vlastního pole {} nesmí být objekt", id),
+    assignee_unresolved: |name| format!("Řešitele '{}' nelze určit", name),
+    batch_parent_conflict: |index| format!("Úkol [{}] má zadané parent_index i parent_issue_id, použijte jen jedno", index),
+    batch_parent_missing: |index, parent| format!("Úkol [{}] odkazuje na neexistující parent_index {}", index, parent),
+    batch_parent_cycle: |index| format!("Úkol [{}] je součástí cyklu v parent_index", index),
+    batch_skipped_after_error: "Nevytvořeno po předchozí chybě (stop_on_error)",
+    batch_parent_not_created: |parent| format!("Nadřazený úkol [{}] nebyl vytvořen", parent),
+    batch_value_missing: |name| format!("Chybí {} (zadejte u úkolu nebo v defaults)", name),
+    batch_created: |created, total| format!("Vytvořeno {} z {} úkolů", created, total),
+    upload_disabled: "Nahrávání souborů z disku je vypnuté (nastavte tools.issues.upload_dir)",
+    directory_unavailable: |dir| format!("Adresář {} není dostupný", dir),
+    file_unavailable: |file| format!("Soubor {} není dostupný", file),
+    file_outside_upload_dir: |file, dir| format!("Soubor {} leží mimo povolený adresář {}", file, dir),
+    file_too_large: |file, size, max| format!("Soubor {} má {} B, povolené maximum je {} B", file, size, max),
+    file_unreadable: |file| format!("Soubor {} nelze načíst", file),
+    invalid_base64: "Neplatný base64 obsah",
+    user_fallback_name: |id| format!("uživatel {}", id),
+    remaining_hours_rule: "odhad hodin × (100 % − procento dokončení), jen otevřené úkoly",
+    status_param_invalid: "Parametr 'status' musí být název nebo ID stavu",
+    status_id_unavailable: |id, names| format!("Stav s ID {} není k dispozici. Možné stavy: {}", id, names),
+    status_not_found: |name, names| format!("Stav '{}' nebyl nalezen. Možné stavy: {}", name, names),
+    status_ambiguous: |name, matches| format!("Název '{}' odpovídá více stavům: {}. Upřesněte název nebo použijte ID", name, matches),
+    error_fetching_parent_issue: "Chyba při získávání nadřazeného úkolu",
+    bulk_edit_preview: |issues, matches| format!("Náhled změn (nic nebylo uloženo): {} úkolů, {} shod", issues, matches),
+    bulk_edit_scan_truncated: "⚠️ Průchod úkolů byl omezen limitem max_scan_records, některé úkoly nemusí být zahrnuty",
+    match_count: |count| format!("{} shod", count),
+    bulk_edit_too_many: |count, max| format!("⚠️ Počet úkolů ({}) překračuje max_issues ({}), upřesněte hledaný výraz nebo zvyšte limit", count, max),
+    bulk_edit_confirm_hint: |token| format!("Pro uložení zavolejte znovu se stejnými parametry, dry_run: false a confirm_token: \"{}\"", token),
+    descriptions_updated: |count| format!("Upraveno popisů úkolů: {}", count),
+    failed_edits: |count| format!("Neúspěšné úpravy ({})", count),
+    issues_exported: |count, path| format!("Exportováno {} úkolů do souboru {}", count, path),
+    export_limited: |exported, total| format!("Export omezen parametrem max_records ({} z {} úkolů)", exported, total),
+    found_issues_for: |count, query| format!("Nalezeno {} úkolů pro '{}'", count, query),
+    of_matching: |total| format!(" (z {} odpovídajících)", total),
+    unassigned: "nepřiřazeno",
+
+    batch_issues_description: |max| format!("Definice úkolů (povinné, nejvýše {})", max),
+    raw_api_path_description: |patterns| format!("Cesta relativně k base_url bez přípony .json, např. 'trackers' nebo 'issues/123'. Povolené vzory: {}", patterns),
+
+    error_fetching_project_members: "Chyba při získávání členů projektu",
+    assignment_history_heading: |user_id, from, to| format!("Historie přiřazení uživatele {} (období: {} – {})", user_id, from, to),
+    period_start: "začátek",
+    today: "dnes",
+    no_assignment_changes: "V daném období nebyly nalezeny žádné změny přiřazení.",
+    assigned_action: "přiřazeno",
+    unassigned_action: "odebráno",
+    previously_user: |id| format!(" (předtím: uživatel {})", id),
+    newly_user: |id| format!(" (nově: uživatel {})", id),
+    changed_by: |name| format!(", změnil: {}", name),
+    assignment_summary: |assigned, unassigned, scanned| format!("Souhrn: {} přiřazení, {} odebrání, prošlo {} úkolů", assigned, unassigned, scanned),
+    limited_by_max_issues: |max| format!(" (omezeno parametrem max_issues = {})", max),
+    issue_history_failed: |count| format!(", historii {} úkolů se nepodařilo načíst", count),
+    budget_burn_heading: |days, warning, critical| format!("Čerpání rozpočtu (tempo za posledních {} dní, varování od {:.0} %, kritické od {:.0} %)", days, warning, critical),
+    budget_project_failed: |id| format!("Projekt {}: chyba při načítání", id),
+    budget_estimates_failed: |name, id| format!("Projekt '{}' ({}): chyba při načítání odhadů", name, id),
+    budget_time_entries_failed: |name, id| format!("Projekt '{}' ({}): chyba při načítání časových záznamů", name, id),
+    budget_sources: ["zadaný rozpočet", "finanční rozpočet / sazba", "součet odhadů úkolů"],
+    budget_unknown: |spent, burn| format!("Rozpočet: neznámý (žádné odhady), odpracováno {:.1} h, tempo {:.1} h/den", spent, burn),
+    budget_consumption: |budget, source, spent, percent, remaining| format!("Rozpočet: {:.1} h ({}), odpracováno: {:.1} h ({:.0} %), zbývá: {:.1} h", budget, source, spent, percent, remaining),
+    budget_costs: |spent, budget| format!("Náklady: {:.0} z {:.0}", spent, budget),
+    burn_rate: |burn| format!("Tempo čerpání: {:.1} h/den", burn),
+    budget_exhausted: "Rozpočet je již vyčerpán",
+    budget_exhaustion_estimate: |date, days| format!("Odhad vyčerpání: {} (za {} dní při současném tempu)", date, days),
+    budget_no_recent_burn: "Odhad vyčerpání: v posledním období se nečerpalo",
+    budget_status_critical: "🔴 KRITICKÉ",
+    budget_status_warning: "🟠 VAROVÁNÍ",
+    budget_status: "Stav",
+    no_project_over_threshold: "Žádný projekt nepřekročil práh varování.",
+    projects_over_threshold: "Projekty nad prahem",
+    generated_reports_heading: |dir, total| format!("Vygenerované sestavy v {} ({} celkem)", dir, total),
+    generated_report_line: |file, project_id, generated_at, size| format!("• {} - projekt {}, {} ({} B)", file, project_id, generated_at, size),
+    more_reports: |count| format!("... a dalších {} sestav", count),
+    check_subtask_due_after_parent: "Podúkol má termín po termínu nadřazeného úkolu",
+    check_closed_parent_open_children: "Nadřazený úkol je uzavřený, ale podúkol je otevřený",
+    check_assigned_to_locked_user: "Otevřený úkol je přiřazen zablokovanému uživateli (upravte assigned_to_id)",
+    locked_users_unavailable: "Nepodařilo se načíst zablokované uživatele",
+    check_time_logged_after_close: "Čas vykázaný na uzavřený úkol po jeho uzavření (přesuňte na jiný úkol)",
+    baseline_saved: |name, project, project_id, issues, hours, file| format!("Baseline '{}' projektu '{}' (ID: {}) uložen: {} úkolů, odhad celkem {:.1} h\nSoubor: {}", name, project, project_id, issues, hours, file),
+    baseline_scan_truncated: "⚠️ Průchod úkolů byl omezen limitem max_scan_records, baseline nemusí obsahovat všechny úkoly",
+    no_baselines: |id| format!("Projekt {} nemá žádný uložený baseline (vytvořte jej pomocí create_baseline)", id),
+    project_baselines: |id, names| format!("Baseline projektu {}: {}", id, names),
+
+    schedule_title: |id| format!("Projekt {}", id),
+    schedule_default_section: "Úkoly",
+    velocity_trends: ["nedostatek dat", "stabilní", "rostoucí", "klesající"],
+    team: "Tým",
+    risk_levels: ["kritická", "vysoká", "střední"],
+    unassigned_group: "Nepřiřazeno",
+    total: "Celkem",
+    timesheet_title: |from, to| format!("Výkaz práce {} - {}", from, to),
+    workdays_without_time: "Pracovní dny bez vykázaného času",
+    portfolio_health_levels: ["kritický", "varování", "v pořádku"],
+    portfolio_table_header: "| # | Projekt | Stav | Otevřené | Po termínu | Nepřiřazené | Zbývá (h) | Odpracováno (h) |",
+    portfolio_heading: |count, from, to| format!("Přehled portfolia ({} aktivních projektů, hodiny {} - {})", count, from, to),
+    shown_first_projects: |shown, total| format!("Zobrazeno prvních {} z {} projektů.", shown, total),
+    portfolio_scan_truncated: "Upozornění: průchod dat byl omezen limitem max_scan_records, počty mohou být neúplné.",
+};
+
+pub static EN: Messages = Messages {
+    locale: Locale::En,
+
+    project: "Project",
+    issue: "Issue",
+    user: "User",
+    time_entry: "Time entry",
+    status: "Status",
+    description: "Description",
+    identifier: "Identifier",
+    homepage: "Homepage",
+    parent_project: "Parent project",
+    created: "Created",
+    updated: "Updated",
+    tracker: "Tracker",
+    priority: "Priority",
+    author: "Author",
+    assigned_to: "Assigned to",
+    estimated_hours: "Estimated hours",
+    spent_hours: "Spent hours",
+    done_ratio: "Done",
+    start_date: "Start date",
+    due_date: "Due date",
+    attachments: "Attachments",
+    uploaded: "Uploaded",
+    login: "Login",
+    email: "Email",
+    role: "Role",
+    administrator: "Administrator",
+    last_login: "Last login",
+    hours: "hours",
+    activity: "Activity",
+    date: "Date",
+    comment: "Comment",
+    subject: "Subject",
+    name: "Name",
+    user_type: "Type",
+    issue_subject: "Issue subject",
+    total_hours: "Total hours",
+    yes: "yes",
+    no: "no",
+    error: "Error",
+
+    project_active: "Active",
+    project_closed: "Closed",
+    project_archived: "Archived",
+    project_planned: "Planned",
+    project_deleted: "Deleted",
+    user_active: "Active",
+    user_registered: "Registered",
+    user_locked: "Locked",
+    unknown: "Unknown",
+
+    no_projects: "No projects found.",
+    no_issues: "No issues found.",
+    no_users: "No users found.",
+    no_time_entries: "No time entries found.",
+    no_records: "No records found.",
+    found_projects: |count| format!("Found {} projects", count),
+    found_issues: |count| format!("Found {} issues", count),
+    found_users: |count| format!("Found {} users", count),
+    found_time_entries: |count| format!("Found {} time entries", count),
+    found_projects_total: |count, total| format!("Found {} projects (total: {})", count, total),
+    found_issues_total: |count, total| format!("Found {} issues (total: {})", count, total),
+    found_users_total: |count, total| format!("Found {} users (total: {})", count, total),
+    found_time_entries_total: |count, total, hours| format!("Found {} time entries (total: {}, {} hours)", count, total, hours),
+
+    project_detail: |name| format!("Project '{}'", name),
+    issue_detail: |subject| format!("Issue '{}'", subject),
+    user_detail: |firstname, lastname| format!("User '{} {}'", firstname, lastname),
+    time_entry_detail: |id, hours, date, project| format!("Time entry {} ({} h, {}, project {})", id, hours, date, project),
+
+    missing_id: "Missing required parameter 'id'",
+    error_fetching_projects: "Error fetching projects",
+    error_fetching_project: |id| format!("Error fetching project {}", id),
+    error_fetching_issues: "Error fetching issues",
+    error_fetching_issue: |id| format!("Error fetching issue {}", id),
+    error_fetching_users: "Error fetching users",
+    error_fetching_user: |id| format!("Error fetching user {}", id),
+    error_fetching_user_types: "Error fetching user types",
+    error_fetching_time_entries: "Error fetching time entries",
+    error_fetching_time_entry: |id| format!("Error fetching time entry {}", id),
+    invalid_date: |parameter, value| format!("Invalid date format '{}': {}. Expected format: YYYY-MM-DD", parameter, value),
+    issue_unavailable: "Issue cannot be loaded",
+
+    format_description: "Output format: json (default), text (readable overview), markdown, compact (single-line JSON)",
+    columns_description: |defaults| format!("Table columns for format=markdown and export_format=csv (default: {})", defaults),
+    export_format_description: "Export for spreadsheets: csv (';' separator, first row is the header) - the output is only the CSV text, the format parameter is ignored",
+    unknown_column: |column, available| format!("Unknown column '{}'. Available columns: {}", column, available),
+
+    unavailable_prefix: "[UNAVAILABLE - missing permissions]",
+    fresh_description: "Bypass the short-lived result cache and fetch current data (default: false)",
+    instance_description: |default| format!("EasyProject instance to run the tool against (default: {})", default),
+    unknown_instance: |instance, available| format!("Unknown instance '{}'. Available instances: {}", instance, available),
+    project_reference_hint: "A project identifier or name can be given instead of the ID",
+    project_not_resolved: |reference, error| format!("Project '{}' cannot be resolved: {}", reference, error),
+    tool_unavailable: |tool| format!(
+        "Tool '{}' is not available - the API key lacks the required permissions. See the get_permissions tool for details.",
+        tool
+    ),
+    tool_not_found: |tool| format!("Tool '{}' not found", tool),
+    invalid_arguments: |tool, errors| format!("Invalid arguments for tool '{}': {}", tool, errors),
+    arguments_not_object: "arguments must be a JSON object",
+    missing_arguments: |fields| format!("missing required arguments: {}", fields),
+    argument_type: |argument, expected| format!("argument '{}' must be of type {}", argument, expected),
+    argument_enum: |argument, allowed| format!("argument '{}' must be one of: {}", argument, allowed),
+    argument_minimum: |argument, minimum| format!("argument '{}' must be at least {}", argument, minimum),
+    argument_maximum: |argument, maximum| format!("argument '{}' must be at most {}", argument, maximum),
+    argument_pattern: |argument, pattern| format!("argument '{}' does not match pattern {}", argument, pattern),
+    tool_timeout: |tool, seconds| format!(
+        "Tool '{}' did not finish within {} s - EasyProject is responding slowly. Try a smaller data range (limit, filters) or retry later.",
+        tool, seconds
+    ),
+    chunk_truncated: |bytes, pages, remaining| format!(
+        "The result has {} B and was split into {} pages. \
+        Page 1 is shown, load the remaining pages via resources/read:\n{}",
+        bytes, pages, remaining
+    ),
+    chunk_limit_hint: |limit| format!("\nA lower limit also gives a smaller result (currently {}).", limit),
+
+    error_fetching_enumerations: "Error fetching enumerations",
+    error_fetching_custom_fields: "Error fetching custom fields",
+    custom_fields_admin_hint: " (the endpoint requires an administrator, project fields are available via get_project with include issue_custom_fields)",
+    found_milestones_total: |count, total| format!("Found {} milestones (total: {})", count, total),
+    error_fetching_milestones: "Error fetching milestones",
+    error_fetching_project_versions: |project_id| format!("Error fetching versions of project {}", project_id),
+    milestone_detail: |name| format!("Milestone detail '{}'", name),
+    error_fetching_milestone: |id| format!("Error fetching milestone {}", id),
+    milestone_created: |name, id| format!("Milestone '{}' was created with ID {}", name, id),
+    error_creating_milestone: "Error creating milestone",
+    milestone_updated: |name| format!("Milestone '{}' was updated", name),
+    error_updating_milestone: |id| format!("Error updating milestone {}", id),
+    milestone_deleted: |id| format!("Milestone with ID {} was deleted", id),
+    error_deleting_milestone: |id| format!("Error deleting milestone {}", id),
+
+    missing_parameter: |parameter| format!("Missing required parameter '{}'", parameter),
+    empty_parameter: |parameter| format!("Parameter '{}' must not be empty", parameter),
+    error_loading_config: "Error loading configuration",
+    api_path_not_allowed: |path, patterns| format!("Path '{}' is not allowed. Allowed patterns: {}", path, patterns),
+    query_key_not_allowed: "Query parameter 'key' is not allowed",
+    query_value_type: |key, value| format!("Query parameter '{}' must be a string, number or boolean, not {}", key, value),
+    error_calling_api: |path| format!("Error calling API '{}'", path),
+    instance_info: "EasyProject instance information",
+    server_info: |name, version| format!("MCP server information {} {}", name, version),
+    audit_empty: "The audit log contains no write operations",
+    audit_summary: |count, failed| format!("Last {} write operations ({} failed)", count, failed),
+
+    no_user_type_in_category: |category| format!(
+        "EasyProject has no user type in category '{}'. Available types are listed by list_user_types.",
+        category
+    ),
+    user_workload: |mail, firstname, lastname| format!("Workload of user '{}' ({} {})", mail, firstname, lastname),
+    error_fetching_current_user: "Error fetching the current user",
+    error_fetching_role: |id| format!("Error fetching role {}", id),
+    error_fetching_roles: "Error fetching roles",
+
+    duplicate_time_entries: |listed| format!(
+        "Probable duplicate entry - an entry with similar hours already exists for the same issue on the same day:\n{}\n\n\
+        The entry was not created. If this really is additional work, repeat the call with allow_duplicate: true.",
+        listed
+    ),
+    invalid_hours: "Hours must be between 0.01 and 24.0",
+    issue_or_project_required: "At least one of the parameters 'issue_id' or 'project_id' is required",
+    time_entry_created: |id, hours, date| format!("Time entry created with ID: {} ({} hours on {})", id, hours, date),
+    error_creating_time_entry: "Error creating time entry",
+    no_time_entry_changes: "No change given - provide at least one field to update",
+    error_updating_time_entry: |id| format!("Error updating time entry {}", id),
+    time_entry_updated_unreadable: |id| format!("Time entry {} was updated, but its new state could not be loaded", id),
+    time_entry_updated: |id, summary| format!("Time entry {} was updated: {}", id, summary),
+    time_entry_deleted: |id, hours, date, user, project, issue| format!(
+        "Time entry {} was deleted ({} hours, {}, {}, project {}{})",
+        id, hours, date, user, project,
+        issue.map(|issue| format!(", issue #{}", issue)).unwrap_or_default()
+    ),
+    error_deleting_time_entry: |id| format!("Error deleting time entry {}", id),
+    move_target_required: "Provide exactly one target: to_issue_id or to_project_id",
+    move_target_issue_unavailable: |id| format!("Target issue {} cannot be loaded", id),
+    move_source_required: "Provide time_entry_ids or a source (from_issue_id / from_project_id)",
+    error_selecting_time_entries: "Error selecting time entries",
+    error_parsing_arguments: "Error parsing arguments",
+    missing_log_time_arguments: "Missing required parameters for log_time",
+    user_not_resolved: |user| format!("User '{}' cannot be resolved", user),
+    time_logged: |hours, date, id| format!("✅ Time logged: {} hours on {} (ID: {})", hours, date, id),
+    error_logging_time: "Error logging time",
+
+    project_created: |name, id| format!("Project '{}' was created with ID {}", name, id),
+    error_creating_project: |name| format!("Error creating project '{}'", name),
+    project_updated: |name, id| format!("Project '{}' (ID: {}) was updated", name, id),
+    error_updating_project: |id| format!("Error updating project {}", id),
+    error_fetching_project_before_delete: |id| format!("Error fetching project {} before deletion", id),
+    project_removed: |name, id| format!("Project '{}' (ID: {}) was deleted.", name, id),
+    error_deleting_project: |id, name| format!("Error deleting project {} ({})", id, name),
+    project_modules: |name, id| format!("Modules of project '{}' (ID: {})", name, id),
+    modules_change_required: "Provide 'modules', 'enable' or 'disable'",
+    unknown_modules: |unknown, known| format!("Unknown modules: {}. Known modules: {}", unknown, known),
+    project_modules_unchanged: |name, id, modules| format!("Modules of project '{}' (ID: {}) are unchanged: {}", name, id, modules),
+    project_modules_updated: |name, id| format!("Modules of project '{}' (ID: {}) were updated", name, id),
+    error_updating_project_modules: |id| format!("Error updating modules of project {}", id),
+    found_news_total: |count, total| format!("Found {} news (total: {})", count, total),
+    error_fetching_news: "Error fetching news",
+    project_files: |id, count| format!("Project {} has {} files", id, count),
+    error_fetching_project_files: |id| format!("Error fetching files of project {}", id),
+    unknown_search_type: |unknown, allowed| format!("Unknown type '{}'. Allowed types: {}", unknown, allowed),
+    error_searching: "Error searching",
+    error_fetching_template_versions: |id| format!("Error fetching milestones of template {}", id),
+    template_plan: |name, template, categories, versions, issues, shift_days| format!(
+        "Plan for creating project '{}' from template '{}' (dry_run - nothing was created): {} categories, {} milestones, {} issues, dates shifted by {} days",
+        name, template, categories, versions, issues, shift_days
+    ),
+    template_category_failed: |name| format!("Category '{}'", name),
+    template_version_failed: |name| format!("Milestone '{}'", name),
+    template_statuses_unavailable: "Issue statuses cannot be loaded",
+    template_issue_failed: |id, subject| format!("Issue #{} '{}'", id, subject),
+    template_created_counts: |categories, categories_total, versions, versions_total, issues, issues_total| format!(
+        "{} of {} categories, {} of {} milestones, {} of {} issues",
+        categories, categories_total, versions, versions_total, issues, issues_total
+    ),
+    project_created_from_template: |name, id, template, counts, shift_days| format!(
+        "Project '{}' (ID {}) created from template '{}': {} (dates shifted by {} days).",
+        name, id, template, counts, shift_days
+    ),
+    template_not_created: "Could not be created",
+
+    error_creating_issue: |subject| format!("Error creating issue '{}'", subject),
+    issue_created: |subject, id| format!("Issue '{}' was created with ID {}", subject, id),
+    batch_size: |max, given| format!("The batch must contain 1 to {} issues ({} given)", max, given),
+    error_parsing_update_arguments: "Error parsing arguments for the issue update",
+    missing_update_arguments: "Missing arguments for the issue update",
+    error_updating_issue: |id| format!("Error updating issue {}", id),
+    issue_updated: |subject, id| format!("Issue '{}' (ID: {}) was updated", subject, id),
+    error_fetching_issue_before_delete: |id| format!("Error fetching issue {} before deletion", id),
+    issue_removed: |subject, id| format!("Issue '{}' (ID: {}) was deleted.", subject, id),
+    error_deleting_issue: |id, subject| format!("Error deleting issue {} ({})", id, subject),
+    error_fetching_attachments: |id| format!("Error fetching attachments of issue {}", id),
+    error_fetching_attachment: |id| format!("Error fetching attachment {}", id),
+    error_downloading_attachment: |id| format!("Error downloading attachment {}", id),
+    attachment_summary: |filename, id, content_type, bytes| format!("Attachment '{}' (ID: {}, {}, {} B)", filename, id, content_type, bytes),
+    attachment_source_required: "Provide exactly one of the parameters content, content_base64 or file_path",
+    attachment_too_large: |bytes, max_bytes| format!("The content has {} B, the allowed maximum is {} B (tools.issues.max_attachment_bytes)", bytes, max_bytes),
+    error_uploading_file: |filename| format!("Error uploading file {}", filename),
+    attachment_added: |filename, bytes, issue_id| format!("File '{}' ({} B) was attached to issue {}.", filename, bytes, issue_id),
+    error_attaching_file: |filename, issue_id| format!("Error attaching {} to issue {}", filename, issue_id),
+    watcher_added: |user_id, issue_id| format!("User {} is now watching issue {}.", user_id, issue_id),
+    error_adding_watcher: |user_id, issue_id| format!("Error adding watcher {} to issue {}", user_id, issue_id),
+    watcher_removed: |user_id, issue_id| format!("User {} no longer watches issue {}.", user_id, issue_id),
+    error_removing_watcher: |user_id, issue_id| format!("Error removing watcher {} from issue {}", user_id, issue_id),
+    error_fetching_user_load: |user_id| format!("Error determining the workload of user {}", user_id),
+    assignment_preview: |issue_id, user| format!(
+        "Workload preview before assigning issue {} to user {} (the issue was not changed, \
+        call assign_issue without preview_load to assign it)",
+        issue_id, user
+    ),
+    assignee_required: "Parameter 'assigned_to_id' or 'assigned_to' is required",
+    issue_assigned: |issue_id, user_id| format!("Issue {} was assigned to user {}.", issue_id, user_id),
+    issue_completed: |issue_id, done_ratio| format!("Issue {} was marked as done ({}%).", issue_id, done_ratio),
+    error_fetching_statuses: "Error fetching issue statuses",
+    workflow_transitions_hint: |status| format!(" (transitions allowed by the workflow from status '{}')", status),
+    issue_already_in_status: |id, status| format!("Issue {} is already in status '{}'", id, status),
+    status_not_changed: |id, current, from, to| format!(
+        "The status of issue {} did not change (still '{}') - the workflow probably does not allow the transition '{}' -> '{}'",
+        id, current, from, to
+    ),
+    status_changed: |subject, id, from, to, with_notes| format!(
+        "Issue '{}' (ID: {}) moved from status '{}' to '{}'{}.",
+        subject, id, from, to, if with_notes { " with a comment" } else { "" }
+    ),
+    error_changing_status: |id| format!("Error changing the status of issue {}", id),
+    issue_context: |subject, id| format!("Context of issue '{}' (ID: {})", subject, id),
+    invalid_regex: "Invalid regular expression",
+    error_scanning_project_issues: |project_id| format!("Error scanning issues of project {}", project_id),
+    no_description_matches: |scanned, project_id| format!("No match found in the descriptions of {} scanned issues of project {}", scanned, project_id),
+    confirm_token_required: "Applying the change requires the confirm_token from the preview (call with dry_run: true first)",
+    preview_outdated: "Issue descriptions or parameters changed since the preview, run a new preview (dry_run: true)",
+    too_many_edits: |count, max| format!("The number of issues ({}) exceeds max_issues ({}), nothing was saved", count, max),
+    error_creating_export_dir: |dir| format!("Cannot create the export directory {}", dir),
+    error_creating_file: |path| format!("Cannot create file {}", path),
+    export_interrupted: |error, path, exported| format!(
+        "Error loading issues: {}. File {} contains only the first {} issues.",
+        error, path, exported
+    ),
+    error_copying_issue: |id| format!("Error copying issue {}", id),
+    clone_version_unavailable: |id, version_id| format!("#{}: milestone {} is not available in the target project, the copy has no milestone", id, version_id),
+    clone_watcher_failed: |id, watcher| format!("#{}: watcher {} cannot be added", id, watcher),
+    clone_attachment_failed: |id, filename| format!("#{}: attachment '{}' cannot be copied", id, filename),
+    clone_attach_failed: |id| format!("#{}: attachments cannot be attached", id),
+    clone_subtask_limit: |max, remaining| format!("At most {} subtasks were copied, the remaining ({}) were not copied", max, remaining),
+    clone_subtask_unreadable: |id| format!("Subtask #{} cannot be loaded", id),
+    clone_subtask_failed: |id| format!("Subtask #{} cannot be copied", id),
+    issue_cloned: |source_id, id, subject, project| format!("Issue #{} copied as #{} '{}' in project {}", source_id, id, subject, project),
+    including_subtasks: |count| format!(" including {} subtasks", count),
+    warnings: "Warnings",
+
+    project_report: |name, id| format!("Report for project '{}' (ID: {})", name, id),
+    dashboard_data: "Dashboard data",
+    project_ids_required: "Parameter 'project_ids' must contain at least one project",
+    budget_requires_rate: "Parameter 'budget_amount' requires 'hourly_rate'",
+    invalid_report_file_name: |file_name| format!("Invalid report file name: {}", file_name),
+    report_not_found: |file_name| format!("Report {} does not exist", file_name),
+    pdf_report_saved: |path| format!("The PDF report is stored in file {}", path),
+    error_reading_report: |file_name| format!("Error reading report {}", file_name),
+    report_dir_empty: |dir| format!("Directory {} does not contain any reports yet", dir),
+    error_reading_report_dir: "Error reading the report directory",
+    no_generated_reports: "No generated reports found",
+    consistency_report: |project_id, findings| format!("Consistency check of project {} ({} findings)", project_id, findings),
+    baseline_exists: |name, project_id| format!("Baseline '{}' of project {} already exists (use overwrite: true to replace it)", name, project_id),
+    error_saving_baseline: "Error saving baseline",
+    baseline_not_found: |name, project_id| format!("Baseline '{}' of project {} does not exist", name, project_id),
+    error_loading_baseline: |name| format!("Error loading baseline '{}'", name),
+    baseline_variance: |project_id, name| format!(
+        "Deviations of project {} from baseline '{}' (missing issues may be closed or deleted)",
+        project_id, name
+    ),
+    error_loading_project_issues: |project_id| format!("Error loading issues of project {}", project_id),
+    project_schedule: |project_id, scheduled, unscheduled, critical| format!(
+        "Schedule of project {} ({} scheduled issues, {} without dates, critical path of {} issues)",
+        project_id, scheduled, unscheduled, critical
+    ),
+    invalid_value: |parameter, value, allowed| format!("Invalid value of '{}': {} (allowed: {})", parameter, value, allowed),
+    error_loading_closed_issues: "Error loading closed issues",
+    error_loading_time_entries: "Error loading time entries",
+    team_velocity: |from, to, closed_trend, hours_trend| format!(
+        "Team velocity from {} to {} (closed issues: {}, hours: {})",
+        from, to, closed_trend, hours_trend
+    ),
+    error_loading_issues: "Error loading issues",
+    deadline_risks: |overdue, at_risk| format!("Overdue issues: {}, deadlines at risk: {}", overdue, at_risk),
+    date_range_reversed: "Parameter 'to_date' must not be earlier than 'from_date'",
+    capacity_plan: |from, to, business_days, over_allocated| format!(
+        "Capacity plan {} - {} ({} business days, over-allocated: {})",
+        from, to, business_days, over_allocated
+    ),
+    nobody: "nobody",
+    timesheet_period: |max_days| format!("The timesheet period must have 1 to {} days and 'to_date' must not be before 'from_date'", max_days),
+
+    tool_group_labels: ["projects", "issues", "users", "time entries", "reports", "milestones"],
+    instructions_header: |base_url, groups, tools| format!(
+        "EasyProject MCP Server for managing projects, issues and users via the EasyProject API.\n\
+        Connected instance: {}\n\
+        Enabled tool groups: {} ({} tools)\n",
+        base_url, groups, tools
+    ),
+    instructions_no_groups: "none",
+    instructions_read_only: "Mode: read-only - data in EasyProject cannot be changed.\n",
+    instructions_unavailable: |tools| format!("Unavailable because the API key lacks permissions: {}\n", tools),
+    instructions_workflow: "\nRecommended workflow:\n",
+    instructions_enumerations: "- Before filtering issues by status, priority or tracker, call get_issue_enumerations to look up the IDs.\n",
+    instructions_projects: "- Project IDs are available via list_projects, list results are paginated (limit/offset).\n",
+    instructions_versions: "- Before setting fixed_version_id, check the available versions with list_assignable_versions.\n",
+    instructions_user_types: "- To filter users by type, use list_users with the user_type parameter.\n",
+    instructions_chunks: "- Long results are split into pages, load the remaining pages via resources/read.\n",
+    instructions_resources: "- Entity details can also be read as resources, e.g. easyproject://issue/{id}.\n",
+
+    error_creating_api_client: "Failed to create the API client",
+    server_not_initialized: "Server is not initialized",
+    invalid_request_params: |method| format!("Invalid parameters for {}", method),
+    missing_request_params: |method| format!("Missing parameters for {}", method),
+    resource_not_found: |uri| format!("Resource '{}' does not exist or has expired", uri),
+    result_page_name: |total| format!("Result page ({} pages in total)", total),
+
+    issue_enumerations: "Enumerations for filtering issues",
+    enumeration_statuses: "STATUSES (status_id)",
+    enumeration_priorities: "PRIORITIES (priority_id)",
+    enumeration_trackers: "TRACKERS (tracker_id)",
+    enumerations_usage: "Usage:\n\
+        - To filter by status: list_issues with parameter status_id=<ID>\n\
+        - To filter by priority: list_issues with parameter priority_id=<ID>\n\
+        - To filter by tracker: list_issues with parameter tracker_id=<ID>\n",
+
+    missing_parameters: |parameters| format!("Missing required parameters: {}", parameters),
+    missing_tool_arguments: |tool| format!("Missing arguments for {}", tool),
+
+    assignable_versions: |project_id, count, total| format!("Assignable versions for project {} ({} of {})", project_id, count, total),
+    no_assignable_versions: "The project has no open own or shared versions.",
+    version_shared_from: |project, id, sharing| format!("shared from project '{}' (ID: {}, sharing: {})", project, id, sharing),
+    due_on: "due",
+
+    found_search_results: |count, query, total| format!("Found {} results for '{}' (total: {})", count, query, total),
+
+    my_work_summary: |name, id, login, day, hours, entries, issues| format!(
+        "Signed in as {} (ID {}{})\n\nToday ({}): logged {:.2} h in {} entries\nOpen assigned issues: {}\n",
+        name, id, login, day, hours, entries, issues
+    ),
+    overdue: "Overdue",
+    due_within_days: |days| format!("Due within {} days", days),
+    user_types_heading: |count| format!("User types ({})", count),
+    category: "category",
+    unspecified: "unspecified",
+    default_flag: "default",
+    roles_heading: |count| format!("Roles ({})", count),
+    not_assignable: "not assignable",
+
+    api_key_permissions: "API key permissions",
+    probed_at: "Verified",
+    probe_allowed: "✅ allowed",
+    probe_denied: "⛔ denied",
+    probe_unknown: "❔ not verified",
+    all_tools_available: "All tools are available.",
+    unavailable_tools: "Unavailable tools",
+    permission_area_labels: ["Reading projects", "Creating projects", "Reading issues", "Reading time entries", "User list", "Administration"],
+    user_type_categories: ["internal", "external", "client"],
+    config_diagnostics: "Configuration diagnostics",
+    config_load_failed: |error| format!("⚠️ The configuration could not be loaded as a whole: {}", error),
+    config_failed_keys: "Invalid values (default value used)",
+    config_unknown_keys: "Unknown keys (ignored, check for typos)",
+    config_env_overrides: "Overridden by environment variables",
+    config_defaulted_keys: |count| format!("Default values ({})", count),
+    config_defaulted_count: |count| format!("Default value used for {} keys (list them with include_defaulted=true).", count),
+    config_validation_failed: |error| format!("❌ Validation failed: {}", error),
+    config_valid_with_problems: "⚠️ The configuration is valid but has the problems listed above.",
+    config_ok: "✅ The configuration is fine.",
+    instance_enumeration_labels: ["Trackers", "Issue statuses", "Priorities", "Time entry activities", "Roles", "Custom fields"],
+    instance_plugin_labels: ["Contacts", "CRM cases", "Attendance", "User types"],
+    current_user_unavailable: "The current user cannot be determined",
+    instance_version_unknown: "unknown - the REST API does not expose the version, the /admin/info page requires an administrator",
+    project_modules_unavailable: "Project modules cannot be determined",
+    cache_summary: |entries, max_entries, ratio, hits, misses| format!("API client cache: {} of {} entries, hit ratio {:.0} % ({} hits, {} misses)", entries, max_entries, ratio, hits, misses),
+    cache_disabled: "The API client cache is disabled (cache.enabled = false)",
+    cache_pattern_cleared: |pattern, removed, before| format!("Removed cache entries matching pattern '{}' ({} of {} entries)", pattern, removed, before),
+    cache_cleared: |count| format!("Cache cleared ({} entries)", count),
+    connection_ok: |base_url, user, latency, version| format!("✅ Connection to {} is fine: signed in as {}, response time {} ms{}", base_url, user, latency, version),
+    version_suffix: |version| format!(", version {}", version),
+    connection_auth_failed: |base_url, latency, error| format!(
+        "❌ Instance {} responds but authentication failed (response time {} ms): {}\n\
+        Check the API key (or the username and password) and its permissions.",
+        base_url, latency, error
+    ),
+    connection_unreachable: |base_url, error| format!("❌ Instance {} is not reachable: {}\nCheck base_url and the network connection.", base_url, error),
+    capabilities_not_probed: "not verified - enable tools.probe_permissions or call get_permissions",
+
+    issue_unreadable: |id| format!("Issue {} cannot be loaded (it does not exist or you have no access to it)", id),
+    issue_project_mismatch: |issue_id, project_name, issue_project_id, project_id| format!(
+        "Issue {} belongs to project '{}' (ID {}), not to project {}. \
+        Omit project_id (it is taken from the issue) or use project_id {}.",
+        issue_id, project_name, issue_project_id, project_id, issue_project_id
+    ),
+    time_entry_change_labels: ["hours", "date", "activity", "project", "issue", "comment"],
+    no_value_changes: "no values changed",
+    issue_target: |id| format!("issue #{}", id),
+    project_target: |project| format!("project {}", project),
+    time_entries_move_heading: |dry_run, count, hours, target| format!("{} {} time entries ({:.2} h) to {}", if dry_run { "Preview of moving" } else { "Moving" }, count, hours, target),
+    moved_from: "from",
+    entries_already_on_target: |count| format!("{} entries are already on the target, skipped.", count),
+    dry_run_no_changes: "No changes were made (dry_run).",
+    moved_summary: |moved, failed| format!("Moved: {}, errors: {}", moved, failed),
+    bulk_hours_per_day_required: "The 'hours_per_day' parameter is required for a period (from_date, to_date)",
+    to_date_before_from_date: "The 'to_date' parameter must not be before 'from_date'",
+    bulk_period_incomplete: "A period needs both 'from_date' and 'to_date'",
+    bulk_entries_missing: "Provide 'entries' or a period 'from_date' and 'to_date' with 'hours_per_day'",
+    bulk_too_many_entries: |max, count| format!("At most {} entries can be created at once ({} given)", max, count),
+    bulk_entry_invalid: |index, date, problem| format!("Entry {} ({}): {}", index, date, problem),
+    bulk_entry_missing_target: "'issue_id' or 'project_id' is missing",
+    bulk_entry_missing_activity: "'activity_id' is missing",
+    bulk_entry_invalid_hours: "hours must be between 0.01 and 24.0",
+    probable_duplicate: |id| format!("probable duplicate of existing entry #{} (use allow_duplicate: true)", id),
+    task_aborted: "the task was aborted",
+    bulk_created_summary: |created, total, hours| format!("Created {} of {} time entries, {:.2} h in total.", created, total, hours),
+    bulk_not_created: "Entries not created",
+
+    custom_field_key_not_numeric: |key| format!("Custom field key '{}' is not a numeric field ID", key),
+    custom_field_value_object: |id| format!("The value of custom field {} must not be an object", id),
+    assignee_unresolved: |name| format!("Assignee '{}' cannot be determined", name),
+    batch_parent_conflict: |index| format!("Issue [{}] has both parent_index and parent_issue_id, use only one", index),
+    batch_parent_missing: |index, parent| format!("Issue [{}] refers to a nonexistent parent_index {}", index, parent),
+    batch_parent_cycle: |index| format!("Issue [{}] is part of a parent_index cycle", index),
+    batch_skipped_after_error: "Not created after a previous error (stop_on_error)",
+    batch_parent_not_created: |parent| format!("Parent issue [{}] was not created", parent),
+    batch_value_missing: |name| format!("{} is missing (set it on the issue or in defaults)", name),
+    batch_created: |created, total| format!("Created {} of {} issues", created, total),
+    upload_disabled: "Uploading files from disk is disabled (set tools.issues.upload_dir)",
+    directory_unavailable: |dir| format!("Directory {} is not available", dir),
+    file_unavailable: |file| format!("File {} is not available", file),
+    file_outside_upload_dir: |file, dir| format!("File {} lies outside the allowed directory {}", file, dir),
+    file_too_large: |file, size, max| format!("File {} has {} B, the allowed maximum is {} B", file, size, max),
+    file_unreadable: |file| format!("File {} cannot be read", file),
+    invalid_base64: "Invalid base64 content",
+    user_fallback_name: |id| format!("user {}", id),
+    remaining_hours_rule: "estimated hours × (100 % − done ratio), open issues only",
+    status_param_invalid: "The 'status' parameter must be a status name or ID",
+    status_id_unavailable: |id, names| format!("Status with ID {} is not available. Possible statuses: {}", id, names),
+    status_not_found: |name, names| format!("Status '{}' was not found. Possible statuses: {}", name, names),
+    status_ambiguous: |name, matches| format!("Name '{}' matches several statuses: {}. Refine the name or use the ID", name, matches),
+    error_fetching_parent_issue: "Error fetching the parent issue",
+    bulk_edit_preview: |issues, matches| format!("Preview of changes (nothing was saved): {} issues, {} matches", issues, matches),
+    bulk_edit_scan_truncated: "⚠️ The issue scan was limited by max_scan_records, some issues may be missing",
+    match_count: |count| format!("{} matches", count),
+    bulk_edit_too_many: |count, max| format!("⚠️ The number of issues ({}) exceeds max_issues ({}), refine the search text or raise the limit", count, max),
+    bulk_edit_confirm_hint: |token| format!("To save, call again with the same parameters, dry_run: false and confirm_token: \"{}\"", token),
+    descriptions_updated: |count| format!("Issue descriptions updated: {}", count),
+    failed_edits: |count| format!("Failed edits ({})", count),
+    issues_exported: |count, path| format!("Exported {} issues to file {}", count, path),
+    export_limited: |exported, total| format!("Export limited by max_records ({} of {} issues)", exported, total),
+    found_issues_for: |count, query| format!("Found {} issues for '{}'", count, query),
+    of_matching: |total| format!(" (of {} matching)", total),
+    unassigned: "unassigned",
+
+    batch_issues_description: |max| format!("Issue definitions (required, at most {})", max),
+    raw_api_path_description: |patterns| format!("Path relative to base_url without the .json suffix, e.g. 'trackers' or 'issues/123'. Allowed patterns: {}", patterns),
+
+    error_fetching_project_members: "Error fetching project members",
+    assignment_history_heading: |user_id, from, to| format!("Assignment history of user {} (period: {} – {})", user_id, from, to),
+    period_start: "start",
+    today: "today",
+    no_assignment_changes: "No assignment changes were found in the given period.",
+    assigned_action: "assigned",
+    unassigned_action: "unassigned",
+    previously_user: |id| format!(" (previously: user {})", id),
+    newly_user: |id| format!(" (now: user {})", id),
+    changed_by: |name| format!(", changed by: {}", name),
+    assignment_summary: |assigned, unassigned, scanned| format!("Summary: {} assignments, {} unassignments, {} issues scanned", assigned, unassigned, scanned),
+    limited_by_max_issues: |max| format!(" (limited by max_issues = {})", max),
+    issue_history_failed: |count| format!(", the history of {} issues could not be loaded", count),
+    budget_burn_heading: |days, warning, critical| format!("Budget burn (rate over the last {} days, warning from {:.0} %, critical from {:.0} %)", days, warning, critical),
+    budget_project_failed: |id| format!("Project {}: loading failed", id),
+    budget_estimates_failed: |name, id| format!("Project '{}' ({}): loading estimates failed", name, id),
+    budget_time_entries_failed: |name, id| format!("Project '{}' ({}): loading time entries failed", name, id),
+    budget_sources: ["given budget", "financial budget / rate", "sum of issue estimates"],
+    budget_unknown: |spent, burn| format!("Budget: unknown (no estimates), spent {:.1} h, rate {:.1} h/day", spent, burn),
+    budget_consumption: |budget, source, spent, percent, remaining| format!("Budget: {:.1} h ({}), spent: {:.1} h ({:.0} %), remaining: {:.1} h", budget, source, spent, percent, remaining),
+    budget_costs: |spent, budget| format!("Costs: {:.0} of {:.0}", spent, budget),
+    burn_rate: |burn| format!("Burn rate: {:.1} h/day", burn),
+    budget_exhausted: "The budget is already used up",
+    budget_exhaustion_estimate: |date, days| format!("Estimated exhaustion: {} (in {} days at the current rate)", date, days),
+    budget_no_recent_burn: "Estimated exhaustion: nothing was spent in the recent period",
+    budget_status_critical: "🔴 CRITICAL",
+    budget_status_warning: "🟠 WARNING",
+    budget_status: "Status",
+    no_project_over_threshold: "No project exceeded the warning threshold.",
+    projects_over_threshold: "Projects over the threshold",
+    generated_reports_heading: |dir, total| format!("Generated reports in {} ({} in total)", dir, total),
+    generated_report_line: |file, project_id, generated_at, size| format!("• {} - project {}, {} ({} B)", file, project_id, generated_at, size),
+    more_reports: |count| format!("... and {} more reports", count),
+    check_subtask_due_after_parent: "A subtask is due after its parent issue",
+    check_closed_parent_open_children: "The parent issue is closed but a subtask is open",
+    check_assigned_to_locked_user: "An open issue is assigned to a locked user (change assigned_to_id)",
+    locked_users_unavailable: "Locked users could not be loaded",
+    check_time_logged_after_close: "Time logged on a closed issue after it was closed (move it to another issue)",
+    baseline_saved: |name, project, project_id, issues, hours, file| format!("Baseline '{}' of project '{}' (ID: {}) saved: {} issues, {:.1} h estimated in total\nFile: {}", name, project, project_id, issues, hours, file),
+    baseline_scan_truncated: "⚠️ The issue scan was limited by max_scan_records, the baseline may not contain all issues",
+    no_baselines: |id| format!("Project {} has no saved baseline (create one with create_baseline)", id),
+    project_baselines: |id, names| format!("Baselines of project {}: {}", id, names),
+
+    schedule_title: |id| format!("Project {}", id),
+    schedule_default_section: "Issues",
+    velocity_trends: ["not enough data", "stable", "rising", "falling"],
+    team: "Team",
+    risk_levels: ["critical", "high", "medium"],
+    unassigned_group: "Unassigned",
+    total: "Total",
+    timesheet_title: |from, to| format!("Timesheet {} - {}", from, to),
+    workdays_without_time: "Working days without logged time",
+    portfolio_health_levels: ["critical", "warning", "ok"],
+    portfolio_table_header: "| # | Project | Status | Open | Overdue | Unassigned | Remaining (h) | Spent (h) |",
+    portfolio_heading: |count, from, to| format!("Portfolio overview ({} active projects, hours {} - {})", count, from, to),
+    shown_first_projects: |shown, total| format!("Showing the first {} of {} projects.", shown, total),
+    portfolio_scan_truncated: "Warning: the data scan was limited by max_scan_records, the counts may be incomplete.",
+};
@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+mod catalog;
+mod messages;
+
+pub use messages::{Messages, CS, EN};
+
+/// Jazyk popisů tools a textového výstupu
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Cs,
+    En,
+}
+
+static LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Nastaví jazyk výstupu, volá se jednou při startu podle konfigurace
+///
+/// Další volání už jazyk nezmění.
+pub fn init(locale: Locale) {
+    let _ = LOCALE.set(locale);
+}
+
+/// Aktuální jazyk (před inicializací čeština)
+pub fn locale() -> Locale {
+    LOCALE.get().copied().unwrap_or_default()
+}
+
+/// Texty pro aktuální jazyk
+pub fn messages() -> &'static Messages {
+    match locale() {
+        Locale::Cs => &CS,
+        Locale::En => &EN,
+    }
+}
+
+/// Popis tool v aktuálním jazyce, bez překladu vrátí původní český popis
+pub fn tool_description<'a>(tool_name: &str, description: &'a str) -> &'a str {
+    if locale() == Locale::Cs {
+        return description;
+    }
+    catalog::TOOL_DESCRIPTIONS.iter()
+        .find(|(name, _)| *name == tool_name)
+        .map(|(_, translated)| *translated)
+        .unwrap_or(description)
+}
+
+/// Přeloží popisy parametrů ve schématu tool (včetně vnořených položek a objektů)
+pub fn localize_schema(schema: &mut Value) {
+    if locale() == Locale::Cs {
+        return;
+    }
+    localize_descriptions(schema, schema_translations());
+}
+
+fn schema_translations() -> &'static HashMap<&'static str, &'static str> {
+    static TRANSLATIONS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    TRANSLATIONS.get_or_init(|| catalog::SCHEMA_DESCRIPTIONS.iter().copied().collect())
+}
+
+fn localize_descriptions(value: &mut Value, translations: &HashMap<&str, &str>) {
+    match value {
+        Value::Object(map) => {
+            for (key, nested) in map.iter_mut() {
+                match nested {
+                    Value::String(text) if key == "description" => {
+                        if let Some(translated) = translations.get(text.as_str()) {
+                            *text = translated.to_string();
+                        }
+                    }
+                    nested => localize_descriptions(nested, translations),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                localize_descriptions(item, translations);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_localize_descriptions_nested() {
+        let translations = HashMap::from([("ID úkolu", "Issue ID"), ("Popis úkolu", "Issue description")]);
+        let mut schema = json!({
+            "id": { "type": "integer", "description": "ID úkolu" },
+            "description": { "type": "string", "description": "Popis úkolu" },
+            "issues": {
+                "type": "array",
+                "items": { "type": "object", "properties": { "parent_issue_id": { "description": "ID úkolu" } } }
+            },
+            "other": { "description": "Bez překladu" }
+        });
+
+        localize_descriptions(&mut schema, &translations);
+
+        assert_eq!(schema["id"]["description"], "Issue ID");
+        assert_eq!(schema["description"]["description"], "Issue description");
+        assert_eq!(schema["issues"]["items"]["properties"]["parent_issue_id"]["description"], "Issue ID");
+        assert_eq!(schema["other"]["description"], "Bez překladu");
+    }
+
+    #[test]
+    fn test_catalog_has_unique_keys() {
+        let tools: std::collections::HashSet<_> = catalog::TOOL_DESCRIPTIONS.iter().map(|(name, _)| name).collect();
+        assert_eq!(tools.len(), catalog::TOOL_DESCRIPTIONS.len());
+        assert_eq!(schema_translations().len(), catalog::SCHEMA_DESCRIPTIONS.len());
+    }
+
+    #[test]
+    fn test_locale_deserialize() {
+        assert_eq!(serde_json::from_value::<Locale>(json!("cs")).unwrap(), Locale::Cs);
+        assert_eq!(serde_json::from_value::<Locale>(json!("en")).unwrap(), Locale::En);
+        assert!(serde_json::from_value::<Locale>(json!("de")).is_err());
+        assert!(serde_json::from_value::<Locale>(json!("EN")).is_err());
+    }
+
+    #[test]
+    fn test_default_locale_is_czech() {
+        // Testy jazyk neinicializují, platí výchozí čeština
+        assert_eq!(locale(), Locale::Cs);
+        assert_eq!(messages().locale, Locale::Cs);
+        assert_eq!(tool_description("get_issue", "Získá detail úkolu"), "Získá detail úkolu");
+
+        let mut schema = json!({ "id": { "description": "ID úkolu" } });
+        localize_schema(&mut schema);
+        assert_eq!(schema["id"]["description"], "ID úkolu");
+    }
+
+    #[test]
+    fn test_messages_per_locale() {
+        assert_eq!(CS.locale, Locale::Cs);
+        assert_eq!(EN.locale, Locale::En);
+        assert_eq!((CS.found_issues)(3), "Nalezeno 3 úkolů");
+        assert_eq!((EN.found_issues)(3), "Found 3 issues");
+        assert_eq!((EN.unknown_column)("foo", "id, subject"), "Unknown column 'foo'. Available columns: id, subject");
+        assert_eq!((CS.chunk_limit_hint)(25), "\nMenší výsledek získáte také nižším limitem (nyní 25).");
+        assert_eq!((EN.invalid_value)("period", "day", "week, sprint"), "Invalid value of 'period': day (allowed: week, sprint)");
+    }
+
+    #[test]
+    fn test_catalog_translations_are_english() {
+        for (name, translated) in catalog::TOOL_DESCRIPTIONS {
+            assert!(!translated.is_empty(), "prázdný popis tool {}", name);
+            assert!(!translated.contains(['ř', 'ů', 'ě']), "nepřeložený popis tool {}", name);
+        }
+    }
+}
@@ -2,4 +2,9 @@ pub mod config;
 pub mod mcp;
 pub mod api;
 pub mod tools;
-pub mod utils; 
\ No newline at end of file
+pub mod utils;
+pub mod i18n;
+pub mod scheduler;
+pub mod logging;
+pub mod audit;
+pub mod snapshots;
@@ -0,0 +1,91 @@
+pub mod rotation;
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use tracing::warn;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::{fmt::writer::BoxMakeWriter, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+use crate::config::{AppConfig, LogFormat, LogRotation, LogTarget, LoggingConfig, TransportType};
+use rotation::SizeRotatingWriter;
+
+/// Inicializuje logování podle sekce [logging]
+///
+/// Při zápisu do souboru vrací guard zapisovacího vlákna; musí žít do konce běhu
+/// serveru, jinak se poslední záznamy nezapíšou.
+pub fn init(config: &AppConfig) -> Result<Option<WorkerGuard>> {
+    let logging = &config.logging;
+
+    // RUST_LOG má přednost před úrovní z konfigurace
+    let filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&logging.level))
+        .with_context(|| format!("Neplatná úroveň logování '{}'", logging.level))?;
+
+    // Při stdio transportu patří stdout výhradně JSON-RPC zprávám
+    let stdout_taken = matches!(logging.target, LogTarget::Stdout)
+        && matches!(config.server.transport, TransportType::Stdio);
+    let mut guard = None;
+    let writer = match logging.target {
+        LogTarget::Stdout if !stdout_taken => BoxMakeWriter::new(std::io::stdout),
+        LogTarget::Stdout | LogTarget::Stderr => BoxMakeWriter::new(std::io::stderr),
+        LogTarget::File => {
+            let path = logging.path.as_ref()
+                .context("logging.path je povinný pro logging.target = 'file'")?;
+            let (writer, file_guard) = file_writer(logging, path)?;
+            guard = Some(file_guard);
+            BoxMakeWriter::new(writer)
+        }
+    };
+
+    let layer = tracing_subscriber::fmt::layer()
+        .with_ansi(false)  // Vypne ANSI escape sekvence
+        .with_writer(writer);
+    let layer = match logging.format {
+        LogFormat::Json => layer.json().with_current_span(true).with_span_list(false).boxed(),
+        LogFormat::Pretty => layer.pretty().boxed(),
+        LogFormat::Compact => layer.with_target(false).compact().boxed(),
+    };
+
+    tracing_subscriber::registry()
+        .with(filter)
+        .with(layer)
+        .init();
+
+    if stdout_taken {
+        warn!("logging.target = 'stdout' nelze použít se stdio transportem, loguji na stderr");
+    }
+
+    Ok(guard)
+}
+
+/// Zapisovač souboru logu s rotací; zápis probíhá v samostatném vlákně
+fn file_writer(logging: &LoggingConfig, path: &Path) -> Result<(tracing_appender::non_blocking::NonBlocking, WorkerGuard)> {
+    let directory = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    std::fs::create_dir_all(directory)
+        .with_context(|| format!("Nelze vytvořit adresář logu {}", directory.display()))?;
+
+    let rotation = match logging.rotation {
+        LogRotation::Size => {
+            let writer = SizeRotatingWriter::new(path, logging.max_size_mb * 1024 * 1024, logging.max_files)
+                .with_context(|| format!("Nelze otevřít soubor logu {}", path.display()))?;
+            return Ok(tracing_appender::non_blocking(writer));
+        }
+        LogRotation::Never => Rotation::NEVER,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Daily => Rotation::DAILY,
+    };
+
+    let file_name = path.file_name()
+        .with_context(|| format!("logging.path {} neobsahuje název souboru", path.display()))?;
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(file_name.to_string_lossy());
+    if logging.max_files > 0 && logging.rotation != LogRotation::Never {
+        builder = builder.max_log_files(logging.max_files);
+    }
+    let appender = builder.build(directory)
+        .with_context(|| format!("Nelze otevřít soubor logu {}", path.display()))?;
+
+    Ok(tracing_appender::non_blocking(appender))
+}
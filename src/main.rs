@@ -1,40 +1,126 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::path::PathBuf;
 use tracing::{info, error};
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use easyproject_mcp_server::{
-    config::AppConfig,
-    api::EasyProjectClient,
-    tools::ToolRegistry,
+    config::{AppConfig, CliOverrides},
+    i18n,
+    logging,
     mcp::McpServer,
 };
 
+/// MCP server pro EasyProject API
+///
+/// Parametry přepisují hodnoty z konfiguračního souboru i environment proměnných.
+#[derive(Debug, Parser)]
+#[command(name = "easyproject-mcp-server", version)]
+struct Cli {
+    /// Konfigurační soubor (výchozí config.* v pracovním adresáři)
+    #[arg(long, value_name = "PATH")]
+    config: Option<PathBuf>,
+    
+    /// URL EasyProject instance (easyproject.base_url)
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+    
+    /// Název environment proměnné s API klíčem (pro více instancí v jednom MCP klientovi)
+    #[arg(long, value_name = "VAR")]
+    api_key_env: Option<String>,
+    
+    /// Transport MCP serveru (server.transport)
+    #[arg(long, value_parser = ["stdio", "websocket", "http"])]
+    transport: Option<String>,
+    
+    /// Port HTTP nebo WebSocket transportu
+    #[arg(long)]
+    port: Option<u16>,
+    
+    /// Úroveň logování nebo filtr ve formátu RUST_LOG (logging.level)
+    #[arg(long, value_name = "LEVEL")]
+    log_level: Option<String>,
+    
+    /// Vypíše výslednou konfiguraci (hesla a klíče zamaskované) a skončí
+    #[arg(long)]
+    print_config: bool,
+}
+
+impl Cli {
+    /// Převede parametry na přepsání klíčů konfigurace
+    fn into_overrides(self) -> Result<CliOverrides> {
+        let mut values = Vec::new();
+        if let Some(base_url) = self.base_url {
+            values.push(("easyproject.base_url".to_string(), base_url));
+        }
+        if let Some(variable) = self.api_key_env {
+            let api_key = std::env::var(&variable)
+                .with_context(|| format!("Environment proměnná {} z --api-key-env není nastavená", variable))?;
+            values.push(("easyproject.api_key".to_string(), api_key));
+        }
+        if let Some(transport) = self.transport {
+            values.push(("server.transport".to_string(), transport));
+        }
+        if let Some(port) = self.port {
+            values.push(("server.http_port".to_string(), port.to_string()));
+            values.push(("server.websocket_port".to_string(), port.to_string()));
+        }
+        if let Some(level) = self.log_level {
+            values.push(("logging.level".to_string(), level));
+        }
+        Ok(CliOverrides { config_path: self.config, values })
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let print_config = cli.print_config;
+    cli.into_overrides()?.install();
+    
     // Načtení konfigurace
-    let config = AppConfig::load().map_err(|e| anyhow::anyhow!("Chyba při načítání konfigurace: {}", e))?;
+    let (config, diagnostics) = AppConfig::load_with_diagnostics()
+        .map_err(|e| anyhow::anyhow!("Chyba při načítání konfigurace: {}", e))?;
+    
+    // Explicitně zadaný --config musí jít načíst, jinak by server běžel s výchozí konfigurací
+    if let Some(path) = CliOverrides::current().and_then(|cli| cli.config_path.as_ref()) {
+        if diagnostics.defaults_only {
+            anyhow::bail!(
+                "Konfigurační soubor {} nelze načíst: {}",
+                path.display(),
+                diagnostics.load_error.as_deref().unwrap_or_default()
+            );
+        }
+    }
+    
+    // Výpis jde na stdout ještě před inicializací logování, server se nespouští
+    if print_config {
+        if let Some(ref error) = diagnostics.load_error {
+            eprintln!("Chyba při načítání konfigurace: {}", error);
+        }
+        print!("{}", toml::to_string_pretty(&config.redacted())?);
+        return Ok(());
+    }
+    
+    // Inicializace logování (guard drží zápis do souboru logu až do konce běhu)
+    let _log_guard = logging::init(&config)?;
+    
+    // Jazyk popisů tools a výstupu
+    i18n::init(config.localization.locale);
+    
+    // Diagnostika konfigurace až po inicializaci logování, jinak by se varování ztratila
+    diagnostics.log();
     
     // Validace konfigurace
     config.validate().map_err(|e| anyhow::anyhow!("Neplatná konfigurace: {}", e))?;
     
-    // Inicializace logování
-    init_logging(&config)?;
-    
     info!("🚀 Spouštím EasyProject MCP Server v{}", config.server.version);
     info!("📡 Transport: {:?}", config.server.transport);
     info!("🌐 EasyProject URL: {}", config.easyproject.base_url);
     
-    // Vytvoření API klienta
-    let api_client = EasyProjectClient::new(&config).await
-        .map_err(|e| anyhow::anyhow!("Chyba při vytváření API klienta: {}", e))?;
-    
-    // Vytvoření tool registry
-    let tool_registry = ToolRegistry::new(api_client, &config);
-    info!("🔧 Registrováno {} nástrojů", tool_registry.tool_count());
-    
     // Vytvoření a spuštění MCP serveru
     let mut mcp_server = McpServer::new(config).await
         .map_err(|e| anyhow::anyhow!("Chyba při vytváření MCP serveru: {}", e))?;
+    info!("🔧 Registrováno {} nástrojů", mcp_server.tool_count());
     
     info!("✅ Server je připraven k příjmu požadavků");
     
@@ -49,18 +135,3 @@ async fn main() -> Result<()> {
         }
     }
 }
-
-fn init_logging(_config: &AppConfig) -> Result<()> {
-    let subscriber = tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::fmt::layer()
-                .with_ansi(false)  // Vypne ANSI escape sekvence
-                .with_target(false) // Vypne target ve výpisu  
-                .with_writer(std::io::stderr) // Přesměruje na stderr místo stdout
-                .compact()  // Kompaktní formát
-        );
-    
-    subscriber.init();
-    
-    Ok(())
-} 
\ No newline at end of file
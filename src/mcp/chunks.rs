@@ -0,0 +1,68 @@
+use std::collections::{HashMap, VecDeque};
+
+/// Prefix URI pro stránky rozdělených výsledků tools
+pub const RESULT_URI_PREFIX: &str = "easyproject://tool-results/";
+
+/// Úložiště stránek výsledků tools, které se nevešly do jedné zprávy
+///
+/// Uchovává jen omezený počet posledních výsledků, starší se zahazují.
+pub struct ResultChunkStore {
+    max_results: usize,
+    next_id: u64,
+    order: VecDeque<u64>,
+    results: HashMap<u64, Vec<String>>,
+}
+
+impl ResultChunkStore {
+    pub fn new(max_results: usize) -> Self {
+        Self {
+            max_results: max_results.max(1),
+            next_id: 1,
+            order: VecDeque::new(),
+            results: HashMap::new(),
+        }
+    }
+    
+    /// Uloží stránky výsledku a vrátí jeho ID
+    pub fn store(&mut self, pages: Vec<String>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        
+        self.results.insert(id, pages);
+        self.order.push_back(id);
+        while self.order.len() > self.max_results {
+            if let Some(oldest) = self.order.pop_front() {
+                self.results.remove(&oldest);
+            }
+        }
+        
+        id
+    }
+    
+    /// Vrátí stránku výsledku (číslováno od 1)
+    pub fn page(&self, id: u64, page: usize) -> Option<&str> {
+        self.results.get(&id)?
+            .get(page.checked_sub(1)?)
+            .map(String::as_str)
+    }
+    
+    /// Vrátí URI všech uložených stránek s počtem stránek daného výsledku
+    pub fn list(&self) -> Vec<(String, usize)> {
+        self.order.iter()
+            .filter_map(|id| self.results.get(id).map(|pages| (*id, pages.len())))
+            .flat_map(|(id, total)| (1..=total).map(move |page| (page_uri(id, page), total)))
+            .collect()
+    }
+}
+
+/// URI konkrétní stránky výsledku
+pub fn page_uri(id: u64, page: usize) -> String {
+    format!("{}{}/{}", RESULT_URI_PREFIX, id, page)
+}
+
+/// Rozebere URI stránky na ID výsledku a číslo stránky
+pub fn parse_page_uri(uri: &str) -> Option<(u64, usize)> {
+    let rest = uri.strip_prefix(RESULT_URI_PREFIX)?;
+    let (id, page) = rest.split_once('/')?;
+    Some((id.parse().ok()?, page.parse().ok()?))
+}
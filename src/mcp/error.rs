@@ -45,8 +45,14 @@ pub enum TransportError {
     #[error("WebSocket chyba: {0}")]
     WebSocket(String),
     
+    #[error("HTTP chyba: {0}")]
+    Http(String),
+    
     #[error("Spojení uzavřeno")]
     ConnectionClosed,
+    
+    #[error("Zpráva má {size} bajtů a překračuje limit {limit} bajtů")]
+    MessageTooLarge { size: usize, limit: usize },
 }
 
 /// JSON-RPC 2.0 Error Response podle MCP specifikace
@@ -108,6 +114,14 @@ impl JsonRpcError {
         }
     }
     
+    pub fn message_too_large(size: usize, limit: usize) -> Self {
+        Self {
+            code: -32600,
+            message: "Message too large".to_string(),
+            data: Some(serde_json::json!({ "size": size, "limit": limit })),
+        }
+    }
+    
     pub fn tool_not_found(tool_name: &str) -> Self {
         Self {
             code: -32001,
@@ -137,6 +151,7 @@ impl From<McpError> for JsonRpcError {
             McpError::InternalError(msg) => JsonRpcError::internal_error(&msg),
             McpError::Serialization(err) => JsonRpcError::internal_error(&err.to_string()),
             McpError::Io(err) => JsonRpcError::internal_error(&err.to_string()),
+            McpError::Transport(TransportError::MessageTooLarge { size, limit }) => JsonRpcError::message_too_large(size, limit),
             McpError::Transport(err) => JsonRpcError::internal_error(&err.to_string()),
         }
     }
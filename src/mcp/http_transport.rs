@@ -0,0 +1,519 @@
+use async_trait::async_trait;
+use axum::extract::{DefaultBodyLimit, State};
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::Router;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, mpsc, oneshot, Notify};
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+use tracing::{debug, error, info, warn};
+
+use crate::config::ServerConfig;
+use super::error::{JsonRpcError, McpResult, TransportError};
+use super::protocol::{JsonRpcResponse, McpMessage};
+use super::transport::{Connection, Listener, Transport};
+
+/// Hlavička s identifikátorem relace podle MCP Streamable HTTP
+const SESSION_HEADER: &str = "mcp-session-id";
+
+/// Kolik zpráv pro SSE stream se drží pro pomalé odběratele
+const EVENT_CHANNEL_CAPACITY: usize = 100;
+
+/// Kolik přijatých zpráv může čekat na zpracování serverem
+const INBOUND_CHANNEL_CAPACITY: usize = 32;
+
+/// Nejdelší interval kontroly nečinných relací
+const SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// HTTP Listener podle MCP Streamable HTTP
+///
+/// Požadavky přicházejí jako POST na `http_path` a odpověď se vrací v těle téže HTTP odpovědi.
+/// Zprávy iniciované serverem (notifikace) se posílají přes SSE stream otevřený požadavkem GET.
+/// Každé `initialize` bez hlavičky relace založí novou relaci, kterou server obsluhuje
+/// jako samostatné spojení.
+pub struct HttpListener {
+    sessions: mpsc::Receiver<Connection>,
+    shutdown: Arc<Notify>,
+}
+
+/// Transport jedné HTTP relace
+pub struct HttpTransport {
+    session: Arc<SessionChannels>,
+    inbound: mpsc::Receiver<McpMessage>,
+    is_closed: bool,
+    max_message_size: usize,
+}
+
+/// Stav sdílený HTTP handlery
+struct HttpState {
+    sessions: Mutex<HashMap<String, HttpSession>>,
+    new_sessions: mpsc::Sender<Connection>,
+    allowed_origins: Vec<String>,
+    max_message_size: usize,
+    /// Nečinná relace se ukončí po této době (None = nikdy)
+    session_idle_timeout: Option<Duration>,
+    /// Maximální počet současných relací (0 = bez omezení)
+    max_sessions: usize,
+}
+
+/// Relace z pohledu HTTP handlerů
+///
+/// Odebráním z mapy relací se zahodí odesílatel příchozích zpráv a transport relace skončí.
+struct HttpSession {
+    inbound: mpsc::Sender<McpMessage>,
+    channels: Arc<SessionChannels>,
+    last_activity: Instant,
+}
+
+/// Kanály sdílené mezi HTTP handlery a transportem relace
+struct SessionChannels {
+    /// HTTP požadavky čekající na odpověď podle JSON-RPC id (`None` = požadavek zrušen klientem)
+    pending: Mutex<HashMap<String, oneshot::Sender<Option<McpMessage>>>>,
+    events: broadcast::Sender<String>,
+}
+
+impl HttpListener {
+    /// Začne naslouchat na `http_host:http_port` a obsluhovat `http_path`
+    pub async fn bind(config: &ServerConfig) -> McpResult<Self> {
+        let address = format!("{}:{}", config.http_host, config.http_port);
+        let listener = tokio::net::TcpListener::bind(&address).await
+            .map_err(|e| TransportError::Http(format!("Nelze naslouchat na {}: {}", address, e)))?;
+
+        let (new_sessions, sessions) = mpsc::channel(INBOUND_CHANNEL_CAPACITY);
+        let state = Arc::new(HttpState {
+            sessions: Mutex::new(HashMap::new()),
+            new_sessions,
+            allowed_origins: config.http_allowed_origins.clone(),
+            max_message_size: config.max_message_size,
+            session_idle_timeout: Some(Duration::from_secs(config.http_session_idle_timeout_seconds))
+                .filter(|timeout| !timeout.is_zero()),
+            max_sessions: config.http_max_sessions,
+        });
+        if let Some(timeout) = state.session_idle_timeout {
+            spawn_session_sweep(Arc::downgrade(&state), timeout);
+        }
+
+        let app = Router::new()
+            .route(&config.http_path, post(handle_post).get(handle_get).delete(handle_delete))
+            .layer(DefaultBodyLimit::max(config.max_message_size))
+            .with_state(state);
+
+        let shutdown = Arc::new(Notify::new());
+        let signal = shutdown.clone();
+        tokio::spawn(async move {
+            let server = axum::serve(listener, app)
+                .with_graceful_shutdown(async move { signal.notified().await });
+            if let Err(e) = server.await {
+                error!("HTTP: Server skončil s chybou: {}", e);
+            }
+        });
+
+        info!("HTTP: Naslouchám na http://{}{}", address, config.http_path);
+        Ok(Self { sessions, shutdown })
+    }
+}
+
+#[async_trait]
+impl Listener for HttpListener {
+    async fn accept(&mut self) -> McpResult<Connection> {
+        self.sessions.recv().await.ok_or_else(|| TransportError::ConnectionClosed.into())
+    }
+
+    async fn close(&mut self) -> McpResult<()> {
+        info!("HTTP: Zavírám server");
+        self.shutdown.notify_one();
+        Ok(())
+    }
+}
+
+impl HttpState {
+    /// Najde relaci podle hlavičky - bez hlavičky 400, neznámá relace 404 (klient má znovu inicializovat)
+    fn session(&self, headers: &HeaderMap) -> Result<(mpsc::Sender<McpMessage>, Arc<SessionChannels>), StatusCode> {
+        let session_id = headers.get(SESSION_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(StatusCode::BAD_REQUEST)?;
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        let session = sessions.get_mut(session_id).ok_or(StatusCode::NOT_FOUND)?;
+        session.last_activity = Instant::now();
+        Ok((session.inbound.clone(), session.channels.clone()))
+    }
+
+    /// Založí novou relaci a předá její transport serveru
+    async fn open_session(&self) -> Result<(String, mpsc::Sender<McpMessage>, Arc<SessionChannels>), StatusCode> {
+        if self.max_sessions > 0 && self.session_count() >= self.max_sessions {
+            warn!("HTTP: Dosažen limit {} relací, nová relace odmítnuta", self.max_sessions);
+            return Err(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        let session_id = uuid::Uuid::new_v4().to_string();
+        let (inbound, inbound_rx) = mpsc::channel(INBOUND_CHANNEL_CAPACITY);
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let channels = Arc::new(SessionChannels {
+            pending: Mutex::new(HashMap::new()),
+            events,
+        });
+
+        let transport = HttpTransport {
+            session: channels.clone(),
+            inbound: inbound_rx,
+            is_closed: false,
+            max_message_size: self.max_message_size,
+        };
+        let connection = Connection { id: session_id.clone(), transport: Box::new(transport) };
+        if self.new_sessions.send(connection).await.is_err() {
+            return Err(StatusCode::SERVICE_UNAVAILABLE);
+        }
+
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).insert(
+            session_id.clone(),
+            HttpSession { inbound: inbound.clone(), channels: channels.clone(), last_activity: Instant::now() },
+        );
+        info!("HTTP: Nová relace {}", session_id);
+        Ok((session_id, inbound, channels))
+    }
+
+    fn session_count(&self) -> usize {
+        self.sessions.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Ukončí relace bez aktivity déle než `timeout`, vrací počet ukončených
+    ///
+    /// Relace s rozpracovaným požadavkem nebo otevřeným SSE streamem se za nečinnou nepovažuje.
+    fn expire_idle_sessions(&self, timeout: Duration, now: Instant) -> usize {
+        let mut sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        let before = sessions.len();
+        sessions.retain(|session_id, session| {
+            let busy = session.channels.events.receiver_count() > 0
+                || !session.channels.pending.lock().unwrap_or_else(|e| e.into_inner()).is_empty();
+            let keep = busy || now.saturating_duration_since(session.last_activity) < timeout;
+            if !keep {
+                info!("HTTP: Relace {} ukončena po nečinnosti", session_id);
+            }
+            keep
+        });
+        before - sessions.len()
+    }
+
+    /// Odmítne požadavky z cizích webových stránek (ochrana proti DNS rebinding)
+    ///
+    /// Bez hlavičky Origin (běžní MCP klienti) a z localhost je požadavek povolen vždy.
+    fn check_origin(&self, headers: &HeaderMap) -> Result<(), StatusCode> {
+        let Some(origin) = headers.get(header::ORIGIN).and_then(|value| value.to_str().ok()) else {
+            return Ok(());
+        };
+        let host = origin.split("://").nth(1).unwrap_or(origin);
+        let is_local = ["localhost", "127.0.0.1", "[::1]"].iter()
+            .any(|local| host == *local || host.starts_with(&format!("{}:", local)));
+        if is_local || self.allowed_origins.iter().any(|allowed| allowed == origin) {
+            Ok(())
+        } else {
+            warn!("HTTP: Odmítnut požadavek z nepovoleného Origin {}", origin);
+            Err(StatusCode::FORBIDDEN)
+        }
+    }
+}
+
+impl SessionChannels {
+    /// Uvolní HTTP požadavek čekající na odpověď na zrušený požadavek
+    ///
+    /// Zrušený požadavek podle MCP odpověď nedostane, POST proto skončí bez těla.
+    fn cancel(&self, notification: &McpMessage) {
+        let McpMessage::Notification(notification) = notification else {
+            return;
+        };
+        if notification.method != "notifications/cancelled" {
+            return;
+        }
+        let request_key = notification.params.as_ref()
+            .and_then(|params| params.get("requestId"))
+            .map(Value::to_string);
+        let waiting = request_key.and_then(|key| self.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(&key));
+        if let Some(sender) = waiting {
+            let _ = sender.send(None);
+        }
+    }
+
+    /// Pošle zprávu odběratelům SSE streamu relace
+    fn broadcast(&self, message: &McpMessage) -> McpResult<()> {
+        let json = message.to_json()?;
+        if self.events.send(json).is_err() {
+            debug!("HTTP: Žádný otevřený SSE stream, zpráva serveru se zahazuje");
+        }
+        Ok(())
+    }
+}
+
+/// Pravidelně ukončuje nečinné relace, dokud běží HTTP server
+fn spawn_session_sweep(state: Weak<HttpState>, timeout: Duration) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(timeout.min(SESSION_SWEEP_INTERVAL));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let Some(state) = state.upgrade() else {
+                break;
+            };
+            let expired = state.expire_idle_sessions(timeout, Instant::now());
+            if expired > 0 {
+                debug!("HTTP: Ukončeno {} nečinných relací", expired);
+            }
+        }
+    });
+}
+
+async fn handle_post(State(state): State<Arc<HttpState>>, headers: HeaderMap, body: String) -> Response {
+    if let Err(status) = state.check_origin(&headers) {
+        return status.into_response();
+    }
+
+    let message = match McpMessage::from_json(&body) {
+        Ok(message) => message,
+        Err(e) => {
+            warn!("HTTP: Neplatná JSON-RPC zpráva: {}", e);
+            let response = JsonRpcResponse::error(None, JsonRpcError::parse_error());
+            return json_response(StatusCode::BAD_REQUEST, &McpMessage::Response(response));
+        }
+    };
+
+    let is_initialize = matches!(&message, McpMessage::Request(request) if request.method == "initialize");
+    let session = if is_initialize && !headers.contains_key(SESSION_HEADER) {
+        state.open_session().await.map(|(id, inbound, channels)| (Some(id), inbound, channels))
+    } else {
+        state.session(&headers).map(|(inbound, channels)| (None, inbound, channels))
+    };
+    let (new_session_id, inbound, channels) = match session {
+        Ok(session) => session,
+        Err(status) => return status.into_response(),
+    };
+
+    let request_key = match &message {
+        McpMessage::Request(request) => request.id.as_ref().map(Value::to_string),
+        _ => None,
+    };
+    let Some(request_key) = request_key else {
+        // Notifikace a odpovědi klienta nemají odpověď v těle
+        channels.cancel(&message);
+        return match inbound.send(message).await {
+            Ok(()) => StatusCode::ACCEPTED.into_response(),
+            Err(_) => StatusCode::NOT_FOUND.into_response(),
+        };
+    };
+
+    let (sender, receiver) = oneshot::channel();
+    channels.pending.lock().unwrap_or_else(|e| e.into_inner()).insert(request_key.clone(), sender);
+    if inbound.send(message).await.is_err() {
+        channels.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(&request_key);
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let response = match receiver.await {
+        Ok(Some(response)) => response,
+        Ok(None) => return StatusCode::NO_CONTENT.into_response(),
+        Err(_) => return StatusCode::SERVICE_UNAVAILABLE.into_response(),
+    };
+    let mut http_response = json_response(StatusCode::OK, &response);
+    if let Some(value) = new_session_id.and_then(|id| HeaderValue::from_str(&id).ok()) {
+        http_response.headers_mut().insert(SESSION_HEADER, value);
+    }
+    http_response
+}
+
+async fn handle_get(State(state): State<Arc<HttpState>>, headers: HeaderMap) -> Response {
+    let channels = match state.check_origin(&headers).and_then(|_| state.session(&headers)) {
+        Ok((_, channels)) => channels,
+        Err(status) => return status.into_response(),
+    };
+
+    let accepts_sse = headers.get(header::ACCEPT)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|accept| accept.contains("text/event-stream"));
+    if !accepts_sse {
+        return StatusCode::METHOD_NOT_ALLOWED.into_response();
+    }
+
+    debug!("HTTP: Otevřen SSE stream");
+    let stream = BroadcastStream::new(channels.events.subscribe())
+        // Pomalý odběratel o zmeškané zprávy přijde, stream ale pokračuje
+        .filter_map(|event| event.ok())
+        .map(|data| Ok::<_, Infallible>(Event::default().event("message").data(data)));
+    Sse::new(stream).keep_alive(KeepAlive::default()).into_response()
+}
+
+async fn handle_delete(State(state): State<Arc<HttpState>>, headers: HeaderMap) -> Response {
+    if let Err(status) = state.check_origin(&headers).and_then(|_| state.session(&headers)) {
+        return status.into_response();
+    }
+
+    let session_id = headers.get(SESSION_HEADER).and_then(|value| value.to_str().ok()).unwrap_or_default();
+    state.sessions.lock().unwrap_or_else(|e| e.into_inner()).remove(session_id);
+    info!("HTTP: Relace {} ukončena klientem", session_id);
+    StatusCode::OK.into_response()
+}
+
+fn json_response(status: StatusCode, message: &McpMessage) -> Response {
+    match message.to_json() {
+        Ok(json) => (status, [(header::CONTENT_TYPE, "application/json")], json).into_response(),
+        Err(e) => {
+            error!("HTTP: Chyba při serializaci odpovědi: {}", e);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+#[async_trait]
+impl Transport for HttpTransport {
+    async fn receive(&mut self) -> McpResult<McpMessage> {
+        if self.is_closed {
+            return Err(TransportError::ConnectionClosed.into());
+        }
+
+        match self.inbound.recv().await {
+            Some(message) => Ok(message),
+            None => {
+                self.is_closed = true;
+                Err(TransportError::ConnectionClosed.into())
+            }
+        }
+    }
+
+    async fn send(&mut self, message: McpMessage) -> McpResult<()> {
+        if self.is_closed {
+            return Err(TransportError::ConnectionClosed.into());
+        }
+
+        let mut message = message;
+        let size = message.to_json()?.len();
+        if size > self.max_message_size {
+            warn!("HTTP: Odchozí zpráva ({} bytů) překračuje limit {} bytů", size, self.max_message_size);
+            match message {
+                McpMessage::Response(response) => {
+                    message = McpMessage::Response(JsonRpcResponse::error(
+                        response.id,
+                        JsonRpcError::message_too_large(size, self.max_message_size),
+                    ));
+                }
+                _ => return Err(TransportError::MessageTooLarge { size, limit: self.max_message_size }.into()),
+            }
+        }
+
+        // Odpověď patří HTTP požadavku se stejným id, ostatní zprávy jdou do SSE streamu
+        let waiting = match &message {
+            McpMessage::Response(response) => response.id.as_ref()
+                .map(Value::to_string)
+                .and_then(|key| self.session.pending.lock().unwrap_or_else(|e| e.into_inner()).remove(&key)),
+            _ => None,
+        };
+        match waiting {
+            Some(sender) => {
+                if sender.send(Some(message)).is_err() {
+                    debug!("HTTP: Klient se odpojil dřív, než dostal odpověď");
+                }
+                Ok(())
+            }
+            None => self.session.broadcast(&message),
+        }
+    }
+
+    async fn close(&mut self) -> McpResult<()> {
+        self.is_closed = true;
+        self.inbound.close();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state() -> (Arc<HttpState>, mpsc::Receiver<Connection>) {
+        let (new_sessions, sessions) = mpsc::channel(INBOUND_CHANNEL_CAPACITY);
+        let state = Arc::new(HttpState {
+            sessions: Mutex::new(HashMap::new()),
+            new_sessions,
+            allowed_origins: Vec::new(),
+            max_message_size: 1024 * 1024,
+            session_idle_timeout: None,
+            max_sessions: 2,
+        });
+        (state, sessions)
+    }
+
+    fn session_headers(session_id: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(SESSION_HEADER, HeaderValue::from_str(session_id).unwrap());
+        headers
+    }
+
+    #[tokio::test]
+    async fn test_cancel_in_flight_request() {
+        let (state, mut sessions) = state();
+        let (session_id, _, channels) = state.open_session().await.unwrap();
+        let mut connection = sessions.recv().await.unwrap();
+
+        let request = r#"{"jsonrpc":"2.0","id":7,"method":"tools/call","params":{"name":"list_projects"}}"#;
+        let post = tokio::spawn(handle_post(State(state.clone()), session_headers(&session_id), request.to_string()));
+
+        // Server požadavek převzal, ale ještě neodpověděl
+        assert!(matches!(connection.transport.receive().await.unwrap(), McpMessage::Request(_)));
+        assert_eq!(channels.pending.lock().unwrap().len(), 1);
+
+        let cancel = r#"{"jsonrpc":"2.0","method":"notifications/cancelled","params":{"requestId":7}}"#;
+        let accepted = handle_post(State(state.clone()), session_headers(&session_id), cancel.to_string()).await;
+        assert_eq!(accepted.status(), StatusCode::ACCEPTED);
+
+        let response = tokio::time::timeout(std::time::Duration::from_secs(1), post).await.unwrap().unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert!(channels.pending.lock().unwrap().is_empty());
+        assert!(matches!(connection.transport.receive().await.unwrap(), McpMessage::Notification(_)));
+    }
+
+    #[tokio::test]
+    async fn test_response_completes_request() {
+        let (state, mut sessions) = state();
+        let (session_id, _, _) = state.open_session().await.unwrap();
+        let mut connection = sessions.recv().await.unwrap();
+
+        let request = r#"{"jsonrpc":"2.0","id":"a","method":"ping"}"#;
+        let post = tokio::spawn(handle_post(State(state.clone()), session_headers(&session_id), request.to_string()));
+
+        let McpMessage::Request(request) = connection.transport.receive().await.unwrap() else {
+            panic!("očekáván požadavek");
+        };
+        let response = JsonRpcResponse::success(request.id, serde_json::json!({}));
+        connection.transport.send(McpMessage::Response(response)).await.unwrap();
+
+        assert_eq!(post.await.unwrap().status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_max_sessions_limit() {
+        let (state, _sessions) = state();
+        state.open_session().await.unwrap();
+        state.open_session().await.unwrap();
+
+        assert_eq!(state.open_session().await.err(), Some(StatusCode::SERVICE_UNAVAILABLE));
+        assert_eq!(state.session_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_idle_sessions_expire() {
+        let (state, _sessions) = state();
+        let (idle_id, _, _) = state.open_session().await.unwrap();
+        let (streaming_id, _, channels) = state.open_session().await.unwrap();
+        let _stream = channels.events.subscribe();
+
+        let timeout = Duration::from_secs(60);
+        assert_eq!(state.expire_idle_sessions(timeout, Instant::now()), 0);
+        assert_eq!(state.expire_idle_sessions(timeout, Instant::now() + timeout * 2), 1);
+
+        assert_eq!(state.session(&session_headers(&idle_id)).err(), Some(StatusCode::NOT_FOUND));
+        assert!(state.session(&session_headers(&streaming_id)).is_ok());
+    }
+}
@@ -1,7 +1,11 @@
 pub mod protocol;
 pub mod server;
 pub mod transport;
+pub mod http_transport;
 pub mod error;
+pub mod chunks;
+pub mod resources;
+pub mod prompts;
 
 pub use server::McpServer;
 pub use protocol::*;
@@ -0,0 +1,203 @@
+use serde_json::{json, Map, Value};
+
+use crate::utils::date_utils::{current_date_local, end_of_week, format_date_iso, parse_date_flexible, start_of_week};
+use super::error::{McpError, McpResult};
+
+/// Argument šablony promptu
+struct PromptArgument {
+    name: &'static str,
+    description: &'static str,
+    required: bool,
+}
+
+/// Vestavěná šablona promptu, která navede model na posloupnost volání tools
+struct PromptTemplate {
+    name: &'static str,
+    description: &'static str,
+    arguments: &'static [PromptArgument],
+    /// Tools, bez kterých šablona nedává smysl (jinak se nenabízí)
+    required_tools: &'static [&'static str],
+    render: fn(&PromptArguments) -> McpResult<String>,
+}
+
+/// Argumenty předané v prompts/get
+struct PromptArguments(Map<String, Value>);
+
+impl PromptArguments {
+    fn get(&self, name: &str) -> Option<String> {
+        match self.0.get(name)? {
+            Value::String(value) if !value.trim().is_empty() => Some(value.trim().to_string()),
+            Value::Number(value) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    fn require(&self, name: &str) -> McpResult<String> {
+        self.get(name)
+            .ok_or_else(|| McpError::InvalidParams(format!("Chybí povinný argument promptu '{}'", name)))
+    }
+}
+
+const TEMPLATES: &[PromptTemplate] = &[
+    PromptTemplate {
+        name: "weekly_status_report",
+        description: "Týdenní status report projektu - hotová práce, odpracovaný čas, rizika a úkoly po termínu",
+        arguments: &[
+            PromptArgument { name: "project_id", description: "ID projektu", required: true },
+            PromptArgument { name: "week", description: "Libovolné datum v týdnu (YYYY-MM-DD, výchozí: aktuální týden)", required: false },
+        ],
+        required_tools: &["generate_project_report"],
+        render: render_weekly_status_report,
+    },
+    PromptTemplate {
+        name: "triage_overdue_issues",
+        description: "Projde úkoly po termínu a navrhne nový termín, přeřazení nebo uzavření",
+        arguments: &[
+            PromptArgument { name: "project_id", description: "ID projektu (výchozí: všechny projekty)", required: false },
+            PromptArgument { name: "assigned_to_id", description: "Jen úkoly daného uživatele", required: false },
+        ],
+        required_tools: &["list_issues", "update_issue"],
+        render: render_triage_overdue_issues,
+    },
+    PromptTemplate {
+        name: "project_health_check",
+        description: "Kontrola zdraví projektu - konzistence úkolů, čerpání rozpočtu a vytížení členů",
+        arguments: &[
+            PromptArgument { name: "project_id", description: "ID projektu", required: true },
+        ],
+        required_tools: &["check_project_consistency"],
+        render: render_project_health_check,
+    },
+    PromptTemplate {
+        name: "user_workload_review",
+        description: "Přehled vytížení uživatele a návrh přerozdělení práce",
+        arguments: &[
+            PromptArgument { name: "user_id", description: "ID uživatele", required: true },
+        ],
+        required_tools: &["get_user_workload"],
+        render: render_user_workload_review,
+    },
+];
+
+fn render_weekly_status_report(args: &PromptArguments) -> McpResult<String> {
+    let project_id = args.require("project_id")?;
+    let day = match args.get("week") {
+        Some(week) => parse_date_flexible(&week).map_err(McpError::InvalidParams)?,
+        None => current_date_local(),
+    };
+    let (from, to) = (format_date_iso(&start_of_week(day)), format_date_iso(&end_of_week(day)));
+
+    Ok(format!(
+        "Připrav týdenní status report projektu {project_id} za období {from} až {to}.\n\n\
+        Postup:\n\
+        1. Zavolej generate_project_report s project_id={project_id}, from_date=\"{from}\", to_date=\"{to}\".\n\
+        2. Zavolej list_issues s project_id={project_id} a seřazením podle updated_on:desc pro úkoly změněné v tomto týdnu.\n\
+        3. Pokud je dostupný budget_burn_report, zavolej jej s project_ids=[{project_id}].\n\n\
+        Výstup: stručné shrnutí (3-5 vět), dokončená práce, odpracované hodiny podle lidí, \
+        úkoly po termínu a rizika s doporučeným dalším krokem."
+    ))
+}
+
+fn render_triage_overdue_issues(args: &PromptArguments) -> McpResult<String> {
+    let mut filter = Vec::new();
+    if let Some(project_id) = args.get("project_id") {
+        filter.push(format!("project_id={}", project_id));
+    }
+    if let Some(assigned_to_id) = args.get("assigned_to_id") {
+        filter.push(format!("assigned_to_id={}", assigned_to_id));
+    }
+    let filter = if filter.is_empty() { "bez dalších filtrů".to_string() } else { filter.join(", ") };
+
+    Ok(format!(
+        "Proveď triáž úkolů po termínu (dnes je {today}).\n\n\
+        Postup:\n\
+        1. Zavolej list_issues ({filter}) a vyber otevřené úkoly s due_date před dneškem.\n\
+        2. U každého úkolu zvaž prioritu, procento dokončení a řešitele; detail získáš přes get_issue_context.\n\
+        3. Navrhni pro každý úkol jednu akci: nový termín, přeřazení jinému řešiteli nebo uzavření.\n\n\
+        Změny přes update_issue nebo assign_issue prováděj až po potvrzení uživatelem. \
+        Výstup: tabulka úkol | termín | navržená akce | zdůvodnění.",
+        today = format_date_iso(&current_date_local()),
+    ))
+}
+
+fn render_project_health_check(args: &PromptArguments) -> McpResult<String> {
+    let project_id = args.require("project_id")?;
+
+    Ok(format!(
+        "Zkontroluj zdraví projektu {project_id}.\n\n\
+        Postup:\n\
+        1. Zavolej check_project_consistency s project_id={project_id}.\n\
+        2. Pokud je dostupný budget_burn_report, zavolej jej s project_ids=[{project_id}].\n\
+        3. Pokud je dostupný get_dashboard_data, zavolej jej pro přehled vytížení členů.\n\n\
+        Výstup: semafor (zelená/oranžová/červená) pro termíny, rozpočet a konzistenci dat, \
+        seznam nálezů seřazený podle závažnosti a navržené nápravy s konkrétními tools."
+    ))
+}
+
+fn render_user_workload_review(args: &PromptArguments) -> McpResult<String> {
+    let user_id = args.require("user_id")?;
+
+    Ok(format!(
+        "Zhodnoť vytížení uživatele {user_id}.\n\n\
+        Postup:\n\
+        1. Zavolej get_user_workload s user_id={user_id}.\n\
+        2. Zavolej list_issues s assigned_to_id={user_id} pro otevřené úkoly a jejich termíny.\n\n\
+        Výstup: odpracované vs. plánované hodiny, úkoly ohrožené termínem \
+        a návrh, které úkoly přerozdělit (změny proveď až po potvrzení uživatelem)."
+    ))
+}
+
+/// Vestavěné šablony promptů (prompts/list a prompts/get)
+pub struct PromptRegistry {
+    templates: Vec<&'static PromptTemplate>,
+}
+
+impl PromptRegistry {
+    /// Nabídne jen šablony, jejichž potřebné tools jsou registrovány
+    pub fn new(has_tool: impl Fn(&str) -> bool) -> Self {
+        Self {
+            templates: TEMPLATES.iter()
+                .filter(|template| template.required_tools.iter().all(|tool| has_tool(tool)))
+                .collect(),
+        }
+    }
+
+    pub fn list(&self) -> Vec<Value> {
+        self.templates.iter()
+            .map(|template| json!({
+                "name": template.name,
+                "description": template.description,
+                "arguments": template.arguments.iter()
+                    .map(|argument| json!({
+                        "name": argument.name,
+                        "description": argument.description,
+                        "required": argument.required,
+                    }))
+                    .collect::<Vec<_>>(),
+            }))
+            .collect()
+    }
+
+    pub fn get(&self, name: &str, arguments: Option<Value>) -> McpResult<Value> {
+        let template = self.templates.iter()
+            .find(|template| template.name == name)
+            .ok_or_else(|| McpError::InvalidParams(format!("Prompt '{}' neexistuje", name)))?;
+
+        let arguments = match arguments {
+            Some(Value::Object(map)) => PromptArguments(map),
+            Some(Value::Null) | None => PromptArguments(Map::new()),
+            Some(_) => return Err(McpError::InvalidParams("Argumenty promptu musí být objekt".to_string())),
+        };
+
+        Ok(json!({
+            "description": template.description,
+            "messages": [{
+                "role": "user",
+                "content": {
+                    "type": "text",
+                    "text": (template.render)(&arguments)?,
+                },
+            }],
+        }))
+    }
+}
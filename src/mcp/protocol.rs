@@ -163,6 +163,57 @@ pub struct CallToolResult {
     #[serde(rename = "isError")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_error: Option<bool>,
+    /// Strojově čitelná data výsledku (text obsahuje stejná data pro starší klienty)
+    #[serde(rename = "structuredContent", default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub structured_content: Option<Value>,
+    #[serde(rename = "_meta")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meta: Option<Value>,
+}
+
+/// Strukturovaná informace o stránkování pro list tools
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaginationInfo {
+    pub total_count: u32,
+    pub offset: u32,
+    pub limit: u32,
+    pub has_more: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_offset: Option<u32>,
+    /// Původně požadovaný limit, pokud byl upraven na povolený rozsah
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requested_limit: Option<u32>,
+}
+
+impl PaginationInfo {
+    /// Sestaví stránkování z odpovědi API (total_count může chybět)
+    pub fn new(total_count: Option<i32>, offset: u32, limit: u32, returned: usize) -> Self {
+        let fetched_until = offset + returned as u32;
+        let total_count = total_count
+            .map(|total| total.max(0) as u32)
+            .unwrap_or(fetched_until);
+        let has_more = returned > 0 && fetched_until < total_count;
+        
+        Self {
+            total_count,
+            offset,
+            limit,
+            has_more,
+            next_offset: if has_more { Some(fetched_until) } else { None },
+            requested_limit: None,
+        }
+    }
+    
+    /// Poznamená původní limit, pokud se liší od skutečně použitého
+    pub fn with_requested_limit(mut self, requested: Option<u32>) -> Self {
+        if let Some(requested) = requested {
+            if requested != self.limit {
+                self.requested_limit = Some(requested);
+            }
+        }
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -180,13 +231,30 @@ pub enum ToolResult {
     Resource {
         resource: ResourceReference,
     },
+    /// Odkaz na resource bez vloženého obsahu
+    #[serde(rename = "resource_link")]
+    ResourceLink {
+        uri: String,
+        name: String,
+        #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+        mime_type: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        size: Option<u64>,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceReference {
     pub uri: String,
+    #[serde(rename = "mimeType", skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    /// Binární obsah v base64
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob: Option<String>,
 }
 
 /// Notification types
@@ -288,6 +356,41 @@ impl ToolResult {
             mime_type: mime_type.into(),
         }
     }
+    
+    /// Vložený resource s textovým obsahem
+    pub fn resource_text<S: Into<String>>(uri: S, mime_type: Option<String>, text: S) -> Self {
+        Self::Resource {
+            resource: ResourceReference {
+                uri: uri.into(),
+                mime_type,
+                text: Some(text.into()),
+                blob: None,
+            },
+        }
+    }
+    
+    /// Odkaz na resource (např. soubor ke stažení) bez vloženého obsahu
+    pub fn resource_link<S: Into<String>>(uri: S, name: S, mime_type: Option<String>, description: Option<String>, size: Option<u64>) -> Self {
+        Self::ResourceLink {
+            uri: uri.into(),
+            name: name.into(),
+            mime_type,
+            description,
+            size,
+        }
+    }
+    
+    /// Vložený resource s binárním obsahem (base64)
+    pub fn resource_blob<S: Into<String>>(uri: S, mime_type: Option<String>, blob: S) -> Self {
+        Self::Resource {
+            resource: ResourceReference {
+                uri: uri.into(),
+                mime_type,
+                text: None,
+                blob: Some(blob.into()),
+            },
+        }
+    }
 }
 
 impl CallToolResult {
@@ -295,6 +398,8 @@ impl CallToolResult {
         Self {
             content,
             is_error: Some(false),
+            structured_content: None,
+            meta: None,
         }
     }
     
@@ -302,6 +407,75 @@ impl CallToolResult {
         Self {
             content,
             is_error: Some(true),
+            structured_content: None,
+            meta: None,
         }
     }
-} 
\ No newline at end of file
+    
+    /// Přidá strukturovaná data výsledku (structuredContent)
+    ///
+    /// Hodnoty, které nejsou JSON objekt, se zabalí do `{"result": ...}`.
+    pub fn with_structured_content<T: Serialize>(mut self, value: &T) -> Self {
+        self.structured_content = match serde_json::to_value(value) {
+            Ok(value @ Value::Object(_)) => Some(value),
+            Ok(value) => Some(serde_json::json!({ "result": value })),
+            Err(_) => None,
+        };
+        self
+    }
+    
+    /// Přidá validační hlášky API do `_meta.validation_errors` (bez hlášek výsledek nemění)
+    pub fn with_validation_errors(mut self, errors: &[String]) -> Self {
+        if errors.is_empty() {
+            return self;
+        }
+        let mut meta = match self.meta.take() {
+            Some(Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        meta.insert("validation_errors".to_string(), serde_json::json!(errors));
+        self.meta = Some(Value::Object(meta));
+        self
+    }
+    
+    /// Přidá strukturované stránkování do `_meta` i jako samostatný textový blok
+    pub fn with_pagination(mut self, pagination: PaginationInfo) -> Self {
+        let pagination_value = serde_json::to_value(&pagination).unwrap_or(Value::Null);
+        
+        if let Some(requested) = pagination.requested_limit {
+            self.content.push(ToolResult::text(format!(
+                "Upozornění: požadovaný limit {} byl upraven na {} (povolený rozsah).",
+                requested, pagination.limit
+            )));
+        }
+        self.content.push(ToolResult::text(format!("Stránkování: {}", pagination_value)));
+        
+        let mut meta = match self.meta.take() {
+            Some(Value::Object(map)) => map,
+            _ => serde_json::Map::new(),
+        };
+        meta.insert("pagination".to_string(), pagination_value);
+        self.meta = Some(Value::Object(meta));
+        self
+    }
+} 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_resource_serialization() {
+        let text = ToolResult::resource_text("easyproject://attachment/1", Some("text/plain".to_string()), "obsah");
+        assert_eq!(serde_json::to_value(&text).unwrap(), json!({
+            "type": "resource",
+            "resource": { "uri": "easyproject://attachment/1", "mimeType": "text/plain", "text": "obsah" }
+        }));
+
+        let blob = ToolResult::resource_blob("easyproject://attachment/2", None, "YWJj");
+        assert_eq!(serde_json::to_value(&blob).unwrap(), json!({
+            "type": "resource",
+            "resource": { "uri": "easyproject://attachment/2", "blob": "YWJj" }
+        }));
+    }
+}
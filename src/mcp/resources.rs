@@ -0,0 +1,173 @@
+use serde_json::{json, Value};
+use tracing::{debug, warn};
+
+use crate::api::EasyProjectClient;
+use crate::config::AppConfig;
+use super::error::{McpError, McpResult};
+
+/// Prefix URI entit EasyProject vystavených jako resources
+const ENTITY_URI_PREFIX: &str = "easyproject://";
+
+/// Počet projektů vrácených v resources/list (ostatní entity jsou dostupné přes šablony URI)
+const LISTED_PROJECTS_LIMIT: u32 = 100;
+
+/// Entita EasyProject adresovaná URI `easyproject://{druh}/{id}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityUri {
+    Project(i32),
+    Issue(i32),
+    User(i32),
+    Milestone(i32),
+}
+
+impl EntityUri {
+    pub fn parse(uri: &str) -> Option<Self> {
+        let (kind, id) = uri.strip_prefix(ENTITY_URI_PREFIX)?.split_once('/')?;
+        let id = id.parse().ok()?;
+        match kind {
+            "project" => Some(EntityUri::Project(id)),
+            "issue" => Some(EntityUri::Issue(id)),
+            "user" => Some(EntityUri::User(id)),
+            "milestone" => Some(EntityUri::Milestone(id)),
+            _ => None,
+        }
+    }
+
+    pub fn uri(&self) -> String {
+        let (kind, id) = match self {
+            EntityUri::Project(id) => ("project", id),
+            EntityUri::Issue(id) => ("issue", id),
+            EntityUri::User(id) => ("user", id),
+            EntityUri::Milestone(id) => ("milestone", id),
+        };
+        format!("{}{}/{}", ENTITY_URI_PREFIX, kind, id)
+    }
+}
+
+/// Projekty, úkoly, uživatelé a milníky jako MCP resources včetně odběru změn
+pub struct EntityResources {
+    api_client: EasyProjectClient,
+    projects: bool,
+    issues: bool,
+    users: bool,
+    milestones: bool,
+}
+
+impl EntityResources {
+    pub fn new(api_client: EasyProjectClient, config: &AppConfig) -> Self {
+        Self {
+            api_client,
+            projects: config.tools.projects.enabled,
+            issues: config.tools.issues.enabled,
+            users: config.tools.users.enabled,
+            milestones: config.tools.milestones.enabled,
+        }
+    }
+
+    fn is_enabled(&self, entity: EntityUri) -> bool {
+        match entity {
+            EntityUri::Project(_) => self.projects,
+            EntityUri::Issue(_) => self.issues,
+            EntityUri::User(_) => self.users,
+            EntityUri::Milestone(_) => self.milestones,
+        }
+    }
+
+    /// Konkrétní resources pro resources/list - projekty (první stránka)
+    pub async fn list(&self) -> Vec<Value> {
+        if !self.projects {
+            return Vec::new();
+        }
+
+        match self.api_client.list_projects(Some(LISTED_PROJECTS_LIMIT), None, None, None, None, None).await {
+            Ok(response) => response.projects.iter()
+                .map(|project| json!({
+                    "uri": EntityUri::Project(project.id).uri(),
+                    "name": project.name,
+                    "description": project.identifier.as_ref().map(|identifier| format!("Projekt {}", identifier)),
+                    "mimeType": "application/json",
+                }))
+                .collect(),
+            Err(e) => {
+                warn!("Nepodařilo se načíst projekty pro resources/list: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Šablony URI pro resources/templates/list
+    pub fn templates(&self) -> Vec<Value> {
+        [
+            (self.projects, "project", "Projekt", "Detail projektu podle ID"),
+            (self.issues, "issue", "Úkol", "Detail úkolu podle ID"),
+            (self.users, "user", "Uživatel", "Detail uživatele podle ID"),
+            (self.milestones, "milestone", "Milník", "Detail milníku (verze) podle ID"),
+        ]
+        .iter()
+        .filter(|(enabled, ..)| *enabled)
+        .map(|(_, kind, name, description)| json!({
+            "uriTemplate": format!("{}{}/{{id}}", ENTITY_URI_PREFIX, kind),
+            "name": name,
+            "description": description,
+            "mimeType": "application/json",
+        }))
+        .collect()
+    }
+
+    /// Načte obsah entity jako JSON text, `None` pokud URI nepatří entitám
+    pub async fn read(&self, uri: &str) -> McpResult<Option<String>> {
+        let Some(entity) = EntityUri::parse(uri).filter(|entity| self.is_enabled(*entity)) else {
+            return Ok(None);
+        };
+
+        debug!("Načítám resource {}", uri);
+        let value = match entity {
+            EntityUri::Project(id) => self.api_client.get_project(id, None).await
+                .map(|response| serde_json::to_value(response.project)),
+            EntityUri::Issue(id) => self.api_client.get_issue(id, None).await
+                .map(|response| serde_json::to_value(response.issue)),
+            EntityUri::User(id) => self.api_client.get_user(id).await
+                .map(|response| serde_json::to_value(response.user)),
+            EntityUri::Milestone(id) => self.api_client.get_milestone(id).await
+                .map(|response| serde_json::to_value(response.version)),
+        }
+        .map_err(|e| McpError::InvalidParams(format!("Resource '{}' nelze načíst: {}", uri, e)))??;
+
+        Ok(Some(serde_json::to_string_pretty(&value)?))
+    }
+
+    /// Ověří, že lze odebírat změny entity (odběry si drží relace klienta)
+    pub fn check_subscribable(&self, uri: &str) -> McpResult<()> {
+        if !EntityUri::parse(uri).is_some_and(|entity| self.is_enabled(entity)) {
+            return Err(McpError::InvalidParams(format!("Resource '{}' nelze odebírat", uri)));
+        }
+        Ok(())
+    }
+
+    /// Resources, kterých se týká volání měnícího tool
+    ///
+    /// Entitu určuje parametr `id` podle názvu tool (např. update_issue) a parametry
+    /// `issue_id`, `project_id` a `user_id`.
+    pub fn affected_uris(&self, tool_name: &str, arguments: Option<&Value>) -> Vec<String> {
+        let Some(arguments) = arguments else { return Vec::new() };
+        let id_of = |key: &str| arguments.get(key).and_then(Value::as_i64).map(|id| id as i32);
+
+        let mut entities = Vec::new();
+        if let Some(id) = id_of("id") {
+            if tool_name.contains("issue") {
+                entities.push(EntityUri::Issue(id));
+            } else if tool_name.contains("project") {
+                entities.push(EntityUri::Project(id));
+            } else if tool_name.contains("milestone") {
+                entities.push(EntityUri::Milestone(id));
+            } else if tool_name.contains("user") {
+                entities.push(EntityUri::User(id));
+            }
+        }
+        entities.extend(id_of("issue_id").map(EntityUri::Issue));
+        entities.extend(id_of("project_id").map(EntityUri::Project));
+        entities.extend(id_of("user_id").map(EntityUri::User));
+
+        entities.iter().map(EntityUri::uri).collect()
+    }
+}
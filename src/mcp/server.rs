@@ -1,20 +1,66 @@
 use serde_json::{json, Value};
-use tracing::{debug, error, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+use tokio::sync::mpsc;
+use tokio::task::{AbortHandle, JoinSet};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
-use crate::config::AppConfig;
+use crate::config::{spawn_reload_triggers, AppConfig, ReloadTrigger};
+use crate::i18n;
 use crate::api::EasyProjectClient;
+use crate::audit::{AuditEntry, AuditLog};
+use crate::scheduler::ReportScheduler;
 use crate::tools::ToolRegistry;
+use crate::tools::executor::InvalidArguments;
+use crate::utils::correlation::{current_correlation_id, new_correlation_id, with_correlation_id};
+use crate::utils::text_utils::split_text_chunks;
 
-use super::error::{McpError, McpResult};
+use super::chunks::{ResultChunkStore, page_uri, parse_page_uri};
+use super::prompts::PromptRegistry;
+use super::resources::EntityResources;
+use super::error::{JsonRpcError, McpError, McpResult, TransportError};
 use super::protocol::{*, PromptsCapability, ResourcesCapability};
-use super::transport::{Transport, create_transport};
+use super::transport::{Connection, Listener, create_listener};
+
+/// Počet posledních rozdělených výsledků, jejichž stránky zůstávají dostupné
+const MAX_CHUNKED_RESULTS: usize = 20;
 
 pub struct McpServer {
+    listener: Box<dyn Listener + Send>,
+    state: Arc<ServerState>,
+}
+
+/// Stav sdílený všemi připojenými klienty
+struct ServerState {
+    /// Konfigurace a z ní sestavené části, při znovunačtení konfigurace se vymění vcelku
+    runtime: RwLock<Arc<Runtime>>,
+    /// Otevřené relace podle ID spojení (pro notifikace o změnách odebíraných resources)
+    sessions: Mutex<HashMap<String, Arc<Session>>>,
+}
+
+/// Části serveru závislé na konfiguraci
+///
+/// Rozpracované požadavky doběhnou s verzí, se kterou začaly.
+struct Runtime {
     config: AppConfig,
-    transport: Box<dyn Transport + Send>,
     tool_registry: ToolRegistry,
-    is_initialized: bool,
-    client_info: Option<ClientInfo>,
+    entity_resources: EntityResources,
+    prompts: PromptRegistry,
+}
+
+/// Stav jednoho připojeného klienta
+struct Session {
+    id: String,
+    state: Arc<ServerState>,
+    /// Zprávy k odeslání klientovi (odpovědi i notifikace)
+    outbound: mpsc::UnboundedSender<McpMessage>,
+    is_initialized: AtomicBool,
+    client_info: Mutex<Option<ClientInfo>>,
+    result_chunks: Mutex<ResultChunkStore>,
+    subscriptions: Mutex<HashSet<String>>,
+    /// Rozpracované požadavky podle JSON-RPC id (pro notifications/cancelled)
+    in_flight: Mutex<HashMap<String, AbortHandle>>,
 }
 
 impl McpServer {
@@ -22,79 +68,374 @@ impl McpServer {
         info!("Inicializuji MCP Server");
         
         // Vytvoření transportní vrstvy
-        let transport = create_transport(
-            config.server.transport.clone(),
-            config.server.websocket_port
-        );
+        let listener = create_listener(&config.server).await?;
         
         // Vytvoření API klienta
         let api_client = EasyProjectClient::new(&config).await
-            .map_err(|e| McpError::InternalError(format!("Nepodařilo se vytvořit API klient: {}", e)))?;
+            .map_err(|e| McpError::InternalError(format!("{}: {}", i18n::messages().error_creating_api_client, e)))?;
         
-        // Inicializace tool registry
-        let tool_registry = ToolRegistry::new(api_client, &config);
+        // Plánovač sestav běží na pozadí se samostatnou kopií klienta
+        if config.scheduler.enabled {
+            ReportScheduler::new(api_client.clone(), &config).spawn();
+        }
+        
+        let runtime = Runtime::build(api_client, config, None).await;
+        let state = Arc::new(ServerState {
+            runtime: RwLock::new(Arc::new(runtime)),
+            sessions: Mutex::new(HashMap::new()),
+        });
         
-        Ok(Self {
-            config,
-            transport,
-            tool_registry,
-            is_initialized: false,
-            client_info: None,
-        })
+        // Znovunačtení konfigurace po SIGHUP nebo změně souboru (server.watch_config)
+        let mut triggers = spawn_reload_triggers(state.runtime().config.server.watch_config);
+        let reload_state = state.clone();
+        tokio::spawn(async move {
+            while let Some(trigger) = triggers.recv().await {
+                reload_state.reload(trigger).await;
+            }
+        });
+        
+        Ok(Self { listener, state })
+    }
+    
+    /// Počet registrovaných tools aktuální konfigurace
+    pub fn tool_count(&self) -> usize {
+        self.state.runtime().tool_registry.tool_count()
     }
     
+    /// Přijímá spojení a každé obsluhuje samostatně, dokud listener neskončí
+    ///
+    /// Po ukončení naslouchání (např. STDIO má jediné spojení) čeká na dokončení otevřených spojení.
     pub async fn run(&mut self) -> McpResult<()> {
         info!("MCP Server spuštěn a čeká na zprávy");
         
+        let mut connections = JoinSet::new();
         loop {
-            match self.transport.receive().await {
-                Ok(message) => {
-                    if let Err(e) = self.handle_message(message).await {
-                        error!("Chyba při zpracování zprávy: {}", e);
-                        // Pokračujeme v běhu i při chybách
-                    }
+            match self.listener.accept().await {
+                Ok(connection) => {
+                    info!("Nové spojení {}", connection.id);
+                    connections.spawn(Session::serve(self.state.clone(), connection));
+                    while connections.try_join_next().is_some() {}
                 }
-                Err(McpError::Transport(crate::mcp::error::TransportError::ConnectionClosed)) => {
-                    info!("Spojení ukončeno, zastavuji server");
+                Err(McpError::Transport(TransportError::ConnectionClosed)) => {
+                    debug!("Listener nepřijímá další spojení");
                     break;
                 }
                 Err(e) => {
                     error!("Chyba transportní vrstvy: {}", e);
-                    // Můžeme se rozhodnout, zda pokračovat nebo ukončit
                     break;
                 }
             }
         }
         
+        while connections.join_next().await.is_some() {}
+        
         // Cleanup
-        self.transport.close().await?;
+        self.listener.close().await?;
         info!("MCP Server ukončen");
         Ok(())
     }
+}
+
+/// Ověří spojení a spustí přednačtení cache podle konfigurace instance
+async fn prepare_client(api_client: &EasyProjectClient, config: &AppConfig) {
+    // Chybný API klíč nebo base_url se projeví hned, ne až u prvního volání tool
+    if config.easyproject.check_connection_on_startup {
+        let check = api_client.check_connection().await;
+        match check.error {
+            None => info!(
+                "Spojení s EasyProject ověřeno: uživatel {}, odezva {} ms",
+                check.user.as_deref().unwrap_or("?"), check.latency_ms
+            ),
+            Some(ref error) if check.reachable => error!(
+                "Ověření spojení s EasyProject {} selhalo: {} (zkontrolujte api_key, případně username/password)",
+                check.base_url, error
+            ),
+            Some(ref error) => error!(
+                "EasyProject {} není dostupný: {} (zkontrolujte base_url a síťové spojení)",
+                check.base_url, error
+            ),
+        }
+    }
+    
+    // Přednačtení cache nezdržuje start, první volání tools už ho mohou využít
+    if config.cache.enabled && config.cache.warm_up {
+        let client = api_client.clone();
+        let targets = config.cache.warm_up_targets.clone();
+        tokio::spawn(async move { client.warm_up_cache(&targets).await });
+    }
+}
+
+impl Runtime {
+    /// Sestaví registry tools, resources a prompty nad klientem API pro danou konfiguraci
+    ///
+    /// Při znovunačtení konfigurace se předává audit z původní registry, aby se záznamy neztratily.
+    async fn build(api_client: EasyProjectClient, config: AppConfig, audit_log: Option<Arc<AuditLog>>) -> Self {
+        prepare_client(&api_client, &config).await;
+        
+        let entity_resources = EntityResources::new(api_client.clone(), &config);
+        
+        // Inicializace tool registry
+        let mut tool_registry = match audit_log {
+            Some(audit_log) => ToolRegistry::with_audit_log(api_client, &config, audit_log),
+            None => ToolRegistry::new(api_client, &config),
+        };
+        if config.tools.probe_permissions {
+            tool_registry.refresh_permissions().await;
+        }
+        
+        // Další instance EasyProject z [instances] - vlastní klient, společný audit
+        let instances = config.instance_configs().unwrap_or_else(|e| {
+            error!("Neplatná konfigurace instancí: {}", e);
+            Vec::new()
+        });
+        for (name, instance_config) in instances {
+            let client = match EasyProjectClient::new(&instance_config).await {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Klienta pro instanci '{}' se nepodařilo vytvořit: {}", name, e);
+                    continue;
+                }
+            };
+            prepare_client(&client, &instance_config).await;
+            let registry = ToolRegistry::with_audit_log(client, &instance_config, tool_registry.audit_log().clone());
+            if instance_config.tools.probe_permissions {
+                registry.refresh_permissions().await;
+            }
+            info!("Instance EasyProject '{}' ({}) připravena", name, instance_config.easyproject.base_url);
+            tool_registry.add_instance(registry);
+        }
+        
+        let prompts = PromptRegistry::new(|name| tool_registry.has_tool(name));
+        
+        Self { config, tool_registry, entity_resources, prompts }
+    }
+    
+    fn tool_names(&self) -> HashSet<String> {
+        self.tool_registry.list_tools().into_iter().map(|tool| tool.name).collect()
+    }
+}
+
+impl ServerState {
+    /// Aktuální konfigurace a z ní sestavené části serveru
+    fn runtime(&self) -> Arc<Runtime> {
+        self.runtime.read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+    
+    /// Znovu načte konfiguraci a vymění klienta API, registry tools, resources a prompty
+    ///
+    /// Při chybě v konfiguraci server běží dál s původní. Nastavení transportu, logování
+    /// a plánovače sestav se projeví až po restartu serveru.
+    async fn reload(&self, trigger: ReloadTrigger) {
+        match trigger {
+            ReloadTrigger::Signal => info!("SIGHUP: znovu načítám konfiguraci"),
+            ReloadTrigger::FileChanged(ref path) => info!("Soubor {} se změnil, znovu načítám konfiguraci", path.display()),
+        }
+        
+        let config = match AppConfig::load_with_diagnostics() {
+            Ok((config, diagnostics)) => {
+                diagnostics.log();
+                if diagnostics.load_error.is_some() {
+                    error!("Konfiguraci nelze načíst, ponechávám původní");
+                    return;
+                }
+                config
+            }
+            Err(e) => {
+                error!("Konfiguraci nelze načíst, ponechávám původní: {}", e);
+                return;
+            }
+        };
+        if let Err(e) = config.validate() {
+            error!("Neplatná konfigurace, ponechávám původní: {:#}", e);
+            return;
+        }
+        
+        let current = self.runtime();
+        let restart_only = [
+            ("server", json!(current.config.server) != json!(config.server)),
+            ("logging", json!(current.config.logging) != json!(config.logging)),
+            ("scheduler", json!(current.config.scheduler) != json!(config.scheduler)),
+        ];
+        for (section, _) in restart_only.iter().filter(|(_, changed)| *changed) {
+            warn!("Změny v sekci [{}] se projeví až po restartu serveru", section);
+        }
+        
+        let api_client = match EasyProjectClient::new(&config).await {
+            Ok(client) => client,
+            Err(e) => {
+                error!("Nepodařilo se vytvořit API klient, ponechávám původní konfiguraci: {}", e);
+                return;
+            }
+        };
+        let audit_log = current.tool_registry.audit_log().clone();
+        // Server pokračuje s původním transportem, i když se server.* v souboru změnilo
+        let config = AppConfig { server: current.config.server.clone(), ..config };
+        let runtime = Runtime::build(api_client, config, Some(audit_log)).await;
+        
+        let tools_changed = runtime.tool_names() != current.tool_names();
+        info!("Konfigurace znovu načtena, registrováno {} tools", runtime.tool_registry.tool_count());
+        *self.runtime.write().unwrap_or_else(|e| e.into_inner()) = Arc::new(runtime);
+        
+        if tools_changed {
+            self.notify_tools_changed();
+        }
+    }
+    
+    /// Oznámí všem relacím změnu seznamu tools (klient si načte nový tools/list)
+    fn notify_tools_changed(&self) {
+        let sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        for session in sessions.values().filter(|session| session.is_initialized()) {
+            let notification = JsonRpcRequest {
+                jsonrpc: "2.0".to_string(),
+                method: "notifications/tools/list_changed".to_string(),
+                params: None,
+                id: None,
+            };
+            let _ = session.outbound.send(McpMessage::Notification(notification));
+        }
+    }
+    
+    /// Pošle notifikaci o změně resources všem relacím, které je odebírají
+    fn notify_resources_updated(&self, uris: &[String]) {
+        if uris.is_empty() {
+            return;
+        }
+        let sessions = self.sessions.lock().unwrap_or_else(|e| e.into_inner());
+        for session in sessions.values() {
+            let subscriptions = session.subscriptions.lock().unwrap_or_else(|e| e.into_inner());
+            for uri in uris.iter().filter(|uri| subscriptions.contains(*uri)) {
+                let notification = JsonRpcRequest {
+                    jsonrpc: "2.0".to_string(),
+                    method: "notifications/resources/updated".to_string(),
+                    params: Some(json!({ "uri": uri })),
+                    id: None,
+                };
+                // Relace se mezitím mohla ukončit
+                let _ = session.outbound.send(McpMessage::Notification(notification));
+            }
+        }
+    }
+}
+
+impl Session {
+    /// Obsluhuje jedno spojení - požadavky zpracovává souběžně a odpovědi posílá, jakmile jsou hotové
+    async fn serve(state: Arc<ServerState>, connection: Connection) {
+        let Connection { id, mut transport } = connection;
+        let (outbound, mut outbound_rx) = mpsc::unbounded_channel();
+        let session = Arc::new(Session {
+            id: id.clone(),
+            state: state.clone(),
+            outbound,
+            is_initialized: AtomicBool::new(false),
+            client_info: Mutex::new(None),
+            result_chunks: Mutex::new(ResultChunkStore::new(MAX_CHUNKED_RESULTS)),
+            subscriptions: Mutex::new(HashSet::new()),
+            in_flight: Mutex::new(HashMap::new()),
+        });
+        state.sessions.lock().unwrap_or_else(|e| e.into_inner()).insert(id.clone(), session.clone());
+        
+        let mut requests = JoinSet::new();
+        loop {
+            tokio::select! {
+                received = transport.receive() => match received {
+                    Ok(message) => {
+                        session.clone().dispatch(message, &mut requests).await;
+                        while requests.try_join_next().is_some() {}
+                    }
+                    Err(McpError::Transport(TransportError::ConnectionClosed)) => {
+                        info!("Spojení {} ukončeno", id);
+                        break;
+                    }
+                    Err(McpError::Transport(TransportError::MessageTooLarge { size, limit })) => {
+                        // Id požadavku neznáme, odpovíme chybou bez id a pokračujeme
+                        warn!("Odmítnuta příliš velká zpráva ({} bytů, limit {})", size, limit);
+                        let response = JsonRpcResponse::error(None, JsonRpcError::message_too_large(size, limit));
+                        let _ = session.outbound.send(McpMessage::Response(response));
+                    }
+                    Err(e) => {
+                        error!("Chyba transportní vrstvy: {}", e);
+                        break;
+                    }
+                },
+                Some(message) = outbound_rx.recv() => {
+                    if let Err(e) = transport.send(message).await {
+                        error!("Chyba při odesílání zprávy: {}", e);
+                    }
+                }
+            }
+        }
+        
+        // Rozpracované požadavky se dokončí a jejich odpovědi ještě odešleme
+        state.sessions.lock().unwrap_or_else(|e| e.into_inner()).remove(&id);
+        while requests.join_next().await.is_some() {}
+        while let Ok(message) = outbound_rx.try_recv() {
+            if let Err(e) = transport.send(message).await {
+                error!("Chyba při odesílání zprávy: {}", e);
+            }
+        }
+        if let Err(e) = transport.close().await {
+            error!("Chyba při zavírání spojení {}: {}", id, e);
+        }
+    }
     
-    async fn handle_message(&mut self, message: McpMessage) -> McpResult<()> {
+    /// Předá zprávu ke zpracování - initialize a notifikace hned, ostatní požadavky na pozadí
+    async fn dispatch(self: Arc<Self>, message: McpMessage, requests: &mut JoinSet<()>) {
         match message {
+            McpMessage::Request(request) if request.method == "initialize" => {
+                // Ostatní požadavky závisí na inicializaci, proto ji nezpracováváme souběžně
+                self.respond(request).await;
+            }
             McpMessage::Request(request) => {
                 debug!("Zpracovávám request: {}", request.method);
-                let response = self.handle_request(request).await;
-                self.transport.send(McpMessage::Response(response)).await?;
+                let key = request_key(request.id.as_ref());
+                // Zámek drží záznam konzistentní i při okamžitém dokončení úlohy
+                let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+                let session = self.clone();
+                let handle = requests.spawn(async move { session.respond(request).await });
+                if let Some(key) = key {
+                    in_flight.insert(key, handle);
+                }
             }
             McpMessage::Notification(notification) => {
                 debug!("Zpracovávám notification: {}", notification.method);
-                self.handle_notification(notification).await?;
+                if let Err(e) = self.handle_notification(notification).await {
+                    error!("Chyba při zpracování zprávy: {}", e);
+                }
             }
             McpMessage::Response(_) => {
                 warn!("Přijata neočekávaná response zpráva");
             }
         }
-        Ok(())
     }
     
-    async fn handle_request(&mut self, request: JsonRpcRequest) -> JsonRpcResponse {
+    /// Zpracuje požadavek, odešle odpověď a poté notifikace o změněných resources
+    async fn respond(&self, request: JsonRpcRequest) {
+        let key = request_key(request.id.as_ref());
+        let mut updated_uris = Vec::new();
+        let response = self.handle_request(request, &mut updated_uris).await;
+        if let Some(key) = key {
+            self.in_flight.lock().unwrap_or_else(|e| e.into_inner()).remove(&key);
+        }
+        let _ = self.outbound.send(McpMessage::Response(response));
+        self.state.notify_resources_updated(&updated_uris);
+    }
+    
+    fn is_initialized(&self) -> bool {
+        self.is_initialized.load(Ordering::Acquire)
+    }
+    
+    async fn handle_request(&self, request: JsonRpcRequest, updated_uris: &mut Vec<String>) -> JsonRpcResponse {
         let result = match request.method.as_str() {
             "initialize" => self.handle_initialize(request.params).await,
             "tools/list" => self.handle_tools_list(request.params).await,
-            "tools/call" => self.handle_tools_call(request.params).await,
+            "tools/call" => self.handle_tools_call(request.params, updated_uris).await,
+            "resources/list" => self.handle_resources_list(request.params).await,
+            "resources/read" => self.handle_resources_read(request.params).await,
+            "resources/templates/list" => self.handle_resource_templates_list().await,
+            "resources/subscribe" => self.handle_resources_subscribe(request.params, true).await,
+            "resources/unsubscribe" => self.handle_resources_subscribe(request.params, false).await,
+            "prompts/list" => self.handle_prompts_list().await,
+            "prompts/get" => self.handle_prompts_get(request.params).await,
             method => {
                 error!("Neznámá metoda: {}", method);
                 Err(McpError::UnknownMethod(method.to_string()))
@@ -107,14 +448,25 @@ impl McpServer {
         }
     }
     
-    async fn handle_notification(&mut self, notification: JsonRpcRequest) -> McpResult<()> {
+    async fn handle_notification(&self, notification: JsonRpcRequest) -> McpResult<()> {
         match notification.method.as_str() {
             "notifications/initialized" => {
                 info!("Klient potvrdil inicializaci");
                 Ok(())
             }
             "notifications/cancelled" => {
-                debug!("Operace zrušena");
+                let request_id = notification.params.as_ref().and_then(|params| params.get("requestId"));
+                let handle = request_key(request_id)
+                    .and_then(|key| self.in_flight.lock().unwrap_or_else(|e| e.into_inner()).remove(&key));
+                match handle {
+                    Some(handle) => {
+                        // Zrušený požadavek už podle MCP nedostane odpověď
+                        handle.abort();
+                        let id = request_id.map(|id| id.to_string()).unwrap_or_default();
+                        info!("Požadavek {} zrušen klientem", id);
+                    }
+                    None => debug!("Zrušení požadavku, který už neběží: {:?}", request_id),
+                }
                 Ok(())
             }
             method => {
@@ -124,22 +476,23 @@ impl McpServer {
         }
     }
     
-    async fn handle_initialize(&mut self, params: Option<Value>) -> McpResult<Value> {
+    async fn handle_initialize(&self, params: Option<Value>) -> McpResult<Value> {
         let params: InitializeParams = match params {
             Some(p) => serde_json::from_value(p)
-                .map_err(|e| McpError::InvalidParams(format!("Neplatné parametry initialize: {}", e)))?,
-            None => return Err(McpError::InvalidParams("Chybí parametry pro initialize".to_string())),
+                .map_err(|e| McpError::InvalidParams(format!("{}: {}", (i18n::messages().invalid_request_params)("initialize"), e)))?,
+            None => return Err(McpError::InvalidParams((i18n::messages().missing_request_params)("initialize"))),
         };
         
-        info!("Inicializace od klienta: {} v{}", params.client_info.name, params.client_info.version);
+        info!("Inicializace od klienta: {} v{} (spojení {})", params.client_info.name, params.client_info.version, self.id);
         
         if params.protocol_version != "2024-11-05" {
             warn!("Nepodporovaná verze MCP protokolu: {}", params.protocol_version);
         }
         
-        self.client_info = Some(params.client_info);
-        self.is_initialized = true;
+        *self.client_info.lock().unwrap_or_else(|e| e.into_inner()) = Some(params.client_info);
+        self.is_initialized.store(true, Ordering::Release);
         
+        let runtime = self.state.runtime();
         let result = InitializeResult {
             protocol_version: "2024-11-05".to_string(),
             capabilities: ServerCapabilities {
@@ -148,26 +501,79 @@ impl McpServer {
                     list_changed: Some(false),
                 }),
                 resources: Some(ResourcesCapability {
-                    subscribe: Some(false),
+                    subscribe: Some(true),
                     list_changed: Some(false),
                 }),
                 tools: Some(ToolsCapability {
-                    list_changed: Some(false),
+                    list_changed: Some(true),
                 }),
             },
             server_info: ServerInfo {
-                name: self.config.server.name.clone(),
-                version: self.config.server.version.clone(),
+                name: runtime.config.server.name.clone(),
+                version: runtime.config.server.version.clone(),
             },
-            instructions: Some("EasyProject MCP Server pro správu projektů, úkolů a uživatelů prostřednictvím EasyProject API.".to_string()),
+            instructions: Some(self.build_instructions()),
         };
         
         Ok(serde_json::to_value(result)?)
     }
     
+    /// Sestaví instrukce pro klienta podle aktuální konfigurace a registrovaných tools
+    fn build_instructions(&self) -> String {
+        let runtime = self.state.runtime();
+        let tools = &runtime.config.tools;
+        let messages = i18n::messages();
+        let groups: Vec<&str> = [
+            tools.projects.enabled,
+            tools.issues.enabled,
+            tools.users.enabled,
+            tools.time_entries.enabled,
+            tools.reports.enabled,
+            tools.milestones.enabled,
+        ]
+        .iter()
+        .zip(messages.tool_group_labels)
+        .filter(|(enabled, _)| **enabled)
+        .map(|(_, label)| label)
+        .collect();
+        
+        let mut instructions = (messages.instructions_header)(
+            &runtime.config.easyproject.base_url,
+            &if groups.is_empty() { messages.instructions_no_groups.to_string() } else { groups.join(", ") },
+            runtime.tool_registry.tool_count()
+        );
+        
+        if runtime.tool_registry.is_read_only() {
+            instructions.push_str(messages.instructions_read_only);
+        }
+        
+        let unavailable = runtime.tool_registry.unavailable_tools();
+        if !unavailable.is_empty() {
+            instructions.push_str(&(messages.instructions_unavailable)(&unavailable.join(", ")));
+        }
+        
+        instructions.push_str(messages.instructions_workflow);
+        if runtime.tool_registry.has_tool("get_issue_enumerations") {
+            instructions.push_str(messages.instructions_enumerations);
+        }
+        if runtime.tool_registry.has_tool("list_projects") {
+            instructions.push_str(messages.instructions_projects);
+        }
+        if runtime.tool_registry.has_tool("list_assignable_versions") {
+            instructions.push_str(messages.instructions_versions);
+        }
+        if runtime.tool_registry.has_tool("list_user_types") {
+            instructions.push_str(messages.instructions_user_types);
+        }
+        instructions.push_str(messages.instructions_chunks);
+        instructions.push_str(messages.instructions_resources);
+        
+        instructions
+    }
+    
     async fn handle_tools_list(&self, params: Option<Value>) -> McpResult<Value> {
-        if !self.is_initialized {
-            return Err(McpError::Protocol("Server není inicializován".to_string()));
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
         }
         
         let _params: ListToolsParams = match params {
@@ -176,7 +582,7 @@ impl McpServer {
         };
         
         debug!("Generuji seznam dostupných tools");
-        let tools = self.tool_registry.list_tools();
+        let tools = self.state.runtime().tool_registry.list_tools();
         
         let result = ListToolsResult {
             tools,
@@ -186,28 +592,249 @@ impl McpServer {
         Ok(serde_json::to_value(result)?)
     }
     
-    async fn handle_tools_call(&self, params: Option<Value>) -> McpResult<Value> {
-        if !self.is_initialized {
-            return Err(McpError::Protocol("Server není inicializován".to_string()));
+    async fn handle_tools_call(&self, params: Option<Value>, updated_uris: &mut Vec<String>) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
         }
         
         let params: CallToolParams = match params {
             Some(p) => serde_json::from_value(p)
-                .map_err(|e| McpError::InvalidParams(format!("Neplatné parametry pro tools/call: {}", e)))?,
-            None => return Err(McpError::InvalidParams("Chybí parametry pro tools/call".to_string())),
+                .map_err(|e| McpError::InvalidParams(format!("{}: {}", (i18n::messages().invalid_request_params)("tools/call"), e)))?,
+            None => return Err(McpError::InvalidParams((i18n::messages().missing_request_params)("tools/call"))),
         };
         
+        // Všechny logy volání včetně API požadavků nesou stejné korelační ID
+        let correlation_id = new_correlation_id();
+        let span = info_span!("tool_call", correlation_id = %correlation_id, tool = %params.name);
+        with_correlation_id(correlation_id, self.call_tool(params, updated_uris))
+            .instrument(span)
+            .await
+    }
+    
+    async fn call_tool(&self, params: CallToolParams, updated_uris: &mut Vec<String>) -> McpResult<Value> {
         info!("Volám tool: {}", params.name);
         debug!("Argumenty: {:?}", params.arguments);
         
-        let result = self.tool_registry.execute_tool(&params.name, params.arguments).await
-            .map_err(|e| {
-                error!("Chyba při volání tool {}: {}", params.name, e);
-                McpError::ToolError(e.to_string())
-            })?;
+        // Volání doběhne s registry, se kterou začalo, i když se mezitím znovu načte konfigurace
+        let runtime = self.state.runtime();
+        let arguments = params.arguments.clone();
+        let registry = &runtime.tool_registry;
+        // Zápisový tool i audit se určují podle instance, nad kterou volání poběží
+        let instance = registry.instance_registry(
+            arguments.as_ref().and_then(|arguments| arguments.get("instance")).and_then(Value::as_str)
+        );
+        let mutating = instance.is_some_and(|instance| {
+            instance.has_tool(&params.name) && !instance.is_tool_read_only(&params.name)
+        });
+        let result = registry.execute_tool(&params.name, params.arguments).await;
+        
+        if let Some(instance) = instance.filter(|_| mutating) {
+            self.audit(&params.name, instance.instance_name(), arguments.as_ref(), result.as_ref().map_err(|e| e.to_string()));
+        }
+        
+        let result = result.map_err(|e| {
+            if e.downcast_ref::<InvalidArguments>().is_some() {
+                return McpError::InvalidParams(e.to_string());
+            }
+            error!("Chyba při volání tool {}: {}", params.name, e);
+            McpError::ToolError(e.to_string())
+        })?;
+        
+        // Resources easyproject:// patří výchozí instanci, zápis na jiné instanci se jich netýká
+        let default_instance = instance.is_some_and(|instance| instance.instance_name() == registry.instance_name());
+        if !result.is_error.unwrap_or(false) && mutating && default_instance {
+            updated_uris.extend(runtime.entity_resources.affected_uris(&params.name, arguments.as_ref()));
+        }
+        
+        let result = self.chunk_large_result(result);
         
         Ok(serde_json::to_value(result)?)
     }
+    
+    /// Zapíše volání zápisového tool do auditu včetně klienta a korelačního ID
+    fn audit(&self, tool: &str, instance: &str, arguments: Option<&Value>, result: Result<&CallToolResult, String>) {
+        let mut entry = AuditEntry::from_call(tool, arguments, result);
+        entry.instance = Some(instance.to_string());
+        entry.client = self.client_info.lock().unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|client| format!("{} {}", client.name, client.version));
+        entry.correlation_id = current_correlation_id();
+        self.state.runtime().tool_registry.audit_log().record(entry);
+    }
+    
+    /// Rozdělí příliš dlouhé textové výsledky na stránky dostupné přes resources/read
+    ///
+    /// Velikost stránky je menší z server.result_chunk_size a tools.max_response_bytes (0 = bez rozpočtu).
+    /// V odpovědi zůstane první stránka a odkazy na zbývající stránky.
+    fn chunk_large_result(&self, mut result: CallToolResult) -> CallToolResult {
+        let runtime = self.state.runtime();
+        let chunk_size = match runtime.config.tools.max_response_bytes {
+            0 => runtime.config.server.result_chunk_size,
+            max_bytes => max_bytes.min(runtime.config.server.result_chunk_size),
+        };
+        let messages = i18n::messages();
+        let limit_hint = result.meta.as_ref()
+            .and_then(|meta| meta.pointer("/pagination/limit"))
+            .and_then(Value::as_u64)
+            .map(messages.chunk_limit_hint)
+            .unwrap_or_default();
+        let mut content = Vec::with_capacity(result.content.len());
+        let mut chunked = Vec::new();
+        
+        for item in result.content {
+            match item {
+                ToolResult::Text { text } if text.len() > chunk_size => {
+                    let pages = split_text_chunks(&text, chunk_size);
+                    let total = pages.len();
+                    let first_page = pages[0].clone();
+                    let id = self.result_chunks.lock().unwrap_or_else(|e| e.into_inner()).store(pages);
+                    
+                    info!("Výsledek ({} bytů) rozdělen na {} stránek (ID {})", text.len(), total, id);
+                    
+                    let remaining: Vec<String> = (2..=total).map(|page| page_uri(id, page)).collect();
+                    content.push(ToolResult::text(first_page));
+                    content.push(ToolResult::text(format!(
+                        "{}{}",
+                        (messages.chunk_truncated)(text.len(), total, &remaining.join("\n")),
+                        limit_hint
+                    )));
+                    chunked.push(json!({
+                        "first_page": page_uri(id, 1),
+                        "next_page": remaining.first(),
+                        "total_pages": total,
+                        "bytes": text.len(),
+                    }));
+                }
+                other => content.push(other),
+            }
+        }
+        
+        result.content = content;
+        
+        if !chunked.is_empty() {
+            result.structured_content = None;
+            let mut meta = match result.meta.take() {
+                Some(Value::Object(map)) => map,
+                _ => serde_json::Map::new(),
+            };
+            meta.insert("chunked".to_string(), Value::Array(chunked));
+            result.meta = Some(Value::Object(meta));
+        }
+        
+        result
+    }
+    
+    async fn handle_resources_list(&self, _params: Option<Value>) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        let mut resources = self.state.runtime().entity_resources.list().await;
+        resources.extend(self.result_chunks.lock().unwrap_or_else(|e| e.into_inner()).list()
+            .into_iter()
+            .map(|(uri, total)| json!({
+                "uri": uri,
+                "name": (i18n::messages().result_page_name)(total),
+                "mimeType": "text/plain",
+            })));
+        
+        Ok(json!({ "resources": resources }))
+    }
+    
+    async fn handle_resources_read(&self, params: Option<Value>) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        let uri = params
+            .as_ref()
+            .and_then(|p| p.get("uri"))
+            .and_then(|uri| uri.as_str())
+            .ok_or_else(|| McpError::InvalidParams((i18n::messages().missing_parameter)("uri")))?;
+        
+        if let Some(text) = self.state.runtime().entity_resources.read(uri).await? {
+            return Ok(json!({
+                "contents": [{
+                    "uri": uri,
+                    "mimeType": "application/json",
+                    "text": text,
+                }]
+            }));
+        }
+        
+        let text = parse_page_uri(uri)
+            .and_then(|(id, page)| self.result_chunks.lock().unwrap_or_else(|e| e.into_inner()).page(id, page).map(str::to_string))
+            .ok_or_else(|| McpError::InvalidParams((i18n::messages().resource_not_found)(uri)))?;
+        
+        Ok(json!({
+            "contents": [{
+                "uri": uri,
+                "mimeType": "text/plain",
+                "text": text,
+            }]
+        }))
+    }
+    
+    async fn handle_resource_templates_list(&self) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        Ok(json!({ "resourceTemplates": self.state.runtime().entity_resources.templates() }))
+    }
+    
+    async fn handle_resources_subscribe(&self, params: Option<Value>, subscribe: bool) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        let uri = params
+            .as_ref()
+            .and_then(|p| p.get("uri"))
+            .and_then(|uri| uri.as_str())
+            .ok_or_else(|| McpError::InvalidParams((i18n::messages().missing_parameter)("uri")))?;
+        
+        if subscribe {
+            self.state.runtime().entity_resources.check_subscribable(uri)?;
+            self.subscriptions.lock().unwrap_or_else(|e| e.into_inner()).insert(uri.to_string());
+            debug!("Přihlášen odběr změn resource {}", uri);
+        } else {
+            self.subscriptions.lock().unwrap_or_else(|e| e.into_inner()).remove(uri);
+            debug!("Zrušen odběr změn resource {}", uri);
+        }
+        
+        Ok(json!({}))
+    }
+    
+    async fn handle_prompts_list(&self) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        Ok(json!({ "prompts": self.state.runtime().prompts.list() }))
+    }
+    
+    async fn handle_prompts_get(&self, params: Option<Value>) -> McpResult<Value> {
+        if !self.is_initialized() {
+            return Err(McpError::Protocol(i18n::messages().server_not_initialized.to_string()));
+        }
+        
+        let mut params = params.unwrap_or(Value::Null);
+        let name = params
+            .get("name")
+            .and_then(|name| name.as_str())
+            .ok_or_else(|| McpError::InvalidParams((i18n::messages().missing_parameter)("name")))?
+            .to_string();
+        let arguments = params.get_mut("arguments").map(Value::take);
+        
+        debug!("Sestavuji prompt {}", name);
+        self.state.runtime().prompts.get(&name, arguments)
+    }
+}
+
+/// Klíč požadavku podle JSON-RPC id (číslo i řetězec se rozlišují)
+fn request_key(id: Option<&Value>) -> Option<String> {
+    id.map(Value::to_string)
 }
 
 // Default implementace pro ListToolsParams
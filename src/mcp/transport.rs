@@ -1,10 +1,13 @@
 use async_trait::async_trait;
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, stdin, stdout};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader, stdin, stdout};
 use tracing::{debug, error, info, warn};
-use super::error::{TransportError, McpResult};
-use super::protocol::McpMessage;
+use super::error::{JsonRpcError, TransportError, McpResult};
+use super::protocol::{JsonRpcResponse, McpMessage};
 
 /// Abstraktní trait pro různé transportní vrstvy
+///
+/// Jedna instance odpovídá jednomu připojenému klientovi. `receive` musí jít bezpečně
+/// zrušit (server ho čeká v `tokio::select!` souběžně s odesíláním odpovědí).
 #[async_trait]
 pub trait Transport {
     async fn receive(&mut self) -> McpResult<McpMessage>;
@@ -12,23 +15,122 @@ pub trait Transport {
     async fn close(&mut self) -> McpResult<()>;
 }
 
+/// Připojený klient s vlastním transportem
+pub struct Connection {
+    pub id: String,
+    pub transport: Box<dyn Transport + Send>,
+}
+
+/// Zdroj spojení - STDIO má jediné spojení, HTTP nové spojení pro každou relaci
+#[async_trait]
+pub trait Listener {
+    /// Počká na další spojení, po ukončení naslouchání vrací `ConnectionClosed`
+    async fn accept(&mut self) -> McpResult<Connection>;
+    async fn close(&mut self) -> McpResult<()>;
+}
+
+/// Listener, který vydá jediné spojení (STDIO)
+pub struct SingleConnectionListener {
+    transport: Option<Box<dyn Transport + Send>>,
+}
+
+impl SingleConnectionListener {
+    pub fn new(transport: Box<dyn Transport + Send>) -> Self {
+        Self { transport: Some(transport) }
+    }
+}
+
+#[async_trait]
+impl Listener for SingleConnectionListener {
+    async fn accept(&mut self) -> McpResult<Connection> {
+        match self.transport.take() {
+            Some(transport) => Ok(Connection { id: "main".to_string(), transport }),
+            None => Err(TransportError::ConnectionClosed.into()),
+        }
+    }
+
+    async fn close(&mut self) -> McpResult<()> {
+        Ok(())
+    }
+}
+
 /// STDIO Transport - komunikace přes standard input/output
 pub struct StdioTransport {
     reader: BufReader<tokio::io::Stdin>,
     writer: tokio::io::Stdout,
+    /// Rozečtený řádek a jeho dosavadní délka (zachované při zrušení `receive`)
+    line: Vec<u8>,
+    line_len: usize,
     is_closed: bool,
+    max_message_size: usize,
 }
 
 impl StdioTransport {
-    pub fn new() -> Self {
+    pub fn new(max_message_size: usize) -> Self {
         Self {
             reader: BufReader::new(stdin()),
             writer: stdout(),
+            line: Vec::new(),
+            line_len: 0,
             is_closed: false,
+            max_message_size,
         }
     }
 }
 
+/// Výsledek čtení jednoho řádku s omezenou délkou
+enum BoundedLine {
+    Eof,
+    Line(String),
+    TooLarge(usize),
+}
+
+/// Přečte jeden řádek, ale do paměti uloží nejvýše `limit` bajtů
+///
+/// Příliš dlouhý řádek se dočte do konce a zahodí, aby další zpráva začínala
+/// na správném místě. Rozečtená data zůstávají v `buffer` a `total`, takže čtení
+/// lze zrušit a později na něj navázat.
+async fn read_bounded_line<R: AsyncBufRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut Vec<u8>,
+    total: &mut usize,
+    limit: usize,
+) -> std::io::Result<BoundedLine> {
+    loop {
+        let available = reader.fill_buf().await?;
+        if available.is_empty() {
+            break;
+        }
+        
+        let (chunk_len, found_newline) = match available.iter().position(|b| *b == b'\n') {
+            Some(pos) => (pos + 1, true),
+            None => (available.len(), false),
+        };
+        
+        *total += chunk_len;
+        if *total <= limit {
+            buffer.extend_from_slice(&available[..chunk_len]);
+        }
+        reader.consume(chunk_len);
+        
+        if found_newline {
+            break;
+        }
+    }
+    
+    let buffer = std::mem::take(buffer);
+    let total = std::mem::take(total);
+    if total == 0 {
+        Ok(BoundedLine::Eof)
+    } else if total > limit {
+        Ok(BoundedLine::TooLarge(total))
+    } else {
+        String::from_utf8(buffer)
+            .map(BoundedLine::Line)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
+    }
+}
+
 #[async_trait]
 impl Transport for StdioTransport {
     async fn receive(&mut self) -> McpResult<McpMessage> {
@@ -36,16 +138,19 @@ impl Transport for StdioTransport {
             return Err(TransportError::ConnectionClosed.into());
         }
         
-        let mut line = String::new();
-        match self.reader.read_line(&mut line).await {
-            Ok(0) => {
+        match read_bounded_line(&mut self.reader, &mut self.line, &mut self.line_len, self.max_message_size).await {
+            Ok(BoundedLine::Eof) => {
                 // EOF reached
                 info!("STDIO: EOF dosažen, ukončuji spojení");
                 self.is_closed = true;
                 Err(TransportError::ConnectionClosed.into())
             }
-            Ok(bytes_read) => {
-                debug!("STDIO: Přečteno {} bytů", bytes_read);
+            Ok(BoundedLine::TooLarge(size)) => {
+                warn!("STDIO: Zpráva ({} bytů) překračuje limit {} bytů, zahazuji ji", size, self.max_message_size);
+                Err(TransportError::MessageTooLarge { size, limit: self.max_message_size }.into())
+            }
+            Ok(BoundedLine::Line(line)) => {
+                debug!("STDIO: Přečteno {} bytů", line.len());
                 let trimmed = line.trim();
                 if trimmed.is_empty() {
                     debug!("STDIO: Prázdný řádek, zkouším další");
@@ -74,7 +179,23 @@ impl Transport for StdioTransport {
             return Err(TransportError::ConnectionClosed.into());
         }
         
-        let json = message.to_json()?;
+        let mut json = message.to_json()?;
+        if json.len() > self.max_message_size {
+            // Místo zprávy, kterou by klient mohl oříznout, pošleme chybovou odpověď
+            warn!("STDIO: Odchozí zpráva ({} bytů) překračuje limit {} bytů", json.len(), self.max_message_size);
+            match message {
+                McpMessage::Response(response) => {
+                    let error = JsonRpcResponse::error(
+                        response.id,
+                        JsonRpcError::message_too_large(json.len(), self.max_message_size),
+                    );
+                    json = McpMessage::Response(error).to_json()?;
+                }
+                _ => {
+                    return Err(TransportError::MessageTooLarge { size: json.len(), limit: self.max_message_size }.into());
+                }
+            }
+        }
         debug!("STDIO: Odesílám zprávu: {}", json);
         
         match self.writer.write_all(format!("{}\n", json).as_bytes()).await {
@@ -135,17 +256,21 @@ impl Transport for WebSocketTransport {
     }
 }
 
-/// Transport Factory pro vytváření správného typu transportu
-pub fn create_transport(transport_type: crate::config::TransportType, port: Option<u16>) -> Box<dyn Transport + Send> {
-    match transport_type {
+/// Vytvoří zdroj spojení podle konfigurovaného transportu
+pub async fn create_listener(config: &crate::config::ServerConfig) -> McpResult<Box<dyn Listener + Send>> {
+    match config.transport {
         crate::config::TransportType::Stdio => {
-            info!("Inicializuji STDIO transport");
-            Box::new(StdioTransport::new())
+            info!("Inicializuji STDIO transport (limit zprávy {} bytů)", config.max_message_size);
+            Ok(Box::new(SingleConnectionListener::new(Box::new(StdioTransport::new(config.max_message_size)))))
         }
         crate::config::TransportType::Websocket => {
-            let port = port.unwrap_or(8080);
+            let port = config.websocket_port.unwrap_or(8080);
             info!("Inicializuji WebSocket transport na portu {}", port);
-            Box::new(WebSocketTransport::new(port))
+            Ok(Box::new(SingleConnectionListener::new(Box::new(WebSocketTransport::new(port)))))
+        }
+        crate::config::TransportType::Http => {
+            info!("Inicializuji HTTP transport (limit zprávy {} bytů)", config.max_message_size);
+            Ok(Box::new(super::http_transport::HttpListener::bind(config).await?))
         }
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,187 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+/// Maximální počet kroků při hledání dalšího spuštění (pojistka proti nesplnitelným výrazům)
+const MAX_SEARCH_STEPS: usize = 100_000;
+
+/// Cron výraz s pěti poli: minuta, hodina, den v měsíci, měsíc, den v týdnu
+///
+/// Podporuje `*`, čísla, rozsahy `a-b`, kroky `*/n` a `a-b/n`, seznamy oddělené čárkou
+/// a zkratky `@hourly`, `@daily`, `@weekly`, `@monthly`. Den v týdnu 0 i 7 je neděle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Den v měsíci i den v týdnu jsou omezené - stačí shoda jednoho z nich (jako v cron)
+    day_or: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let expression = match expression.trim() {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            other => other,
+        };
+
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "cron výraz musí mít 5 polí (minuta hodina den měsíc den_v_týdnu), nalezeno {}",
+                fields.len()
+            ));
+        }
+
+        let mut days_of_week = parse_field(fields[4], 0, 7, "den v týdnu")?;
+        // Neděle může být zapsána jako 0 i 7
+        if days_of_week & (1 << 7) != 0 {
+            days_of_week = (days_of_week & !(1 << 7)) | 1;
+        }
+
+        Ok(Self {
+            minutes: parse_field(fields[0], 0, 59, "minuta")?,
+            hours: parse_field(fields[1], 0, 23, "hodina")?,
+            days_of_month: parse_field(fields[2], 1, 31, "den v měsíci")?,
+            months: parse_field(fields[3], 1, 12, "měsíc")?,
+            days_of_week,
+            day_or: fields[2] != "*" && fields[4] != "*",
+        })
+    }
+
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let dom = self.days_of_month & (1 << date.day()) != 0;
+        let dow = self.days_of_week & (1 << date.weekday().num_days_from_sunday()) != 0;
+        if self.day_or {
+            dom || dow
+        } else {
+            dom && dow
+        }
+    }
+
+    /// Najde první čas spuštění po zadaném okamžiku (s přesností na minuty)
+    pub fn next_after(&self, after: NaiveDateTime) -> Option<NaiveDateTime> {
+        let mut time = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+
+        for _ in 0..MAX_SEARCH_STEPS {
+            let date = time.date();
+            if self.months & (1 << date.month()) == 0 {
+                let (year, month) = if date.month() == 12 {
+                    (date.year() + 1, 1)
+                } else {
+                    (date.year(), date.month() + 1)
+                };
+                time = NaiveDate::from_ymd_opt(year, month, 1)?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if !self.matches_day(date) {
+                time = date.succ_opt()?.and_hms_opt(0, 0, 0)?;
+                continue;
+            }
+            if self.hours & (1 << time.hour()) == 0 {
+                time = date.and_hms_opt(time.hour(), 0, 0)? + Duration::hours(1);
+                continue;
+            }
+            if self.minutes & (1 << time.minute()) == 0 {
+                time += Duration::minutes(1);
+                continue;
+            }
+            return Some(time);
+        }
+
+        None
+    }
+}
+
+/// Parsuje jedno pole cron výrazu na bitovou masku povolených hodnot
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let mut mask = 0u64;
+
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step.parse()
+                    .map_err(|_| format!("neplatný krok '{}' v poli {}", step, name))?;
+                if step == 0 {
+                    return Err(format!("krok v poli {} musí být větší než 0", name));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start, min, max, name)?, parse_value(end, min, max, name)?)
+        } else {
+            let value = parse_value(range, min, max, name)?;
+            // "5/15" znamená od 5 do konce rozsahu po 15
+            (value, if step > 1 { max } else { value })
+        };
+
+        if start > end {
+            return Err(format!("neplatný rozsah '{}' v poli {}", range, name));
+        }
+
+        let mut value = start;
+        while value <= end {
+            mask |= 1 << value;
+            value += step;
+        }
+    }
+
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, max: u32, name: &str) -> Result<u32, String> {
+    let parsed: u32 = value.parse()
+        .map_err(|_| format!("neplatná hodnota '{}' v poli {}", value, name))?;
+    if parsed < min || parsed > max {
+        return Err(format!("hodnota {} v poli {} musí být v rozsahu {}-{}", parsed, name, min, max));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("0 10-5 * * *").is_err());
+        assert!(CronSchedule::parse("0 7 * * 1-5").is_ok());
+    }
+
+    #[test]
+    fn test_next_after() {
+        // Každé pondělí v 7:00 - 2024-06-12 je středa
+        let weekly = CronSchedule::parse("0 7 * * 1").unwrap();
+        assert_eq!(weekly.next_after(at("2024-06-12 10:00")), Some(at("2024-06-17 07:00")));
+
+        let every_quarter = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(every_quarter.next_after(at("2024-06-12 10:07")), Some(at("2024-06-12 10:15")));
+        assert_eq!(every_quarter.next_after(at("2024-06-12 10:45")), Some(at("2024-06-12 11:00")));
+
+        let monthly = CronSchedule::parse("@monthly").unwrap();
+        assert_eq!(monthly.next_after(at("2024-12-15 00:00")), Some(at("2025-01-01 00:00")));
+
+        // Neděle jako 7
+        let sunday = CronSchedule::parse("30 18 * * 7").unwrap();
+        assert_eq!(sunday.next_after(at("2024-06-12 10:00")), Some(at("2024-06-16 18:30")));
+
+        // 31. den jen v měsících, které ho mají
+        let last = CronSchedule::parse("0 0 31 * *").unwrap();
+        assert_eq!(last.next_after(at("2024-06-01 00:00")), Some(at("2024-07-31 00:00")));
+    }
+}
@@ -0,0 +1,313 @@
+use serde_json::Value;
+
+use crate::utils::formatting::csv_cell;
+use crate::utils::text_utils::fold_diacritics;
+
+/// Převede JSON sestavy projektu do Markdownu
+pub fn render_markdown(report: &Value) -> String {
+    let mut out = String::new();
+    let project = &report["project"];
+
+    out.push_str(&format!("# Sestava projektu {}\n\n", text(&project["name"])));
+    out.push_str(&format!("- ID projektu: {}\n", text(&project["id"])));
+    out.push_str(&format!("- Vygenerováno: {}\n", text(&report["report_generated_at"])));
+    out.push_str(&format!(
+        "- Období: {} – {}\n",
+        or_dash(&report["period"]["from"]),
+        or_dash(&report["period"]["to"])
+    ));
+
+    let issues = &report["issues"];
+    if !issues.is_null() {
+        out.push_str("\n## Úkoly\n\n");
+        if let Some(error) = issues["error"].as_str() {
+            out.push_str(&format!("Chyba: {}\n", error));
+        } else {
+            push_table(&mut out, ("Ukazatel", "Hodnota"), object_rows(&issues["summary"]));
+            if let Some(rule) = issues["overdue_rule"].as_str() {
+                out.push_str(&format!("\n_{}_\n", rule));
+            }
+            out.push_str("\n### Podle stavu\n\n");
+            push_table(&mut out, ("Stav", "Počet"), object_rows(&issues["by_status"]));
+            out.push_str("\n### Podle priority\n\n");
+            push_table(&mut out, ("Priorita", "Počet"), object_rows(&issues["by_priority"]));
+        }
+    }
+
+    let time_entries = &report["time_entries"];
+    if !time_entries.is_null() {
+        out.push_str("\n## Časové záznamy\n\n");
+        if let Some(error) = time_entries["error"].as_str() {
+            out.push_str(&format!("Chyba: {}\n", error));
+        } else {
+            push_table(&mut out, ("Ukazatel", "Hodnota"), object_rows(&time_entries["summary"]));
+            out.push_str("\n### Podle uživatele (hodiny)\n\n");
+            push_table(&mut out, ("Uživatel", "Hodiny"), object_rows(&time_entries["by_user"]));
+            out.push_str("\n### Podle aktivity (hodiny)\n\n");
+            push_table(&mut out, ("Aktivita", "Hodiny"), object_rows(&time_entries["by_activity"]));
+        }
+    }
+
+    let users = &report["users"];
+    if !users.is_null() {
+        out.push_str("\n## Členové projektu\n\n");
+        if let Some(error) = users["error"].as_str() {
+            out.push_str(&format!("Chyba: {}\n", error));
+        } else {
+            out.push_str("| Člen | Role | Přiřazené | Otevřené | Hodiny |\n|---|---|---|---|---|\n");
+            for member in users["members"].as_array().into_iter().flatten() {
+                out.push_str(&format!(
+                    "| {} | {} | {} | {} | {} |\n",
+                    escape_cell(&text(&member["name"])),
+                    escape_cell(&roles(member)),
+                    or_dash(&member["assigned_issues"]),
+                    or_dash(&member["open_issues"]),
+                    or_dash(&member["logged_hours"])
+                ));
+            }
+        }
+    }
+
+    out
+}
+
+/// Převede JSON sestavy do CSV (sekce, klíč, hodnota)
+pub fn render_csv(report: &Value) -> String {
+    let mut out = String::from("sekce;klic;hodnota\n");
+    let mut push = |section: &str, key: &str, value: String| {
+        out.push_str(&format!("{};{};{}\n", csv_cell(section), csv_cell(key), csv_cell(&value)));
+    };
+
+    push("projekt", "id", text(&report["project"]["id"]));
+    push("projekt", "nazev", text(&report["project"]["name"]));
+    push("projekt", "vygenerovano", text(&report["report_generated_at"]));
+    push("projekt", "obdobi_od", or_dash(&report["period"]["from"]));
+    push("projekt", "obdobi_do", or_dash(&report["period"]["to"]));
+
+    for (section, path) in [
+        ("ukoly", &report["issues"]["summary"]),
+        ("ukoly_podle_stavu", &report["issues"]["by_status"]),
+        ("ukoly_podle_priority", &report["issues"]["by_priority"]),
+        ("cas", &report["time_entries"]["summary"]),
+        ("cas_podle_uzivatele", &report["time_entries"]["by_user"]),
+        ("cas_podle_aktivity", &report["time_entries"]["by_activity"]),
+    ] {
+        for (key, value) in object_rows(path) {
+            push(section, &key, value);
+        }
+    }
+
+    for member in report["users"]["members"].as_array().into_iter().flatten() {
+        let name = text(&member["name"]);
+        push("clen", &name, roles(member));
+        if !member["logged_hours"].is_null() {
+            push("clen_hodiny", &name, text(&member["logged_hours"]));
+        }
+    }
+
+    out
+}
+
+/// Vytvoří jednoduchý PDF dokument z textu (písmo Helvetica, A4)
+///
+/// Standardní písma PDF nepodporují českou diakritiku, text se proto převádí na ASCII.
+pub fn render_pdf(content: &str) -> Vec<u8> {
+    const LINES_PER_PAGE: usize = 60;
+    const MAX_LINE_CHARS: usize = 100;
+
+    let lines: Vec<String> = fold_diacritics(content)
+        .lines()
+        .flat_map(|line| wrap_line(line, MAX_LINE_CHARS))
+        .map(|line| line.chars().filter(|c| c.is_ascii() && !c.is_ascii_control()).collect())
+        .collect();
+    let pages: Vec<&[String]> = if lines.is_empty() {
+        vec![&lines[..]]
+    } else {
+        lines.chunks(LINES_PER_PAGE).collect()
+    };
+
+    // Objekty: 1 katalog, 2 stránky, 3 písmo, pak pro každou stránku objekt stránky a obsahu
+    let mut objects: Vec<String> = Vec::new();
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 4 + i * 2).collect();
+
+    objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+    objects.push(format!(
+        "<< /Type /Pages /Kids [{}] /Count {} >>",
+        page_ids.iter().map(|id| format!("{} 0 R", id)).collect::<Vec<_>>().join(" "),
+        pages.len()
+    ));
+    objects.push("<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_string());
+
+    for (index, page_lines) in pages.iter().enumerate() {
+        let mut stream = String::from("BT /F1 10 Tf 12 TL 50 800 Td\n");
+        for line in page_lines.iter() {
+            stream.push_str(&format!("({}) Tj T*\n", escape_pdf(line)));
+        }
+        stream.push_str("ET");
+
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 595 842] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            page_ids[index] + 1
+        ));
+        objects.push(format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream));
+    }
+
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+    }
+
+    let xref_offset = pdf.len();
+    pdf.push_str(&format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1));
+    for offset in offsets {
+        pdf.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    pdf.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+
+    pdf.into_bytes()
+}
+
+fn text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    }
+}
+
+fn or_dash(value: &Value) -> String {
+    if value.is_null() {
+        "-".to_string()
+    } else {
+        text(value)
+    }
+}
+
+fn roles(member: &Value) -> String {
+    member["roles"].as_array()
+        .map(|roles| roles.iter().map(text).collect::<Vec<_>>().join(", "))
+        .unwrap_or_default()
+}
+
+fn object_rows(value: &Value) -> Vec<(String, String)> {
+    value.as_object()
+        .map(|map| map.iter().map(|(key, value)| (key.clone(), text(value))).collect())
+        .unwrap_or_default()
+}
+
+fn push_table(out: &mut String, header: (&str, &str), rows: Vec<(String, String)>) {
+    out.push_str(&format!("| {} | {} |\n|---|---|\n", header.0, header.1));
+    for (key, value) in rows {
+        out.push_str(&format!("| {} | {} |\n", escape_cell(&key), escape_cell(&value)));
+    }
+}
+
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+fn escape_pdf(line: &str) -> String {
+    line.replace('\\', "\\\\").replace('(', "\\(").replace(')', "\\)")
+}
+
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        return vec![String::new()];
+    }
+    chars.chunks(width).map(|chunk| chunk.iter().collect()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pdf_text(content: &str) -> String {
+        String::from_utf8(render_pdf(content)).expect("PDF obsahuje pouze ASCII")
+    }
+
+    #[test]
+    fn test_render_pdf_structure() {
+        let pdf = pdf_text("Sestava projektu\nDruhý řádek");
+        assert!(pdf.starts_with("%PDF-1.4\n"));
+        assert!(pdf.ends_with("%EOF\n"));
+        assert!(pdf.contains("/Type /Pages /Kids [4 0 R] /Count 1"));
+        assert!(pdf.contains("(Sestava projektu) Tj T*"));
+        // Diakritika se převádí na ASCII
+        assert!(pdf.contains("(Druhy radek) Tj T*"));
+    }
+
+    #[test]
+    fn test_render_pdf_xref_offsets() {
+        let pdf = pdf_text("Obsah");
+        let startxref: usize = pdf.rsplit("startxref\n").next().unwrap()
+            .lines().next().unwrap().parse().unwrap();
+        assert!(pdf[startxref..].starts_with("xref\n0 6\n"));
+
+        // Každý záznam v xref musí ukazovat na začátek odpovídajícího objektu
+        let entries: Vec<&str> = pdf[startxref..].lines().skip(3).take(5).collect();
+        for (index, entry) in entries.iter().enumerate() {
+            let offset: usize = entry[..10].parse().unwrap();
+            assert!(pdf[offset..].starts_with(&format!("{} 0 obj\n", index + 1)));
+        }
+        assert!(pdf.contains("trailer\n<< /Size 6 /Root 1 0 R >>"));
+    }
+
+    #[test]
+    fn test_render_pdf_escapes_and_filters() {
+        let pdf = pdf_text("a (b) c\\d\tkonec ✓");
+        assert!(pdf.contains("(a \\(b\\) c\\\\dkonec ) Tj T*"));
+    }
+
+    #[test]
+    fn test_render_pdf_pagination_and_wrapping() {
+        let content = (1..=61).map(|i| format!("radek {}", i)).collect::<Vec<_>>().join("\n");
+        let pdf = pdf_text(&content);
+        assert!(pdf.contains("/Kids [4 0 R 6 0 R] /Count 2"));
+        assert!(pdf.contains("(radek 61) Tj T*"));
+
+        let long = "x".repeat(150);
+        let pdf = pdf_text(&long);
+        assert!(pdf.contains(&format!("({}) Tj T*", "x".repeat(100))));
+        assert!(pdf.contains(&format!("({}) Tj T*", "x".repeat(50))));
+    }
+
+    #[test]
+    fn test_render_pdf_empty_content() {
+        let pdf = pdf_text("");
+        assert!(pdf.contains("/Kids [4 0 R] /Count 1"));
+        assert!(pdf.contains("BT /F1 10 Tf 12 TL 50 800 Td\nET"));
+    }
+
+    #[test]
+    fn test_render_markdown_and_csv() {
+        let report = json!({
+            "project": {"id": 7, "name": "Web"},
+            "report_generated_at": "2024-06-17T07:00:00Z",
+            "period": {"from": "2024-06-01", "to": null},
+            "issues": {"summary": {"total": 3}, "by_status": {"Nový": 2}, "by_priority": {}},
+            "time_entries": {"error": "Přístup odepřen"},
+            "users": {"members": [{"name": "Jan | Novák", "roles": ["Vývojář"], "logged_hours": 4.5}]}
+        });
+
+        let markdown = render_markdown(&report);
+        assert!(markdown.starts_with("# Sestava projektu Web\n"));
+        assert!(markdown.contains("- Období: 2024-06-01 – -\n"));
+        assert!(markdown.contains("| total | 3 |"));
+        assert!(markdown.contains("## Časové záznamy\n\nChyba: Přístup odepřen\n"));
+        assert!(markdown.contains("| Jan \\| Novák | Vývojář | - | - | 4.5 |"));
+
+        let csv = render_csv(&report);
+        assert!(csv.starts_with("sekce;klic;hodnota\n"));
+        assert!(csv.contains("projekt;nazev;Web\n"));
+        assert!(csv.contains("ukoly_podle_stavu;Nový;2\n"));
+        assert!(csv.contains("clen_hodiny;Jan | Novák;4.5\n"));
+    }
+}
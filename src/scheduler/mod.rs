@@ -0,0 +1,216 @@
+pub mod cron;
+pub mod export;
+
+use chrono::{Duration, NaiveDateTime, Utc};
+use serde_json::json;
+use std::path::{Path, PathBuf};
+use tracing::{error, info, warn};
+
+use crate::api::EasyProjectClient;
+use crate::config::{AppConfig, ReportExportFormat, ScheduledReportConfig};
+use crate::tools::report_tools::{GenerateProjectReportArgs, GenerateProjectReportTool};
+use crate::utils::date_utils::{parse_timezone, DateZone};
+
+use self::cron::CronSchedule;
+
+/// Formát časové značky v názvu souboru sestavy
+const FILE_TIMESTAMP_FORMAT: &str = "%Y-%m-%d_%H%M";
+
+/// Název souboru vygenerované sestavy: `project-{id}_{YYYY-MM-DD_HHMM}.{přípona}`
+pub fn report_file_name(project_id: i32, generated_at: NaiveDateTime, format: ReportExportFormat) -> String {
+    format!(
+        "project-{}_{}.{}",
+        project_id,
+        generated_at.format(FILE_TIMESTAMP_FORMAT),
+        format.extension()
+    )
+}
+
+/// Rozloží název souboru sestavy na ID projektu, čas vygenerování a příponu
+pub fn parse_report_file_name(file_name: &str) -> Option<(i32, NaiveDateTime, &str)> {
+    let (stem, extension) = file_name.rsplit_once('.')?;
+    let (project_id, timestamp) = stem.strip_prefix("project-")?.split_once('_')?;
+    let project_id = project_id.parse().ok()?;
+    let generated_at = NaiveDateTime::parse_from_str(timestamp, FILE_TIMESTAMP_FORMAT).ok()?;
+    Some((project_id, generated_at, extension))
+}
+
+struct ScheduledJob {
+    config: ScheduledReportConfig,
+    schedule: CronSchedule,
+}
+
+/// Plánovač, který podle cron výrazů generuje sestavy projektů do exportního adresáře
+pub struct ReportScheduler {
+    report_tool: GenerateProjectReportTool,
+    jobs: Vec<ScheduledJob>,
+    export_dir: PathBuf,
+    zone: DateZone,
+}
+
+impl ReportScheduler {
+    pub fn new(api_client: EasyProjectClient, config: &AppConfig) -> Self {
+        Self {
+            report_tool: GenerateProjectReportTool::new(api_client, config.clone()),
+            jobs: parse_jobs(&config.scheduler.reports),
+            export_dir: PathBuf::from(&config.scheduler.export_dir),
+            zone: parse_timezone(&config.tools.reports.timezone).unwrap_or(DateZone::Local),
+        }
+    }
+
+    /// Spustí plánovač na pozadí
+    pub fn spawn(self) -> Option<tokio::task::JoinHandle<()>> {
+        if self.jobs.is_empty() {
+            info!("Plánovač sestav nemá žádné úlohy, nespouští se");
+            return None;
+        }
+
+        info!("Spouštím plánovač sestav ({} úloh, adresář {})", self.jobs.len(), self.export_dir.display());
+        Some(tokio::spawn(async move { self.run().await }))
+    }
+
+    async fn run(self) {
+        loop {
+            let now = self.zone.datetime_at(Utc::now());
+            let Some((next_run, due)) = next_due(&self.jobs, now) else {
+                warn!("Žádná plánovaná sestava už nemá další termín spuštění, plánovač končí");
+                return;
+            };
+
+            let wait = (next_run - now).to_std().unwrap_or_default();
+            tokio::time::sleep(wait).await;
+
+            for job in due {
+                if let Err(e) = self.run_job(&job.config, next_run).await {
+                    error!("Plánovaná sestava projektu {} selhala: {}", job.config.project_id, e);
+                }
+            }
+        }
+    }
+
+    async fn run_job(&self, job: &ScheduledReportConfig, run_at: NaiveDateTime) -> Result<(), String> {
+        let from_date = job.period_days
+            .map(|days| (run_at.date() - Duration::days(days as i64)).format("%Y-%m-%d").to_string());
+        let args: GenerateProjectReportArgs = serde_json::from_value(json!({
+            "project_id": job.project_id,
+            "from_date": from_date,
+            "include_details": job.include_details,
+        })).map_err(|e| format!("Neplatné parametry sestavy: {}", e))?;
+
+        let report = self.report_tool.build_report(&args).await
+            .map_err(|e| format!("Chyba při generování sestavy: {}", e))?;
+
+        tokio::fs::create_dir_all(&self.export_dir).await
+            .map_err(|e| format!("Nelze vytvořit adresář {}: {}", self.export_dir.display(), e))?;
+
+        let markdown = export::render_markdown(&report);
+        for format in &job.formats {
+            let content = match format {
+                ReportExportFormat::Md => markdown.clone().into_bytes(),
+                ReportExportFormat::Csv => export::render_csv(&report).into_bytes(),
+                ReportExportFormat::Pdf => export::render_pdf(&markdown),
+            };
+            let path = self.export_dir.join(report_file_name(job.project_id, run_at, *format));
+            write_file(&path, &content).await?;
+            info!("Uložena plánovaná sestava projektu {}: {}", job.project_id, path.display());
+        }
+
+        Ok(())
+    }
+}
+
+/// Sestaví úlohy z konfigurace, úlohy s neplatným cron výrazem přeskočí
+fn parse_jobs(reports: &[ScheduledReportConfig]) -> Vec<ScheduledJob> {
+    reports.iter()
+        .filter_map(|report| match CronSchedule::parse(&report.cron) {
+            Ok(schedule) => Some(ScheduledJob { config: report.clone(), schedule }),
+            Err(e) => {
+                warn!("Přeskakuji plánovanou sestavu projektu {}: {}", report.project_id, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Nejbližší termín spuštění po `now` a všechny úlohy naplánované na tuto minutu
+fn next_due(jobs: &[ScheduledJob], now: NaiveDateTime) -> Option<(NaiveDateTime, Vec<&ScheduledJob>)> {
+    let next_run = jobs.iter().filter_map(|job| job.schedule.next_after(now)).min()?;
+    let due = jobs.iter()
+        .filter(|job| job.schedule.next_after(now) == Some(next_run))
+        .collect();
+    Some((next_run, due))
+}
+
+async fn write_file(path: &Path, content: &[u8]) -> Result<(), String> {
+    tokio::fs::write(path, content).await
+        .map_err(|e| format!("Nelze zapsat soubor {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn report(project_id: i32, cron: &str) -> ScheduledReportConfig {
+        ScheduledReportConfig {
+            project_id,
+            cron: cron.to_string(),
+            formats: vec![ReportExportFormat::Md],
+            period_days: None,
+            include_details: false,
+        }
+    }
+
+    #[test]
+    fn test_report_file_name_roundtrip() {
+        let generated_at = at("2024-06-17 07:05");
+        let name = report_file_name(42, generated_at, ReportExportFormat::Pdf);
+        assert_eq!(name, "project-42_2024-06-17_0705.pdf");
+        assert_eq!(parse_report_file_name(&name), Some((42, generated_at, "pdf")));
+    }
+
+    #[test]
+    fn test_parse_report_file_name_invalid() {
+        assert_eq!(parse_report_file_name("project-42_2024-06-17_0705"), None);
+        assert_eq!(parse_report_file_name("report-42_2024-06-17_0705.md"), None);
+        assert_eq!(parse_report_file_name("project-abc_2024-06-17_0705.md"), None);
+        assert_eq!(parse_report_file_name("project-42_2024-06-17.md"), None);
+    }
+
+    #[test]
+    fn test_parse_jobs_skips_invalid_cron() {
+        let jobs = parse_jobs(&[report(1, "0 7 * * 1"), report(2, "nesmysl"), report(3, "61 * * * *")]);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].config.project_id, 1);
+    }
+
+    #[test]
+    fn test_next_due_picks_earliest_and_groups_jobs() {
+        // 2024-06-12 je středa
+        let jobs = parse_jobs(&[
+            report(1, "0 7 * * 1"),
+            report(2, "30 10 * * *"),
+            report(3, "30 10 12 6 *"),
+        ]);
+
+        let (next_run, due) = next_due(&jobs, at("2024-06-12 10:00")).unwrap();
+        assert_eq!(next_run, at("2024-06-12 10:30"));
+        let mut projects: Vec<i32> = due.iter().map(|job| job.config.project_id).collect();
+        projects.sort();
+        assert_eq!(projects, vec![2, 3]);
+
+        // Po spuštění v 10:30 je další termín až denní úloha následující den
+        let (next_run, due) = next_due(&jobs, at("2024-06-12 10:30")).unwrap();
+        assert_eq!(next_run, at("2024-06-13 10:30"));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].config.project_id, 2);
+    }
+
+    #[test]
+    fn test_next_due_without_jobs() {
+        assert!(next_due(&[], at("2024-06-12 10:00")).is_none());
+    }
+}
@@ -0,0 +1,320 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::PathBuf;
+
+use crate::api::models::Issue;
+
+/// Druh snímku v úložišti pro baseline plánu
+pub const BASELINE_SNAPSHOT_KIND: &str = "baselines";
+
+/// Úložiště snímků projektů na disku: `{dir}/{druh}/project-{id}/{název}.json`
+#[derive(Debug, Clone)]
+pub struct SnapshotStore {
+    dir: PathBuf,
+}
+
+impl SnapshotStore {
+    pub fn new(config: &crate::config::AppConfig) -> Self {
+        Self {
+            dir: PathBuf::from(&config.snapshots.dir),
+        }
+    }
+
+    fn project_dir(&self, kind: &str, project_id: i32) -> PathBuf {
+        self.dir.join(kind).join(format!("project-{}", project_id))
+    }
+
+    fn path(&self, kind: &str, project_id: i32, name: &str) -> PathBuf {
+        self.project_dir(kind, project_id).join(format!("{}.json", file_stem(name)))
+    }
+
+    pub async fn exists(&self, kind: &str, project_id: i32, name: &str) -> bool {
+        tokio::fs::try_exists(self.path(kind, project_id, name)).await.unwrap_or(false)
+    }
+
+    pub async fn save<T: Serialize>(&self, kind: &str, project_id: i32, name: &str, snapshot: &T) -> io::Result<PathBuf> {
+        tokio::fs::create_dir_all(self.project_dir(kind, project_id)).await?;
+        let path = self.path(kind, project_id, name);
+        let content = serde_json::to_vec_pretty(snapshot)?;
+        tokio::fs::write(&path, content).await?;
+        Ok(path)
+    }
+
+    /// Načte snímek, `None` pokud neexistuje
+    pub async fn load<T: DeserializeOwned>(&self, kind: &str, project_id: i32, name: &str) -> io::Result<Option<T>> {
+        match tokio::fs::read(self.path(kind, project_id, name)).await {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Názvy souborů (bez přípony) uložených snímků projektu, seřazené
+    pub async fn list(&self, kind: &str, project_id: i32) -> io::Result<Vec<String>> {
+        let mut entries = match tokio::fs::read_dir(self.project_dir(kind, project_id)).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut names = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            if let Some(stem) = file_name.strip_suffix(".json") {
+                names.push(stem.to_string());
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
+
+/// Název snímku převedený na bezpečný název souboru (bez oddělovačů cest)
+fn file_stem(name: &str) -> String {
+    crate::utils::text_utils::fold_diacritics(name.trim())
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// Uložený plán projektu (termíny a odhady úkolů v okamžiku vytvoření)
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    pub name: String,
+    pub project_id: i32,
+    pub project_name: String,
+    pub created_at: DateTime<Utc>,
+    pub issues: Vec<BaselineIssue>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BaselineIssue {
+    pub id: i32,
+    pub subject: String,
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub due_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub estimated_hours: Option<f64>,
+    #[serde(default)]
+    pub done_ratio: Option<i32>,
+}
+
+impl BaselineIssue {
+    pub fn from_issue(issue: &Issue) -> Self {
+        Self {
+            id: issue.id,
+            subject: issue.subject.clone(),
+            start_date: issue.start_date,
+            due_date: issue.due_date,
+            estimated_hours: issue.estimated_hours,
+            done_ratio: issue.done_ratio,
+        }
+    }
+}
+
+impl Baseline {
+    /// Odchylky současných úkolů od baseline - termíny, odhady, přidané a chybějící úkoly
+    ///
+    /// Ve výpisu změněných úkolů je nejvýše `max_details` (max. 500) úkolů s největším skluzem.
+    pub fn variance(&self, current: &HashMap<i32, Issue>, max_details: usize) -> Value {
+        let days = |from: Option<NaiveDate>, to: Option<NaiveDate>| match (from, to) {
+            (Some(from), Some(to)) => Some((to - from).num_days()),
+            _ => None,
+        };
+
+        let mut compared = 0;
+        let mut slipped = 0;
+        let mut ahead = 0;
+        let mut slip_sum = 0i64;
+        let mut baseline_estimate = 0.0;
+        let mut current_estimate = 0.0;
+        let mut spent = 0.0;
+        let mut missing = Vec::new();
+        let mut details = Vec::new();
+
+        for planned in &self.issues {
+            let Some(issue) = current.get(&planned.id) else {
+                missing.push(json!({ "id": planned.id, "subject": planned.subject }));
+                continue;
+            };
+            compared += 1;
+            baseline_estimate += planned.estimated_hours.unwrap_or(0.0);
+            current_estimate += issue.estimated_hours.unwrap_or(0.0);
+            spent += issue.spent_hours.unwrap_or(0.0);
+
+            let due_slip = days(planned.due_date, issue.due_date);
+            match due_slip {
+                Some(slip) if slip > 0 => slipped += 1,
+                Some(slip) if slip < 0 => ahead += 1,
+                _ => {}
+            }
+            slip_sum += due_slip.unwrap_or(0);
+
+            let estimate_change = issue.estimated_hours.unwrap_or(0.0) - planned.estimated_hours.unwrap_or(0.0);
+            if due_slip.unwrap_or(0) != 0 || days(planned.start_date, issue.start_date).unwrap_or(0) != 0 || estimate_change.abs() > f64::EPSILON {
+                details.push(json!({
+                    "id": issue.id,
+                    "subject": issue.subject,
+                    "start_slip_days": days(planned.start_date, issue.start_date),
+                    "due_slip_days": due_slip,
+                    "baseline_due_date": planned.due_date,
+                    "current_due_date": issue.due_date,
+                    "baseline_estimate": planned.estimated_hours,
+                    "current_estimate": issue.estimated_hours,
+                    "spent_hours": issue.spent_hours,
+                    "done_ratio": issue.done_ratio
+                }));
+            }
+        }
+
+        let baseline_ids: HashSet<i32> = self.issues.iter().map(|issue| issue.id).collect();
+        let mut added: Vec<&Issue> = current.values().filter(|issue| !baseline_ids.contains(&issue.id)).collect();
+        added.sort_by_key(|issue| issue.id);
+
+        details.sort_by_key(|detail| std::cmp::Reverse(detail["due_slip_days"].as_i64().unwrap_or(0)));
+        details.truncate(max_details.min(500));
+
+        let baseline_finish = self.issues.iter().filter_map(|issue| issue.due_date).max();
+        let current_finish = current.values().filter_map(|issue| issue.due_date).max();
+        let effort_variance = current_estimate - baseline_estimate;
+
+        json!({
+            "baseline": {
+                "name": self.name,
+                "created_at": self.created_at,
+                "issues": self.issues.len()
+            },
+            "schedule": {
+                "baseline_finish": baseline_finish,
+                "current_finish": current_finish,
+                "finish_slip_days": days(baseline_finish, current_finish),
+                "issues_compared": compared,
+                "issues_slipped": slipped,
+                "issues_ahead": ahead,
+                "average_due_slip_days": if compared > 0 { slip_sum as f64 / compared as f64 } else { 0.0 }
+            },
+            "effort": {
+                "baseline_estimate_hours": baseline_estimate,
+                "current_estimate_hours": current_estimate,
+                "estimate_variance_hours": effort_variance,
+                "estimate_variance_percent": if baseline_estimate > 0.0 { Some((effort_variance / baseline_estimate * 100.0).round()) } else { None },
+                "spent_hours": spent,
+                "added_issues_estimate_hours": added.iter().filter_map(|issue| issue.estimated_hours).sum::<f64>()
+            },
+            "added_issues": added.iter().map(|issue| json!({ "id": issue.id, "subject": issue.subject })).collect::<Vec<_>>(),
+            "missing_issues": missing,
+            "changed_issues": details
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> SnapshotStore {
+        SnapshotStore {
+            dir: std::env::temp_dir().join(format!("easyproject-mcp-snapshots-{}", uuid::Uuid::new_v4())),
+        }
+    }
+
+    fn date(value: &str) -> Option<NaiveDate> {
+        Some(NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap())
+    }
+
+    fn issue(id: i32, due_date: &str, estimated_hours: f64) -> Issue {
+        serde_json::from_value(json!({
+            "id": id,
+            "subject": format!("Úkol {}", id),
+            "project": { "id": 1, "name": "Projekt" },
+            "tracker": { "id": 1, "name": "Úkol" },
+            "status": { "id": 1, "name": "Nový" },
+            "priority": { "id": 2, "name": "Normální" },
+            "due_date": due_date,
+            "estimated_hours": estimated_hours,
+            "spent_hours": 1.0
+        }))
+        .unwrap()
+    }
+
+    fn baseline(issues: &[Issue]) -> Baseline {
+        Baseline {
+            name: "plan".to_string(),
+            project_id: 1,
+            project_name: "Projekt".to_string(),
+            created_at: Utc::now(),
+            issues: issues.iter().map(BaselineIssue::from_issue).collect(),
+        }
+    }
+
+    #[test]
+    fn test_file_stem_strips_path_separators() {
+        assert_eq!(file_stem(" Schválený plán/../2024 "), "Schvaleny_plan____2024");
+    }
+
+    #[tokio::test]
+    async fn test_save_load_round_trip() {
+        let store = temp_store();
+        let saved = baseline(&[issue(1, "2024-03-01", 8.0), issue(2, "2024-03-05", 4.0)]);
+
+        assert!(!store.exists(BASELINE_SNAPSHOT_KIND, 1, "plan").await);
+        store.save(BASELINE_SNAPSHOT_KIND, 1, "plan", &saved).await.unwrap();
+        assert!(store.exists(BASELINE_SNAPSHOT_KIND, 1, "plan").await);
+
+        let loaded: Baseline = store.load(BASELINE_SNAPSHOT_KIND, 1, "plan").await.unwrap().unwrap();
+        assert_eq!(loaded.name, saved.name);
+        assert_eq!(loaded.created_at, saved.created_at);
+        assert_eq!(loaded.issues.len(), 2);
+        assert_eq!(loaded.issues[1].due_date, date("2024-03-05"));
+        assert_eq!(store.list(BASELINE_SNAPSHOT_KIND, 1).await.unwrap(), vec!["plan"]);
+
+        let _ = std::fs::remove_dir_all(&store.dir);
+    }
+
+    #[tokio::test]
+    async fn test_missing_snapshot() {
+        let store = temp_store();
+        let loaded: Option<Baseline> = store.load(BASELINE_SNAPSHOT_KIND, 1, "plan").await.unwrap();
+        assert!(loaded.is_none());
+        assert!(store.list(BASELINE_SNAPSHOT_KIND, 1).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_variance_against_current_issues() {
+        let planned = baseline(&[
+            issue(1, "2024-03-01", 8.0),
+            issue(2, "2024-03-05", 4.0),
+            issue(3, "2024-03-10", 2.0),
+        ]);
+        let current: HashMap<i32, Issue> = [
+            issue(1, "2024-03-04", 10.0),
+            issue(2, "2024-03-05", 4.0),
+            issue(4, "2024-03-20", 6.0),
+        ]
+        .into_iter()
+        .map(|issue| (issue.id, issue))
+        .collect();
+
+        let report = planned.variance(&current, 20);
+        assert_eq!(report["schedule"]["issues_compared"], 2);
+        assert_eq!(report["schedule"]["issues_slipped"], 1);
+        assert_eq!(report["schedule"]["finish_slip_days"], 10);
+        assert_eq!(report["effort"]["estimate_variance_hours"], 2.0);
+        assert_eq!(report["effort"]["added_issues_estimate_hours"], 6.0);
+        assert_eq!(report["added_issues"][0]["id"], 4);
+        assert_eq!(report["missing_issues"][0]["id"], 3);
+
+        let changed = report["changed_issues"].as_array().unwrap();
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0]["id"], 1);
+        assert_eq!(changed[0]["due_slip_days"], 3);
+
+        let limited = planned.variance(&current, 0);
+        assert!(limited["changed_issues"].as_array().unwrap().is_empty());
+    }
+}
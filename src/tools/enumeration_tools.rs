@@ -4,6 +4,7 @@ use serde_json::{json, Value};
 use tracing::{debug, error, info};
 
 use crate::api::EasyProjectClient;
+use crate::i18n;
 use crate::mcp::protocol::{CallToolResult, ToolResult};
 use super::executor::ToolExecutor;
 
@@ -33,7 +34,7 @@ impl ToolExecutor for GetIssueEnumerationsTool {
 
     fn description(&self) -> &str {
         "Získá číselníky (status, priority, tracker) pro použití při filtrování úkolů. \
-        \n\nTool INTERNĚ skenuje všechny issues pomocí paginace a vrací pouze kompaktní seznam ID a názvů. \
+        \n\nTool načítá číselníky z API (na starších instancích interně skenuje úkoly) a vrací pouze kompaktní seznam ID a názvů. \
         Žádné velké datové množiny nejsou vraceny do LLM kontextu. \
         \n\nVyužití: Zavolejte před použitím list_issues s filtry status_id, priority_id nebo tracker_id."
     }
@@ -42,11 +43,15 @@ impl ToolExecutor for GetIssueEnumerationsTool {
         json!({
             "project_id": {
                 "type": "integer",
-                "description": "Volitelné ID projektu pro získání specifických číselníků tohoto projektu"
+                "description": "Volitelné ID projektu - vrátí jen trackery povolené v tomto projektu"
             }
         })
     }
 
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetIssueEnumerationsArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -58,31 +63,29 @@ impl ToolExecutor for GetIssueEnumerationsTool {
 
         debug!("Volání get_issue_enumerations, project_id: {:?}", args.project_id);
 
-        // Voláme metodu API klienta, která INTERNĚ provede paginaci
         match self.api_client.get_issue_enumerations(args.project_id).await {
             Ok(enumerations) => {
                 // Vytvoříme kompaktní textový výstup
-                let mut result = String::from("Číselníky pro filtrování úkolů:\n\n");
+                let messages = i18n::messages();
+                let mut result = format!("{}:\n\n", messages.issue_enumerations);
 
-                result.push_str("STAVY (status_id):\n");
+                result.push_str(&format!("{}:\n", messages.enumeration_statuses));
                 for status in &enumerations.statuses {
                     result.push_str(&format!("  {} = {}\n", status.id, status.name));
                 }
 
-                result.push_str("\nPRIORITY (priority_id):\n");
+                result.push_str(&format!("\n{}:\n", messages.enumeration_priorities));
                 for priority in &enumerations.priorities {
                     result.push_str(&format!("  {} = {}\n", priority.id, priority.name));
                 }
 
-                result.push_str("\nTYPY ÚKOLŮ (tracker_id):\n");
+                result.push_str(&format!("\n{}:\n", messages.enumeration_trackers));
                 for tracker in &enumerations.trackers {
                     result.push_str(&format!("  {} = {}\n", tracker.id, tracker.name));
                 }
 
-                result.push_str("\nPoužití:\n");
-                result.push_str("- Pro filtrování podle statusu: list_issues s parametrem status_id=<ID>\n");
-                result.push_str("- Pro filtrování podle priority: list_issues s parametrem priority_id=<ID>\n");
-                result.push_str("- Pro filtrování podle typu: list_issues s parametrem tracker_id=<ID>\n");
+                result.push('\n');
+                result.push_str(messages.enumerations_usage);
 
                 info!("Vráceny číselníky: {} statusů, {} priorit, {} trackerů",
                     enumerations.statuses.len(),
@@ -96,7 +99,89 @@ impl ToolExecutor for GetIssueEnumerationsTool {
             Err(e) => {
                 error!("Chyba při získávání číselníků: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání číselníků: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_enumerations, e))
+                ]))
+            }
+        }
+    }
+}
+
+// === LIST CUSTOM FIELDS TOOL ===
+
+pub struct ListCustomFieldsTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListCustomFieldsTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ListCustomFieldsArgs {
+    #[serde(default)]
+    customized_type: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for ListCustomFieldsTool {
+    fn name(&self) -> &str {
+        "list_custom_fields"
+    }
+
+    fn description(&self) -> &str {
+        "Získá definice vlastních polí (ID, formát, povinnost, možné hodnoty) pro nastavení \
+        custom_field_values v create_issue a update_issue. Vyžaduje administrátorská práva; \
+        bez nich použijte get_project s include issue_custom_fields."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "customized_type": {
+                "type": "string",
+                "description": "Jen pole daného typu entity (např. issue, project, time_entry, user)"
+            }
+        })
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListCustomFieldsArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => ListCustomFieldsArgs::default(),
+        };
+
+        debug!("Získávám vlastní pole, typ: {:?}", args.customized_type);
+
+        match self.api_client.list_custom_fields().await {
+            Ok(response) => {
+                let fields: Vec<_> = response.custom_fields.into_iter()
+                    .filter(|field| match (&args.customized_type, &field.customized_type) {
+                        (Some(wanted), Some(actual)) => wanted.eq_ignore_ascii_case(actual),
+                        (Some(_), None) => false,
+                        (None, _) => true,
+                    })
+                    .collect();
+
+                info!("Vráceno {} vlastních polí", fields.len());
+
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(serde_json::to_string_pretty(&json!({
+                        "total_count": fields.len(),
+                        "custom_fields": fields,
+                    }))?)
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při získávání vlastních polí: {}", e);
+                let messages = i18n::messages();
+                let hint = if e.is_forbidden() { messages.custom_fields_admin_hint } else { "" };
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}{}", messages.error_fetching_custom_fields, e, hint))
                 ]))
             }
         }
@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use serde_json::Value;
+use regex::Regex;
+use serde_json::{Map, Value};
+use thiserror::Error;
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
 use crate::mcp::protocol::CallToolResult;
 
 /// Trait pro implementaci MCP tools
@@ -14,6 +18,166 @@ pub trait ToolExecutor: Send + Sync {
     /// JSON schema pro input parametry
     fn input_schema(&self) -> Value;
     
+    /// Povinné argumenty - uvádí se v `required` schématu a kontrolují se před spuštěním
+    fn required_fields(&self) -> &'static [&'static str] {
+        &[]
+    }
+    
+    /// Oblast API, bez které tool nemůže fungovat (pro skrytí/označení při chybějících oprávněních)
+    fn required_permission(&self) -> Option<PermissionArea> {
+        None
+    }
+    
+    /// Tool pouze čte data (nemění stav EasyProject, registruje se i v režimu tools.read_only)
+    fn is_read_only(&self) -> bool {
+        false
+    }
+    
+    /// Výsledek lze vrátit z cache výsledků
+    ///
+    /// Vypínají ho čtecí tools s vedlejším efektem (zápis souboru) nebo se stavem serveru.
+    fn is_cacheable(&self) -> bool {
+        self.is_read_only()
+    }
+    
+    /// Aliasy argumentů specifické pro tool jako dvojice (alias, kanonický název)
+    ///
+    /// Doplňují společnou tabulku aliasů v registry, typicky pro parametr `id`.
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        &[]
+    }
+    
     /// Spustí tool s danými argumenty
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>>;
-} 
\ No newline at end of file
+} 
+
+/// Argumenty neodpovídají schématu tool (vrací se klientovi jako JSON-RPC InvalidParams)
+#[derive(Debug, Error)]
+#[error("{0}")]
+pub struct InvalidArguments(pub String);
+
+/// Zkontroluje argumenty proti schématu tool před jejich deserializací
+///
+/// Kontroluje povinné argumenty, typy, `enum`, `minimum`/`maximum`, `pattern` a `format: date`
+/// včetně položek polí a vnořených objektů. Neznámé argumenty a hodnoty null se nekontrolují.
+pub fn validate_arguments(tool: &dyn ToolExecutor, arguments: Option<&Value>) -> Result<(), InvalidArguments> {
+    let messages = i18n::messages();
+    let empty = Map::new();
+    let arguments = match arguments {
+        None | Some(Value::Null) => &empty,
+        Some(Value::Object(map)) => map,
+        Some(_) => return Err(InvalidArguments((messages.invalid_arguments)(tool.name(), messages.arguments_not_object))),
+    };
+
+    let mut errors = Vec::new();
+    let missing: Vec<&str> = tool.required_fields()
+        .iter()
+        .copied()
+        .filter(|field| arguments.get(*field).is_none_or(Value::is_null))
+        .collect();
+    if !missing.is_empty() {
+        errors.push((messages.missing_arguments)(&missing.join(", ")));
+    }
+
+    if let Value::Object(properties) = tool.input_schema() {
+        validate_properties(&properties, arguments, "", &mut errors);
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(InvalidArguments((messages.invalid_arguments)(tool.name(), &errors.join("; "))))
+    }
+}
+
+fn validate_properties(properties: &Map<String, Value>, values: &Map<String, Value>, prefix: &str, errors: &mut Vec<String>) {
+    for (name, value) in values {
+        if let Some(schema) = properties.get(name) {
+            validate_value(&format!("{}{}", prefix, name), schema, value, errors);
+        }
+    }
+}
+
+fn validate_value(path: &str, schema: &Value, value: &Value, errors: &mut Vec<String>) {
+    if value.is_null() {
+        return;
+    }
+    let messages = i18n::messages();
+
+    let types: Vec<&str> = match schema.get("type") {
+        Some(Value::String(kind)) => vec![kind.as_str()],
+        Some(Value::Array(kinds)) => kinds.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    if !types.is_empty() && !types.iter().any(|kind| matches_type(kind, value)) {
+        errors.push((messages.argument_type)(path, &types.join(" | ")));
+        return;
+    }
+
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            let allowed: Vec<String> = allowed.iter().map(Value::to_string).collect();
+            errors.push((messages.argument_enum)(path, &allowed.join(", ")));
+        }
+    }
+
+    if let Some(number) = value.as_f64() {
+        if let Some(minimum) = schema.get("minimum").and_then(Value::as_f64).filter(|minimum| number < *minimum) {
+            errors.push((messages.argument_minimum)(path, minimum));
+        }
+        if let Some(maximum) = schema.get("maximum").and_then(Value::as_f64).filter(|maximum| number > *maximum) {
+            errors.push((messages.argument_maximum)(path, maximum));
+        }
+    }
+
+    match value {
+        Value::String(text) => {
+            if schema.get("format").and_then(Value::as_str) == Some("date")
+                && chrono::NaiveDate::parse_from_str(text, "%Y-%m-%d").is_err()
+            {
+                errors.push((messages.invalid_date)(path, text));
+            } else if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                // Chybný vzor ve schématu je chyba tool, ne argumentu - kontrola se přeskočí
+                if Regex::new(pattern).is_ok_and(|regex| !regex.is_match(text)) {
+                    errors.push((messages.argument_pattern)(path, pattern));
+                }
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(&format!("{}[{}]", path, index), item_schema, item, errors);
+                }
+            }
+        }
+        Value::Object(map) => {
+            if let Some(required) = schema.get("required").and_then(Value::as_array) {
+                let missing: Vec<String> = required.iter()
+                    .filter_map(Value::as_str)
+                    .filter(|field| map.get(*field).is_none_or(Value::is_null))
+                    .map(|field| format!("{}.{}", path, field))
+                    .collect();
+                if !missing.is_empty() {
+                    errors.push((messages.missing_arguments)(&missing.join(", ")));
+                }
+            }
+            if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+                validate_properties(properties, map, &format!("{}.", path), errors);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn matches_type(kind: &str, value: &Value) -> bool {
+    match kind {
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "null" => value.is_null(),
+        _ => true,
+    }
+}
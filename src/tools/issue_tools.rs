@@ -1,13 +1,74 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use serde_json::{json, Value};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
 use tracing::{debug, error, info};
 use chrono::NaiveDate;
 
-use crate::api::{EasyProjectClient, CreateIssueRequest, CreateIssue};
-use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::api::{EasyProjectClient, CreateIssueRequest, CreateIssue, Issue, IssueStatus, IssueUpload};
+use crate::api::client::{IssueListFilter, IssueQuery, FETCH_ALL};
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult, PaginationInfo};
+use crate::utils::formatting::{ExportFormat, OutputFormat, ISSUE_COLUMNS, csv_table, columns_schema, format_issue, format_issue_list, markdown_table, select_columns, truncate_text};
+use crate::utils::text_utils::{match_score, text_contains, text_equals};
 use super::executor::ToolExecutor;
 
+/// Aliasy parametru `id` pro tools pracující s jedním úkolem
+const ISSUE_ID_ALIASES: &[(&str, &str)] = &[("task_id", "id"), ("issue_id", "id"), ("issue", "id"), ("task", "id")];
+
+/// Schéma parametru custom_field_values pro create_issue a update_issue
+fn custom_field_values_schema() -> Value {
+    json!({
+        "type": "object",
+        "description": "Hodnoty vlastních polí podle ID pole, např. {\"12\": \"hodnota\", \"15\": [\"a\", \"b\"]} \
+        (ID polí viz list_custom_fields nebo get_project s include issue_custom_fields)"
+    })
+}
+
+/// Převede parametr custom_field_values na hodnoty pro API (klíčem je číselné ID pole)
+fn parse_custom_field_values(values: Option<Map<String, Value>>) -> Result<Option<BTreeMap<String, Value>>, String> {
+    let Some(values) = values else { return Ok(None) };
+
+    let mut parsed = BTreeMap::new();
+    for (key, value) in values {
+        let id: i32 = key.trim().parse()
+            .map_err(|_| (i18n::messages().custom_field_key_not_numeric)(&key))?;
+        let value = match value {
+            Value::Null | Value::String(_) => value,
+            Value::Number(_) | Value::Bool(_) => Value::String(value.to_string()),
+            Value::Array(items) => Value::Array(items.into_iter()
+                .map(|item| match item {
+                    Value::String(_) => item,
+                    other => Value::String(other.to_string()),
+                })
+                .collect()),
+            Value::Object(_) => return Err((i18n::messages().custom_field_value_object)(id)),
+        };
+        parsed.insert(id.to_string(), value);
+    }
+    Ok(Some(parsed))
+}
+
+/// Určí řešitele z číselného ID nebo ze jména, loginu či emailu (parametr `assigned_to`)
+async fn resolve_assignee(api_client: &EasyProjectClient, assigned_to_id: Option<i32>, assigned_to: Option<&str>) -> Result<Option<i32>, String> {
+    match (assigned_to_id, assigned_to) {
+        (Some(id), _) => Ok(Some(id)),
+        (None, Some(name)) => api_client.resolve_user(name).await
+            .map(|user| Some(user.id))
+            .map_err(|e| format!("{}: {}", (i18n::messages().assignee_unresolved)(name), e)),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Schéma parametru assigned_to (řešitel zadaný jménem)
+fn assigned_to_schema() -> Value {
+    json!({
+        "type": "string",
+        "description": "Řešitel zadaný jménem, loginem nebo emailem (alternativa k assigned_to_id, 'me' = aktuální uživatel)"
+    })
+}
+
 // === LIST ISSUES TOOL ===
 
 pub struct ListIssuesTool {
@@ -42,6 +103,16 @@ struct ListIssuesArgs {
     tracker_id: Option<i32>,
     #[serde(default)]
     priority_id: Option<i32>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    fetch_all: bool,
+    #[serde(default)]
+    format: OutputFormat,
+    #[serde(default)]
+    columns: Option<Vec<String>>,
+    #[serde(default)]
+    export_format: Option<ExportFormat>,
 }
 
 #[async_trait]
@@ -70,15 +141,18 @@ impl ToolExecutor for ListIssuesTool {
             },
             "limit": {
                 "type": "integer",
-                "description": "Maximální počet úkolů k vrácení (výchozí: 25, maximum: 100)",
-                "minimum": 1,
-                "maximum": 100
+                "description": "Maximální počet úkolů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)",
+                "minimum": 1
             },
             "offset": {
                 "type": "integer",
                 "description": "Počet úkolů k přeskočení pro stránkování",
                 "minimum": 0
             },
+            "fetch_all": {
+                "type": "boolean",
+                "description": "Načte všechny úkoly od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)"
+            },
             "include": {
                 "type": "array",
                 "description": "Dodatečné informace k zahrnutí",
@@ -110,10 +184,25 @@ impl ToolExecutor for ListIssuesTool {
             "priority_id": {
                 "type": "integer",
                 "description": "ID priority úkolu (např. 1=Nízká, 2=Normální, 3=Vysoká, 4=Urgentní)"
-            }
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Při zadaném project_id zahrnout i úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "format": OutputFormat::schema(),
+            "columns": columns_schema(ISSUE_COLUMNS),
+            "export_format": ExportFormat::schema()
         })
     }
 
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: ListIssuesArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -129,41 +218,66 @@ impl ToolExecutor for ListIssuesTool {
                 status_id: None,
                 tracker_id: None,
                 priority_id: None,
+                include_subprojects: None,
+                fetch_all: false,
+                format: OutputFormat::default(),
+                columns: None,
+                export_format: None,
             }
         };
 
         debug!("Získávám seznam úkolů s parametry: {:?}", args);
 
-        match self.api_client.list_issues(
-            args.project_id,
-            args.limit,
-            args.offset,
-            args.include,
-            args.search,
-            None, // set_filter
-            args.sort,
-            args.assigned_to_id,
-            args.status_id,
-            args.tracker_id,
-            args.priority_id
-        ).await {
+        let columns = match select_columns(ISSUE_COLUMNS, args.columns.as_deref()) {
+            Ok(columns) => columns,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+
+        match self.api_client.list_issues(IssueListFilter {
+            project_id: args.project_id,
+            limit: if args.fetch_all { Some(FETCH_ALL) } else { args.limit },
+            offset: args.offset,
+            include: args.include,
+            easy_query_q: args.search,
+            sort: args.sort,
+            assigned_to_id: args.assigned_to_id,
+            status_id: args.status_id,
+            tracker_id: args.tracker_id,
+            priority_id: args.priority_id,
+            include_subprojects: args.include_subprojects,
+            ..Default::default()
+        }).await {
             Ok(response) => {
-                let issues_json = serde_json::to_string_pretty(&response)?;
+                let title = (i18n::messages().found_issues_total)(
+                    response.issues.len(),
+                    response.total_count.unwrap_or(response.issues.len() as i32)
+                );
+                let output = match args.export_format {
+                    Some(ExportFormat::Csv) => csv_table(&response.issues, &columns),
+                    None => args.format.render_list(
+                        &title,
+                        &response,
+                        || format_issue_list(&response.issues),
+                        || markdown_table(&response.issues, &columns),
+                    )?,
+                };
                 info!("Úspěšně získáno {} úkolů", response.issues.len());
                 
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.issues.len(),
+                ).with_requested_limit(args.limit);
+                
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Nalezeno {} úkolů (celkem: {}):\n\n{}",
-                        response.issues.len(),
-                        response.total_count.unwrap_or(response.issues.len() as i32),
-                        issues_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response).with_pagination(pagination))
             }
             Err(e) => {
                 error!("Chyba při získávání úkolů: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání úkolů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
                 ]))
             }
         }
@@ -187,6 +301,8 @@ struct GetIssueArgs {
     id: i32,
     #[serde(default)]
     include: Option<Vec<String>>,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[async_trait]
@@ -195,6 +311,10 @@ impl ToolExecutor for GetIssueTool {
         "get_issue"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá detail konkrétního úkolu podle ID"
     }
@@ -210,36 +330,46 @@ impl ToolExecutor for GetIssueTool {
                 "description": "Dodatečné informace k zahrnutí",
                 "items": {
                     "type": "string",
-                    "enum": ["attachments", "relations", "total_estimated_time", "spent_time", "checklists"]
+                    "enum": ["attachments", "relations", "total_estimated_time", "spent_time", "checklists", "watchers"]
                 }
-            }
+            },
+            "format": OutputFormat::schema()
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetIssueArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Získávám úkol s ID: {}", args.id);
         
         match self.api_client.get_issue(args.id, args.include).await {
             Ok(response) => {
-                let issue_json = serde_json::to_string_pretty(&response.issue)?;
+                let title = (i18n::messages().issue_detail)(&response.issue.subject);
+                let output = args.format.render(&title, &response.issue, || format_issue(&response.issue))?;
                 info!("Úspěšně získán úkol: {}", response.issue.subject);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Detail úkolu '{}':\n\n{}",
-                        response.issue.subject,
-                        issue_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response.issue))
             }
             Err(e) => {
                 error!("Chyba při získávání úkolu {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání úkolu {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(args.id), e))
                 ]))
             }
         }
@@ -274,6 +404,8 @@ struct CreateIssueArgs {
     #[serde(default)]
     assigned_to_id: Option<i32>,
     #[serde(default)]
+    assigned_to: Option<String>,
+    #[serde(default)]
     parent_issue_id: Option<i32>,
     #[serde(default)]
     estimated_hours: Option<f64>,
@@ -283,6 +415,8 @@ struct CreateIssueArgs {
     due_date: Option<NaiveDate>,
     #[serde(default)]
     done_ratio: Option<i32>,
+    #[serde(default)]
+    custom_field_values: Option<Map<String, Value>>,
 }
 
 #[async_trait]
@@ -333,6 +467,7 @@ impl ToolExecutor for CreateIssueTool {
                 "type": "integer",
                 "description": "ID uživatele, kterému je úkol přiřazen"
             },
+            "assigned_to": assigned_to_schema(),
             "parent_issue_id": {
                 "type": "integer",
                 "description": "ID nadřazeného úkolu"
@@ -356,17 +491,49 @@ impl ToolExecutor for CreateIssueTool {
                 "description": "Procento dokončení (0-100)",
                 "minimum": 0,
                 "maximum": 100
-            }
+            },
+            "custom_field_values": custom_field_values_schema()
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id", "tracker_id", "status_id", "priority_id", "subject"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: CreateIssueArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro vytvoření úkolu")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Vytvářím nový úkol: {}", args.subject);
         
+        let custom_field_values = match parse_custom_field_values(args.custom_field_values) {
+            Ok(values) => values,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_issue)(&args.subject), e))
+                ]));
+            }
+        };
+        
+        if let Some(version_id) = args.fixed_version_id {
+            if let Err(e) = self.api_client.validate_version_assignable(args.project_id, version_id).await {
+                error!("Neplatná verze pro nový úkol: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_issue)(&args.subject), e))
+                ]));
+            }
+        }
+        
+        let assigned_to_id = match resolve_assignee(&self.api_client, args.assigned_to_id, args.assigned_to.as_deref()).await {
+            Ok(assigned_to_id) => assigned_to_id,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_issue)(&args.subject), e))
+                ]));
+            }
+        };
+        
         let issue_data = CreateIssueRequest {
             issue: CreateIssue {
                 project_id: args.project_id,
@@ -377,12 +544,13 @@ impl ToolExecutor for CreateIssueTool {
                 description: args.description,
                 category_id: args.category_id,
                 fixed_version_id: args.fixed_version_id,
-                assigned_to_id: args.assigned_to_id,
+                assigned_to_id,
                 parent_issue_id: args.parent_issue_id,
                 estimated_hours: args.estimated_hours,
                 start_date: args.start_date,
                 due_date: args.due_date,
                 done_ratio: args.done_ratio,
+                custom_field_values,
             }
         };
         
@@ -393,9 +561,8 @@ impl ToolExecutor for CreateIssueTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Úkol '{}' byl úspěšně vytvořen s ID {}:\n\n{}",
-                        response.issue.subject,
-                        response.issue.id,
+                        "{}:\n\n{}",
+                        (i18n::messages().issue_created)(&response.issue.subject, response.issue.id),
                         issue_json
                     ))
                 ]))
@@ -403,9 +570,278 @@ impl ToolExecutor for CreateIssueTool {
             Err(e) => {
                 error!("Chyba při vytváření úkolu '{}': {}", args.subject, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při vytváření úkolu '{}': {}", args.subject, e))
-                ]))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_issue)(&args.subject), e))
+                ]).with_validation_errors(e.validation_errors()))
+            }
+        }
+    }
+}
+
+// === CREATE ISSUES BATCH TOOL ===
+
+/// Nejvyšší počet úkolů v jednom volání create_issues_batch
+const MAX_BATCH_ISSUES: usize = 50;
+
+pub struct CreateIssuesBatchTool {
+    api_client: EasyProjectClient,
+}
+
+impl CreateIssuesBatchTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+/// Společné hodnoty pro všechny úkoly dávky
+#[derive(Debug, Deserialize, Default)]
+struct BatchIssueDefaults {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    tracker_id: Option<i32>,
+    #[serde(default)]
+    status_id: Option<i32>,
+    #[serde(default)]
+    priority_id: Option<i32>,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    fixed_version_id: Option<i32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BatchIssue {
+    subject: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    tracker_id: Option<i32>,
+    #[serde(default)]
+    status_id: Option<i32>,
+    #[serde(default)]
+    priority_id: Option<i32>,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    fixed_version_id: Option<i32>,
+    /// Index nadřazeného úkolu v této dávce
+    #[serde(default)]
+    parent_index: Option<usize>,
+    /// ID existujícího nadřazeného úkolu
+    #[serde(default)]
+    parent_issue_id: Option<i32>,
+    #[serde(default)]
+    estimated_hours: Option<f64>,
+    #[serde(default)]
+    start_date: Option<NaiveDate>,
+    #[serde(default)]
+    due_date: Option<NaiveDate>,
+    #[serde(default)]
+    custom_field_values: Option<Map<String, Value>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateIssuesBatchArgs {
+    issues: Vec<BatchIssue>,
+    #[serde(default)]
+    defaults: BatchIssueDefaults,
+    #[serde(default)]
+    stop_on_error: bool,
+}
+
+/// Pořadí vytváření - nadřazené úkoly před podúkoly (podle hloubky v dávce)
+fn batch_creation_order(issues: &[BatchIssue]) -> Result<Vec<usize>, String> {
+    let mut depths = Vec::with_capacity(issues.len());
+    for (index, issue) in issues.iter().enumerate() {
+        if issue.parent_index.is_some() && issue.parent_issue_id.is_some() {
+            return Err((i18n::messages().batch_parent_conflict)(index));
+        }
+
+        let mut depth = 0;
+        let mut current = index;
+        while let Some(parent) = issues[current].parent_index {
+            if parent >= issues.len() {
+                return Err((i18n::messages().batch_parent_missing)(current, parent));
+            }
+            depth += 1;
+            if depth > issues.len() {
+                return Err((i18n::messages().batch_parent_cycle)(index));
+            }
+            current = parent;
+        }
+        depths.push(depth);
+    }
+
+    let mut order: Vec<usize> = (0..issues.len()).collect();
+    order.sort_by_key(|index| depths[*index]);
+    Ok(order)
+}
+
+#[async_trait]
+impl ToolExecutor for CreateIssuesBatchTool {
+    fn name(&self) -> &str {
+        "create_issues_batch"
+    }
+    
+    fn description(&self) -> &str {
+        "Vytvoří více úkolů najednou (např. rozpad funkce na podúkoly). Vazbu na nadřazený úkol z téže dávky \
+        zadejte přes parent_index (index v poli issues) - nadřazené úkoly se vytvoří první. \
+        Společné hodnoty (projekt, tracker, stav, priorita) lze zadat jednou v defaults."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "issues": {
+                "type": "array",
+                "description": (i18n::messages().batch_issues_description)(MAX_BATCH_ISSUES),
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "subject": { "type": "string", "description": "Název úkolu (povinné)" },
+                        "description": { "type": "string", "description": "Popis úkolu" },
+                        "project_id": { "type": "integer", "description": "ID projektu (jinak z defaults)" },
+                        "tracker_id": { "type": "integer", "description": "ID trackeru (jinak z defaults)" },
+                        "status_id": { "type": "integer", "description": "ID statusu (jinak z defaults)" },
+                        "priority_id": { "type": "integer", "description": "ID priority (jinak z defaults)" },
+                        "assigned_to_id": { "type": "integer", "description": "ID řešitele" },
+                        "fixed_version_id": { "type": "integer", "description": "ID verze/milníku" },
+                        "parent_index": { "type": "integer", "minimum": 0, "description": "Index nadřazeného úkolu v poli issues" },
+                        "parent_issue_id": { "type": "integer", "description": "ID existujícího nadřazeného úkolu" },
+                        "estimated_hours": { "type": "number", "description": "Odhadované hodiny" },
+                        "start_date": { "type": "string", "format": "date", "description": "Datum zahájení (YYYY-MM-DD)" },
+                        "due_date": { "type": "string", "format": "date", "description": "Termín dokončení (YYYY-MM-DD)" },
+                        "custom_field_values": custom_field_values_schema()
+                    },
+                    "required": ["subject"]
+                }
+            },
+            "defaults": {
+                "type": "object",
+                "description": "Společné hodnoty pro všechny úkoly: project_id, tracker_id, status_id, priority_id, assigned_to_id, fixed_version_id"
+            },
+            "stop_on_error": {
+                "type": "boolean",
+                "description": "Po první chybě nevytvářet další úkoly (výchozí: false - pokračuje se, přeskočí se jen podúkoly nevytvořených úkolů)"
             }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["issues"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CreateIssuesBatchArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("issues"))?
+        )?;
+        
+        if args.issues.is_empty() || args.issues.len() > MAX_BATCH_ISSUES {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().batch_size)(MAX_BATCH_ISSUES, args.issues.len()))
+            ]));
+        }
+        let order = match batch_creation_order(&args.issues) {
+            Ok(order) => order,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        debug!("Vytvářím dávku {} úkolů", args.issues.len());
+        
+        let defaults = &args.defaults;
+        let mut created: Vec<Option<i32>> = vec![None; args.issues.len()];
+        let mut results: Vec<Option<Value>> = vec![None; args.issues.len()];
+        let mut stopped = false;
+        
+        for index in order {
+            let issue = &args.issues[index];
+            let outcome = |status: &str, detail: Value| json!({
+                "index": index,
+                "subject": issue.subject,
+                "status": status,
+                "detail": detail,
+            });
+            
+            if stopped {
+                results[index] = Some(outcome("skipped", json!(i18n::messages().batch_skipped_after_error)));
+                continue;
+            }
+            
+            let parent_issue_id = match issue.parent_index {
+                Some(parent) => match created[parent] {
+                    Some(id) => Some(id),
+                    None => {
+                        results[index] = Some(outcome("skipped", json!((i18n::messages().batch_parent_not_created)(parent))));
+                        continue;
+                    }
+                },
+                None => issue.parent_issue_id,
+            };
+            
+            let request = (|| -> Result<CreateIssueRequest, String> {
+                let required = |value: Option<i32>, default: Option<i32>, name: &str| value.or(default)
+                    .ok_or_else(|| (i18n::messages().batch_value_missing)(name));
+                Ok(CreateIssueRequest {
+                    issue: CreateIssue {
+                        project_id: required(issue.project_id, defaults.project_id, "project_id")?,
+                        tracker_id: required(issue.tracker_id, defaults.tracker_id, "tracker_id")?,
+                        status_id: required(issue.status_id, defaults.status_id, "status_id")?,
+                        priority_id: required(issue.priority_id, defaults.priority_id, "priority_id")?,
+                        subject: issue.subject.clone(),
+                        description: issue.description.clone(),
+                        category_id: None,
+                        fixed_version_id: issue.fixed_version_id.or(defaults.fixed_version_id),
+                        assigned_to_id: issue.assigned_to_id.or(defaults.assigned_to_id),
+                        parent_issue_id,
+                        estimated_hours: issue.estimated_hours,
+                        start_date: issue.start_date,
+                        due_date: issue.due_date,
+                        done_ratio: None,
+                        custom_field_values: parse_custom_field_values(issue.custom_field_values.clone())?,
+                    }
+                })
+            })();
+            
+            let result = match request {
+                Ok(request) => self.api_client.create_issue(request).await.map_err(|e| e.to_string()),
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(response) => {
+                    created[index] = Some(response.issue.id);
+                    results[index] = Some(outcome("created", json!({ "id": response.issue.id, "parent_issue_id": parent_issue_id })));
+                }
+                Err(e) => {
+                    error!("Chyba při vytváření úkolu [{}] '{}': {}", index, issue.subject, e);
+                    results[index] = Some(outcome("failed", json!(e)));
+                    stopped = args.stop_on_error;
+                }
+            }
+        }
+        
+        let created_count = created.iter().flatten().count();
+        info!("Dávka úkolů: vytvořeno {} z {}", created_count, args.issues.len());
+        
+        let summary = json!({
+            "created": created_count,
+            "total": args.issues.len(),
+            "issues": results.into_iter().flatten().collect::<Vec<_>>(),
+        });
+        let text = format!(
+            "{}:\n\n{}",
+            (i18n::messages().batch_created)(created_count, args.issues.len()),
+            serde_json::to_string_pretty(&summary)?
+        );
+        
+        if created_count == 0 {
+            Ok(CallToolResult::error(vec![ToolResult::text(text)]))
+        } else {
+            Ok(CallToolResult::success(vec![ToolResult::text(text)]))
         }
     }
 }
@@ -422,7 +858,7 @@ impl UpdateIssueTool {
     }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Default, Deserialize, Serialize)]
 struct UpdateIssueArgs {
     id: i32,
     #[serde(default)]
@@ -436,6 +872,8 @@ struct UpdateIssueArgs {
     #[serde(default)]
     assigned_to_id: Option<i32>,
     #[serde(default)]
+    fixed_version_id: Option<i32>,
+    #[serde(default)]
     done_ratio: Option<i32>,
     #[serde(default)]
     estimated_hours: Option<f64>,
@@ -443,6 +881,8 @@ struct UpdateIssueArgs {
     start_date: Option<NaiveDate>,
     #[serde(default)]
     due_date: Option<NaiveDate>,
+    #[serde(default)]
+    custom_field_values: Option<Map<String, Value>>,
 }
 
 #[async_trait]
@@ -451,6 +891,10 @@ impl ToolExecutor for UpdateIssueTool {
         "update_issue"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Aktualizuje existující úkol v EasyProject systému"
     }
@@ -481,6 +925,10 @@ impl ToolExecutor for UpdateIssueTool {
                 "type": "integer",
                 "description": "ID uživatele, kterému přiřadit úkol"
             },
+            "fixed_version_id": {
+                "type": "integer",
+                "description": "ID cílové verze/milníku (musí být dostupná v projektu úkolu, viz list_assignable_versions)"
+            },
             "done_ratio": {
                 "type": "integer",
                 "description": "Nové procento dokončení (0-100)",
@@ -500,10 +948,15 @@ impl ToolExecutor for UpdateIssueTool {
                 "type": "string",
                 "format": "date",
                 "description": "Nový termín dokončení (YYYY-MM-DD)"
-            }
+            },
+            "custom_field_values": custom_field_values_schema()
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: UpdateIssueArgs = match arguments {
             Some(args) => {
@@ -513,7 +966,7 @@ impl ToolExecutor for UpdateIssueTool {
                     Err(e) => {
                         error!("Chyba při parsování argumentů pro aktualizaci úkolu: {}", e);
                         return Ok(CallToolResult::error(vec![
-                            ToolResult::text(format!("Chyba při parsování argumentů pro aktualizaci úkolu: {}", e))
+                            ToolResult::text(format!("{}: {}", i18n::messages().error_parsing_update_arguments, e))
                         ]));
                     }
                 }
@@ -521,24 +974,42 @@ impl ToolExecutor for UpdateIssueTool {
             None => {
                 error!("Chybí argumenty pro aktualizaci úkolu");
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text("Chybí argumenty pro aktualizaci úkolu".to_string())
+                    ToolResult::text(i18n::messages().missing_update_arguments.to_string())
                 ]));
             }
         };
         
         debug!("Aktualizuji úkol s ID: {}", args.id);
         
+        let custom_field_values = match parse_custom_field_values(args.custom_field_values) {
+            Ok(values) => values,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_issue)(args.id), e))
+                ]));
+            }
+        };
+        
         // Nejdříve získáme současný stav úkolu
         let current_issue = match self.api_client.get_issue(args.id, None).await {
             Ok(response) => response.issue,
             Err(e) => {
                 error!("Chyba při získávání úkolu {}: {}", args.id, e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání úkolu {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(args.id), e))
                 ]));
             }
         };
         
+        if let Some(version_id) = args.fixed_version_id {
+            if let Err(e) = self.api_client.validate_version_assignable(current_issue.project.id, version_id).await {
+                error!("Neplatná verze pro úkol {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_issue)(args.id), e))
+                ]));
+            }
+        }
+        
         let issue_data = CreateIssueRequest {
             issue: CreateIssue {
                 project_id: current_issue.project.id,
@@ -548,13 +1019,14 @@ impl ToolExecutor for UpdateIssueTool {
                 subject: args.subject.unwrap_or(current_issue.subject.clone()),
                 description: args.description.or(current_issue.description),
                 category_id: current_issue.category.map(|c| c.id),
-                fixed_version_id: current_issue.fixed_version.map(|v| v.id),
+                fixed_version_id: args.fixed_version_id.or(current_issue.fixed_version.map(|v| v.id)),
                 assigned_to_id: args.assigned_to_id.or(current_issue.assigned_to.map(|u| u.id)),
                 parent_issue_id: current_issue.parent.map(|p| p.id),
                 estimated_hours: args.estimated_hours.or(current_issue.estimated_hours),
                 start_date: args.start_date.or(current_issue.start_date),
                 due_date: args.due_date.or(current_issue.due_date),
                 done_ratio: args.done_ratio.or(current_issue.done_ratio),
+                custom_field_values,
             }
         };
         
@@ -569,9 +1041,8 @@ impl ToolExecutor for UpdateIssueTool {
                 debug!("Vytvářím success CallToolResult pro úkol {}", response.issue.id);
                 let result = CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Úkol '{}' (ID: {}) byl úspěšně aktualizován:\n\n{}",
-                        response.issue.subject,
-                        response.issue.id,
+                        "{}:\n\n{}",
+                        (i18n::messages().issue_updated)(&response.issue.subject, response.issue.id),
                         issue_json
                     ))
                 ]);
@@ -582,183 +1053,2422 @@ impl ToolExecutor for UpdateIssueTool {
                 error!("Chyba při aktualizaci úkolu {}: {}", args.id, e);
                 debug!("Vytvářím error CallToolResult pro úkol {}", args.id);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při aktualizaci úkolu {}: {}", args.id, e))
-                ]))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_issue)(args.id), e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
 }
 
-// === ASSIGN ISSUE TOOL ===
+// === DELETE ISSUE TOOL ===
 
-pub struct AssignIssueTool {
+pub struct DeleteIssueTool {
     api_client: EasyProjectClient,
 }
 
-impl AssignIssueTool {
+impl DeleteIssueTool {
     pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
         Self { api_client }
     }
 }
 
 #[derive(Debug, Deserialize)]
-struct AssignIssueArgs {
+struct DeleteIssueArgs {
     id: i32,
-    assigned_to_id: i32,
 }
 
 #[async_trait]
-impl ToolExecutor for AssignIssueTool {
+impl ToolExecutor for DeleteIssueTool {
     fn name(&self) -> &str {
-        "assign_issue"
+        "delete_issue"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
     }
     
     fn description(&self) -> &str {
-        "Přiřadí úkol konkrétnímu uživateli"
+        "Smaže úkol včetně jeho podúkolů a časových záznamů (POZOR: Tato operace je nevratná! \
+        Před smazáním si vyžádejte potvrzení uživatele; pro ukončení práce na úkolu raději použijte complete_task)"
     }
     
     fn input_schema(&self) -> Value {
         json!({
             "id": {
                 "type": "integer",
-                "description": "ID úkolu k přiřazení (povinné)"
-            },
-            "assigned_to_id": {
-                "type": "integer",
-                "description": "ID uživatele, kterému přiřadit úkol (povinné)"
+                "description": "ID úkolu ke smazání (povinné)"
             }
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
-        let args: AssignIssueArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro přiřazení úkolu")?
+        let args: DeleteIssueArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
-        debug!("Přiřazuji úkol {} uživateli {}", args.id, args.assigned_to_id);
+        debug!("Mažu úkol s ID: {}", args.id);
         
-        // Použijeme update_issue s pouze změnou assigned_to_id
-        let update_args = UpdateIssueArgs {
-            id: args.id,
-            assigned_to_id: Some(args.assigned_to_id),
-            subject: None,
-            description: None,
-            status_id: None,
-            priority_id: None,
-            done_ratio: None,
-            estimated_hours: None,
-            start_date: None,
-            due_date: None,
+        // Nejdříve získáme název úkolu pro potvrzení
+        let subject = match self.api_client.get_issue(args.id, None).await {
+            Ok(response) => response.issue.subject,
+            Err(e) => {
+                error!("Chyba při získávání úkolu {} před smazáním: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue_before_delete)(args.id), e))
+                ]));
+            }
         };
         
-        // Delegujeme na UpdateIssueTool
-        let default_config = crate::config::AppConfig::default();
-        let update_tool = UpdateIssueTool::new(self.api_client.clone(), default_config);
-        let result = update_tool.execute(Some(serde_json::to_value(update_args)?)).await?;
-        
-        // Upravíme zprávu pro lepší kontext
-        match result.is_error {
-            Some(true) => Ok(result),
-            _ => {
+        match self.api_client.delete_issue(args.id).await {
+            Ok(_) => {
+                info!("Úspěšně smazán úkol: {} (ID: {})", subject, args.id);
+                
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Úkol {} byl úspěšně přiřazen uživateli {}.",
-                        args.id,
-                        args.assigned_to_id
-                    ))
+                    ToolResult::text((i18n::messages().issue_removed)(&subject, args.id))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při mazání úkolu {} ({}): {}", args.id, subject, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_deleting_issue)(args.id, &subject), e))
                 ]))
             }
         }
     }
 }
 
-// === COMPLETE ISSUE TOOL ===
+// === LIST ISSUE ATTACHMENTS TOOL ===
 
-pub struct CompleteIssueTool {
+pub struct ListIssueAttachmentsTool {
     api_client: EasyProjectClient,
 }
 
-impl CompleteIssueTool {
+impl ListIssueAttachmentsTool {
     pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
         Self { api_client }
     }
 }
 
 #[derive(Debug, Deserialize)]
-struct CompleteIssueArgs {
+struct ListIssueAttachmentsArgs {
     id: i32,
-    #[serde(default = "default_done_ratio")]
-    done_ratio: i32,
 }
 
-fn default_done_ratio() -> i32 {
-    100
+#[async_trait]
+impl ToolExecutor for ListIssueAttachmentsTool {
+    fn name(&self) -> &str {
+        "list_issue_attachments"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Vypíše přílohy úkolu (název, velikost, typ, autor); obsah přílohy stáhnete přes download_attachment"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu (povinné)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListIssueAttachmentsArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        debug!("Získávám přílohy úkolu {}", args.id);
+        
+        match self.api_client.get_issue(args.id, Some(vec!["attachments".to_string()])).await {
+            Ok(response) => {
+                let attachments = response.issue.attachments.unwrap_or_default();
+                let result = json!({
+                    "issue_id": args.id,
+                    "subject": response.issue.subject,
+                    "total_count": attachments.len(),
+                    "attachments": attachments,
+                });
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(serde_json::to_string_pretty(&result)?)
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při získávání příloh úkolu {}: {}", args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_attachments)(args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === DOWNLOAD ATTACHMENT TOOL ===
+
+/// Aliasy parametru `id` pro tools pracující s jednou přílohou
+const ATTACHMENT_ID_ALIASES: &[(&str, &str)] = &[("attachment_id", "id"), ("attachment", "id")];
+
+/// Typy obsahu, které se vrací jako text (ostatní jako base64)
+fn is_text_content_type(content_type: &str) -> bool {
+    content_type.starts_with("text/")
+        || ["json", "xml", "javascript", "yaml", "csv"].iter().any(|kind| content_type.contains(kind))
+}
+
+pub struct DownloadAttachmentTool {
+    api_client: EasyProjectClient,
+    max_bytes: u64,
+}
+
+impl DownloadAttachmentTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self { api_client, max_bytes: config.tools.issues.max_attachment_bytes }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DownloadAttachmentArgs {
+    id: i32,
 }
 
 #[async_trait]
-impl ToolExecutor for CompleteIssueTool {
+impl ToolExecutor for DownloadAttachmentTool {
     fn name(&self) -> &str {
-        "complete_task"
+        "download_attachment"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ATTACHMENT_ID_ALIASES
     }
     
     fn description(&self) -> &str {
-        "Označí úkol jako dokončený (nastaví done_ratio na 100%)"
+        "Stáhne obsah přílohy - obrázky vrací jako obrázek, textové soubory jako text a ostatní jako base64 \
+        (velikost omezuje tools.issues.max_attachment_bytes)"
     }
     
     fn input_schema(&self) -> Value {
         json!({
             "id": {
                 "type": "integer",
-                "description": "ID úkolu k označení jako dokončený (povinné)"
-            },
-            "done_ratio": {
+                "description": "ID přílohy (povinné, viz list_issue_attachments)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+        let args: DownloadAttachmentArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        debug!("Stahuji přílohu {}", args.id);
+        
+        let attachment = match self.api_client.get_attachment(args.id).await {
+            Ok(response) => response.attachment,
+            Err(e) => {
+                error!("Chyba při získávání přílohy {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_attachment)(args.id), e))
+                ]));
+            }
+        };
+        
+        let content = match self.api_client.download_attachment(&attachment, self.max_bytes).await {
+            Ok(content) => content,
+            Err(e) => {
+                error!("Chyba při stahování přílohy {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_downloading_attachment)(args.id), e))
+                ]));
+            }
+        };
+        
+        let content_type = attachment.content_type.clone()
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        let uri = attachment.content_url.clone()
+            .unwrap_or_else(|| format!("easyproject://attachment/{}", attachment.id));
+        let summary = ToolResult::text((i18n::messages().attachment_summary)(
+            &attachment.filename, attachment.id, &content_type, content.len()
+        ));
+        
+        let body = if content_type.starts_with("image/") {
+            ToolResult::image(STANDARD.encode(&content), content_type)
+        } else if is_text_content_type(&content_type) {
+            match String::from_utf8(content) {
+                Ok(text) => ToolResult::resource_text(uri, Some(content_type), text),
+                Err(e) => ToolResult::resource_blob(uri, Some(content_type), STANDARD.encode(e.as_bytes())),
+            }
+        } else {
+            ToolResult::resource_blob(uri, Some(content_type), STANDARD.encode(&content))
+        };
+        
+        info!("Stažena příloha {} ({})", attachment.filename, attachment.id);
+        Ok(CallToolResult::success(vec![summary, body]))
+    }
+}
+
+// === UPLOAD ATTACHMENT TOOL ===
+
+pub struct UploadAttachmentTool {
+    api_client: EasyProjectClient,
+    max_bytes: u64,
+    upload_dir: Option<String>,
+}
+
+impl UploadAttachmentTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            max_bytes: config.tools.issues.max_attachment_bytes,
+            upload_dir: config.tools.issues.upload_dir.clone(),
+        }
+    }
+
+    /// Načte obsah souboru - jen uvnitř nakonfigurovaného upload_dir
+    async fn read_upload_file(&self, file_path: &str) -> Result<Vec<u8>, String> {
+        let messages = i18n::messages();
+        let upload_dir = self.upload_dir.as_ref()
+            .ok_or(messages.upload_disabled)?;
+        let root = tokio::fs::canonicalize(upload_dir).await
+            .map_err(|e| format!("{}: {}", (messages.directory_unavailable)(upload_dir), e))?;
+        let path = tokio::fs::canonicalize(root.join(file_path)).await
+            .map_err(|e| format!("{}: {}", (messages.file_unavailable)(file_path), e))?;
+        if !path.starts_with(&root) {
+            return Err((messages.file_outside_upload_dir)(file_path, upload_dir));
+        }
+
+        let size = tokio::fs::metadata(&path).await
+            .map_err(|e| format!("{}: {}", (messages.file_unavailable)(file_path), e))?
+            .len();
+        if size > self.max_bytes {
+            return Err((messages.file_too_large)(file_path, size, self.max_bytes));
+        }
+        tokio::fs::read(&path).await
+            .map_err(|e| format!("{}: {}", (messages.file_unreadable)(file_path), e))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct UploadAttachmentArgs {
+    id: i32,
+    filename: Option<String>,
+    content_base64: Option<String>,
+    content: Option<String>,
+    file_path: Option<String>,
+    content_type: Option<String>,
+    description: Option<String>,
+    notes: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for UploadAttachmentTool {
+    fn name(&self) -> &str {
+        "upload_attachment"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Nahraje soubor a připojí jej k úkolu jako přílohu. Obsah zadejte právě jedním z parametrů \
+        content (text), content_base64 (binární data) nebo file_path (soubor v tools.issues.upload_dir)"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
                 "type": "integer",
-                "description": "Procento dokončení (výchozí: 100)",
-                "minimum": 0,
-                "maximum": 100,
-                "default": 100
+                "description": "ID úkolu (povinné)"
+            },
+            "filename": {
+                "type": "string",
+                "description": "Název souboru přílohy (povinné, kromě file_path - výchozí je název souboru)"
+            },
+            "content": {
+                "type": "string",
+                "description": "Textový obsah souboru"
+            },
+            "content_base64": {
+                "type": "string",
+                "description": "Binární obsah souboru v base64"
+            },
+            "file_path": {
+                "type": "string",
+                "description": "Cesta k souboru relativně k tools.issues.upload_dir"
+            },
+            "content_type": {
+                "type": "string",
+                "description": "MIME typ přílohy (např. image/png)"
+            },
+            "description": {
+                "type": "string",
+                "description": "Popis přílohy"
+            },
+            "notes": {
+                "type": "string",
+                "description": "Komentář přidaný k úkolu spolu s přílohou"
             }
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
-        let args: CompleteIssueArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro dokončení úkolu")?
+        use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+        let args: UploadAttachmentArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
-        debug!("Označuji úkol {} jako dokončený ({}%)", args.id, args.done_ratio);
+        let sources = [args.content.is_some(), args.content_base64.is_some(), args.file_path.is_some()];
+        if sources.iter().filter(|given| **given).count() != 1 {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().attachment_source_required.to_string())
+            ]));
+        }
         
-        // Použijeme update_issue s pouze změnou done_ratio
-        let update_args = UpdateIssueArgs {
-            id: args.id,
-            done_ratio: Some(args.done_ratio),
-            assigned_to_id: None,
-            subject: None,
-            description: None,
-            status_id: None,
-            priority_id: None,
-            estimated_hours: None,
-            start_date: None,
-            due_date: None,
+        let content = if let Some(text) = args.content {
+            Ok(text.into_bytes())
+        } else if let Some(encoded) = args.content_base64 {
+            STANDARD.decode(encoded.trim())
+                .map_err(|e| format!("{}: {}", i18n::messages().invalid_base64, e))
+        } else {
+            self.read_upload_file(args.file_path.as_deref().unwrap_or_default()).await
+        };
+        let content = match content {
+            Ok(content) if content.len() as u64 > self.max_bytes => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().attachment_too_large)(content.len(), self.max_bytes))
+                ]));
+            }
+            Ok(content) => content,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
         };
         
-        // Delegujeme na UpdateIssueTool
-        let default_config = crate::config::AppConfig::default();
-        let update_tool = UpdateIssueTool::new(self.api_client.clone(), default_config);
-        let result = update_tool.execute(Some(serde_json::to_value(update_args)?)).await?;
+        let filename = args.filename
+            .or_else(|| args.file_path.as_deref()
+                .and_then(|path| std::path::Path::new(path).file_name())
+                .map(|name| name.to_string_lossy().to_string()));
+        let Some(filename) = filename.filter(|name| !name.trim().is_empty()) else {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().missing_parameter)("filename"))
+            ]));
+        };
         
-        // Upravíme zprávu pro lepší kontext
-        match result.is_error {
-            Some(true) => Ok(result),
-            _ => {
+        debug!("Nahrávám přílohu {} ({} B) k úkolu {}", filename, content.len(), args.id);
+        let size = content.len();
+        
+        let token = match self.api_client.upload_file(&filename, content).await {
+            Ok(token) => token,
+            Err(e) => {
+                error!("Chyba při nahrávání souboru {}: {}", filename, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_uploading_file)(&filename), e))
+                ]));
+            }
+        };
+        
+        let upload = IssueUpload {
+            token,
+            filename: filename.clone(),
+            content_type: args.content_type,
+            description: args.description,
+        };
+        
+        match self.api_client.attach_to_issue(args.id, vec![upload], args.notes).await {
+            Ok(_) => {
+                info!("Příloha {} připojena k úkolu {}", filename, args.id);
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Úkol {} byl úspěšně označen jako dokončený ({}%).",
-                        args.id,
-                        args.done_ratio
-                    ))
+                    ToolResult::text((i18n::messages().attachment_added)(&filename, size, args.id))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při připojování přílohy {} k úkolu {}: {}", filename, args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_attaching_file)(&filename, args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === ADD WATCHER TOOL ===
+
+pub struct AddWatcherTool {
+    api_client: EasyProjectClient,
+}
+
+impl AddWatcherTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+/// Parametry add_watcher a remove_watcher
+#[derive(Debug, Deserialize)]
+struct WatcherArgs {
+    id: i32,
+    user_id: i32,
+}
+
+#[async_trait]
+impl ToolExecutor for AddWatcherTool {
+    fn name(&self) -> &str {
+        "add_watcher"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Přidá uživatele mezi sledující úkolu - bude dostávat notifikace o jeho změnách"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu (povinné)"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "ID uživatele, který má úkol sledovat (povinné)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id", "user_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: WatcherArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameters)("id, user_id"))?
+        )?;
+        
+        debug!("Přidávám sledujícího {} k úkolu {}", args.user_id, args.id);
+        
+        match self.api_client.add_watcher(args.id, args.user_id).await {
+            Ok(_) => {
+                info!("Uživatel {} přidán mezi sledující úkolu {}", args.user_id, args.id);
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().watcher_added)(args.user_id, args.id))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při přidávání sledujícího {} k úkolu {}: {}", args.user_id, args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_adding_watcher)(args.user_id, args.id), e))
                 ]))
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+// === REMOVE WATCHER TOOL ===
+
+pub struct RemoveWatcherTool {
+    api_client: EasyProjectClient,
+}
+
+impl RemoveWatcherTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for RemoveWatcherTool {
+    fn name(&self) -> &str {
+        "remove_watcher"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Odebere uživatele ze sledujících úkolu"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu (povinné)"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "ID sledujícího uživatele k odebrání (povinné)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id", "user_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: WatcherArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameters)("id, user_id"))?
+        )?;
+        
+        debug!("Odebírám sledujícího {} z úkolu {}", args.user_id, args.id);
+        
+        match self.api_client.remove_watcher(args.id, args.user_id).await {
+            Ok(_) => {
+                info!("Uživatel {} odebrán ze sledujících úkolu {}", args.user_id, args.id);
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().watcher_removed)(args.user_id, args.id))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při odebírání sledujícího {} z úkolu {}: {}", args.user_id, args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_removing_watcher)(args.user_id, args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === ASSIGN ISSUE TOOL ===
+
+pub struct AssignIssueTool {
+    api_client: EasyProjectClient,
+}
+
+impl AssignIssueTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+    
+    /// Vytížení cílového uživatele před přiřazením (úkol se nemění)
+    async fn preview_load(&self, issue_id: i32, assigned_to_id: i32) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("Náhled vytížení uživatele {} před přiřazením úkolu {}", assigned_to_id, issue_id);
+        
+        let issue = match self.api_client.get_issue(issue_id, None).await {
+            Ok(response) => response.issue,
+            Err(e) => {
+                error!("Chyba při získávání úkolu {}: {}", issue_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(issue_id), e))
+                ]));
+            }
+        };
+        
+        let today = crate::utils::date_utils::current_date_local();
+        let mut open_issues = 0usize;
+        let mut remaining_hours = 0.0;
+        let mut without_estimate = 0usize;
+        let mut overdue = 0usize;
+        let mut already_assigned = false;
+        
+        // scan_issues vrací jen otevřené úkoly (výchozí filtr API)
+        let scan = self.api_client.scan_issues(None, Some(assigned_to_id), None, None, |issues| {
+            for open_issue in issues {
+                open_issues += 1;
+                already_assigned |= open_issue.id == issue_id;
+                match remaining_estimate(open_issue) {
+                    Some(hours) => remaining_hours += hours,
+                    None => without_estimate += 1,
+                }
+                if open_issue.due_date.is_some_and(|due_date| due_date < today) {
+                    overdue += 1;
+                }
+            }
+        }).await;
+        
+        let scan = match scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                error!("Chyba při zjišťování vytížení uživatele {}: {}", assigned_to_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_user_load)(assigned_to_id), e))
+                ]));
+            }
+        };
+        
+        let user_name = self.api_client.get_user(assigned_to_id).await
+            .ok()
+            .and_then(|response| match (response.user.firstname, response.user.lastname) {
+                (Some(first), Some(last)) => Some(format!("{} {}", first, last)),
+                (first, last) => first.or(last).or(response.user.login),
+            })
+            .unwrap_or_else(|| (i18n::messages().user_fallback_name)(assigned_to_id));
+        let issue_remaining = if already_assigned { None } else { remaining_estimate(&issue) };
+        
+        let preview = json!({
+            "issue": {
+                "id": issue.id,
+                "subject": issue.subject,
+                "current_assignee": issue.assigned_to.as_ref().map(|user| &user.name),
+                "remaining_estimated_hours": remaining_estimate(&issue),
+            },
+            "target_user": {
+                "id": assigned_to_id,
+                "name": user_name,
+                "open_issues": open_issues,
+                "overdue_issues": overdue,
+                "remaining_estimated_hours": (remaining_hours * 100.0).round() / 100.0,
+                "issues_without_estimate": without_estimate,
+                "already_assigned": already_assigned,
+            },
+            "after_assignment": {
+                "open_issues": if already_assigned { open_issues } else { open_issues + 1 },
+                "remaining_estimated_hours": ((remaining_hours + issue_remaining.unwrap_or(0.0)) * 100.0).round() / 100.0,
+            },
+            "remaining_hours_rule": i18n::messages().remaining_hours_rule,
+            "scan": scan,
+        });
+        
+        info!("Náhled vytížení uživatele {}: {} otevřených úkolů", assigned_to_id, open_issues);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().assignment_preview)(issue_id, &user_name),
+                serde_json::to_string_pretty(&preview)?
+            ))
+        ]))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AssignIssueArgs {
+    id: i32,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    assigned_to: Option<String>,
+    #[serde(default)]
+    preview_load: bool,
+}
+
+/// Zbývající odhad úkolu podle procenta dokončení
+fn remaining_estimate(issue: &crate::api::models::Issue) -> Option<f64> {
+    let done_ratio = issue.done_ratio.unwrap_or(0).clamp(0, 100) as f64;
+    issue.estimated_hours.map(|hours| hours * (100.0 - done_ratio) / 100.0)
+}
+
+#[async_trait]
+impl ToolExecutor for AssignIssueTool {
+    fn name(&self) -> &str {
+        "assign_issue"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Přiřadí úkol konkrétnímu uživateli - podle ID (assigned_to_id) nebo jména, loginu či emailu (assigned_to)"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu k přiřazení (povinné)"
+            },
+            "assigned_to_id": {
+                "type": "integer",
+                "description": "ID uživatele, kterému přiřadit úkol (povinné, pokud není zadáno assigned_to)"
+            },
+            "assigned_to": assigned_to_schema(),
+            "preview_load": {
+                "type": "boolean",
+                "description": "Úkol nepřiřadí, jen zobrazí aktuální vytížení uživatele (otevřené úkoly a zbývající odhad hodin) včetně tohoto úkolu (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: AssignIssueArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        let assigned_to_id = match resolve_assignee(&self.api_client, args.assigned_to_id, args.assigned_to.as_deref()).await {
+            Ok(Some(assigned_to_id)) => assigned_to_id,
+            Ok(None) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().assignee_required.to_string())
+                ]));
+            }
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        if args.preview_load {
+            return self.preview_load(args.id, assigned_to_id).await;
+        }
+        
+        debug!("Přiřazuji úkol {} uživateli {}", args.id, assigned_to_id);
+        
+        // Použijeme update_issue s pouze změnou assigned_to_id
+        let update_args = UpdateIssueArgs {
+            id: args.id,
+            assigned_to_id: Some(assigned_to_id),
+            ..Default::default()
+        };
+        
+        // Delegujeme na UpdateIssueTool
+        let default_config = crate::config::AppConfig::default();
+        let update_tool = UpdateIssueTool::new(self.api_client.clone(), default_config);
+        let result = update_tool.execute(Some(serde_json::to_value(update_args)?)).await?;
+        
+        // Upravíme zprávu pro lepší kontext
+        match result.is_error {
+            Some(true) => Ok(result),
+            _ => {
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().issue_assigned)(args.id, assigned_to_id))
+                ]))
+            }
+        }
+    }
+}
+
+// === COMPLETE ISSUE TOOL ===
+
+pub struct CompleteIssueTool {
+    api_client: EasyProjectClient,
+}
+
+impl CompleteIssueTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CompleteIssueArgs {
+    id: i32,
+    #[serde(default = "default_done_ratio")]
+    done_ratio: i32,
+}
+
+fn default_done_ratio() -> i32 {
+    100
+}
+
+#[async_trait]
+impl ToolExecutor for CompleteIssueTool {
+    fn name(&self) -> &str {
+        "complete_task"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Označí úkol jako dokončený (nastaví done_ratio na 100%)"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu k označení jako dokončený (povinné)"
+            },
+            "done_ratio": {
+                "type": "integer",
+                "description": "Procento dokončení (výchozí: 100)",
+                "minimum": 0,
+                "maximum": 100,
+                "default": 100
+            }
+        })
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CompleteIssueArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        debug!("Označuji úkol {} jako dokončený ({}%)", args.id, args.done_ratio);
+        
+        // Použijeme update_issue s pouze změnou done_ratio
+        let update_args = UpdateIssueArgs {
+            id: args.id,
+            done_ratio: Some(args.done_ratio),
+            ..Default::default()
+        };
+        
+        // Delegujeme na UpdateIssueTool
+        let default_config = crate::config::AppConfig::default();
+        let update_tool = UpdateIssueTool::new(self.api_client.clone(), default_config);
+        let result = update_tool.execute(Some(serde_json::to_value(update_args)?)).await?;
+        
+        // Upravíme zprávu pro lepší kontext
+        match result.is_error {
+            Some(true) => Ok(result),
+            _ => {
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().issue_completed)(args.id, args.done_ratio))
+                ]))
+            }
+        }
+    }
+}
+
+// === TRANSITION ISSUE TOOL ===
+
+pub struct TransitionIssueTool {
+    api_client: EasyProjectClient,
+}
+
+impl TransitionIssueTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TransitionIssueArgs {
+    id: i32,
+    status: Value,
+    #[serde(default)]
+    notes: Option<String>,
+}
+
+/// Najde cílový stav podle ID nebo názvu (bez ohledu na diakritiku a velikost písmen)
+///
+/// Přesná shoda názvu má přednost; jinak musí být část názvu jednoznačná.
+fn resolve_status<'a>(candidates: &'a [IssueStatus], status: &Value) -> Result<&'a IssueStatus, String> {
+    let messages = i18n::messages();
+    let names = || candidates.iter().map(|s| format!("{} (ID {})", s.name, s.id)).collect::<Vec<_>>().join(", ");
+    
+    let requested = match status {
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.trim().to_string(),
+        _ => return Err(messages.status_param_invalid.to_string()),
+    };
+    if let Ok(id) = requested.parse::<i32>() {
+        return candidates.iter().find(|s| s.id == id)
+            .ok_or_else(|| (messages.status_id_unavailable)(id, &names()));
+    }
+    if let Some(exact) = candidates.iter().find(|s| text_equals(&s.name, &requested)) {
+        return Ok(exact);
+    }
+    let partial: Vec<&IssueStatus> = candidates.iter().filter(|s| text_contains(&s.name, &requested)).collect();
+    match partial.as_slice() {
+        [single] => Ok(single),
+        [] => Err((messages.status_not_found)(&requested, &names())),
+        many => Err((messages.status_ambiguous)(
+            &requested,
+            &many.iter().map(|s| format!("{} (ID {})", s.name, s.id)).collect::<Vec<_>>().join(", ")
+        )),
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for TransitionIssueTool {
+    fn name(&self) -> &str {
+        "transition_issue"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Převede úkol do jiného stavu podle názvu stavu (např. 'Vyřešený') nebo jeho ID. \
+        Respektuje workflow - povolí jen přechody dostupné pro tracker a roli uživatele, pokud je instance vrací. \
+        Volitelně připojí komentář."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu (povinné)"
+            },
+            "status": {
+                "type": ["string", "integer"],
+                "description": "Cílový stav - název (bez ohledu na diakritiku a velikost písmen) nebo ID (povinné)"
+            },
+            "notes": {
+                "type": "string",
+                "description": "Komentář připojený ke změně stavu (nepovinné)"
+            }
+        })
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id", "status"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: TransitionIssueArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        debug!("Měním stav úkolu {} na {}", args.id, args.status);
+        
+        // Povolené přechody vrací EasyProject/Redmine 5+ při include=allowed_statuses
+        let issue = match self.api_client.get_issue(args.id, Some(vec!["allowed_statuses".to_string()])).await {
+            Ok(response) => response.issue,
+            Err(e) => {
+                error!("Chyba při získávání úkolu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(args.id), e))
+                ]));
+            }
+        };
+        
+        let workflow_known = issue.allowed_statuses.as_ref().is_some_and(|allowed| !allowed.is_empty());
+        let candidates = match issue.allowed_statuses.clone().filter(|allowed| !allowed.is_empty()) {
+            Some(allowed) => allowed,
+            None => match self.api_client.list_issue_statuses().await {
+                Ok(response) => response.issue_statuses,
+                Err(e) => {
+                    error!("Chyba při získávání stavů úkolů: {}", e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_statuses, e))
+                    ]));
+                }
+            },
+        };
+        
+        let target = match resolve_status(&candidates, &args.status) {
+            Ok(status) => status.clone(),
+            Err(message) => {
+                let message = if workflow_known {
+                    format!("{}{}", message, (i18n::messages().workflow_transitions_hint)(&issue.status.name))
+                } else {
+                    message
+                };
+                return Ok(CallToolResult::error(vec![ToolResult::text(message)]));
+            }
+        };
+        
+        if target.id == issue.status.id {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().issue_already_in_status)(args.id, &target.name))
+            ]));
+        }
+        
+        match self.api_client.transition_issue(args.id, target.id, args.notes.clone()).await {
+            Ok(response) => {
+                // API může změnu stavu tiše ignorovat (workflow bez include=allowed_statuses)
+                if response.issue.status.id != target.id {
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().status_not_changed)(
+                            args.id, &response.issue.status.name, &issue.status.name, &target.name
+                        ))
+                    ]));
+                }
+                
+                info!("Úkol {} převeden ze stavu '{}' do '{}'", args.id, issue.status.name, target.name);
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().status_changed)(
+                        &response.issue.subject,
+                        args.id,
+                        &issue.status.name,
+                        &target.name,
+                        args.notes.is_some()
+                    ))
+                ]).with_structured_content(&json!({
+                    "id": args.id,
+                    "from_status": issue.status,
+                    "to_status": response.issue.status,
+                    "notes_added": args.notes.is_some(),
+                    "workflow_checked": workflow_known
+                })))
+            }
+            Err(e) => {
+                error!("Chyba při změně stavu úkolu {}: {}", args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_changing_status)(args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === GET ISSUE CONTEXT TOOL ===
+
+pub struct GetIssueContextTool {
+    api_client: EasyProjectClient,
+}
+
+impl GetIssueContextTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GetIssueContextArgs {
+    id: i32,
+    #[serde(default = "default_context_journal_limit")]
+    journal_limit: usize,
+    #[serde(default = "default_context_time_entry_limit")]
+    time_entry_limit: u32,
+    #[serde(default = "default_context_description_length")]
+    max_description_length: usize,
+}
+
+fn default_context_journal_limit() -> usize {
+    5
+}
+
+fn default_context_time_entry_limit() -> u32 {
+    10
+}
+
+fn default_context_description_length() -> usize {
+    1500
+}
+
+#[async_trait]
+impl ToolExecutor for GetIssueContextTool {
+    fn name(&self) -> &str {
+        "get_issue_context"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Získá kompletní kontext úkolu v jednom volání - úkol, nadřazený úkol, podúkoly, vazby, \
+        poslední záznamy historie a poslední časové záznamy. Výstup je zkrácený a kompaktní, \
+        vhodný jako podklad pro další práci s úkolem."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID úkolu (povinné)"
+            },
+            "journal_limit": {
+                "type": "integer",
+                "description": "Počet posledních záznamů historie (výchozí: 5)",
+                "minimum": 0,
+                "maximum": 50,
+                "default": 5
+            },
+            "time_entry_limit": {
+                "type": "integer",
+                "description": "Počet posledních časových záznamů (výchozí: 10)",
+                "minimum": 0,
+                "maximum": 100,
+                "default": 10
+            },
+            "max_description_length": {
+                "type": "integer",
+                "description": "Maximální délka popisu úkolu ve znacích (výchozí: 1500)",
+                "minimum": 0,
+                "default": 1500
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetIssueContextArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        debug!("Získávám kontext úkolu s ID: {}", args.id);
+        
+        // 1. Úkol včetně podúkolů, vazeb a historie
+        let include = vec!["children".to_string(), "relations".to_string(), "journals".to_string()];
+        let issue = match self.api_client.get_issue(args.id, Some(include)).await {
+            Ok(response) => response.issue,
+            Err(e) => {
+                error!("Chyba při získávání úkolu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(args.id), e))
+                ]));
+            }
+        };
+        
+        let mut context = json!({
+            "issue": {
+                "id": issue.id,
+                "subject": issue.subject,
+                "project": issue.project,
+                "tracker": issue.tracker.name,
+                "status": issue.status.name,
+                "priority": issue.priority.name,
+                "assigned_to": issue.assigned_to.as_ref().map(|u| &u.name),
+                "author": issue.author.as_ref().map(|u| &u.name),
+                "fixed_version": issue.fixed_version.as_ref().map(|v| &v.name),
+                "done_ratio": issue.done_ratio,
+                "estimated_hours": issue.estimated_hours,
+                "spent_hours": issue.spent_hours,
+                "start_date": issue.start_date,
+                "due_date": issue.due_date,
+                "updated_on": issue.updated_on,
+                "description": issue.description.as_deref()
+                    .map(|d| truncate_text(d, args.max_description_length))
+            }
+        });
+        
+        // 2. Nadřazený úkol (pouze souhrn)
+        if let Some(ref parent) = issue.parent {
+            context["parent"] = match self.api_client.get_issue(parent.id, None).await {
+                Ok(response) => json!({
+                    "id": response.issue.id,
+                    "subject": response.issue.subject,
+                    "status": response.issue.status.name,
+                    "assigned_to": response.issue.assigned_to.as_ref().map(|u| &u.name),
+                    "due_date": response.issue.due_date
+                }),
+                Err(e) => {
+                    error!("Chyba při získávání nadřazeného úkolu {}: {}", parent.id, e);
+                    json!({"id": parent.id, "error": format!("{}: {}", i18n::messages().error_fetching_parent_issue, e)})
+                }
+            };
+        }
+        
+        // 3. Podúkoly a vazby
+        context["children"] = json!(issue.children.as_ref().map(|children| {
+            children.iter()
+                .map(|child| json!({
+                    "id": child.id,
+                    "subject": child.subject,
+                    "tracker": child.tracker.as_ref().map(|t| &t.name)
+                }))
+                .collect::<Vec<_>>()
+        }).unwrap_or_default());
+        context["relations"] = json!(issue.relations.clone().unwrap_or_default());
+        
+        // 4. Poslední záznamy historie (nejnovější první)
+        let journals: Vec<Value> = issue.journals.as_ref()
+            .map(|journals| {
+                journals.iter()
+                    .rev()
+                    .filter(|j| j.notes.as_deref().is_some_and(|n| !n.trim().is_empty()) || !j.details.is_empty())
+                    .take(args.journal_limit)
+                    .map(|j| json!({
+                        "user": j.user.as_ref().map(|u| &u.name),
+                        "created_on": j.created_on,
+                        "notes": j.notes.as_deref()
+                            .filter(|n| !n.trim().is_empty())
+                            .map(|n| truncate_text(n, 500)),
+                        "changes": j.details.iter()
+                            .map(|d| format!("{}: {} → {}",
+                                d.name,
+                                d.old_value.as_deref().unwrap_or("-"),
+                                d.new_value.as_deref().unwrap_or("-")))
+                            .collect::<Vec<_>>()
+                    }))
+                    .collect()
+            })
+            .unwrap_or_default();
+        context["latest_journals"] = json!(journals);
+        
+        // 5. Poslední časové záznamy
+        if args.time_entry_limit > 0 {
+            context["recent_time_entries"] = match self.api_client.get_issue_time_entries(args.id, Some(args.time_entry_limit), None).await {
+                Ok(response) => json!({
+                    "total_count": response.total_count,
+                    "entries": response.time_entries.iter()
+                        .map(|te| json!({
+                            "spent_on": te.spent_on,
+                            "hours": te.hours,
+                            "user": te.user.name,
+                            "activity": te.activity.name,
+                            "comments": te.comments
+                        }))
+                        .collect::<Vec<_>>()
+                }),
+                Err(e) => {
+                    error!("Chyba při získávání časových záznamů úkolu {}: {}", args.id, e);
+                    json!({"error": format!("{}: {}", i18n::messages().error_fetching_time_entries, e)})
+                }
+            };
+        }
+        
+        let context_json = serde_json::to_string_pretty(&context)?;
+        info!("Úspěšně získán kontext úkolu: {} (ID: {})", issue.subject, issue.id);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().issue_context)(&issue.subject, issue.id),
+                context_json
+            ))
+        ]))
+    }
+}
+
+// === BULK EDIT DESCRIPTIONS TOOL ===
+
+pub struct BulkEditDescriptionsTool {
+    api_client: EasyProjectClient,
+}
+
+impl BulkEditDescriptionsTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BulkEditDescriptionsArgs {
+    project_id: i32,
+    find: String,
+    replace: String,
+    #[serde(default)]
+    regex: bool,
+    #[serde(default = "default_true")]
+    case_sensitive: bool,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default = "default_bulk_edit_max_issues")]
+    max_issues: usize,
+    #[serde(default = "default_true")]
+    dry_run: bool,
+    #[serde(default)]
+    confirm_token: Option<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_bulk_edit_max_issues() -> usize {
+    50
+}
+
+/// Plánovaná změna popisu jednoho úkolu
+struct DescriptionEdit {
+    issue: crate::api::Issue,
+    new_description: String,
+    matches: usize,
+}
+
+/// Token náhledu - otisk hledaného výrazu, náhrady a původních popisů dotčených úkolů
+///
+/// Ostrá změna se provede jen s tokenem z aktuálního náhledu, takže se nikdy
+/// neaplikuje na úkoly, jejichž popis se mezitím změnil.
+fn bulk_edit_token(args: &BulkEditDescriptionsArgs, edits: &[DescriptionEdit]) -> String {
+    let mut fingerprint = format!("{}\u{0}{}\u{0}{}\u{0}{}", args.find, args.replace, args.regex, args.case_sensitive);
+    for edit in edits {
+        fingerprint.push_str(&format!("\u{0}{}\u{0}{}", edit.issue.id, edit.issue.description.as_deref().unwrap_or("")));
+    }
+    format!("{:016x}", crate::api::client::fnv1a_64(fingerprint.as_bytes()))
+}
+
+#[async_trait]
+impl ToolExecutor for BulkEditDescriptionsTool {
+    fn name(&self) -> &str {
+        "bulk_edit_descriptions"
+    }
+    
+    fn description(&self) -> &str {
+        "Hromadně nahradí text v popisech úkolů projektu (např. rozbité odkazy nebo přejmenované pojmy). \
+        Vždy nejdříve proveďte náhled (dry_run: true), který ukáže rozdíl pro každý úkol a vrátí confirm_token. \
+        Změny se uloží až voláním s dry_run: false a tímto tokenem."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu, v jehož úkolech se hledá (povinné)"
+            },
+            "find": {
+                "type": "string",
+                "description": "Hledaný text nebo regulární výraz (povinné)"
+            },
+            "replace": {
+                "type": "string",
+                "description": "Náhrada (u regulárního výrazu lze použít skupiny $1, ${name}) (povinné)"
+            },
+            "regex": {
+                "type": "boolean",
+                "description": "Interpretovat 'find' jako regulární výraz (výchozí: false)",
+                "default": false
+            },
+            "case_sensitive": {
+                "type": "boolean",
+                "description": "Rozlišovat velká a malá písmena (výchozí: true)",
+                "default": true
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "max_issues": {
+                "type": "integer",
+                "description": "Maximální počet upravených úkolů; při více shodách se nic neuloží (výchozí: 50)",
+                "minimum": 1,
+                "maximum": 500,
+                "default": 50
+            },
+            "dry_run": {
+                "type": "boolean",
+                "description": "Pouze náhled změn bez uložení (výchozí: true)",
+                "default": true
+            },
+            "confirm_token": {
+                "type": "string",
+                "description": "Token z náhledu, povinný pro dry_run: false"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id", "find", "replace"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: BulkEditDescriptionsArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameters)("project_id, find, replace"))?
+        )?;
+        
+        if args.find.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().empty_parameter)("find"))
+            ]));
+        }
+        
+        let pattern = if args.regex { args.find.clone() } else { regex::escape(&args.find) };
+        let matcher = match regex::RegexBuilder::new(&pattern).case_insensitive(!args.case_sensitive).build() {
+            Ok(matcher) => matcher,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().invalid_regex, e))
+                ]));
+            }
+        };
+        
+        debug!("Hledám '{}' v popisech úkolů projektu {}", args.find, args.project_id);
+        
+        let mut edits = Vec::new();
+        let scan = self.api_client.scan_issues(Some(args.project_id), None, args.include_subprojects, None, |issues| {
+            for issue in issues {
+                let Some(description) = issue.description.as_deref() else { continue };
+                let matches = matcher.find_iter(description).count();
+                if matches == 0 {
+                    continue;
+                }
+                let new_description = if args.regex {
+                    matcher.replace_all(description, args.replace.as_str()).into_owned()
+                } else {
+                    matcher.replace_all(description, regex::NoExpand(&args.replace)).into_owned()
+                };
+                if new_description != description {
+                    edits.push(DescriptionEdit { issue: issue.clone(), new_description, matches });
+                }
+            }
+        }).await;
+        
+        let summary = match scan {
+            Ok(summary) => summary,
+            Err(e) => {
+                error!("Chyba při procházení úkolů projektu {}: {}", args.project_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_scanning_project_issues)(args.project_id), e))
+                ]));
+            }
+        };
+        
+        if edits.is_empty() {
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text((i18n::messages().no_description_matches)(summary.scanned, args.project_id))
+            ]));
+        }
+        
+        edits.sort_by_key(|edit| edit.issue.id);
+        let token = bulk_edit_token(&args, &edits);
+        let max_issues = args.max_issues.clamp(1, 500);
+        
+        if args.dry_run {
+            let messages = i18n::messages();
+            let mut result = format!(
+                "{}\n",
+                (messages.bulk_edit_preview)(edits.len(), edits.iter().map(|edit| edit.matches).sum::<usize>())
+            );
+            if summary.truncated {
+                result.push_str(&format!("{}\n", messages.bulk_edit_scan_truncated));
+            }
+            for edit in &edits {
+                result.push_str(&format!(
+                    "\n#{} {} ({}):\n",
+                    edit.issue.id, edit.issue.subject, (messages.match_count)(edit.matches)
+                ));
+                let old_description = edit.issue.description.as_deref().unwrap_or("");
+                for line in crate::utils::text_utils::line_diff(old_description, &edit.new_description) {
+                    result.push_str(&format!("  {}\n", truncate_text(&line, 300)));
+                }
+            }
+            if edits.len() > max_issues {
+                result.push_str(&format!("\n{}\n", (messages.bulk_edit_too_many)(edits.len(), max_issues)));
+            } else {
+                result.push_str(&format!("\n{}\n", (messages.bulk_edit_confirm_hint)(&token)));
+            }
+            return Ok(CallToolResult::success(vec![ToolResult::text(result)]));
+        }
+        
+        match args.confirm_token.as_deref() {
+            None => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().confirm_token_required.to_string())
+                ]));
+            }
+            Some(confirm_token) if confirm_token != token => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().preview_outdated.to_string())
+                ]));
+            }
+            Some(_) => {}
+        }
+        if edits.len() > max_issues {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().too_many_edits)(edits.len(), max_issues))
+            ]));
+        }
+        
+        let mut updated = Vec::new();
+        let mut failed = Vec::new();
+        for edit in edits {
+            let issue = edit.issue;
+            let issue_data = CreateIssueRequest {
+                issue: CreateIssue {
+                    project_id: issue.project.id,
+                    tracker_id: issue.tracker.id,
+                    status_id: issue.status.id,
+                    priority_id: issue.priority.id,
+                    subject: issue.subject.clone(),
+                    description: Some(edit.new_description),
+                    category_id: issue.category.map(|c| c.id),
+                    fixed_version_id: issue.fixed_version.map(|v| v.id),
+                    assigned_to_id: issue.assigned_to.map(|u| u.id),
+                    parent_issue_id: issue.parent.map(|p| p.id),
+                    estimated_hours: issue.estimated_hours,
+                    start_date: issue.start_date,
+                    due_date: issue.due_date,
+                    done_ratio: issue.done_ratio,
+                    custom_field_values: None,
+                }
+            };
+            
+            match self.api_client.update_issue(issue.id, issue_data).await {
+                Ok(_) => updated.push(format!("#{} {}", issue.id, issue.subject)),
+                Err(e) => {
+                    error!("Chyba při úpravě popisu úkolu {}: {}", issue.id, e);
+                    failed.push(format!("#{} {}: {}", issue.id, issue.subject, e));
+                }
+            }
+        }
+        
+        info!("Hromadná úprava popisů: {} upraveno, {} chyb", updated.len(), failed.len());
+        
+        let messages = i18n::messages();
+        let mut result = format!("{}\n", (messages.descriptions_updated)(updated.len()));
+        for line in &updated {
+            result.push_str(&format!("  ✓ {}\n", line));
+        }
+        if !failed.is_empty() {
+            result.push_str(&format!("\n{}:\n", (messages.failed_edits)(failed.len())));
+            for line in &failed {
+                result.push_str(&format!("  ✗ {}\n", line));
+            }
+        }
+        
+        let result = if updated.is_empty() {
+            CallToolResult::error(vec![ToolResult::text(result)])
+        } else {
+            CallToolResult::success(vec![ToolResult::text(result)])
+        };
+        Ok(result)
+    }
+}
+
+// === EXPORT ISSUES JSONL TOOL ===
+
+pub struct ExportIssuesJsonlTool {
+    api_client: EasyProjectClient,
+    export_dir: std::path::PathBuf,
+}
+
+impl ExportIssuesJsonlTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            export_dir: std::path::PathBuf::from(&config.scheduler.export_dir),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ExportIssuesJsonlArgs {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    status: Option<String>,
+    #[serde(default)]
+    tracker_id: Option<i32>,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    updated_since: Option<NaiveDate>,
+    #[serde(default)]
+    search: Option<String>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    max_records: Option<usize>,
+}
+
+#[async_trait]
+impl ToolExecutor for ExportIssuesJsonlTool {
+    fn name(&self) -> &str {
+        "export_issues_jsonl"
+    }
+    
+    fn description(&self) -> &str {
+        "Exportuje všechny úkoly odpovídající filtru do souboru JSON Lines (jeden úkol na řádek) v exportním adresáři. \
+        Úkoly se načítají postupně po stránkách s dodržením rate limitu, vhodné pro BI nástroje a datové pipeline."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (bez zadání všechny projekty)"
+            },
+            "status": {
+                "type": "string",
+                "description": "Stav úkolů: 'open', 'closed', '*' (všechny) nebo ID stavu (výchozí: '*')",
+                "default": "*"
+            },
+            "tracker_id": {
+                "type": "integer",
+                "description": "ID trackeru"
+            },
+            "assigned_to_id": {
+                "type": "integer",
+                "description": "ID přiřazeného uživatele"
+            },
+            "updated_since": {
+                "type": "string",
+                "format": "date",
+                "description": "Jen úkoly změněné od data (YYYY-MM-DD) - pro přírůstkový export"
+            },
+            "search": {
+                "type": "string",
+                "description": "Fulltextové vyhledávání (easy_query_q)"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "max_records": {
+                "type": "integer",
+                "description": "Maximální počet exportovaných úkolů (bez zadání všechny)",
+                "minimum": 1
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        use tokio::io::AsyncWriteExt;
+        
+        let args: ExportIssuesJsonlArgs = serde_json::from_value(arguments.unwrap_or(json!({})))?;
+        
+        let query = IssueQuery {
+            project_id: args.project_id,
+            status_id: Some(args.status.unwrap_or_else(|| "*".to_string())),
+            tracker_id: args.tracker_id,
+            assigned_to_id: args.assigned_to_id,
+            updated_since: args.updated_since.map(|date| date.format("%Y-%m-%d").to_string()),
+            easy_query_q: args.search,
+            include_subprojects: args.include_subprojects,
+            include: None,
+        };
+        let max_records = args.max_records.unwrap_or(usize::MAX);
+        
+        let file_name = format!(
+            "issues-{}_{}.jsonl",
+            args.project_id.map(|id| id.to_string()).unwrap_or_else(|| "all".to_string()),
+            chrono::Local::now().format("%Y-%m-%d_%H%M%S")
+        );
+        let path = self.export_dir.join(&file_name);
+        
+        if let Err(e) = tokio::fs::create_dir_all(&self.export_dir).await {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_export_dir)(&self.export_dir.display().to_string()), e))
+            ]));
+        }
+        let file = match tokio::fs::File::create(&path).await {
+            Ok(file) => file,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_file)(&path.display().to_string()), e))
+                ]));
+            }
+        };
+        let mut writer = tokio::io::BufWriter::new(file);
+        
+        debug!("Exportuji úkoly do {}", path.display());
+        
+        let mut pager = self.api_client.issue_pages(&query);
+        let mut exported = 0;
+        while exported < max_records {
+            let issues = match pager.next_page().await {
+                Ok(Some(issues)) => issues,
+                Ok(None) => break,
+                Err(e) => {
+                    error!("Chyba při exportu úkolů po {} záznamech: {}", exported, e);
+                    writer.flush().await?;
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().export_interrupted)(
+                            &e.to_string(), &path.display().to_string(), exported
+                        ))
+                    ]));
+                }
+            };
+            
+            for issue in issues.iter().take(max_records - exported) {
+                let mut line = serde_json::to_vec(issue)?;
+                line.push(b'\n');
+                writer.write_all(&line).await?;
+                exported += 1;
+            }
+        }
+        writer.flush().await?;
+        
+        info!("Exportováno {} úkolů do {}", exported, path.display());
+        
+        let total = pager.total_count().unwrap_or(exported);
+        let messages = i18n::messages();
+        let mut result = format!("{}\n", (messages.issues_exported)(exported, &path.display().to_string()));
+        if exported < total {
+            result.push_str(&format!("{}\n", (messages.export_limited)(exported, total)));
+        }
+        
+        Ok(CallToolResult::success(vec![ToolResult::text(result)]))
+    }
+}
+
+// === SEARCH ISSUES TOOL ===
+
+/// Nejvyšší počet úkolů načtených z API pro seřazení podle relevance
+const MAX_SEARCH_CANDIDATES: usize = 200;
+
+pub struct SearchIssuesTool {
+    api_client: EasyProjectClient,
+}
+
+impl SearchIssuesTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchIssuesArgs {
+    query: String,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default = "default_open_only")]
+    open_only: bool,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default = "default_search_limit")]
+    limit: usize,
+}
+
+fn default_open_only() -> bool {
+    true
+}
+
+fn default_search_limit() -> usize {
+    20
+}
+
+#[async_trait]
+impl ToolExecutor for SearchIssuesTool {
+    fn name(&self) -> &str {
+        "search_issues"
+    }
+    
+    fn description(&self) -> &str {
+        "Fulltextové vyhledávání úkolů v názvu a popisu. Vrací stručný seznam seřazený podle relevance \
+        (ID, název, stav, řešitel). Číslo úkolu (např. '#123') najde úkol přímo. \
+        Pro filtrování podle stavu, trackeru nebo priority použijte list_issues."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "query": {
+                "type": "string",
+                "description": "Hledaný text (slova se hledají bez ohledu na diakritiku a velikost písmen)"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "Hledat jen v projektu (nepovinné)"
+            },
+            "open_only": {
+                "type": "boolean",
+                "description": "Jen otevřené úkoly (výchozí: true)",
+                "default": true
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Při zadaném project_id hledat i v podprojektech (výchozí: podle nastavení EasyProject instance)"
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximální počet výsledků (výchozí: 20)",
+                "minimum": 1,
+                "maximum": 100,
+                "default": 20
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["query"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: SearchIssuesArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("query"))?
+        )?;
+        
+        let query = args.query.trim();
+        if query.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().empty_parameter)("query"))
+            ]));
+        }
+        let limit = args.limit.clamp(1, 100);
+        
+        debug!("Vyhledávám úkoly: '{}' (projekt {:?})", query, args.project_id);
+        
+        let mut issues: Vec<Issue> = Vec::new();
+        
+        // Číslo úkolu - přímá shoda má přednost před fulltextem
+        if let Ok(id) = query.trim_start_matches('#').parse::<i32>() {
+            if let Ok(response) = self.api_client.get_issue(id, None).await {
+                issues.push(response.issue);
+            }
+        }
+        let direct_match = issues.first().map(|issue| issue.id);
+        
+        let search = IssueQuery {
+            project_id: args.project_id,
+            status_id: Some(if args.open_only { "open" } else { "*" }.to_string()),
+            easy_query_q: Some(query.to_string()),
+            include_subprojects: args.include_subprojects,
+            ..Default::default()
+        };
+        let mut pager = self.api_client.issue_pages(&search);
+        while issues.len() < MAX_SEARCH_CANDIDATES {
+            match pager.next_page().await {
+                Ok(Some(page)) => issues.extend(page.into_iter().filter(|issue| Some(issue.id) != direct_match)),
+                Ok(None) => break,
+                Err(e) => {
+                    error!("Chyba při vyhledávání úkolů: {}", e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
+                    ]));
+                }
+            }
+        }
+        let total = pager.total_count();
+        
+        // Řazení podle relevance, při shodě novější změna dříve
+        let mut ranked: Vec<(u32, Issue)> = issues.into_iter()
+            .map(|issue| {
+                let score = if Some(issue.id) == direct_match {
+                    u32::MAX
+                } else {
+                    match_score(&issue.subject, issue.description.as_deref(), query)
+                };
+                (score, issue)
+            })
+            .collect();
+        ranked.sort_by(|(score_a, a), (score_b, b)| score_b.cmp(score_a).then(b.updated_on.cmp(&a.updated_on)));
+        ranked.truncate(limit);
+        
+        let results: Vec<Value> = ranked.iter()
+            .map(|(score, issue)| json!({
+                "id": issue.id,
+                "subject": issue.subject,
+                "status": issue.status.name,
+                "assigned_to": issue.assigned_to.as_ref().map(|user| user.name.clone()),
+                "project": issue.project.name,
+                "score": if *score == u32::MAX { None } else { Some(*score) }
+            }))
+            .collect();
+        
+        let messages = i18n::messages();
+        let mut output = (messages.found_issues_for)(results.len(), query);
+        if let Some(total) = total.filter(|total| *total > results.len()) {
+            output.push_str(&(messages.of_matching)(total));
+        }
+        output.push_str(":\n\n");
+        for (_, issue) in &ranked {
+            output.push_str(&format!(
+                "#{} [{}] {} - {} ({})\n",
+                issue.id,
+                issue.status.name,
+                issue.subject,
+                issue.assigned_to.as_ref().map(|user| user.name.as_str()).unwrap_or(messages.unassigned),
+                issue.project.name
+            ));
+        }
+        
+        info!("Vyhledávání '{}': {} výsledků", query, results.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&json!({ "query": query, "total_count": total, "issues": results })))
+    }
+}
+
+// === CLONE ISSUE TOOL ===
+
+/// Nejvyšší počet kopírovaných podúkolů (včetně vnořených) v jednom volání clone_issue
+const MAX_CLONED_SUBTASKS: usize = 100;
+
+pub struct CloneIssueTool {
+    api_client: EasyProjectClient,
+    max_attachment_bytes: u64,
+}
+
+impl CloneIssueTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self { api_client, max_attachment_bytes: config.tools.issues.max_attachment_bytes }
+    }
+    
+    /// Vytvoří kopii úkolu a podle voleb přenese přílohy a sledující
+    ///
+    /// Chyby při přenosu příloh a sledujících úkol nezastaví, vrací se jako upozornění.
+    async fn copy_issue(&self, source: &Issue, args: &CloneIssueArgs, target_project_id: i32, parent_issue_id: Option<i32>, top_level: bool, warnings: &mut Vec<String>) -> Result<Issue, crate::api::ApiError> {
+        let same_project = target_project_id == source.project.id;
+        
+        // Zadaný milník ověřuje execute; převzatý z jiného projektu nemusí být v cílovém dostupný
+        let version_override = args.fixed_version_id.filter(|_| top_level);
+        let mut fixed_version_id = version_override.or(source.fixed_version.as_ref().map(|version| version.id));
+        if let Some(version_id) = fixed_version_id.filter(|_| version_override.is_none() && !same_project) {
+            if self.api_client.validate_version_assignable(target_project_id, version_id).await.is_err() {
+                warnings.push((i18n::messages().clone_version_unavailable)(source.id, version_id));
+                fixed_version_id = None;
+            }
+        }
+        
+        let custom_field_values = if args.copy_custom_fields {
+            source.custom_fields.as_ref().map(|fields| fields.iter()
+                .filter(|field| !field.value.is_null())
+                .map(|field| (field.id.to_string(), field.value.clone()))
+                .collect::<BTreeMap<String, Value>>())
+                .filter(|values| !values.is_empty())
+        } else {
+            None
+        };
+        
+        let (subject, assigned_to_id) = if top_level {
+            (
+                args.subject.clone().unwrap_or_else(|| source.subject.clone()),
+                args.assigned_to_id.or(source.assigned_to.as_ref().map(|user| user.id)),
+            )
+        } else {
+            (source.subject.clone(), source.assigned_to.as_ref().map(|user| user.id))
+        };
+        
+        let issue_data = CreateIssueRequest {
+            issue: CreateIssue {
+                project_id: target_project_id,
+                tracker_id: source.tracker.id,
+                status_id: source.status.id,
+                priority_id: source.priority.id,
+                subject,
+                description: if args.copy_description { source.description.clone() } else { None },
+                category_id: source.category.as_ref().filter(|_| same_project).map(|category| category.id),
+                fixed_version_id,
+                assigned_to_id,
+                parent_issue_id: parent_issue_id.or(source.parent.as_ref().filter(|_| same_project && top_level).map(|parent| parent.id)),
+                estimated_hours: source.estimated_hours,
+                start_date: source.start_date,
+                due_date: source.due_date,
+                done_ratio: None,
+                custom_field_values,
+            }
+        };
+        let copy = self.api_client.create_issue(issue_data).await?.issue;
+        
+        if args.copy_watchers {
+            for watcher in source.watchers.iter().flatten() {
+                if let Err(e) = self.api_client.add_watcher(copy.id, watcher.id).await {
+                    warnings.push(format!("{}: {}", (i18n::messages().clone_watcher_failed)(copy.id, &watcher.name), e));
+                }
+            }
+        }
+        
+        if args.copy_attachments {
+            let mut uploads = Vec::new();
+            for attachment in source.attachments.iter().flatten() {
+                let uploaded = match self.api_client.download_attachment(attachment, self.max_attachment_bytes).await {
+                    Ok(content) => self.api_client.upload_file(&attachment.filename, content).await,
+                    Err(e) => Err(e),
+                };
+                match uploaded {
+                    Ok(token) => uploads.push(IssueUpload {
+                        token,
+                        filename: attachment.filename.clone(),
+                        content_type: attachment.content_type.clone(),
+                        description: attachment.description.clone(),
+                    }),
+                    Err(e) => warnings.push(format!("{}: {}", (i18n::messages().clone_attachment_failed)(copy.id, &attachment.filename), e)),
+                }
+            }
+            if !uploads.is_empty() {
+                if let Err(e) = self.api_client.attach_to_issue(copy.id, uploads, None).await {
+                    warnings.push(format!("{}: {}", (i18n::messages().clone_attach_failed)(copy.id), e));
+                }
+            }
+        }
+        
+        Ok(copy)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CloneIssueArgs {
+    id: i32,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    subject: Option<String>,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    fixed_version_id: Option<i32>,
+    #[serde(default = "default_true")]
+    copy_description: bool,
+    #[serde(default = "default_true")]
+    copy_custom_fields: bool,
+    #[serde(default)]
+    copy_attachments: bool,
+    #[serde(default)]
+    copy_watchers: bool,
+    #[serde(default)]
+    copy_subtasks: bool,
+}
+
+#[async_trait]
+impl ToolExecutor for CloneIssueTool {
+    fn name(&self) -> &str {
+        "clone_issue"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        ISSUE_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Vytvoří kopii existujícího úkolu ve stejném nebo jiném projektu. Volitelně přenese popis, vlastní pole, \
+        přílohy, sledující a podúkoly; název, řešitele a milník lze u kopie změnit."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID kopírovaného úkolu (povinné)"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "Cílový projekt (výchozí: projekt původního úkolu)"
+            },
+            "subject": {
+                "type": "string",
+                "description": "Název kopie (výchozí: název původního úkolu)"
+            },
+            "assigned_to_id": {
+                "type": "integer",
+                "description": "Řešitel kopie (výchozí: řešitel původního úkolu)"
+            },
+            "fixed_version_id": {
+                "type": "integer",
+                "description": "Milník kopie (výchozí: milník původního úkolu, pokud je v cílovém projektu dostupný)"
+            },
+            "copy_description": {
+                "type": "boolean",
+                "description": "Zkopírovat popis (výchozí: true)",
+                "default": true
+            },
+            "copy_custom_fields": {
+                "type": "boolean",
+                "description": "Zkopírovat hodnoty vlastních polí (výchozí: true)",
+                "default": true
+            },
+            "copy_attachments": {
+                "type": "boolean",
+                "description": "Zkopírovat přílohy do velikosti tools.issues.max_attachment_bytes (výchozí: false)",
+                "default": false
+            },
+            "copy_watchers": {
+                "type": "boolean",
+                "description": "Přidat stejné sledující (výchozí: false)",
+                "default": false
+            },
+            "copy_subtasks": {
+                "type": "boolean",
+                "description": "Zkopírovat i podúkoly včetně vnořených (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CloneIssueArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        debug!("Kopíruji úkol {} (projekt {:?})", args.id, args.project_id);
+        
+        let include = || Some(vec!["attachments".to_string(), "watchers".to_string(), "children".to_string()]);
+        let source = match self.api_client.get_issue(args.id, include()).await {
+            Ok(response) => response.issue,
+            Err(e) => {
+                error!("Chyba při získávání úkolu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_issue)(args.id), e))
+                ]));
+            }
+        };
+        let target_project_id = args.project_id.unwrap_or(source.project.id);
+        
+        if let Some(version_id) = args.fixed_version_id {
+            if let Err(e) = self.api_client.validate_version_assignable(target_project_id, version_id).await {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_copying_issue)(args.id), e))
+                ]));
+            }
+        }
+        
+        let mut warnings = Vec::new();
+        let copy = match self.copy_issue(&source, &args, target_project_id, None, true, &mut warnings).await {
+            Ok(copy) => copy,
+            Err(e) => {
+                error!("Chyba při kopírování úkolu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_copying_issue)(args.id), e))
+                ]));
+            }
+        };
+        
+        // Podúkoly do šířky - dvojice (ID původního podúkolu, ID nadřazené kopie)
+        let mut copied_subtasks: Vec<Value> = Vec::new();
+        if args.copy_subtasks {
+            let mut pending: std::collections::VecDeque<(i32, i32)> = source.children.iter().flatten()
+                .map(|child| (child.id, copy.id))
+                .collect();
+            while let Some((child_id, parent_id)) = pending.pop_front() {
+                if copied_subtasks.len() >= MAX_CLONED_SUBTASKS {
+                    warnings.push((i18n::messages().clone_subtask_limit)(MAX_CLONED_SUBTASKS, pending.len() + 1));
+                    break;
+                }
+                let child = match self.api_client.get_issue(child_id, include()).await {
+                    Ok(response) => response.issue,
+                    Err(e) => {
+                        warnings.push(format!("{}: {}", (i18n::messages().clone_subtask_unreadable)(child_id), e));
+                        continue;
+                    }
+                };
+                match self.copy_issue(&child, &args, target_project_id, Some(parent_id), false, &mut warnings).await {
+                    Ok(child_copy) => {
+                        pending.extend(child.children.iter().flatten().map(|grandchild| (grandchild.id, child_copy.id)));
+                        copied_subtasks.push(json!({ "source_id": child.id, "id": child_copy.id, "subject": child_copy.subject, "parent_id": parent_id }));
+                    }
+                    Err(e) => warnings.push(format!("{}: {}", (i18n::messages().clone_subtask_failed)(child_id), e)),
+                }
+            }
+        }
+        
+        info!("Úkol {} zkopírován jako {} ({} podúkolů)", args.id, copy.id, copied_subtasks.len());
+        
+        let messages = i18n::messages();
+        let mut output = (messages.issue_cloned)(source.id, copy.id, &copy.subject, &copy.project.name);
+        if !copied_subtasks.is_empty() {
+            output.push_str(&(messages.including_subtasks)(copied_subtasks.len()));
+        }
+        output.push('.');
+        if !warnings.is_empty() {
+            output.push_str(&format!("\n\n{}:\n", messages.warnings));
+            for warning in &warnings {
+                output.push_str(&format!("- {}\n", warning));
+            }
+        }
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&json!({
+            "source_id": source.id,
+            "issue": copy,
+            "subtasks": copied_subtasks,
+            "warnings": warnings
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+
+    async fn test_config(base_url: &str) -> (EasyProjectClient, AppConfig) {
+        let mut config = AppConfig::default();
+        config.easyproject.base_url = base_url.to_string();
+        config.easyproject.api_key = Some("test".to_string());
+        config.cache.enabled = false;
+        config.tools.issues.max_attachment_bytes = 16;
+        (EasyProjectClient::new(&config).await.unwrap(), config)
+    }
+
+    fn error_text(result: &CallToolResult) -> &str {
+        assert_eq!(result.is_error, Some(true));
+        match &result.content[0] {
+            ToolResult::Text { text } => text,
+            other => panic!("Neočekávaný výsledek: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_is_text_content_type() {
+        assert!(is_text_content_type("text/plain"));
+        assert!(is_text_content_type("application/json"));
+        assert!(is_text_content_type("application/xml"));
+        assert!(!is_text_content_type("application/pdf"));
+        assert!(!is_text_content_type("image/png"));
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_content_kinds() {
+        let mut server = mockito::Server::new_async().await;
+        for (id, filename, content_type) in [(1, "a.txt", "text/plain"), (2, "b.png", "image/png"), (3, "c.pdf", "application/pdf")] {
+            server.mock("GET", format!("/attachments/{}.json", id).as_str())
+                .with_header("content-type", "application/json")
+                .with_body(json!({ "attachment": { "id": id, "filename": filename, "content_type": content_type } }).to_string())
+                .create_async().await;
+            server.mock("GET", format!("/attachments/download/{}/{}", id, filename).as_str())
+                .with_body("abc")
+                .create_async().await;
+        }
+        let (client, config) = test_config(&server.url()).await;
+        let tool = DownloadAttachmentTool::new(client, config);
+
+        let text = tool.execute(Some(json!({ "id": 1 }))).await.unwrap();
+        assert!(matches!(&text.content[1], ToolResult::Resource { resource }
+            if resource.text.as_deref() == Some("abc") && resource.blob.is_none()));
+
+        let image = tool.execute(Some(json!({ "id": 2 }))).await.unwrap();
+        assert!(matches!(&image.content[1], ToolResult::Image { data, mime_type }
+            if data == "YWJj" && mime_type == "image/png"));
+
+        let binary = tool.execute(Some(json!({ "id": 3 }))).await.unwrap();
+        assert!(matches!(&binary.content[1], ToolResult::Resource { resource }
+            if resource.blob.as_deref() == Some("YWJj") && resource.mime_type.as_deref() == Some("application/pdf")));
+    }
+
+    #[tokio::test]
+    async fn test_download_attachment_missing() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/attachments/5.json").with_status(404).create_async().await;
+        let (client, config) = test_config(&server.url()).await;
+
+        let result = DownloadAttachmentTool::new(client, config).execute(Some(json!({ "id": 5 }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Chyba při získávání přílohy 5"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_invalid_content() {
+        let (client, config) = test_config("http://localhost:1").await;
+        let tool = UploadAttachmentTool::new(client, config);
+
+        let result = tool.execute(Some(json!({ "id": 1, "filename": "a.txt" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Zadejte právě jeden"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "filename": "a.txt", "content": "a", "content_base64": "YQ==" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Zadejte právě jeden"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "filename": "a.bin", "content_base64": "není base64" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Neplatný base64 obsah"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "filename": "a.txt", "content": "x".repeat(17) }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Obsah má 17 B, povolené maximum je 16 B"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "content": "a" }))).await.unwrap();
+        assert_eq!(error_text(&result), "Chybí povinný parametr 'filename'");
+
+        let result = tool.execute(Some(json!({ "id": 1, "file_path": "a.txt" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Nahrávání souborů z disku je vypnuté"));
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_file_path_restrictions() {
+        let dir = std::env::temp_dir().join(format!("easyproject-mcp-upload-{}", uuid::Uuid::new_v4()));
+        let root = dir.join("uploads");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(dir.join("outside.txt"), "tajné").unwrap();
+        std::fs::write(root.join("large.txt"), "x".repeat(17)).unwrap();
+
+        let (client, mut config) = test_config("http://localhost:1").await;
+        config.tools.issues.upload_dir = Some(root.to_string_lossy().to_string());
+        let tool = UploadAttachmentTool::new(client, config);
+
+        let result = tool.execute(Some(json!({ "id": 1, "file_path": "../outside.txt" }))).await.unwrap();
+        assert!(error_text(&result).contains("leží mimo povolený adresář"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "file_path": "large.txt" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Soubor large.txt má 17 B"));
+
+        let result = tool.execute(Some(json!({ "id": 1, "file_path": "missing.txt" }))).await.unwrap();
+        assert!(error_text(&result).starts_with("Soubor missing.txt není dostupný"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_from_file() {
+        let root = std::env::temp_dir().join(format!("easyproject-mcp-upload-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("notes.txt"), "poznámky").unwrap();
+
+        let mut server = mockito::Server::new_async().await;
+        let upload = server.mock("POST", "/uploads.json")
+            .match_query(mockito::Matcher::UrlEncoded("filename".to_string(), "notes.txt".to_string()))
+            .match_body("poznámky")
+            .with_status(201)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"upload":{"token":"1.token"}}"#)
+            .create_async().await;
+        let attach = server.mock("PUT", "/issues/3.json")
+            .match_body(mockito::Matcher::PartialJson(json!({ "issue": { "uploads": [{ "token": "1.token", "filename": "notes.txt" }] } })))
+            .with_status(204)
+            .create_async().await;
+
+        let (client, mut config) = test_config(&server.url()).await;
+        config.tools.issues.upload_dir = Some(root.to_string_lossy().to_string());
+        let result = UploadAttachmentTool::new(client, config)
+            .execute(Some(json!({ "id": 3, "file_path": "notes.txt" }))).await.unwrap();
+
+        assert_ne!(result.is_error, Some(true));
+        upload.assert_async().await;
+        attach.assert_async().await;
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
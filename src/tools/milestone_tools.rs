@@ -4,9 +4,13 @@ use serde_json::{json, Value};
 use tracing::{debug, error, info};
 
 use crate::api::EasyProjectClient;
-use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult, PaginationInfo};
 use super::executor::ToolExecutor;
 
+/// Aliasy parametru `id` pro tools pracující s jedním milníkem
+const MILESTONE_ID_ALIASES: &[(&str, &str)] = &[("milestone_id", "id"), ("version_id", "id")];
+
 // === LIST MILESTONES TOOL ===
 
 pub struct ListMilestonesTool {
@@ -47,9 +51,8 @@ impl ToolExecutor for ListMilestonesTool {
         json!({
             "limit": {
                 "type": "integer",
-                "description": "Maximální počet milníků k vrácení (výchozí: 25, maximum: 100)",
-                "minimum": 1,
-                "maximum": 100
+                "description": "Maximální počet milníků k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)",
+                "minimum": 1
             },
             "offset": {
                 "type": "integer", 
@@ -72,6 +75,10 @@ impl ToolExecutor for ListMilestonesTool {
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: ListMilestonesArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -98,19 +105,126 @@ impl ToolExecutor for ListMilestonesTool {
                 let milestones_json = serde_json::to_string_pretty(&response)?;
                 info!("Úspěšně získáno {} milníků", response.versions.len());
                 
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.versions.len(),
+                ).with_requested_limit(args.limit);
+                
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Nalezeno {} milníků (celkem: {}):\n\n{}",
-                        response.versions.len(),
-                        response.total_count.unwrap_or(response.versions.len() as i32),
+                        "{}:\n\n{}",
+                        (i18n::messages().found_milestones_total)(
+                            response.versions.len(),
+                            response.total_count.unwrap_or(response.versions.len() as i32)
+                        ),
                         milestones_json
                     ))
-                ]))
+                ]).with_pagination(pagination))
             }
             Err(e) => {
                 error!("Chyba při získávání milníků: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání milníků: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_milestones, e))
+                ]))
+            }
+        }
+    }
+}
+
+// === LIST ASSIGNABLE VERSIONS TOOL ===
+
+pub struct ListAssignableVersionsTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListAssignableVersionsTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAssignableVersionsArgs {
+    project_id: i32,
+}
+
+#[async_trait]
+impl ToolExecutor for ListAssignableVersionsTool {
+    fn name(&self) -> &str {
+        "list_assignable_versions"
+    }
+    
+    fn description(&self) -> &str {
+        "Vrátí verze (milníky), které lze nastavit jako fixed_version_id úkolům v daném projektu - \
+        otevřené verze projektu i verze sdílené z jiných projektů"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu úkolu (povinné)"
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListAssignableVersionsArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_id"))?
+        )?;
+        
+        debug!("Získávám přiřaditelné verze pro projekt {}", args.project_id);
+        
+        match self.api_client.list_project_versions(args.project_id).await {
+            Ok(response) => {
+                let assignable: Vec<_> = response.versions.iter()
+                    .filter(|v| v.status.as_deref().unwrap_or("open") == "open")
+                    .collect();
+                
+                let messages = i18n::messages();
+                let mut result = format!(
+                    "{}:\n\n",
+                    (messages.assignable_versions)(args.project_id, assignable.len(), response.versions.len())
+                );
+                
+                if assignable.is_empty() {
+                    result.push_str(messages.no_assignable_versions);
+                    result.push('\n');
+                }
+                
+                for version in &assignable {
+                    let owner = match version.project {
+                        Some(ref project) if project.id != args.project_id => {
+                            format!(", {}", (messages.version_shared_from)(&project.name, project.id, version.sharing.as_deref().unwrap_or("none")))
+                        }
+                        _ => String::new(),
+                    };
+                    let due = version.effective_date.or(version.due_date)
+                        .map(|d| format!(", {}: {}", messages.due_on, d))
+                        .unwrap_or_default();
+                    result.push_str(&format!("- {} = {}{}{}\n", version.id, version.name, due, owner));
+                }
+                
+                info!("Vráceno {} přiřaditelných verzí pro projekt {}", assignable.len(), args.project_id);
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(result)
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při získávání verzí projektu {}: {}", args.project_id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project_versions)(args.project_id), e))
                 ]))
             }
         }
@@ -140,6 +254,10 @@ impl ToolExecutor for GetMilestoneTool {
         "get_milestone"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        MILESTONE_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá detail konkrétního milníku podle ID"
     }
@@ -153,9 +271,17 @@ impl ToolExecutor for GetMilestoneTool {
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetMilestoneArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Získávám milník s ID: {}", args.id);
@@ -167,8 +293,8 @@ impl ToolExecutor for GetMilestoneTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Detail milníku '{}':\n\n{}",
-                        response.version.name,
+                        "{}:\n\n{}",
+                        (i18n::messages().milestone_detail)(&response.version.name),
                         milestone_json
                     ))
                 ]))
@@ -176,7 +302,7 @@ impl ToolExecutor for GetMilestoneTool {
             Err(e) => {
                 error!("Chyba při získávání milníku {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání milníku {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_milestone)(args.id), e))
                 ]))
             }
         }
@@ -270,9 +396,13 @@ impl ToolExecutor for CreateMilestoneTool {
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id", "name"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: CreateMilestoneArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro vytvoření milníku")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Vytvářím milník s názvem: {}", args.name);
@@ -294,9 +424,8 @@ impl ToolExecutor for CreateMilestoneTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Milník '{}' byl úspěšně vytvořen s ID {}:\n\n{}",
-                        response.version.name,
-                        response.version.id,
+                        "{}:\n\n{}",
+                        (i18n::messages().milestone_created)(&response.version.name, response.version.id),
                         milestone_json
                     ))
                 ]))
@@ -304,8 +433,8 @@ impl ToolExecutor for CreateMilestoneTool {
             Err(e) => {
                 error!("Chyba při vytváření milníku: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při vytváření milníku: {}", e))
-                ]))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_creating_milestone, e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
@@ -350,6 +479,10 @@ impl ToolExecutor for UpdateMilestoneTool {
         "update_milestone"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        MILESTONE_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Aktualizuje existující milník"
     }
@@ -399,9 +532,13 @@ impl ToolExecutor for UpdateMilestoneTool {
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: UpdateMilestoneArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro aktualizaci milníku")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Aktualizuji milník s ID: {}", args.id);
@@ -423,8 +560,8 @@ impl ToolExecutor for UpdateMilestoneTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Milník '{}' byl úspěšně aktualizován:\n\n{}",
-                        response.version.name,
+                        "{}:\n\n{}",
+                        (i18n::messages().milestone_updated)(&response.version.name),
                         milestone_json
                     ))
                 ]))
@@ -432,8 +569,8 @@ impl ToolExecutor for UpdateMilestoneTool {
             Err(e) => {
                 error!("Chyba při aktualizaci milníku {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při aktualizaci milníku {}: {}", args.id, e))
-                ]))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_milestone)(args.id), e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
@@ -462,6 +599,10 @@ impl ToolExecutor for DeleteMilestoneTool {
         "delete_milestone"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        MILESTONE_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Smaže existující milník"
     }
@@ -475,9 +616,13 @@ impl ToolExecutor for DeleteMilestoneTool {
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: DeleteMilestoneArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Mažu milník s ID: {}", args.id);
@@ -487,16 +632,13 @@ impl ToolExecutor for DeleteMilestoneTool {
                 info!("Úspěšně smazán milník s ID: {}", args.id);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Milník s ID {} byl úspěšně smazán",
-                        args.id
-                    ))
+                    ToolResult::text((i18n::messages().milestone_deleted)(args.id))
                 ]))
             }
             Err(e) => {
                 error!("Chyba při mazání milníku {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při mazání milníku {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_deleting_milestone)(args.id), e))
                 ]))
             }
         }
@@ -1,12 +1,21 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use std::collections::{HashMap, HashSet};
 use tracing::{debug, error, info};
+use chrono::NaiveDate;
 
-use crate::api::{EasyProjectClient, CreateProjectRequest, CreateProject};
-use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::api::{EasyProjectClient, CreateIssue, CreateIssueRequest, CreateProjectRequest, CreateProject, Issue, IssueCategory, Version};
+use crate::api::client::{IssueListFilter, SearchOptions, FETCH_ALL};
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult, PaginationInfo};
+use crate::utils::formatting::{OutputFormat, format_project, format_project_list};
 use super::executor::ToolExecutor;
 
+/// Aliasy parametru `id` pro tools pracující s jedním projektem
+const PROJECT_ID_ALIASES: &[(&str, &str)] = &[("project_id", "id"), ("project", "id")];
+
 // === LIST PROJECTS TOOL ===
 
 pub struct ListProjectsTool {
@@ -31,6 +40,10 @@ struct ListProjectsArgs {
     search: Option<String>,
     #[serde(default)]
     sort: Option<String>,
+    #[serde(default)]
+    fetch_all: bool,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[async_trait]
@@ -49,15 +62,18 @@ impl ToolExecutor for ListProjectsTool {
         json!({
             "limit": {
                 "type": "integer",
-                "description": "Maximální počet projektů k vrácení (výchozí: 25, maximum: 100)",
-                "minimum": 1,
-                "maximum": 100
+                "description": "Maximální počet projektů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)",
+                "minimum": 1
             },
             "offset": {
                 "type": "integer",
                 "description": "Počet projektů k přeskočení pro stránkování",
                 "minimum": 0
             },
+            "fetch_all": {
+                "type": "boolean",
+                "description": "Načte všechny projekty od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)"
+            },
             "include_archived": {
                 "type": "boolean",
                 "description": "Zda zahrnout archivované projekty (výchozí: false)"
@@ -69,10 +85,19 @@ impl ToolExecutor for ListProjectsTool {
             "sort": {
                 "type": "string",
                 "description": "Řazení výsledků (např. 'name' nebo 'created_on:desc'). Formát: 'pole' nebo 'pole:desc'"
-            }
+            },
+            "format": OutputFormat::schema()
         })
     }
 
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: ListProjectsArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -83,29 +108,46 @@ impl ToolExecutor for ListProjectsTool {
                 include_archived: Some(false),
                 search: None,
                 sort: None,
+                fetch_all: false,
+                format: OutputFormat::default(),
             }
         };
 
         debug!("Získávám seznam projektů s parametry: {:?}", args);
 
-        match self.api_client.list_projects(args.limit, args.offset, args.include_archived, args.search, None, args.sort).await {
+        let result = match self.api_client.list_projects(if args.fetch_all { Some(FETCH_ALL) } else { args.limit }, args.offset, args.include_archived, args.search.clone(), None, args.sort).await {
+            // Fulltext rozlišuje diakritiku - zkusíme shodu bez ní
+            Ok(response) if response.projects.is_empty() => match args.search.as_deref() {
+                Some(search) => self.api_client.match_projects_by_text(search, args.include_archived).await,
+                None => Ok(response),
+            },
+            other => other,
+        };
+
+        match result {
             Ok(response) => {
-                let projects_json = serde_json::to_string_pretty(&response)?;
+                let title = (i18n::messages().found_projects_total)(
+                    response.projects.len(),
+                    response.total_count.unwrap_or(response.projects.len() as i32)
+                );
+                let output = args.format.render(&title, &response, || format_project_list(&response.projects))?;
                 info!("Úspěšně získáno {} projektů", response.projects.len());
                 
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.projects.len(),
+                ).with_requested_limit(args.limit);
+                
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Nalezeno {} projektů (celkem: {}):\n\n{}",
-                        response.projects.len(),
-                        response.total_count.unwrap_or(response.projects.len() as i32),
-                        projects_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response).with_pagination(pagination))
             }
             Err(e) => {
                 error!("Chyba při získávání projektů: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání projektů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_projects, e))
                 ]))
             }
         }
@@ -129,6 +171,8 @@ struct GetProjectArgs {
     id: i32,
     #[serde(default)]
     include: Option<Vec<String>>,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[async_trait]
@@ -137,6 +181,10 @@ impl ToolExecutor for GetProjectTool {
         "get_project"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá detail konkrétního projektu podle ID"
     }
@@ -154,34 +202,44 @@ impl ToolExecutor for GetProjectTool {
                     "type": "string",
                     "enum": ["trackers", "issue_categories", "issue_custom_fields", "enabled_modules", "completed_percent", "journals", "easy_stakeholders"]
                 }
-            }
+            },
+            "format": OutputFormat::schema()
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetProjectArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Získávám projekt s ID: {}", args.id);
         
         match self.api_client.get_project(args.id, args.include).await {
             Ok(response) => {
-                let project_json = serde_json::to_string_pretty(&response.project)?;
+                let title = (i18n::messages().project_detail)(&response.project.name);
+                let output = args.format.render(&title, &response.project, || format_project(&response.project))?;
                 info!("Úspěšně získán projekt: {}", response.project.name);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Detail projektu '{}':\n\n{}",
-                        response.project.name,
-                        project_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response.project))
             }
             Err(e) => {
                 error!("Chyba při získávání projektu {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání projektu {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.id), e))
                 ]))
             }
         }
@@ -278,9 +336,17 @@ impl ToolExecutor for CreateProjectTool {
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::ProjectCreation)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["name"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: CreateProjectArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro vytvoření projektu")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Vytvářím nový projekt: {}", args.name);
@@ -306,9 +372,8 @@ impl ToolExecutor for CreateProjectTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Projekt '{}' byl úspěšně vytvořen s ID {}:\n\n{}",
-                        response.project.name,
-                        response.project.id,
+                        "{}:\n\n{}",
+                        (i18n::messages().project_created)(&response.project.name, response.project.id),
                         project_json
                     ))
                 ]))
@@ -316,8 +381,8 @@ impl ToolExecutor for CreateProjectTool {
             Err(e) => {
                 error!("Chyba při vytváření projektu '{}': {}", args.name, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při vytváření projektu '{}': {}", args.name, e))
-                ]))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_project)(&args.name), e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
@@ -364,6 +429,10 @@ impl ToolExecutor for UpdateProjectTool {
         "update_project"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Aktualizuje existující projekt v EasyProject systému"
     }
@@ -419,9 +488,13 @@ impl ToolExecutor for UpdateProjectTool {
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: UpdateProjectArgs = serde_json::from_value(
-            arguments.ok_or("Chybí argumenty pro aktualizaci projektu")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Aktualizuji projekt s ID: {}", args.id);
@@ -432,7 +505,7 @@ impl ToolExecutor for UpdateProjectTool {
             Err(e) => {
                 error!("Chyba při získávání projektu {}: {}", args.id, e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání projektu {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.id), e))
                 ]));
             }
         };
@@ -458,9 +531,8 @@ impl ToolExecutor for UpdateProjectTool {
                 
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Projekt '{}' (ID: {}) byl úspěšně aktualizován:\n\n{}",
-                        response.project.name,
-                        response.project.id,
+                        "{}:\n\n{}",
+                        (i18n::messages().project_updated)(&response.project.name, response.project.id),
                         project_json
                     ))
                 ]))
@@ -468,8 +540,8 @@ impl ToolExecutor for UpdateProjectTool {
             Err(e) => {
                 error!("Chyba při aktualizaci projektu {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při aktualizaci projektu {}: {}", args.id, e))
-                ]))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_project)(args.id), e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
@@ -498,6 +570,10 @@ impl ToolExecutor for DeleteProjectTool {
         "delete_project"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Smaže projekt z EasyProject systému (POZOR: Tato operace je nevratná!)"
     }
@@ -511,9 +587,17 @@ impl ToolExecutor for DeleteProjectTool {
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Admin)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: DeleteProjectArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Mažu projekt s ID: {}", args.id);
@@ -524,7 +608,7 @@ impl ToolExecutor for DeleteProjectTool {
             Err(e) => {
                 error!("Chyba při získávání projektu {} před smazáním: {}", args.id, e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání projektu {} před smazáním: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project_before_delete)(args.id), e))
                 ]));
             }
         };
@@ -533,20 +617,1031 @@ impl ToolExecutor for DeleteProjectTool {
             Ok(_) => {
                 info!("Úspěšně smazán projekt: {} (ID: {})", project_name, args.id);
                 
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().project_removed)(&project_name, args.id))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při mazání projektu {} ({}): {}", args.id, project_name, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_deleting_project)(args.id, &project_name), e))
+                ]))
+            }
+        }
+    }
+} 
+// === GET PROJECT MODULES TOOL ===
+
+pub struct GetProjectModulesTool {
+    api_client: EasyProjectClient,
+    known_modules: Vec<String>,
+}
+
+impl GetProjectModulesTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            known_modules: config.tools.projects.known_modules,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GetProjectModulesArgs {
+    id: i32,
+}
+
+#[async_trait]
+impl ToolExecutor for GetProjectModulesTool {
+    fn name(&self) -> &str {
+        "get_project_modules"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Vypíše moduly projektu (sledování úkolů, času, dokumenty, wiki...) - povolené i dostupné k povolení"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetProjectModulesArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        debug!("Získávám moduly projektu {}", args.id);
+        
+        let project = match self.api_client.get_project(args.id, Some(vec!["enabled_modules".to_string()])).await {
+            Ok(response) => response.project,
+            Err(e) => {
+                error!("Chyba při získávání projektu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.id), e))
+                ]));
+            }
+        };
+        
+        let enabled = project.enabled_modules.unwrap_or_default();
+        let available: Vec<&String> = self.known_modules.iter()
+            .filter(|name| !enabled.contains(name))
+            .collect();
+        
+        let result = json!({
+            "project": { "id": project.id, "name": project.name },
+            "enabled_modules": enabled,
+            "available_modules": available
+        });
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().project_modules)(&project.name, project.id),
+                serde_json::to_string_pretty(&result)?
+            ))
+        ]))
+    }
+}
+
+// === SET PROJECT MODULES TOOL ===
+
+pub struct SetProjectModulesTool {
+    api_client: EasyProjectClient,
+    known_modules: Vec<String>,
+}
+
+impl SetProjectModulesTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            known_modules: config.tools.projects.known_modules,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SetProjectModulesArgs {
+    id: i32,
+    #[serde(default)]
+    modules: Option<Vec<String>>,
+    #[serde(default)]
+    enable: Vec<String>,
+    #[serde(default)]
+    disable: Vec<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for SetProjectModulesTool {
+    fn name(&self) -> &str {
+        "set_project_modules"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+    
+    fn description(&self) -> &str {
+        "Povolí nebo zakáže moduly projektu (např. time_tracking, documents). \
+        Buď nastaví celý seznam (modules), nebo jednotlivé moduly přidá (enable) či odebere (disable)."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            },
+            "modules": {
+                "type": "array",
+                "description": "Úplný seznam modulů, které mají být povolené (nahradí současný stav)",
+                "items": {
+                    "type": "string"
+                }
+            },
+            "enable": {
+                "type": "array",
+                "description": "Moduly k povolení",
+                "items": {
+                    "type": "string"
+                }
+            },
+            "disable": {
+                "type": "array",
+                "description": "Moduly k zakázání",
+                "items": {
+                    "type": "string"
+                }
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: SetProjectModulesArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        if args.modules.is_none() && args.enable.is_empty() && args.disable.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().modules_change_required.to_string())
+            ]));
+        }
+        
+        debug!("Nastavuji moduly projektu {}", args.id);
+        
+        let include = vec!["trackers".to_string(), "enabled_modules".to_string()];
+        let current_project = match self.api_client.get_project(args.id, Some(include)).await {
+            Ok(response) => response.project,
+            Err(e) => {
+                error!("Chyba při získávání projektu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.id), e))
+                ]));
+            }
+        };
+        let previous = current_project.enabled_modules.clone().unwrap_or_default();
+        
+        // Validace proti známým modulům instance (moduly již povolené na projektu jsou vždy platné)
+        let unknown: Vec<&String> = args.modules.iter().flatten()
+            .chain(&args.enable)
+            .filter(|name| !self.known_modules.contains(name) && !previous.contains(name))
+            .collect();
+        if !unknown.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().unknown_modules)(
+                    &unknown.iter().map(|name| name.as_str()).collect::<Vec<_>>().join(", "),
+                    &self.known_modules.join(", ")
+                ))
+            ]));
+        }
+        
+        let mut modules = args.modules.clone().unwrap_or_else(|| previous.clone());
+        for name in &args.enable {
+            if !modules.contains(name) {
+                modules.push(name.clone());
+            }
+        }
+        modules.retain(|name| !args.disable.contains(name));
+        
+        if modules.iter().all(|name| previous.contains(name)) && previous.iter().all(|name| modules.contains(name)) {
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text((i18n::messages().project_modules_unchanged)(
+                    &current_project.name,
+                    args.id,
+                    &previous.join(", ")
+                ))
+            ]));
+        }
+        
+        let project_data = CreateProjectRequest {
+            project: CreateProject {
+                name: current_project.name.clone(),
+                description: current_project.description,
+                identifier: current_project.identifier,
+                homepage: current_project.homepage,
+                is_public: current_project.is_public,
+                parent_id: current_project.parent.map(|p| p.id),
+                inherit_members: current_project.inherit_members,
+                tracker_ids: current_project.trackers.map(|t| t.into_iter().map(|tr| tr.id).collect()),
+                enabled_module_names: Some(modules.clone()),
+            }
+        };
+        
+        match self.api_client.update_project(args.id, project_data).await {
+            Ok(_) => {
+                let enabled: Vec<&String> = modules.iter().filter(|name| !previous.contains(name)).collect();
+                let disabled: Vec<&String> = previous.iter().filter(|name| !modules.contains(name)).collect();
+                info!("Aktualizovány moduly projektu {} (+{} / -{})", args.id, enabled.len(), disabled.len());
+                
+                let result = json!({
+                    "enabled_modules": modules,
+                    "newly_enabled": enabled,
+                    "disabled": disabled
+                });
                 Ok(CallToolResult::success(vec![
                     ToolResult::text(format!(
-                        "Projekt '{}' (ID: {}) byl úspěšně smazán.",
-                        project_name,
-                        args.id
+                        "{}:\n\n{}",
+                        (i18n::messages().project_modules_updated)(&current_project.name, args.id),
+                        serde_json::to_string_pretty(&result)?
                     ))
                 ]))
             }
             Err(e) => {
-                error!("Chyba při mazání projektu {} ({}): {}", args.id, project_name, e);
+                error!("Chyba při aktualizaci modulů projektu {}: {}", args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_project_modules)(args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === LIST NEWS TOOL ===
+
+pub struct ListNewsTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListNewsTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ListNewsArgs {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[async_trait]
+impl ToolExecutor for ListNewsTool {
+    fn name(&self) -> &str {
+        "list_news"
+    }
+
+    fn description(&self) -> &str {
+        "Získá novinky (oznámení) projektů od nejnovějších - pro přehled nedávných událostí v reportech a dashboardech"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (výchozí: novinky všech projektů)"
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximální počet novinek k vrácení (výchozí: 25)",
+                "minimum": 1
+            },
+            "offset": {
+                "type": "integer",
+                "description": "Počet novinek k přeskočení pro stránkování",
+                "minimum": 0
+            }
+        })
+    }
+
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListNewsArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => ListNewsArgs::default(),
+        };
+
+        debug!("Získávám novinky s parametry: {:?}", args);
+
+        match self.api_client.list_news(args.project_id, args.limit, args.offset).await {
+            Ok(response) => {
+                let news_json = serde_json::to_string_pretty(&response)?;
+                info!("Úspěšně získáno {} novinek", response.news.len());
+                
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.news.len(),
+                ).with_requested_limit(args.limit);
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(format!(
+                        "{}:\n\n{}",
+                        (i18n::messages().found_news_total)(
+                            response.news.len(),
+                            response.total_count.unwrap_or(response.news.len() as i32)
+                        ),
+                        news_json
+                    ))
+                ]).with_pagination(pagination))
+            }
+            Err(e) => {
+                error!("Chyba při získávání novinek: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při mazání projektu {} ({}): {}", args.id, project_name, e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_news, e))
                 ]))
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+// === LIST PROJECT FILES TOOL ===
+
+pub struct ListProjectFilesTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListProjectFilesTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListProjectFilesArgs {
+    id: i32,
+}
+
+#[async_trait]
+impl ToolExecutor for ListProjectFilesTool {
+    fn name(&self) -> &str {
+        "list_project_files"
+    }
+
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        PROJECT_ID_ALIASES
+    }
+
+    fn description(&self) -> &str {
+        "Získá soubory projektu (název, velikost, autor, datum nahrání) včetně odkazů ke stažení; \
+        obsah souboru načtete přes download_attachment s ID souboru"
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            }
+        })
+    }
+
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListProjectFilesArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+
+        debug!("Získávám soubory projektu {}", args.id);
+
+        match self.api_client.list_project_files(args.id).await {
+            Ok(response) => {
+                let files: Vec<Value> = response.files.iter()
+                    .map(|file| json!({
+                        "id": file.id,
+                        "filename": file.filename,
+                        "filesize": file.filesize,
+                        "content_type": file.content_type,
+                        "description": file.description,
+                        "author": file.author.as_ref().map(|author| &author.name),
+                        "version": file.version.as_ref().map(|version| &version.name),
+                        "created_on": file.created_on,
+                    }))
+                    .collect();
+                info!("Úspěšně získáno {} souborů projektu {}", files.len(), args.id);
+
+                let mut content = vec![ToolResult::text(format!(
+                    "{}:\n\n{}",
+                    (i18n::messages().project_files)(args.id, files.len()),
+                    serde_json::to_string_pretty(&files)?
+                ))];
+                content.extend(response.files.into_iter()
+                    .filter_map(|file| {
+                        let uri = file.content_url?;
+                        Some(ToolResult::resource_link(
+                            uri,
+                            file.filename,
+                            file.content_type,
+                            file.description.filter(|description| !description.is_empty()),
+                            file.filesize.map(|size| size.max(0) as u64),
+                        ))
+                    }));
+
+                Ok(CallToolResult::success(content))
+            }
+            Err(e) => {
+                error!("Chyba při získávání souborů projektu {}: {}", args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project_files)(args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === GLOBAL SEARCH TOOL ===
+
+/// Typy entit podporované parametrem `types` (názvy odpovídají parametrům /search.json)
+const SEARCH_TYPES: &[&str] = &["issues", "projects", "wiki_pages", "news", "documents"];
+
+pub struct GlobalSearchTool {
+    api_client: EasyProjectClient,
+}
+
+impl GlobalSearchTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GlobalSearchArgs {
+    query: String,
+    #[serde(default)]
+    types: Vec<String>,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    titles_only: bool,
+    #[serde(default)]
+    open_issues: bool,
+    #[serde(default)]
+    limit: Option<u32>,
+    #[serde(default)]
+    offset: Option<u32>,
+}
+
+#[async_trait]
+impl ToolExecutor for GlobalSearchTool {
+    fn name(&self) -> &str {
+        "global_search"
+    }
+
+    fn description(&self) -> &str {
+        "Fulltextové vyhledávání napříč úkoly, projekty, wiki stránkami, novinkami a dokumenty jedním voláním. \
+        Výsledky lze omezit na typy entit a projekt; pro podrobnosti použijte get_issue, get_project apod."
+    }
+
+    fn input_schema(&self) -> Value {
+        json!({
+            "query": {
+                "type": "string",
+                "description": "Hledaný text"
+            },
+            "types": {
+                "type": "array",
+                "items": {
+                    "type": "string",
+                    "enum": SEARCH_TYPES
+                },
+                "description": "Typy entit k prohledání (výchozí: všechny)"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "Hledat jen v projektu a jeho podprojektech (nepovinné)"
+            },
+            "titles_only": {
+                "type": "boolean",
+                "description": "Hledat pouze v názvech (výchozí: false)",
+                "default": false
+            },
+            "open_issues": {
+                "type": "boolean",
+                "description": "Z úkolů vracet jen otevřené (výchozí: false)",
+                "default": false
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximální počet výsledků k vrácení (výchozí: 25)",
+                "minimum": 1
+            },
+            "offset": {
+                "type": "integer",
+                "description": "Počet výsledků k přeskočení pro stránkování",
+                "minimum": 0
+            }
+        })
+    }
+
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+
+    fn is_read_only(&self) -> bool {
+        true
+    }
+
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["query"]
+    }
+
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GlobalSearchArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("query"))?
+        )?;
+
+        let query = args.query.trim();
+        if query.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().empty_parameter)("query"))
+            ]));
+        }
+        if let Some(unknown) = args.types.iter().find(|t| !SEARCH_TYPES.contains(&t.as_str())) {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().unknown_search_type)(unknown, &SEARCH_TYPES.join(", ")))
+            ]));
+        }
+
+        debug!("Globální vyhledávání: '{}' (typy {:?}, projekt {:?})", query, args.types, args.project_id);
+
+        match self.api_client.search(query, SearchOptions {
+            project_id: args.project_id,
+            resource_types: args.types.clone(),
+            titles_only: args.titles_only,
+            open_issues: args.open_issues,
+            limit: args.limit,
+            offset: args.offset,
+        }).await {
+            Ok(response) => {
+                info!("Vyhledávání '{}': {} výsledků", query, response.results.len());
+
+                let mut counts: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+                for result in &response.results {
+                    *counts.entry(result.result_type.as_str()).or_default() += 1;
+                }
+
+                let mut output = (i18n::messages().found_search_results)(
+                    response.results.len(),
+                    query,
+                    response.total_count.unwrap_or(response.results.len() as i32)
+                );
+                if !counts.is_empty() {
+                    let summary: Vec<String> = counts.iter().map(|(t, n)| format!("{}: {}", t, n)).collect();
+                    output.push_str(&format!(" - {}", summary.join(", ")));
+                }
+                output.push_str(":\n\n");
+                for result in &response.results {
+                    output.push_str(&format!("[{}] #{} {}", result.result_type, result.id, result.title));
+                    if let Some(url) = &result.url {
+                        output.push_str(&format!(" - {}", url));
+                    }
+                    output.push('\n');
+                }
+
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.results.len(),
+                ).with_requested_limit(args.limit);
+
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(output)
+                ]).with_structured_content(&response).with_pagination(pagination))
+            }
+            Err(e) => {
+                error!("Chyba při vyhledávání: {}", e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_searching, e))
+                ]))
+            }
+        }
+    }
+}
+
+// === CREATE PROJECT FROM TEMPLATE TOOL ===
+
+pub struct CreateProjectFromTemplateTool {
+    api_client: EasyProjectClient,
+}
+
+impl CreateProjectFromTemplateTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateProjectFromTemplateArgs {
+    template_project_id: i32,
+    name: String,
+    #[serde(default)]
+    identifier: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    parent_id: Option<i32>,
+    #[serde(default)]
+    is_public: Option<bool>,
+    #[serde(default)]
+    start_date: Option<NaiveDate>,
+    #[serde(default = "default_copy_structure")]
+    copy_versions: bool,
+    #[serde(default = "default_copy_structure")]
+    copy_categories: bool,
+    #[serde(default)]
+    copy_issues: bool,
+    #[serde(default)]
+    dry_run: bool,
+}
+
+fn default_copy_structure() -> bool {
+    true
+}
+
+/// Posune datum o zadaný počet dní
+fn shift_date(date: Option<NaiveDate>, days: i64) -> Option<NaiveDate> {
+    date.map(|date| date + chrono::Duration::days(days))
+}
+
+#[async_trait]
+impl ToolExecutor for CreateProjectFromTemplateTool {
+    fn name(&self) -> &str {
+        "create_project_from_template"
+    }
+    
+    fn description(&self) -> &str {
+        "Založí nový projekt podle existujícího projektu (šablony) - převezme trackery, moduly, milníky, kategorie \
+        a volitelně otevřené úkoly jako nové úkoly s posunutými termíny. S dry_run jen vrátí plán bez změn."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "template_project_id": {
+                "type": "integer",
+                "description": "ID projektu, který slouží jako šablona (povinné)"
+            },
+            "name": {
+                "type": "string",
+                "description": "Název nového projektu (povinné)"
+            },
+            "identifier": {
+                "type": "string",
+                "description": "Unikátní identifikátor projektu"
+            },
+            "description": {
+                "type": "string",
+                "description": "Popis nového projektu (výchozí: popis šablony)"
+            },
+            "parent_id": {
+                "type": "integer",
+                "description": "ID nadřazeného projektu (výchozí: nadřazený projekt šablony)"
+            },
+            "is_public": {
+                "type": "boolean",
+                "description": "Zda je projekt veřejný (výchozí: podle šablony)"
+            },
+            "start_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Začátek nového projektu (YYYY-MM-DD) - termíny milníků a úkolů se posunou o rozdíl od nejdřívějšího data šablony (výchozí: bez posunu)"
+            },
+            "copy_versions": {
+                "type": "boolean",
+                "description": "Zkopírovat milníky (verze) šablony (výchozí: true)",
+                "default": true
+            },
+            "copy_categories": {
+                "type": "boolean",
+                "description": "Zkopírovat kategorie úkolů (výchozí: true)",
+                "default": true
+            },
+            "copy_issues": {
+                "type": "boolean",
+                "description": "Zkopírovat otevřené úkoly šablony jako nové úkoly (výchozí: false)",
+                "default": false
+            },
+            "dry_run": {
+                "type": "boolean",
+                "description": "Jen vrátit plán kopírování, nic nevytvářet (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::ProjectCreation)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["template_project_id", "name"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CreateProjectFromTemplateArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        let template_id = args.template_project_id;
+        
+        debug!("Vytvářím projekt '{}' ze šablony {}", args.name, template_id);
+        
+        // 1. Struktura šablony
+        let include = vec!["trackers".to_string(), "issue_categories".to_string(), "enabled_modules".to_string()];
+        let template = match self.api_client.get_project(template_id, Some(include)).await {
+            Ok(response) => response.project,
+            Err(e) => {
+                error!("Chyba při získávání šablony {}: {}", template_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(template_id), e))
+                ]));
+            }
+        };
+        
+        // Jen vlastní milníky šablony, sdílené z jiných projektů se nekopírují
+        let versions: Vec<Version> = if args.copy_versions {
+            match self.api_client.list_project_versions(template_id).await {
+                Ok(response) => response.versions.into_iter()
+                    .filter(|version| version.project.as_ref().map(|project| project.id).unwrap_or(template_id) == template_id)
+                    .collect(),
+                Err(e) => {
+                    error!("Chyba při získávání milníků šablony {}: {}", template_id, e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_template_versions)(template_id), e))
+                    ]));
+                }
+            }
+        } else {
+            Vec::new()
+        };
+        let categories: Vec<IssueCategory> = if args.copy_categories {
+            template.issue_categories.clone().unwrap_or_default()
+        } else {
+            Vec::new()
+        };
+        
+        // Otevřené úkoly šablony (bez podprojektů), nadřazené před podúkoly
+        let mut issues: Vec<Issue> = Vec::new();
+        if args.copy_issues {
+            match self.api_client.list_issues(IssueListFilter {
+                project_id: Some(template_id),
+                limit: Some(FETCH_ALL),
+                set_filter: Some(true),
+                sort: Some("id".to_string()),
+                include_subprojects: Some(false),
+                ..Default::default()
+            }).await {
+                Ok(response) => issues = response.issues.into_iter()
+                    .filter(|issue| issue.project.id == template_id)
+                    .collect(),
+                Err(e) => {
+                    error!("Chyba při získávání úkolů šablony {}: {}", template_id, e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
+                    ]));
+                }
+            }
+        }
+        // Rodič mimo kopírované úkoly (uzavřený nebo z podprojektu) se ignoruje - kopie bude bez rodiče
+        let copied: HashSet<i32> = issues.iter().map(|issue| issue.id).collect();
+        let mut placed: HashSet<i32> = HashSet::new();
+        let mut ordered: Vec<Issue> = Vec::with_capacity(issues.len());
+        let mut remaining = issues;
+        while !remaining.is_empty() {
+            let (ready, waiting): (Vec<Issue>, Vec<Issue>) = remaining.into_iter().partition(|issue| {
+                issue.parent.as_ref()
+                    .map(|parent| !copied.contains(&parent.id) || placed.contains(&parent.id))
+                    .unwrap_or(true)
+            });
+            if ready.is_empty() {
+                ordered.extend(waiting);
+                break;
+            }
+            placed.extend(ready.iter().map(|issue| issue.id));
+            ordered.extend(ready);
+            remaining = waiting;
+        }
+        
+        // Posun termínů podle nejdřívějšího data šablony
+        let earliest = versions.iter().filter_map(|version| version.effective_date.or(version.due_date))
+            .chain(ordered.iter().filter_map(|issue| issue.start_date.or(issue.due_date)))
+            .min();
+        let shift_days = match (args.start_date, earliest) {
+            (Some(start), Some(earliest)) => (start - earliest).num_days(),
+            _ => 0,
+        };
+        
+        let tracker_ids: Option<Vec<i32>> = template.trackers.as_ref().map(|trackers| trackers.iter().map(|tracker| tracker.id).collect());
+        let plan = json!({
+            "template": { "id": template.id, "name": template.name },
+            "project": {
+                "name": args.name,
+                "identifier": args.identifier,
+                "parent_id": args.parent_id.or(template.parent.as_ref().map(|parent| parent.id)),
+                "tracker_ids": tracker_ids,
+                "enabled_modules": template.enabled_modules,
+            },
+            "shift_days": shift_days,
+            "categories": categories.iter().map(|category| &category.name).collect::<Vec<_>>(),
+            "versions": versions.iter().map(|version| json!({
+                "name": version.name,
+                "effective_date": shift_date(version.effective_date, shift_days),
+                "due_date": shift_date(version.due_date, shift_days),
+            })).collect::<Vec<_>>(),
+            "issues": ordered.iter().map(|issue| json!({
+                "source_id": issue.id,
+                "subject": issue.subject,
+                "start_date": shift_date(issue.start_date, shift_days),
+                "due_date": shift_date(issue.due_date, shift_days),
+            })).collect::<Vec<_>>(),
+        });
+        
+        if args.dry_run {
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text((i18n::messages().template_plan)(
+                    &args.name, &template.name, categories.len(), versions.len(), ordered.len(), shift_days
+                ))
+            ]).with_structured_content(&plan));
+        }
+        
+        // 2. Projekt s trackery a moduly šablony
+        let project_data = CreateProjectRequest {
+            project: CreateProject {
+                name: args.name.clone(),
+                description: args.description.clone().or(template.description.clone()),
+                identifier: args.identifier.clone(),
+                homepage: None,
+                is_public: args.is_public.or(template.is_public),
+                parent_id: args.parent_id.or(template.parent.as_ref().map(|parent| parent.id)),
+                inherit_members: template.inherit_members,
+                tracker_ids,
+                enabled_module_names: template.enabled_modules.clone(),
+            }
+        };
+        let project = match self.api_client.create_project(project_data).await {
+            Ok(response) => response.project,
+            Err(e) => {
+                error!("Chyba při vytváření projektu '{}': {}", args.name, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_creating_project)(&args.name), e))
+                ]));
+            }
+        };
+        
+        let messages = i18n::messages();
+        let mut failures: Vec<String> = Vec::new();
+        
+        // 3. Kategorie a milníky - mapování původních ID na nová
+        let mut category_ids: HashMap<i32, i32> = HashMap::new();
+        for category in &categories {
+            match self.api_client.create_issue_category(project.id, &category.name).await {
+                Ok(response) => { category_ids.insert(category.id, response.issue_category.id); }
+                Err(e) => failures.push(format!("{}: {}", (messages.template_category_failed)(&category.name), e)),
+            }
+        }
+        let mut version_ids: HashMap<i32, i32> = HashMap::new();
+        for version in &versions {
+            let date = |date: Option<NaiveDate>| shift_date(date, shift_days).map(|date| date.format("%Y-%m-%d").to_string());
+            match self.api_client.create_milestone(
+                project.id,
+                version.name.clone(),
+                version.description.clone(),
+                date(version.effective_date),
+                date(version.due_date),
+                None,
+                version.sharing.clone(),
+                version.default_project_version,
+                None,
+            ).await {
+                Ok(response) => { version_ids.insert(version.id, response.version.id); }
+                Err(e) => failures.push(format!("{}: {}", (messages.template_version_failed)(&version.name), e)),
+            }
+        }
+        
+        // 4. Úkoly jako nové - výchozí (první otevřený) stav, bez procenta dokončení
+        let mut issue_ids: HashMap<i32, i32> = HashMap::new();
+        if !ordered.is_empty() {
+            let initial_status = match self.api_client.list_issue_statuses().await {
+                Ok(response) => response.issue_statuses.into_iter().find(|status| !status.is_closed.unwrap_or(false)),
+                Err(e) => {
+                    failures.push(format!("{}: {}", messages.template_statuses_unavailable, e));
+                    None
+                }
+            };
+            for issue in &ordered {
+                let issue_data = CreateIssueRequest {
+                    issue: CreateIssue {
+                        project_id: project.id,
+                        tracker_id: issue.tracker.id,
+                        status_id: initial_status.as_ref().map(|status| status.id).unwrap_or(issue.status.id),
+                        priority_id: issue.priority.id,
+                        subject: issue.subject.clone(),
+                        description: issue.description.clone(),
+                        category_id: issue.category.as_ref().and_then(|category| category_ids.get(&category.id).copied()),
+                        fixed_version_id: issue.fixed_version.as_ref().and_then(|version| version_ids.get(&version.id).copied()),
+                        assigned_to_id: issue.assigned_to.as_ref().map(|user| user.id),
+                        parent_issue_id: issue.parent.as_ref().and_then(|parent| issue_ids.get(&parent.id).copied()),
+                        estimated_hours: issue.estimated_hours,
+                        start_date: shift_date(issue.start_date, shift_days),
+                        due_date: shift_date(issue.due_date, shift_days),
+                        done_ratio: None,
+                        custom_field_values: None,
+                    }
+                };
+                match self.api_client.create_issue(issue_data).await {
+                    Ok(response) => { issue_ids.insert(issue.id, response.issue.id); }
+                    Err(e) => failures.push(format!("{}: {}", (messages.template_issue_failed)(issue.id, &issue.subject), e)),
+                }
+            }
+        }
+        
+        info!(
+            "Projekt '{}' (ID {}) vytvořen ze šablony {}: {} kategorií, {} milníků, {} úkolů, {} chyb",
+            project.name, project.id, template_id, category_ids.len(), version_ids.len(), issue_ids.len(), failures.len()
+        );
+        
+        let counts = (messages.template_created_counts)(
+            category_ids.len(), categories.len(),
+            version_ids.len(), versions.len(),
+            issue_ids.len(), ordered.len()
+        );
+        let mut output = (messages.project_created_from_template)(&project.name, project.id, &template.name, &counts, shift_days);
+        if !failures.is_empty() {
+            output.push_str(&format!("\n\n{}:\n", messages.template_not_created));
+            for failure in &failures {
+                output.push_str(&format!("- {}\n", failure));
+            }
+        }
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&json!({
+            "project": project,
+            "template_id": template_id,
+            "shift_days": shift_days,
+            "categories": category_ids.len(),
+            "versions": version_ids.len(),
+            "issues": issue_ids.iter().map(|(source, copy)| json!({ "source_id": source, "id": copy })).collect::<Vec<_>>(),
+            "failures": failures
+        })))
+    }
+}
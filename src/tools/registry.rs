@@ -1,13 +1,18 @@
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use moka::future::Cache;
 use serde_json::Value;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn, Instrument};
 
 use crate::config::AppConfig;
+use crate::i18n;
 use crate::api::EasyProjectClient;
-use crate::mcp::protocol::{Tool, ToolInputSchema, CallToolResult};
+use crate::api::permissions::PermissionReport;
+use crate::audit::AuditLog;
+use crate::mcp::protocol::{Tool, ToolInputSchema, CallToolResult, ToolResult};
 
-use super::executor::ToolExecutor;
+use super::executor::{validate_arguments, InvalidArguments, ToolExecutor};
 use super::project_tools::*;
 use super::issue_tools::*;
 use super::user_tools::*;
@@ -15,13 +20,32 @@ use super::time_entry_tools::*;
 use super::report_tools::*;
 use super::milestone_tools::*;
 use super::enumeration_tools::*;
+use super::system_tools::*;
 
 pub struct ToolRegistry {
     tools: HashMap<String, Arc<dyn ToolExecutor>>,
+    api_client: EasyProjectClient,
+    permissions: SharedPermissions,
+    hide_unavailable_tools: bool,
+    result_cache: Option<Cache<String, CallToolResult>>,
+    prefetch_enabled: bool,
+    tool_timeout: Option<Duration>,
+    structured_content: bool,
+    structured_content_exclude: Vec<String>,
+    audit_log: Arc<AuditLog>,
+    /// Název instance EasyProject, nad kterou tools běží (easyproject.instance_name)
+    instance_name: String,
+    /// Registry dalších instancí z [instances], volání se na ně předává podle argumentu `instance`
+    instances: BTreeMap<String, ToolRegistry>,
 }
 
 impl ToolRegistry {
     pub fn new(api_client: EasyProjectClient, config: &AppConfig) -> Self {
+        Self::with_audit_log(api_client, config, Arc::new(AuditLog::from_config(&config.audit)))
+    }
+    
+    /// Sestaví registry se sdíleným auditem (audit se zachová i po znovunačtení konfigurace)
+    pub fn with_audit_log(api_client: EasyProjectClient, config: &AppConfig, audit_log: Arc<AuditLog>) -> Self {
         let mut tools: HashMap<String, Arc<dyn ToolExecutor>> = HashMap::new();
         
         info!("Inicializuji MCP tools...");
@@ -33,12 +57,24 @@ impl ToolRegistry {
             let create_project = Arc::new(CreateProjectTool::new(api_client.clone(), config.clone()));
             let update_project = Arc::new(UpdateProjectTool::new(api_client.clone(), config.clone()));
             let delete_project = Arc::new(DeleteProjectTool::new(api_client.clone(), config.clone()));
+            let get_project_modules = Arc::new(GetProjectModulesTool::new(api_client.clone(), config.clone()));
+            let set_project_modules = Arc::new(SetProjectModulesTool::new(api_client.clone(), config.clone()));
+            let list_news = Arc::new(ListNewsTool::new(api_client.clone(), config.clone()));
+            let list_project_files = Arc::new(ListProjectFilesTool::new(api_client.clone(), config.clone()));
+            let global_search = Arc::new(GlobalSearchTool::new(api_client.clone(), config.clone()));
+            let create_project_from_template = Arc::new(CreateProjectFromTemplateTool::new(api_client.clone(), config.clone()));
             
             tools.insert(list_projects.name().to_string(), list_projects);
             tools.insert(get_project.name().to_string(), get_project);
             tools.insert(create_project.name().to_string(), create_project);
             tools.insert(update_project.name().to_string(), update_project);
             tools.insert(delete_project.name().to_string(), delete_project);
+            tools.insert(get_project_modules.name().to_string(), get_project_modules);
+            tools.insert(set_project_modules.name().to_string(), set_project_modules);
+            tools.insert(list_news.name().to_string(), list_news);
+            tools.insert(list_project_files.name().to_string(), list_project_files);
+            tools.insert(global_search.name().to_string(), global_search);
+            tools.insert(create_project_from_template.name().to_string(), create_project_from_template);
             
             info!("Registrovány project tools");
         }
@@ -48,18 +84,46 @@ impl ToolRegistry {
             let list_issues = Arc::new(ListIssuesTool::new(api_client.clone(), config.clone()));
             let get_issue = Arc::new(GetIssueTool::new(api_client.clone(), config.clone()));
             let create_issue = Arc::new(CreateIssueTool::new(api_client.clone(), config.clone()));
+            let create_issues_batch = Arc::new(CreateIssuesBatchTool::new(api_client.clone(), config.clone()));
             let update_issue = Arc::new(UpdateIssueTool::new(api_client.clone(), config.clone()));
+            let delete_issue = Arc::new(DeleteIssueTool::new(api_client.clone(), config.clone()));
+            let list_issue_attachments = Arc::new(ListIssueAttachmentsTool::new(api_client.clone(), config.clone()));
+            let download_attachment = Arc::new(DownloadAttachmentTool::new(api_client.clone(), config.clone()));
+            let upload_attachment = Arc::new(UploadAttachmentTool::new(api_client.clone(), config.clone()));
+            let add_watcher = Arc::new(AddWatcherTool::new(api_client.clone(), config.clone()));
+            let remove_watcher = Arc::new(RemoveWatcherTool::new(api_client.clone(), config.clone()));
             let assign_issue = Arc::new(AssignIssueTool::new(api_client.clone(), config.clone()));
             let complete_issue = Arc::new(CompleteIssueTool::new(api_client.clone(), config.clone()));
+            let transition_issue = Arc::new(TransitionIssueTool::new(api_client.clone(), config.clone()));
             let get_issue_enumerations = Arc::new(GetIssueEnumerationsTool::new(api_client.clone(), config.clone()));
+            let list_custom_fields = Arc::new(ListCustomFieldsTool::new(api_client.clone(), config.clone()));
+            let get_issue_context = Arc::new(GetIssueContextTool::new(api_client.clone(), config.clone()));
+            let bulk_edit_descriptions = Arc::new(BulkEditDescriptionsTool::new(api_client.clone(), config.clone()));
+            let export_issues_jsonl = Arc::new(ExportIssuesJsonlTool::new(api_client.clone(), config.clone()));
+            let search_issues = Arc::new(SearchIssuesTool::new(api_client.clone(), config.clone()));
+            let clone_issue = Arc::new(CloneIssueTool::new(api_client.clone(), config.clone()));
 
             tools.insert(list_issues.name().to_string(), list_issues);
             tools.insert(get_issue.name().to_string(), get_issue);
             tools.insert(create_issue.name().to_string(), create_issue);
+            tools.insert(create_issues_batch.name().to_string(), create_issues_batch);
             tools.insert(update_issue.name().to_string(), update_issue);
+            tools.insert(delete_issue.name().to_string(), delete_issue);
+            tools.insert(list_issue_attachments.name().to_string(), list_issue_attachments);
+            tools.insert(download_attachment.name().to_string(), download_attachment);
+            tools.insert(upload_attachment.name().to_string(), upload_attachment);
+            tools.insert(add_watcher.name().to_string(), add_watcher);
+            tools.insert(remove_watcher.name().to_string(), remove_watcher);
             tools.insert(assign_issue.name().to_string(), assign_issue);
             tools.insert(complete_issue.name().to_string(), complete_issue);
+            tools.insert(transition_issue.name().to_string(), transition_issue);
             tools.insert(get_issue_enumerations.name().to_string(), get_issue_enumerations);
+            tools.insert(list_custom_fields.name().to_string(), list_custom_fields);
+            tools.insert(get_issue_context.name().to_string(), get_issue_context);
+            tools.insert(bulk_edit_descriptions.name().to_string(), bulk_edit_descriptions);
+            tools.insert(export_issues_jsonl.name().to_string(), export_issues_jsonl);
+            tools.insert(search_issues.name().to_string(), search_issues);
+            tools.insert(clone_issue.name().to_string(), clone_issue);
 
             info!("Registrovány issue tools");
         }
@@ -69,10 +133,16 @@ impl ToolRegistry {
             let list_users = Arc::new(ListUsersTool::new(api_client.clone(), config.clone()));
             let get_user = Arc::new(GetUserTool::new(api_client.clone(), config.clone()));
             let get_user_workload = Arc::new(GetUserWorkloadTool::new(api_client.clone(), config.clone()));
+            let get_my_work = Arc::new(GetMyWorkTool::new(api_client.clone(), config.clone()));
+            let list_user_types = Arc::new(ListUserTypesTool::new(api_client.clone(), config.clone()));
+            let list_roles = Arc::new(ListRolesTool::new(api_client.clone(), config.clone()));
             
             tools.insert(list_users.name().to_string(), list_users);
             tools.insert(get_user.name().to_string(), get_user);
             tools.insert(get_user_workload.name().to_string(), get_user_workload);
+            tools.insert(get_my_work.name().to_string(), get_my_work);
+            tools.insert(list_user_types.name().to_string(), list_user_types);
+            tools.insert(list_roles.name().to_string(), list_roles);
             
             info!("Registrovány user tools");
         }
@@ -85,6 +155,8 @@ impl ToolRegistry {
             let update_time_entry = Arc::new(UpdateTimeEntryTool::new(api_client.clone(), config.clone()));
             let delete_time_entry = Arc::new(DeleteTimeEntryTool::new(api_client.clone(), config.clone()));
             let log_time = Arc::new(LogTimeTool::new(api_client.clone(), config.clone()));
+            let move_time_entries = Arc::new(MoveTimeEntriesTool::new(api_client.clone(), config.clone()));
+            let log_time_bulk = Arc::new(LogTimeBulkTool::new(api_client.clone(), config.clone()));
             
             tools.insert(list_time_entries.name().to_string(), list_time_entries);
             tools.insert(get_time_entry.name().to_string(), get_time_entry);
@@ -92,6 +164,8 @@ impl ToolRegistry {
             tools.insert(update_time_entry.name().to_string(), update_time_entry);
             tools.insert(delete_time_entry.name().to_string(), delete_time_entry);
             tools.insert(log_time.name().to_string(), log_time);
+            tools.insert(move_time_entries.name().to_string(), move_time_entries);
+            tools.insert(log_time_bulk.name().to_string(), log_time_bulk);
             
             info!("Registrovány time entry tools");
         }
@@ -100,9 +174,33 @@ impl ToolRegistry {
         if config.tools.reports.enabled {
             let generate_project_report = Arc::new(GenerateProjectReportTool::new(api_client.clone(), config.clone()));
             let get_dashboard_data = Arc::new(GetDashboardDataTool::new(api_client.clone(), config.clone()));
+            let assignment_history = Arc::new(AssignmentHistoryTool::new(api_client.clone(), config.clone()));
+            let budget_burn_report = Arc::new(BudgetBurnReportTool::new(api_client.clone(), config.clone()));
+            let list_generated_reports = Arc::new(ListGeneratedReportsTool::new(api_client.clone(), config.clone()));
+            let check_project_consistency = Arc::new(CheckProjectConsistencyTool::new(api_client.clone(), config.clone()));
+            let create_baseline = Arc::new(CreateBaselineTool::new(api_client.clone(), config.clone()));
+            let baseline_variance_report = Arc::new(BaselineVarianceReportTool::new(api_client.clone(), config.clone()));
+            let generate_gantt_data = Arc::new(GenerateGanttDataTool::new(api_client.clone(), config.clone()));
+            let team_velocity = Arc::new(TeamVelocityTool::new(api_client.clone(), config.clone()));
+            let find_overdue_issues = Arc::new(FindOverdueIssuesTool::new(api_client.clone(), config.clone()));
+            let plan_capacity = Arc::new(PlanCapacityTool::new(api_client.clone(), config.clone()));
+            let generate_timesheet = Arc::new(GenerateTimesheetTool::new(api_client.clone(), config.clone()));
+            let portfolio_overview = Arc::new(PortfolioOverviewTool::new(api_client.clone(), config.clone()));
             
             tools.insert(generate_project_report.name().to_string(), generate_project_report);
             tools.insert(get_dashboard_data.name().to_string(), get_dashboard_data);
+            tools.insert(assignment_history.name().to_string(), assignment_history);
+            tools.insert(budget_burn_report.name().to_string(), budget_burn_report);
+            tools.insert(list_generated_reports.name().to_string(), list_generated_reports);
+            tools.insert(check_project_consistency.name().to_string(), check_project_consistency);
+            tools.insert(create_baseline.name().to_string(), create_baseline);
+            tools.insert(baseline_variance_report.name().to_string(), baseline_variance_report);
+            tools.insert(generate_gantt_data.name().to_string(), generate_gantt_data);
+            tools.insert(team_velocity.name().to_string(), team_velocity);
+            tools.insert(find_overdue_issues.name().to_string(), find_overdue_issues);
+            tools.insert(plan_capacity.name().to_string(), plan_capacity);
+            tools.insert(generate_timesheet.name().to_string(), generate_timesheet);
+            tools.insert(portfolio_overview.name().to_string(), portfolio_overview);
             
             info!("Registrovány report tools");
         }
@@ -111,12 +209,14 @@ impl ToolRegistry {
         if config.tools.milestones.enabled {
             let list_milestones = Arc::new(ListMilestonesTool::new(api_client.clone(), config.clone()));
             let get_milestone = Arc::new(GetMilestoneTool::new(api_client.clone(), config.clone()));
+            let list_assignable_versions = Arc::new(ListAssignableVersionsTool::new(api_client.clone(), config.clone()));
             let create_milestone = Arc::new(CreateMilestoneTool::new(api_client.clone(), config.clone()));
             let update_milestone = Arc::new(UpdateMilestoneTool::new(api_client.clone(), config.clone()));
             let delete_milestone = Arc::new(DeleteMilestoneTool::new(api_client.clone(), config.clone()));
             
             tools.insert(list_milestones.name().to_string(), list_milestones);
             tools.insert(get_milestone.name().to_string(), get_milestone);
+            tools.insert(list_assignable_versions.name().to_string(), list_assignable_versions);
             tools.insert(create_milestone.name().to_string(), create_milestone);
             tools.insert(update_milestone.name().to_string(), update_milestone);
             tools.insert(delete_milestone.name().to_string(), delete_milestone);
@@ -124,37 +224,342 @@ impl ToolRegistry {
             info!("Registrovány milestone tools");
         }
         
+        // Obecný GET na REST API je ve výchozím stavu vypnutý
+        if config.tools.raw_api.enabled {
+            let raw_api_request = Arc::new(RawApiRequestTool::new(api_client.clone(), config.clone()));
+            tools.insert(raw_api_request.name().to_string(), raw_api_request);
+            
+            info!("Registrován raw_api_request ({} povolených cest)", config.tools.raw_api.allowed_paths.len());
+        }
+        
+        if config.tools.read_only {
+            let before = tools.len();
+            tools.retain(|_, tool| tool.is_read_only());
+            info!("Režim jen pro čtení: vynecháno {} tools měnících data", before - tools.len());
+        }
+        
+        // System tools
+        let permissions: SharedPermissions = Arc::new(RwLock::new(None));
+        let tool_requirements = tools.values()
+            .filter(|tool| config.tools.is_tool_allowed(tool.name()))
+            .filter_map(|tool| tool.required_permission().map(|area| (tool.name().to_string(), area)))
+            .collect();
+        let get_permissions = Arc::new(GetPermissionsTool::new(api_client.clone(), permissions.clone(), tool_requirements));
+        tools.insert(get_permissions.name().to_string(), get_permissions);
+        let validate_config = Arc::new(ValidateConfigTool::new());
+        tools.insert(validate_config.name().to_string(), validate_config);
+        let get_instance_info = Arc::new(GetInstanceInfoTool::new(api_client.clone(), config.clone()));
+        tools.insert(get_instance_info.name().to_string(), get_instance_info);
+        let get_server_info = Arc::new(GetServerInfoTool::new(api_client.clone(), config.clone(), permissions.clone()));
+        tools.insert(get_server_info.name().to_string(), get_server_info);
+        let check_connection = Arc::new(CheckConnectionTool::new(api_client.clone(), config.clone()));
+        tools.insert(check_connection.name().to_string(), check_connection);
+        let cache_stats = Arc::new(CacheStatsTool::new(api_client.clone(), config.clone()));
+        tools.insert(cache_stats.name().to_string(), cache_stats);
+        if config.cache.enable_clear_tool {
+            let clear_cache = Arc::new(ClearCacheTool::new(api_client.clone(), config.clone()));
+            tools.insert(clear_cache.name().to_string(), clear_cache);
+        }
+        if audit_log.is_enabled() {
+            let get_audit_log = Arc::new(GetAuditLogTool::new(audit_log.clone()));
+            tools.insert(get_audit_log.name().to_string(), get_audit_log);
+        }
+        
+        // Jednotlivé tools vypnuté seznamy allowlist/denylist
+        for name in config.tools.allowlist.iter().chain(&config.tools.denylist) {
+            if !tools.contains_key(name) {
+                warn!("Tool '{}' z tools.allowlist/denylist není registrovaný", name);
+            }
+        }
+        tools.retain(|name, _| config.tools.is_tool_allowed(name));
+        
         info!("Celkem registrováno {} tools", tools.len());
         
-        Self { tools }
+        let result_cache = if config.cache.enabled && config.cache.tool_result_ttl > 0 {
+            Some(Cache::builder()
+                .max_capacity(config.cache.max_entries)
+                .time_to_live(Duration::from_secs(config.cache.tool_result_ttl))
+                .build())
+        } else {
+            None
+        };
+        
+        Self {
+            tools,
+            api_client,
+            permissions,
+            hide_unavailable_tools: config.tools.hide_unavailable_tools,
+            result_cache,
+            prefetch_enabled: config.cache.enabled && config.cache.prefetch_next_page,
+            tool_timeout: (config.tools.tool_timeout_seconds > 0)
+                .then(|| Duration::from_secs(config.tools.tool_timeout_seconds)),
+            structured_content: config.tools.structured_content,
+            structured_content_exclude: config.tools.structured_content_exclude.clone(),
+            audit_log,
+            instance_name: config.easyproject.instance_name.clone(),
+            instances: BTreeMap::new(),
+        }
+    }
+    
+    /// Přidá registry další instance EasyProject (viz [instances] v konfiguraci)
+    pub fn add_instance(&mut self, registry: ToolRegistry) {
+        self.instances.insert(registry.instance_name.clone(), registry);
+    }
+    
+    /// Názvy všech instancí, výchozí první
+    pub fn instance_names(&self) -> Vec<String> {
+        std::iter::once(self.instance_name.clone())
+            .chain(self.instances.keys().cloned())
+            .collect()
+    }
+    
+    /// Ověří oprávnění API klíče a podle nich označí nebo skryje nedostupné tools
+    pub async fn refresh_permissions(&self) -> PermissionReport {
+        let report = self.api_client.probe_permissions().await;
+        
+        let unavailable: Vec<&str> = self.tools.values()
+            .filter(|tool| tool.required_permission().is_some_and(|area| report.is_denied(area)))
+            .map(|tool| tool.name())
+            .collect();
+        if !unavailable.is_empty() {
+            warn!("Kvůli chybějícím oprávněním jsou nedostupné tools: {}", unavailable.join(", "));
+        }
+        
+        *self.permissions.write().unwrap_or_else(|e| e.into_inner()) = Some(report.clone());
+        report
+    }
+    
+    /// Doplní do schématu cachovaných tools parametr `fresh` pro obejití cache výsledků
+    fn schema_with_cache_bypass(&self, tool: &Arc<dyn ToolExecutor>) -> Value {
+        let mut schema = tool.input_schema();
+        if self.result_cache.is_some() && tool.is_cacheable() {
+            if let Value::Object(ref mut properties) = schema {
+                properties.insert("fresh".to_string(), serde_json::json!({
+                    "type": "boolean",
+                    "description": i18n::messages().fresh_description,
+                    "default": false
+                }));
+            }
+        }
+        schema
+    }
+    
+    /// Doplní do schématu parametr `instance`, pokud server obsluhuje více instancí
+    fn add_instance_argument(&self, schema: &mut Value) {
+        if self.instances.is_empty() {
+            return;
+        }
+        if let Value::Object(ref mut properties) = schema {
+            properties.insert("instance".to_string(), serde_json::json!({
+                "type": "string",
+                "description": (i18n::messages().instance_description)(&self.instance_name),
+                "enum": self.instance_names(),
+                "default": self.instance_name
+            }));
+        }
+    }
+    
+    /// Vrátí true, pokud tool podle posledního ověření oprávnění nemůže fungovat
+    fn is_unavailable(&self, tool: &Arc<dyn ToolExecutor>) -> bool {
+        let permissions = self.permissions.read().unwrap_or_else(|e| e.into_inner());
+        match (tool.required_permission(), permissions.as_ref()) {
+            (Some(area), Some(report)) => report.is_denied(area),
+            _ => false,
+        }
     }
     
     /// Vrátí seznam všech dostupných tools pro MCP protokol
     pub fn list_tools(&self) -> Vec<Tool> {
         self.tools
             .values()
-            .map(|tool| Tool {
-                name: tool.name().to_string(),
-                description: tool.description().to_string(),
-                input_schema: ToolInputSchema {
-                    schema_type: "object".to_string(),
-                    properties: Some(tool.input_schema()),
-                    required: None,
-                    additional_properties: Some(false),
-                },
+            .filter(|tool| !(self.hide_unavailable_tools && self.is_unavailable(tool)))
+            .map(|tool| {
+                let description = i18n::tool_description(tool.name(), tool.description());
+                let mut properties = self.schema_with_cache_bypass(tool);
+                i18n::localize_schema(&mut properties);
+                widen_project_reference(&mut properties);
+                self.add_instance_argument(&mut properties);
+                Tool {
+                    name: tool.name().to_string(),
+                    description: if self.is_unavailable(tool) {
+                        format!("{} {}", i18n::messages().unavailable_prefix, description)
+                    } else {
+                        description.to_string()
+                    },
+                    input_schema: ToolInputSchema {
+                        schema_type: "object".to_string(),
+                        properties: Some(properties),
+                        required: (!tool.required_fields().is_empty())
+                            .then(|| tool.required_fields().iter().map(|field| field.to_string()).collect()),
+                        additional_properties: Some(false),
+                    },
+                }
             })
             .collect()
     }
     
     /// Spustí tool s danými argumenty
+    ///
+    /// Volání delší než tools.tool_timeout_seconds se přeruší a vrátí chybu timeout.
     pub async fn execute_tool(&self, tool_name: &str, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let (arguments, instance) = take_instance(arguments);
+        let Some(registry) = self.instance_registry(instance.as_deref()) else {
+            let name = instance.unwrap_or_default();
+            let available = self.instance_names().join(", ");
+            return Err(InvalidArguments((i18n::messages().unknown_instance)(&name, &available)).into());
+        };
+        registry.execute_on_instance(tool_name, arguments).await
+    }
+    
+    /// Registry instance podle argumentu `instance`, bez něj výchozí instance
+    pub fn instance_registry(&self, instance: Option<&str>) -> Option<&ToolRegistry> {
+        match instance {
+            Some(name) if name != self.instance_name => self.instances.get(name),
+            _ => Some(self),
+        }
+    }
+    
+    /// Název instance EasyProject této registry
+    pub fn instance_name(&self) -> &str {
+        &self.instance_name
+    }
+    
+    /// Spustí tool nad klientem API této instance
+    async fn execute_on_instance(&self, tool_name: &str, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let result = match self.tool_timeout {
+            Some(limit) => match tokio::time::timeout(limit, self.run_tool(tool_name, arguments)).await {
+                Ok(result) => result?,
+                Err(_) => return Ok(self.timeout_result(tool_name, limit)),
+            },
+            None => self.run_tool(tool_name, arguments).await?,
+        };
+        Ok(self.filter_structured_content(tool_name, result))
+    }
+    
+    /// Odebere structuredContent, pokud je vypnutý globálně nebo pro daný tool
+    fn filter_structured_content(&self, tool_name: &str, mut result: CallToolResult) -> CallToolResult {
+        if !self.structured_content || self.structured_content_exclude.iter().any(|name| name == tool_name) {
+            result.structured_content = None;
+        }
+        result
+    }
+    
+    /// Chybový výsledek pro volání přerušené po vypršení limitu
+    ///
+    /// Přerušený zápisový tool mohl změnu v EasyProject přesto provést, proto se zahodí cache výsledků.
+    fn timeout_result(&self, tool_name: &str, limit: Duration) -> CallToolResult {
+        warn!("Tool {} přerušen po {} s", tool_name, limit.as_secs());
+        
+        if !self.is_tool_read_only(tool_name) {
+            if let Some(ref cache) = self.result_cache {
+                cache.invalidate_all();
+            }
+        }
+        
+        let mut result = CallToolResult::error(vec![
+            ToolResult::text((i18n::messages().tool_timeout)(tool_name, limit.as_secs()))
+        ]);
+        result.meta = Some(serde_json::json!({
+            "error": {
+                "kind": "timeout",
+                "tool": tool_name,
+                "timeout_seconds": limit.as_secs(),
+            }
+        }));
+        result
+    }
+    
+    /// Nahradí identifikátor nebo název projektu v argumentech `project_id` číselným ID
+    ///
+    /// Týká se jen `project_id` s celočíselným schématem (i v položkách polí a vnořených objektech);
+    /// ostatní argumenty se nemění.
+    async fn resolve_project_reference(&self, tool: &Arc<dyn ToolExecutor>, arguments: Option<Value>) -> Result<Option<Value>, CallToolResult> {
+        let Some(mut arguments) = arguments else {
+            return Ok(None);
+        };
+        let schema = tool.input_schema();
+        let mut references = Vec::new();
+        project_references(&schema, &arguments, &mut references);
+        
+        let mut resolved = HashMap::new();
+        for reference in references {
+            if resolved.contains_key(&reference) {
+                continue;
+            }
+            match self.api_client.resolve_project(&reference).await {
+                Ok(id) => {
+                    debug!("Tool {}: projekt '{}' určen jako ID {}", tool.name(), reference, id);
+                    resolved.insert(reference, id);
+                }
+                Err(e) => {
+                    warn!("Tool {}: projekt '{}' nelze určit: {}", tool.name(), reference, e);
+                    return Err(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().project_not_resolved)(&reference, &e.to_string()))
+                    ]));
+                }
+            }
+        }
+        replace_project_references(&schema, &mut arguments, &resolved);
+        Ok(Some(arguments))
+    }
+    
+    /// Spustí tool včetně oprávnění, aliasů parametrů a cache výsledků
+    async fn run_tool(&self, tool_name: &str, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         debug!("Spouštím tool: {} s argumenty: {:?}", tool_name, arguments);
         
         match self.tools.get(tool_name) {
+            Some(tool) if self.is_unavailable(tool) => {
+                warn!("Tool {} je nedostupný kvůli chybějícím oprávněním", tool_name);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().tool_unavailable)(tool_name))
+                ]))
+            }
             Some(tool) => {
-                match tool.execute(arguments).await {
+                let (arguments, fresh) = take_fresh_flag(arguments);
+                let arguments = apply_argument_aliases(tool, arguments);
+                let arguments = match self.resolve_project_reference(tool, arguments).await {
+                    Ok(arguments) => arguments,
+                    Err(result) => return Ok(result),
+                };
+                
+                if let Err(e) = validate_arguments(tool.as_ref(), arguments.as_ref()) {
+                    warn!("Tool {}: {}", tool_name, e);
+                    return Err(e.into());
+                }
+                
+                let cache_key = match self.result_cache {
+                    Some(_) if tool.is_cacheable() => Some(result_cache_key(self.api_client.cache_namespace(), tool_name, &arguments)),
+                    _ => None,
+                };
+                
+                if let (Some(cache), Some(key)) = (&self.result_cache, &cache_key) {
+                    if !fresh {
+                        if let Some(cached) = cache.get(key).await {
+                            debug!("Tool {} vrácen z cache výsledků", tool_name);
+                            return Ok(cached);
+                        }
+                    }
+                }
+                
+                match tool.execute(arguments.clone()).await {
                     Ok(result) => {
                         debug!("Tool {} úspěšně dokončen", tool_name);
+                        
+                        if self.prefetch_enabled && tool.is_cacheable() && !result.is_error.unwrap_or(false) {
+                            self.prefetch_next_page(tool, &arguments, &result);
+                        }
+                        
+                        if let Some(ref cache) = self.result_cache {
+                            if let Some(key) = cache_key {
+                                if !result.is_error.unwrap_or(false) {
+                                    cache.insert(key, result.clone()).await;
+                                }
+                            } else if !tool.is_read_only() {
+                                // Zápisový tool mohl změnit data - cachované výsledky už nemusí platit
+                                cache.invalidate_all();
+                            }
+                        }
+                        
                         Ok(result)
                     }
                     Err(e) => {
@@ -165,11 +570,61 @@ impl ToolRegistry {
             }
             None => {
                 error!("Tool {} nenalezen", tool_name);
-                Err(format!("Tool '{}' nenalezen", tool_name).into())
+                Err((i18n::messages().tool_not_found)(tool_name).into())
             }
         }
     }
     
+    /// Spustí na pozadí načtení další stránky seznamu, pokud výsledek hlásí další data
+    ///
+    /// Výsledek se uloží do cache výsledků (a tím i do cache API klienta), takže následný
+    /// dotaz na další stránku je okamžitý. Při vytížení rate limiteru se přednačtení vynechá.
+    fn prefetch_next_page(&self, tool: &Arc<dyn ToolExecutor>, arguments: &Option<Value>, result: &CallToolResult) {
+        let next_offset = result.meta.as_ref()
+            .and_then(|meta| meta.get("pagination"))
+            .filter(|pagination| pagination.get("has_more").and_then(Value::as_bool) == Some(true))
+            .and_then(|pagination| pagination.get("next_offset"))
+            .and_then(Value::as_u64);
+        let Some(next_offset) = next_offset else {
+            return;
+        };
+        
+        if !self.api_client.has_spare_capacity() {
+            debug!("Přednačtení další stránky {} vynecháno - rate limiter nemá volnou kapacitu", tool.name());
+            return;
+        }
+        
+        let mut next_arguments = match arguments {
+            Some(Value::Object(map)) => map.clone(),
+            _ => serde_json::Map::new(),
+        };
+        next_arguments.insert("offset".to_string(), Value::from(next_offset));
+        let next_arguments = Some(Value::Object(next_arguments));
+        
+        let cache_key = result_cache_key(self.api_client.cache_namespace(), tool.name(), &next_arguments);
+        let cache = self.result_cache.clone();
+        let tool = tool.clone();
+        
+        tokio::spawn(async move {
+            if let Some(ref cache) = cache {
+                if cache.contains_key(&cache_key) {
+                    return;
+                }
+            }
+            
+            debug!("Přednačítám další stránku {} (offset {})", tool.name(), next_offset);
+            match tool.execute(next_arguments).await {
+                Ok(result) if !result.is_error.unwrap_or(false) => {
+                    if let Some(cache) = cache {
+                        cache.insert(cache_key, result).await;
+                    }
+                }
+                Ok(_) => debug!("Přednačtení další stránky {} vrátilo chybu", tool.name()),
+                Err(e) => debug!("Přednačtení další stránky {} selhalo: {}", tool.name(), e),
+            }
+        }.in_current_span());
+    }
+    
     /// Vrátí počet registrovaných tools
     pub fn tool_count(&self) -> usize {
         self.tools.len()
@@ -179,4 +634,376 @@ impl ToolRegistry {
     pub fn has_tool(&self, tool_name: &str) -> bool {
         self.tools.contains_key(tool_name)
     }
-} 
\ No newline at end of file
+    
+    /// Vrátí true, pokud tool existuje a nemění data
+    pub fn is_tool_read_only(&self, tool_name: &str) -> bool {
+        self.tools.get(tool_name).is_some_and(|tool| tool.is_read_only())
+    }
+    
+    /// Audit volání tools, které mění data
+    pub fn audit_log(&self) -> &Arc<AuditLog> {
+        &self.audit_log
+    }
+    
+    /// Vrátí true, pokud žádný dostupný tool nemění data
+    pub fn is_read_only(&self) -> bool {
+        self.tools.values()
+            .filter(|tool| !self.is_unavailable(tool))
+            .all(|tool| tool.is_read_only())
+    }
+    
+    /// Vrátí seřazené názvy tools, které jsou nedostupné kvůli chybějícím oprávněním
+    pub fn unavailable_tools(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.tools.values()
+            .filter(|tool| self.is_unavailable(tool))
+            .map(|tool| tool.name().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+/// Společné aliasy argumentů jako dvojice (alias, kanonický název)
+///
+/// Alias se použije jen u tools, jejichž schéma obsahuje kanonický parametr a neobsahuje alias.
+const COMMON_ARGUMENT_ALIASES: &[(&str, &str)] = &[
+    ("project", "project_id"),
+    ("projectId", "project_id"),
+    ("task_id", "issue_id"),
+    ("task", "issue_id"),
+    ("issueId", "issue_id"),
+    ("assignee", "assigned_to_id"),
+    ("assignee_id", "assigned_to_id"),
+    ("assigned_to", "assigned_to_id"),
+    ("user", "user_id"),
+    ("userId", "user_id"),
+    ("version_id", "fixed_version_id"),
+    ("milestone_id", "fixed_version_id"),
+    ("parent_id", "parent_issue_id"),
+    ("estimate", "estimated_hours"),
+    ("date", "spent_on"),
+    ("comment", "comments"),
+    ("query", "search"),
+    ("q", "search"),
+    ("from", "from_date"),
+    ("to", "to_date"),
+];
+
+/// Přejmenuje známé synonymní argumenty na kanonické názvy parametrů tool
+///
+/// Nemění argumenty, které tool zná, ani nepřepíše již zadaný kanonický parametr.
+fn apply_argument_aliases(tool: &Arc<dyn ToolExecutor>, arguments: Option<Value>) -> Option<Value> {
+    let mut map = match arguments {
+        Some(Value::Object(map)) => map,
+        other => return other,
+    };
+    
+    let schema = tool.input_schema();
+    let known = |name: &str| schema.get(name).is_some();
+    
+    for &(alias, canonical) in tool.argument_aliases().iter().chain(COMMON_ARGUMENT_ALIASES.iter()) {
+        if !known(canonical) || known(alias) || map.contains_key(canonical) {
+            continue;
+        }
+        if let Some(value) = map.remove(alias) {
+            debug!("Tool {}: argument '{}' přejmenován na '{}'", tool.name(), alias, canonical);
+            map.insert(canonical.to_string(), value);
+        }
+    }
+    
+    Some(Value::Object(map))
+}
+
+/// Vlastnost schématu je celé číslo
+fn is_integer_property(property: &Value) -> bool {
+    property.get("type").is_some_and(|schema_type| schema_type == "integer")
+}
+
+/// Schéma vlastností vnořeného objektu nebo položek pole
+fn nested_properties(property: &Value) -> Option<&Value> {
+    property.get("properties")
+        .or_else(|| property.get("items").and_then(|items| items.get("properties")))
+}
+
+/// Řetězcové odkazy na projekt v argumentech - `project_id` s celočíselným schématem,
+/// i v položkách polí a vnořených objektech (např. `issues[].project_id`)
+fn project_references(properties: &Value, arguments: &Value, references: &mut Vec<String>) {
+    let Value::Object(arguments) = arguments else {
+        return;
+    };
+    for (key, value) in arguments {
+        let Some(property) = properties.get(key) else {
+            continue;
+        };
+        match value {
+            Value::String(reference) if key == "project_id" && is_integer_property(property) => {
+                references.push(reference.clone());
+            }
+            Value::Object(_) => {
+                if let Some(nested) = nested_properties(property) {
+                    project_references(nested, value, references);
+                }
+            }
+            Value::Array(items) => {
+                if let Some(nested) = nested_properties(property) {
+                    for item in items {
+                        project_references(nested, item, references);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Nahradí řetězcové odkazy na projekt zjištěnými ID (viz `project_references`)
+fn replace_project_references(properties: &Value, arguments: &mut Value, resolved: &HashMap<String, i32>) {
+    let Value::Object(arguments) = arguments else {
+        return;
+    };
+    for (key, value) in arguments.iter_mut() {
+        let Some(property) = properties.get(key) else {
+            continue;
+        };
+        match value {
+            Value::String(reference) if key == "project_id" && is_integer_property(property) => {
+                if let Some(id) = resolved.get(reference.as_str()) {
+                    *value = Value::from(*id);
+                }
+            }
+            Value::Object(_) => {
+                if let Some(nested) = nested_properties(property) {
+                    replace_project_references(nested, value, resolved);
+                }
+            }
+            Value::Array(items) => {
+                if let Some(nested) = nested_properties(property) {
+                    for item in items.iter_mut() {
+                        replace_project_references(nested, item, resolved);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Ve schématu pro klienta povolí u `project_id` i řetězec (identifikátor nebo název projektu),
+/// stejně jako `project_references` i ve vnořených objektech a položkách polí
+fn widen_project_reference(properties: &mut Value) {
+    let Value::Object(properties) = properties else {
+        return;
+    };
+    for (key, property) in properties.iter_mut() {
+        if key == "project_id" && is_integer_property(property) {
+            let Value::Object(property) = property else {
+                continue;
+            };
+            property.insert("type".to_string(), serde_json::json!(["integer", "string"]));
+            let description = property.get("description").and_then(Value::as_str).unwrap_or_default();
+            let hint = i18n::messages().project_reference_hint;
+            let description = if description.is_empty() { hint.to_string() } else { format!("{}. {}", description, hint) };
+            property.insert("description".to_string(), Value::String(description));
+            continue;
+        }
+        if let Some(nested) = property.get_mut("properties") {
+            widen_project_reference(nested);
+        }
+        if let Some(nested) = property.get_mut("items").and_then(|items| items.get_mut("properties")) {
+            widen_project_reference(nested);
+        }
+    }
+}
+
+/// Odebere z argumentů název instance (argument `instance`)
+fn take_instance(arguments: Option<Value>) -> (Option<Value>, Option<String>) {
+    match arguments {
+        Some(Value::Object(mut map)) => {
+            let instance = map.remove("instance").and_then(|v| v.as_str().map(str::to_string));
+            (Some(Value::Object(map)), instance)
+        }
+        other => (other, None),
+    }
+}
+
+/// Odebere z argumentů příznak `fresh` a vrátí jeho hodnotu
+fn take_fresh_flag(arguments: Option<Value>) -> (Option<Value>, bool) {
+    match arguments {
+        Some(Value::Object(mut map)) => {
+            let fresh = map.remove("fresh").and_then(|v| v.as_bool()).unwrap_or(false);
+            (Some(Value::Object(map)), fresh)
+        }
+        other => (other, false),
+    }
+}
+
+/// Klíč cache výsledků - název toolu + argumenty se seřazenými klíči
+fn result_cache_key(namespace: &str, tool_name: &str, arguments: &Option<Value>) -> String {
+    match arguments {
+        Some(arguments) => format!("{}::{}:{}", namespace, tool_name, canonicalize(arguments)),
+        None => format!("{}::{}:{{}}", namespace, tool_name),
+    }
+}
+
+fn canonicalize(value: &Value) -> String {
+    match value {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            let entries: Vec<String> = keys.into_iter()
+                .filter(|key| !map[*key].is_null())
+                .map(|key| format!("{}:{}", Value::String(key.clone()), canonicalize(&map[key])))
+                .collect();
+            format!("{{{}}}", entries.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(canonicalize).collect();
+            format!("[{}]", items.join(","))
+        }
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::protocol::ToolResult;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::BTreeSet;
+
+    /// Tool, který vrací přijaté argumenty (jako v create_issues_batch a log_time_bulk)
+    struct EchoTool;
+
+    #[async_trait]
+    impl ToolExecutor for EchoTool {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn description(&self) -> &str {
+            "Vrátí argumenty"
+        }
+
+        fn input_schema(&self) -> Value {
+            json!({
+                "project_id": { "type": "integer" },
+                "issues": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "project_id": { "type": "integer" },
+                            "subject": { "type": "string" }
+                        }
+                    }
+                },
+                "defaults": {
+                    "type": "object",
+                    "properties": { "project_id": { "type": "integer" } }
+                },
+                "note": { "type": "string" }
+            })
+        }
+
+        async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+            Ok(CallToolResult::success(vec![ToolResult::text(arguments.unwrap_or_default().to_string())]))
+        }
+    }
+
+    async fn registry(config: &AppConfig) -> ToolRegistry {
+        let api_client = EasyProjectClient::new(config).await.unwrap();
+        ToolRegistry::new(api_client, config)
+    }
+
+    fn test_config() -> AppConfig {
+        let mut config = AppConfig::default();
+        config.easyproject.api_key = Some("test".to_string());
+        config
+    }
+
+    async fn tool_names(read_only: bool) -> BTreeSet<String> {
+        let mut config = test_config();
+        config.tools.read_only = read_only;
+        let registry = registry(&config).await;
+        assert_eq!(registry.is_read_only(), read_only);
+        registry.list_tools().into_iter().map(|tool| tool.name).collect()
+    }
+
+    #[tokio::test]
+    async fn test_instance_registry_by_argument() {
+        let mut archive_config = test_config();
+        archive_config.easyproject.instance_name = "archiv".to_string();
+        archive_config.tools.read_only = true;
+        let archive = registry(&archive_config).await;
+        let mut registry = registry(&test_config()).await;
+        registry.add_instance(archive);
+
+        let default_name = registry.instance_name().to_string();
+        assert_eq!(registry.instance_registry(None).unwrap().instance_name(), default_name);
+        assert_eq!(registry.instance_registry(Some(&default_name)).unwrap().instance_name(), default_name);
+
+        let archive = registry.instance_registry(Some("archiv")).unwrap();
+        assert_eq!(archive.instance_name(), "archiv");
+        assert!(registry.has_tool("create_issue"));
+        assert!(!archive.has_tool("create_issue"));
+
+        assert!(registry.instance_registry(Some("neznama")).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_only_mode_skips_exactly_mutating_tools() {
+        let all = tool_names(false).await;
+        let read_only = tool_names(true).await;
+
+        // Při přidání tool, který mění data, je nutné ho doplnit i sem
+        let skipped: Vec<&str> = all.difference(&read_only).map(String::as_str).collect();
+        assert_eq!(skipped, vec![
+            "add_watcher", "assign_issue", "bulk_edit_descriptions", "clone_issue", "complete_task",
+            "create_baseline", "create_issue", "create_issues_batch", "create_milestone", "create_project",
+            "create_project_from_template", "create_time_entry", "delete_issue", "delete_milestone",
+            "delete_project", "delete_time_entry", "log_time", "log_time_bulk", "move_time_entries",
+            "remove_watcher", "set_project_modules", "transition_issue", "update_issue", "update_milestone",
+            "update_project", "update_time_entry", "upload_attachment"
+        ]);
+        assert!(read_only.is_subset(&all));
+    }
+
+    #[tokio::test]
+    async fn test_nested_project_references_are_resolved() {
+        let mut registry = registry(&test_config()).await;
+        registry.tools.insert("echo".to_string(), Arc::new(EchoTool));
+
+        let arguments = json!({
+            "project_id": "12",
+            "issues": [
+                { "subject": "A", "project_id": "34" },
+                { "subject": "B", "project_id": 56 }
+            ],
+            "defaults": { "project_id": " 78 " },
+            "note": "90"
+        });
+        let result = registry.execute_tool("echo", Some(arguments)).await.unwrap();
+        let ToolResult::Text { text } = &result.content[0] else {
+            panic!("očekáván text");
+        };
+        let echoed: Value = serde_json::from_str(text).unwrap();
+
+        assert_eq!(echoed["project_id"], 12);
+        assert_eq!(echoed["issues"][0]["project_id"], 34);
+        assert_eq!(echoed["issues"][1]["project_id"], 56);
+        assert_eq!(echoed["defaults"]["project_id"], 78);
+        assert_eq!(echoed["note"], "90");
+    }
+
+    #[test]
+    fn test_nested_project_reference_schema_is_widened() {
+        let mut schema = EchoTool.input_schema();
+        widen_project_reference(&mut schema);
+
+        assert_eq!(schema["project_id"]["type"], json!(["integer", "string"]));
+        assert_eq!(schema["issues"]["items"]["properties"]["project_id"]["type"], json!(["integer", "string"]));
+        assert_eq!(schema["defaults"]["properties"]["project_id"]["type"], json!(["integer", "string"]));
+        assert_eq!(schema["note"]["type"], "string");
+    }
+}
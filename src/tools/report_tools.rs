@@ -1,27 +1,392 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tracing::{debug, error, info};
-use chrono::{Utc, Local};
+use tracing::{debug, error, info, warn};
+use chrono::{Datelike, NaiveDate, Utc};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 use crate::api::EasyProjectClient;
+use crate::api::client::{date_range_filter, IssueQuery, UserListFilter};
+use crate::api::models::{Issue, IssueRelation, TimeEntry};
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
 use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::scheduler::parse_report_file_name;
+use crate::snapshots::{Baseline, BaselineIssue, SnapshotStore, BASELINE_SNAPSHOT_KIND};
+use crate::utils::date_utils::{add_business_days, business_day_offset, business_days_between, parse_date, parse_timezone, DateZone, OverdueRule};
+use crate::utils::formatting::{csv_rows, ExportFormat};
+use crate::utils::text_utils::compare_czech;
 use super::executor::ToolExecutor;
 
+// === STREAMING AGREGACE ===
+
+/// Vrací true, pokud datum (YYYY-MM-DD) spadá do zadaného období
+fn in_period(date: &str, from_date: &Option<String>, to_date: &Option<String>) -> bool {
+    let after_from = from_date.as_ref().map(|from| date >= from.as_str()).unwrap_or(true);
+    let before_to = to_date.as_ref().map(|to| date <= to.as_str()).unwrap_or(true);
+    after_from && before_to
+}
+
+/// Průběžně počítané statistiky úkolů - detaily se drží jen na vyžádání a do limitu
+struct IssueStats {
+    total: usize,
+    completed: usize,
+    in_progress: usize,
+    overdue: usize,
+    estimated_hours: f64,
+    by_status: HashMap<String, usize>,
+    by_priority: HashMap<String, usize>,
+    /// Počet přiřazených úkolů podle ID řešitele: (celkem, otevřené)
+    by_assignee: HashMap<i32, (usize, usize)>,
+    details: Vec<Issue>,
+    max_details: usize,
+    overdue_rule: OverdueRule,
+}
+
+impl IssueStats {
+    fn new(max_details: usize, overdue_rule: OverdueRule) -> Self {
+        Self {
+            total: 0,
+            completed: 0,
+            in_progress: 0,
+            overdue: 0,
+            estimated_hours: 0.0,
+            by_status: HashMap::new(),
+            by_priority: HashMap::new(),
+            by_assignee: HashMap::new(),
+            details: Vec::new(),
+            max_details,
+            overdue_rule,
+        }
+    }
+
+    fn add(&mut self, issue: &Issue) {
+        let ratio = issue.done_ratio.unwrap_or(0);
+        self.total += 1;
+        if ratio == 100 {
+            self.completed += 1;
+        } else if ratio > 0 {
+            self.in_progress += 1;
+        }
+        if overdue_days(&self.overdue_rule, issue).is_some() {
+            self.overdue += 1;
+        }
+        self.estimated_hours += issue.estimated_hours.unwrap_or(0.0);
+        *self.by_status.entry(issue.status.name.clone()).or_insert(0) += 1;
+        *self.by_priority.entry(issue.priority.name.clone()).or_insert(0) += 1;
+        if let Some(ref assignee) = issue.assigned_to {
+            let counts = self.by_assignee.entry(assignee.id).or_insert((0, 0));
+            counts.0 += 1;
+            if ratio < 100 {
+                counts.1 += 1;
+            }
+        }
+        if self.details.len() < self.max_details {
+            self.details.push(issue.clone());
+        }
+    }
+
+    fn completion_rate(&self) -> f64 {
+        if self.total > 0 {
+            (self.completed as f64 / self.total as f64 * 100.0).round()
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Počet dní po termínu nedokončeného úkolu, `None` pokud úkol není po termínu
+fn overdue_days(rule: &OverdueRule, issue: &Issue) -> Option<i64> {
+    let due_date = issue.due_date?;
+    let assignee_id = issue.assigned_to.as_ref().map(|assignee| assignee.id);
+    if issue.done_ratio.unwrap_or(0) >= 100 || !rule.is_overdue(due_date, assignee_id) {
+        return None;
+    }
+    Some((rule.today_for(assignee_id) - due_date).num_days())
+}
+
+/// Zbývající odhad úkolu - odhad snížený o procento hotovo
+fn remaining_estimate(issue: &Issue) -> Option<f64> {
+    let estimated = issue.estimated_hours.filter(|hours| *hours > 0.0)?;
+    let ratio = issue.done_ratio.unwrap_or(0).clamp(0, 100) as f64;
+    Some(estimated * (100.0 - ratio) / 100.0)
+}
+
+/// Průběžně počítané statistiky časových záznamů
+struct TimeEntryStats {
+    total_entries: usize,
+    total_hours: f64,
+    by_user: HashMap<String, f64>,
+    by_activity: HashMap<String, f64>,
+    hours_by_user_id: HashMap<i32, f64>,
+    details: Vec<TimeEntry>,
+    max_details: usize,
+}
+
+impl TimeEntryStats {
+    fn new(max_details: usize) -> Self {
+        Self {
+            total_entries: 0,
+            total_hours: 0.0,
+            by_user: HashMap::new(),
+            by_activity: HashMap::new(),
+            hours_by_user_id: HashMap::new(),
+            details: Vec::new(),
+            max_details,
+        }
+    }
+
+    fn add(&mut self, entry: &TimeEntry) {
+        self.total_entries += 1;
+        self.total_hours += entry.hours;
+        *self.by_user.entry(entry.user.name.clone()).or_insert(0.0) += entry.hours;
+        *self.by_activity.entry(entry.activity.name.clone()).or_insert(0.0) += entry.hours;
+        *self.hours_by_user_id.entry(entry.user.id).or_insert(0.0) += entry.hours;
+        if self.details.len() < self.max_details {
+            self.details.push(entry.clone());
+        }
+    }
+
+    fn average_per_entry(&self) -> f64 {
+        if self.total_entries > 0 {
+            self.total_hours / self.total_entries as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Časové pásmo sestav z konfigurace (neplatná hodnota spadne na lokální pásmo)
+fn report_timezone(config: &crate::config::AppConfig) -> DateZone {
+    parse_timezone(&config.tools.reports.timezone).unwrap_or_else(|e| {
+        warn!("{} - používám lokální časové pásmo", e);
+        DateZone::Local
+    })
+}
+
 // === GENERATE PROJECT REPORT TOOL ===
 
 pub struct GenerateProjectReportTool {
     api_client: EasyProjectClient,
+    max_detail_records: usize,
+    timezone: DateZone,
 }
 
 impl GenerateProjectReportTool {
-    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
-        Self { api_client }
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            max_detail_records: config.tools.reports.max_detail_records as usize,
+            timezone: report_timezone(&config),
+        }
+    }
+    
+    /// Načte časová pásma (utc_offset) členů projektu
+    async fn member_utc_offsets(&self, project_id: i32) -> HashMap<i32, i32> {
+        let mut offsets = HashMap::new();
+        let memberships = match self.api_client.list_all_project_memberships(project_id).await {
+            Ok(memberships) => memberships,
+            Err(e) => {
+                warn!("Nepodařilo se načíst členy projektu {} pro časová pásma: {}", project_id, e);
+                return offsets;
+            }
+        };
+        
+        for user in memberships.iter().filter_map(|membership| membership.user.as_ref()) {
+            if offsets.contains_key(&user.id) {
+                continue;
+            }
+            match self.api_client.get_user(user.id).await {
+                Ok(response) => {
+                    if let Some(offset) = response.user.utc_offset {
+                        offsets.insert(user.id, offset);
+                    }
+                }
+                Err(e) => debug!("Nepodařilo se načíst časové pásmo uživatele {}: {}", user.id, e),
+            }
+        }
+        
+        offsets
+    }
+    
+    /// Sestaví sestavu projektu jako JSON (chyba jen pokud projekt nelze načíst)
+    pub(crate) async fn build_report(&self, args: &GenerateProjectReportArgs) -> crate::api::error::ApiResult<Value> {
+        let include_time_entries = args.include_time_entries.unwrap_or(true);
+        let include_issues = args.include_issues.unwrap_or(true);
+        let include_users = args.include_users.unwrap_or(true);
+        let max_details = if args.include_details.unwrap_or(false) { self.max_detail_records } else { 0 };
+        
+        // 1. Získáme detail projektu
+        let project_response = self.api_client.get_project(args.project_id, Some(vec!["trackers".to_string(), "enabled_modules".to_string()])).await?;
+        
+        let project = &project_response.project;
+        let mut report = json!({
+            "project": {
+                "id": project.id,
+                "name": project.name,
+                "description": project.description,
+                "status": project.status,
+                "created_on": project.created_on,
+                "updated_on": project.updated_on
+            },
+            "report_generated_at": Utc::now(),
+            "period": {
+                "from": args.from_date,
+                "to": args.to_date
+            }
+        });
+        
+        // Statistiky si držíme i pro přehled členů projektu
+        let mut issue_stats: Option<IssueStats> = None;
+        let mut time_entry_stats: Option<TimeEntryStats> = None;
+        
+        // 2. Statistiky úkolů (pokud je požadováno)
+        if include_issues {
+            let mut overdue_rule = OverdueRule::new(self.timezone);
+            if args.use_assignee_timezone.unwrap_or(false) {
+                overdue_rule = overdue_rule.with_assignee_offsets(self.member_utc_offsets(args.project_id).await);
+            }
+            let mut stats = IssueStats::new(max_details, overdue_rule);
+            // Období podle data vytvoření vyhodnotí API, načtou se jen odpovídající úkoly
+            let created_on = date_range_filter(args.from_date.as_deref(), args.to_date.as_deref());
+            let scan = self.api_client.scan_issues(Some(args.project_id), None, args.include_subprojects, created_on, |issues| {
+                for issue in issues {
+                    stats.add(issue);
+                }
+            }).await;
+
+            match scan {
+                Ok(scan) => {
+                    report["issues"] = json!({
+                        "summary": {
+                            "total": stats.total,
+                            "completed": stats.completed,
+                            "in_progress": stats.in_progress,
+                            "pending": stats.total - stats.completed - stats.in_progress,
+                            "overdue": stats.overdue,
+                            "completion_rate": stats.completion_rate(),
+                            "total_estimated_hours": stats.estimated_hours
+                        },
+                        "overdue_rule": stats.overdue_rule.describe(),
+                        "by_status": stats.by_status,
+                        "by_priority": stats.by_priority,
+                        "scan": scan
+                    });
+                    if max_details > 0 {
+                        report["issues"]["details"] = json!(stats.details);
+                    }
+                    issue_stats = Some(stats);
+                }
+                Err(e) => {
+                    error!("Chyba při získávání úkolů pro projekt {}: {}", args.project_id, e);
+                    report["issues"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_issues, e)});
+                }
+            }
+        }
+        
+        // 3. Časové záznamy (pokud je požadováno)
+        if include_time_entries {
+            let mut stats = TimeEntryStats::new(max_details);
+            let scan = self.api_client.scan_time_entries(Some(args.project_id), None, args.from_date.clone(), args.to_date.clone(), args.include_subprojects, |entries| {
+                for entry in entries {
+                    if in_period(&entry.spent_on.format("%Y-%m-%d").to_string(), &args.from_date, &args.to_date) {
+                        stats.add(entry);
+                    }
+                }
+            }).await;
+
+            match scan {
+                Ok(scan) => {
+                    report["time_entries"] = json!({
+                        "summary": {
+                            "total_entries": stats.total_entries,
+                            "total_hours": stats.total_hours,
+                            "average_per_entry": stats.average_per_entry()
+                        },
+                        "by_user": stats.by_user,
+                        "by_activity": stats.by_activity,
+                        "scan": scan
+                    });
+                    if max_details > 0 {
+                        report["time_entries"]["details"] = json!(stats.details);
+                    }
+                    time_entry_stats = Some(stats);
+                }
+                Err(e) => {
+                    error!("Chyba při získávání časových záznamů pro projekt {}: {}", args.project_id, e);
+                    report["time_entries"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_time_entries, e)});
+                }
+            }
+        }
+        
+        // 4. Přehled uživatelů (pokud je požadováno)
+        if include_users {
+            // Pouze skuteční členové projektu s jejich rolemi
+            match self.api_client.list_all_project_memberships(args.project_id).await {
+                Ok(memberships) => {
+                    let mut members = Vec::new();
+                    let mut groups = Vec::new();
+                    
+                    for membership in &memberships {
+                        let roles: Vec<&str> = membership.roles.iter().map(|r| r.name.as_str()).collect();
+                        
+                        if let Some(ref user) = membership.user {
+                            let (assigned, open) = issue_stats.as_ref()
+                                .and_then(|s| s.by_assignee.get(&user.id).copied())
+                                .unwrap_or((0, 0));
+                            let hours = time_entry_stats.as_ref()
+                                .and_then(|s| s.hours_by_user_id.get(&user.id).copied())
+                                .unwrap_or(0.0);
+                            
+                            let mut member = json!({
+                                "id": user.id,
+                                "name": user.name,
+                                "roles": roles
+                            });
+                            if issue_stats.is_some() {
+                                member["assigned_issues"] = json!(assigned);
+                                member["open_issues"] = json!(open);
+                            }
+                            if time_entry_stats.is_some() {
+                                member["logged_hours"] = json!(hours);
+                            }
+                            members.push(member);
+                        } else if let Some(ref group) = membership.group {
+                            groups.push(json!({
+                                "id": group.id,
+                                "name": group.name,
+                                "roles": roles
+                            }));
+                        }
+                    }
+                    
+                    let by_name = |a: &Value, b: &Value| compare_czech(a["name"].as_str().unwrap_or(""), b["name"].as_str().unwrap_or(""));
+                    members.sort_by(by_name);
+                    groups.sort_by(by_name);
+                    
+                    report["users"] = json!({
+                        "summary": {
+                            "total_members": members.len(),
+                            "total_groups": groups.len()
+                        },
+                        "members": members,
+                        "groups": groups
+                    });
+                }
+                Err(e) => {
+                    error!("Chyba při získávání členů projektu {}: {}", args.project_id, e);
+                    report["users"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_project_members, e)});
+                }
+            }
+        }
+        
+        Ok(report)
     }
 }
 
 #[derive(Debug, Deserialize)]
-struct GenerateProjectReportArgs {
+pub(crate) struct GenerateProjectReportArgs {
     project_id: i32,
     #[serde(default)]
     from_date: Option<String>,
@@ -33,6 +398,14 @@ struct GenerateProjectReportArgs {
     include_issues: Option<bool>,
     #[serde(default)]
     include_users: Option<bool>,
+    #[serde(default)]
+    include_details: Option<bool>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    use_assignee_timezone: Option<bool>,
+    #[serde(default)]
+    export_format: Option<ExportFormat>,
 }
 
 #[async_trait]
@@ -73,225 +446,71 @@ impl ToolExecutor for GenerateProjectReportTool {
             },
             "include_users": {
                 "type": "boolean",
-                "description": "Zahrnout přehled uživatelů do sestavy (výchozí: true)",
+                "description": "Zahrnout přehled členů projektu s rolemi a statistikami (výchozí: true)",
                 "default": true
-            }
+            },
+            "include_details": {
+                "type": "boolean",
+                "description": "Zahrnout detailní seznam úkolů a časových záznamů (omezeno konfigurací max_detail_records, výchozí: false)",
+                "default": false
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly a časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "use_assignee_timezone": {
+                "type": "boolean",
+                "description": "Určit úkoly po termínu podle časového pásma řešitele (utc_offset), jinak podle konfigurace (výchozí: false)",
+                "default": false
+            },
+            "export_format": ExportFormat::schema()
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GenerateProjectReportArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'project_id'")?
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_id"))?
         )?;
         
-        let include_time_entries = args.include_time_entries.unwrap_or(true);
-        let include_issues = args.include_issues.unwrap_or(true);
-        let include_users = args.include_users.unwrap_or(true);
-        
         debug!("Generuji sestavu pro projekt {}", args.project_id);
         
-        // 1. Získáme detail projektu
-        let project_response = match self.api_client.get_project(args.project_id, Some(vec!["trackers".to_string(), "enabled_modules".to_string()])).await {
-            Ok(response) => response,
+        let report = match self.build_report(&args).await {
+            Ok(report) => report,
             Err(e) => {
                 error!("Chyba při získávání projektu {}: {}", args.project_id, e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání projektu {}: {}", args.project_id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.project_id), e))
                 ]));
             }
         };
         
-        let project = &project_response.project;
-        let mut report = json!({
-            "project": {
-                "id": project.id,
-                "name": project.name,
-                "description": project.description,
-                "status": project.status,
-                "created_on": project.created_on,
-                "updated_on": project.updated_on
-            },
-            "report_generated_at": Utc::now(),
-            "period": {
-                "from": args.from_date,
-                "to": args.to_date
-            }
-        });
-        
-        // 2. Statistiky úkolů (pokud je požadováno)
-        if include_issues {
-            match self.api_client.list_issues(Some(args.project_id), Some(1000), None, None, None, None, None, None, None, None, None).await {
-                Ok(issues_response) => {
-                    let issues = &issues_response.issues;
-                    
-                    // Filtrování podle data
-                    let filtered_issues: Vec<_> = if args.from_date.is_some() || args.to_date.is_some() {
-                        issues.iter().filter(|issue| {
-                            if let Some(ref created_on) = issue.created_on {
-                                let issue_date = created_on.format("%Y-%m-%d").to_string();
-                                
-                                let after_from = args.from_date.as_ref()
-                                    .map(|from| issue_date >= *from)
-                                    .unwrap_or(true);
-                                    
-                                let before_to = args.to_date.as_ref()
-                                    .map(|to| issue_date <= *to)
-                                    .unwrap_or(true);
-                                    
-                                after_from && before_to
-                            } else {
-                                true
-                            }
-                        }).collect()
-                    } else {
-                        issues.iter().collect()
-                    };
-                    
-                    let total_issues = filtered_issues.len();
-                    let completed_issues = filtered_issues.iter()
-                        .filter(|issue| issue.done_ratio.unwrap_or(0) == 100)
-                        .count();
-                    let in_progress_issues = filtered_issues.iter()
-                        .filter(|issue| {
-                            let ratio = issue.done_ratio.unwrap_or(0);
-                            ratio > 0 && ratio < 100
-                        })
-                        .count();
-                    let pending_issues = total_issues - completed_issues - in_progress_issues;
-                    
-                    let total_estimated_hours: f64 = filtered_issues.iter()
-                        .filter_map(|issue| issue.estimated_hours)
-                        .sum();
-                    
-                    // Seskupení podle statusu
-                    let mut status_counts = std::collections::HashMap::new();
-                    for issue in &filtered_issues {
-                        *status_counts.entry(&issue.status.name).or_insert(0) += 1;
-                    }
-                    
-                    // Seskupení podle priority
-                    let mut priority_counts = std::collections::HashMap::new();
-                    for issue in &filtered_issues {
-                        *priority_counts.entry(&issue.priority.name).or_insert(0) += 1;
-                    }
-                    
-                    report["issues"] = json!({
-                        "summary": {
-                            "total": total_issues,
-                            "completed": completed_issues,
-                            "in_progress": in_progress_issues,
-                            "pending": pending_issues,
-                            "completion_rate": if total_issues > 0 { 
-                                (completed_issues as f64 / total_issues as f64 * 100.0).round() 
-                            } else { 0.0 },
-                            "total_estimated_hours": total_estimated_hours
-                        },
-                        "by_status": status_counts,
-                        "by_priority": priority_counts,
-                        "details": filtered_issues
-                    });
-                }
-                Err(e) => {
-                    error!("Chyba při získávání úkolů pro projekt {}: {}", args.project_id, e);
-                    report["issues"] = json!({"error": format!("Chyba při získávání úkolů: {}", e)});
-                }
-            }
-        }
-        
-        // 3. Časové záznamy (pokud je požadováno)
-        if include_time_entries {
-            match self.api_client.list_time_entries(Some(args.project_id), None, None, Some(1000), None, args.from_date.clone(), args.to_date.clone()).await {
-                Ok(time_entries_response) => {
-                    let time_entries = &time_entries_response.time_entries;
-                    
-                    // Filtrování podle data
-                    let filtered_entries: Vec<_> = if args.from_date.is_some() || args.to_date.is_some() {
-                        time_entries.iter().filter(|entry| {
-                            let entry_date = entry.spent_on.format("%Y-%m-%d").to_string();
-                            
-                            let after_from = args.from_date.as_ref()
-                                .map(|from| entry_date >= *from)
-                                .unwrap_or(true);
-                                
-                            let before_to = args.to_date.as_ref()
-                                .map(|to| entry_date <= *to)
-                                .unwrap_or(true);
-                                
-                            after_from && before_to
-                        }).collect()
-                    } else {
-                        time_entries.iter().collect()
-                    };
-                    
-                    let total_hours: f64 = filtered_entries.iter()
-                        .map(|entry| entry.hours)
-                        .sum();
-                    
-                    // Seskupení podle uživatelů
-                    let mut user_hours = std::collections::HashMap::new();
-                    for entry in &filtered_entries {
-                        let hours = user_hours.entry(&entry.user.name).or_insert(0.0);
-                        *hours += entry.hours;
-                    }
-                    
-                    // Seskupení podle aktivit
-                    let mut activity_hours = std::collections::HashMap::new();
-                    for entry in &filtered_entries {
-                        let hours = activity_hours.entry(&entry.activity.name).or_insert(0.0);
-                        *hours += entry.hours;
-                    }
-                    
-                    report["time_entries"] = json!({
-                        "summary": {
-                            "total_entries": filtered_entries.len(),
-                            "total_hours": total_hours,
-                            "average_per_entry": if !filtered_entries.is_empty() { 
-                                total_hours / filtered_entries.len() as f64 
-                            } else { 0.0 }
-                        },
-                        "by_user": user_hours,
-                        "by_activity": activity_hours,
-                        "details": filtered_entries
-                    });
-                }
-                Err(e) => {
-                    error!("Chyba při získávání časových záznamů pro projekt {}: {}", args.project_id, e);
-                    report["time_entries"] = json!({"error": format!("Chyba při získávání časových záznamů: {}", e)});
-                }
-            }
-        }
+        let project_name = report["project"]["name"].as_str().unwrap_or("").to_string();
         
-        // 4. Přehled uživatelů (pokud je požadováno)
-        if include_users {
-            // Získáme seznam všech uživatelů a pak filtrujeme ty, kteří pracují na projektu
-            match self.api_client.list_users(Some(100), None, None, None, None, None).await {
-                Ok(users_response) => {
-                    // V reálné implementaci bychom získali pouze uživatele projektu
-                    // Pro demonstraci použijeme všechny uživatele
-                    report["users"] = json!({
-                        "summary": {
-                            "total_users": users_response.users.len()
-                        },
-                        "details": users_response.users
-                    });
-                }
-                Err(e) => {
-                    error!("Chyba při získávání uživatelů: {}", e);
-                    report["users"] = json!({"error": format!("Chyba při získávání uživatelů: {}", e)});
-                }
-            }
+        // CSV ve stejném tvaru jako sestavy plánovače (sekce, klíč, hodnota)
+        if args.export_format == Some(ExportFormat::Csv) {
+            info!("Sestava pro projekt {} ({}) exportována do CSV", project_name, args.project_id);
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text(crate::scheduler::export::render_csv(&report))
+            ]));
         }
         
         let report_json = serde_json::to_string_pretty(&report)?;
         
         info!("Úspěšně vygenerována sestava pro projekt {} ({})", 
-              project.name, args.project_id);
+              project_name, args.project_id);
         
         Ok(CallToolResult::success(vec![
             ToolResult::text(format!(
-                "Sestava pro projekt '{}' (ID: {}):\n\n{}",
-                project.name,
-                args.project_id,
+                "{}:\n\n{}",
+                (i18n::messages().project_report)(&project_name, args.project_id),
                 report_json
             ))
         ]))
@@ -302,11 +521,15 @@ impl ToolExecutor for GenerateProjectReportTool {
 
 pub struct GetDashboardDataTool {
     api_client: EasyProjectClient,
+    timezone: DateZone,
 }
 
 impl GetDashboardDataTool {
-    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
-        Self { api_client }
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            timezone: report_timezone(&config),
+        }
     }
 }
 
@@ -358,6 +581,10 @@ impl ToolExecutor for GetDashboardDataTool {
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetDashboardDataArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -407,124 +634,68 @@ impl ToolExecutor for GetDashboardDataTool {
             }
             Err(e) => {
                 error!("Chyba při získávání projektů: {}", e);
-                dashboard["projects"] = json!({"error": format!("Chyba při získávání projektů: {}", e)});
+                dashboard["projects"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_projects, e)});
             }
         }
         
         // 2. Přehled úkolů
-        match self.api_client.list_issues(None, Some(1000), None, None, None, None, None, None, None, None, None).await {
-            Ok(issues_response) => {
-                let mut issues = issues_response.issues;
-                
-                // Filtrování podle projektů
-                if let Some(ref project_ids) = args.project_ids {
-                    issues.retain(|issue| project_ids.contains(&issue.project.id));
-                }
-                
-                // Filtrování podle uživatele
-                if let Some(user_id) = args.user_id {
-                    issues.retain(|issue| {
-                        issue.assigned_to.as_ref().map(|u| u.id) == Some(user_id)
-                    });
-                }
-                
-                // Filtrování podle data
-                if args.from_date.is_some() || args.to_date.is_some() {
-                    issues.retain(|issue| {
-                        if let Some(ref created_on) = issue.created_on {
-                            let issue_date = created_on.format("%Y-%m-%d").to_string();
-                            
-                            let after_from = args.from_date.as_ref()
-                                .map(|from| issue_date >= *from)
-                                .unwrap_or(true);
-                                
-                            let before_to = args.to_date.as_ref()
-                                .map(|to| issue_date <= *to)
-                                .unwrap_or(true);
-                                
-                            after_from && before_to
-                        } else {
-                            true
-                        }
-                    });
+        let mut stats = IssueStats::new(0, OverdueRule::new(self.timezone));
+        let created_on = date_range_filter(args.from_date.as_deref(), args.to_date.as_deref());
+        let scan = self.api_client.scan_issues(None, args.user_id, None, created_on, |issues| {
+            for issue in issues {
+                let in_projects = args.project_ids.as_ref()
+                    .map(|ids| ids.contains(&issue.project.id))
+                    .unwrap_or(true);
+                if in_projects {
+                    stats.add(issue);
                 }
-                
-                let total_issues = issues.len();
-                let completed_issues = issues.iter()
-                    .filter(|issue| issue.done_ratio.unwrap_or(0) == 100)
-                    .count();
-                let overdue_issues = issues.iter()
-                    .filter(|issue| {
-                        if let Some(ref due_date) = issue.due_date {
-                            let today = Local::now().date_naive();
-                            due_date < &today && issue.done_ratio.unwrap_or(0) < 100
-                        } else {
-                            false
-                        }
-                    })
-                    .count();
-                
+            }
+        }).await;
+
+        match scan {
+            Ok(scan) => {
                 dashboard["issues"] = json!({
-                    "total": total_issues,
-                    "completed": completed_issues,
-                    "in_progress": issues.iter().filter(|issue| {
-                        let ratio = issue.done_ratio.unwrap_or(0);
-                        ratio > 0 && ratio < 100
-                    }).count(),
-                    "pending": total_issues - completed_issues,
-                    "overdue": overdue_issues,
-                    "completion_rate": if total_issues > 0 { 
-                        (completed_issues as f64 / total_issues as f64 * 100.0).round() 
-                    } else { 0.0 }
+                    "total": stats.total,
+                    "completed": stats.completed,
+                    "in_progress": stats.in_progress,
+                    "pending": stats.total - stats.completed,
+                    "overdue": stats.overdue,
+                    "overdue_rule": stats.overdue_rule.describe(),
+                    "completion_rate": stats.completion_rate(),
+                    "scan": scan
                 });
             }
             Err(e) => {
                 error!("Chyba při získávání úkolů: {}", e);
-                dashboard["issues"] = json!({"error": format!("Chyba při získávání úkolů: {}", e)});
+                dashboard["issues"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_issues, e)});
             }
         }
         
         // 3. Přehled časových záznamů
-        match self.api_client.list_time_entries(None, None, args.user_id, Some(1000), None, args.from_date.clone(), args.to_date.clone()).await {
-            Ok(time_entries_response) => {
-                let mut time_entries = time_entries_response.time_entries;
-                
-                // Filtrování podle projektů
-                if let Some(ref project_ids) = args.project_ids {
-                    time_entries.retain(|entry| project_ids.contains(&entry.project.id));
-                }
-                
-                // Filtrování podle data
-                if args.from_date.is_some() || args.to_date.is_some() {
-                    time_entries.retain(|entry| {
-                        let entry_date = entry.spent_on.format("%Y-%m-%d").to_string();
-                        
-                        let after_from = args.from_date.as_ref()
-                            .map(|from| entry_date >= *from)
-                            .unwrap_or(true);
-                            
-                        let before_to = args.to_date.as_ref()
-                            .map(|to| entry_date <= *to)
-                            .unwrap_or(true);
-                            
-                        after_from && before_to
-                    });
+        let mut stats = TimeEntryStats::new(0);
+        let scan = self.api_client.scan_time_entries(None, args.user_id, args.from_date.clone(), args.to_date.clone(), None, |entries| {
+            for entry in entries {
+                let in_projects = args.project_ids.as_ref()
+                    .map(|ids| ids.contains(&entry.project.id))
+                    .unwrap_or(true);
+                if in_projects && in_period(&entry.spent_on.format("%Y-%m-%d").to_string(), &args.from_date, &args.to_date) {
+                    stats.add(entry);
                 }
-                
-                let total_hours: f64 = time_entries.iter().map(|entry| entry.hours).sum();
-                let total_entries = time_entries.len();
-                
+            }
+        }).await;
+
+        match scan {
+            Ok(scan) => {
                 dashboard["time_entries"] = json!({
-                    "total_entries": total_entries,
-                    "total_hours": total_hours,
-                    "average_per_entry": if total_entries > 0 { 
-                        total_hours / total_entries as f64 
-                    } else { 0.0 }
+                    "total_entries": stats.total_entries,
+                    "total_hours": stats.total_hours,
+                    "average_per_entry": stats.average_per_entry(),
+                    "scan": scan
                 });
             }
             Err(e) => {
                 error!("Chyba při získávání časových záznamů: {}", e);
-                dashboard["time_entries"] = json!({"error": format!("Chyba při získávání časových záznamů: {}", e)});
+                dashboard["time_entries"] = json!({"error": format!("{}: {}", i18n::messages().error_fetching_time_entries, e)});
             }
         }
         
@@ -534,9 +705,3336 @@ impl ToolExecutor for GetDashboardDataTool {
         
         Ok(CallToolResult::success(vec![
             ToolResult::text(format!(
-                "Dashboard data:\n\n{}",
+                "{}:\n\n{}",
+                i18n::messages().dashboard_data,
                 dashboard_json
             ))
         ]))
     }
-} 
\ No newline at end of file
+} 
+// === ASSIGNMENT HISTORY TOOL ===
+
+pub struct AssignmentHistoryTool {
+    api_client: EasyProjectClient,
+}
+
+impl AssignmentHistoryTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AssignmentHistoryArgs {
+    user_id: i32,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    from_date: Option<String>,
+    #[serde(default)]
+    to_date: Option<String>,
+    #[serde(default = "default_assignment_history_max_issues")]
+    max_issues: usize,
+}
+
+fn default_assignment_history_max_issues() -> usize {
+    100
+}
+
+/// Jedna změna přiřazení rekonstruovaná z historie úkolu
+struct AssignmentEvent {
+    date: chrono::DateTime<Utc>,
+    issue_id: i32,
+    subject: String,
+    assigned: bool,
+    changed_by: Option<String>,
+    counterpart: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for AssignmentHistoryTool {
+    fn name(&self) -> &str {
+        "assignment_history"
+    }
+    
+    fn description(&self) -> &str {
+        "Rekonstruuje z historie úkolů (journals), kdy byly úkoly uživateli přiřazeny nebo odebrány \
+        v zadaném období. Vhodné pro retrospektivy a řešení sporů o kapacitu."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "user_id": {
+                "type": "integer",
+                "description": "ID uživatele, jehož přiřazení sledujeme (povinné)"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "Omezení na konkrétní projekt (nepovinné)"
+            },
+            "from_date": {
+                "type": "string",
+                "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
+                "description": "Začátek období (formát: YYYY-MM-DD)"
+            },
+            "to_date": {
+                "type": "string",
+                "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
+                "description": "Konec období (formát: YYYY-MM-DD)"
+            },
+            "max_issues": {
+                "type": "integer",
+                "description": "Maximální počet úkolů, jejichž historie se prochází (výchozí: 100)",
+                "minimum": 1,
+                "maximum": 500,
+                "default": 100
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["user_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: AssignmentHistoryArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("user_id"))?
+        )?;
+        
+        debug!("Rekonstruuji historii přiřazení pro uživatele {}", args.user_id);
+        
+        // 1. Kandidáti - úkoly aktualizované v daném období (historie jiných úkolů se nemohla změnit)
+        let mut candidates: Vec<i32> = Vec::new();
+        let mut candidates_truncated = false;
+        let scan = self.api_client.scan_issues(args.project_id, None, None, None, |issues| {
+            for issue in issues {
+                let updated_after_from = match (&args.from_date, issue.updated_on) {
+                    (Some(from), Some(updated_on)) => updated_on.format("%Y-%m-%d").to_string() >= *from,
+                    _ => true,
+                };
+                if !updated_after_from {
+                    continue;
+                }
+                if candidates.len() < args.max_issues {
+                    candidates.push(issue.id);
+                } else {
+                    candidates_truncated = true;
+                }
+            }
+        }).await;
+        
+        if let Err(e) = scan {
+            error!("Chyba při získávání úkolů pro historii přiřazení: {}", e);
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
+            ]));
+        }
+        
+        // 2. Projdeme historii kandidátů a vybereme změny pole assigned_to_id
+        let user_key = args.user_id.to_string();
+        let mut events: Vec<AssignmentEvent> = Vec::new();
+        let mut failed_issues = 0;
+        
+        for issue_id in &candidates {
+            let issue = match self.api_client.get_issue(*issue_id, Some(vec!["journals".to_string()])).await {
+                Ok(response) => response.issue,
+                Err(e) => {
+                    debug!("Nepodařilo se načíst historii úkolu {}: {}", issue_id, e);
+                    failed_issues += 1;
+                    continue;
+                }
+            };
+            
+            let journals = issue.journals.clone().unwrap_or_default();
+            let assignment_changes: Vec<_> = journals.iter()
+                .flat_map(|journal| journal.details.iter()
+                    .filter(|detail| detail.property == "attr" && detail.name == "assigned_to_id")
+                    .map(move |detail| (journal, detail)))
+                .collect();
+            
+            // Přiřazení při založení úkolu se v historii neobjevuje jako změna
+            let initial_assignee = match assignment_changes.first() {
+                Some((_, detail)) => detail.old_value.clone(),
+                None => issue.assigned_to.as_ref().map(|u| u.id.to_string()),
+            };
+            if let Some(created_on) = issue.created_on {
+                if initial_assignee.as_deref() == Some(user_key.as_str())
+                    && in_period(&created_on.format("%Y-%m-%d").to_string(), &args.from_date, &args.to_date)
+                {
+                    events.push(AssignmentEvent {
+                        date: created_on,
+                        issue_id: issue.id,
+                        subject: issue.subject.clone(),
+                        assigned: true,
+                        changed_by: issue.author.as_ref().map(|a| a.name.clone()),
+                        counterpart: None,
+                    });
+                }
+            }
+            
+            for (journal, detail) in assignment_changes {
+                let Some(date) = journal.created_on else { continue };
+                if !in_period(&date.format("%Y-%m-%d").to_string(), &args.from_date, &args.to_date) {
+                    continue;
+                }
+                
+                let assigned = detail.new_value.as_deref() == Some(user_key.as_str());
+                let unassigned = detail.old_value.as_deref() == Some(user_key.as_str());
+                if !assigned && !unassigned {
+                    continue;
+                }
+                
+                events.push(AssignmentEvent {
+                    date,
+                    issue_id: issue.id,
+                    subject: issue.subject.clone(),
+                    assigned,
+                    changed_by: journal.user.as_ref().map(|u| u.name.clone()),
+                    counterpart: if assigned { detail.old_value.clone() } else { detail.new_value.clone() },
+                });
+            }
+        }
+        
+        events.sort_by_key(|event| event.date);
+        
+        let assigned_count = events.iter().filter(|e| e.assigned).count();
+        let unassigned_count = events.len() - assigned_count;
+        
+        let messages = i18n::messages();
+        let mut result = format!(
+            "{}:\n\n",
+            (messages.assignment_history_heading)(
+                args.user_id,
+                args.from_date.as_deref().unwrap_or(messages.period_start),
+                args.to_date.as_deref().unwrap_or(messages.today)
+            )
+        );
+        
+        if events.is_empty() {
+            result.push_str(&format!("{}\n", messages.no_assignment_changes));
+        }
+        
+        for event in &events {
+            let action = if event.assigned { messages.assigned_action } else { messages.unassigned_action };
+            let counterpart = match (&event.counterpart, event.assigned) {
+                (Some(id), true) => (messages.previously_user)(id),
+                (Some(id), false) => (messages.newly_user)(id),
+                (None, _) => String::new(),
+            };
+            result.push_str(&format!(
+                "- {} #{} {}: {}{}{}\n",
+                event.date.format("%Y-%m-%d %H:%M"),
+                event.issue_id,
+                event.subject,
+                action,
+                counterpart,
+                event.changed_by.as_deref().map(messages.changed_by).unwrap_or_default()
+            ));
+        }
+        
+        result.push_str(&format!("\n{}", (messages.assignment_summary)(assigned_count, unassigned_count, candidates.len())));
+        if candidates_truncated {
+            result.push_str(&(messages.limited_by_max_issues)(args.max_issues));
+        }
+        if failed_issues > 0 {
+            result.push_str(&(messages.issue_history_failed)(failed_issues));
+        }
+        result.push('\n');
+        
+        info!("Historie přiřazení uživatele {}: {} událostí", args.user_id, events.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(result)
+        ]))
+    }
+}
+
+// === BUDGET BURN REPORT TOOL ===
+
+pub struct BudgetBurnReportTool {
+    api_client: EasyProjectClient,
+    warning_threshold: f64,
+    critical_threshold: f64,
+    timezone: DateZone,
+}
+
+impl BudgetBurnReportTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            warning_threshold: config.tools.reports.budget_warning_threshold,
+            critical_threshold: config.tools.reports.budget_critical_threshold,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BudgetBurnReportArgs {
+    project_ids: Vec<i32>,
+    #[serde(default)]
+    budget_hours: Option<f64>,
+    #[serde(default)]
+    budget_amount: Option<f64>,
+    #[serde(default)]
+    hourly_rate: Option<f64>,
+    #[serde(default = "default_burn_window_days")]
+    window_days: u32,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    warning_threshold: Option<f64>,
+    #[serde(default)]
+    critical_threshold: Option<f64>,
+}
+
+fn default_burn_window_days() -> u32 {
+    30
+}
+
+#[async_trait]
+impl ToolExecutor for BudgetBurnReportTool {
+    fn name(&self) -> &str {
+        "budget_burn_report"
+    }
+    
+    fn description(&self) -> &str {
+        "Porovná odpracované hodiny (případně náklady) projektů s rozpočtem nebo součtem odhadů úkolů, \
+        spočítá tempo čerpání za poslední období a odhadne datum vyčerpání rozpočtu. \
+        Projekty nad nastavenými prahy jsou označeny varováním."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_ids": {
+                "type": "array",
+                "description": "ID projektů k vyhodnocení (povinné)",
+                "items": {
+                    "type": "integer"
+                }
+            },
+            "budget_hours": {
+                "type": "number",
+                "description": "Rozpočet v hodinách pro každý projekt (výchozí: součet odhadů úkolů projektu)"
+            },
+            "budget_amount": {
+                "type": "number",
+                "description": "Finanční rozpočet pro každý projekt (vyžaduje hourly_rate)"
+            },
+            "hourly_rate": {
+                "type": "number",
+                "description": "Hodinová sazba pro přepočet hodin na náklady"
+            },
+            "window_days": {
+                "type": "integer",
+                "description": "Počet posledních dní pro výpočet tempa čerpání (výchozí: 30)",
+                "minimum": 1,
+                "maximum": 365,
+                "default": 30
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly a časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "warning_threshold": {
+                "type": "number",
+                "description": "Podíl vyčerpání pro varování, např. 0.8 = 80 % (výchozí: z konfigurace)"
+            },
+            "critical_threshold": {
+                "type": "number",
+                "description": "Podíl vyčerpání pro kritický stav (výchozí: z konfigurace)"
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_ids"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: BudgetBurnReportArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_ids"))?
+        )?;
+        
+        if args.project_ids.is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().project_ids_required.to_string())
+            ]));
+        }
+        if args.budget_amount.is_some() && args.hourly_rate.is_none() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().budget_requires_rate.to_string())
+            ]));
+        }
+        
+        let warning_threshold = args.warning_threshold.unwrap_or(self.warning_threshold);
+        let critical_threshold = args.critical_threshold.unwrap_or(self.critical_threshold);
+        let window_days = args.window_days.max(1);
+        let today = self.timezone.date_at(Utc::now());
+        let window_start = today - chrono::Duration::days(window_days as i64);
+        
+        debug!("Generuji budget burn report pro projekty {:?}", args.project_ids);
+        
+        let messages = i18n::messages();
+        let [given_budget, financial_budget, estimates_sum] = messages.budget_sources;
+        let mut result = format!(
+            "{}:\n\n",
+            (messages.budget_burn_heading)(window_days, warning_threshold * 100.0, critical_threshold * 100.0)
+        );
+        let mut flagged = Vec::new();
+        
+        for project_id in &args.project_ids {
+            let project_name = match self.api_client.get_project(*project_id, None).await {
+                Ok(response) => response.project.name,
+                Err(e) => {
+                    error!("Chyba při získávání projektu {}: {}", project_id, e);
+                    result.push_str(&format!("{} - {}\n\n", (messages.budget_project_failed)(*project_id), e));
+                    continue;
+                }
+            };
+            
+            // Rozpočet v hodinách - explicitní, z finančního rozpočtu nebo ze součtu odhadů
+            let (budget_hours, budget_source) = match (args.budget_hours, args.budget_amount, args.hourly_rate) {
+                (Some(hours), _, _) => (hours, given_budget),
+                (None, Some(amount), Some(rate)) if rate > 0.0 => (amount / rate, financial_budget),
+                _ => {
+                    let mut estimated = 0.0;
+                    let scan = self.api_client.scan_issues(Some(*project_id), None, args.include_subprojects, None, |issues| {
+                        // Odhady nadřazených úkolů už obsahují podúkoly - sčítáme jen kořenové úkoly
+                        estimated += issues.iter()
+                            .filter(|issue| issue.parent.is_none())
+                            .filter_map(|issue| issue.estimated_hours)
+                            .sum::<f64>();
+                    }).await;
+                    if let Err(e) = scan {
+                        error!("Chyba při získávání úkolů projektu {}: {}", project_id, e);
+                        result.push_str(&format!("{} - {}\n\n", (messages.budget_estimates_failed)(&project_name, *project_id), e));
+                        continue;
+                    }
+                    (estimated, estimates_sum)
+                }
+            };
+            
+            let mut spent_hours = 0.0;
+            let mut window_hours = 0.0;
+            let scan = self.api_client.scan_time_entries(Some(*project_id), None, None, None, args.include_subprojects, |entries| {
+                for entry in entries {
+                    spent_hours += entry.hours;
+                    if entry.spent_on > window_start {
+                        window_hours += entry.hours;
+                    }
+                }
+            }).await;
+            if let Err(e) = scan {
+                error!("Chyba při získávání časových záznamů projektu {}: {}", project_id, e);
+                result.push_str(&format!("{} - {}\n\n", (messages.budget_time_entries_failed)(&project_name, *project_id), e));
+                continue;
+            }
+            
+            let burn_per_day = window_hours / window_days as f64;
+            
+            result.push_str(&format!("📁 {} (ID: {})\n", project_name, project_id));
+            
+            if budget_hours <= 0.0 {
+                result.push_str(&format!("  {}\n\n", (messages.budget_unknown)(spent_hours, burn_per_day)));
+                continue;
+            }
+            
+            let consumed = spent_hours / budget_hours;
+            let remaining_hours = budget_hours - spent_hours;
+            
+            result.push_str(&format!(
+                "  {}\n",
+                (messages.budget_consumption)(budget_hours, budget_source, spent_hours, consumed * 100.0, remaining_hours)
+            ));
+            
+            if let Some(rate) = args.hourly_rate {
+                result.push_str(&format!("  {}\n", (messages.budget_costs)(spent_hours * rate, budget_hours * rate)));
+            }
+            
+            result.push_str(&format!("  {}\n", (messages.burn_rate)(burn_per_day)));
+            
+            if remaining_hours <= 0.0 {
+                result.push_str(&format!("  {}\n", messages.budget_exhausted));
+            } else if burn_per_day > 0.0 {
+                let days_left = (remaining_hours / burn_per_day).ceil() as i64;
+                let exhaustion_date = today + chrono::Duration::days(days_left);
+                result.push_str(&format!(
+                    "  {}\n",
+                    (messages.budget_exhaustion_estimate)(&exhaustion_date.format("%Y-%m-%d").to_string(), days_left)
+                ));
+            } else {
+                result.push_str(&format!("  {}\n", messages.budget_no_recent_burn));
+            }
+            
+            let status = if consumed >= critical_threshold {
+                messages.budget_status_critical
+            } else if consumed >= warning_threshold {
+                messages.budget_status_warning
+            } else {
+                "🟢 OK"
+            };
+            result.push_str(&format!("  {}: {}\n\n", messages.budget_status, status));
+            
+            if consumed >= warning_threshold {
+                flagged.push(format!("{} ({:.0} %)", project_name, consumed * 100.0));
+            }
+        }
+        
+        if flagged.is_empty() {
+            result.push_str(&format!("{}\n", messages.no_project_over_threshold));
+        } else {
+            result.push_str(&format!("{}: {}\n", messages.projects_over_threshold, flagged.join(", ")));
+        }
+        
+        info!("Budget burn report dokončen ({} projektů nad prahem)", flagged.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(result)
+        ]))
+    }
+}
+
+// === LIST GENERATED REPORTS TOOL ===
+
+pub struct ListGeneratedReportsTool {
+    export_dir: PathBuf,
+}
+
+impl ListGeneratedReportsTool {
+    pub fn new(_api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            export_dir: PathBuf::from(&config.scheduler.export_dir),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListGeneratedReportsArgs {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    format: Option<String>,
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    file_name: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for ListGeneratedReportsTool {
+    fn name(&self) -> &str {
+        "list_generated_reports"
+    }
+    
+    fn description(&self) -> &str {
+        "Vypíše sestavy projektů vygenerované plánovačem do exportního adresáře (od nejnovějších). \
+        Se zadaným file_name vrátí obsah sestavy ve formátu Markdown nebo CSV, u PDF jen cestu k souboru."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "Pouze sestavy daného projektu"
+            },
+            "format": {
+                "type": "string",
+                "description": "Pouze sestavy v daném formátu",
+                "enum": ["md", "csv", "pdf"]
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximální počet vypsaných sestav (výchozí: 20)",
+                "minimum": 1,
+                "maximum": 500,
+                "default": 20
+            },
+            "file_name": {
+                "type": "string",
+                "description": "Název souboru sestavy, jejíž obsah se má vrátit"
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListGeneratedReportsArgs = serde_json::from_value(arguments.unwrap_or(json!({})))?;
+        
+        if let Some(file_name) = args.file_name {
+            // Povolené jsou jen názvy vygenerovaných sestav, nikoli cesty mimo exportní adresář
+            let Some((_, _, extension)) = parse_report_file_name(&file_name)
+                .filter(|_| !file_name.contains(['/', '\\']) && !file_name.contains(".."))
+            else {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().invalid_report_file_name)(&file_name))
+                ]));
+            };
+            
+            let path = self.export_dir.join(&file_name);
+            if extension == "pdf" {
+                if !path.is_file() {
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().report_not_found)(&file_name))
+                    ]));
+                }
+                return Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().pdf_report_saved)(&path.display().to_string()))
+                ]));
+            }
+            
+            return match tokio::fs::read_to_string(&path).await {
+                Ok(content) => Ok(CallToolResult::success(vec![ToolResult::text(content)])),
+                Err(e) => Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_reading_report)(&file_name), e))
+                ])),
+            };
+        }
+        
+        let mut entries = match tokio::fs::read_dir(&self.export_dir).await {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().report_dir_empty)(&self.export_dir.display().to_string()))
+                ]));
+            }
+            Err(e) => {
+                error!("Chyba při čtení adresáře sestav {}: {}", self.export_dir.display(), e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_reading_report_dir, e))
+                ]));
+            }
+        };
+        
+        let mut reports = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+            let Some((project_id, generated_at, extension)) = parse_report_file_name(&file_name) else { continue };
+            if args.project_id.is_some_and(|id| id != project_id) {
+                continue;
+            }
+            if args.format.as_deref().is_some_and(|format| !format.eq_ignore_ascii_case(extension)) {
+                continue;
+            }
+            let size = entry.metadata().await.map(|metadata| metadata.len()).unwrap_or(0);
+            reports.push((generated_at, project_id, file_name.clone(), size));
+        }
+        
+        if reports.is_empty() {
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text(i18n::messages().no_generated_reports.to_string())
+            ]));
+        }
+        
+        reports.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.2.cmp(&b.2)));
+        let total = reports.len();
+        let limit = args.limit.unwrap_or(20).clamp(1, 500);
+        
+        let messages = i18n::messages();
+        let mut result = format!(
+            "{}:\n\n",
+            (messages.generated_reports_heading)(&self.export_dir.display().to_string(), total)
+        );
+        for (generated_at, project_id, file_name, size) in reports.iter().take(limit) {
+            result.push_str(&format!(
+                "{}\n",
+                (messages.generated_report_line)(file_name, *project_id, &generated_at.format("%Y-%m-%d %H:%M").to_string(), *size)
+            ));
+        }
+        if total > limit {
+            result.push_str(&format!("\n{}\n", (messages.more_reports)(total - limit)));
+        }
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(result)
+        ]))
+    }
+}
+
+// === CHECK PROJECT CONSISTENCY TOOL ===
+
+pub struct CheckProjectConsistencyTool {
+    api_client: EasyProjectClient,
+}
+
+impl CheckProjectConsistencyTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckProjectConsistencyArgs {
+    project_id: i32,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default = "default_consistency_max_findings")]
+    max_findings: usize,
+}
+
+fn default_consistency_max_findings() -> usize {
+    100
+}
+
+/// Údaje úkolu potřebné pro kontrolu konzistence (celé úkoly se v paměti nedrží)
+struct IssueSnapshot {
+    subject: String,
+    parent_id: Option<i32>,
+    due_date: Option<NaiveDate>,
+    closed: bool,
+    closed_on: Option<NaiveDate>,
+    assignee: Option<(i32, String)>,
+}
+
+impl IssueSnapshot {
+    fn from_issue(issue: &Issue) -> Self {
+        Self {
+            subject: issue.subject.clone(),
+            parent_id: issue.parent.as_ref().map(|parent| parent.id),
+            due_date: issue.due_date,
+            // Starší API neposílá status.is_closed - pak rozhoduje datum uzavření
+            closed: issue.status.is_closed.unwrap_or(issue.closed_on.is_some()),
+            closed_on: issue.closed_on.map(|closed_on| closed_on.date_naive()),
+            assignee: issue.assigned_to.as_ref().map(|user| (user.id, user.name.clone())),
+        }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for CheckProjectConsistencyTool {
+    fn name(&self) -> &str {
+        "check_project_consistency"
+    }
+    
+    fn description(&self) -> &str {
+        "Zkontroluje projekt na časté chyby: podúkoly s termínem po termínu nadřazeného úkolu, \
+        uzavřené nadřazené úkoly s otevřenými podúkoly, úkoly přiřazené zablokovaným uživatelům \
+        a čas vykázaný na uzavřené úkoly po jejich uzavření. Vrací seznam náprav s doporučeným nástrojem."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu ke kontrole (povinné)"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly a časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "max_findings": {
+                "type": "integer",
+                "description": "Maximální počet nálezů v každé kontrole (výchozí: 100)",
+                "minimum": 1,
+                "maximum": 1000,
+                "default": 100
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CheckProjectConsistencyArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_id"))?
+        )?;
+        let max_findings = args.max_findings.clamp(1, 1000);
+        
+        debug!("Kontroluji konzistenci projektu {}", args.project_id);
+        
+        let mut issues: HashMap<i32, IssueSnapshot> = HashMap::new();
+        let issue_scan = self.api_client.scan_issues(Some(args.project_id), None, args.include_subprojects, None, |page| {
+            for issue in page {
+                issues.insert(issue.id, IssueSnapshot::from_issue(issue));
+            }
+        }).await;
+        let issue_scan = match issue_scan {
+            Ok(summary) => summary,
+            Err(e) => {
+                error!("Chyba při procházení úkolů projektu {}: {}", args.project_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_scanning_project_issues)(args.project_id), e))
+                ]));
+            }
+        };
+        
+        let mut ids: Vec<i32> = issues.keys().copied().collect();
+        ids.sort_unstable();
+        
+        // 1. Podúkoly s termínem po termínu nadřazeného úkolu
+        let mut late_subtasks = Vec::new();
+        for id in &ids {
+            let issue = &issues[id];
+            let Some(parent_id) = issue.parent_id else { continue };
+            let Some(parent) = issues.get(&parent_id) else { continue };
+            if let (Some(due), Some(parent_due)) = (issue.due_date, parent.due_date) {
+                if due > parent_due {
+                    late_subtasks.push(json!({
+                        "issue_id": id,
+                        "subject": issue.subject,
+                        "due_date": due,
+                        "parent_id": parent_id,
+                        "parent_due_date": parent_due,
+                        "fix": { "tool": "update_issue", "arguments": { "id": id, "due_date": parent_due } }
+                    }));
+                }
+            }
+        }
+        
+        // 2. Uzavřené nadřazené úkoly s otevřenými podúkoly
+        let mut closed_parents = Vec::new();
+        for id in &ids {
+            let issue = &issues[id];
+            let Some(parent_id) = issue.parent_id else { continue };
+            let Some(parent) = issues.get(&parent_id) else { continue };
+            if parent.closed && !issue.closed {
+                closed_parents.push(json!({
+                    "issue_id": id,
+                    "subject": issue.subject,
+                    "parent_id": parent_id,
+                    "parent_subject": parent.subject,
+                    "fix": { "tool": "complete_issue", "arguments": { "id": id } }
+                }));
+            }
+        }
+        
+        // 3. Otevřené úkoly přiřazené zablokovaným uživatelům (stav 3 = zablokovaný)
+        let mut locked_assignees = Vec::new();
+        let locked_users = match self.api_client.list_users(UserListFilter {
+            limit: Some(100),
+            status: Some("3".to_string()),
+            ..Default::default()
+        }).await {
+            Ok(response) => Some(response.users.iter()
+                .filter(|user| user.status == Some(3))
+                .map(|user| user.id)
+                .collect::<std::collections::HashSet<i32>>()),
+            Err(e) => {
+                warn!("Nepodařilo se načíst zablokované uživatele: {}", e);
+                None
+            }
+        };
+        if let Some(ref locked_users) = locked_users {
+            for id in &ids {
+                let issue = &issues[id];
+                let Some((user_id, ref user_name)) = issue.assignee else { continue };
+                if !issue.closed && locked_users.contains(&user_id) {
+                    locked_assignees.push(json!({
+                        "issue_id": id,
+                        "subject": issue.subject,
+                        "assigned_to": { "id": user_id, "name": user_name },
+                        "fix": { "tool": "assign_issue", "arguments": { "id": id }, "missing_arguments": ["assigned_to_id"] }
+                    }));
+                }
+            }
+        }
+        
+        // 4. Čas vykázaný na uzavřené úkoly po jejich uzavření
+        let mut time_after_close = Vec::new();
+        let entry_scan = self.api_client.scan_time_entries(Some(args.project_id), None, None, None, args.include_subprojects, |page| {
+            for entry in page {
+                let Some(issue_id) = entry.issue.as_ref().map(|issue| issue.id) else { continue };
+                let Some(issue) = issues.get(&issue_id) else { continue };
+                if issue.closed && issue.closed_on.is_some_and(|closed_on| entry.spent_on > closed_on) {
+                    time_after_close.push(json!({
+                        "time_entry_id": entry.id,
+                        "issue_id": issue_id,
+                        "subject": issue.subject,
+                        "user": entry.user.name,
+                        "spent_on": entry.spent_on,
+                        "closed_on": issue.closed_on,
+                        "hours": entry.hours,
+                        "fix": { "tool": "move_time_entries", "arguments": { "time_entry_ids": [entry.id] } }
+                    }));
+                }
+            }
+        }).await;
+        if let Err(ref e) = entry_scan {
+            warn!("Nepodařilo se projít časové záznamy projektu {}: {}", args.project_id, e);
+        }
+        
+        let total_findings = late_subtasks.len() + closed_parents.len() + locked_assignees.len() + time_after_close.len();
+        let check = |findings: Vec<Value>, description: &str, skipped: Option<String>| {
+            let count = findings.len();
+            json!({
+                "description": description,
+                "count": count,
+                "skipped": skipped,
+                "issue_ids": findings.iter().map(|finding| finding["issue_id"].clone()).collect::<Vec<_>>(),
+                "findings": findings.into_iter().take(max_findings).collect::<Vec<_>>()
+            })
+        };
+        
+        let messages = i18n::messages();
+        let report = json!({
+            "project_id": args.project_id,
+            "scanned_issues": issue_scan.scanned,
+            "truncated": issue_scan.truncated,
+            "total_findings": total_findings,
+            "checks": {
+                "subtask_due_after_parent": check(late_subtasks, messages.check_subtask_due_after_parent, None),
+                "closed_parent_open_children": check(closed_parents, messages.check_closed_parent_open_children, None),
+                "assigned_to_locked_user": check(
+                    locked_assignees,
+                    messages.check_assigned_to_locked_user,
+                    locked_users.is_none().then(|| messages.locked_users_unavailable.to_string())
+                ),
+                "time_logged_after_close": check(
+                    time_after_close,
+                    messages.check_time_logged_after_close,
+                    entry_scan.as_ref().err().map(|e| format!("{}: {}", messages.error_loading_time_entries, e))
+                )
+            }
+        });
+        
+        info!("Kontrola konzistence projektu {} dokončena: {} nálezů", args.project_id, total_findings);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().consistency_report)(args.project_id, total_findings),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]))
+    }
+}
+
+// === BASELINE TOOLS ===
+
+pub struct CreateBaselineTool {
+    api_client: EasyProjectClient,
+    store: SnapshotStore,
+}
+
+impl CreateBaselineTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            store: SnapshotStore::new(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateBaselineArgs {
+    project_id: i32,
+    name: String,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    overwrite: bool,
+}
+
+#[async_trait]
+impl ToolExecutor for CreateBaselineTool {
+    fn name(&self) -> &str {
+        "create_baseline"
+    }
+    
+    fn description(&self) -> &str {
+        "Uloží pojmenovaný baseline projektu - plánované termíny a odhady všech úkolů. \
+        Odchylky od něj později vyhodnotí baseline_variance_report."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            },
+            "name": {
+                "type": "string",
+                "description": "Název baseline, např. 'schvaleny-plan-2024' (povinné)"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "overwrite": {
+                "type": "boolean",
+                "description": "Přepsat existující baseline se stejným názvem (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id", "name"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: CreateBaselineArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameters)("project_id, name"))?
+        )?;
+        
+        if args.name.trim().is_empty() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().empty_parameter)("name"))
+            ]));
+        }
+        if !args.overwrite && self.store.exists(BASELINE_SNAPSHOT_KIND, args.project_id, &args.name).await {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().baseline_exists)(&args.name, args.project_id))
+            ]));
+        }
+        
+        let project_name = match self.api_client.get_project(args.project_id, None).await {
+            Ok(response) => response.project.name,
+            Err(e) => {
+                error!("Chyba při získávání projektu {}: {}", args.project_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_project)(args.project_id), e))
+                ]));
+            }
+        };
+        
+        let mut issues = Vec::new();
+        let scan = self.api_client.scan_issues(Some(args.project_id), None, args.include_subprojects, None, |page| {
+            issues.extend(page.iter().map(BaselineIssue::from_issue));
+        }).await;
+        let summary = match scan {
+            Ok(summary) => summary,
+            Err(e) => {
+                error!("Chyba při procházení úkolů projektu {}: {}", args.project_id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_scanning_project_issues)(args.project_id), e))
+                ]));
+            }
+        };
+        
+        let baseline = Baseline {
+            name: args.name.trim().to_string(),
+            project_id: args.project_id,
+            project_name,
+            created_at: Utc::now(),
+            issues,
+        };
+        
+        let path = match self.store.save(BASELINE_SNAPSHOT_KIND, args.project_id, &baseline.name, &baseline).await {
+            Ok(path) => path,
+            Err(e) => {
+                error!("Chyba při ukládání baseline '{}': {}", baseline.name, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_saving_baseline, e))
+                ]));
+            }
+        };
+        
+        info!("Uložen baseline '{}' projektu {} ({} úkolů)", baseline.name, args.project_id, baseline.issues.len());
+        
+        let messages = i18n::messages();
+        let mut result = format!(
+            "{}\n",
+            (messages.baseline_saved)(
+                &baseline.name,
+                &baseline.project_name,
+                baseline.project_id,
+                baseline.issues.len(),
+                baseline.issues.iter().filter_map(|issue| issue.estimated_hours).sum::<f64>(),
+                &path.display().to_string()
+            )
+        );
+        if summary.truncated {
+            result.push_str(&format!("{}\n", messages.baseline_scan_truncated));
+        }
+        
+        Ok(CallToolResult::success(vec![ToolResult::text(result)]))
+    }
+}
+
+pub struct BaselineVarianceReportTool {
+    api_client: EasyProjectClient,
+    store: SnapshotStore,
+}
+
+impl BaselineVarianceReportTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            store: SnapshotStore::new(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BaselineVarianceReportArgs {
+    project_id: i32,
+    #[serde(default)]
+    baseline: Option<String>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default = "default_variance_max_details")]
+    max_details: usize,
+}
+
+fn default_variance_max_details() -> usize {
+    20
+}
+
+#[async_trait]
+impl ToolExecutor for BaselineVarianceReportTool {
+    fn name(&self) -> &str {
+        "baseline_variance_report"
+    }
+    
+    fn description(&self) -> &str {
+        "Porovná současný stav projektu s uloženým baseline (create_baseline): posun termínů zahájení a dokončení, \
+        změnu odhadů a odpracované hodiny, přidané a chybějící úkoly. Bez zadání baseline vypíše dostupné baseline."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            },
+            "baseline": {
+                "type": "string",
+                "description": "Název baseline k porovnání"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "max_details": {
+                "type": "integer",
+                "description": "Počet úkolů s největším skluzem ve výpisu (výchozí: 20)",
+                "minimum": 0,
+                "maximum": 500,
+                "default": 20
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: BaselineVarianceReportArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_id"))?
+        )?;
+        
+        let Some(baseline_name) = args.baseline else {
+            let names = self.store.list(BASELINE_SNAPSHOT_KIND, args.project_id).await?;
+            let text = if names.is_empty() {
+                (i18n::messages().no_baselines)(args.project_id)
+            } else {
+                (i18n::messages().project_baselines)(args.project_id, &names.join(", "))
+            };
+            return Ok(CallToolResult::success(vec![ToolResult::text(text)]));
+        };
+        
+        let baseline: Baseline = match self.store.load(BASELINE_SNAPSHOT_KIND, args.project_id, &baseline_name).await {
+            Ok(Some(baseline)) => baseline,
+            Ok(None) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().baseline_not_found)(&baseline_name, args.project_id))
+                ]));
+            }
+            Err(e) => {
+                error!("Chyba při načítání baseline '{}': {}", baseline_name, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_loading_baseline)(&baseline_name), e))
+                ]));
+            }
+        };
+        
+        debug!("Porovnávám projekt {} s baseline '{}'", args.project_id, baseline.name);
+        
+        let mut current: HashMap<i32, Issue> = HashMap::new();
+        let scan = self.api_client.scan_issues(Some(args.project_id), None, args.include_subprojects, None, |page| {
+            for issue in page {
+                current.insert(issue.id, issue.clone());
+            }
+        }).await;
+        if let Err(e) = scan {
+            error!("Chyba při procházení úkolů projektu {}: {}", args.project_id, e);
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(format!("{}: {}", (i18n::messages().error_scanning_project_issues)(args.project_id), e))
+            ]));
+        }
+        
+        let report = baseline.variance(&current, args.max_details);
+        
+        info!("Vyhodnocen baseline '{}' projektu {}", baseline.name, args.project_id);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().baseline_variance)(args.project_id, &baseline.name),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]))
+    }
+}
+
+// === GANTT TOOL ===
+
+pub struct GenerateGanttDataTool {
+    api_client: EasyProjectClient,
+    max_issues: usize,
+}
+
+impl GenerateGanttDataTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            max_issues: config.tools.reports.max_scan_records as usize,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateGanttDataArgs {
+    project_id: i32,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    include_closed: bool,
+    #[serde(default)]
+    mermaid: bool,
+}
+
+/// Úkol harmonogramu s pozicí v pracovních dnech od začátku projektu
+struct GanttTask {
+    id: i32,
+    subject: String,
+    parent_id: Option<i32>,
+    start_date: NaiveDate,
+    due_date: NaiveDate,
+    done_ratio: Option<i32>,
+    assignee: Option<String>,
+    closed: bool,
+    start: i64,
+    duration: i64,
+    early_start: i64,
+    late_start: i64,
+}
+
+impl GanttTask {
+    fn early_finish(&self) -> i64 {
+        self.early_start + self.duration
+    }
+
+    fn slack(&self) -> i64 {
+        self.late_start - self.early_start
+    }
+}
+
+/// Vazba "předchůdce musí skončit před zahájením následníka" s posunem v pracovních dnech
+struct GanttDependency {
+    from: i32,
+    to: i32,
+    delay: i64,
+    relation_type: String,
+}
+
+/// Převede vazbu úkolů na dvojici (předchůdce, následník), ostatní typy vazeb harmonogram neovlivňují
+fn finish_to_start(relation: &IssueRelation) -> Option<(i32, i32)> {
+    match relation.relation_type.as_str() {
+        "precedes" | "blocks" => Some((relation.issue_id, relation.issue_to_id)),
+        "follows" | "blocked" => Some((relation.issue_to_id, relation.issue_id)),
+        _ => None,
+    }
+}
+
+/// Vypočítá nejdříve a nejpozději možné zahájení (metoda kritické cesty)
+///
+/// Úkol nezačne dřív než podle plánu ani dřív, než skončí jeho předchůdci. Vrací pořadí úkolů
+/// v topologickém uspořádání a ID úkolů v cyklu závislostí (ty se do výpočtu nezahrnou).
+fn compute_critical_path(tasks: &mut HashMap<i32, GanttTask>, dependencies: &[GanttDependency]) -> (Vec<i32>, Vec<i32>) {
+    let mut incoming: HashMap<i32, usize> = tasks.keys().map(|id| (*id, 0)).collect();
+    let mut successors: HashMap<i32, Vec<&GanttDependency>> = HashMap::new();
+    let mut predecessors: HashMap<i32, Vec<&GanttDependency>> = HashMap::new();
+    for dependency in dependencies {
+        *incoming.entry(dependency.to).or_default() += 1;
+        successors.entry(dependency.from).or_default().push(dependency);
+        predecessors.entry(dependency.to).or_default().push(dependency);
+    }
+
+    let mut ready: Vec<i32> = incoming.iter().filter(|(_, count)| **count == 0).map(|(id, _)| *id).collect();
+    ready.sort_unstable_by(|a, b| b.cmp(a));
+    let mut order = Vec::with_capacity(tasks.len());
+    while let Some(id) = ready.pop() {
+        order.push(id);
+        for dependency in successors.get(&id).into_iter().flatten() {
+            let count = incoming.entry(dependency.to).or_default();
+            *count -= 1;
+            if *count == 0 {
+                ready.push(dependency.to);
+            }
+        }
+    }
+    let mut cyclic: Vec<i32> = tasks.keys().copied().filter(|id| !order.contains(id)).collect();
+    cyclic.sort_unstable();
+
+    // Dopředný průchod - nejdříve možné zahájení
+    for id in &order {
+        let earliest = predecessors.get(id).into_iter().flatten()
+            .filter_map(|dependency| tasks.get(&dependency.from).map(|from| from.early_finish() + dependency.delay))
+            .max();
+        let task = tasks.get_mut(id).expect("úkol z topologického pořadí");
+        task.early_start = earliest.map_or(task.start, |earliest| earliest.max(task.start));
+    }
+    let finish = order.iter().map(|id| tasks[id].early_finish()).max().unwrap_or(0);
+
+    // Zpětný průchod - nejpozději přípustné zahájení bez posunu konce projektu
+    for id in order.iter().rev() {
+        let latest_finish = successors.get(id).into_iter().flatten()
+            .filter_map(|dependency| tasks.get(&dependency.to).map(|to| to.late_start - dependency.delay))
+            .min()
+            .unwrap_or(finish);
+        let task = tasks.get_mut(id).expect("úkol z topologického pořadí");
+        task.late_start = latest_finish - task.duration;
+    }
+
+    (order, cyclic)
+}
+
+/// Text pro mermaid gantt - sekce podle nadřazených úkolů, kritické úkoly označené `crit`
+fn mermaid_gantt(title: &str, tasks: &[&GanttTask], section_of: impl Fn(&GanttTask) -> String) -> String {
+    let clean = |text: &str| text.replace([':', '#', ';'], " ");
+    let mut sections: Vec<(String, Vec<&GanttTask>)> = Vec::new();
+    for task in tasks {
+        let section = section_of(task);
+        match sections.iter_mut().find(|(name, _)| *name == section) {
+            Some((_, section_tasks)) => section_tasks.push(task),
+            None => sections.push((section, vec![task])),
+        }
+    }
+
+    let mut text = format!("gantt\n    title {}\n    dateFormat YYYY-MM-DD\n", clean(title));
+    for (section, section_tasks) in sections {
+        text.push_str(&format!("    section {}\n", clean(&section)));
+        for task in section_tasks {
+            let mut tags = Vec::new();
+            if task.slack() == 0 {
+                tags.push("crit");
+            }
+            if task.closed {
+                tags.push("done");
+            } else if task.done_ratio.unwrap_or(0) > 0 {
+                tags.push("active");
+            }
+            tags.push("");
+            // Konec je v mermaid exkluzivní
+            text.push_str(&format!(
+                "    #{} {} :{}t{}, {}, {}\n",
+                task.id,
+                clean(&task.subject),
+                tags.join(", "),
+                task.id,
+                task.start_date,
+                task.due_date + chrono::Duration::days(1)
+            ));
+        }
+    }
+    text
+}
+
+#[async_trait]
+impl ToolExecutor for GenerateGanttDataTool {
+    fn name(&self) -> &str {
+        "generate_gantt_data"
+    }
+    
+    fn description(&self) -> &str {
+        "Sestaví harmonogram projektu pro Ganttův diagram: úkoly s termíny zahájení a dokončení, nadřazené úkoly \
+        a závislosti (precedes/blocks). Vypočítá kritickou cestu a rezervu každého úkolu v pracovních dnech, \
+        upozorní na porušené závislosti a volitelně vrátí text pro mermaid gantt."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (povinné)"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "include_closed": {
+                "type": "boolean",
+                "description": "Zahrnout i uzavřené úkoly (výchozí: false)",
+                "default": false
+            },
+            "mermaid": {
+                "type": "boolean",
+                "description": "Přidat text diagramu ve formátu mermaid gantt (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["project_id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GenerateGanttDataArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("project_id"))?
+        )?;
+        
+        debug!("Sestavuji harmonogram projektu {}", args.project_id);
+        
+        let query = IssueQuery {
+            project_id: Some(args.project_id),
+            status_id: Some(if args.include_closed { "*" } else { "open" }.to_string()),
+            include_subprojects: args.include_subprojects,
+            include: Some(vec!["relations".to_string()]),
+            ..Default::default()
+        };
+        let mut pager = self.api_client.issue_pages(&query);
+        let mut issues: Vec<Issue> = Vec::new();
+        let mut truncated = false;
+        loop {
+            match pager.next_page().await {
+                Ok(Some(page)) => {
+                    issues.extend(page);
+                    if issues.len() >= self.max_issues {
+                        truncated = issues.len() > self.max_issues || pager.total_count().is_some_and(|total| total > self.max_issues);
+                        issues.truncate(self.max_issues);
+                        break;
+                    }
+                }
+                Ok(None) => break,
+                Err(e) => {
+                    error!("Chyba při načítání úkolů projektu {}: {}", args.project_id, e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", (i18n::messages().error_loading_project_issues)(args.project_id), e))
+                    ]));
+                }
+            }
+        }
+        
+        // Nadřazené úkoly jsou souhrnem podúkolů, do kritické cesty vstupují jen listové úkoly
+        let loaded: std::collections::HashSet<i32> = issues.iter().map(|issue| issue.id).collect();
+        let parents: std::collections::HashSet<i32> = issues.iter()
+            .filter_map(|issue| issue.parent.as_ref().map(|parent| parent.id))
+            .filter(|id| loaded.contains(id))
+            .collect();
+        
+        let origin = issues.iter()
+            .filter(|issue| !parents.contains(&issue.id))
+            .filter_map(|issue| issue.start_date.filter(|_| issue.due_date.is_some()))
+            .min();
+        
+        let mut tasks: HashMap<i32, GanttTask> = HashMap::new();
+        let mut unscheduled = Vec::new();
+        for issue in issues.iter().filter(|issue| !parents.contains(&issue.id)) {
+            let (Some(origin), Some(start_date), Some(due_date)) = (origin, issue.start_date, issue.due_date) else {
+                unscheduled.push(json!({
+                    "id": issue.id,
+                    "subject": issue.subject,
+                    "start_date": issue.start_date,
+                    "due_date": issue.due_date
+                }));
+                continue;
+            };
+            let start = business_day_offset(origin, start_date);
+            tasks.insert(issue.id, GanttTask {
+                id: issue.id,
+                subject: issue.subject.clone(),
+                parent_id: issue.parent.as_ref().map(|parent| parent.id),
+                start_date,
+                due_date,
+                done_ratio: issue.done_ratio,
+                assignee: issue.assigned_to.as_ref().map(|user| user.name.clone()),
+                closed: issue.status.is_closed.unwrap_or(issue.closed_on.is_some()),
+                start,
+                duration: business_days_between(start_date, due_date).max(1),
+                early_start: start,
+                late_start: start,
+            });
+        }
+        
+        // Vazby se vrací u obou úkolů, proto se odstraní duplicity podle ID vazby
+        let mut seen_relations = std::collections::HashSet::new();
+        let mut dependencies = Vec::new();
+        let mut ignored_dependencies = 0;
+        for relation in issues.iter().flat_map(|issue| issue.relations.iter().flatten()) {
+            if !seen_relations.insert(relation.id) {
+                continue;
+            }
+            let Some((from, to)) = finish_to_start(relation) else { continue };
+            if tasks.contains_key(&from) && tasks.contains_key(&to) {
+                dependencies.push(GanttDependency {
+                    from,
+                    to,
+                    delay: relation.delay.unwrap_or(0) as i64,
+                    relation_type: relation.relation_type.clone(),
+                });
+            } else {
+                ignored_dependencies += 1;
+            }
+        }
+        
+        let (order, cyclic) = compute_critical_path(&mut tasks, &dependencies);
+        let dependencies: Vec<&GanttDependency> = dependencies.iter()
+            .filter(|dependency| !cyclic.contains(&dependency.from) && !cyclic.contains(&dependency.to))
+            .collect();
+        
+        // Naplánované zahájení před skončením předchůdce
+        let conflicts: Vec<Value> = dependencies.iter()
+            .filter_map(|dependency| {
+                let (from, to) = (&tasks[&dependency.from], &tasks[&dependency.to]);
+                let required_start = from.start + from.duration + dependency.delay;
+                (to.start < required_start).then(|| json!({
+                    "predecessor_id": from.id,
+                    "successor_id": to.id,
+                    "relation_type": dependency.relation_type,
+                    "predecessor_due_date": from.due_date,
+                    "successor_start_date": to.start_date,
+                    "overlap_days": required_start - to.start
+                }))
+            })
+            .collect();
+        
+        // Kritická cesta - od úkolu končícího nejpozději zpět přes předchůdce bez rezervy
+        let finish = order.iter().map(|id| tasks[id].early_finish()).max();
+        let mut critical_path = Vec::new();
+        let mut current = order.iter().rev()
+            .find(|id| Some(tasks[*id].early_finish()) == finish && tasks[*id].slack() == 0)
+            .copied();
+        while let Some(id) = current {
+            critical_path.push(id);
+            current = dependencies.iter()
+                .filter(|dependency| dependency.to == id)
+                .map(|dependency| &tasks[&dependency.from])
+                .find(|from| from.slack() == 0 && from.early_finish() + dependency_delay(&dependencies, from.id, id) == tasks[&id].early_start)
+                .map(|from| from.id);
+        }
+        critical_path.reverse();
+        
+        let mut scheduled: Vec<&GanttTask> = order.iter().chain(cyclic.iter()).map(|id| &tasks[id]).collect();
+        scheduled.sort_by_key(|task| (task.start_date, task.id));
+        
+        let summary_tasks: Vec<Value> = issues.iter()
+            .filter(|issue| parents.contains(&issue.id))
+            .map(|issue| {
+                let children: Vec<&GanttTask> = scheduled.iter().filter(|task| task.parent_id == Some(issue.id)).copied().collect();
+                json!({
+                    "id": issue.id,
+                    "subject": issue.subject,
+                    "parent_id": issue.parent.as_ref().map(|parent| parent.id),
+                    "start_date": children.iter().map(|task| task.start_date).min().or(issue.start_date),
+                    "due_date": children.iter().map(|task| task.due_date).max().or(issue.due_date),
+                    "children": children.iter().map(|task| task.id).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        
+        let task_values: Vec<Value> = scheduled.iter()
+            .map(|task| {
+                let in_cycle = cyclic.contains(&task.id);
+                json!({
+                    "id": task.id,
+                    "subject": task.subject,
+                    "parent_id": task.parent_id,
+                    "start_date": task.start_date,
+                    "due_date": task.due_date,
+                    "duration_days": task.duration,
+                    "done_ratio": task.done_ratio,
+                    "assigned_to": task.assignee,
+                    "closed": task.closed,
+                    "predecessors": dependencies.iter().filter(|dependency| dependency.to == task.id).map(|dependency| dependency.from).collect::<Vec<_>>(),
+                    "early_start": (!in_cycle).then(|| origin.map(|origin| add_business_days(origin, task.early_start))).flatten(),
+                    "late_start": (!in_cycle).then(|| origin.map(|origin| add_business_days(origin, task.late_start))).flatten(),
+                    "slack_days": (!in_cycle).then(|| task.slack()),
+                    "critical": !in_cycle && task.slack() == 0
+                })
+            })
+            .collect();
+        
+        let project_finish = scheduled.iter().map(|task| task.due_date).max();
+        let mut report = json!({
+            "project_id": args.project_id,
+            "project_start": origin,
+            "project_finish": project_finish,
+            "working_days": origin.zip(project_finish).map(|(start, end)| business_days_between(start, end)),
+            "scanned_issues": issues.len(),
+            "truncated": truncated,
+            "critical_path": critical_path,
+            "tasks": task_values,
+            "summary_tasks": summary_tasks,
+            "unscheduled": unscheduled,
+            "dependency_conflicts": conflicts,
+            "dependency_cycles": cyclic,
+            "ignored_dependencies": ignored_dependencies
+        });
+        
+        if args.mermaid {
+            let subjects: HashMap<i32, &str> = issues.iter().map(|issue| (issue.id, issue.subject.as_str())).collect();
+            let messages = i18n::messages();
+            let mermaid = mermaid_gantt(&(messages.schedule_title)(args.project_id), &scheduled, |task| {
+                task.parent_id
+                    .and_then(|parent_id| subjects.get(&parent_id))
+                    .map(|subject| subject.to_string())
+                    .unwrap_or_else(|| messages.schedule_default_section.to_string())
+            });
+            report["mermaid"] = Value::String(mermaid);
+        }
+        
+        info!("Harmonogram projektu {}: {} naplánovaných úkolů, kritická cesta {} úkolů",
+              args.project_id, scheduled.len(), critical_path.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().project_schedule)(
+                    args.project_id,
+                    scheduled.len(),
+                    report["unscheduled"].as_array().map_or(0, Vec::len),
+                    report["critical_path"].as_array().map_or(0, Vec::len)
+                ),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]).with_structured_content(&report))
+    }
+}
+
+/// Posun vazby mezi dvojicí úkolů (nejdelší, pokud je vazeb více)
+fn dependency_delay(dependencies: &[&GanttDependency], from: i32, to: i32) -> i64 {
+    dependencies.iter()
+        .filter(|dependency| dependency.from == from && dependency.to == to)
+        .map(|dependency| dependency.delay)
+        .max()
+        .unwrap_or(0)
+}
+
+// === TEAM VELOCITY TOOL ===
+
+pub struct TeamVelocityTool {
+    api_client: EasyProjectClient,
+    max_issues: usize,
+    timezone: DateZone,
+}
+
+impl TeamVelocityTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            max_issues: config.tools.reports.max_scan_records as usize,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TeamVelocityArgs {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    user_id: Option<i32>,
+    #[serde(default = "default_velocity_period")]
+    period: String,
+    #[serde(default = "default_sprint_weeks")]
+    sprint_weeks: u32,
+    #[serde(default = "default_velocity_periods")]
+    periods: u32,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+}
+
+fn default_velocity_period() -> String {
+    "week".to_string()
+}
+
+fn default_sprint_weeks() -> u32 {
+    2
+}
+
+fn default_velocity_periods() -> u32 {
+    6
+}
+
+/// Uzavřené úkoly a odpracované hodiny v jednotlivých obdobích
+#[derive(Debug)]
+struct VelocitySeries {
+    name: String,
+    closed: Vec<u32>,
+    hours: Vec<f64>,
+}
+
+impl VelocitySeries {
+    fn new(name: String, periods: usize) -> Self {
+        Self {
+            name,
+            closed: vec![0; periods],
+            hours: vec![0.0; periods],
+        }
+    }
+
+    /// Souhrn řady - průměry a trend se počítají jen z uzavřených (dokončených) období
+    fn summary(&self, id: Option<i32>, complete_periods: usize) -> Value {
+        let closed: Vec<f64> = self.closed[..complete_periods].iter().map(|count| *count as f64).collect();
+        let hours = &self.hours[..complete_periods];
+        json!({
+            "id": id,
+            "name": self.name,
+            "closed_issues": self.closed,
+            "hours": self.hours.iter().map(|hours| (hours * 100.0).round() / 100.0).collect::<Vec<_>>(),
+            "total_closed": self.closed.iter().sum::<u32>(),
+            "total_hours": (self.hours.iter().sum::<f64>() * 100.0).round() / 100.0,
+            "avg_closed": average(&closed).map(|avg| (avg * 100.0).round() / 100.0),
+            "avg_hours": average(hours).map(|avg| (avg * 100.0).round() / 100.0),
+            "closed_trend": velocity_trend(&closed),
+            "hours_trend": velocity_trend(hours)
+        })
+    }
+}
+
+fn average(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+/// Trend řady porovnáním průměru novější a starší poloviny období (změna o víc než 10 %)
+fn velocity_trend(values: &[f64]) -> &'static str {
+    let [not_enough_data, stable, rising, falling] = i18n::messages().velocity_trends;
+    if values.len() < 2 {
+        return not_enough_data;
+    }
+    let (older, newer) = values.split_at(values.len() / 2);
+    let (older, newer) = (average(older).unwrap_or(0.0), average(newer).unwrap_or(0.0));
+    if older == 0.0 && newer == 0.0 {
+        stable
+    } else if newer > older * 1.1 {
+        rising
+    } else if newer < older * 0.9 {
+        falling
+    } else {
+        stable
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for TeamVelocityTool {
+    fn name(&self) -> &str {
+        "team_velocity"
+    }
+    
+    fn description(&self) -> &str {
+        "Spočítá rychlost týmu - počet uzavřených úkolů a odpracované hodiny po týdnech nebo sprintech \
+        za posledních N období, za tým celkem, po uživatelích a po projektech. Vrací průměry a trend \
+        (rostoucí/klesající/stabilní) z dokončených období."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (volitelné, jinak všechny projekty)"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "ID uživatele (volitelné, jinak celý tým)"
+            },
+            "period": {
+                "type": "string",
+                "description": "Délka období: week (týden) nebo sprint (výchozí: week)",
+                "enum": ["week", "sprint"],
+                "default": "week"
+            },
+            "sprint_weeks": {
+                "type": "integer",
+                "description": "Délka sprintu v týdnech pro period=sprint (výchozí: 2)",
+                "minimum": 1,
+                "maximum": 8,
+                "default": 2
+            },
+            "periods": {
+                "type": "integer",
+                "description": "Počet posledních období včetně probíhajícího (výchozí: 6)",
+                "minimum": 1,
+                "maximum": 52,
+                "default": 6
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly a časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::TimeEntries)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: TeamVelocityArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => serde_json::from_value(json!({}))?,
+        };
+        
+        let period_days = match args.period.as_str() {
+            "week" => 7,
+            "sprint" => 7 * args.sprint_weeks.clamp(1, 8) as i64,
+            other => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().invalid_value)("period", other, "week, sprint"))
+                ]));
+            }
+        };
+        let periods = args.periods.clamp(1, 52) as usize;
+        
+        // Období končí nedělí aktuálního týdne, poslední období tedy ještě probíhá
+        let today = self.timezone.date_at(Utc::now());
+        let end = crate::utils::date_utils::end_of_week(today);
+        let start = end - chrono::Duration::days(period_days * periods as i64 - 1);
+        let period_of = |date: NaiveDate| -> Option<usize> {
+            (date >= start && date <= end).then(|| ((date - start).num_days() / period_days) as usize)
+        };
+        let complete_periods = if today < end { periods - 1 } else { periods };
+        
+        debug!("Počítám rychlost týmu od {} do {} ({} období po {} dnech)", start, end, periods, period_days);
+        
+        let mut team = VelocitySeries::new(i18n::messages().team.to_string(), periods);
+        let mut by_user: HashMap<i32, VelocitySeries> = HashMap::new();
+        let mut by_project: HashMap<i32, VelocitySeries> = HashMap::new();
+        let mut unassigned_closed = vec![0u32; periods];
+        
+        // Uzavřené úkoly - změněné od začátku sledovaného období, započtené podle data uzavření
+        let query = IssueQuery {
+            project_id: args.project_id,
+            status_id: Some("closed".to_string()),
+            assigned_to_id: args.user_id,
+            updated_since: Some(start.format("%Y-%m-%d").to_string()),
+            include_subprojects: args.include_subprojects,
+            ..Default::default()
+        };
+        let mut pager = self.api_client.issue_pages(&query);
+        let mut scanned_issues = 0;
+        let mut truncated = false;
+        loop {
+            let page = match pager.next_page().await {
+                Ok(Some(page)) => page,
+                Ok(None) => break,
+                Err(e) => {
+                    error!("Chyba při načítání uzavřených úkolů: {}", e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", i18n::messages().error_loading_closed_issues, e))
+                    ]));
+                }
+            };
+            for issue in &page {
+                let Some(index) = issue.closed_on.and_then(|closed_on| period_of(self.timezone.date_at(closed_on))) else {
+                    continue;
+                };
+                team.closed[index] += 1;
+                by_project.entry(issue.project.id)
+                    .or_insert_with(|| VelocitySeries::new(issue.project.name.clone(), periods))
+                    .closed[index] += 1;
+                match &issue.assigned_to {
+                    Some(user) => {
+                        by_user.entry(user.id)
+                            .or_insert_with(|| VelocitySeries::new(user.name.clone(), periods))
+                            .closed[index] += 1;
+                    }
+                    None => unassigned_closed[index] += 1,
+                }
+            }
+            scanned_issues += page.len();
+            if scanned_issues >= self.max_issues {
+                truncated = true;
+                warn!("Načítání uzavřených úkolů ukončeno limitem max_scan_records ({})", self.max_issues);
+                break;
+            }
+        }
+        
+        // Odpracované hodiny
+        let scan = self.api_client.scan_time_entries(
+            args.project_id,
+            args.user_id,
+            Some(start.format("%Y-%m-%d").to_string()),
+            Some(end.format("%Y-%m-%d").to_string()),
+            args.include_subprojects,
+            |entries| {
+                for entry in entries {
+                    let Some(index) = period_of(entry.spent_on) else { continue };
+                    team.hours[index] += entry.hours;
+                    by_user.entry(entry.user.id)
+                        .or_insert_with(|| VelocitySeries::new(entry.user.name.clone(), periods))
+                        .hours[index] += entry.hours;
+                    by_project.entry(entry.project.id)
+                        .or_insert_with(|| VelocitySeries::new(entry.project.name.clone(), periods))
+                        .hours[index] += entry.hours;
+                }
+            },
+        ).await;
+        let time_scan = match scan {
+            Ok(summary) => summary,
+            Err(e) => {
+                error!("Chyba při načítání časových záznamů: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_loading_time_entries, e))
+                ]));
+            }
+        };
+        
+        let period_values: Vec<Value> = (0..periods)
+            .map(|index| {
+                let period_start = start + chrono::Duration::days(period_days * index as i64);
+                json!({
+                    "start": period_start,
+                    "end": period_start + chrono::Duration::days(period_days - 1),
+                    "in_progress": index >= complete_periods
+                })
+            })
+            .collect();
+        
+        let sorted_summaries = |series: HashMap<i32, VelocitySeries>| -> Vec<Value> {
+            let mut series: Vec<(i32, VelocitySeries)> = series.into_iter().collect();
+            series.sort_by(|(_, a), (_, b)| {
+                b.hours.iter().sum::<f64>().total_cmp(&a.hours.iter().sum::<f64>())
+                    .then_with(|| compare_czech(&a.name, &b.name))
+            });
+            series.iter().map(|(id, series)| series.summary(Some(*id), complete_periods)).collect()
+        };
+        
+        let team_summary = team.summary(None, complete_periods);
+        let report = json!({
+            "project_id": args.project_id,
+            "user_id": args.user_id,
+            "period": args.period,
+            "period_days": period_days,
+            "from_date": start,
+            "to_date": end,
+            "periods": period_values,
+            "team": team_summary,
+            "unassigned_closed_issues": unassigned_closed,
+            "users": sorted_summaries(by_user),
+            "projects": sorted_summaries(by_project),
+            "truncated": truncated || time_scan.truncated
+        });
+        
+        info!("Rychlost týmu: {} uzavřených úkolů, {:.1} h za {} období",
+              team.closed.iter().sum::<u32>(), team.hours.iter().sum::<f64>(), periods);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().team_velocity)(
+                    &start.format("%Y-%m-%d").to_string(),
+                    &end.format("%Y-%m-%d").to_string(),
+                    team_summary["closed_trend"].as_str().unwrap_or_default(),
+                    team_summary["hours_trend"].as_str().unwrap_or_default()
+                ),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]).with_structured_content(&report))
+    }
+}
+
+// === FIND OVERDUE ISSUES TOOL ===
+
+pub struct FindOverdueIssuesTool {
+    api_client: EasyProjectClient,
+    max_detail_records: usize,
+    hours_per_day: f64,
+    timezone: DateZone,
+}
+
+impl FindOverdueIssuesTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            max_detail_records: config.tools.reports.max_detail_records as usize,
+            hours_per_day: config.tools.reports.working_hours_per_day,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct FindOverdueIssuesArgs {
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    assigned_to_id: Option<i32>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default = "default_include_at_risk")]
+    include_at_risk: bool,
+    #[serde(default)]
+    hours_per_day: Option<f64>,
+    #[serde(default)]
+    min_severity: Option<u32>,
+}
+
+fn default_include_at_risk() -> bool {
+    true
+}
+
+/// Úkol po termínu nebo s ohroženým termínem
+struct DueDateRisk {
+    issue_id: i32,
+    subject: String,
+    project: (i32, String),
+    assignee: Option<(i32, String)>,
+    due_date: NaiveDate,
+    done_ratio: i32,
+    overdue_days: Option<i64>,
+    remaining_hours: Option<f64>,
+    available_hours: Option<f64>,
+    severity: u32,
+}
+
+impl DueDateRisk {
+    /// Vyhodnotí úkol - po termínu, nebo zbývající odhad přesahuje pracovní hodiny do termínu
+    ///
+    /// Závažnost 0-100: úkoly po termínu 60 + 2 body za každý den zpoždění (max. 100),
+    /// ohrožené úkoly podle poměru zbývajícího odhadu k dostupným hodinám (max. 59).
+    fn assess(issue: &Issue, rule: &OverdueRule, hours_per_day: f64, include_at_risk: bool) -> Option<Self> {
+        let due_date = issue.due_date?;
+        let remaining_hours = remaining_estimate(issue);
+        let assignee_id = issue.assigned_to.as_ref().map(|assignee| assignee.id);
+        
+        let (overdue, available_hours, severity) = if let Some(days) = overdue_days(rule, issue) {
+            (Some(days), None, (60 + 2 * days.min(20)) as u32)
+        } else {
+            let remaining = remaining_hours.filter(|_| include_at_risk && issue.done_ratio.unwrap_or(0) < 100)?;
+            let available = business_days_between(rule.today_for(assignee_id), due_date) as f64 * hours_per_day;
+            if remaining <= available {
+                return None;
+            }
+            let severity = if available > 0.0 { (remaining / available * 30.0).round().min(59.0) } else { 59.0 };
+            (None, Some(available), severity as u32)
+        };
+        
+        Some(Self {
+            issue_id: issue.id,
+            subject: issue.subject.clone(),
+            project: (issue.project.id, issue.project.name.clone()),
+            assignee: issue.assigned_to.as_ref().map(|assignee| (assignee.id, assignee.name.clone())),
+            due_date,
+            done_ratio: issue.done_ratio.unwrap_or(0),
+            overdue_days: overdue,
+            remaining_hours,
+            available_hours,
+            severity,
+        })
+    }
+    
+    fn level(&self) -> &'static str {
+        let [critical, high, medium] = i18n::messages().risk_levels;
+        match self.severity {
+            80.. => critical,
+            60.. => high,
+            _ => medium,
+        }
+    }
+    
+    fn to_json(&self) -> Value {
+        json!({
+            "id": self.issue_id,
+            "subject": self.subject,
+            "project": {"id": self.project.0, "name": self.project.1},
+            "assigned_to": self.assignee.as_ref().map(|(id, name)| json!({"id": id, "name": name})),
+            "due_date": self.due_date,
+            "done_ratio": self.done_ratio,
+            "status": if self.overdue_days.is_some() { "overdue" } else { "at_risk" },
+            "overdue_days": self.overdue_days,
+            "remaining_hours": self.remaining_hours.map(|hours| (hours * 10.0).round() / 10.0),
+            "available_hours": self.available_hours,
+            "severity": self.severity,
+            "severity_level": self.level()
+        })
+    }
+}
+
+/// Klíč skupiny rizikových úkolů - ID a název řešitele nebo projektu (None = nepřiřazeno)
+type RiskGroupKey = Option<(i32, String)>;
+
+/// Souhrn rizikových úkolů jedné skupiny (řešitel nebo projekt)
+fn group_risks<'a>(risks: &[&'a DueDateRisk], key: impl Fn(&DueDateRisk) -> RiskGroupKey) -> Vec<Value> {
+    let mut groups: Vec<(RiskGroupKey, Vec<&'a DueDateRisk>)> = Vec::new();
+    for risk in risks.iter().copied() {
+        let group_key = key(risk);
+        match groups.iter_mut().find(|(existing, _)| *existing == group_key) {
+            Some((_, members)) => members.push(risk),
+            None => groups.push((group_key, vec![risk])),
+        }
+    }
+    groups.sort_by(|(_, a), (_, b)| {
+        let score = |members: &[&DueDateRisk]| members.iter().map(|risk| risk.severity).sum::<u32>();
+        score(b).cmp(&score(a))
+    });
+    
+    groups.iter()
+        .map(|(group_key, members)| json!({
+            "id": group_key.as_ref().map(|(id, _)| *id),
+            "name": group_key.as_ref().map_or(i18n::messages().unassigned_group, |(_, name)| name.as_str()),
+            "overdue": members.iter().filter(|risk| risk.overdue_days.is_some()).count(),
+            "at_risk": members.iter().filter(|risk| risk.overdue_days.is_none()).count(),
+            "max_severity": members.iter().map(|risk| risk.severity).max(),
+            "severity_sum": members.iter().map(|risk| risk.severity).sum::<u32>(),
+            "issue_ids": members.iter().map(|risk| risk.issue_id).collect::<Vec<_>>()
+        }))
+        .collect()
+}
+
+#[async_trait]
+impl ToolExecutor for FindOverdueIssuesTool {
+    fn name(&self) -> &str {
+        "find_overdue_issues"
+    }
+    
+    fn description(&self) -> &str {
+        "Najde otevřené úkoly po termínu a úkoly s ohroženým termínem (zbývající odhad přesahuje pracovní \
+        hodiny do termínu). Každý úkol má skóre závažnosti 0-100, výsledek je seskupený podle řešitelů a projektů."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu (volitelné, jinak všechny projekty)"
+            },
+            "assigned_to_id": {
+                "type": "integer",
+                "description": "ID řešitele pro filtrování (nepovinné)"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "include_at_risk": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly, jejichž zbývající odhad nestihne termín (výchozí: true)",
+                "default": true
+            },
+            "hours_per_day": {
+                "type": "number",
+                "description": "Pracovní hodiny za den pro výpočet ohrožených úkolů (výchozí: z konfigurace)",
+                "minimum": 0.5,
+                "maximum": 24
+            },
+            "min_severity": {
+                "type": "integer",
+                "description": "Minimální skóre závažnosti 0-100 (výchozí: 0)",
+                "minimum": 0,
+                "maximum": 100
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: FindOverdueIssuesArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => serde_json::from_value(json!({}))?,
+        };
+        
+        let rule = OverdueRule::new(self.timezone);
+        let hours_per_day = args.hours_per_day.unwrap_or(self.hours_per_day);
+        let min_severity = args.min_severity.unwrap_or(0);
+        
+        debug!("Hledám úkoly po termínu (projekt {:?}, řešitel {:?})", args.project_id, args.assigned_to_id);
+        
+        let mut risks = Vec::new();
+        let scan = self.api_client.scan_issues(args.project_id, args.assigned_to_id, args.include_subprojects, None, |issues| {
+            risks.extend(issues.iter()
+                .filter_map(|issue| DueDateRisk::assess(issue, &rule, hours_per_day, args.include_at_risk))
+                .filter(|risk| risk.severity >= min_severity));
+        }).await;
+        let scan = match scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                error!("Chyba při hledání úkolů po termínu: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_loading_issues, e))
+                ]));
+            }
+        };
+        
+        risks.sort_by(|a, b| b.severity.cmp(&a.severity).then(a.due_date.cmp(&b.due_date)));
+        let all: Vec<&DueDateRisk> = risks.iter().collect();
+        let overdue_count = risks.iter().filter(|risk| risk.overdue_days.is_some()).count();
+        
+        let report = json!({
+            "overdue_rule": rule.describe(),
+            "hours_per_day": hours_per_day,
+            "overdue": overdue_count,
+            "at_risk": risks.len() - overdue_count,
+            "by_assignee": group_risks(&all, |risk| risk.assignee.clone()),
+            "by_project": group_risks(&all, |risk| Some(risk.project.clone())),
+            "issues": risks.iter().take(self.max_detail_records).map(DueDateRisk::to_json).collect::<Vec<_>>(),
+            "issues_truncated": risks.len() > self.max_detail_records,
+            "scan": scan
+        });
+        
+        info!("Nalezeno {} úkolů po termínu a {} ohrožených", overdue_count, risks.len() - overdue_count);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().deadline_risks)(overdue_count, risks.len() - overdue_count),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]).with_structured_content(&report))
+    }
+}
+
+// === PLAN CAPACITY TOOL ===
+
+pub struct PlanCapacityTool {
+    api_client: EasyProjectClient,
+    hours_per_day: f64,
+    timezone: DateZone,
+}
+
+impl PlanCapacityTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            hours_per_day: config.tools.reports.working_hours_per_day,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PlanCapacityArgs {
+    #[serde(default)]
+    project_ids: Option<Vec<i32>>,
+    #[serde(default)]
+    user_ids: Option<Vec<i32>>,
+    #[serde(default)]
+    from_date: Option<String>,
+    #[serde(default)]
+    to_date: Option<String>,
+    #[serde(default)]
+    hours_per_day: Option<f64>,
+    #[serde(default = "default_allocation_threshold")]
+    allocation_threshold: f64,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+}
+
+fn default_allocation_threshold() -> f64 {
+    1.0
+}
+
+/// Vytížení jednoho uživatele v plánovaném období
+#[derive(Default)]
+struct UserCapacity {
+    name: String,
+    demand_hours: f64,
+    undated_hours: f64,
+    open_issues: usize,
+    unestimated_issues: usize,
+    by_project: HashMap<String, f64>,
+}
+
+/// Část zbývajícího odhadu připadající na období `[from, to]`
+///
+/// Zbývající práce se rozloží rovnoměrně do pracovních dnů od zahájení (nejdříve dnes) do termínu.
+/// Úkol po termínu spadá celý do začátku období, úkol bez termínu vrací `None`.
+fn hours_in_range(issue: &Issue, remaining: f64, from: NaiveDate, to: NaiveDate, today: NaiveDate) -> Option<f64> {
+    let due_date = issue.due_date?;
+    let start = issue.start_date.unwrap_or(today).max(today).min(due_date);
+    if due_date < from.max(today) {
+        return Some(if from <= today && today <= to { remaining } else { 0.0 });
+    }
+    let total_days = business_days_between(start, due_date);
+    if total_days == 0 {
+        // Termín na víkend - práce připadne na den termínu
+        return Some(if due_date >= from && due_date <= to { remaining } else { 0.0 });
+    }
+    let days_in_range = business_days_between(start.max(from), due_date.min(to));
+    Some(remaining * days_in_range as f64 / total_days as f64)
+}
+
+#[async_trait]
+impl ToolExecutor for PlanCapacityTool {
+    fn name(&self) -> &str {
+        "plan_capacity"
+    }
+    
+    fn description(&self) -> &str {
+        "Plánování kapacit - porovná zbývající odhad otevřených přiřazených úkolů každého uživatele \
+        s jeho pracovní kapacitou (pracovní dny v období × hodiny za den) napříč zvolenými projekty \
+        a označí přetížené uživatele."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_ids": {
+                "type": "array",
+                "description": "ID projektů (volitelné, jinak všechny projekty)",
+                "items": {
+                    "type": "integer"
+                }
+            },
+            "user_ids": {
+                "type": "array",
+                "description": "ID uživatelů (volitelné, jinak všichni řešitelé)",
+                "items": {
+                    "type": "integer"
+                }
+            },
+            "from_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Začátek plánovaného období (formát: YYYY-MM-DD, výchozí: dnes)"
+            },
+            "to_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Konec plánovaného období (formát: YYYY-MM-DD, výchozí: 4 týdny od začátku)"
+            },
+            "hours_per_day": {
+                "type": "number",
+                "description": "Pracovní hodiny za den (výchozí: z konfigurace)",
+                "minimum": 0.5,
+                "maximum": 24
+            },
+            "allocation_threshold": {
+                "type": "number",
+                "description": "Podíl vytížení, od kterého je uživatel přetížený, např. 1.0 = 100 % (výchozí: 1.0)",
+                "minimum": 0,
+                "default": 1.0
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout úkoly podprojektů (výchozí: podle nastavení EasyProject instance)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: PlanCapacityArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => serde_json::from_value(json!({}))?,
+        };
+        
+        let today = self.timezone.date_at(Utc::now());
+        let from = match args.from_date.as_deref().map(parse_date).transpose() {
+            Ok(from) => from.unwrap_or(today),
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        let to = match args.to_date.as_deref().map(parse_date).transpose() {
+            Ok(to) => to.unwrap_or(from + chrono::Duration::days(27)),
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        if to < from {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().date_range_reversed.to_string())
+            ]));
+        }
+        
+        let hours_per_day = args.hours_per_day.unwrap_or(self.hours_per_day);
+        let business_days = business_days_between(from, to);
+        let capacity = business_days as f64 * hours_per_day;
+        
+        debug!("Plánuji kapacity od {} do {} ({} pracovních dní)", from, to, business_days);
+        
+        // Bez výběru projektů se prochází všechny úkoly najednou
+        let scopes: Vec<Option<i32>> = match &args.project_ids {
+            Some(ids) if !ids.is_empty() => ids.iter().copied().map(Some).collect(),
+            _ => vec![None],
+        };
+        let mut seen_issues = std::collections::HashSet::new();
+        let mut users: HashMap<i32, UserCapacity> = HashMap::new();
+        let mut unassigned_hours = 0.0;
+        let mut truncated = false;
+        
+        for scope in scopes {
+            let scan = self.api_client.scan_issues(scope, None, args.include_subprojects, None, |issues| {
+                for issue in issues {
+                    if !seen_issues.insert(issue.id) || issue.done_ratio.unwrap_or(0) >= 100 {
+                        continue;
+                    }
+                    let remaining = remaining_estimate(issue);
+                    let Some(assignee) = &issue.assigned_to else {
+                        unassigned_hours += remaining
+                            .and_then(|remaining| hours_in_range(issue, remaining, from, to, today))
+                            .unwrap_or(0.0);
+                        continue;
+                    };
+                    if args.user_ids.as_ref().is_some_and(|ids| !ids.contains(&assignee.id)) {
+                        continue;
+                    }
+                    
+                    let user = users.entry(assignee.id).or_insert_with(|| UserCapacity {
+                        name: assignee.name.clone(),
+                        ..Default::default()
+                    });
+                    user.open_issues += 1;
+                    let Some(remaining) = remaining else {
+                        user.unestimated_issues += 1;
+                        continue;
+                    };
+                    match hours_in_range(issue, remaining, from, to, today) {
+                        Some(hours) => {
+                            user.demand_hours += hours;
+                            *user.by_project.entry(issue.project.name.clone()).or_default() += hours;
+                        }
+                        None => user.undated_hours += remaining,
+                    }
+                }
+            }).await;
+            match scan {
+                Ok(scan) => truncated |= scan.truncated,
+                Err(e) => {
+                    error!("Chyba při načítání úkolů pro plánování kapacit: {}", e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", i18n::messages().error_loading_issues, e))
+                    ]));
+                }
+            }
+        }
+        
+        let round = |hours: f64| (hours * 10.0).round() / 10.0;
+        let mut users: Vec<(i32, UserCapacity)> = users.into_iter().collect();
+        users.sort_by(|(_, a), (_, b)| b.demand_hours.total_cmp(&a.demand_hours).then_with(|| compare_czech(&a.name, &b.name)));
+        
+        let mut over_allocated = Vec::new();
+        let user_values: Vec<Value> = users.iter()
+            .map(|(id, user)| {
+                let utilization = if capacity > 0.0 { Some(user.demand_hours / capacity) } else { None };
+                let is_over = utilization.map_or(user.demand_hours > 0.0, |utilization| utilization > args.allocation_threshold);
+                if is_over {
+                    over_allocated.push(user.name.clone());
+                }
+                let mut by_project: Vec<(&String, &f64)> = user.by_project.iter().collect();
+                by_project.sort_by(|a, b| b.1.total_cmp(a.1));
+                json!({
+                    "id": id,
+                    "name": user.name,
+                    "capacity_hours": round(capacity),
+                    "demand_hours": round(user.demand_hours),
+                    "free_hours": round(capacity - user.demand_hours),
+                    "utilization_percent": utilization.map(|utilization| (utilization * 100.0).round()),
+                    "over_allocated": is_over,
+                    "open_issues": user.open_issues,
+                    "unestimated_issues": user.unestimated_issues,
+                    "undated_hours": round(user.undated_hours),
+                    "by_project": by_project.iter()
+                        .map(|(project, hours)| json!({"project": project, "hours": round(**hours)}))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        
+        let report = json!({
+            "from_date": from,
+            "to_date": to,
+            "business_days": business_days,
+            "hours_per_day": hours_per_day,
+            "capacity_per_user_hours": round(capacity),
+            "allocation_threshold": args.allocation_threshold,
+            "project_ids": args.project_ids,
+            "users": user_values,
+            "over_allocated": over_allocated,
+            "unassigned_demand_hours": round(unassigned_hours),
+            "truncated": truncated
+        });
+        
+        info!("Plánování kapacit: {} uživatelů, {} přetížených", users.len(), over_allocated.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().capacity_plan)(
+                    &from.format("%Y-%m-%d").to_string(),
+                    &to.format("%Y-%m-%d").to_string(),
+                    business_days,
+                    &if over_allocated.is_empty() { i18n::messages().nobody.to_string() } else { over_allocated.join(", ") }
+                ),
+                serde_json::to_string_pretty(&report)?
+            ))
+        ]).with_structured_content(&report))
+    }
+}
+
+// === TIMESHEET TOOL ===
+
+pub struct GenerateTimesheetTool {
+    api_client: EasyProjectClient,
+    timezone: DateZone,
+}
+
+impl GenerateTimesheetTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GenerateTimesheetArgs {
+    from_date: String,
+    to_date: String,
+    #[serde(default)]
+    user_id: Option<i32>,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default = "default_timesheet_granularity")]
+    granularity: String,
+    #[serde(default = "default_timesheet_format")]
+    format: String,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+}
+
+fn default_timesheet_granularity() -> String {
+    "day".to_string()
+}
+
+fn default_timesheet_format() -> String {
+    "markdown".to_string()
+}
+
+/// Nejdelší období výkazu ve dnech
+const MAX_TIMESHEET_DAYS: i64 = 366;
+
+/// Řádek výkazu - hodiny uživatele ve sloupcích (dny nebo týdny)
+struct TimesheetRow {
+    user_id: i32,
+    name: String,
+    hours: Vec<f64>,
+    hours_by_day: HashMap<NaiveDate, f64>,
+}
+
+impl TimesheetRow {
+    fn new(user_id: i32, name: String, columns: usize) -> Self {
+        Self {
+            user_id,
+            name,
+            hours: vec![0.0; columns],
+            hours_by_day: HashMap::new(),
+        }
+    }
+
+    fn total(&self) -> f64 {
+        self.hours.iter().sum()
+    }
+}
+
+/// Hodiny pro buňku výkazu - prázdná buňka pro nulu, jinak bez zbytečných desetinných míst
+fn format_timesheet_hours(hours: f64) -> String {
+    if hours.abs() < 0.005 {
+        String::new()
+    } else {
+        format!("{:.2}", hours).trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+/// Vykreslí výkaz jako markdown tabulku nebo CSV (oddělovač `;`)
+fn render_timesheet(labels: &[String], rows: &[TimesheetRow], column_totals: &[f64], csv: bool) -> String {
+    let mut lines: Vec<Vec<String>> = Vec::with_capacity(rows.len() + 2);
+    let messages = i18n::messages();
+    let mut header = vec![messages.user.to_string()];
+    header.extend(labels.iter().cloned());
+    header.push(messages.total.to_string());
+    lines.push(header);
+    for row in rows {
+        let mut line = vec![row.name.clone()];
+        line.extend(row.hours.iter().map(|hours| format_timesheet_hours(*hours)));
+        line.push(format_timesheet_hours(row.total()));
+        lines.push(line);
+    }
+    let mut totals = vec![messages.total.to_string()];
+    totals.extend(column_totals.iter().map(|hours| format_timesheet_hours(*hours)));
+    totals.push(format_timesheet_hours(column_totals.iter().sum()));
+    lines.push(totals);
+
+    if csv {
+        return csv_rows(lines);
+    }
+    let mut out = String::new();
+    for (index, line) in lines.iter().enumerate() {
+        out.push_str(&format!("| {} |\n", line.iter().map(|cell| cell.replace('|', "\\|")).collect::<Vec<_>>().join(" | ")));
+        if index == 0 {
+            out.push_str(&format!("|{}\n", "---|".repeat(line.len())));
+        }
+    }
+    out
+}
+
+#[async_trait]
+impl ToolExecutor for GenerateTimesheetTool {
+    fn name(&self) -> &str {
+        "generate_timesheet"
+    }
+    
+    fn description(&self) -> &str {
+        "Sestaví výkaz práce za období - matici uživatel × den (nebo týden) s odpracovanými hodinami, \
+        součty za uživatele i sloupce a seznamem pracovních dnů bez vykázaného času. \
+        Výstup jako markdown tabulka, CSV nebo JSON."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "from_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Začátek období (formát: YYYY-MM-DD)"
+            },
+            "to_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Konec období (formát: YYYY-MM-DD)"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "ID uživatele (volitelné, jinak celý tým)"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "ID projektu - tým tvoří členové projektu včetně těch bez záznamů (nepovinné)"
+            },
+            "granularity": {
+                "type": "string",
+                "description": "Sloupce výkazu: day (dny) nebo week (týdny) (výchozí: day)",
+                "enum": ["day", "week"],
+                "default": "day"
+            },
+            "format": {
+                "type": "string",
+                "description": "Formát výstupu: markdown, csv nebo json (výchozí: markdown)",
+                "enum": ["markdown", "csv", "json"],
+                "default": "markdown"
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Zahrnout časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::TimeEntries)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["from_date", "to_date"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GenerateTimesheetArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameters)("from_date, to_date"))?
+        )?;
+        
+        let (from, to) = match (parse_date(&args.from_date), parse_date(&args.to_date)) {
+            (Ok(from), Ok(to)) => (from, to),
+            (Err(e), _) | (_, Err(e)) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        if to < from || (to - from).num_days() >= MAX_TIMESHEET_DAYS {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().timesheet_period)(MAX_TIMESHEET_DAYS))
+            ]));
+        }
+        let weekly = match args.granularity.as_str() {
+            "day" => false,
+            "week" => true,
+            other => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().invalid_value)("granularity", other, "day, week"))
+                ]));
+            }
+        };
+        
+        debug!("Sestavuji výkaz práce {} - {} (uživatel {:?}, projekt {:?})", from, to, args.user_id, args.project_id);
+        
+        // Sloupce - jednotlivé dny, nebo týdny od pondělí (první a poslední týden mohou být zkrácené)
+        let first_column = if weekly { crate::utils::date_utils::start_of_week(from) } else { from };
+        let column_days: i64 = if weekly { 7 } else { 1 };
+        let columns = ((to - first_column).num_days() / column_days + 1) as usize;
+        let column_of = |date: NaiveDate| ((date - first_column).num_days() / column_days) as usize;
+        let labels: Vec<String> = (0..columns)
+            .map(|index| {
+                let date = first_column + chrono::Duration::days(index as i64 * column_days);
+                if weekly {
+                    let week = date.iso_week();
+                    format!("{}-W{:02}", week.year(), week.week())
+                } else {
+                    date.format("%Y-%m-%d").to_string()
+                }
+            })
+            .collect();
+        
+        // Členové projektu tvoří řádky i bez vykázaného času, aby šlo zjistit chybějící dny
+        let mut rows: Vec<TimesheetRow> = Vec::new();
+        if let (Some(project_id), None) = (args.project_id, args.user_id) {
+            match self.api_client.list_all_project_memberships(project_id).await {
+                Ok(memberships) => {
+                    for user in memberships.iter().filter_map(|membership| membership.user.as_ref()) {
+                        if !rows.iter().any(|row| row.user_id == user.id) {
+                            rows.push(TimesheetRow::new(user.id, user.name.clone(), columns));
+                        }
+                    }
+                }
+                Err(e) => warn!("Nepodařilo se načíst členy projektu {} pro výkaz: {}", project_id, e),
+            }
+        }
+        
+        let scan = self.api_client.scan_time_entries(
+            args.project_id,
+            args.user_id,
+            Some(args.from_date.clone()),
+            Some(args.to_date.clone()),
+            args.include_subprojects,
+            |entries| {
+                for entry in entries.iter().filter(|entry| entry.spent_on >= from && entry.spent_on <= to) {
+                    let index = match rows.iter().position(|row| row.user_id == entry.user.id) {
+                        Some(index) => index,
+                        None => {
+                            rows.push(TimesheetRow::new(entry.user.id, entry.user.name.clone(), columns));
+                            rows.len() - 1
+                        }
+                    };
+                    let row = &mut rows[index];
+                    row.hours[column_of(entry.spent_on)] += entry.hours;
+                    *row.hours_by_day.entry(entry.spent_on).or_default() += entry.hours;
+                }
+            },
+        ).await;
+        let scan = match scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                error!("Chyba při načítání časových záznamů pro výkaz: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_loading_time_entries, e))
+                ]));
+            }
+        };
+        
+        rows.sort_by(|a, b| compare_czech(&a.name, &b.name));
+        let column_totals: Vec<f64> = (0..columns)
+            .map(|index| rows.iter().map(|row| row.hours[index]).sum())
+            .collect();
+        
+        // Chybějící dny - pracovní dny do dneška bez jediného záznamu
+        let today = self.timezone.date_at(Utc::now());
+        let workdays: Vec<NaiveDate> = from.iter_days()
+            .take_while(|date| *date <= to.min(today))
+            .filter(|date| crate::utils::date_utils::is_business_day(*date))
+            .collect();
+        let missing_days: Vec<Value> = rows.iter()
+            .filter_map(|row| {
+                let missing: Vec<NaiveDate> = workdays.iter()
+                    .filter(|date| !row.hours_by_day.contains_key(date))
+                    .copied()
+                    .collect();
+                (!missing.is_empty()).then(|| json!({
+                    "user_id": row.user_id,
+                    "name": row.name,
+                    "days": missing
+                }))
+            })
+            .collect();
+        
+        let report = json!({
+            "from_date": from,
+            "to_date": to,
+            "granularity": args.granularity,
+            "columns": labels,
+            "users": rows.iter().map(|row| json!({
+                "id": row.user_id,
+                "name": row.name,
+                "hours": row.hours,
+                "total": row.total()
+            })).collect::<Vec<_>>(),
+            "column_totals": column_totals,
+            "total_hours": column_totals.iter().sum::<f64>(),
+            "missing_days": missing_days,
+            "scan": scan
+        });
+        
+        let messages = i18n::messages();
+        let title = (messages.timesheet_title)(&from.format("%Y-%m-%d").to_string(), &to.format("%Y-%m-%d").to_string());
+        let output = match args.format.as_str() {
+            "csv" => render_timesheet(&labels, &rows, &column_totals, true),
+            "json" => format!("{}:\n\n{}", title, serde_json::to_string_pretty(&report)?),
+            _ => {
+                let mut output = format!("## {}\n\n{}", title, render_timesheet(&labels, &rows, &column_totals, false));
+                if !missing_days.is_empty() {
+                    output.push_str(&format!("\n### {}\n\n", messages.workdays_without_time));
+                    for row in &missing_days {
+                        let days: Vec<&str> = row["days"].as_array().into_iter().flatten().filter_map(Value::as_str).collect();
+                        output.push_str(&format!("- {}: {}\n", row["name"].as_str().unwrap_or_default(), days.join(", ")));
+                    }
+                }
+                output
+            }
+        };
+        
+        info!("Výkaz práce: {} uživatelů, {:.1} h", rows.len(), column_totals.iter().sum::<f64>());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&report))
+    }
+}
+
+// === PORTFOLIO OVERVIEW TOOL ===
+
+pub struct PortfolioOverviewTool {
+    api_client: EasyProjectClient,
+    timezone: DateZone,
+}
+
+impl PortfolioOverviewTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self {
+            api_client,
+            timezone: report_timezone(&config),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct PortfolioOverviewArgs {
+    #[serde(default)]
+    project_ids: Option<Vec<i32>>,
+    #[serde(default)]
+    from_date: Option<String>,
+    #[serde(default)]
+    to_date: Option<String>,
+    #[serde(default = "default_portfolio_sort")]
+    sort_by: String,
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+fn default_portfolio_sort() -> String {
+    "risk".to_string()
+}
+
+/// Výchozí délka období pro odpracované hodiny v přehledu portfolia
+const PORTFOLIO_DEFAULT_DAYS: i64 = 30;
+
+/// Souhrn jednoho projektu portfolia
+#[derive(Default)]
+struct PortfolioRow {
+    id: i32,
+    name: String,
+    parent: Option<String>,
+    open_issues: usize,
+    overdue_issues: usize,
+    unassigned_issues: usize,
+    remaining_hours: f64,
+    hours_in_period: f64,
+}
+
+impl PortfolioRow {
+    /// Podíl otevřených úkolů po termínu
+    fn overdue_ratio(&self) -> f64 {
+        if self.open_issues == 0 { 0.0 } else { self.overdue_issues as f64 / self.open_issues as f64 }
+    }
+
+    /// Stav projektu - kritický (0) od 25 % úkolů po termínu, varování (1) od 10 % nebo bez práce v období, jinak v pořádku (2)
+    fn health_level(&self) -> usize {
+        let ratio = self.overdue_ratio();
+        if ratio >= 0.25 {
+            0
+        } else if ratio >= 0.10 || (self.open_issues > 0 && self.hours_in_period == 0.0) {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// Popis stavu projektu v jazyce výstupu
+    fn health(&self) -> &'static str {
+        i18n::messages().portfolio_health_levels[self.health_level()]
+    }
+
+    /// Skóre rizika pro řazení - podíl i počet úkolů po termínu, nečinnost s otevřenými úkoly
+    fn risk_score(&self) -> f64 {
+        let idle = if self.open_issues > 0 && self.hours_in_period == 0.0 { 10.0 } else { 0.0 };
+        self.overdue_ratio() * 100.0 + self.overdue_issues as f64 + idle
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for PortfolioOverviewTool {
+    fn name(&self) -> &str {
+        "portfolio_overview"
+    }
+    
+    fn description(&self) -> &str {
+        "Přehled portfolia - porovná všechny aktivní projekty v jedné tabulce: stav, otevřené úkoly, \
+        úkoly po termínu, nepřiřazené úkoly, zbývající odhad a odpracované hodiny v období. \
+        Projekty jsou seřazené podle rizika (nebo podle zvoleného sloupce)."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "project_ids": {
+                "type": "array",
+                "description": "ID projektů (volitelné, jinak všechny aktivní projekty)",
+                "items": {
+                    "type": "integer"
+                }
+            },
+            "from_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Začátek období pro odpracované hodiny (formát: YYYY-MM-DD, výchozí: před 30 dny)"
+            },
+            "to_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Konec období pro odpracované hodiny (formát: YYYY-MM-DD, výchozí: dnes)"
+            },
+            "sort_by": {
+                "type": "string",
+                "description": "Řazení: risk (riziko), overdue, open, hours nebo name (výchozí: risk)",
+                "enum": ["risk", "overdue", "open", "hours", "name"],
+                "default": "risk"
+            },
+            "limit": {
+                "type": "integer",
+                "description": "Maximální počet projektů ve výsledku (výchozí: všechny)",
+                "minimum": 1
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Projects)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: PortfolioOverviewArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => serde_json::from_value(json!({}))?,
+        };
+        
+        let today = self.timezone.date_at(Utc::now());
+        let to = match args.to_date.as_deref().map(parse_date).transpose() {
+            Ok(to) => to.unwrap_or(today),
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        let from = match args.from_date.as_deref().map(parse_date).transpose() {
+            Ok(from) => from.unwrap_or(to - chrono::Duration::days(PORTFOLIO_DEFAULT_DAYS)),
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        debug!("Sestavuji přehled portfolia (hodiny {} - {})", from, to);
+        
+        let projects = match self.api_client.list_projects(Some(crate::api::client::FETCH_ALL), None, Some(false), None, None, None).await {
+            Ok(response) => response.projects,
+            Err(e) => {
+                error!("Chyba při získávání projektů pro portfolio: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_projects, e))
+                ]));
+            }
+        };
+        
+        let mut rows: HashMap<i32, PortfolioRow> = projects.iter()
+            .filter(|project| matches!(project.status, crate::api::models::ProjectStatus::Active))
+            .filter(|project| args.project_ids.as_ref().map(|ids| ids.contains(&project.id)).unwrap_or(true))
+            .map(|project| (project.id, PortfolioRow {
+                id: project.id,
+                name: project.name.clone(),
+                parent: project.parent.as_ref().map(|parent| parent.name.clone()),
+                ..Default::default()
+            }))
+            .collect();
+        
+        // Jeden průchod přes všechny otevřené úkoly a časové záznamy místo dotazů po projektech
+        let rule = OverdueRule::new(self.timezone);
+        let issue_scan = self.api_client.scan_issues(None, None, None, None, |issues| {
+            for issue in issues {
+                let Some(row) = rows.get_mut(&issue.project.id) else { continue };
+                row.open_issues += 1;
+                if overdue_days(&rule, issue).is_some() {
+                    row.overdue_issues += 1;
+                }
+                if issue.assigned_to.is_none() {
+                    row.unassigned_issues += 1;
+                }
+                row.remaining_hours += remaining_estimate(issue).unwrap_or(0.0);
+            }
+        }).await;
+        let issue_scan = match issue_scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                error!("Chyba při získávání úkolů pro portfolio: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
+                ]));
+            }
+        };
+        
+        let time_scan = self.api_client.scan_time_entries(
+            None,
+            None,
+            Some(from.format("%Y-%m-%d").to_string()),
+            Some(to.format("%Y-%m-%d").to_string()),
+            None,
+            |entries| {
+                for entry in entries {
+                    if let Some(row) = rows.get_mut(&entry.project.id) {
+                        row.hours_in_period += entry.hours;
+                    }
+                }
+            },
+        ).await;
+        let time_scan = match time_scan {
+            Ok(scan) => scan,
+            Err(e) => {
+                error!("Chyba při získávání časových záznamů pro portfolio: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_time_entries, e))
+                ]));
+            }
+        };
+        
+        let mut rows: Vec<PortfolioRow> = rows.into_values().collect();
+        rows.sort_by(|a, b| {
+            let order = match args.sort_by.as_str() {
+                "overdue" => b.overdue_issues.cmp(&a.overdue_issues),
+                "open" => b.open_issues.cmp(&a.open_issues),
+                "hours" => b.hours_in_period.total_cmp(&a.hours_in_period),
+                "name" => std::cmp::Ordering::Equal,
+                _ => b.risk_score().total_cmp(&a.risk_score()),
+            };
+            order.then_with(|| compare_czech(&a.name, &b.name))
+        });
+        
+        let total_projects = rows.len();
+        let round = |hours: f64| (hours * 10.0).round() / 10.0;
+        let summary = json!({
+            "projects": total_projects,
+            "critical": rows.iter().filter(|row| row.health_level() == 0).count(),
+            "warning": rows.iter().filter(|row| row.health_level() == 1).count(),
+            "open_issues": rows.iter().map(|row| row.open_issues).sum::<usize>(),
+            "overdue_issues": rows.iter().map(|row| row.overdue_issues).sum::<usize>(),
+            "hours_in_period": round(rows.iter().map(|row| row.hours_in_period).sum())
+        });
+        rows.truncate(args.limit.unwrap_or(usize::MAX));
+        
+        let messages = i18n::messages();
+        let mut table = format!("{}\n|---|---|---|---|---|---|---|---|\n", messages.portfolio_table_header);
+        for (rank, row) in rows.iter().enumerate() {
+            let name = match &row.parent {
+                Some(parent) => format!("{} ({})", row.name, parent),
+                None => row.name.clone(),
+            };
+            table.push_str(&format!(
+                "| {} | {} (#{}) | {} | {} | {} ({:.0} %) | {} | {:.1} | {:.1} |\n",
+                rank + 1,
+                name.replace('|', "\\|"),
+                row.id,
+                row.health(),
+                row.open_issues,
+                row.overdue_issues,
+                row.overdue_ratio() * 100.0,
+                row.unassigned_issues,
+                row.remaining_hours,
+                row.hours_in_period
+            ));
+        }
+        
+        let report = json!({
+            "from_date": from,
+            "to_date": to,
+            "overdue_rule": rule.describe(),
+            "summary": summary,
+            "projects": rows.iter().enumerate().map(|(rank, row)| json!({
+                "rank": rank + 1,
+                "id": row.id,
+                "name": row.name,
+                "parent": row.parent,
+                "health": row.health(),
+                "open_issues": row.open_issues,
+                "overdue_issues": row.overdue_issues,
+                "overdue_percent": (row.overdue_ratio() * 100.0).round(),
+                "unassigned_issues": row.unassigned_issues,
+                "remaining_hours": round(row.remaining_hours),
+                "hours_in_period": round(row.hours_in_period),
+                "risk_score": round(row.risk_score())
+            })).collect::<Vec<_>>(),
+            "truncated": issue_scan.truncated || time_scan.truncated
+        });
+        
+        info!("Přehled portfolia: {} projektů", total_projects);
+        
+        let mut output = format!(
+            "## {}\n\n{}",
+            (messages.portfolio_heading)(total_projects, &from.format("%Y-%m-%d").to_string(), &to.format("%Y-%m-%d").to_string()),
+            table
+        );
+        if rows.len() < total_projects {
+            output.push_str(&format!("\n{}\n", (messages.shown_first_projects)(rows.len(), total_projects)));
+        }
+        if issue_scan.truncated || time_scan.truncated {
+            output.push_str(&format!("\n{}\n", messages.portfolio_scan_truncated));
+        }
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&report))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    fn task(id: i32, start: i64, duration: i64) -> GanttTask {
+        GanttTask {
+            id,
+            subject: format!("Úkol {}", id),
+            parent_id: None,
+            start_date: date("2024-01-15"),
+            due_date: date("2024-01-16"),
+            done_ratio: None,
+            assignee: None,
+            closed: false,
+            start,
+            duration,
+            early_start: start,
+            late_start: start,
+        }
+    }
+
+    fn dependency(from: i32, to: i32, delay: i64) -> GanttDependency {
+        GanttDependency { from, to, delay, relation_type: "precedes".to_string() }
+    }
+
+    fn relation(id: i32, issue_id: i32, issue_to_id: i32, relation_type: &str) -> Value {
+        json!({ "id": id, "issue_id": issue_id, "issue_to_id": issue_to_id, "relation_type": relation_type })
+    }
+
+    fn issue(id: i32, subject: &str, start_date: Option<&str>, due_date: Option<&str>, extra: Value) -> Value {
+        let mut issue = json!({
+            "id": id,
+            "subject": subject,
+            "project": { "id": 1, "name": "Web" },
+            "tracker": { "id": 1, "name": "Úkol" },
+            "status": { "id": 1, "name": "Nový" },
+            "priority": { "id": 2, "name": "Normální" },
+            "start_date": start_date,
+            "due_date": due_date
+        });
+        issue.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+        issue
+    }
+
+    async fn gantt_tool(base_url: &str) -> GenerateGanttDataTool {
+        let mut config = crate::config::AppConfig::default();
+        config.easyproject.base_url = base_url.to_string();
+        config.easyproject.api_key = Some("test".to_string());
+        config.cache.enabled = false;
+        let client = EasyProjectClient::new(&config).await.unwrap();
+        GenerateGanttDataTool::new(client, config)
+    }
+
+    #[test]
+    fn test_finish_to_start() {
+        let parse = |value: Value| serde_json::from_value::<IssueRelation>(value).unwrap();
+        assert_eq!(finish_to_start(&parse(relation(1, 10, 20, "precedes"))), Some((10, 20)));
+        assert_eq!(finish_to_start(&parse(relation(1, 10, 20, "blocks"))), Some((10, 20)));
+        assert_eq!(finish_to_start(&parse(relation(1, 10, 20, "follows"))), Some((20, 10)));
+        assert_eq!(finish_to_start(&parse(relation(1, 10, 20, "blocked"))), Some((20, 10)));
+        assert_eq!(finish_to_start(&parse(relation(1, 10, 20, "relates"))), None);
+    }
+
+    #[test]
+    fn test_compute_critical_path() {
+        // 1 -> 2 (s posunem 1 den), 3 běží souběžně a má rezervu
+        let mut tasks: HashMap<i32, GanttTask> = [task(1, 0, 3), task(2, 2, 2), task(3, 0, 2)]
+            .into_iter().map(|task| (task.id, task)).collect();
+
+        let (order, cyclic) = compute_critical_path(&mut tasks, &[dependency(1, 2, 1)]);
+
+        assert_eq!(order.len(), 3);
+        assert!(order.iter().position(|id| *id == 1) < order.iter().position(|id| *id == 2));
+        assert!(cyclic.is_empty());
+        // Úkol 2 nezačne dřív než den po skončení úkolu 1
+        assert_eq!(tasks[&2].early_start, 4);
+        assert_eq!(tasks[&1].slack(), 0);
+        assert_eq!(tasks[&2].slack(), 0);
+        assert_eq!(tasks[&3].late_start, 4);
+        assert_eq!(tasks[&3].slack(), 4);
+    }
+
+    #[test]
+    fn test_compute_critical_path_cycle() {
+        let mut tasks: HashMap<i32, GanttTask> = [task(1, 0, 1), task(2, 1, 1), task(3, 0, 2)]
+            .into_iter().map(|task| (task.id, task)).collect();
+
+        let (order, cyclic) = compute_critical_path(&mut tasks, &[dependency(1, 2, 0), dependency(2, 1, 0)]);
+
+        assert_eq!(order, vec![3]);
+        assert_eq!(cyclic, vec![1, 2]);
+        assert_eq!(tasks[&3].slack(), 0);
+    }
+
+    #[test]
+    fn test_mermaid_gantt() {
+        let mut critical = task(1, 0, 2);
+        critical.subject = "Návrh: API #1".to_string();
+        critical.done_ratio = Some(50);
+        let mut done = task(2, 0, 2);
+        done.closed = true;
+        done.late_start = 3;
+        done.parent_id = Some(10);
+
+        let text = mermaid_gantt("Projekt 7", &[&critical, &done], |task| {
+            if task.parent_id == Some(10) { "Příprava".to_string() } else { "Úkoly".to_string() }
+        });
+
+        assert_eq!(text, "gantt\n    title Projekt 7\n    dateFormat YYYY-MM-DD\n\
+            \x20   section Úkoly\n\
+            \x20   #1 Návrh  API  1 :crit, active, t1, 2024-01-15, 2024-01-17\n\
+            \x20   section Příprava\n\
+            \x20   #2 Úkol 2 :done, t2, 2024-01-15, 2024-01-17\n");
+    }
+
+    #[tokio::test]
+    async fn test_generate_gantt_data() {
+        // 2024-01-15 je pondělí; 1 -> 2 -> 6, úkol 3 patří pod souhrnný úkol 10, úkol 4 nemá termíny
+        let precedes = relation(100, 1, 2, "precedes");
+        let issues = json!({
+            "issues": [
+                issue(1, "Analýza", Some("2024-01-15"), Some("2024-01-17"), json!({ "relations": [precedes.clone(), relation(103, 1, 999, "precedes")] })),
+                issue(2, "Implementace", Some("2024-01-18"), Some("2024-01-19"), json!({ "relations": [precedes], "done_ratio": 50 })),
+                issue(3, "Příprava prostředí", Some("2024-01-15"), Some("2024-01-16"), json!({ "parent": { "id": 10 }, "relations": [relation(102, 3, 1, "relates")] })),
+                issue(4, "Dokumentace", None, None, json!({})),
+                issue(6, "Nasazení", Some("2024-01-19"), Some("2024-01-22"), json!({ "relations": [relation(101, 6, 2, "blocked")] })),
+                issue(10, "Infrastruktura", None, None, json!({}))
+            ],
+            "total_count": 6
+        });
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/issues.json")
+            .match_query(mockito::Matcher::Any)
+            .with_header("content-type", "application/json")
+            .with_body(issues.to_string())
+            .create_async().await;
+
+        let result = gantt_tool(&server.url()).await
+            .execute(Some(json!({ "project_id": 1, "mermaid": true }))).await.unwrap();
+        let report = result.structured_content.unwrap();
+
+        assert_eq!(report["project_start"], "2024-01-15");
+        assert_eq!(report["project_finish"], "2024-01-22");
+        assert_eq!(report["working_days"], 6);
+        assert_eq!(report["critical_path"], json!([1, 2, 6]));
+        assert_eq!(report["ignored_dependencies"], 1);
+        assert_eq!(report["unscheduled"][0]["id"], 4);
+        assert_eq!(report["summary_tasks"][0]["children"], json!([3]));
+        assert_eq!(report["dependency_conflicts"], json!([{
+            "predecessor_id": 2,
+            "successor_id": 6,
+            "relation_type": "blocked",
+            "predecessor_due_date": "2024-01-19",
+            "successor_start_date": "2024-01-19",
+            "overlap_days": 1
+        }]));
+
+        let tasks = report["tasks"].as_array().unwrap();
+        let find = |id: i64| tasks.iter().find(|task| task["id"] == id).unwrap();
+        assert_eq!(find(6)["early_start"], "2024-01-22");
+        assert_eq!(find(6)["predecessors"], json!([2]));
+        assert_eq!(find(3)["slack_days"], 5);
+        assert_eq!(find(3)["critical"], false);
+
+        let mermaid = report["mermaid"].as_str().unwrap();
+        assert!(mermaid.contains("    section Infrastruktura\n    #3 Příprava prostředí :t3, 2024-01-15, 2024-01-17\n"));
+        assert!(mermaid.contains("    #2 Implementace :crit, active, t2, 2024-01-18, 2024-01-20\n"));
+    }
+
+    #[tokio::test]
+    async fn test_generate_gantt_data_api_error() {
+        let mut server = mockito::Server::new_async().await;
+        server.mock("GET", "/issues.json")
+            .match_query(mockito::Matcher::Any)
+            .with_status(403)
+            .create_async().await;
+
+        let result = gantt_tool(&server.url()).await
+            .execute(Some(json!({ "project_id": 1 }))).await.unwrap();
+
+        assert_eq!(result.is_error, Some(true));
+        assert!(matches!(&result.content[0], ToolResult::Text { text } if text.starts_with("Chyba při načítání úkolů projektu 1")));
+    }
+}
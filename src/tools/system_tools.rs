@@ -0,0 +1,908 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::sync::{Arc, RwLock};
+use tracing::{debug, info};
+
+use crate::api::EasyProjectClient;
+use crate::api::client::MAX_PAGE_SIZE;
+use crate::config::AppConfig;
+use crate::api::permissions::{PermissionArea, PermissionReport, ProbeStatus};
+use crate::audit::AuditLog;
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::utils::validation::{matches_api_path_pattern, normalize_api_path};
+use super::executor::ToolExecutor;
+
+/// Sdílený výsledek ověření oprávnění (registry + get_permissions)
+pub type SharedPermissions = Arc<RwLock<Option<PermissionReport>>>;
+
+// === GET PERMISSIONS TOOL ===
+
+pub struct GetPermissionsTool {
+    api_client: EasyProjectClient,
+    permissions: SharedPermissions,
+    tool_requirements: Vec<(String, PermissionArea)>,
+}
+
+impl GetPermissionsTool {
+    pub fn new(api_client: EasyProjectClient, permissions: SharedPermissions, tool_requirements: Vec<(String, PermissionArea)>) -> Self {
+        Self { api_client, permissions, tool_requirements }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GetPermissionsArgs {
+    #[serde(default)]
+    refresh: bool,
+}
+
+#[async_trait]
+impl ToolExecutor for GetPermissionsTool {
+    fn name(&self) -> &str {
+        "get_permissions"
+    }
+    
+    fn description(&self) -> &str {
+        "Zobrazí, ke kterým oblastem EasyProject API má nakonfigurovaný API klíč přístup, \
+        a které tools jsou kvůli chybějícím oprávněním nedostupné"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "refresh": {
+                "type": "boolean",
+                "description": "Znovu ověřit oprávnění proti API místo použití posledního výsledku (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetPermissionsArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => GetPermissionsArgs { refresh: false },
+        };
+        
+        let cached = self.permissions.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let report = match cached {
+            Some(report) if !args.refresh => report,
+            _ => {
+                debug!("Ověřuji oprávnění API klíče");
+                let report = self.api_client.probe_permissions().await;
+                *self.permissions.write().unwrap_or_else(|e| e.into_inner()) = Some(report.clone());
+                report
+            }
+        };
+        
+        let messages = i18n::messages();
+        let mut result = format!("{}:\n\n", messages.api_key_permissions);
+        if let Some(ref name) = report.user_name {
+            result.push_str(&format!("{}: {} (ID: {})\n", messages.user, name, report.user_id.unwrap_or(0)));
+        }
+        if let Some(is_admin) = report.is_admin {
+            result.push_str(&format!("{}: {}\n", messages.administrator, if is_admin { messages.yes } else { messages.no }));
+        }
+        result.push_str(&format!("{}: {}\n\n", messages.probed_at, report.probed_at.format("%Y-%m-%d %H:%M:%S UTC")));
+        
+        for probe in &report.probes {
+            let status = match probe.status {
+                ProbeStatus::Allowed => messages.probe_allowed,
+                ProbeStatus::Denied => messages.probe_denied,
+                ProbeStatus::Unknown => messages.probe_unknown,
+            };
+            result.push_str(&format!("- {}: {}", probe.area.label(), status));
+            if let Some(ref detail) = probe.detail {
+                result.push_str(&format!(" ({})", detail));
+            }
+            result.push('\n');
+        }
+        
+        let unavailable: Vec<&str> = self.tool_requirements.iter()
+            .filter(|(_, area)| report.is_denied(*area))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        
+        if unavailable.is_empty() {
+            result.push_str(&format!("\n{}\n", messages.all_tools_available));
+        } else {
+            result.push_str(&format!("\n{}: {}\n", messages.unavailable_tools, unavailable.join(", ")));
+        }
+        
+        info!("Vrácen přehled oprávnění ({} nedostupných tools)", unavailable.len());
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(result)
+        ]))
+    }
+}
+
+// === VALIDATE CONFIG TOOL ===
+
+#[derive(Default)]
+pub struct ValidateConfigTool;
+
+impl ValidateConfigTool {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ValidateConfigArgs {
+    #[serde(default)]
+    include_defaulted: bool,
+}
+
+#[async_trait]
+impl ToolExecutor for ValidateConfigTool {
+    fn name(&self) -> &str {
+        "validate_config"
+    }
+    
+    fn description(&self) -> &str {
+        "Znovu načte konfiguraci (soubor config.toml a environment proměnné) a vypíše diagnostiku: \
+        klíče s neplatnou hodnotou, neznámé klíče (překlepy), klíče s výchozí hodnotou, \
+        přepisy z environment proměnných a výsledek validace"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "include_defaulted": {
+                "type": "boolean",
+                "description": "Vypsat i všechny klíče, pro které byla použita výchozí hodnota (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ValidateConfigArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => ValidateConfigArgs::default(),
+        };
+        
+        let (config, diagnostics) = match AppConfig::load_with_diagnostics() {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {:#}", i18n::messages().error_loading_config, e))
+                ]));
+            }
+        };
+        let validation = config.validate().err().map(|e| format!("{:#}", e));
+        
+        let messages = i18n::messages();
+        let mut result = format!("{}:\n\n", messages.config_diagnostics);
+        if let Some(ref error) = diagnostics.load_error {
+            result.push_str(&format!("{}\n\n", (messages.config_load_failed)(error)));
+        }
+        
+        if !diagnostics.failed_keys.is_empty() {
+            result.push_str(&format!("{}:\n", messages.config_failed_keys));
+            for failed in &diagnostics.failed_keys {
+                result.push_str(&format!("- {}: {}\n", failed.key, failed.error));
+            }
+            result.push('\n');
+        }
+        
+        if !diagnostics.unknown_keys.is_empty() {
+            result.push_str(&format!("{}:\n", messages.config_unknown_keys));
+            for key in &diagnostics.unknown_keys {
+                result.push_str(&format!("- {}\n", key));
+            }
+            result.push('\n');
+        }
+        
+        if !diagnostics.env_overrides.is_empty() {
+            result.push_str(&format!("{}:\n", messages.config_env_overrides));
+            for env in &diagnostics.env_overrides {
+                result.push_str(&format!("- {} ← {}\n", env.key, env.variable));
+            }
+            result.push('\n');
+        }
+        
+        if args.include_defaulted {
+            result.push_str(&format!("{}:\n", (messages.config_defaulted_keys)(diagnostics.defaulted_keys.len())));
+            for key in &diagnostics.defaulted_keys {
+                result.push_str(&format!("- {}\n", key));
+            }
+            result.push('\n');
+        } else {
+            result.push_str(&format!("{}\n\n", (messages.config_defaulted_count)(diagnostics.defaulted_keys.len())));
+        }
+        
+        match validation {
+            Some(ref error) => result.push_str(&(messages.config_validation_failed)(error)),
+            None if diagnostics.has_problems() => result.push_str(messages.config_valid_with_problems),
+            None => result.push_str(messages.config_ok),
+        }
+        result.push('\n');
+        
+        info!(
+            "Diagnostika konfigurace: {} neplatných, {} neznámých klíčů",
+            diagnostics.failed_keys.len(),
+            diagnostics.unknown_keys.len()
+        );
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(result)
+        ]))
+    }
+}
+
+// === RAW API REQUEST TOOL ===
+
+pub struct RawApiRequestTool {
+    api_client: EasyProjectClient,
+    allowed_paths: Vec<String>,
+}
+
+impl RawApiRequestTool {
+    pub fn new(api_client: EasyProjectClient, config: AppConfig) -> Self {
+        Self {
+            api_client,
+            allowed_paths: config.tools.raw_api.allowed_paths,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RawApiRequestArgs {
+    path: String,
+    #[serde(default)]
+    query: serde_json::Map<String, Value>,
+}
+
+#[async_trait]
+impl ToolExecutor for RawApiRequestTool {
+    fn name(&self) -> &str {
+        "raw_api_request"
+    }
+    
+    fn description(&self) -> &str {
+        "Obecný GET požadavek na REST API EasyProject pro endpointy, které nemají vlastní tool. \
+        Povolené jsou jen cesty z konfigurace tools.raw_api.allowed_paths; vrací JSON odpovědi"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "path": {
+                "type": "string",
+                "description": (i18n::messages().raw_api_path_description)(&self.allowed_paths.join(", "))
+            },
+            "query": {
+                "type": "object",
+                "description": "Query parametry požadavku (např. {\"limit\": 10, \"include\": \"journals\"})",
+                "additionalProperties": {
+                    "type": ["string", "number", "boolean"]
+                }
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["path"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: RawApiRequestArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => return Ok(CallToolResult::error(vec![
+                ToolResult::text((i18n::messages().missing_parameter)("path"))
+            ])),
+        };
+        
+        let path = match normalize_api_path(&args.path) {
+            Ok(path) => path,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        if !self.allowed_paths.iter().any(|pattern| matches_api_path_pattern(&path, pattern)) {
+            return Ok(CallToolResult::error(vec![ToolResult::text(
+                (i18n::messages().api_path_not_allowed)(&path, &self.allowed_paths.join(", "))
+            )]));
+        }
+        
+        let mut query = Vec::with_capacity(args.query.len());
+        for (key, value) in args.query {
+            // Autentifikaci řeší klient, API klíč v query by obešel nastavení serveru
+            if key.eq_ignore_ascii_case("key") {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().query_key_not_allowed.to_string())
+                ]));
+            }
+            let value = match value {
+                Value::String(text) => text,
+                Value::Number(number) => number.to_string(),
+                Value::Bool(flag) => flag.to_string(),
+                other => return Ok(CallToolResult::error(vec![ToolResult::text(
+                    (i18n::messages().query_value_type)(&key, &other.to_string())
+                )])),
+            };
+            query.push((key, value));
+        }
+        
+        debug!("raw_api_request: GET {} {:?}", path, query);
+        match self.api_client.get_raw(&path, &query).await {
+            Ok(response) => {
+                info!("raw_api_request: GET {} úspěšný", path);
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(serde_json::to_string_pretty(&response)?)
+                ]))
+            }
+            Err(e) => Ok(CallToolResult::error(vec![
+                ToolResult::text(format!("{}: {}", (i18n::messages().error_calling_api)(&path), e))
+            ])),
+        }
+    }
+}
+
+// === GET INSTANCE INFO TOOL ===
+
+/// Číselníky instance, jejichž počet tool zjišťuje (cesta, klíč v odpovědi), popisy v Messages::instance_enumeration_labels
+const INSTANCE_ENUMERATIONS: [(&str, &str); 6] = [
+    ("trackers", "trackers"),
+    ("issue_statuses", "issue_statuses"),
+    ("enumerations/issue_priorities", "issue_priorities"),
+    ("enumerations/time_entry_activities", "time_entry_activities"),
+    ("roles", "roles"),
+    ("custom_fields", "custom_fields"),
+];
+
+/// Volitelné REST endpointy rozšíření EasyProject, popisy v Messages::instance_plugin_labels
+const INSTANCE_PLUGIN_ENDPOINTS: [&str; 4] = [
+    "easy_contacts",
+    "easy_crm_cases",
+    "easy_attendances",
+    "easy_user_types",
+];
+
+pub struct GetInstanceInfoTool {
+    api_client: EasyProjectClient,
+    config: AppConfig,
+}
+
+impl GetInstanceInfoTool {
+    pub fn new(api_client: EasyProjectClient, config: AppConfig) -> Self {
+        Self { api_client, config }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for GetInstanceInfoTool {
+    fn name(&self) -> &str {
+        "get_instance_info"
+    }
+    
+    fn description(&self) -> &str {
+        "Zobrazí informace o připojené instanci EasyProject: verzi (pokud je zjistitelná), \
+        dostupné rozšíření a moduly projektů, počty front, stavů a dalších číselníků a limity API. \
+        Pomůže zjistit, proč některé tools nebo pole nejsou na instanci dostupné"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({})
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("Zjišťuji informace o instanci {}", self.config.easyproject.base_url);
+        
+        let messages = i18n::messages();
+        let mut info_json = json!({
+            "base_url": self.config.easyproject.base_url,
+            "auth_type": self.config.easyproject.auth_type,
+        });
+        
+        info_json["current_user"] = match self.api_client.get_current_user().await {
+            Ok(response) => json!({
+                "id": response.user.id,
+                "login": response.user.login,
+                "admin": response.user.admin,
+            }),
+            Err(e) => json!({ "error": format!("{}: {}", messages.current_user_unavailable, e) }),
+        };
+        
+        info_json["version"] = match self.api_client.detect_instance_version().await {
+            Some(version) => json!(version),
+            None => json!(messages.instance_version_unknown),
+        };
+        
+        let mut enumerations = serde_json::Map::new();
+        for ((path, key), label) in INSTANCE_ENUMERATIONS.into_iter().zip(messages.instance_enumeration_labels) {
+            let value = match self.api_client.get_raw(path, &[]).await {
+                Ok(response) => {
+                    let items = response[key].as_array().cloned().unwrap_or_default();
+                    json!({
+                        "label": label,
+                        "count": items.len(),
+                        "names": items.iter().filter_map(|item| item["name"].as_str()).collect::<Vec<_>>(),
+                    })
+                }
+                Err(e) => json!({ "label": label, "available": false, "error": e.to_string() }),
+            };
+            enumerations.insert(key.to_string(), value);
+        }
+        info_json["enumerations"] = Value::Object(enumerations);
+        
+        let mut plugins = serde_json::Map::new();
+        for (path, label) in INSTANCE_PLUGIN_ENDPOINTS.into_iter().zip(messages.instance_plugin_labels) {
+            let query = [("limit".to_string(), "1".to_string())];
+            let available = self.api_client.get_raw(path, &query).await.is_ok();
+            plugins.insert(path.to_string(), json!({ "label": label, "available": available }));
+        }
+        info_json["plugins"] = Value::Object(plugins);
+        
+        // Moduly používané v projektech (první stránka projektů)
+        let query = [
+            ("include".to_string(), "enabled_modules".to_string()),
+            ("limit".to_string(), MAX_PAGE_SIZE.to_string()),
+        ];
+        info_json["project_modules"] = match self.api_client.get_raw("projects", &query).await {
+            Ok(response) => {
+                let mut modules: Vec<&str> = response["projects"].as_array().into_iter().flatten()
+                    .flat_map(|project| project["enabled_modules"].as_array().into_iter().flatten())
+                    .filter_map(|module| module.as_str().or_else(|| module["name"].as_str()))
+                    .collect();
+                modules.sort_unstable();
+                modules.dedup();
+                json!(modules)
+            }
+            Err(e) => json!({ "error": format!("{}: {}", messages.project_modules_unavailable, e) }),
+        };
+        
+        info_json["api_limits"] = json!({
+            "max_page_size": MAX_PAGE_SIZE,
+            "max_scan_records": self.config.tools.reports.max_scan_records,
+            "rate_limit_per_minute": self.config.rate_limiting.enabled.then_some(self.config.rate_limiting.requests_per_minute),
+            "rate_limit_burst": self.config.rate_limiting.enabled.then_some(self.config.rate_limiting.burst_size),
+            "timeout_seconds": self.config.http.timeout_seconds,
+            "max_retries": self.config.http.max_retries,
+        });
+        info_json["connections"] = json!(self.api_client.connection_stats());
+        
+        info!("Vráceny informace o instanci {}", self.config.easyproject.base_url);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                messages.instance_info,
+                serde_json::to_string_pretty(&info_json)?
+            ))
+        ]))
+    }
+}
+
+// === CACHE STATS TOOL ===
+
+pub struct CacheStatsTool {
+    api_client: EasyProjectClient,
+    config: AppConfig,
+}
+
+impl CacheStatsTool {
+    pub fn new(api_client: EasyProjectClient, config: AppConfig) -> Self {
+        Self { api_client, config }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for CacheStatsTool {
+    fn name(&self) -> &str {
+        "cache_stats"
+    }
+    
+    fn description(&self) -> &str {
+        "Zobrazí statistiku cache API klienta: počet záznamů, zásahy a výpadky podle typu entity, \
+        záznamy vyřazené po TTL nebo kvůli kapacitě, invalidace po zápisech a odpovědi 304 \
+        z podmíněných dotazů. Obsahuje i TTL jednotlivých typů entit pro odhad stáří dat"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({})
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let stats = self.api_client.cache_stats().await;
+        let cache = &self.config.cache;
+        
+        let mut stats_json = json!(stats);
+        stats_json["ttl_seconds"] = json!({
+            "default": cache.ttl_seconds,
+            "project": cache.project_ttl,
+            "user": cache.user_ttl,
+            "issue": cache.issue_ttl,
+            "time_entry": cache.time_entry_ttl,
+            "tool_result": cache.tool_result_ttl,
+        });
+        
+        debug!("Statistika cache: {} zásahů, {} výpadků", stats.hits, stats.misses);
+        
+        let messages = i18n::messages();
+        let summary = if stats.enabled {
+            (messages.cache_summary)(stats.entries, stats.max_entries, stats.hit_ratio * 100.0, stats.hits, stats.misses)
+        } else {
+            messages.cache_disabled.to_string()
+        };
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!("{}\n\n{}", summary, serde_json::to_string_pretty(&stats_json)?))
+        ]).with_structured_content(&stats_json))
+    }
+}
+
+// === CLEAR CACHE TOOL ===
+
+pub struct ClearCacheTool {
+    api_client: EasyProjectClient,
+}
+
+impl ClearCacheTool {
+    pub fn new(api_client: EasyProjectClient, _config: AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ClearCacheArgs {
+    #[serde(default)]
+    pattern: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for ClearCacheTool {
+    fn name(&self) -> &str {
+        "clear_cache"
+    }
+    
+    fn description(&self) -> &str {
+        "Vymaže cache API klienta, aby se další dotazy načetly přímo z EasyProject. \
+        Bez parametru vymaže vše, s parametrem pattern jen odpovídající záznamy \
+        (např. 'issue_42', 'project_7' nebo 'issues_*')"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "pattern": {
+                "type": "string",
+                "description": "Vzor klíče cache: entita s ID (např. 'issue_42') nebo glob s * (např. 'issues_*'). Bez vzoru se vymaže celá cache"
+            }
+        })
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ClearCacheArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => ClearCacheArgs::default(),
+        };
+        
+        let before = self.api_client.cache_stats().await.entries;
+        let message = match args.pattern.as_deref().map(str::trim).filter(|pattern| !pattern.is_empty()) {
+            Some(pattern) => {
+                self.api_client.invalidate_cache(pattern).await;
+                let after = self.api_client.cache_stats().await.entries;
+                (i18n::messages().cache_pattern_cleared)(pattern, before.saturating_sub(after), before)
+            }
+            None => {
+                self.api_client.clear_cache().await;
+                (i18n::messages().cache_cleared)(before)
+            }
+        };
+        
+        info!("{}", message);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(message)
+        ]))
+    }
+}
+
+// === CHECK CONNECTION TOOL ===
+
+pub struct CheckConnectionTool {
+    api_client: EasyProjectClient,
+}
+
+impl CheckConnectionTool {
+    pub fn new(api_client: EasyProjectClient, _config: AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for CheckConnectionTool {
+    fn name(&self) -> &str {
+        "check_connection"
+    }
+    
+    fn description(&self) -> &str {
+        "Ověří spojení s EasyProject: dostupnost instance, platnost API klíče nebo přihlášení, \
+        dobu odezvy a verzi instance (jen pro administrátora). Použijte jako první krok, \
+        když volání ostatních tools selhávají"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({})
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let check = self.api_client.check_connection().await;
+        
+        let messages = i18n::messages();
+        let summary = match check.error {
+            None => (messages.connection_ok)(
+                &check.base_url,
+                check.user.as_deref().unwrap_or("?"),
+                check.latency_ms,
+                &check.version.as_deref().map(messages.version_suffix).unwrap_or_default()
+            ),
+            Some(ref error) if check.reachable => (messages.connection_auth_failed)(&check.base_url, check.latency_ms, error),
+            Some(ref error) => (messages.connection_unreachable)(&check.base_url, error),
+        };
+        
+        info!("Ověření spojení: dostupné {}, přihlášeno {}, {} ms", check.reachable, check.authenticated, check.latency_ms);
+        
+        let content = vec![ToolResult::text(format!("{}\n\n{}", summary, serde_json::to_string_pretty(&check)?))];
+        let result = if check.authenticated {
+            CallToolResult::success(content)
+        } else {
+            CallToolResult::error(content)
+        };
+        Ok(result.with_structured_content(&check))
+    }
+}
+
+// === GET SERVER INFO TOOL ===
+
+pub struct GetServerInfoTool {
+    api_client: EasyProjectClient,
+    config: AppConfig,
+    permissions: SharedPermissions,
+}
+
+impl GetServerInfoTool {
+    pub fn new(api_client: EasyProjectClient, config: AppConfig, permissions: SharedPermissions) -> Self {
+        Self { api_client, config, permissions }
+    }
+}
+
+/// Zamaskuje tajný údaj, ponechá jen poslední 4 znaky delších hodnot
+fn redact_secret(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() > 8 {
+        format!("***{}", chars[chars.len() - 4..].iter().collect::<String>())
+    } else {
+        "***".to_string()
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for GetServerInfoTool {
+    fn name(&self) -> &str {
+        "get_server_info"
+    }
+    
+    fn description(&self) -> &str {
+        "Zobrazí verzi a nastavení tohoto MCP serveru: připojenou instanci EasyProject \
+        (API klíč zamaskovaný), povolené kategorie tools, nastavení cache a rate limitingu \
+        a zjištěné schopnosti API. Hodí se při ladění více prostředí"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({})
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let config = &self.config;
+        let tools = &config.tools;
+        
+        let categories: Vec<&str> = [
+            ("projects", tools.projects.enabled),
+            ("issues", tools.issues.enabled),
+            ("users", tools.users.enabled),
+            ("time_entries", tools.time_entries.enabled),
+            ("reports", tools.reports.enabled),
+            ("milestones", tools.milestones.enabled),
+            ("raw_api", tools.raw_api.enabled),
+        ].into_iter()
+            .filter_map(|(category, enabled)| enabled.then_some(category))
+            .collect();
+        
+        let report = self.permissions.read().unwrap_or_else(|e| e.into_inner()).clone();
+        let capabilities = match report {
+            Some(report) => json!({
+                "probed_at": report.probed_at,
+                "is_admin": report.is_admin,
+                "areas": report.probes,
+            }),
+            None => json!(i18n::messages().capabilities_not_probed),
+        };
+        
+        let cache_stats = self.api_client.cache_stats().await;
+        let info_json = json!({
+            "server": {
+                "name": config.server.name,
+                "version": config.server.version,
+                "build_version": env!("CARGO_PKG_VERSION"),
+                "transport": config.server.transport,
+            },
+            "easyproject": {
+                "base_url": config.easyproject.base_url,
+                "api_version": config.easyproject.api_version,
+                "auth_type": config.easyproject.auth_type,
+                "api_key": config.easyproject.api_key.as_deref().map(redact_secret),
+                "username": config.easyproject.username,
+                "version": self.api_client.detect_instance_version().await,
+            },
+            "tools": {
+                "enabled_categories": categories,
+                "read_only": tools.read_only,
+                "allowlist": tools.allowlist,
+                "denylist": tools.denylist,
+                "probe_permissions": tools.probe_permissions,
+                "structured_content": tools.structured_content,
+            },
+            "cache": {
+                "enabled": config.cache.enabled,
+                "ttl_seconds": config.cache.ttl_seconds,
+                "max_entries": config.cache.max_entries,
+                "conditional_requests": config.cache.conditional_requests,
+                "entries": cache_stats.entries,
+                "hit_ratio": cache_stats.hit_ratio,
+            },
+            "rate_limiting": {
+                "enabled": config.rate_limiting.enabled,
+                "requests_per_minute": config.rate_limiting.requests_per_minute,
+                "burst_size": config.rate_limiting.burst_size,
+                "current_requests_per_minute": self.api_client.current_requests_per_minute(),
+                "queued_requests": self.api_client.queued_requests(),
+            },
+            "capabilities": capabilities,
+        });
+        
+        debug!("Vráceny informace o serveru {} {}", config.server.name, config.server.version);
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().server_info)(&config.server.name, &config.server.version),
+                serde_json::to_string_pretty(&info_json)?
+            ))
+        ]).with_structured_content(&info_json))
+    }
+}
+
+// === GET AUDIT LOG TOOL ===
+
+/// Výchozí a nejvyšší počet vrácených záznamů auditu
+const DEFAULT_AUDIT_LIMIT: usize = 20;
+const MAX_AUDIT_LIMIT: usize = 200;
+
+pub struct GetAuditLogTool {
+    audit_log: Arc<AuditLog>,
+}
+
+impl GetAuditLogTool {
+    pub fn new(audit_log: Arc<AuditLog>) -> Self {
+        Self { audit_log }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct GetAuditLogArgs {
+    #[serde(default)]
+    limit: Option<usize>,
+    #[serde(default)]
+    tool: Option<String>,
+}
+
+#[async_trait]
+impl ToolExecutor for GetAuditLogTool {
+    fn name(&self) -> &str {
+        "get_audit_log"
+    }
+    
+    fn description(&self) -> &str {
+        "Zobrazí audit posledních volání tools, které mění data v EasyProject: čas, tool, argumenty \
+        (hesla a tokeny zamaskované), ID vytvořené nebo změněné entity, výsledek a MCP klienta. \
+        Záznamy jsou od nejnovějšího"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "limit": {
+                "type": "integer",
+                "description": "Počet vrácených záznamů (výchozí 20, maximum 200)",
+                "minimum": 1,
+                "maximum": MAX_AUDIT_LIMIT
+            },
+            "tool": {
+                "type": "string",
+                "description": "Jen záznamy daného tool (např. 'update_issue')"
+            }
+        })
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn is_cacheable(&self) -> bool {
+        false
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetAuditLogArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => GetAuditLogArgs::default(),
+        };
+        
+        let limit = args.limit.unwrap_or(DEFAULT_AUDIT_LIMIT).clamp(1, MAX_AUDIT_LIMIT);
+        let entries = self.audit_log.recent(limit, args.tool.as_deref());
+        
+        if entries.is_empty() {
+            return Ok(CallToolResult::success(vec![
+                ToolResult::text(i18n::messages().audit_empty.to_string())
+            ]));
+        }
+        
+        let failed = entries.iter().filter(|entry| !entry.success).count();
+        let entries_json = json!({ "count": entries.len(), "failed": failed, "entries": entries });
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(format!(
+                "{}:\n\n{}",
+                (i18n::messages().audit_summary)(entries.len(), failed),
+                serde_json::to_string_pretty(&entries_json)?
+            ))
+        ]).with_structured_content(&entries_json))
+    }
+}
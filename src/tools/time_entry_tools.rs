@@ -1,13 +1,101 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
 use chrono::NaiveDate;
 
-use crate::api::{EasyProjectClient, CreateTimeEntryRequest, CreateTimeEntry};
-use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::api::{EasyProjectClient, CreateTimeEntryRequest, CreateTimeEntry, TimeEntry, UpdateTimeEntryRequest, UpdateTimeEntry};
+use crate::api::client::{TimeEntryListFilter, FETCH_ALL};
+use crate::api::error::ApiResult;
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult, PaginationInfo};
+use crate::utils::date_utils::is_business_day;
+use crate::utils::formatting::{ExportFormat, OutputFormat, TIME_ENTRY_COLUMNS, csv_table, columns_schema, format_time_entry, format_time_entry_list, markdown_table, select_columns};
+use crate::utils::text_utils::text_equals;
 use super::executor::ToolExecutor;
 
+/// Aliasy parametru `id` pro tools pracující s jedním časovým záznamem
+const TIME_ENTRY_ID_ALIASES: &[(&str, &str)] = &[("time_entry_id", "id"), ("entry_id", "id")];
+
+/// Rozdíl hodin, do kterého se existující záznam považuje za možný duplikát
+const DUPLICATE_HOURS_TOLERANCE: f64 = 0.25;
+
+/// Najde záznamy uživatele (výchozí aktuálního) ze stejného dne na stejném úkolu (projektu) s podobnými hodinami
+///
+/// Chrání před dvojím zápisem času, když agent po chybě nebo timeoutu volání zopakuje.
+/// Záznamy s odlišným komentářem (bez ohledu na velikost písmen a diakritiku) se za duplikát nepovažují.
+async fn find_duplicate_entries(api_client: &EasyProjectClient, user_id: Option<i32>, issue_id: Option<i32>, project_id: Option<i32>, spent_on: NaiveDate, hours: f64, comments: Option<&str>) -> ApiResult<Vec<TimeEntry>> {
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => api_client.get_current_user().await?.user.id,
+    };
+    let day = spent_on.format("%Y-%m-%d").to_string();
+    let response = api_client.list_time_entries(TimeEntryListFilter {
+        project_id,
+        issue_id,
+        user_id: Some(user_id),
+        limit: Some(100),
+        from_date: Some(day.clone()),
+        to_date: Some(day),
+        ..Default::default()
+    }).await?;
+    
+    Ok(response.time_entries.into_iter()
+        .filter(|entry| entry.user.id == user_id && entry.spent_on == spent_on)
+        .filter(|entry| entry.issue.as_ref().map(|issue| issue.id) == issue_id)
+        .filter(|entry| issue_id.is_some() || Some(entry.project.id) == project_id)
+        .filter(|entry| (entry.hours - hours).abs() <= DUPLICATE_HOURS_TOLERANCE)
+        .filter(|entry| same_comment(entry.comments.as_deref(), comments))
+        .collect())
+}
+
+/// Komentáře se shodují, pokud některý chybí nebo se liší jen velikostí písmen a diakritikou
+fn same_comment(existing: Option<&str>, new: Option<&str>) -> bool {
+    match (existing.filter(|c| !c.trim().is_empty()), new.filter(|c| !c.trim().is_empty())) {
+        (Some(existing), Some(new)) => text_equals(existing, new),
+        _ => true,
+    }
+}
+
+fn duplicate_entries_error(duplicates: &[TimeEntry]) -> CallToolResult {
+    let listed: Vec<String> = duplicates.iter()
+        .map(|entry| format!(
+            "- #{}: {} h, {}, {}{}",
+            entry.id,
+            entry.hours,
+            entry.spent_on,
+            entry.activity.name,
+            entry.comments.as_deref().filter(|c| !c.is_empty()).map(|c| format!(" ({})", c)).unwrap_or_default()
+        ))
+        .collect();
+    
+    CallToolResult::error(vec![
+        ToolResult::text((i18n::messages().duplicate_time_entries)(&listed.join("\n")))
+    ])
+}
+
+/// Ověří dvojici úkol/projekt nového časového záznamu a vrátí projekt, do kterého patří
+///
+/// Při zadaném úkolu se projekt doplní z úkolu; pokud je zadán i projekt, musí se shodovat,
+/// jinak EasyProject záznam odmítne s nesrozumitelnou chybou.
+async fn resolve_entry_project(api_client: &EasyProjectClient, issue_id: Option<i32>, project_id: Option<i32>) -> Result<Option<i32>, String> {
+    let Some(issue_id) = issue_id else {
+        return Ok(project_id);
+    };
+    
+    let issue = api_client.get_issue(issue_id, None).await
+        .map_err(|e| format!("{}: {}", (i18n::messages().issue_unreadable)(issue_id), e))?
+        .issue;
+    
+    match project_id {
+        Some(project_id) if project_id != issue.project.id => Err(
+            (i18n::messages().issue_project_mismatch)(issue_id, &issue.project.name, issue.project.id, project_id)
+        ),
+        _ => Ok(Some(issue.project.id)),
+    }
+}
+
 // === LIST TIME ENTRIES TOOL ===
 
 pub struct ListTimeEntriesTool {
@@ -37,6 +125,16 @@ struct ListTimeEntriesArgs {
     from_date: Option<String>,
     #[serde(default)]
     to_date: Option<String>,
+    #[serde(default)]
+    include_subprojects: Option<bool>,
+    #[serde(default)]
+    fetch_all: bool,
+    #[serde(default)]
+    format: OutputFormat,
+    #[serde(default)]
+    columns: Option<Vec<String>>,
+    #[serde(default)]
+    export_format: Option<ExportFormat>,
 }
 
 #[async_trait]
@@ -53,15 +151,18 @@ impl ToolExecutor for ListTimeEntriesTool {
         json!({
             "limit": {
                 "type": "integer",
-                "description": "Maximální počet záznamů k vrácení (výchozí: 25, maximum: 100)",
-                "minimum": 1,
-                "maximum": 100
+                "description": "Maximální počet záznamů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)",
+                "minimum": 1
             },
             "offset": {
                 "type": "integer",
                 "description": "Počet záznamů k přeskočení pro stránkování",
                 "minimum": 0
             },
+            "fetch_all": {
+                "type": "boolean",
+                "description": "Načte všechny časové záznamy od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)"
+            },
             "project_id": {
                 "type": "integer",
                 "description": "ID projektu pro filtrování"
@@ -83,10 +184,25 @@ impl ToolExecutor for ListTimeEntriesTool {
                 "type": "string",
                 "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
                 "description": "Datum do (formát: YYYY-MM-DD)"
-            }
+            },
+            "include_subprojects": {
+                "type": "boolean",
+                "description": "Při zadaném project_id zahrnout i časové záznamy podprojektů (výchozí: podle nastavení EasyProject instance)"
+            },
+            "format": OutputFormat::schema(),
+            "columns": columns_schema(TIME_ENTRY_COLUMNS),
+            "export_format": ExportFormat::schema()
         })
     }
     
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::TimeEntries)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: ListTimeEntriesArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -99,16 +215,26 @@ impl ToolExecutor for ListTimeEntriesTool {
                 user_id: None,
                 from_date: None,
                 to_date: None,
+                include_subprojects: None,
+                fetch_all: false,
+                format: OutputFormat::default(),
+                columns: None,
+                export_format: None,
             }
         };
         
         debug!("Získávám časové záznamy s parametry: {:?}", args);
+
+        let columns = match select_columns(TIME_ENTRY_COLUMNS, args.columns.as_deref()) {
+            Ok(columns) => columns,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
         
         // Validace dat
         if let Some(ref from_str) = args.from_date {
             if NaiveDate::parse_from_str(from_str, "%Y-%m-%d").is_err() {
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Neplatný formát data 'from_date': {}. Očekávaný formát: YYYY-MM-DD", from_str))
+                    ToolResult::text((i18n::messages().invalid_date)("from_date", from_str))
                 ]));
             }
         }
@@ -116,41 +242,56 @@ impl ToolExecutor for ListTimeEntriesTool {
         if let Some(ref to_str) = args.to_date {
             if NaiveDate::parse_from_str(to_str, "%Y-%m-%d").is_err() {
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Neplatný formát data 'to_date': {}. Očekávaný formát: YYYY-MM-DD", to_str))
+                    ToolResult::text((i18n::messages().invalid_date)("to_date", to_str))
                 ]));
             }
         }
         
-        match self.api_client.list_time_entries(
-            args.project_id,
-            args.issue_id,
-            args.user_id,
-            args.limit,
-            args.offset,
-            args.from_date,
-            args.to_date
-        ).await {
+        match self.api_client.list_time_entries(TimeEntryListFilter {
+            project_id: args.project_id,
+            issue_id: args.issue_id,
+            user_id: args.user_id,
+            limit: if args.fetch_all { Some(FETCH_ALL) } else { args.limit },
+            offset: args.offset,
+            from_date: args.from_date,
+            to_date: args.to_date,
+            include_subprojects: args.include_subprojects,
+        }).await {
             Ok(response) => {
-                let time_entries_json = serde_json::to_string_pretty(&response)?;
                 let total_hours: f64 = response.time_entries.iter().map(|te| te.hours).sum();
+                let title = (i18n::messages().found_time_entries_total)(
+                    response.time_entries.len(),
+                    response.total_count.unwrap_or(response.time_entries.len() as i32),
+                    total_hours
+                );
+                let output = match args.export_format {
+                    Some(ExportFormat::Csv) => csv_table(&response.time_entries, &columns),
+                    None => args.format.render_list(
+                        &title,
+                        &response,
+                        || format_time_entry_list(&response.time_entries),
+                        || markdown_table(&response.time_entries, &columns),
+                    )?,
+                };
                 
                 info!("Úspěšně získáno {} časových záznamů (celkem {} hodin)", 
                       response.time_entries.len(), total_hours);
                 
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.time_entries.len(),
+                ).with_requested_limit(args.limit);
+                
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Nalezeno {} časových záznamů (celkem: {}, {} hodin):\n\n{}",
-                        response.time_entries.len(),
-                        response.total_count.unwrap_or(response.time_entries.len() as i32),
-                        total_hours,
-                        time_entries_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response).with_pagination(pagination))
             }
             Err(e) => {
                 error!("Chyba při získávání časových záznamů: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání časových záznamů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_time_entries, e))
                 ]))
             }
         }
@@ -159,7 +300,6 @@ impl ToolExecutor for ListTimeEntriesTool {
 
 // === GET TIME ENTRY TOOL ===
 
-#[allow(dead_code)]
 pub struct GetTimeEntryTool {
     api_client: EasyProjectClient,
     _config: crate::config::AppConfig,
@@ -171,10 +311,11 @@ impl GetTimeEntryTool {
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct GetTimeEntryArgs {
     id: i32,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[async_trait]
@@ -183,6 +324,10 @@ impl ToolExecutor for GetTimeEntryTool {
         "get_time_entry"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        TIME_ENTRY_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá detail konkrétního časového záznamu podle ID"
     }
@@ -192,15 +337,70 @@ impl ToolExecutor for GetTimeEntryTool {
             "id": {
                 "type": "integer",
                 "description": "ID časového záznamu"
-            }
+            },
+            "format": OutputFormat::schema()
         })
     }
     
-    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
-        // Zatím není implementováno v API klientovi
-        Ok(CallToolResult::error(vec![
-            ToolResult::text("get_time_entry zatím není implementováno".to_string())
-        ]))
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetTimeEntryArgs = serde_json::from_value(
+            arguments.ok_or(i18n::messages().missing_id)?
+        )?;
+        
+        debug!("Získávám časový záznam s ID: {}", args.id);
+        
+        let entry = match self.api_client.get_time_entry(args.id).await {
+            Ok(response) => response.time_entry,
+            Err(e) => {
+                error!("Chyba při získávání časového záznamu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_time_entry)(args.id), e))
+                ]));
+            }
+        };
+        
+        let mut detail = serde_json::to_value(&entry)?;
+        
+        // Doplnění souvisejícího úkolu - záznam obsahuje jen jeho ID
+        if let Some(ref issue) = entry.issue {
+            detail["issue"] = match self.api_client.get_issue(issue.id, None).await {
+                Ok(response) => json!({
+                    "id": response.issue.id,
+                    "subject": response.issue.subject,
+                    "tracker": response.issue.tracker.name,
+                    "status": response.issue.status.name,
+                    "assigned_to": response.issue.assigned_to.as_ref().map(|user| &user.name),
+                }),
+                Err(e) => {
+                    warn!("Úkol {} časového záznamu {} nelze načíst: {}", issue.id, args.id, e);
+                    json!({ "id": issue.id, "error": format!("{}: {}", i18n::messages().issue_unavailable, e) })
+                }
+            };
+        }
+        
+        info!("Úspěšně získán časový záznam {}", args.id);
+        
+        let messages = i18n::messages();
+        let title = (messages.time_entry_detail)(entry.id, entry.hours, &entry.spent_on.to_string(), &entry.project.name);
+        let output = args.format.render(&title, &detail, || {
+            let mut text = format_time_entry(&entry);
+            if let Some(subject) = detail.pointer("/issue/subject").and_then(Value::as_str) {
+                text.push_str(&format!("  {}: {}\n", messages.issue_subject, subject));
+            }
+            text
+        })?;
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&detail))
     }
 }
 
@@ -208,12 +408,12 @@ impl ToolExecutor for GetTimeEntryTool {
 
 pub struct CreateTimeEntryTool {
     api_client: EasyProjectClient,
-    _config: crate::config::AppConfig,
+    config: crate::config::AppConfig,
 }
 
 impl CreateTimeEntryTool {
-    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
-        Self { api_client, _config }
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self { api_client, config }
     }
 }
 
@@ -228,6 +428,8 @@ struct CreateTimeEntryArgs {
     project_id: Option<i32>,
     #[serde(default)]
     comments: Option<String>,
+    #[serde(default)]
+    allow_duplicate: bool,
 }
 
 #[async_trait]
@@ -263,18 +465,27 @@ impl ToolExecutor for CreateTimeEntryTool {
             },
             "project_id": {
                 "type": "integer",
-                "description": "ID projektu (alternativně k issue_id)"
+                "description": "ID projektu (alternativně k issue_id, při zadaném úkolu se doplní z úkolu a musí se shodovat)"
             },
             "comments": {
                 "type": "string",
                 "description": "Komentář k časovému záznamu"
+            },
+            "allow_duplicate": {
+                "type": "boolean",
+                "description": "Vytvořit záznam i tehdy, když ve stejný den na stejném úkolu existuje záznam s podobnými hodinami (výchozí: false)",
+                "default": false
             }
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["hours", "activity_id", "spent_on"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: CreateTimeEntryArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinné parametry")?
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
         )?;
         
         debug!("Vytvářím časový záznam: {:?}", args);
@@ -282,7 +493,7 @@ impl ToolExecutor for CreateTimeEntryTool {
         // Validace
         if args.hours <= 0.0 || args.hours > 24.0 {
             return Ok(CallToolResult::error(vec![
-                ToolResult::text("Počet hodin musí být mezi 0.01 a 24.0".to_string())
+                ToolResult::text(i18n::messages().invalid_hours.to_string())
             ]));
         }
         
@@ -290,24 +501,41 @@ impl ToolExecutor for CreateTimeEntryTool {
             Ok(date) => date,
             Err(_) => {
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Neplatný formát data 'spent_on': {}. Očekávaný formát: YYYY-MM-DD", args.spent_on))
+                    ToolResult::text((i18n::messages().invalid_date)("spent_on", &args.spent_on))
                 ]));
             }
         };
         
         if args.issue_id.is_none() && args.project_id.is_none() {
             return Ok(CallToolResult::error(vec![
-                ToolResult::text("Musí být zadán alespoň jeden z parametrů 'issue_id' nebo 'project_id'".to_string())
+                ToolResult::text(i18n::messages().issue_or_project_required.to_string())
             ]));
         }
         
+        let project_id = match resolve_entry_project(&self.api_client, args.issue_id, args.project_id).await {
+            Ok(project_id) => project_id,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        if self.config.tools.time_entries.duplicate_check && !args.allow_duplicate {
+            match find_duplicate_entries(&self.api_client, None, args.issue_id, project_id, spent_on, args.hours, args.comments.as_deref()).await {
+                Ok(duplicates) if !duplicates.is_empty() => {
+                    info!("Odmítnut pravděpodobně duplicitní časový záznam ({} existujících)", duplicates.len());
+                    return Ok(duplicate_entries_error(&duplicates));
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Kontrolu duplicitních časových záznamů nelze provést: {}", e),
+            }
+        }
+        
         let time_entry = CreateTimeEntry {
             issue_id: args.issue_id,
-            project_id: args.project_id,
+            project_id,
             spent_on,
             hours: args.hours,
             activity_id: args.activity_id,
             comments: args.comments,
+            user_id: None,
         };
         
         let request = CreateTimeEntryRequest { time_entry };
@@ -317,19 +545,18 @@ impl ToolExecutor for CreateTimeEntryTool {
                 info!("Úspěšně vytvořen časový záznam s ID: {}", response.time_entry.id);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Časový záznam úspěšně vytvořen s ID: {} ({} hodin na {})",
+                    ToolResult::text((i18n::messages().time_entry_created)(
                         response.time_entry.id,
                         response.time_entry.hours,
-                        response.time_entry.spent_on
+                        &response.time_entry.spent_on.to_string()
                     ))
                 ]))
             }
             Err(e) => {
                 error!("Chyba při vytváření časového záznamu: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při vytváření časového záznamu: {}", e))
-                ]))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_creating_time_entry, e))
+                ]).with_validation_errors(e.validation_errors()))
             }
         }
     }
@@ -337,7 +564,6 @@ impl ToolExecutor for CreateTimeEntryTool {
 
 // === UPDATE TIME ENTRY TOOL ===
 
-#[allow(dead_code)]
 pub struct UpdateTimeEntryTool {
     api_client: EasyProjectClient,
     _config: crate::config::AppConfig,
@@ -349,7 +575,6 @@ impl UpdateTimeEntryTool {
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct UpdateTimeEntryArgs {
     id: i32,
@@ -373,8 +598,12 @@ impl ToolExecutor for UpdateTimeEntryTool {
         "update_time_entry"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        TIME_ENTRY_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
-        "Aktualizuje existující časový záznam"
+        "Aktualizuje existující časový záznam - mění se pouze zadaná pole"
     }
     
     fn input_schema(&self) -> Value {
@@ -400,11 +629,11 @@ impl ToolExecutor for UpdateTimeEntryTool {
             },
             "issue_id": {
                 "type": "integer",
-                "description": "ID úkolu"
+                "description": "ID úkolu (projekt se doplní z úkolu)"
             },
             "project_id": {
                 "type": "integer",
-                "description": "ID projektu"
+                "description": "ID projektu (u záznamu vázaného na úkol musí odpovídat projektu úkolu)"
             },
             "comments": {
                 "type": "string",
@@ -413,17 +642,138 @@ impl ToolExecutor for UpdateTimeEntryTool {
         })
     }
     
-    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
-        // Zatím není implementováno v API klientovi
-        Ok(CallToolResult::error(vec![
-            ToolResult::text("update_time_entry zatím není implementováno".to_string())
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: UpdateTimeEntryArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        debug!("Aktualizuji časový záznam: {:?}", args);
+        
+        if args.hours.is_none() && args.activity_id.is_none() && args.spent_on.is_none()
+            && args.issue_id.is_none() && args.project_id.is_none() && args.comments.is_none() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().no_time_entry_changes.to_string())
+            ]));
+        }
+        
+        if let Some(hours) = args.hours {
+            if hours <= 0.0 || hours > 24.0 {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().invalid_hours.to_string())
+                ]));
+            }
+        }
+        
+        let spent_on = match args.spent_on.as_deref().map(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d")) {
+            Some(Ok(date)) => Some(date),
+            Some(Err(_)) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text((i18n::messages().invalid_date)("spent_on", &args.spent_on.unwrap_or_default()))
+                ]));
+            }
+            None => None,
+        };
+        
+        // Aktuální stav záznamu - ověří existenci a umožní kontrolu vazby na úkol
+        let current = match self.api_client.get_time_entry(args.id).await {
+            Ok(response) => response.time_entry,
+            Err(e) => {
+                error!("Chyba při získávání časového záznamu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_time_entry)(args.id), e))
+                ]));
+            }
+        };
+        
+        // Změna projektu u záznamu vázaného na úkol musí odpovídat projektu úkolu
+        let issue_id = args.issue_id.or(current.issue.as_ref().map(|issue| issue.id));
+        let project_id = if args.issue_id.is_some() || args.project_id.is_some() {
+            match resolve_entry_project(&self.api_client, issue_id, args.project_id).await {
+                Ok(project_id) => project_id.filter(|project_id| *project_id != current.project.id),
+                Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+            }
+        } else {
+            None
+        };
+        
+        let update = UpdateTimeEntryRequest {
+            time_entry: UpdateTimeEntry {
+                issue_id: args.issue_id.map(Some),
+                project_id,
+                spent_on,
+                hours: args.hours,
+                activity_id: args.activity_id,
+                comments: args.comments,
+            }
+        };
+        
+        if let Err(e) = self.api_client.update_time_entry(args.id, update).await {
+            error!("Chyba při aktualizaci časového záznamu {}: {}", args.id, e);
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(format!("{}: {}", (i18n::messages().error_updating_time_entry)(args.id), e))
+            ]).with_validation_errors(e.validation_errors()));
+        }
+        
+        info!("Úspěšně aktualizován časový záznam {}", args.id);
+        
+        // PUT vrací prázdnou odpověď - nový stav načteme (cache byla invalidována)
+        let updated = match self.api_client.get_time_entry(args.id).await {
+            Ok(response) => response.time_entry,
+            Err(e) => {
+                return Ok(CallToolResult::success(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().time_entry_updated_unreadable)(args.id), e))
+                ]));
+            }
+        };
+        
+        let messages = i18n::messages();
+        let [hours_label, date_label, activity_label, project_label, issue_label_text, comment_label] = messages.time_entry_change_labels;
+        let mut changes = Vec::new();
+        if current.hours != updated.hours {
+            changes.push(format!("{}: {} → {}", hours_label, current.hours, updated.hours));
+        }
+        if current.spent_on != updated.spent_on {
+            changes.push(format!("{}: {} → {}", date_label, current.spent_on, updated.spent_on));
+        }
+        if current.activity.id != updated.activity.id {
+            changes.push(format!("{}: {} → {}", activity_label, current.activity.name, updated.activity.name));
+        }
+        if current.project.id != updated.project.id {
+            changes.push(format!("{}: {} → {}", project_label, current.project.name, updated.project.name));
+        }
+        let issue_label = |entry: &TimeEntry| entry.issue.as_ref()
+            .map(|issue| format!("#{}", issue.id))
+            .unwrap_or_else(|| "-".to_string());
+        if issue_label(&current) != issue_label(&updated) {
+            changes.push(format!("{}: {} → {}", issue_label_text, issue_label(&current), issue_label(&updated)));
+        }
+        if current.comments != updated.comments {
+            changes.push(format!(
+                "{}: '{}' → '{}'",
+                comment_label,
+                current.comments.as_deref().unwrap_or(""),
+                updated.comments.as_deref().unwrap_or("")
+            ));
+        }
+        
+        let summary = if changes.is_empty() {
+            messages.no_value_changes.to_string()
+        } else {
+            changes.join(", ")
+        };
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text((messages.time_entry_updated)(args.id, &summary))
         ]))
     }
 }
 
 // === DELETE TIME ENTRY TOOL ===
 
-#[allow(dead_code)]
 pub struct DeleteTimeEntryTool {
     api_client: EasyProjectClient,
     _config: crate::config::AppConfig,
@@ -435,7 +785,6 @@ impl DeleteTimeEntryTool {
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Deserialize)]
 struct DeleteTimeEntryArgs {
     id: i32,
@@ -447,6 +796,10 @@ impl ToolExecutor for DeleteTimeEntryTool {
         "delete_time_entry"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        TIME_ENTRY_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Smaže časový záznam"
     }
@@ -460,11 +813,331 @@ impl ToolExecutor for DeleteTimeEntryTool {
         })
     }
     
-    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
-        // Zatím není implementováno v API klientovi
-        Ok(CallToolResult::error(vec![
-            ToolResult::text("delete_time_entry zatím není implementováno".to_string())
-        ]))
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: DeleteTimeEntryArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_parameter)("id"))?
+        )?;
+        
+        debug!("Mažu časový záznam {}", args.id);
+        
+        // Načtení před smazáním - ověří existenci a umožní potvrdit, co bylo smazáno
+        let entry = match self.api_client.get_time_entry(args.id).await {
+            Ok(response) => response.time_entry,
+            Err(e) => {
+                error!("Chyba při získávání časového záznamu {}: {}", args.id, e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_time_entry)(args.id), e))
+                ]));
+            }
+        };
+        
+        match self.api_client.delete_time_entry(args.id).await {
+            Ok(()) => {
+                info!("Úspěšně smazán časový záznam {}", args.id);
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text((i18n::messages().time_entry_deleted)(
+                        entry.id,
+                        entry.hours,
+                        &entry.spent_on.to_string(),
+                        &entry.user.name,
+                        &entry.project.name,
+                        entry.issue.as_ref().map(|issue| issue.id)
+                    ))
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při mazání časového záznamu {}: {}", args.id, e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_deleting_time_entry)(args.id), e))
+                ]))
+            }
+        }
+    }
+}
+
+// === MOVE TIME ENTRIES TOOL ===
+
+pub struct MoveTimeEntriesTool {
+    api_client: EasyProjectClient,
+}
+
+impl MoveTimeEntriesTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct MoveTimeEntriesArgs {
+    #[serde(default)]
+    time_entry_ids: Option<Vec<i32>>,
+    #[serde(default)]
+    from_issue_id: Option<i32>,
+    #[serde(default)]
+    from_project_id: Option<i32>,
+    #[serde(default)]
+    user_id: Option<i32>,
+    #[serde(default)]
+    from_date: Option<String>,
+    #[serde(default)]
+    to_date: Option<String>,
+    #[serde(default)]
+    to_issue_id: Option<i32>,
+    #[serde(default)]
+    to_project_id: Option<i32>,
+    #[serde(default)]
+    dry_run: bool,
+    #[serde(default = "default_move_max_entries")]
+    max_entries: usize,
+}
+
+fn default_move_max_entries() -> usize {
+    100
+}
+
+#[async_trait]
+impl ToolExecutor for MoveTimeEntriesTool {
+    fn name(&self) -> &str {
+        "move_time_entries"
+    }
+    
+    fn description(&self) -> &str {
+        "Přesune vybrané časové záznamy na jiný úkol nebo projekt (např. čas zalogovaný na špatný ticket). \
+        Záznamy lze vybrat výčtem ID nebo filtrem (zdrojový úkol/projekt, uživatel, období). \
+        S dry_run=true pouze zobrazí, co by se přesunulo."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "time_entry_ids": {
+                "type": "array",
+                "description": "ID konkrétních časových záznamů k přesunu",
+                "items": {
+                    "type": "integer"
+                }
+            },
+            "from_issue_id": {
+                "type": "integer",
+                "description": "Přesunout záznamy z tohoto úkolu"
+            },
+            "from_project_id": {
+                "type": "integer",
+                "description": "Přesunout záznamy z tohoto projektu"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "Pouze záznamy tohoto uživatele"
+            },
+            "from_date": {
+                "type": "string",
+                "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
+                "description": "Pouze záznamy od data (formát: YYYY-MM-DD)"
+            },
+            "to_date": {
+                "type": "string",
+                "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
+                "description": "Pouze záznamy do data (formát: YYYY-MM-DD)"
+            },
+            "to_issue_id": {
+                "type": "integer",
+                "description": "Cílový úkol (projekt se převezme z úkolu)"
+            },
+            "to_project_id": {
+                "type": "integer",
+                "description": "Cílový projekt (záznamy budou odpojeny od úkolu)"
+            },
+            "dry_run": {
+                "type": "boolean",
+                "description": "Pouze zobrazit záznamy, které by byly přesunuty (výchozí: false)",
+                "default": false
+            },
+            "max_entries": {
+                "type": "integer",
+                "description": "Maximální počet přesouvaných záznamů (výchozí: 100)",
+                "minimum": 1,
+                "maximum": 500,
+                "default": 100
+            }
+        })
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: MoveTimeEntriesArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        // Validace cíle a výběru
+        let target_issue_id = args.to_issue_id;
+        let target_project_id = match (args.to_issue_id, args.to_project_id) {
+            (Some(_), Some(_)) | (None, None) => {
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(i18n::messages().move_target_required.to_string())
+                ]));
+            }
+            (Some(issue_id), None) => match self.api_client.get_issue(issue_id, None).await {
+                Ok(response) => response.issue.project.id,
+                Err(e) => {
+                    error!("Cílový úkol {} nelze načíst: {}", issue_id, e);
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", (i18n::messages().move_target_issue_unavailable)(issue_id), e))
+                    ]));
+                }
+            },
+            (None, Some(project_id)) => project_id,
+        };
+        
+        if args.time_entry_ids.is_none() && args.from_issue_id.is_none() && args.from_project_id.is_none() {
+            return Ok(CallToolResult::error(vec![
+                ToolResult::text(i18n::messages().move_source_required.to_string())
+            ]));
+        }
+        
+        for (name, value) in [("from_date", &args.from_date), ("to_date", &args.to_date)] {
+            if let Some(date) = value {
+                if NaiveDate::parse_from_str(date, "%Y-%m-%d").is_err() {
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().invalid_date)(name, date))
+                    ]));
+                }
+            }
+        }
+        
+        // Výběr záznamů
+        let entries = match self.select_entries(&args).await {
+            Ok(entries) => entries,
+            Err(e) => {
+                error!("Chyba při výběru časových záznamů k přesunu: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_selecting_time_entries, e))
+                ]));
+            }
+        };
+        
+        let (to_move, already_there): (Vec<TimeEntry>, Vec<TimeEntry>) = entries.into_iter().partition(|entry| {
+            match target_issue_id {
+                Some(issue_id) => entry.issue.as_ref().map(|i| i.id) != Some(issue_id),
+                None => entry.project.id != target_project_id || entry.issue.is_some(),
+            }
+        });
+        
+        let messages = i18n::messages();
+        let target_label = match target_issue_id {
+            Some(issue_id) => (messages.issue_target)(issue_id),
+            None => (messages.project_target)(&target_project_id.to_string()),
+        };
+        
+        let total_hours: f64 = to_move.iter().map(|e| e.hours).sum();
+        let mut result = format!(
+            "{}:\n\n",
+            (messages.time_entries_move_heading)(args.dry_run, to_move.len(), total_hours, &target_label)
+        );
+        
+        let mut moved = 0;
+        let mut failed = 0;
+        
+        for entry in &to_move {
+            let source = match entry.issue {
+                Some(ref issue) => (messages.issue_target)(issue.id),
+                None => (messages.project_target)(&entry.project.name),
+            };
+            let line = format!(
+                "#{} {} {} {:.2} h ({}), {}: {}",
+                entry.id, entry.spent_on, entry.user.name, entry.hours, entry.activity.name, messages.moved_from, source
+            );
+            
+            if args.dry_run {
+                result.push_str(&format!("- {}\n", line));
+                continue;
+            }
+            
+            let update = UpdateTimeEntryRequest {
+                time_entry: UpdateTimeEntry {
+                    issue_id: Some(target_issue_id),
+                    project_id: Some(target_project_id),
+                    ..Default::default()
+                }
+            };
+            
+            match self.api_client.update_time_entry(entry.id, update).await {
+                Ok(()) => {
+                    moved += 1;
+                    result.push_str(&format!("- ✅ {}\n", line));
+                }
+                Err(e) => {
+                    failed += 1;
+                    error!("Chyba při přesunu časového záznamu {}: {}", entry.id, e);
+                    result.push_str(&format!("- ❌ {} - {}: {}\n", line, messages.error.to_lowercase(), e));
+                }
+            }
+        }
+        
+        if !already_there.is_empty() {
+            result.push_str(&format!("\n{}\n", (messages.entries_already_on_target)(already_there.len())));
+        }
+        
+        if args.dry_run {
+            result.push_str(&format!("\n{}\n", messages.dry_run_no_changes));
+            return Ok(CallToolResult::success(vec![ToolResult::text(result)]));
+        }
+        
+        result.push_str(&format!("\n{}\n", (messages.moved_summary)(moved, failed)));
+        info!("Přesunuto {} časových záznamů na {} ({} chyb)", moved, target_label, failed);
+        
+        if failed > 0 && moved == 0 {
+            Ok(CallToolResult::error(vec![ToolResult::text(result)]))
+        } else {
+            Ok(CallToolResult::success(vec![ToolResult::text(result)]))
+        }
+    }
+}
+
+impl MoveTimeEntriesTool {
+    /// Načte záznamy podle výčtu ID nebo podle filtrů (stránkovaně, do max_entries)
+    async fn select_entries(&self, args: &MoveTimeEntriesArgs) -> Result<Vec<TimeEntry>, crate::api::ApiError> {
+        let mut entries = Vec::new();
+        
+        if let Some(ref ids) = args.time_entry_ids {
+            for id in ids.iter().take(args.max_entries) {
+                entries.push(self.api_client.get_time_entry(*id).await?.time_entry);
+            }
+            return Ok(entries);
+        }
+        
+        let page_size: u32 = 100;
+        let mut offset: u32 = 0;
+        
+        loop {
+            let response = self.api_client.list_time_entries(TimeEntryListFilter {
+                project_id: args.from_project_id,
+                issue_id: args.from_issue_id,
+                user_id: args.user_id,
+                limit: Some(page_size),
+                offset: Some(offset),
+                from_date: args.from_date.clone(),
+                to_date: args.to_date.clone(),
+                include_subprojects: None,
+            }).await?;
+            
+            if response.time_entries.is_empty() {
+                break;
+            }
+            
+            let total = response.total_count.unwrap_or(0).max(0) as usize;
+            entries.extend(response.time_entries);
+            offset += page_size;
+            
+            if entries.len() >= args.max_entries || offset as usize >= total {
+                break;
+            }
+        }
+        
+        entries.truncate(args.max_entries);
+        Ok(entries)
     }
 }
 
@@ -472,12 +1145,12 @@ impl ToolExecutor for DeleteTimeEntryTool {
 
 pub struct LogTimeTool {
     api_client: EasyProjectClient,
-    _config: crate::config::AppConfig,
+    config: crate::config::AppConfig,
 }
 
 impl LogTimeTool {
-    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
-        Self { api_client, _config }
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self { api_client, config }
     }
 }
 
@@ -493,6 +1166,12 @@ struct LogTimeArgs {
     comments: Option<String>,
     #[serde(default)]
     date: Option<String>,
+    #[serde(default)]
+    user_id: Option<i32>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    allow_duplicate: bool,
 }
 
 #[async_trait]
@@ -523,7 +1202,7 @@ impl ToolExecutor for LogTimeTool {
             },
             "project_id": {
                 "type": "integer",
-                "description": "ID projektu (alternativně k issue_id)"
+                "description": "ID projektu (alternativně k issue_id, při zadaném úkolu se doplní z úkolu a musí se shodovat)"
             },
             "comments": {
                 "type": "string",
@@ -533,10 +1212,27 @@ impl ToolExecutor for LogTimeTool {
                 "type": "string",
                 "pattern": "^\\d{4}-\\d{2}-\\d{2}$",
                 "description": "Datum práce (formát: YYYY-MM-DD, výchozí: dnes)"
+            },
+            "user_id": {
+                "type": "integer",
+                "description": "ID uživatele, za kterého se čas loguje (výchozí: aktuální uživatel; vyžaduje oprávnění logovat čas za ostatní)"
+            },
+            "user": {
+                "type": "string",
+                "description": "Uživatel zadaný jménem, loginem nebo emailem (alternativa k user_id)"
+            },
+            "allow_duplicate": {
+                "type": "boolean",
+                "description": "Vytvořit záznam i tehdy, když ve stejný den na stejném úkolu existuje záznam s podobnými hodinami (výchozí: false)",
+                "default": false
             }
         })
     }
     
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["hours", "activity_id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: LogTimeArgs = match arguments {
             Some(args) => {
@@ -546,7 +1242,7 @@ impl ToolExecutor for LogTimeTool {
                     Err(e) => {
                         error!("Chyba při parsování argumentů: {}", e);
                         return Ok(CallToolResult::error(vec![
-                            ToolResult::text(format!("Chyba při parsování argumentů: {}", e))
+                            ToolResult::text(format!("{}: {}", i18n::messages().error_parsing_arguments, e))
                         ]));
                     }
                 }
@@ -554,7 +1250,7 @@ impl ToolExecutor for LogTimeTool {
             None => {
                 error!("Chybí povinné parametry pro log_time");
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text("Chybí povinné parametry pro log_time".to_string())
+                    ToolResult::text(i18n::messages().missing_log_time_arguments.to_string())
                 ]));
             }
         };
@@ -564,7 +1260,7 @@ impl ToolExecutor for LogTimeTool {
         // Validace hodin
         if args.hours <= 0.0 || args.hours > 24.0 {
             return Ok(CallToolResult::error(vec![
-                ToolResult::text("Počet hodin musí být mezi 0.01 a 24.0".to_string())
+                ToolResult::text(i18n::messages().invalid_hours.to_string())
             ]));
         }
         
@@ -574,7 +1270,7 @@ impl ToolExecutor for LogTimeTool {
                 Ok(date) => date,
                 Err(_) => {
                     return Ok(CallToolResult::error(vec![
-                        ToolResult::text(format!("Neplatný formát data: {}. Očekávaný formát: YYYY-MM-DD", date_str))
+                        ToolResult::text((i18n::messages().invalid_date)("date", &date_str))
                     ]));
                 }
             }
@@ -584,17 +1280,47 @@ impl ToolExecutor for LogTimeTool {
         
         if args.issue_id.is_none() && args.project_id.is_none() {
             return Ok(CallToolResult::error(vec![
-                ToolResult::text("Musí být zadán alespoň jeden z parametrů 'issue_id' nebo 'project_id'".to_string())
+                ToolResult::text(i18n::messages().issue_or_project_required.to_string())
             ]));
         }
         
+        let project_id = match resolve_entry_project(&self.api_client, args.issue_id, args.project_id).await {
+            Ok(project_id) => project_id,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        
+        let user_id = match (args.user_id, args.user.as_deref()) {
+            (Some(user_id), _) => Some(user_id),
+            (None, Some(user)) => match self.api_client.resolve_user(user).await {
+                Ok(user) => Some(user.id),
+                Err(e) => {
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", (i18n::messages().user_not_resolved)(user), e))
+                    ]));
+                }
+            },
+            (None, None) => None,
+        };
+        
+        if self.config.tools.time_entries.duplicate_check && !args.allow_duplicate {
+            match find_duplicate_entries(&self.api_client, user_id, args.issue_id, project_id, spent_on, args.hours, args.comments.as_deref()).await {
+                Ok(duplicates) if !duplicates.is_empty() => {
+                    info!("Odmítnut pravděpodobně duplicitní časový záznam ({} existujících)", duplicates.len());
+                    return Ok(duplicate_entries_error(&duplicates));
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Kontrolu duplicitních časových záznamů nelze provést: {}", e),
+            }
+        }
+        
         let time_entry = CreateTimeEntry {
             issue_id: args.issue_id,
-            project_id: args.project_id,
+            project_id,
             spent_on,
             hours: args.hours,
             activity_id: args.activity_id,
             comments: args.comments,
+            user_id,
         };
         
         let request = CreateTimeEntryRequest { time_entry };
@@ -606,10 +1332,9 @@ impl ToolExecutor for LogTimeTool {
                 info!("Úspěšně zalogován čas: {} hodin", response.time_entry.hours);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "✅ Čas úspěšně zalogován: {} hodin na {} (ID: {})",
+                    ToolResult::text((i18n::messages().time_logged)(
                         response.time_entry.hours,
-                        response.time_entry.spent_on,
+                        &response.time_entry.spent_on.to_string(),
                         response.time_entry.id
                     ))
                 ]))
@@ -617,9 +1342,332 @@ impl ToolExecutor for LogTimeTool {
             Err(e) => {
                 error!("Chyba při logování času: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při logování času: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_logging_time, e))
                 ]))
             }
         }
     }
-} 
\ No newline at end of file
+} 
+// === LOG TIME BULK TOOL ===
+
+/// Nejvyšší počet záznamů v jednom volání log_time_bulk
+const MAX_BULK_TIME_ENTRIES: usize = 100;
+
+/// Počet záznamů vytvářených současně (rychlost dále omezuje rate limiter klienta)
+const BULK_CONCURRENCY: usize = 4;
+
+pub struct LogTimeBulkTool {
+    api_client: EasyProjectClient,
+    config: crate::config::AppConfig,
+}
+
+impl LogTimeBulkTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        Self { api_client, config }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct BulkTimeEntry {
+    date: NaiveDate,
+    hours: f64,
+    #[serde(default)]
+    issue_id: Option<i32>,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    activity_id: Option<i32>,
+    #[serde(default)]
+    comments: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LogTimeBulkArgs {
+    #[serde(default)]
+    entries: Vec<BulkTimeEntry>,
+    #[serde(default)]
+    from_date: Option<NaiveDate>,
+    #[serde(default)]
+    to_date: Option<NaiveDate>,
+    #[serde(default)]
+    hours_per_day: Option<f64>,
+    #[serde(default)]
+    issue_id: Option<i32>,
+    #[serde(default)]
+    project_id: Option<i32>,
+    #[serde(default)]
+    activity_id: Option<i32>,
+    #[serde(default)]
+    comments: Option<String>,
+    #[serde(default)]
+    allow_duplicate: bool,
+}
+
+impl LogTimeBulkArgs {
+    /// Záznamy k vytvoření - zadaný seznam, nebo pracovní dny období s hours_per_day
+    ///
+    /// Společné issue_id/project_id/activity_id/comments doplňují položky, které je nemají.
+    fn planned_entries(&self) -> Result<Vec<BulkTimeEntry>, String> {
+        let messages = i18n::messages();
+        let mut entries = self.entries.clone();
+        
+        match (self.from_date, self.to_date) {
+            (Some(from), Some(to)) => {
+                let hours = self.hours_per_day
+                    .ok_or(messages.bulk_hours_per_day_required)?;
+                if to < from {
+                    return Err(messages.to_date_before_from_date.to_string());
+                }
+                entries.extend(from.iter_days()
+                    .take_while(|date| *date <= to)
+                    .filter(|date| is_business_day(*date))
+                    .map(|date| BulkTimeEntry {
+                        date,
+                        hours,
+                        issue_id: None,
+                        project_id: None,
+                        activity_id: None,
+                        comments: None,
+                    }));
+            }
+            (None, None) => {}
+            _ => return Err(messages.bulk_period_incomplete.to_string()),
+        }
+        
+        if entries.is_empty() {
+            return Err(messages.bulk_entries_missing.to_string());
+        }
+        if entries.len() > MAX_BULK_TIME_ENTRIES {
+            return Err((messages.bulk_too_many_entries)(MAX_BULK_TIME_ENTRIES, entries.len()));
+        }
+        
+        for (index, entry) in entries.iter_mut().enumerate() {
+            if entry.issue_id.is_none() && entry.project_id.is_none() {
+                entry.issue_id = self.issue_id;
+                entry.project_id = self.project_id;
+            }
+            entry.activity_id = entry.activity_id.or(self.activity_id);
+            entry.comments = entry.comments.clone().or(self.comments.clone());
+            
+            if entry.issue_id.is_none() && entry.project_id.is_none() {
+                return Err((messages.bulk_entry_invalid)(index + 1, &entry.date.to_string(), messages.bulk_entry_missing_target));
+            }
+            if entry.activity_id.is_none() {
+                return Err((messages.bulk_entry_invalid)(index + 1, &entry.date.to_string(), messages.bulk_entry_missing_activity));
+            }
+            if entry.hours <= 0.0 || entry.hours > 24.0 {
+                return Err((messages.bulk_entry_invalid)(index + 1, &entry.date.to_string(), messages.bulk_entry_invalid_hours));
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Vytvoří jeden záznam hromadného logování včetně kontroly projektu a duplicit
+async fn create_bulk_entry(api_client: EasyProjectClient, entry: BulkTimeEntry, duplicate_check: bool) -> Result<TimeEntry, String> {
+    let project_id = resolve_entry_project(&api_client, entry.issue_id, entry.project_id).await?;
+    
+    if duplicate_check {
+        match find_duplicate_entries(&api_client, None, entry.issue_id, project_id, entry.date, entry.hours, entry.comments.as_deref()).await {
+            Ok(duplicates) if !duplicates.is_empty() => {
+                return Err((i18n::messages().probable_duplicate)(duplicates[0].id));
+            }
+            Ok(_) => {}
+            Err(e) => warn!("Kontrolu duplicitních časových záznamů nelze provést: {}", e),
+        }
+    }
+    
+    let request = CreateTimeEntryRequest {
+        time_entry: CreateTimeEntry {
+            issue_id: entry.issue_id,
+            project_id,
+            spent_on: entry.date,
+            hours: entry.hours,
+            activity_id: entry.activity_id.unwrap_or_default(),
+            comments: entry.comments,
+            user_id: None,
+        }
+    };
+    
+    api_client.create_time_entry(request).await
+        .map(|response| response.time_entry)
+        .map_err(|e| e.to_string())
+}
+
+#[async_trait]
+impl ToolExecutor for LogTimeBulkTool {
+    fn name(&self) -> &str {
+        "log_time_bulk"
+    }
+    
+    fn description(&self) -> &str {
+        "Hromadně zaloguje čas - seznam záznamů (entries) nebo stejný počet hodin na každý pracovní den období. \
+        Záznamy se vytváří souběžně a výsledek uvádí chyby u jednotlivých záznamů; úspěšné záznamy zůstanou vytvořené."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "entries": {
+                "type": "array",
+                "description": "Záznamy k vytvoření (nejvýše 100)",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "date": {
+                            "type": "string",
+                            "format": "date",
+                            "description": "Datum práce (YYYY-MM-DD)"
+                        },
+                        "hours": {
+                            "type": "number",
+                            "description": "Počet odpracovaných hodin",
+                            "minimum": 0.01,
+                            "maximum": 24.0
+                        },
+                        "issue_id": {
+                            "type": "integer",
+                            "description": "ID úkolu (alternativně k project_id)"
+                        },
+                        "project_id": {
+                            "type": "integer",
+                            "description": "ID projektu (alternativně k issue_id)"
+                        },
+                        "activity_id": {
+                            "type": "integer",
+                            "description": "ID aktivity (výchozí: společné activity_id)"
+                        },
+                        "comments": {
+                            "type": "string",
+                            "description": "Komentář k časovému záznamu"
+                        }
+                    },
+                    "required": ["date", "hours"]
+                }
+            },
+            "from_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Začátek období - záznam na každý pracovní den (YYYY-MM-DD)"
+            },
+            "to_date": {
+                "type": "string",
+                "format": "date",
+                "description": "Konec období včetně (YYYY-MM-DD)"
+            },
+            "hours_per_day": {
+                "type": "number",
+                "description": "Hodiny na každý pracovní den období",
+                "minimum": 0.01,
+                "maximum": 24.0
+            },
+            "issue_id": {
+                "type": "integer",
+                "description": "Společné ID úkolu pro záznamy bez issue_id/project_id"
+            },
+            "project_id": {
+                "type": "integer",
+                "description": "Společné ID projektu pro záznamy bez issue_id/project_id"
+            },
+            "activity_id": {
+                "type": "integer",
+                "description": "Společné ID aktivity pro záznamy bez activity_id"
+            },
+            "comments": {
+                "type": "string",
+                "description": "Společný komentář pro záznamy bez komentáře"
+            },
+            "allow_duplicate": {
+                "type": "boolean",
+                "description": "Vytvořit záznamy i tehdy, když ve stejný den na stejném úkolu existuje záznam s podobnými hodinami (výchozí: false)",
+                "default": false
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::TimeEntries)
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: LogTimeBulkArgs = serde_json::from_value(
+            arguments.ok_or_else(|| (i18n::messages().missing_tool_arguments)(self.name()))?
+        )?;
+        
+        let entries = match args.planned_entries() {
+            Ok(entries) => entries,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+        let duplicate_check = self.config.tools.time_entries.duplicate_check && !args.allow_duplicate;
+        
+        debug!("Hromadně loguji {} časových záznamů", entries.len());
+        
+        // Souběžně nejvýše BULK_CONCURRENCY záznamů, výsledky v pořadí zadání
+        let mut results: Vec<Option<Result<TimeEntry, String>>> = vec![None; entries.len()];
+        for (chunk_index, chunk) in entries.chunks(BULK_CONCURRENCY).enumerate() {
+            let mut tasks = tokio::task::JoinSet::new();
+            for (offset, entry) in chunk.iter().enumerate() {
+                let index = chunk_index * BULK_CONCURRENCY + offset;
+                let api_client = self.api_client.clone();
+                let entry = entry.clone();
+                tasks.spawn(async move { (index, create_bulk_entry(api_client, entry, duplicate_check).await) });
+            }
+            while let Some(joined) = tasks.join_next().await {
+                match joined {
+                    Ok((index, result)) => results[index] = Some(result),
+                    Err(e) => error!("Úloha hromadného logování selhala: {}", e),
+                }
+            }
+        }
+        
+        let mut created = Vec::new();
+        let mut failures = Vec::new();
+        for (entry, result) in entries.iter().zip(results) {
+            match result.unwrap_or_else(|| Err(i18n::messages().task_aborted.to_string())) {
+                Ok(time_entry) => created.push(time_entry),
+                Err(e) => failures.push(json!({
+                    "date": entry.date,
+                    "hours": entry.hours,
+                    "issue_id": entry.issue_id,
+                    "project_id": entry.project_id,
+                    "error": e
+                })),
+            }
+        }
+        let total_hours: f64 = created.iter().map(|entry| entry.hours).sum();
+        
+        info!("Hromadně zalogováno {} z {} záznamů ({} h)", created.len(), entries.len(), total_hours);
+        
+        let messages = i18n::messages();
+        let mut output = (messages.bulk_created_summary)(created.len(), entries.len(), total_hours);
+        if !failures.is_empty() {
+            output.push_str(&format!("\n\n{}:\n", messages.bulk_not_created));
+            for failure in &failures {
+                output.push_str(&format!(
+                    "- {} ({} h): {}\n",
+                    failure["date"].as_str().unwrap_or_default(),
+                    failure["hours"],
+                    failure["error"].as_str().unwrap_or_default()
+                ));
+            }
+        }
+        
+        let summary = json!({
+            "created": created.iter().map(|entry| json!({
+                "id": entry.id,
+                "spent_on": entry.spent_on,
+                "hours": entry.hours,
+                "issue_id": entry.issue.as_ref().map(|issue| issue.id),
+                "project_id": entry.project.id
+            })).collect::<Vec<_>>(),
+            "failed": failures,
+            "total_hours": total_hours
+        });
+        
+        let result = if created.is_empty() {
+            CallToolResult::error(vec![ToolResult::text(output)])
+        } else {
+            CallToolResult::success(vec![ToolResult::text(output)])
+        };
+        Ok(result.with_structured_content(&summary))
+    }
+}
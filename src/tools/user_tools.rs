@@ -1,12 +1,22 @@
 use async_trait::async_trait;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tracing::{debug, error, info};
+use chrono::Utc;
+use tracing::{debug, error, info, warn};
 
 use crate::api::EasyProjectClient;
-use crate::mcp::protocol::{CallToolResult, ToolResult};
+use crate::api::models::{Issue, UserTypeCategory};
+use crate::api::client::{IssueListFilter, TimeEntryListFilter, UserListFilter, FETCH_ALL};
+use crate::api::permissions::PermissionArea;
+use crate::i18n;
+use crate::mcp::protocol::{CallToolResult, ToolResult, PaginationInfo};
+use crate::utils::date_utils::{DateZone, parse_timezone};
+use crate::utils::formatting::{OutputFormat, USER_COLUMNS, columns_schema, format_user, format_user_list, markdown_table, select_columns};
 use super::executor::ToolExecutor;
 
+/// Aliasy parametru `id` pro tools pracující s jedním uživatelem
+const USER_ID_ALIASES: &[(&str, &str)] = &[("user_id", "id"), ("user", "id")];
+
 // === LIST USERS TOOL ===
 
 pub struct ListUsersTool {
@@ -31,6 +41,16 @@ struct ListUsersArgs {
     sort: Option<String>,
     #[serde(default)]
     status: Option<String>,
+    #[serde(default)]
+    user_type: Option<UserTypeCategory>,
+    #[serde(default)]
+    user_type_id: Option<i32>,
+    #[serde(default)]
+    fetch_all: bool,
+    #[serde(default)]
+    format: OutputFormat,
+    #[serde(default)]
+    columns: Option<Vec<String>>,
 }
 
 #[async_trait]
@@ -43,6 +63,7 @@ impl ToolExecutor for ListUsersTool {
         "Získá seznam všech uživatelů v EasyProject systému s možností fulltextového vyhledávání a filtrování. \
         \n\nPoužití: Pro vyhledání uživatelů podle jména nebo emailu použijte parametr 'search'. \
         Pro filtrování podle stavu použijte 'status' (např. 'active' pro aktivní uživatele). \
+        Pro filtrování podle typu uživatele použijte 'user_type' (internal/external/client) nebo 'user_type_id'. \
         \nPříklad: search='Jan Novák' najde všechny uživatele obsahující tento text ve jménu."
     }
 
@@ -50,15 +71,18 @@ impl ToolExecutor for ListUsersTool {
         json!({
             "limit": {
                 "type": "integer",
-                "description": "Maximální počet uživatelů k vrácení (výchozí: 25, maximum: 100)",
-                "minimum": 1,
-                "maximum": 100
+                "description": "Maximální počet uživatelů k vrácení (výchozí: 25; více než 100 se načte automaticky po stránkách)",
+                "minimum": 1
             },
             "offset": {
                 "type": "integer",
                 "description": "Počet uživatelů k přeskočení pro stránkování",
                 "minimum": 0
             },
+            "fetch_all": {
+                "type": "boolean",
+                "description": "Načte všechny uživatele od offsetu po stránkách, limit se ignoruje (nejvýše tools.fetch_all_max_records)"
+            },
             "search": {
                 "type": "string",
                 "description": "Fulltextové vyhledávání ve jménech a emailech uživatelů (např. 'Jan Novák' nebo 'jan@firma.cz')"
@@ -71,10 +95,29 @@ impl ToolExecutor for ListUsersTool {
                 "type": "string",
                 "description": "Filtrování podle stavu uživatele",
                 "enum": ["active", "locked", "registered"]
-            }
+            },
+            "user_type": {
+                "type": "string",
+                "description": "Filtrování podle kategorie typu uživatele",
+                "enum": ["internal", "external", "client"]
+            },
+            "user_type_id": {
+                "type": "integer",
+                "description": "Filtrování podle konkrétního typu uživatele (ID z list_user_types)"
+            },
+            "format": OutputFormat::schema(),
+            "columns": columns_schema(USER_COLUMNS)
         })
     }
 
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Users)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: ListUsersArgs = if let Some(args) = arguments {
             serde_json::from_value(args)?
@@ -85,29 +128,95 @@ impl ToolExecutor for ListUsersTool {
                 search: None,
                 sort: None,
                 status: None,
+                user_type: None,
+                user_type_id: None,
+                fetch_all: false,
+                format: OutputFormat::default(),
+                columns: None,
             }
         };
 
         debug!("Získávám seznam uživatelů s parametry: {:?}", args);
 
-        match self.api_client.list_users(args.limit, args.offset, args.search, None, args.sort, args.status).await {
+        let columns = match select_columns(USER_COLUMNS, args.columns.as_deref()) {
+            Ok(columns) => columns,
+            Err(e) => return Ok(CallToolResult::error(vec![ToolResult::text(e)])),
+        };
+
+        // Kategorie typu se převádí na konkrétní ID typů dané instance
+        let user_type_ids = match (args.user_type_id, args.user_type) {
+            (Some(id), _) => Some(vec![id]),
+            (None, Some(category)) => {
+                let types = match self.api_client.list_user_types().await {
+                    Ok(response) => response.easy_user_types,
+                    Err(e) => {
+                        error!("Chyba při získávání typů uživatelů: {}", e);
+                        return Ok(CallToolResult::error(vec![
+                            ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_user_types, e))
+                        ]));
+                    }
+                };
+                let ids: Vec<i32> = types.iter()
+                    .filter(|user_type| user_type.category() == Some(category))
+                    .map(|user_type| user_type.id)
+                    .collect();
+                if ids.is_empty() {
+                    return Ok(CallToolResult::error(vec![
+                        ToolResult::text((i18n::messages().no_user_type_in_category)(category.label()))
+                    ]));
+                }
+                Some(ids)
+            }
+            (None, None) => None,
+        };
+
+        let filter = UserListFilter {
+            limit: if args.fetch_all { Some(FETCH_ALL) } else { args.limit },
+            offset: args.offset,
+            easy_query_q: args.search.clone(),
+            sort: args.sort,
+            status: args.status,
+            easy_user_type_ids: user_type_ids,
+            ..Default::default()
+        };
+        let result = match self.api_client.list_users(filter.clone()).await {
+            // Fulltext rozlišuje diakritiku - zkusíme shodu bez ní
+            Ok(response) if response.users.is_empty() => match args.search.as_deref() {
+                Some(search) => self.api_client.match_users_by_text(search, filter).await,
+                None => Ok(response),
+            },
+            other => other,
+        };
+
+        match result {
             Ok(response) => {
-                let users_json = serde_json::to_string_pretty(&response)?;
+                let title = (i18n::messages().found_users_total)(
+                    response.users.len(),
+                    response.total_count.unwrap_or(response.users.len() as i32)
+                );
+                let output = args.format.render_list(
+                    &title,
+                    &response,
+                    || format_user_list(&response.users),
+                    || markdown_table(&response.users, &columns),
+                )?;
                 info!("Úspěšně získáno {} uživatelů", response.users.len());
                 
+                let pagination = PaginationInfo::new(
+                    response.total_count,
+                    response.offset.map(|o| o as u32).unwrap_or(args.offset.unwrap_or(0)),
+                    response.limit.map(|l| l as u32).unwrap_or(args.limit.unwrap_or(25)),
+                    response.users.len(),
+                ).with_requested_limit(args.limit);
+                
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Nalezeno {} uživatelů (celkem: {}):\n\n{}",
-                        response.users.len(),
-                        response.total_count.unwrap_or(response.users.len() as i32),
-                        users_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response).with_pagination(pagination))
             }
             Err(e) => {
                 error!("Chyba při získávání uživatelů: {}", e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání uživatelů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_users, e))
                 ]))
             }
         }
@@ -129,6 +238,8 @@ impl GetUserTool {
 #[derive(Debug, Deserialize)]
 struct GetUserArgs {
     id: i32,
+    #[serde(default)]
+    format: OutputFormat,
 }
 
 #[async_trait]
@@ -137,6 +248,10 @@ impl ToolExecutor for GetUserTool {
         "get_user"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        USER_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá detail konkrétního uživatele podle ID"
     }
@@ -146,37 +261,42 @@ impl ToolExecutor for GetUserTool {
             "id": {
                 "type": "integer",
                 "description": "ID uživatele"
-            }
+            },
+            "format": OutputFormat::schema()
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetUserArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Získávám uživatele s ID: {}", args.id);
         
         match self.api_client.get_user(args.id).await {
             Ok(response) => {
-                let user_json = serde_json::to_string_pretty(&response.user)?;
                 let firstname = response.user.firstname.as_deref().unwrap_or("N/A");
                 let lastname = response.user.lastname.as_deref().unwrap_or("N/A");
+                let title = (i18n::messages().user_detail)(firstname, lastname);
+                let output = args.format.render(&title, &response.user, || format_user(&response.user))?;
                 info!("Úspěšně získán uživatel: {} {}", firstname, lastname);
                 
                 Ok(CallToolResult::success(vec![
-                    ToolResult::text(format!(
-                        "Detail uživatele '{} {}':\n\n{}",
-                        firstname,
-                        lastname,
-                        user_json
-                    ))
-                ]))
+                    ToolResult::text(output)
+                ]).with_structured_content(&response.user))
             }
             Err(e) => {
                 error!("Chyba při získávání uživatele {}: {}", args.id, e);
                 Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání uživatele {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_user)(args.id), e))
                 ]))
             }
         }
@@ -210,6 +330,10 @@ impl ToolExecutor for GetUserWorkloadTool {
         "get_user_workload"
     }
     
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        USER_ID_ALIASES
+    }
+    
     fn description(&self) -> &str {
         "Získá pracovní vytížení uživatele - přehled přiřazených úkolů a odpracovaných hodin"
     }
@@ -233,9 +357,17 @@ impl ToolExecutor for GetUserWorkloadTool {
         })
     }
     
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    fn required_fields(&self) -> &'static [&'static str] {
+        &["id"]
+    }
+    
     async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
         let args: GetUserWorkloadArgs = serde_json::from_value(
-            arguments.ok_or("Chybí povinný parametr 'id'")?
+            arguments.ok_or(i18n::messages().missing_id)?
         )?;
         
         debug!("Získávám pracovní vytížení uživatele s ID: {}", args.id);
@@ -246,60 +378,47 @@ impl ToolExecutor for GetUserWorkloadTool {
             Err(e) => {
                 error!("Chyba při získávání uživatele {}: {}", args.id, e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání uživatele {}: {}", args.id, e))
+                    ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_user)(args.id), e))
                 ]));
             }
         };
         
-        // 2. Získáme přiřazené úkoly uživatele
-        let issues_response = match self.api_client.list_issues(None, Some(100), None, None, None, None, None, None, None, None, None).await {
+        // 2. Získáme přiřazené úkoly uživatele - filtr řešitele vyhodnotí API, načtou se všechny stránky
+        let issues_response = match self.api_client.list_issues(IssueListFilter {
+            limit: Some(FETCH_ALL),
+            set_filter: Some(true),
+            assigned_to_id: Some(args.id),
+            ..Default::default()
+        }).await {
             Ok(response) => response,
             Err(e) => {
                 error!("Chyba při získávání úkolů: {}", e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání úkolů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
                 ]));
             }
         };
+        let issues_total = issues_response.total_count.unwrap_or(issues_response.issues.len() as i32);
+        let assigned_issues = issues_response.issues;
         
-        // Filtrujeme pouze úkoly přiřazené tomuto uživateli
-        let assigned_issues: Vec<_> = issues_response.issues.into_iter()
-            .filter(|issue| {
-                issue.assigned_to.as_ref().map(|u| u.id) == Some(args.id)
-            })
-            .collect();
-        
-        // 3. Získáme časové záznamy uživatele
-        let time_entries_response = match self.api_client.list_time_entries(None, None, Some(args.id), Some(100), None, args.from_date.clone(), args.to_date.clone()).await {
+        // 3. Získáme časové záznamy uživatele za období (filtr uživatele i data vyhodnotí API)
+        let time_entries_response = match self.api_client.list_time_entries(TimeEntryListFilter {
+            user_id: Some(args.id),
+            limit: Some(FETCH_ALL),
+            from_date: args.from_date.clone(),
+            to_date: args.to_date.clone(),
+            ..Default::default()
+        }).await {
             Ok(response) => response,
             Err(e) => {
                 error!("Chyba při získávání časových záznamů: {}", e);
                 return Ok(CallToolResult::error(vec![
-                    ToolResult::text(format!("Chyba při získávání časových záznamů: {}", e))
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_time_entries, e))
                 ]));
             }
         };
-        
-        // Filtrujeme časové záznamy podle data pokud je zadáno
-        let filtered_time_entries: Vec<_> = if args.from_date.is_some() || args.to_date.is_some() {
-            time_entries_response.time_entries.into_iter()
-                .filter(|entry| {
-                    let entry_date = entry.spent_on.format("%Y-%m-%d").to_string();
-                    
-                    let after_from = args.from_date.as_ref()
-                        .map(|from| entry_date >= *from)
-                        .unwrap_or(true);
-                        
-                    let before_to = args.to_date.as_ref()
-                        .map(|to| entry_date <= *to)
-                        .unwrap_or(true);
-                        
-                    after_from && before_to
-                })
-                .collect()
-        } else {
-            time_entries_response.time_entries
-        };
+        let time_entries_total = time_entries_response.total_count.unwrap_or(time_entries_response.time_entries.len() as i32);
+        let filtered_time_entries = time_entries_response.time_entries;
         
         // 4. Spočítáme statistiky
         let total_assigned_issues = assigned_issues.len();
@@ -324,6 +443,10 @@ impl ToolExecutor for GetUserWorkloadTool {
             .filter_map(|issue| issue.estimated_hours)
             .sum();
         
+        // Více záznamů než fetch_all_max_records - statistiky jsou jen z načtené části
+        let truncated = (assigned_issues.len() as i32) < issues_total
+            || (filtered_time_entries.len() as i32) < time_entries_total;
+        
         // 5. Sestavíme response
         let firstname = user_response.user.firstname.as_deref().unwrap_or("N/A");
         let lastname = user_response.user.lastname.as_deref().unwrap_or("N/A");
@@ -347,7 +470,8 @@ impl ToolExecutor for GetUserWorkloadTool {
                 "time_period": {
                     "from": args.from_date,
                     "to": args.to_date
-                }
+                },
+                "truncated": truncated
             },
             "assigned_issues": assigned_issues,
             "time_entries": filtered_time_entries
@@ -361,12 +485,393 @@ impl ToolExecutor for GetUserWorkloadTool {
         
         Ok(CallToolResult::success(vec![
             ToolResult::text(format!(
-                "Pracovní vytížení uživatele '{}' ({} {}):\n\n{}",
-                user_response.user.mail.unwrap_or_else(|| "N/A".to_string()),
-                firstname,
-                lastname,
+                "{}:\n\n{}",
+                (i18n::messages().user_workload)(
+                    user_response.user.mail.as_deref().unwrap_or("N/A"),
+                    firstname,
+                    lastname
+                ),
                 workload_json
             ))
         ]))
     }
-} 
\ No newline at end of file
+} 
+
+// === GET MY WORK TOOL ===
+
+pub struct GetMyWorkTool {
+    api_client: EasyProjectClient,
+    timezone: DateZone,
+}
+
+impl GetMyWorkTool {
+    pub fn new(api_client: EasyProjectClient, config: crate::config::AppConfig) -> Self {
+        let timezone = parse_timezone(&config.tools.reports.timezone).unwrap_or_else(|e| {
+            warn!("{} - používám lokální časové pásmo", e);
+            DateZone::Local
+        });
+        Self { api_client, timezone }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct GetMyWorkArgs {
+    #[serde(default = "default_days_ahead")]
+    days_ahead: i64,
+}
+
+fn default_days_ahead() -> i64 {
+    7
+}
+
+#[async_trait]
+impl ToolExecutor for GetMyWorkTool {
+    fn name(&self) -> &str {
+        "get_my_work"
+    }
+    
+    fn description(&self) -> &str {
+        "Přehled práce aktuálního uživatele (vlastníka API klíče) - kdo jsem, otevřené přiřazené úkoly, \
+        dnes odpracované hodiny a blížící se termíny. Není potřeba znát vlastní ID uživatele."
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "days_ahead": {
+                "type": "integer",
+                "description": "Počet dní dopředu pro blížící se termíny (výchozí: 7)",
+                "minimum": 0,
+                "maximum": 90,
+                "default": 7
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Issues)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: GetMyWorkArgs = serde_json::from_value(arguments.unwrap_or_else(|| json!({})))?;
+        let days_ahead = args.days_ahead.clamp(0, 90);
+        
+        // 1. Kdo jsem (GET /users/current.json)
+        let user = match self.api_client.get_current_user().await {
+            Ok(response) => response.user,
+            Err(e) => {
+                error!("Chyba při získávání aktuálního uživatele: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_current_user, e))
+                ]));
+            }
+        };
+        let name = format!(
+            "{} {}",
+            user.firstname.as_deref().unwrap_or(""),
+            user.lastname.as_deref().unwrap_or("")
+        ).trim().to_string();
+        
+        debug!("Získávám přehled práce uživatele {} (ID {})", name, user.id);
+        
+        // 2. Otevřené přiřazené úkoly - bez status_id vrací API jen otevřené
+        let issues_response = match self.api_client.list_issues(IssueListFilter {
+            limit: Some(FETCH_ALL),
+            set_filter: Some(true),
+            sort: Some("due_date".to_string()),
+            assigned_to_id: Some(user.id),
+            ..Default::default()
+        }).await {
+            Ok(response) => response,
+            Err(e) => {
+                error!("Chyba při získávání úkolů: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_issues, e))
+                ]));
+            }
+        };
+        let issues_total = issues_response.total_count.unwrap_or(issues_response.issues.len() as i32);
+        let issues = issues_response.issues;
+        
+        // 3. Dnes odpracované hodiny
+        let today = self.timezone.date_at(Utc::now());
+        let day = today.format("%Y-%m-%d").to_string();
+        let time_entries = match self.api_client.list_time_entries(TimeEntryListFilter {
+            user_id: Some(user.id),
+            limit: Some(FETCH_ALL),
+            from_date: Some(day.clone()),
+            to_date: Some(day),
+            ..Default::default()
+        }).await {
+            Ok(response) => response.time_entries,
+            Err(e) => {
+                error!("Chyba při získávání časových záznamů: {}", e);
+                return Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_time_entries, e))
+                ]));
+            }
+        };
+        let hours_today: f64 = time_entries.iter().map(|entry| entry.hours).sum();
+        
+        // 4. Termíny - po splatnosti a v nejbližších days_ahead dnech
+        let horizon = today + chrono::Duration::days(days_ahead);
+        let mut overdue: Vec<&Issue> = issues.iter()
+            .filter(|issue| issue.due_date.is_some_and(|due| due < today))
+            .collect();
+        let mut upcoming: Vec<&Issue> = issues.iter()
+            .filter(|issue| issue.due_date.is_some_and(|due| due >= today && due <= horizon))
+            .collect();
+        overdue.sort_by_key(|issue| (issue.due_date, issue.id));
+        upcoming.sort_by_key(|issue| (issue.due_date, issue.id));
+        
+        let compact = |issue: &Issue| json!({
+            "id": issue.id,
+            "subject": issue.subject,
+            "project": issue.project.name,
+            "status": issue.status.name,
+            "priority": issue.priority.name,
+            "due_date": issue.due_date,
+            "done_ratio": issue.done_ratio
+        });
+        
+        let messages = i18n::messages();
+        let mut output = (messages.my_work_summary)(
+            if name.is_empty() { "N/A" } else { name.as_str() },
+            user.id,
+            &user.login.as_deref().map(|login| format!(", {}", login)).unwrap_or_default(),
+            &today.format("%Y-%m-%d").to_string(),
+            hours_today,
+            time_entries.len(),
+            issues_total
+        );
+        let upcoming_title = (messages.due_within_days)(days_ahead);
+        for (title, list) in [(messages.overdue, &overdue), (upcoming_title.as_str(), &upcoming)] {
+            if list.is_empty() {
+                continue;
+            }
+            output.push_str(&format!("\n{}:\n", title));
+            for issue in list.iter() {
+                output.push_str(&format!(
+                    "- #{} {} [{}] {} {} ({})\n",
+                    issue.id,
+                    issue.subject,
+                    issue.status.name,
+                    messages.due_on,
+                    issue.due_date.map(|due| due.to_string()).unwrap_or_default(),
+                    issue.project.name
+                ));
+            }
+        }
+        
+        info!("Přehled práce uživatele {}: {} úkolů, dnes {:.2} h", user.id, issues.len(), hours_today);
+        
+        let summary = json!({
+            "user": {
+                "id": user.id,
+                "login": user.login,
+                "name": name,
+                "email": user.mail
+            },
+            "today": {
+                "date": today,
+                "logged_hours": hours_today,
+                "time_entries": time_entries.len()
+            },
+            "open_issues_count": issues_total,
+            "truncated": (issues.len() as i32) < issues_total,
+            "overdue": overdue.iter().map(|issue| compact(issue)).collect::<Vec<_>>(),
+            "upcoming_due": upcoming.iter().map(|issue| compact(issue)).collect::<Vec<_>>(),
+            "open_issues": issues.iter().map(compact).collect::<Vec<_>>()
+        });
+        
+        Ok(CallToolResult::success(vec![
+            ToolResult::text(output)
+        ]).with_structured_content(&summary))
+    }
+}
+
+// === LIST USER TYPES TOOL ===
+
+pub struct ListUserTypesTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListUserTypesTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[async_trait]
+impl ToolExecutor for ListUserTypesTool {
+    fn name(&self) -> &str {
+        "list_user_types"
+    }
+    
+    fn description(&self) -> &str {
+        "Získá seznam typů uživatelů EasyProject (interní, externí, klient...) včetně jejich ID \
+        pro filtrování pomocí list_users"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({})
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Users)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, _arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        debug!("Získávám seznam typů uživatelů");
+        
+        match self.api_client.list_user_types().await {
+            Ok(response) => {
+                let messages = i18n::messages();
+                let mut result = format!("{}:\n\n", (messages.user_types_heading)(response.easy_user_types.len()));
+                for user_type in &response.easy_user_types {
+                    let category = user_type.category()
+                        .map(|category| category.label())
+                        .unwrap_or(messages.unspecified);
+                    let default_flag = if user_type.is_default == Some(true) {
+                        format!(", {}", messages.default_flag)
+                    } else {
+                        String::new()
+                    };
+                    result.push_str(&format!(
+                        "  {} = {} ({}: {}{})\n",
+                        user_type.id,
+                        user_type.name,
+                        messages.category,
+                        category,
+                        default_flag
+                    ));
+                }
+                
+                info!("Úspěšně získáno {} typů uživatelů", response.easy_user_types.len());
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(result)
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při získávání typů uživatelů: {}", e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_user_types, e))
+                ]))
+            }
+        }
+    }
+}
+
+// === LIST ROLES TOOL ===
+
+pub struct ListRolesTool {
+    api_client: EasyProjectClient,
+}
+
+impl ListRolesTool {
+    pub fn new(api_client: EasyProjectClient, _config: crate::config::AppConfig) -> Self {
+        Self { api_client }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+struct ListRolesArgs {
+    id: Option<i32>,
+}
+
+#[async_trait]
+impl ToolExecutor for ListRolesTool {
+    fn name(&self) -> &str {
+        "list_roles"
+    }
+    
+    fn argument_aliases(&self) -> &'static [(&'static str, &'static str)] {
+        &[("role_id", "id"), ("role", "id")]
+    }
+    
+    fn description(&self) -> &str {
+        "Získá seznam rolí včetně jejich ID pro přidělování členům projektu; \
+        se zadaným id vrátí detail role včetně oprávnění"
+    }
+    
+    fn input_schema(&self) -> Value {
+        json!({
+            "id": {
+                "type": "integer",
+                "description": "ID role pro detail s oprávněními (volitelné)"
+            }
+        })
+    }
+    
+    fn required_permission(&self) -> Option<PermissionArea> {
+        Some(PermissionArea::Users)
+    }
+    
+    fn is_read_only(&self) -> bool {
+        true
+    }
+    
+    async fn execute(&self, arguments: Option<Value>) -> Result<CallToolResult, Box<dyn std::error::Error + Send + Sync>> {
+        let args: ListRolesArgs = match arguments {
+            Some(args) => serde_json::from_value(args)?,
+            None => ListRolesArgs::default(),
+        };
+        
+        if let Some(id) = args.id {
+            debug!("Získávám detail role {}", id);
+            return match self.api_client.get_role(id).await {
+                Ok(response) => Ok(CallToolResult::success(vec![
+                    ToolResult::text(serde_json::to_string_pretty(&response.role)?)
+                ])),
+                Err(e) => {
+                    error!("Chyba při získávání role {}: {}", id, e);
+                    Ok(CallToolResult::error(vec![
+                        ToolResult::text(format!("{}: {}", (i18n::messages().error_fetching_role)(id), e))
+                    ]))
+                }
+            };
+        }
+        
+        debug!("Získávám seznam rolí");
+        
+        match self.api_client.list_roles().await {
+            Ok(response) => {
+                let messages = i18n::messages();
+                let mut result = format!("{}:\n\n", (messages.roles_heading)(response.roles.len()));
+                for role in &response.roles {
+                    let not_assignable = if role.assignable == Some(false) {
+                        format!(" ({})", messages.not_assignable)
+                    } else {
+                        String::new()
+                    };
+                    result.push_str(&format!(
+                        "  {} = {}{}\n",
+                        role.id,
+                        role.name,
+                        not_assignable
+                    ));
+                }
+                
+                info!("Úspěšně získáno {} rolí", response.roles.len());
+                
+                Ok(CallToolResult::success(vec![
+                    ToolResult::text(result)
+                ]))
+            }
+            Err(e) => {
+                error!("Chyba při získávání rolí: {}", e);
+                Ok(CallToolResult::error(vec![
+                    ToolResult::text(format!("{}: {}", i18n::messages().error_fetching_roles, e))
+                ]))
+            }
+        }
+    }
+}
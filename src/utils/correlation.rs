@@ -0,0 +1,35 @@
+use std::future::Future;
+
+tokio::task_local! {
+    static CORRELATION_ID: String;
+}
+
+/// Vytvoří nové korelační ID pro jedno volání tool
+pub fn new_correlation_id() -> String {
+    uuid::Uuid::new_v4().simple().to_string()[..12].to_string()
+}
+
+/// Korelační ID aktuálně zpracovávaného volání (None mimo volání tool, např. v úlohách na pozadí)
+pub fn current_correlation_id() -> Option<String> {
+    CORRELATION_ID.try_with(|id| id.clone()).ok()
+}
+
+/// Spustí future s korelačním ID dostupným přes `current_correlation_id`
+pub async fn with_correlation_id<F: Future>(id: String, future: F) -> F::Output {
+    CORRELATION_ID.scope(id, future).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_correlation_id_scope() {
+        assert_eq!(current_correlation_id(), None);
+        let id = new_correlation_id();
+        assert_eq!(id.len(), 12);
+        let seen = with_correlation_id(id.clone(), async { current_correlation_id() }).await;
+        assert_eq!(seen, Some(id));
+        assert_eq!(current_correlation_id(), None);
+    }
+}
@@ -1,4 +1,5 @@
-use chrono::{DateTime, Utc, NaiveDate, Local, Datelike, Duration};
+use chrono::{DateTime, Utc, NaiveDate, NaiveDateTime, Local, Datelike, Duration, FixedOffset};
+use std::collections::HashMap;
 
 /// Získá aktuální datum v UTC
 pub fn current_date_utc() -> NaiveDate {
@@ -154,6 +155,31 @@ pub fn previous_business_day(date: NaiveDate) -> NaiveDate {
     prev
 }
 
+/// Pořadí pracovního dne od `origin` (pracovní dny v intervalu `[origin, date)`)
+///
+/// Pro datum před `origin` vrací záporný počet.
+pub fn business_day_offset(origin: NaiveDate, date: NaiveDate) -> i64 {
+    if date >= origin {
+        business_days_between(origin, date - Duration::days(1))
+    } else {
+        -business_days_between(date, origin - Duration::days(1))
+    }
+}
+
+/// Pracovní den s daným pořadím od `origin` (opak `business_day_offset`)
+///
+/// Víkendový `origin` se nejprve posune na následující pracovní den.
+pub fn add_business_days(origin: NaiveDate, days: i64) -> NaiveDate {
+    let mut date = if is_business_day(origin) { origin } else { next_business_day(origin) };
+    for _ in 0..days.max(0) {
+        date = next_business_day(date);
+    }
+    for _ in 0..(-days).max(0) {
+        date = previous_business_day(date);
+    }
+    date
+}
+
 /// Vytvoří rozsah dat pro časové filtrování
 pub struct DateRange {
     pub start: NaiveDate,
@@ -285,6 +311,142 @@ impl RelativePeriod {
     }
 }
 
+/// Časové pásmo pro určení dnešního data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateZone {
+    /// Lokální časové pásmo serveru
+    Local,
+    /// Pevný posun od UTC
+    Fixed(FixedOffset),
+}
+
+impl DateZone {
+    /// Vytvoří pásmo z posunu od UTC v sekundách (formát `utc_offset` uživatele EasyProject)
+    pub fn from_offset_seconds(seconds: i32) -> Option<Self> {
+        FixedOffset::east_opt(seconds).map(DateZone::Fixed)
+    }
+
+    /// Dnešní datum v tomto pásmu pro daný okamžik
+    pub fn date_at(&self, now: DateTime<Utc>) -> NaiveDate {
+        self.datetime_at(now).date()
+    }
+
+    /// Místní datum a čas v tomto pásmu pro daný okamžik
+    pub fn datetime_at(&self, now: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            DateZone::Local => now.with_timezone(&Local).naive_local(),
+            DateZone::Fixed(offset) => now.with_timezone(offset).naive_local(),
+        }
+    }
+
+    /// Čitelný popis pásma
+    pub fn describe(&self) -> String {
+        match self {
+            DateZone::Local => format!("lokální pásmo serveru (UTC{})", Local::now().offset()),
+            DateZone::Fixed(offset) => format!("UTC{}", offset),
+        }
+    }
+}
+
+/// Parsuje časové pásmo z konfigurace: "local", "UTC" nebo posun ve tvaru "+02:00", "-0530", "+2"
+pub fn parse_timezone(value: &str) -> Result<DateZone, String> {
+    let value = value.trim();
+    let invalid = || format!(
+        "Neplatné časové pásmo: '{}'. Podporované hodnoty: local, UTC, +HH:MM, -HH:MM",
+        value
+    );
+
+    match value.to_lowercase().as_str() {
+        "" | "local" => return Ok(DateZone::Local),
+        "utc" | "z" => return Ok(DateZone::Fixed(FixedOffset::east_opt(0).ok_or_else(invalid)?)),
+        _ => {}
+    }
+
+    let value_without_utc = value.strip_prefix("UTC").unwrap_or(value);
+    let (sign, rest) = match value_without_utc.chars().next() {
+        Some('+') => (1, &value_without_utc[1..]),
+        Some('-') => (-1, &value_without_utc[1..]),
+        _ => return Err(invalid()),
+    };
+
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i32 = minutes.parse().map_err(|_| invalid())?;
+    if hours > 14 || minutes >= 60 {
+        return Err(invalid());
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+        .map(DateZone::Fixed)
+        .ok_or_else(invalid)
+}
+
+/// Pravidlo pro určení, zda je úkol po termínu
+///
+/// Úkol je po termínu, pokud jeho termín je dříve než dnešní datum v příslušném
+/// časovém pásmu. Pro řešitele se známým `utc_offset` lze použít jeho pásmo.
+#[derive(Debug, Clone)]
+pub struct OverdueRule {
+    zone: DateZone,
+    now: DateTime<Utc>,
+    assignee_offsets: HashMap<i32, i32>,
+}
+
+impl OverdueRule {
+    pub fn new(zone: DateZone) -> Self {
+        Self::at(zone, Utc::now())
+    }
+
+    /// Pravidlo vyhodnocené k zadanému okamžiku
+    pub fn at(zone: DateZone, now: DateTime<Utc>) -> Self {
+        Self {
+            zone,
+            now,
+            assignee_offsets: HashMap::new(),
+        }
+    }
+
+    /// Použije časová pásma řešitelů (ID uživatele -> posun od UTC v sekundách)
+    pub fn with_assignee_offsets(mut self, offsets: HashMap<i32, i32>) -> Self {
+        self.assignee_offsets = offsets;
+        self
+    }
+
+    /// Dnešní datum pro daného řešitele (nebo v nastaveném pásmu)
+    pub fn today_for(&self, assignee_id: Option<i32>) -> NaiveDate {
+        assignee_id
+            .and_then(|id| self.assignee_offsets.get(&id))
+            .and_then(|seconds| DateZone::from_offset_seconds(*seconds))
+            .unwrap_or(self.zone)
+            .date_at(self.now)
+    }
+
+    /// Je úkol s daným termínem po termínu?
+    pub fn is_overdue(&self, due_date: NaiveDate, assignee_id: Option<i32>) -> bool {
+        due_date < self.today_for(assignee_id)
+    }
+
+    /// Popis pravidla pro výstup sestav
+    pub fn describe(&self) -> String {
+        let mut description = format!(
+            "Úkol je po termínu, pokud due_date < dnešní datum ({}, dnes {})",
+            self.zone.describe(),
+            format_date_iso(&self.zone.date_at(self.now))
+        );
+        if !self.assignee_offsets.is_empty() {
+            description.push_str(&format!(
+                "; pro {} řešitelů se známým utc_offset se použije jejich časové pásmo",
+                self.assignee_offsets.len()
+            ));
+        }
+        description
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -320,6 +482,21 @@ mod tests {
         assert_eq!(business_days_between(monday, friday), 5);
     }
 
+    #[test]
+    fn test_business_day_offset_roundtrip() {
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+        let next_monday = NaiveDate::from_ymd_opt(2024, 1, 22).unwrap();
+        let previous_friday = NaiveDate::from_ymd_opt(2024, 1, 12).unwrap();
+
+        assert_eq!(business_day_offset(monday, monday), 0);
+        assert_eq!(business_day_offset(monday, next_monday), 5);
+        assert_eq!(business_day_offset(monday, previous_friday), -1);
+        assert_eq!(add_business_days(monday, 5), next_monday);
+        assert_eq!(add_business_days(monday, -1), previous_friday);
+        // Sobota se počítá od následujícího pondělí
+        assert_eq!(add_business_days(NaiveDate::from_ymd_opt(2024, 1, 20).unwrap(), 0), next_monday);
+    }
+
     #[test]
     fn test_date_range() {
         let start = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
@@ -330,4 +507,34 @@ mod tests {
         assert!(range.contains(NaiveDate::from_ymd_opt(2024, 1, 15).unwrap()));
         assert!(!range.contains(NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()));
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_parse_timezone() {
+        assert_eq!(parse_timezone("local").unwrap(), DateZone::Local);
+        assert_eq!(parse_timezone("UTC").unwrap(), DateZone::Fixed(FixedOffset::east_opt(0).unwrap()));
+        assert_eq!(parse_timezone("+02:00").unwrap(), DateZone::Fixed(FixedOffset::east_opt(7200).unwrap()));
+        assert_eq!(parse_timezone("-0530").unwrap(), DateZone::Fixed(FixedOffset::east_opt(-19800).unwrap()));
+        assert_eq!(parse_timezone("UTC+9").unwrap(), DateZone::Fixed(FixedOffset::east_opt(32400).unwrap()));
+        assert!(parse_timezone("Europe/Prague").is_err());
+        assert!(parse_timezone("+25:00").is_err());
+    }
+
+    #[test]
+    fn test_overdue_rule_timezones() {
+        // 22:30 UTC - v Praze (UTC+2) je už následující den
+        let now = DateTime::parse_from_rfc3339("2024-06-10T22:30:00Z").unwrap().with_timezone(&Utc);
+        let due = NaiveDate::from_ymd_opt(2024, 6, 10).unwrap();
+
+        let utc_rule = OverdueRule::at(parse_timezone("UTC").unwrap(), now);
+        assert!(!utc_rule.is_overdue(due, None));
+
+        let prague_rule = OverdueRule::at(parse_timezone("+02:00").unwrap(), now);
+        assert!(prague_rule.is_overdue(due, None));
+
+        let mut offsets = HashMap::new();
+        offsets.insert(7, -5 * 3600);
+        let rule = OverdueRule::at(parse_timezone("+02:00").unwrap(), now).with_assignee_offsets(offsets);
+        assert!(!rule.is_overdue(due, Some(7)));
+        assert!(rule.is_overdue(due, Some(8)));
+    }
+}
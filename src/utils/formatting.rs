@@ -1,42 +1,245 @@
 use chrono::{DateTime, Utc, NaiveDate};
-use crate::api::models::{Project, Issue, User, TimeEntry, ProjectStatus};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use crate::api::models::{Project, Issue, User, TimeEntry, ProjectStatus, Attachment};
+use crate::i18n::{messages, Messages};
+
+/// Formát výstupu list a get tools (parametr `format`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// Odsazený JSON (výchozí)
+    #[default]
+    Json,
+    /// Čitelný text z formátovacích funkcí
+    Text,
+    /// Text s markdown nadpisem
+    Markdown,
+    /// JSON na jednom řádku (nejmenší velikost)
+    Compact,
+}
+
+impl OutputFormat {
+    /// Schéma parametru `format` pro input_schema tools
+    pub fn schema() -> Value {
+        json!({
+            "type": "string",
+            "description": messages().format_description,
+            "enum": ["json", "text", "markdown", "compact"]
+        })
+    }
+
+    /// Vykreslí výsledek s nadpisem - JSON formáty serializují `value`, textové použijí `render_text`
+    pub fn render<T: Serialize>(self, title: &str, value: &T, render_text: impl FnOnce() -> String) -> serde_json::Result<String> {
+        Ok(match self {
+            OutputFormat::Json => format!("{}:\n\n{}", title, serde_json::to_string_pretty(value)?),
+            OutputFormat::Compact => format!("{}:\n{}", title, serde_json::to_string(value)?),
+            OutputFormat::Text => render_text(),
+            OutputFormat::Markdown => format!("## {}\n\n{}", title, render_text()),
+        })
+    }
+
+    /// Vykreslí seznam - markdown formát místo textového přehledu použije tabulku z `render_table`
+    pub fn render_list<T: Serialize>(
+        self,
+        title: &str,
+        value: &T,
+        render_text: impl FnOnce() -> String,
+        render_table: impl FnOnce() -> String,
+    ) -> serde_json::Result<String> {
+        match self {
+            OutputFormat::Markdown => Ok(format!("## {}\n\n{}", title, render_table())),
+            format => format.render(title, value, render_text),
+        }
+    }
+}
+
+/// Formát exportu list tools a sestav (parametr `export_format`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// CSV s oddělovačem `;` a řádkem záhlaví
+    Csv,
+}
+
+impl ExportFormat {
+    /// Schéma parametru `export_format` pro input_schema tools
+    pub fn schema() -> Value {
+        json!({
+            "type": "string",
+            "description": messages().export_format_description,
+            "enum": ["csv"]
+        })
+    }
+}
+
+/// Sloupec markdown tabulky
+pub struct TableColumn<T> {
+    /// Klíč sloupce v parametru `columns`
+    pub key: &'static str,
+    /// Záhlaví sloupce v jazyce výstupu
+    pub header: fn(&Messages) -> &'static str,
+    /// Zda je sloupec ve výchozím výběru
+    pub default: bool,
+    /// Hodnota buňky
+    pub value: fn(&T) -> String,
+}
+
+/// Sloupce tabulky úkolů
+pub const ISSUE_COLUMNS: &[TableColumn<Issue>] = &[
+    TableColumn { key: "id", header: |_| "ID", default: true, value: |issue| format!("#{}", issue.id) },
+    TableColumn { key: "subject", header: |messages| messages.subject, default: true, value: |issue| issue.subject.clone() },
+    TableColumn { key: "project", header: |messages| messages.project, default: false, value: |issue| issue.project.name.clone() },
+    TableColumn { key: "tracker", header: |messages| messages.tracker, default: false, value: |issue| issue.tracker.name.clone() },
+    TableColumn { key: "status", header: |messages| messages.status, default: true, value: |issue| issue.status.name.clone() },
+    TableColumn { key: "priority", header: |messages| messages.priority, default: true, value: |issue| issue.priority.name.clone() },
+    TableColumn { key: "assigned_to", header: |messages| messages.assigned_to, default: true, value: |issue| issue.assigned_to.as_ref().map(|user| user.name.clone()).unwrap_or_default() },
+    TableColumn { key: "start_date", header: |messages| messages.start_date, default: false, value: |issue| issue.start_date.as_ref().map(format_date).unwrap_or_default() },
+    TableColumn { key: "due_date", header: |messages| messages.due_date, default: true, value: |issue| issue.due_date.as_ref().map(format_date).unwrap_or_default() },
+    TableColumn { key: "done_ratio", header: |messages| messages.done_ratio, default: true, value: |issue| issue.done_ratio.map(|ratio| format!("{}%", ratio)).unwrap_or_default() },
+    TableColumn { key: "estimated_hours", header: |messages| messages.estimated_hours, default: false, value: |issue| issue.estimated_hours.map(|hours| hours.to_string()).unwrap_or_default() },
+    TableColumn { key: "spent_hours", header: |messages| messages.spent_hours, default: false, value: |issue| issue.spent_hours.map(|hours| hours.to_string()).unwrap_or_default() },
+    TableColumn { key: "updated_on", header: |messages| messages.updated, default: false, value: |issue| issue.updated_on.as_ref().map(format_datetime).unwrap_or_default() },
+];
+
+/// Sloupce tabulky časových záznamů
+pub const TIME_ENTRY_COLUMNS: &[TableColumn<TimeEntry>] = &[
+    TableColumn { key: "id", header: |_| "ID", default: false, value: |entry| format!("#{}", entry.id) },
+    TableColumn { key: "spent_on", header: |messages| messages.date, default: true, value: |entry| format_date(&entry.spent_on) },
+    TableColumn { key: "hours", header: |messages| messages.spent_hours, default: true, value: |entry| entry.hours.to_string() },
+    TableColumn { key: "user", header: |messages| messages.user, default: true, value: |entry| entry.user.name.clone() },
+    TableColumn { key: "project", header: |messages| messages.project, default: true, value: |entry| entry.project.name.clone() },
+    TableColumn { key: "issue", header: |messages| messages.issue, default: true, value: |entry| entry.issue.as_ref().map(|issue| format!("#{}", issue.id)).unwrap_or_default() },
+    TableColumn { key: "activity", header: |messages| messages.activity, default: true, value: |entry| entry.activity.name.clone() },
+    TableColumn { key: "comments", header: |messages| messages.comment, default: true, value: |entry| entry.comments.as_deref().map(|comments| truncate_text(comments, 80)).unwrap_or_default() },
+];
+
+/// Sloupce tabulky uživatelů
+pub const USER_COLUMNS: &[TableColumn<User>] = &[
+    TableColumn { key: "id", header: |_| "ID", default: true, value: |user| format!("#{}", user.id) },
+    TableColumn { key: "name", header: |messages| messages.name, default: true, value: |user| format!("{} {}", user.firstname.as_deref().unwrap_or("N/A"), user.lastname.as_deref().unwrap_or("N/A")) },
+    TableColumn { key: "login", header: |messages| messages.login, default: true, value: |user| user.login.clone().unwrap_or_default() },
+    TableColumn { key: "mail", header: |messages| messages.email, default: true, value: |user| user.mail.clone().unwrap_or_default() },
+    TableColumn { key: "status", header: |messages| messages.status, default: true, value: |user| user_status_label(user.status).to_string() },
+    TableColumn { key: "admin", header: |messages| messages.administrator, default: false, value: |user| if user.admin == Some(true) { messages().yes } else { messages().no }.to_string() },
+    TableColumn { key: "user_type", header: |messages| messages.user_type, default: false, value: |user| user.easy_user_type.as_ref().map(|user_type| user_type.name.clone()).unwrap_or_default() },
+];
+
+/// Schéma parametru `columns` s klíči dostupných sloupců
+pub fn columns_schema<T>(columns: &[TableColumn<T>]) -> Value {
+    let defaults: Vec<&str> = columns.iter().filter(|column| column.default).map(|column| column.key).collect();
+    json!({
+        "type": "array",
+        "description": (messages().columns_description)(&defaults.join(", ")),
+        "items": {
+            "type": "string",
+            "enum": columns.iter().map(|column| column.key).collect::<Vec<_>>()
+        }
+    })
+}
+
+/// Vybere sloupce podle klíčů, bez klíčů vrátí výchozí sloupce
+pub fn select_columns<'a, T>(columns: &'a [TableColumn<T>], keys: Option<&[String]>) -> Result<Vec<&'a TableColumn<T>>, String> {
+    match keys {
+        None | Some([]) => Ok(columns.iter().filter(|column| column.default).collect()),
+        Some(keys) => keys.iter()
+            .map(|key| columns.iter().find(|column| column.key == key).ok_or_else(|| (messages().unknown_column)(
+                key,
+                &columns.iter().map(|column| column.key).collect::<Vec<_>>().join(", ")
+            )))
+            .collect(),
+    }
+}
+
+/// Vykreslí položky jako markdown tabulku
+pub fn markdown_table<T>(items: &[T], columns: &[&TableColumn<T>]) -> String {
+    let messages = messages();
+    if items.is_empty() {
+        return messages.no_records.to_string();
+    }
+
+    let mut result = format!(
+        "| {} |\n|{}\n",
+        columns.iter().map(|column| (column.header)(messages)).collect::<Vec<_>>().join(" | "),
+        "---|".repeat(columns.len())
+    );
+    for item in items {
+        let cells: Vec<String> = columns.iter()
+            .map(|column| escape_table_cell(&(column.value)(item)))
+            .collect();
+        result.push_str(&format!("| {} |\n", cells.join(" | ")));
+    }
+    result
+}
+
+/// Vykreslí položky jako CSV se záhlavím (stejné sloupce jako markdown tabulka)
+pub fn csv_table<T>(items: &[T], columns: &[&TableColumn<T>]) -> String {
+    let messages = messages();
+    let header: Vec<String> = columns.iter().map(|column| (column.header)(messages).to_string()).collect();
+    let rows = items.iter().map(|item| columns.iter().map(|column| (column.value)(item)).collect::<Vec<_>>());
+    csv_rows(std::iter::once(header).chain(rows))
+}
+
+/// Spojí řádky buněk do CSV (oddělovač `;`, řádky ukončené `\n`)
+pub fn csv_rows<R, C>(rows: impl IntoIterator<Item = R>) -> String
+where
+    R: IntoIterator<Item = C>,
+    C: AsRef<str>,
+{
+    let mut out = String::new();
+    for row in rows {
+        let cells: Vec<String> = row.into_iter().map(|cell| csv_cell(cell.as_ref())).collect();
+        out.push_str(&cells.join(";"));
+        out.push('\n');
+    }
+    out
+}
+
+/// Buňka CSV - hodnoty s oddělovačem, uvozovkami nebo koncem řádku se uzavřou do uvozovek
+pub fn csv_cell(value: &str) -> String {
+    if value.contains([';', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Ošetří znaky, které by rozbily řádek markdown tabulky
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
 
 /// Formátuje projekt pro lidsky čitelný výstup
 pub fn format_project(project: &Project) -> String {
-    let status = match project.status {
-        ProjectStatus::Active => "Aktivní",
-        ProjectStatus::Closed => "Uzavřený", 
-        ProjectStatus::Archived => "Archivovaný",
-        ProjectStatus::Planned => "Plánovaný",
-        ProjectStatus::Deleted => "Smazaný",
-        ProjectStatus::Unknown(status_id) => &format!("Neznámý ({})", status_id),
-    };
+    let messages = messages();
     
     let mut result = format!(
-        "Projekt #{}: {}\n  Status: {}\n",
+        "{} #{}: {}\n  {}: {}\n",
+        messages.project,
         project.id,
         project.name,
-        status
+        messages.status,
+        project_status_label(&project.status)
     );
     
     if let Some(ref description) = project.description {
-        result.push_str(&format!("  Popis: {}\n", description));
+        result.push_str(&format!("  {}: {}\n", messages.description, description));
     }
     
     if let Some(ref identifier) = project.identifier {
-        result.push_str(&format!("  Identifikátor: {}\n", identifier));
+        result.push_str(&format!("  {}: {}\n", messages.identifier, identifier));
     }
     
     if let Some(ref homepage) = project.homepage {
-        result.push_str(&format!("  Domovská stránka: {}\n", homepage));
+        result.push_str(&format!("  {}: {}\n", messages.homepage, homepage));
     }
     
     if let Some(ref parent) = project.parent {
-        result.push_str(&format!("  Nadřazený projekt: {} (ID: {})\n", parent.name, parent.id));
+        result.push_str(&format!("  {}: {} (ID: {})\n", messages.parent_project, parent.name, parent.id));
     }
     
     if let Some(ref created_on) = project.created_on {
-        result.push_str(&format!("  Vytvořeno: {}\n", format_datetime(created_on)));
+        result.push_str(&format!("  {}: {}\n", messages.created, format_datetime(created_on)));
     }
     
     result
@@ -44,100 +247,152 @@ pub fn format_project(project: &Project) -> String {
 
 /// Formátuje úkol pro lidsky čitelný výstup
 pub fn format_issue(issue: &Issue) -> String {
+    let messages = messages();
+    
     let mut result = format!(
-        "Úkol #{}: {}\n  Projekt: {}\n  Tracker: {}\n  Status: {}\n  Priorita: {}\n",
+        "{} #{}: {}\n  {}: {}\n  {}: {}\n  {}: {}\n  {}: {}\n",
+        messages.issue,
         issue.id,
         issue.subject,
+        messages.project,
         issue.project.name,
+        messages.tracker,
         issue.tracker.name,
+        messages.status,
         issue.status.name,
+        messages.priority,
         issue.priority.name
     );
     
     if let Some(ref description) = issue.description {
-        let truncated = if description.len() > 200 {
-            format!("{}...", &description[..200])
-        } else {
-            description.clone()
-        };
-        result.push_str(&format!("  Popis: {}\n", truncated));
+        result.push_str(&format!("  {}: {}\n", messages.description, truncate_text(description, 200)));
     }
     
     if let Some(ref author) = issue.author {
-        result.push_str(&format!("  Autor: {}\n", author.name));
+        result.push_str(&format!("  {}: {}\n", messages.author, author.name));
     }
     
     if let Some(ref assigned_to) = issue.assigned_to {
-        result.push_str(&format!("  Přiřazeno: {}\n", assigned_to.name));
+        result.push_str(&format!("  {}: {}\n", messages.assigned_to, assigned_to.name));
     }
     
     if let Some(estimated_hours) = issue.estimated_hours {
-        result.push_str(&format!("  Odhadované hodiny: {}\n", estimated_hours));
+        result.push_str(&format!("  {}: {}\n", messages.estimated_hours, estimated_hours));
     }
     
     if let Some(spent_hours) = issue.spent_hours {
-        result.push_str(&format!("  Strávené hodiny: {}\n", spent_hours));
+        result.push_str(&format!("  {}: {}\n", messages.spent_hours, spent_hours));
     }
     
     if let Some(done_ratio) = issue.done_ratio {
-        result.push_str(&format!("  Dokončeno: {}%\n", done_ratio));
+        result.push_str(&format!("  {}: {}%\n", messages.done_ratio, done_ratio));
     }
     
     if let Some(ref start_date) = issue.start_date {
-        result.push_str(&format!("  Datum zahájení: {}\n", format_date(start_date)));
+        result.push_str(&format!("  {}: {}\n", messages.start_date, format_date(start_date)));
     }
     
     if let Some(ref due_date) = issue.due_date {
-        result.push_str(&format!("  Termín dokončení: {}\n", format_date(due_date)));
+        result.push_str(&format!("  {}: {}\n", messages.due_date, format_date(due_date)));
     }
     
     if let Some(ref created_on) = issue.created_on {
-        result.push_str(&format!("  Vytvořeno: {}\n", format_datetime(created_on)));
+        result.push_str(&format!("  {}: {}\n", messages.created, format_datetime(created_on)));
+    }
+    
+    if let Some(ref attachments) = issue.attachments {
+        if !attachments.is_empty() {
+            result.push_str(&format!("  {} ({}):\n", messages.attachments, attachments.len()));
+            for attachment in attachments {
+                result.push_str(&format!("    {}\n", format_attachment(attachment)));
+            }
+        }
     }
     
     result
 }
 
+/// Formátuje přílohu na jeden řádek (název, velikost, typ, autor, datum, URL)
+pub fn format_attachment(attachment: &Attachment) -> String {
+    let messages = messages();
+    let mut result = format!("• #{}: {}", attachment.id, attachment.filename);
+    
+    if let Some(filesize) = attachment.filesize {
+        result.push_str(&format!(" ({})", format_file_size(filesize)));
+    }
+    
+    if let Some(ref content_type) = attachment.content_type {
+        result.push_str(&format!(" [{}]", content_type));
+    }
+    
+    if let Some(ref author) = attachment.author {
+        result.push_str(&format!(" | {}: {}", messages.author, author.name));
+    }
+    
+    if let Some(ref created_on) = attachment.created_on {
+        result.push_str(&format!(" | {}: {}", messages.uploaded, format_datetime(created_on)));
+    }
+    
+    if let Some(ref content_url) = attachment.content_url {
+        result.push_str(&format!(" | URL: {}", content_url));
+    }
+    
+    result
+}
+
+/// Formátuje velikost souboru v bajtech do čitelné podoby
+pub fn format_file_size(bytes: i64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    
+    let size = bytes as f64;
+    if size >= MB {
+        format!("{:.1} MB", size / MB)
+    } else if size >= KB {
+        format!("{:.1} kB", size / KB)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
 /// Formátuje uživatele pro lidsky čitelný výstup
 pub fn format_user(user: &User) -> String {
-    let status = match user.status {
-        Some(1) => "Aktivní",
-        Some(2) => "Registrovaný",
-        Some(3) => "Zablokovaný",
-        _ => "Neznámý",
-    };
+    let messages = messages();
+    let status = user_status_label(user.status);
     
     let firstname = user.firstname.as_deref().unwrap_or("N/A");
     let lastname = user.lastname.as_deref().unwrap_or("N/A");
     
     let mut result = format!(
-        "Uživatel #{}: {} {}\n  Status: {}\n",
+        "{} #{}: {} {}\n  {}: {}\n",
+        messages.user,
         user.id,
         firstname,
         lastname,
+        messages.status,
         status
     );
     
     if let Some(ref login) = user.login {
-        result.push_str(&format!("  Přihlašovací jméno: {}\n", login));
+        result.push_str(&format!("  {}: {}\n", messages.login, login));
     }
     
     if let Some(ref mail) = user.mail {
-        result.push_str(&format!("  Email: {}\n", mail));
+        result.push_str(&format!("  {}: {}\n", messages.email, mail));
     }
     
     if let Some(admin) = user.admin {
         if admin {
-            result.push_str("  Role: Administrátor\n");
+            result.push_str(&format!("  {}: {}\n", messages.role, messages.administrator));
         }
     }
     
     if let Some(ref created_on) = user.created_on {
-        result.push_str(&format!("  Vytvořeno: {}\n", format_datetime(created_on)));
+        result.push_str(&format!("  {}: {}\n", messages.created, format_datetime(created_on)));
     }
     
     if let Some(ref last_login_on) = user.last_login_on {
-        result.push_str(&format!("  Poslední přihlášení: {}\n", format_datetime(last_login_on)));
+        result.push_str(&format!("  {}: {}\n", messages.last_login, format_datetime(last_login_on)));
     }
     
     result
@@ -145,26 +400,34 @@ pub fn format_user(user: &User) -> String {
 
 /// Formátuje časový záznam pro lidsky čitelný výstup
 pub fn format_time_entry(time_entry: &TimeEntry) -> String {
+    let messages = messages();
+    
     let mut result = format!(
-        "Časový záznam #{}: {} hodin\n  Projekt: {}\n  Aktivita: {}\n  Datum: {}\n  Uživatel: {}\n",
+        "{} #{}: {} {}\n  {}: {}\n  {}: {}\n  {}: {}\n  {}: {}\n",
+        messages.time_entry,
         time_entry.id,
         time_entry.hours,
+        messages.hours,
+        messages.project,
         time_entry.project.name,
+        messages.activity,
         time_entry.activity.name,
+        messages.date,
         format_date(&time_entry.spent_on),
+        messages.user,
         time_entry.user.name
     );
     
     if let Some(ref issue) = time_entry.issue {
-        result.push_str(&format!("  Úkol: #{}\n", issue.id));
+        result.push_str(&format!("  {}: #{}\n", messages.issue, issue.id));
     }
     
     if let Some(ref comments) = time_entry.comments {
-        result.push_str(&format!("  Komentář: {}\n", comments));
+        result.push_str(&format!("  {}: {}\n", messages.comment, comments));
     }
     
     if let Some(ref created_on) = time_entry.created_on {
-        result.push_str(&format!("  Vytvořeno: {}\n", format_datetime(created_on)));
+        result.push_str(&format!("  {}: {}\n", messages.created, format_datetime(created_on)));
     }
     
     result
@@ -182,36 +445,23 @@ pub fn format_date(date: &NaiveDate) -> String {
 
 /// Formátuje seznam projektů pro přehled
 pub fn format_project_list(projects: &[Project]) -> String {
+    let messages = messages();
     if projects.is_empty() {
-        return "Žádné projekty nebyly nalezeny.".to_string();
+        return messages.no_projects.to_string();
     }
     
-    let mut result = format!("Nalezeno {} projektů:\n\n", projects.len());
+    let mut result = format!("{}:\n\n", (messages.found_projects)(projects.len()));
     
     for project in projects {
-        let status = match project.status {
-            ProjectStatus::Active => "Aktivní",
-            ProjectStatus::Closed => "Uzavřený",
-            ProjectStatus::Archived => "Archivovaný",
-            ProjectStatus::Planned => "Plánovaný",
-            ProjectStatus::Deleted => "Smazaný",
-            ProjectStatus::Unknown(status_id) => &format!("Neznámý ({})", status_id),
-        };
-        
         result.push_str(&format!(
             "• #{}: {} ({})\n",
             project.id,
             project.name,
-            status
+            project_status_label(&project.status)
         ));
         
         if let Some(ref description) = project.description {
-            let truncated = if description.len() > 100 {
-                format!("{}...", &description[..100])
-            } else {
-                description.clone()
-            };
-            result.push_str(&format!("  {}\n", truncated));
+            result.push_str(&format!("  {}\n", truncate_text(description, 100)));
         }
         
         result.push('\n');
@@ -222,11 +472,12 @@ pub fn format_project_list(projects: &[Project]) -> String {
 
 /// Formátuje seznam úkolů pro přehled
 pub fn format_issue_list(issues: &[Issue]) -> String {
+    let messages = messages();
     if issues.is_empty() {
-        return "Žádné úkoly nebyly nalezeny.".to_string();
+        return messages.no_issues.to_string();
     }
     
-    let mut result = format!("Nalezeno {} úkolů:\n\n", issues.len());
+    let mut result = format!("{}:\n\n", (messages.found_issues)(issues.len()));
     
     for issue in issues {
         result.push_str(&format!(
@@ -237,17 +488,19 @@ pub fn format_issue_list(issues: &[Issue]) -> String {
         ));
         
         result.push_str(&format!(
-            "  Projekt: {} | Priorita: {}\n",
+            "  {}: {} | {}: {}\n",
+            messages.project,
             issue.project.name,
+            messages.priority,
             issue.priority.name
         ));
         
         if let Some(ref assigned_to) = issue.assigned_to {
-            result.push_str(&format!("  Přiřazeno: {}\n", assigned_to.name));
+            result.push_str(&format!("  {}: {}\n", messages.assigned_to, assigned_to.name));
         }
         
         if let Some(done_ratio) = issue.done_ratio {
-            result.push_str(&format!("  Dokončeno: {}%\n", done_ratio));
+            result.push_str(&format!("  {}: {}%\n", messages.done_ratio, done_ratio));
         }
         
         result.push('\n');
@@ -258,19 +511,15 @@ pub fn format_issue_list(issues: &[Issue]) -> String {
 
 /// Formátuje seznam uživatelů pro přehled
 pub fn format_user_list(users: &[User]) -> String {
+    let messages = messages();
     if users.is_empty() {
-        return "Žádní uživatelé nebyli nalezeni.".to_string();
+        return messages.no_users.to_string();
     }
     
-    let mut result = format!("Nalezeno {} uživatelů:\n\n", users.len());
+    let mut result = format!("{}:\n\n", (messages.found_users)(users.len()));
     
     for user in users {
-        let status = match user.status {
-            Some(1) => "Aktivní",
-            Some(2) => "Registrovaný", 
-            Some(3) => "Zablokovaný",
-            _ => "Neznámý",
-        };
+        let status = user_status_label(user.status);
         
         let firstname = user.firstname.as_deref().unwrap_or("N/A");
         let lastname = user.lastname.as_deref().unwrap_or("N/A");
@@ -284,12 +533,12 @@ pub fn format_user_list(users: &[User]) -> String {
         ));
         
         if let Some(ref mail) = user.mail {
-            result.push_str(&format!("  Email: {}\n", mail));
+            result.push_str(&format!("  {}: {}\n", messages.email, mail));
         }
         
         if let Some(admin) = user.admin {
             if admin {
-                result.push_str("  Role: Administrátor\n");
+                result.push_str(&format!("  {}: {}\n", messages.role, messages.administrator));
             }
         }
         
@@ -299,54 +548,87 @@ pub fn format_user_list(users: &[User]) -> String {
     result
 }
 
+/// Popis stavu uživatele
+fn user_status_label(status: Option<i32>) -> &'static str {
+    let messages = messages();
+    match status {
+        Some(1) => messages.user_active,
+        Some(2) => messages.user_registered,
+        Some(3) => messages.user_locked,
+        _ => messages.unknown,
+    }
+}
+
+/// Popis stavu projektu
+fn project_status_label(status: &ProjectStatus) -> String {
+    let messages = messages();
+    match status {
+        ProjectStatus::Active => messages.project_active.to_string(),
+        ProjectStatus::Closed => messages.project_closed.to_string(),
+        ProjectStatus::Archived => messages.project_archived.to_string(),
+        ProjectStatus::Planned => messages.project_planned.to_string(),
+        ProjectStatus::Deleted => messages.project_deleted.to_string(),
+        ProjectStatus::Unknown(status_id) => format!("{} ({})", messages.unknown, status_id),
+    }
+}
+
 /// Formátuje seznam časových záznamů pro přehled
 pub fn format_time_entry_list(time_entries: &[TimeEntry]) -> String {
+    let messages = messages();
     if time_entries.is_empty() {
-        return "Žádné časové záznamy nebyly nalezeny.".to_string();
+        return messages.no_time_entries.to_string();
     }
     
-    let mut result = format!("Nalezeno {} časových záznamů:\n\n", time_entries.len());
+    let mut result = format!("{}:\n\n", (messages.found_time_entries)(time_entries.len()));
     let total_hours: f64 = time_entries.iter().map(|te| te.hours).sum();
     
     for time_entry in time_entries {
         result.push_str(&format!(
-            "• #{}: {} hodin - {} ({})\n",
+            "• #{}: {} {} - {} ({})\n",
             time_entry.id,
             time_entry.hours,
+            messages.hours,
             time_entry.project.name,
             format_date(&time_entry.spent_on)
         ));
         
         result.push_str(&format!(
-            "  Aktivita: {} | Uživatel: {}\n",
+            "  {}: {} | {}: {}\n",
+            messages.activity,
             time_entry.activity.name,
+            messages.user,
             time_entry.user.name
         ));
         
         if let Some(ref issue) = time_entry.issue {
-            result.push_str(&format!("  Úkol: #{}\n", issue.id));
+            result.push_str(&format!("  {}: #{}\n", messages.issue, issue.id));
         }
         
         if let Some(ref comments) = time_entry.comments {
-            let truncated = if comments.len() > 80 {
-                format!("{}...", &comments[..80])
-            } else {
-                comments.clone()
-            };
-            result.push_str(&format!("  Komentář: {}\n", truncated));
+            result.push_str(&format!("  {}: {}\n", messages.comment, truncate_text(comments, 80)));
         }
         
         result.push('\n');
     }
     
-    result.push_str(&format!("Celkem hodin: {}\n", total_hours));
+    result.push_str(&format!("{}: {}\n", messages.total_hours, total_hours));
     
     result
 }
 
+/// Zkrátí text na zadaný počet znaků (bezpečně vůči UTF-8) a doplní "..."
+pub fn truncate_text(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        let truncated: String = text.chars().take(max_chars).collect();
+        format!("{}...", truncated)
+    } else {
+        text.to_string()
+    }
+}
+
 /// Formátuje chybovou zprávu
 pub fn format_error(error: &str) -> String {
-    format!("❌ Chyba: {}", error)
+    format!("❌ {}: {}", messages().error, error)
 }
 
 /// Formátuje úspěšnou zprávu
@@ -362,4 +644,97 @@ pub fn format_info(message: &str) -> String {
 /// Formátuje varovnou zprávu
 pub fn format_warning(message: &str) -> String {
     format!("⚠️ {}", message)
-} 
\ No newline at end of file
+} 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(id: i32, subject: &str) -> Issue {
+        serde_json::from_value(json!({
+            "id": id,
+            "subject": subject,
+            "project": { "id": 1, "name": "Web" },
+            "tracker": { "id": 1, "name": "Úkol" },
+            "status": { "id": 1, "name": "Nový" },
+            "priority": { "id": 2, "name": "Normální" },
+            "due_date": "2024-06-30",
+            "done_ratio": 40
+        })).unwrap()
+    }
+
+    #[test]
+    fn test_select_columns() {
+        let defaults = select_columns(ISSUE_COLUMNS, None).unwrap();
+        assert!(defaults.iter().all(|column| column.default));
+        assert_eq!(select_columns(ISSUE_COLUMNS, Some(&[])).unwrap().len(), defaults.len());
+
+        let keys = vec!["subject".to_string(), "id".to_string()];
+        let selected = select_columns(ISSUE_COLUMNS, Some(&keys)).unwrap();
+        assert_eq!(selected.iter().map(|column| column.key).collect::<Vec<_>>(), vec!["subject", "id"]);
+    }
+
+    #[test]
+    fn test_select_columns_unknown() {
+        let keys = vec!["id".to_string(), "neexistuje".to_string()];
+        let error = select_columns(ISSUE_COLUMNS, Some(&keys)).err().unwrap();
+        assert!(error.starts_with("Neznámý sloupec 'neexistuje'. Dostupné sloupce: id, subject"));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let keys = vec!["id".to_string(), "subject".to_string(), "due_date".to_string(), "done_ratio".to_string()];
+        let columns = select_columns(ISSUE_COLUMNS, Some(&keys)).unwrap();
+
+        let table = markdown_table(&[issue(5, "Oprava | přihlášení\nna mobilu")], &columns);
+        assert_eq!(
+            table,
+            "| ID | Název | Termín dokončení | Dokončeno |\n|---|---|---|---|\n| #5 | Oprava \\| přihlášení na mobilu | 30.06.2024 | 40% |\n"
+        );
+
+        assert_eq!(markdown_table::<Issue>(&[], &columns), "Žádné záznamy nebyly nalezeny.");
+    }
+
+    #[test]
+    fn test_csv_table_and_cells() {
+        let keys = vec!["id".to_string(), "subject".to_string()];
+        let columns = select_columns(ISSUE_COLUMNS, Some(&keys)).unwrap();
+        let csv = csv_table(&[issue(5, "Export; \"CSV\"")], &columns);
+        assert_eq!(csv, "ID;Název\n#5;\"Export; \"\"CSV\"\"\"\n");
+
+        assert_eq!(csv_cell("prosty text"), "prosty text");
+        assert_eq!(csv_cell("dva\nradky"), "\"dva\nradky\"");
+    }
+
+    #[test]
+    fn test_output_format_render() {
+        let value = json!({ "id": 1 });
+        assert_eq!(OutputFormat::Json.render("Úkol", &value, || unreachable!()).unwrap(), "Úkol:\n\n{\n  \"id\": 1\n}");
+        assert_eq!(OutputFormat::Compact.render("Úkol", &value, || unreachable!()).unwrap(), "Úkol:\n{\"id\":1}");
+        assert_eq!(OutputFormat::Text.render("Úkol", &value, || "text".to_string()).unwrap(), "text");
+        assert_eq!(
+            OutputFormat::Markdown.render_list("Úkoly", &value, || unreachable!(), || "tabulka".to_string()).unwrap(),
+            "## Úkoly\n\ntabulka"
+        );
+        assert!(serde_json::from_value::<OutputFormat>(json!("xml")).is_err());
+    }
+
+    #[test]
+    fn test_format_issue_list() {
+        let text = format_issue_list(&[issue(7, "Nasazení")]);
+        assert!(text.starts_with("Nalezeno 1 úkolů:\n\n• #7: Nasazení [Nový]\n"));
+        assert!(text.contains("  Projekt: Web | Priorita: Normální\n"));
+        assert!(text.contains("  Dokončeno: 40%\n"));
+        assert_eq!(format_issue_list(&[]), "Žádné úkoly nebyly nalezeny.");
+    }
+
+    #[test]
+    fn test_format_file_size_and_truncate() {
+        assert_eq!(format_file_size(512), "512 B");
+        assert_eq!(format_file_size(2048), "2.0 kB");
+        assert_eq!(format_file_size(5 * 1024 * 1024), "5.0 MB");
+        assert_eq!(truncate_text("příliš dlouhý", 6), "příliš...");
+        assert_eq!(truncate_text("krátký", 10), "krátký");
+        assert_eq!(format_error("selhalo"), "❌ Chyba: selhalo");
+    }
+}
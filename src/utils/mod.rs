@@ -1,7 +1,11 @@
 pub mod validation;
 pub mod formatting;
 pub mod date_utils;
+pub mod text_utils;
+pub mod correlation;
 
 pub use validation::*;
 pub use formatting::*;
-pub use date_utils::*; 
\ No newline at end of file
+pub use date_utils::*;
+pub use text_utils::*;
+pub use correlation::*; 
\ No newline at end of file
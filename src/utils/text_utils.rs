@@ -0,0 +1,262 @@
+use std::cmp::Ordering;
+
+/// Odstraní diakritiku z běžných znaků latinky (čeština, slovenština, němčina, polština...)
+pub fn fold_diacritics(text: &str) -> String {
+    text.chars().map(fold_char).collect()
+}
+
+fn fold_char(c: char) -> char {
+    match c {
+        'á' | 'à' | 'â' | 'ä' | 'ã' | 'å' | 'ą' => 'a',
+        'Á' | 'À' | 'Â' | 'Ä' | 'Ã' | 'Å' | 'Ą' => 'A',
+        'č' | 'ç' | 'ć' => 'c',
+        'Č' | 'Ç' | 'Ć' => 'C',
+        'ď' => 'd',
+        'Ď' => 'D',
+        'é' | 'è' | 'ê' | 'ë' | 'ě' | 'ę' => 'e',
+        'É' | 'È' | 'Ê' | 'Ë' | 'Ě' | 'Ę' => 'E',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'Í' | 'Ì' | 'Î' | 'Ï' => 'I',
+        'ĺ' | 'ľ' | 'ł' => 'l',
+        'Ĺ' | 'Ľ' | 'Ł' => 'L',
+        'ň' | 'ń' | 'ñ' => 'n',
+        'Ň' | 'Ń' | 'Ñ' => 'N',
+        'ó' | 'ò' | 'ô' | 'ö' | 'õ' | 'ő' => 'o',
+        'Ó' | 'Ò' | 'Ô' | 'Ö' | 'Õ' | 'Ő' => 'O',
+        'ŕ' | 'ř' => 'r',
+        'Ŕ' | 'Ř' => 'R',
+        'š' | 'ś' => 's',
+        'Š' | 'Ś' => 'S',
+        'ť' => 't',
+        'Ť' => 'T',
+        'ú' | 'ù' | 'û' | 'ü' | 'ů' | 'ű' => 'u',
+        'Ú' | 'Ù' | 'Û' | 'Ü' | 'Ů' | 'Ű' => 'U',
+        'ý' | 'ÿ' => 'y',
+        'Ý' | 'Ÿ' => 'Y',
+        'ž' | 'ź' | 'ż' => 'z',
+        'Ž' | 'Ź' | 'Ż' => 'Z',
+        other => other,
+    }
+}
+
+/// Normalizuje text pro porovnávání - bez diakritiky, malá písmena, jednoduché mezery
+pub fn normalize_for_match(text: &str) -> String {
+    fold_diacritics(text)
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Porovná dva texty bez ohledu na velikost písmen a diakritiku
+pub fn text_equals(a: &str, b: &str) -> bool {
+    normalize_for_match(a) == normalize_for_match(b)
+}
+
+/// Zjistí, zda text obsahuje hledaný výraz (bez ohledu na velikost písmen a diakritiku)
+pub fn text_contains(haystack: &str, needle: &str) -> bool {
+    normalize_for_match(haystack).contains(&normalize_for_match(needle))
+}
+
+/// Relevance textu pro vyhledávací dotaz (0 = neobsahuje žádné slovo dotazu)
+///
+/// Nejvýše se hodnotí shoda celého názvu, pak celá fráze v názvu, všechna slova v názvu
+/// a poměrná část slov v názvu; shoda v těle textu přidává menší část skóre.
+pub fn match_score(title: &str, body: Option<&str>, query: &str) -> u32 {
+    let query = normalize_for_match(query);
+    let terms: Vec<&str> = query.split(' ').filter(|term| !term.is_empty()).collect();
+    if terms.is_empty() {
+        return 0;
+    }
+    let title = normalize_for_match(title);
+    let body = body.map(normalize_for_match).unwrap_or_default();
+
+    let found = terms.iter().filter(|term| title.contains(*term)).count();
+    let title_score = if title == query {
+        100
+    } else if title.contains(&query) {
+        80
+    } else if found == terms.len() {
+        60
+    } else {
+        (40 * found / terms.len()) as u32
+    };
+    let body_found = terms.iter().filter(|term| body.contains(*term)).count();
+    let body_score = if body.contains(&query) { 15 } else { (10 * body_found / terms.len()) as u32 };
+    title_score + body_score
+}
+
+/// Primární váhy znaků podle české abecedy
+///
+/// Písmena s čárkou a kroužkem (á, é, ů...) mají stejnou váhu jako základní písmeno,
+/// samostatná písmena české abecedy (č, ř, š, ž) a spřežka "ch" se řadí za svůj základ.
+fn czech_primary_weights(text: &str) -> Vec<u32> {
+    let lower = text.to_lowercase();
+    let chars: Vec<char> = lower.chars().collect();
+    let mut weights = Vec::with_capacity(chars.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        // "ch" je samostatné písmeno řazené za "h"
+        if c == 'c' && chars.get(i + 1) == Some(&'h') {
+            weights.push(('h' as u32) * 4 + 2);
+            i += 2;
+            continue;
+        }
+
+        let weight = match c {
+            'č' => ('c' as u32) * 4 + 1,
+            'ř' => ('r' as u32) * 4 + 1,
+            'š' => ('s' as u32) * 4 + 1,
+            'ž' => ('z' as u32) * 4 + 1,
+            other => {
+                let base = fold_char(other);
+                if base.is_alphanumeric() || base.is_whitespace() {
+                    (base as u32) * 4
+                } else {
+                    // Interpunkce se při primárním řazení ignoruje
+                    i += 1;
+                    continue;
+                }
+            }
+        };
+        weights.push(weight);
+        i += 1;
+    }
+
+    weights
+}
+
+/// Porovná dva texty podle pravidel české abecedy
+///
+/// Nejprve se porovnávají základní písmena (á = a, ale č > c), při shodě rozhoduje
+/// diakritika a nakonec velikost písmen.
+pub fn compare_czech(a: &str, b: &str) -> Ordering {
+    czech_primary_weights(a)
+        .cmp(&czech_primary_weights(b))
+        .then_with(|| a.to_lowercase().cmp(&b.to_lowercase()))
+        .then_with(|| a.cmp(b))
+}
+
+/// Seřadí položky podle textového klíče v českém pořadí
+pub fn sort_czech_by_key<T, F>(items: &mut [T], key: F)
+where
+    F: Fn(&T) -> &str,
+{
+    items.sort_by(|a, b| compare_czech(key(a), key(b)));
+}
+
+/// Rozdělí text na části o velikosti nejvýše `max_bytes` bajtů
+///
+/// Dělí se přednostně na konci řádku, jinak na hranici znaku, takže se nikdy
+/// nerozdělí vícebajtový UTF-8 znak.
+pub fn split_text_chunks(text: &str, max_bytes: usize) -> Vec<String> {
+    let max_bytes = max_bytes.max(4);
+    let mut chunks = Vec::new();
+    let mut rest = text;
+
+    while rest.len() > max_bytes {
+        let mut end = max_bytes;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if let Some(newline) = rest[..end].rfind('\n') {
+            if newline > 0 {
+                end = newline + 1;
+            }
+        }
+        chunks.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+
+    if !rest.is_empty() || chunks.is_empty() {
+        chunks.push(rest.to_string());
+    }
+
+    chunks
+}
+
+/// Řádkový rozdíl dvou textů bez společného začátku a konce (`- ` odebrané, `+ ` přidané řádky)
+pub fn line_diff(old: &str, new: &str) -> Vec<String> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+
+    let prefix = old_lines.iter().zip(&new_lines).take_while(|(a, b)| a == b).count();
+    let suffix = old_lines[prefix..].iter().rev()
+        .zip(new_lines[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let removed = old_lines[prefix..old_lines.len() - suffix].iter().map(|line| format!("- {}", line));
+    let added = new_lines[prefix..new_lines.len() - suffix].iter().map(|line| format!("+ {}", line));
+    removed.chain(added).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fold_diacritics() {
+        assert_eq!(fold_diacritics("Příliš žluťoučký kůň úpěl ďábelské ódy"), "Prilis zlutoucky kun upel dabelske ody");
+        assert_eq!(fold_diacritics("ŘEŠENÍ"), "RESENI");
+    }
+
+    #[test]
+    fn test_match_score() {
+        assert_eq!(match_score("Přihlášení", None, "prihlaseni"), 100);
+        assert!(match_score("Chyba přihlášení uživatele", None, "přihlášení uživatele") > match_score("Uživatel a přihlášení", None, "přihlášení uživatele"));
+        assert!(match_score("Uživatel a přihlášení", None, "přihlášení uživatele") > match_score("Export", Some("přihlášení uživatele"), "přihlášení uživatele"));
+        assert_eq!(match_score("Export", Some("faktury"), "login"), 0);
+        assert_eq!(match_score("Export", None, "   "), 0);
+    }
+
+    #[test]
+    fn test_text_matching() {
+        assert!(text_equals("Jiří Novák", "jiri novak"));
+        assert!(text_equals("  Jiří   Novák ", "JIŘÍ NOVÁK"));
+        assert!(text_contains("Účetnictví 2024", "ucetni"));
+        assert!(!text_contains("Účetnictví", "marketing"));
+    }
+
+    #[test]
+    fn test_compare_czech() {
+        assert_eq!(compare_czech("cesta", "čelo"), Ordering::Less);
+        assert_eq!(compare_czech("hrad", "chata"), Ordering::Less);
+        assert_eq!(compare_czech("chata", "isty"), Ordering::Less);
+        assert_eq!(compare_czech("Řehoř", "Sýkora"), Ordering::Less);
+        assert_eq!(compare_czech("Ábel", "Adam"), Ordering::Less);
+        assert_eq!(compare_czech("Zdeněk", "Žaneta"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_split_text_chunks() {
+        assert_eq!(split_text_chunks("krátký", 100), vec!["krátký"]);
+        assert_eq!(split_text_chunks("", 100), vec![""]);
+
+        let chunks = split_text_chunks("řádek 1\nřádek 2\nřádek 3\n", 20);
+        assert_eq!(chunks, vec!["řádek 1\nřádek 2\n", "řádek 3\n"]);
+
+        let chunks = split_text_chunks("ččččč", 5);
+        assert_eq!(chunks, vec!["čč", "čč", "č"]);
+        assert_eq!(chunks.concat(), "ččččč");
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert!(line_diff("stejný\ntext", "stejný\ntext").is_empty());
+        assert_eq!(
+            line_diff("úvod\nodkaz: http://old\nzávěr", "úvod\nodkaz: https://new\nzávěr"),
+            vec!["- odkaz: http://old", "+ odkaz: https://new"]
+        );
+        assert_eq!(line_diff("a", "a\nb"), vec!["+ b"]);
+    }
+
+    #[test]
+    fn test_sort_czech_by_key() {
+        let mut names = vec!["Šimon", "Chalupa", "Čapek", "Svoboda", "Hájek", "Cibulka"];
+        sort_czech_by_key(&mut names, |name| *name);
+        assert_eq!(names, vec!["Cibulka", "Čapek", "Hájek", "Chalupa", "Svoboda", "Šimon"]);
+    }
+}
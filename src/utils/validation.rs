@@ -147,6 +147,36 @@ pub fn validate_pagination_offset(offset: i32) -> Result<(), String> {
     }
 }
 
+/// Normalizuje relativní cestu REST API (bez úvodního lomítka a přípony .json)
+///
+/// Odmítne cesty, které by mohly opustit API (`..`, schéma, query string, prázdné segmenty).
+pub fn normalize_api_path(path: &str) -> Result<String, String> {
+    let trimmed = path.trim().trim_start_matches('/');
+    let trimmed = trimmed.strip_suffix(".json").unwrap_or(trimmed);
+    if trimmed.is_empty() {
+        return Err("Cesta API nesmí být prázdná".to_string());
+    }
+    if trimmed.contains("://") || trimmed.contains(['?', '#', '\\']) {
+        return Err(format!("Neplatná cesta API '{}': použijte relativní cestu bez query stringu", path));
+    }
+    if trimmed.split('/').any(|segment| segment.is_empty() || segment == "." || segment == "..") {
+        return Err(format!("Neplatná cesta API '{}'", path));
+    }
+    Ok(trimmed.to_string())
+}
+
+/// Ověří normalizovanou cestu API proti vzoru, `*` odpovídá právě jednomu segmentu
+pub fn matches_api_path_pattern(path: &str, pattern: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches('/');
+    let pattern = pattern.strip_suffix(".json").unwrap_or(pattern);
+    let path_segments: Vec<&str> = path.split('/').collect();
+    let pattern_segments: Vec<&str> = pattern.split('/').collect();
+
+    path_segments.len() == pattern_segments.len()
+        && path_segments.iter().zip(&pattern_segments)
+            .all(|(segment, expected)| *expected == "*" || segment == expected)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -197,4 +227,16 @@ mod tests {
         assert!(validate_percentage(-1, "test").is_err());
         assert!(validate_percentage(101, "test").is_err());
     }
-} 
\ No newline at end of file
+
+    #[test]
+    fn test_api_path_patterns() {
+        assert_eq!(normalize_api_path("/issues/12.json").unwrap(), "issues/12");
+        assert!(normalize_api_path("issues/../users").is_err());
+        assert!(normalize_api_path("https://evil.example/issues").is_err());
+        assert!(normalize_api_path("issues.json?key=1").is_err());
+        assert!(matches_api_path_pattern("issues/12", "issues/*"));
+        assert!(matches_api_path_pattern("trackers", "/trackers.json"));
+        assert!(!matches_api_path_pattern("issues/12/relations", "issues/*"));
+        assert!(!matches_api_path_pattern("users", "issues"));
+    }
+}